@@ -233,6 +233,15 @@ impl<A: AsRef<[AccelTy]>> Accels<A> {
         Accels { accels: self.accels.as_ref() }
     }
 
+    /// Convert the representation of these accelerators by applying the
+    /// given function to it.
+    pub(crate) fn map<B: AsRef<[AccelTy]>>(
+        self,
+        f: impl FnOnce(A) -> B,
+    ) -> Accels<B> {
+        Accels { accels: f(self.accels) }
+    }
+
     /// Return the bytes representing the serialization of the accelerators.
     pub fn as_bytes(&self) -> &[u8] {
         let accels = self.accels.as_ref();
@@ -25,7 +25,7 @@ use crate::{
         sparse,
     },
     nfa::thompson,
-    util::{look::LookMatcher, search::MatchKind},
+    util::{alphabet::ByteClassSet, look::LookMatcher, search::MatchKind},
 };
 use crate::{
     dfa::{
@@ -1277,6 +1277,82 @@ impl Builder {
         Ok(dfa)
     }
 
+    /// Build a DFA that matches a boolean combination of the languages of
+    /// the DFAs given.
+    ///
+    /// The leaves of `product` refer to the DFAs given by their index. Each
+    /// DFA must support anchored searches and should have been built with
+    /// [`MatchKind::All`] semantics. If any of the DFAs has look-behind
+    /// assertions in its prefix, then every DFA must have been built with the
+    /// same [`LookMatcher`] configuration.
+    ///
+    /// The DFA returned has exactly one pattern and only supports anchored
+    /// searches. The match kind, start kind, starts for each pattern, quit
+    /// bytes and prefilter options of this builder are ignored. Instead, the
+    /// quit bytes of the DFA returned are the union of the quit bytes of the
+    /// DFAs given.
+    pub(crate) fn build_from_product<T: AsRef<[u32]>>(
+        &self,
+        dfas: &[&DFA<T>],
+        product: &determinize::Product,
+    ) -> Result<OwnedDFA, BuildError> {
+        let mut quitset = ByteSet::empty();
+        for dfa in dfas.iter() {
+            for b in dfa.quitset().iter() {
+                quitset.add(b);
+            }
+        }
+        let classes = if !self.config.get_byte_classes() {
+            ByteClasses::singletons()
+        } else {
+            // Every DFA given already distinguishes its quit bytes from all
+            // other bytes in its equivalence classes, so we only need to
+            // merge the classes.
+            let mut set = ByteClassSet::empty();
+            for dfa in dfas.iter() {
+                set.add_classes(dfa.byte_classes());
+            }
+            set.byte_classes()
+        };
+        let flags = Flags {
+            // We could be more precise here by checking whether the product
+            // matches the empty string, but this is always correct.
+            has_empty: true,
+            is_utf8: dfas.iter().all(|dfa| dfa.flags().is_utf8),
+            is_always_start_anchored: true,
+        };
+        let mut dfa = DFA::initial(
+            classes,
+            1,
+            StartKind::Anchored,
+            &LookMatcher::default(),
+            false,
+            None,
+            quitset,
+            flags,
+        )?;
+        // The start states of the product are tuples of the start states of
+        // each DFA, so they must be selected in the same way.
+        if let Some(first) = dfas.first() {
+            dfa.st.start_map = first.st.start_map.clone();
+        }
+        determinize::Config::new()
+            .dfa_size_limit(self.config.get_dfa_size_limit())
+            .determinize_size_limit(self.config.get_determinize_size_limit())
+            .run_product(dfas, product, &mut dfa)?;
+        if self.config.get_minimize() {
+            dfa.minimize();
+        }
+        if self.config.get_accelerate() {
+            dfa.accelerate();
+        }
+        if !self.config.get_specialize_start_states() {
+            dfa.special.set_no_special_start_states();
+        }
+        dfa.set_universal_starts();
+        Ok(dfa)
+    }
+
     /// Apply the given dense DFA configuration options to this builder.
     pub fn configure(&mut self, config: Config) -> &mut Builder {
         self.config = self.config.overwrite(config);
@@ -1607,6 +1683,54 @@ impl<T: AsRef<[u32]>> DFA<T> {
         }
     }
 
+    /// Set the prefilter used by this DFA.
+    ///
+    /// Prefilters are never serialized, so this is used to restore the
+    /// prefilter of a DFA that was deserialized as part of a larger object
+    /// that does know how to serialize its prefilter. The prefilter given
+    /// should be the same one that the DFA was originally built with.
+    pub(crate) fn set_prefilter(&mut self, pre: Option<Prefilter>) {
+        self.pre = pre;
+    }
+
+    /// Convert every table in this DFA to a different representation by
+    /// applying the given function to it.
+    ///
+    /// This is useful for wrapping the tables of DFAs that may be either
+    /// owned or borrowed (like a `Cow`) in a single type. The tables
+    /// themselves are never copied unless `f` copies them.
+    pub(crate) fn map_tables<U: AsRef<[u32]>>(
+        self,
+        mut f: impl FnMut(T) -> U,
+    ) -> DFA<U> {
+        DFA {
+            tt: TransitionTable {
+                table: f(self.tt.table),
+                classes: self.tt.classes,
+                stride2: self.tt.stride2,
+            },
+            st: StartTable {
+                table: f(self.st.table),
+                kind: self.st.kind,
+                start_map: self.st.start_map,
+                stride: self.st.stride,
+                pattern_len: self.st.pattern_len,
+                universal_start_unanchored: self.st.universal_start_unanchored,
+                universal_start_anchored: self.st.universal_start_anchored,
+            },
+            ms: MatchStates {
+                slices: f(self.ms.slices),
+                pattern_ids: f(self.ms.pattern_ids),
+                pattern_len: self.ms.pattern_len,
+            },
+            special: self.special,
+            accels: self.accels.map(f),
+            pre: self.pre,
+            quitset: self.quitset,
+            flags: self.flags,
+        }
+    }
+
     /// Returns the starting state configuration for this DFA.
     ///
     /// The default is [`StartKind::Both`], which means the DFA supports both
@@ -1732,6 +1856,350 @@ impl<T: AsRef<[u32]>> DFA<T> {
     }
 }
 
+/// Routines for combining the languages of dense DFAs and for answering
+/// questions about them, such as whether one DFA matches every string that
+/// another one does.
+///
+/// The language of a DFA, for the purposes of these routines, is the set
+/// of haystacks for which an anchored search over the entire haystack
+/// reports a match ending at the end of the haystack. Its patterns are not
+/// distinguished. The DFAs given must support anchored searches and should
+/// be built with [`MatchKind::All`] semantics, since a DFA built with
+/// leftmost-first semantics may stop searching before the end of a string
+/// in its language. The DFAs returned only support anchored searches and
+/// always report matches for [`PatternID::ZERO`].
+#[cfg(feature = "dfa-build")]
+impl<T: AsRef<[u32]>> DFA<T> {
+    /// Build a DFA that matches precisely the strings matched by both this
+    /// DFA and the one given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::{dense::DFA, Automaton, StartKind},
+    ///     Anchored, Input, MatchKind,
+    /// };
+    ///
+    /// let config = DFA::config()
+    ///     .match_kind(MatchKind::All)
+    ///     .start_kind(StartKind::Anchored);
+    /// let words = DFA::builder().configure(config.clone()).build(r"[a-z]+")?;
+    /// let no_x = DFA::builder().configure(config).build(r"[^x]*")?;
+    /// let dfa = words.intersect(&no_x)?;
+    ///
+    /// let input = Input::new("abc").anchored(Anchored::Yes);
+    /// assert!(dfa.try_search_fwd(&input)?.is_some());
+    /// let input = Input::new("xyz").anchored(Anchored::Yes);
+    /// assert!(dfa.try_search_fwd(&input)?.is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn intersect<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<OwnedDFA, BuildError> {
+        use determinize::Product;
+
+        let product =
+            Product::Intersection(vec![Product::DFA(0), Product::DFA(1)]);
+        self.product_with(other, &product)
+    }
+
+    /// Build a DFA that matches precisely the strings matched by either this
+    /// DFA or the one given.
+    pub fn union<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<OwnedDFA, BuildError> {
+        use determinize::Product;
+
+        let product = Product::Union(vec![Product::DFA(0), Product::DFA(1)]);
+        self.product_with(other, &product)
+    }
+
+    /// Build a DFA that matches precisely the strings matched by this DFA
+    /// but not by the one given.
+    pub fn difference<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<OwnedDFA, BuildError> {
+        use determinize::Product;
+
+        let product = Product::Intersection(vec![
+            Product::DFA(0),
+            Product::Complement(alloc::boxed::Box::new(Product::DFA(1))),
+        ]);
+        self.product_with(other, &product)
+    }
+
+    /// Build a DFA that matches precisely the strings not matched by this
+    /// DFA.
+    ///
+    /// The complement is taken with respect to all byte strings, so the DFA
+    /// returned may match strings that are not valid UTF-8 even if this DFA
+    /// only matches valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::{dense::DFA, Automaton, StartKind},
+    ///     Anchored, Input, MatchKind,
+    /// };
+    ///
+    /// let config = DFA::config()
+    ///     .match_kind(MatchKind::All)
+    ///     .start_kind(StartKind::Anchored);
+    /// let dfa = DFA::builder().configure(config).build(r"a+")?;
+    /// let complement = dfa.complement()?;
+    ///
+    /// // The complement matches the empty string, and nothing matched by
+    /// // the original DFA.
+    /// assert_eq!(Some(vec![]), complement.shortest_match_string()?);
+    /// assert!(dfa.is_disjoint(&complement)?);
+    /// // Since searches report the longest match, this match corresponds to
+    /// // the whole haystack.
+    /// let input = Input::new("aab").anchored(Anchored::Yes);
+    /// let got = complement.try_search_fwd(&input)?;
+    /// assert_eq!(Some(3), got.map(|m| m.offset()));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn complement(&self) -> Result<OwnedDFA, BuildError> {
+        use determinize::Product;
+
+        self.check_language_support()?;
+        let product =
+            Product::Complement(alloc::boxed::Box::new(Product::DFA(0)));
+        Builder::new()
+            .configure(Config::new().minimize(true))
+            .build_from_product(&[&self.as_ref()], &product)
+    }
+
+    /// Returns the shortest string matched by this DFA, or `None` if this
+    /// DFA matches nothing. When there are multiple shortest strings, the
+    /// one returned is unspecified.
+    ///
+    /// Since DFAs operate on bytes, the string returned is not necessarily
+    /// valid UTF-8.
+    ///
+    /// This returns an error if this DFA does not support anchored searches
+    /// or if it has any quit bytes, since the latter make its language
+    /// unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::{dense::DFA, StartKind},
+    ///     MatchKind,
+    /// };
+    ///
+    /// let config = DFA::config()
+    ///     .match_kind(MatchKind::All)
+    ///     .start_kind(StartKind::Anchored);
+    /// let dfa = DFA::builder().configure(config.clone()).build(r"foo|ba")?;
+    /// assert_eq!(Some(b"ba".to_vec()), dfa.shortest_match_string()?);
+    ///
+    /// let dfa = DFA::builder().configure(config).build(r"[a&&b]")?;
+    /// assert_eq!(None, dfa.shortest_match_string()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn shortest_match_string(
+        &self,
+    ) -> Result<Option<Vec<u8>>, BuildError> {
+        self.check_language_support()?;
+        if !self.quitset().is_empty() {
+            return Err(BuildError::unsupported_language_quit());
+        }
+        let start = self
+            .st
+            .start(Anchored::Yes, Start::Text)
+            .map_err(|_| BuildError::unsupported_language_unanchored())?;
+        // A breadth first search from the start state, where each state
+        // records the state and byte that first reached it. Since every
+        // state is visited at most once, this is linear in the size of the
+        // DFA.
+        let mut parents: Vec<Option<(StateID, u8)>> =
+            vec![None; self.state_len()];
+        let mut seen = vec![false; self.state_len()];
+        let mut queue = vec![start];
+        seen[self.to_index(start)] = true;
+        let mut i = 0;
+        while i < queue.len() {
+            let sid = queue[i];
+            i += 1;
+            if self.is_match_state(self.next_eoi_state(sid)) {
+                let mut string = vec![];
+                let mut cur = sid;
+                while let Some((prev, byte)) = parents[self.to_index(cur)] {
+                    string.push(byte);
+                    cur = prev;
+                }
+                string.reverse();
+                return Ok(Some(string));
+            }
+            for unit in self.byte_classes().representatives(0..=255) {
+                let byte = unit.as_u8().unwrap();
+                let next = self.next_state(sid, byte);
+                if seen[self.to_index(next)] {
+                    continue;
+                }
+                seen[self.to_index(next)] = true;
+                parents[self.to_index(next)] = Some((sid, byte));
+                queue.push(next);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns true if and only if this DFA matches no strings at all.
+    ///
+    /// This returns an error in the same cases as
+    /// [`DFA::shortest_match_string`].
+    pub fn is_language_empty(&self) -> Result<bool, BuildError> {
+        Ok(self.shortest_match_string()?.is_none())
+    }
+
+    /// Returns a string matched by this DFA but not by the one given, or
+    /// `None` if every string matched by this DFA is also matched by the one
+    /// given.
+    ///
+    /// # Example
+    ///
+    /// This shows how to check whether one allowlist pattern is subsumed by
+    /// another.
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::{dense::DFA, StartKind},
+    ///     MatchKind,
+    /// };
+    ///
+    /// let config = DFA::config()
+    ///     .match_kind(MatchKind::All)
+    ///     .start_kind(StartKind::Anchored);
+    /// let build = |pattern| {
+    ///     DFA::builder().configure(config.clone()).build(pattern)
+    /// };
+    /// let narrow = build(r"user-[0-9]{3}")?;
+    /// let wide = build(r"user-[0-9]+")?;
+    ///
+    /// assert_eq!(None, narrow.subset_counterexample(&wide)?);
+    /// assert_eq!(
+    ///     Some(b"user-0".to_vec()),
+    ///     wide.subset_counterexample(&narrow)?,
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn subset_counterexample<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<Option<Vec<u8>>, BuildError> {
+        self.difference(other)?.shortest_match_string()
+    }
+
+    /// Returns true if and only if every string matched by this DFA is also
+    /// matched by the one given.
+    pub fn is_subset<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<bool, BuildError> {
+        Ok(self.subset_counterexample(other)?.is_none())
+    }
+
+    /// Returns a string matched by exactly one of this DFA and the one
+    /// given, or `None` if both DFAs match precisely the same strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::{dense::DFA, StartKind},
+    ///     MatchKind,
+    /// };
+    ///
+    /// let config = DFA::config()
+    ///     .match_kind(MatchKind::All)
+    ///     .start_kind(StartKind::Anchored);
+    /// let build = |pattern| {
+    ///     DFA::builder().configure(config.clone()).build(pattern)
+    /// };
+    ///
+    /// let dfa1 = build(r"(?:ab)*a")?;
+    /// let dfa2 = build(r"a(?:ba)*")?;
+    /// assert_eq!(None, dfa1.equivalence_counterexample(&dfa2)?);
+    ///
+    /// let dfa2 = build(r"a(?:ba)+")?;
+    /// assert_eq!(
+    ///     Some(b"a".to_vec()),
+    ///     dfa1.equivalence_counterexample(&dfa2)?,
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn equivalence_counterexample<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<Option<Vec<u8>>, BuildError> {
+        use determinize::Product;
+
+        let not = |i| Product::Complement(alloc::boxed::Box::new(i));
+        let product = Product::Union(vec![
+            Product::Intersection(vec![Product::DFA(0), not(Product::DFA(1))]),
+            Product::Intersection(vec![not(Product::DFA(0)), Product::DFA(1)]),
+        ]);
+        self.product_with(other, &product)?.shortest_match_string()
+    }
+
+    /// Returns true if and only if this DFA and the one given match
+    /// precisely the same strings.
+    pub fn is_equivalent<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<bool, BuildError> {
+        Ok(self.equivalence_counterexample(other)?.is_none())
+    }
+
+    /// Returns a string matched by both this DFA and the one given, or
+    /// `None` if no string is matched by both.
+    pub fn intersection_example<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<Option<Vec<u8>>, BuildError> {
+        self.intersect(other)?.shortest_match_string()
+    }
+
+    /// Returns true if and only if no string is matched by both this DFA
+    /// and the one given.
+    pub fn is_disjoint<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+    ) -> Result<bool, BuildError> {
+        Ok(self.intersection_example(other)?.is_none())
+    }
+
+    /// Build the given boolean combination of this DFA (at index 0) and the
+    /// one given (at index 1). The result is always minimized.
+    fn product_with<U: AsRef<[u32]>>(
+        &self,
+        other: &DFA<U>,
+        product: &determinize::Product,
+    ) -> Result<OwnedDFA, BuildError> {
+        self.check_language_support()?;
+        other.check_language_support()?;
+        Builder::new()
+            .configure(Config::new().minimize(true))
+            .build_from_product(&[&self.as_ref(), &other.as_ref()], product)
+    }
+
+    /// Returns an error if this DFA cannot take part in a product.
+    fn check_language_support(&self) -> Result<(), BuildError> {
+        if !self.start_kind().has_anchored() {
+            return Err(BuildError::unsupported_language_unanchored());
+        }
+        Ok(())
+    }
+}
+
 /// Routines for converting a dense DFA to other representations, such as
 /// sparse DFAs or raw bytes suitable for persistent storage.
 impl<T: AsRef<[u32]>> DFA<T> {
@@ -5015,6 +5483,46 @@ impl BuildError {
         BuildError { kind: BuildErrorKind::Unsupported(msg) }
     }
 
+    pub(crate) fn unsupported_dfa_segment() -> BuildError {
+        let msg = "cannot build DFAs for regexes with Unicode segmentation \
+                   boundaries (like '\\b{wb}' or '\\b{sb}'); use a \
+                   different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_dfa_look_around() -> BuildError {
+        let msg = "cannot build DFAs for regexes with look-around \
+                   sub-expressions (like '(?=re)' or '(?<=re)'); use a \
+                   different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_dfa_backreference() -> BuildError {
+        let msg = "cannot build DFAs for regexes with backreferences \
+                   (like '\\1'); use a different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_dfa_atomic() -> BuildError {
+        let msg = "cannot build DFAs for regexes with atomic groups or \
+                   possessive repetitions (like '(?>a*)' or 'a*+'); \
+                   use a different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_language_unanchored() -> BuildError {
+        let msg = "language operations require DFAs that support anchored \
+                   searches";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_language_quit() -> BuildError {
+        let msg = "language operations cannot be decided for DFAs with \
+                   quit bytes, such as those that heuristically support \
+                   Unicode word boundaries";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
     pub(crate) fn too_many_states() -> BuildError {
         BuildError { kind: BuildErrorKind::TooManyStates }
     }
@@ -5150,4 +5658,93 @@ mod tests {
         let got = dfa.try_search_rev(&input);
         assert_eq!(Err(expected), got);
     }
+
+    fn language_dfa(pattern: &str) -> OwnedDFA {
+        DFA::builder()
+            .configure(
+                DFA::config()
+                    .match_kind(MatchKind::All)
+                    .start_kind(StartKind::Anchored),
+            )
+            .build(pattern)
+            .unwrap()
+    }
+
+    #[test]
+    fn language_union_and_difference() {
+        let digits = language_dfa(r"[0-9]+");
+        let letters = language_dfa(r"[a-z]+");
+        let either = language_dfa(r"[0-9a-z]+");
+        let union = digits.union(&letters).unwrap();
+        assert!(union.is_subset(&either).unwrap());
+        assert_eq!(
+            Some(b"0a".to_vec()),
+            either.subset_counterexample(&union).unwrap(),
+        );
+
+        let diff = either.difference(&letters).unwrap();
+        assert_eq!(Some(b"0".to_vec()), diff.shortest_match_string().unwrap());
+        assert!(diff.is_disjoint(&letters).unwrap());
+        assert!(!diff.is_disjoint(&digits).unwrap());
+    }
+
+    // A start state may be re-entered by a transition, at which point its
+    // complement is a match. This checks that the complement's start state
+    // is nonetheless never a match state.
+    #[test]
+    fn language_complement_reentered_start() {
+        let dfa = language_dfa(r"(?:ab)*");
+        let complement = dfa.complement().unwrap();
+        assert_eq!(
+            Some(b"\x00".to_vec()),
+            complement.shortest_match_string().unwrap(),
+        );
+        assert!(dfa.is_disjoint(&complement).unwrap());
+        let all = dfa.union(&complement).unwrap();
+        let any = DFA::builder()
+            .configure(
+                DFA::config()
+                    .match_kind(MatchKind::All)
+                    .start_kind(StartKind::Anchored),
+            )
+            .thompson(thompson::Config::new().utf8(false))
+            .syntax(crate::util::syntax::Config::new().utf8(false))
+            .build(r"(?s-u:.)*")
+            .unwrap();
+        assert!(all.is_equivalent(&any).unwrap());
+        assert!(complement.complement().unwrap().is_equivalent(&dfa).unwrap());
+    }
+
+    #[test]
+    fn language_look_around() {
+        let dfa1 = language_dfa(r"(?m)a$\n?");
+        let dfa2 = language_dfa(r"a\n?");
+        assert!(dfa1.is_equivalent(&dfa2).unwrap());
+        let dfa3 = language_dfa(r"a$\n?");
+        assert_eq!(
+            Some(b"a\n".to_vec()),
+            dfa1.equivalence_counterexample(&dfa3).unwrap(),
+        );
+    }
+
+    #[test]
+    fn language_errors() {
+        let unanchored = DFA::builder()
+            .configure(DFA::config().start_kind(StartKind::Unanchored))
+            .build(r"a")
+            .unwrap();
+        assert!(unanchored.complement().is_err());
+        assert!(unanchored.is_equivalent(&language_dfa(r"a")).is_err());
+
+        let quit = DFA::builder()
+            .configure(
+                DFA::config()
+                    .match_kind(MatchKind::All)
+                    .start_kind(StartKind::Anchored)
+                    .unicode_word_boundary(true),
+            )
+            .build(r"\b\w+\b")
+            .unwrap();
+        assert!(quit.is_language_empty().is_err());
+    }
 }
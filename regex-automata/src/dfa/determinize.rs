@@ -3,7 +3,7 @@ use alloc::{collections::BTreeMap, vec::Vec};
 use crate::{
     dfa::{
         dense::{self, BuildError},
-        DEAD,
+        Automaton, DEAD,
     },
     nfa::thompson,
     util::{
@@ -76,6 +76,46 @@ impl Config {
         runner.run()
     }
 
+    /// Run a product construction over the given DFAs and write the resulting
+    /// DFA into the one given.
+    ///
+    /// The DFA written matches precisely the strings described by `product`,
+    /// whose leaves refer to the DFAs given by their index. The DFA given
+    /// should be initialized but otherwise empty, with a single pattern and
+    /// only anchored starting states. Each of the DFAs given must support
+    /// anchored searches, and should be built with `MatchKind::All`
+    /// semantics so that every string in its language leads to a match
+    /// state.
+    ///
+    /// States in the product that can never lead to a match are collapsed
+    /// into the dead state.
+    pub fn run_product<T: AsRef<[u32]>>(
+        &self,
+        dfas: &[&dense::DFA<T>],
+        product: &Product,
+        dfa: &mut dense::OwnedDFA,
+    ) -> Result<(), BuildError> {
+        assert!(
+            !dfa.start_kind().has_unanchored(),
+            "product DFAs only support anchored searches",
+        );
+        assert!(
+            dfas.iter().all(|d| d.start_kind().has_anchored()),
+            "every DFA in a product must support anchored searches",
+        );
+        let runner = ProductRunner {
+            config: self.clone(),
+            dfas,
+            product,
+            dfa,
+            tuples: alloc::vec![],
+            matches: alloc::vec![],
+            trans: alloc::vec![],
+            cache: ProductMap::default(),
+        };
+        runner.run()
+    }
+
     /// The match semantics to use for determinization.
     ///
     /// MatchKind::All corresponds to the standard textbook construction.
@@ -218,6 +258,18 @@ impl<'a> Runner<'a> {
         {
             return Err(BuildError::unsupported_dfa_word_boundary_unicode());
         }
+        if self.nfa.look_set_any().contains_segment() {
+            return Err(BuildError::unsupported_dfa_segment());
+        }
+        if self.nfa.has_look_around() {
+            return Err(BuildError::unsupported_dfa_look_around());
+        }
+        if self.nfa.has_backreference() {
+            return Err(BuildError::unsupported_dfa_backreference());
+        }
+        if self.nfa.has_atomic() {
+            return Err(BuildError::unsupported_dfa_atomic());
+        }
 
         // A sequence of "representative" bytes drawn from each equivalence
         // class. These representative bytes are fed to the NFA to compute
@@ -597,3 +649,272 @@ impl<'a> Runner<'a> {
         + self.scratch_state_builder.capacity()
     }
 }
+
+/// A boolean combination of the languages recognized by one or more DFAs.
+///
+/// Each leaf refers to a DFA by its index in the slice of DFAs given to
+/// `Config::run_product`.
+#[derive(Clone, Debug)]
+pub(crate) enum Product {
+    /// The language of the DFA at the given index.
+    DFA(usize),
+    /// The strings that are in every one of the given languages.
+    Intersection(Vec<Product>),
+    /// The strings that are in at least one of the given languages.
+    Union(Vec<Product>),
+    /// The strings that are not in the given language.
+    Complement(alloc::boxed::Box<Product>),
+}
+
+impl Product {
+    /// Returns true if and only if this combination matches, given a
+    /// predicate that reports whether the DFA at each leaf index matches.
+    fn is_match(&self, leaf: &impl Fn(usize) -> bool) -> bool {
+        match *self {
+            Product::DFA(i) => leaf(i),
+            Product::Intersection(ref xs) => {
+                xs.iter().all(|x| x.is_match(leaf))
+            }
+            Product::Union(ref xs) => xs.iter().any(|x| x.is_match(leaf)),
+            Product::Complement(ref x) => !x.is_match(leaf),
+        }
+    }
+}
+
+/// A map from tuples of states (one for each DFA in a product), along with
+/// whether the tuple is a start state, to the index of the corresponding
+/// product state.
+#[cfg(feature = "std")]
+type ProductMap = std::collections::HashMap<(Vec<StateID>, bool), usize>;
+#[cfg(not(feature = "std"))]
+type ProductMap = BTreeMap<(Vec<StateID>, bool), usize>;
+
+/// A sentinel used in place of a product state index for transitions that
+/// lead to the quit state.
+const PRODUCT_QUIT: usize = usize::MAX;
+
+/// The implementation of the product construction.
+///
+/// Each product state is a tuple of states, one from each DFA in the
+/// product. Since every DFA delays its matches by one byte in the same way,
+/// the match status of a product state is just the boolean combination of
+/// the match statuses of its components. The exception is a start state:
+/// since no byte has been consumed yet, it is never a match state. This is
+/// why start states are kept distinct from other product states with the
+/// same tuple. (The complement of a DFA in its start state may well match
+/// once that same state is reached again by a transition.)
+///
+/// The construction happens in two phases. First, every tuple reachable
+/// from a start state is explored. Second, only the tuples from which a
+/// match (or a quit state) is reachable are written to the DFA. Everything
+/// else is mapped to the dead state. This is important because, unlike in
+/// determinization, a tuple of dead states is not necessarily dead. (The
+/// complement of a DFA in its dead state matches everything.)
+struct ProductRunner<'a, T> {
+    /// The configuration used to initialize the product construction.
+    config: Config,
+    /// The DFAs referenced by the leaves of the product.
+    dfas: &'a [&'a dense::DFA<T>],
+    /// The boolean combination of the DFAs to build.
+    product: &'a Product,
+    /// The DFA we're building.
+    dfa: &'a mut dense::OwnedDFA,
+    /// The tuple of component states for each product state, indexed by
+    /// product state index.
+    tuples: Vec<Vec<StateID>>,
+    /// Whether each product state is a match state.
+    matches: Vec<bool>,
+    /// The transitions of every product state, with one entry for each
+    /// equivalence class (including EOI) in the DFA being built. Each entry
+    /// is either a product state index or PRODUCT_QUIT.
+    trans: Vec<usize>,
+    /// A map from tuples to product state indices, for reusing states.
+    cache: ProductMap,
+}
+
+impl<'a, T: AsRef<[u32]>> ProductRunner<'a, T> {
+    /// Build the product DFA.
+    fn run(mut self) -> Result<(), BuildError> {
+        let representatives: Vec<alphabet::Unit> =
+            self.dfa.byte_classes().representatives(..).collect();
+        let stride = representatives.len();
+
+        // Each DFA's anchored start state for each starting configuration.
+        let mut leaf_starts = alloc::vec![
+            alloc::vec![DEAD; Start::len()];
+            self.dfas.len()
+        ];
+        for (i, leaf) in self.dfas.iter().enumerate() {
+            for (sid, anchored, start) in leaf.starts() {
+                if anchored == Anchored::Yes {
+                    leaf_starts[i][start.as_usize()] = sid;
+                }
+            }
+        }
+        let mut uncompiled = alloc::vec![];
+        let mut starts = alloc::vec![];
+        for start_index in 0..Start::len() {
+            let tuple: Vec<StateID> =
+                leaf_starts.iter().map(|ids| ids[start_index]).collect();
+            let (index, is_new) = self.add_tuple(tuple, true, stride)?;
+            if is_new {
+                uncompiled.push(index);
+            }
+            starts.push(index);
+        }
+        while let Some(index) = uncompiled.pop() {
+            for (k, &unit) in representatives.iter().enumerate() {
+                let mut quit = false;
+                let tuple: Vec<StateID> = self.tuples[index]
+                    .iter()
+                    .zip(self.dfas.iter())
+                    .map(|(&sid, leaf)| {
+                        let next = match unit.as_u8() {
+                            Some(byte) => leaf.next_state(sid, byte),
+                            None => leaf.next_eoi_state(sid),
+                        };
+                        quit = quit || leaf.is_quit_state(next);
+                        next
+                    })
+                    .collect();
+                let next = if quit {
+                    PRODUCT_QUIT
+                } else {
+                    let (next, is_new) =
+                        self.add_tuple(tuple, false, stride)?;
+                    if is_new {
+                        uncompiled.push(next);
+                    }
+                    next
+                };
+                self.trans[index * stride + k] = next;
+            }
+        }
+
+        // Find every product state from which a match or a quit state is
+        // reachable, by walking the transitions backwards from match states.
+        let mut preds: Vec<Vec<usize>> =
+            alloc::vec![alloc::vec![]; self.tuples.len()];
+        let mut live = alloc::vec![false; self.tuples.len()];
+        let mut stack = alloc::vec![];
+        for (index, trans) in self.trans.chunks(stride).enumerate() {
+            for &next in trans {
+                if next == PRODUCT_QUIT {
+                    if !live[index] {
+                        live[index] = true;
+                        stack.push(index);
+                    }
+                } else {
+                    preds[next].push(index);
+                }
+            }
+            if self.matches[index] && !live[index] {
+                live[index] = true;
+                stack.push(index);
+            }
+        }
+        while let Some(index) = stack.pop() {
+            for &prev in &preds[index] {
+                if !live[prev] {
+                    live[prev] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+
+        // Now write all live product states to the DFA.
+        let mut ids = alloc::vec![DEAD; self.tuples.len()];
+        for index in 0..self.tuples.len() {
+            if !live[index] {
+                continue;
+            }
+            ids[index] = self.dfa.add_empty_state()?;
+            if let Some(limit) = self.config.dfa_size_limit {
+                if self.dfa.memory_usage() > limit {
+                    return Err(BuildError::dfa_exceeded_size_limit(limit));
+                }
+            }
+        }
+        let quit_id = self.dfa.quit_id();
+        for index in 0..self.tuples.len() {
+            if !live[index] {
+                continue;
+            }
+            for (k, &unit) in representatives.iter().enumerate() {
+                let next = self.trans[index * stride + k];
+                let next_id =
+                    if next == PRODUCT_QUIT { quit_id } else { ids[next] };
+                self.dfa.set_transition(ids[index], unit, next_id);
+            }
+        }
+        for (start_index, &index) in starts.iter().enumerate() {
+            let start = Start::from_usize(start_index).unwrap();
+            self.dfa.set_start_state(Anchored::Yes, start, ids[index]);
+        }
+        debug!(
+            "product construction complete, product states: {}, \
+             dense DFA size: {}",
+            self.tuples.len(),
+            self.dfa.memory_usage(),
+        );
+
+        let mut matches: BTreeMap<StateID, Vec<PatternID>> = BTreeMap::new();
+        for index in 0..self.tuples.len() {
+            if live[index] && self.matches[index] {
+                matches.insert(ids[index], alloc::vec![PatternID::ZERO]);
+            }
+        }
+        self.dfa.shuffle(matches)?;
+        Ok(())
+    }
+
+    /// Return the index of the product state for the given tuple, creating
+    /// it if it doesn't already exist. The boolean returned is true if and
+    /// only if a new product state was created.
+    ///
+    /// When `is_start` is true, the product state returned is a start state,
+    /// which is never a match state.
+    fn add_tuple(
+        &mut self,
+        tuple: Vec<StateID>,
+        is_start: bool,
+        stride: usize,
+    ) -> Result<(usize, bool), BuildError> {
+        let key = (tuple, is_start);
+        if let Some(&index) = self.cache.get(&key) {
+            return Ok((index, false));
+        }
+        let (tuple, _) = key;
+        let index = self.tuples.len();
+        let dfas = self.dfas;
+        let is_match = !is_start
+            && self.product.is_match(&|i| dfas[i].is_match_state(tuple[i]));
+        self.cache.insert((tuple.clone(), is_start), index);
+        self.tuples.push(tuple);
+        self.matches.push(is_match);
+        // The transitions are filled in once this state is compiled.
+        self.trans.extend(core::iter::repeat(0).take(stride));
+        if let Some(limit) = self.config.determinize_size_limit {
+            if self.memory_usage() > limit {
+                return Err(BuildError::determinize_exceeded_size_limit(
+                    limit,
+                ));
+            }
+        }
+        Ok((index, true))
+    }
+
+    /// Return the memory usage, in bytes, of this product construction at the
+    /// current point in time. This does not include memory used by the DFAs.
+    fn memory_usage(&self) -> usize {
+        use core::mem::size_of;
+
+        let tuple_size =
+            size_of::<Vec<StateID>>() + self.dfas.len() * size_of::<StateID>();
+        // Each tuple is stored twice: once in 'tuples' and once in the cache.
+        (2 * self.tuples.len() * tuple_size)
+            + self.cache.len() * size_of::<usize>()
+            + self.matches.len() * size_of::<bool>()
+            + self.trans.len() * size_of::<usize>()
+    }
+}
@@ -341,13 +341,15 @@ pub mod onepass;
 pub mod regex;
 #[cfg(feature = "dfa-search")]
 pub mod sparse;
+#[cfg(feature = "dfa-build")]
+pub mod witness;
 
 #[cfg(feature = "dfa-search")]
 pub(crate) mod accel;
 #[cfg(feature = "dfa-search")]
 mod automaton;
 #[cfg(feature = "dfa-build")]
-mod determinize;
+pub(crate) mod determinize;
 #[cfg(feature = "dfa-build")]
 mod minimize;
 #[cfg(any(feature = "dfa-build", feature = "dfa-onepass"))]
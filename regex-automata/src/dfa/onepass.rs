@@ -51,11 +51,24 @@ use crate::{
         int::{Usize, U32, U64, U8},
         look::{Look, LookSet, UnicodeWordBoundaryError},
         primitives::{NonMaxUsize, PatternID, StateID},
-        search::{Anchored, Input, Match, MatchError, MatchKind, Span},
+        search::{
+            Anchored, Input, Match, MatchError, MatchKind, Span,
+            CANCEL_CHECK_INTERVAL,
+        },
         sparse_set::SparseSet,
+        wire::{self, DeserializeError, Endian, Reader, Writer},
     },
 };
 
+/// The label that is pre-pended to a serialized one-pass DFA.
+const LABEL: &str = "rust-regex-automata-dfa-onepass";
+
+/// The format version of one-pass DFAs. This version gets incremented when a
+/// change occurs. A change may not necessarily be a breaking change, but the
+/// version does permit good error messages in the case where a breaking change
+/// is made.
+const VERSION: u32 = 1;
+
 /// The configuration used for building a [one-pass DFA](DFA).
 ///
 /// A one-pass DFA configuration is a simple data object that is typically used
@@ -277,6 +290,56 @@ impl Config {
             size_limit: o.size_limit.or(self.size_limit),
         }
     }
+
+    /// Serialize this configuration. Options that haven't been set are
+    /// recorded as such, so that the deserialized configuration is exactly
+    /// the same as this one.
+    fn write_to(&self, wtr: &mut Writer) {
+        fn opt_bool(wtr: &mut Writer, yes: Option<bool>) {
+            wtr.u8(yes.map_or(0, |yes| 1 + u8::from(yes)));
+        }
+
+        wtr.u8(match self.match_kind {
+            None => 0,
+            Some(MatchKind::All) => 1,
+            Some(MatchKind::LeftmostFirst) => 2,
+        });
+        opt_bool(wtr, self.starts_for_each_pattern);
+        opt_bool(wtr, self.byte_classes);
+        wtr.bool(self.size_limit.is_some());
+        wtr.option_usize(self.size_limit.flatten());
+    }
+
+    /// Deserialize a configuration written by `Config::write_to`.
+    fn read_from(rdr: &mut Reader<'_>) -> Result<Config, DeserializeError> {
+        fn opt_bool(
+            rdr: &mut Reader<'_>,
+        ) -> Result<Option<bool>, DeserializeError> {
+            match rdr.u8("config option")? {
+                0 => Ok(None),
+                1 => Ok(Some(false)),
+                2 => Ok(Some(true)),
+                _ => Err(DeserializeError::generic("invalid config option")),
+            }
+        }
+
+        let match_kind = match rdr.u8("match kind")? {
+            0 => None,
+            1 => Some(MatchKind::All),
+            2 => Some(MatchKind::LeftmostFirst),
+            _ => return Err(DeserializeError::generic("invalid match kind")),
+        };
+        let starts_for_each_pattern = opt_bool(rdr)?;
+        let byte_classes = opt_bool(rdr)?;
+        let has_size_limit = rdr.bool("config limit")?;
+        let size_limit = rdr.option_usize("config limit")?;
+        Ok(Config {
+            match_kind,
+            starts_for_each_pattern,
+            byte_classes,
+            size_limit: if has_size_limit { Some(size_limit) } else { None },
+        })
+    }
 }
 
 /// A builder for a [one-pass DFA](DFA).
@@ -590,6 +653,15 @@ impl<'a> InternalBuilder<'a> {
                 return Err(BuildError::unsupported_look(look));
             }
         }
+        if self.nfa.has_look_around() {
+            return Err(BuildError::unsupported_look_around());
+        }
+        if self.nfa.has_backreference() {
+            return Err(BuildError::unsupported_backreference());
+        }
+        if self.nfa.has_atomic() {
+            return Err(BuildError::unsupported_atomic());
+        }
         if self.nfa.pattern_len().as_u64() > PatternEpsilons::PATTERN_ID_LIMIT
         {
             return Err(BuildError::too_many_patterns(
@@ -655,6 +727,16 @@ impl<'a> InternalBuilder<'a> {
                         let looks = epsilons.looks().insert(look);
                         self.stack_push(next, epsilons.set_looks(looks))?;
                     }
+                    thompson::State::LookAround { .. } => {
+                        unreachable!("look-around states are rejected above")
+                    }
+                    thompson::State::Backreference { .. } => {
+                        unreachable!("backreference states are rejected above")
+                    }
+                    thompson::State::AtomicStart { .. }
+                    | thompson::State::AtomicEnd { .. } => {
+                        unreachable!("atomic states are rejected above")
+                    }
                     thompson::State::Union { ref alternates } => {
                         for &sid in alternates.iter().rev() {
                             self.stack_push(sid, epsilons)?;
@@ -1539,6 +1621,369 @@ impl DFA {
     }
 }
 
+/// Serialization and deserialization.
+impl DFA {
+    /// Serialize this one-pass DFA to a new byte vector in little endian
+    /// format.
+    ///
+    /// The bytes returned can be turned back into an equivalent DFA with
+    /// [`DFA::from_bytes`] on a little endian target. This makes it possible
+    /// to build a one-pass DFA ahead of time (say, in a build script) and
+    /// load it at runtime without parsing or compiling any patterns. In
+    /// particular, deserialization doesn't require the `syntax` feature, so
+    /// it works in `no_std` environments that have `alloc` available.
+    ///
+    /// The serialized form includes the DFA's configuration, its transition
+    /// table, its starting states and the underlying NFA. (The NFA is used
+    /// for reporting the capturing groups and evaluating look-around
+    /// assertions during a search.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # if cfg!(miri) { return Ok(()); } // miri takes too long
+    /// use regex_automata::{dfa::onepass::DFA, Match};
+    ///
+    /// let re = DFA::new(r"([a-z]+)([0-9]+)")?;
+    /// let bytes = re.to_bytes_little_endian();
+    ///
+    /// # if !cfg!(target_endian = "little") { return Ok(()); }
+    /// let (re, _) = DFA::from_bytes(&bytes)?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// re.captures(&mut cache, "abc123", &mut caps);
+    /// assert_eq!(Some(Match::must(0, 0..6)), caps.get_match());
+    /// assert_eq!(Some(3..6), caps.get_group(2).map(|s| s.range()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_little_endian(&self) -> Vec<u8> {
+        self.to_bytes::<wire::LE>()
+    }
+
+    /// Serialize this one-pass DFA to a new byte vector in big endian format.
+    ///
+    /// The bytes returned can be turned back into an equivalent DFA with
+    /// [`DFA::from_bytes`] on a big endian target.
+    ///
+    /// See [`DFA::to_bytes_little_endian`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # if cfg!(miri) { return Ok(()); } // miri takes too long
+    /// use regex_automata::{dfa::onepass::DFA, Match};
+    ///
+    /// let re = DFA::new(r"([a-z]+)([0-9]+)")?;
+    /// let bytes = re.to_bytes_big_endian();
+    ///
+    /// # if !cfg!(target_endian = "big") { return Ok(()); }
+    /// let (re, _) = DFA::from_bytes(&bytes)?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// re.captures(&mut cache, "abc123", &mut caps);
+    /// assert_eq!(Some(Match::must(0, 0..6)), caps.get_match());
+    /// assert_eq!(Some(3..6), caps.get_group(2).map(|s| s.range()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_big_endian(&self) -> Vec<u8> {
+        self.to_bytes::<wire::BE>()
+    }
+
+    /// Serialize this one-pass DFA to a new byte vector in the native
+    /// endianness of the current target.
+    ///
+    /// Generally speaking, native endian format should only be used when
+    /// you know that the target you're compiling the DFA for matches the
+    /// endianness of the target on which you're compiling DFA. For example,
+    /// if serialization and deserialization happen in the same process or on
+    /// the same machine. Otherwise, when serializing a DFA for use in a
+    /// portable environment, you'll almost certainly want to serialize _both_
+    /// a little endian and a big endian version and then load the correct one
+    /// based on the target's configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # if cfg!(miri) { return Ok(()); } // miri takes too long
+    /// use regex_automata::{
+    ///     dfa::onepass::DFA, Anchored, Input, Match, PatternID,
+    /// };
+    ///
+    /// let re = DFA::builder()
+    ///     .configure(DFA::config().starts_for_each_pattern(true))
+    ///     .build_many(&[r"[a-z]+", r"[0-9]+"])?;
+    /// let bytes = re.to_bytes_native_endian();
+    ///
+    /// let (re, _) = DFA::from_bytes(&bytes)?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// let input = Input::new("123abc")
+    ///     .anchored(Anchored::Pattern(PatternID::must(1)));
+    /// re.try_search(&mut cache, &input, &mut caps)?;
+    /// assert_eq!(Some(Match::must(1, 0..3)), caps.get_match());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_native_endian(&self) -> Vec<u8> {
+        self.to_bytes::<wire::NE>()
+    }
+
+    /// Deserialize a one-pass DFA from the given bytes, which must have been
+    /// produced by one of the `DFA::to_bytes_*` routines using the native
+    /// endianness of the current target.
+    ///
+    /// Upon success, the DFA is returned along with the number of bytes read
+    /// from `slice`.
+    ///
+    /// Unlike dense and sparse DFAs, a one-pass DFA always owns its data.
+    /// Deserializing one copies its transition table and NFA out of the slice
+    /// given. Consequently, the slice given has no alignment requirements and
+    /// deserialization takes time proportional to the size of the DFA.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the bytes given don't correspond to a
+    /// serialized one-pass DFA, if the DFA was serialized with an
+    /// incompatible format version or endianness, or if it fails validation.
+    /// Validation checks that every transition refers to a state in the DFA,
+    /// that every match state refers to one of its patterns, that the slots
+    /// and look-around assertions in every transition are consistent with
+    /// its NFA and that its starting states are valid.
+    ///
+    /// An error is also returned if the DFA requires Unicode word boundary
+    /// data that isn't available in the current build of this crate.
+    ///
+    /// # Safety
+    ///
+    /// This routine never results in undefined behavior, even when given
+    /// untrusted input. In particular, unlike a dense or sparse DFA, there is
+    /// no `from_bytes_unchecked` variant since a one-pass DFA doesn't use any
+    /// unsafe code during a search. A DFA that passes validation but is
+    /// otherwise nonsensical may however result in incorrect search results.
+    ///
+    /// # Example
+    ///
+    /// This shows how to round trip a one-pass DFA through its serialized
+    /// form and use it to report the spans of capturing groups.
+    ///
+    /// ```
+    /// # if cfg!(miri) { return Ok(()); } // miri takes too long
+    /// use regex_automata::dfa::onepass::DFA;
+    ///
+    /// let re = DFA::new(r"(?<year>[0-9]{4})-(?<month>[0-9]{2})")?;
+    /// let bytes = re.to_bytes_native_endian();
+    /// let (re, nread) = DFA::from_bytes(&bytes)?;
+    /// assert_eq!(bytes.len(), nread);
+    ///
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// re.captures(&mut cache, "2010-03-14", &mut caps);
+    /// assert_eq!(Some(0..4), caps.get_group_by_name("year").map(|s| s.range()));
+    /// assert_eq!(Some(5..7), caps.get_group_by_name("month").map(|s| s.range()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: invalid input
+    ///
+    /// Corrupted or truncated input results in an error.
+    ///
+    /// ```
+    /// # if cfg!(miri) { return Ok(()); } // miri takes too long
+    /// use regex_automata::dfa::onepass::DFA;
+    ///
+    /// let re = DFA::new(r"foo([0-9]+)")?;
+    /// let bytes = re.to_bytes_native_endian();
+    /// assert!(DFA::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    /// assert!(DFA::from_bytes(b"not a one-pass DFA").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes(slice: &[u8]) -> Result<(DFA, usize), DeserializeError> {
+        let mut rdr = Reader::new(slice);
+        rdr.header(LABEL, VERSION)?;
+        let config = Config::read_from(&mut rdr)?;
+        let nfa = NFA::read_from(&mut rdr)?;
+        let classes_bytes = rdr.bytes("byte class map")?;
+        let (classes, nr) = ByteClasses::from_bytes(classes_bytes)?;
+        if nr != classes_bytes.len() {
+            return Err(DeserializeError::generic(
+                "byte class map has invalid length",
+            ));
+        }
+        let len =
+            rdr.len(core::mem::size_of::<u64>(), "one-pass transition table")?;
+        let mut table = Vec::with_capacity(len);
+        for _ in 0..len {
+            table.push(Transition(rdr.u64("one-pass transition")?));
+        }
+        let len = rdr.len(StateID::SIZE, "one-pass start states")?;
+        let mut starts = Vec::with_capacity(len);
+        for _ in 0..len {
+            starts.push(rdr.state_id("one-pass start state")?);
+        }
+        let min_match_id = rdr.state_id("one-pass minimum match state")?;
+
+        // See 'InternalBuilder::new' for how these are computed.
+        let alphabet_len = classes.alphabet_len().checked_sub(1).unwrap();
+        let stride2 = classes.stride2();
+        let dfa = DFA {
+            config,
+            table,
+            starts,
+            min_match_id,
+            classes,
+            alphabet_len,
+            stride2,
+            pateps_offset: alphabet_len,
+            // OK because PatternID::MAX*2 is guaranteed not to overflow.
+            explicit_slot_start: nfa.pattern_len().checked_mul(2).unwrap(),
+            nfa,
+        };
+        dfa.validate()?;
+        Ok((dfa, rdr.nread()))
+    }
+
+    fn to_bytes<E: Endian>(&self) -> Vec<u8> {
+        let mut wtr = Writer::new::<E>();
+        wtr.header(LABEL, VERSION);
+        self.config.write_to(&mut wtr);
+        self.nfa.write_to(&mut wtr);
+        let mut classes = vec![0; self.classes.write_to_len()];
+        // OK because the buffer is exactly the size needed.
+        self.classes.write_to(&mut classes).unwrap();
+        wtr.bytes(&classes);
+        wtr.usize(self.table.len());
+        for trans in self.table.iter() {
+            wtr.u64(trans.0);
+        }
+        wtr.usize(self.starts.len());
+        for &sid in self.starts.iter() {
+            wtr.state_id(sid);
+        }
+        wtr.state_id(self.min_match_id);
+        wtr.into_bytes()
+    }
+
+    /// Validate a one-pass DFA that was just deserialized.
+    ///
+    /// This checks the same representational limits that are checked when
+    /// building a one-pass DFA, along with the invariants of the transition
+    /// table and start states that a search relies on.
+    fn validate(&self) -> Result<(), DeserializeError> {
+        let nfa = &self.nfa;
+        if nfa.has_look_around() {
+            return Err(DeserializeError::generic(
+                "one-pass DFA NFA contains look-around sub-expressions",
+            ));
+        }
+        if nfa.has_backreference() {
+            return Err(DeserializeError::generic(
+                "one-pass DFA NFA contains backreferences",
+            ));
+        }
+        for look in nfa.look_set_any().iter() {
+            if look.as_repr() > Look::WordUnicodeNegate.as_repr() {
+                return Err(DeserializeError::generic(
+                    "one-pass DFA NFA contains unsupported assertion",
+                ));
+            }
+        }
+        if nfa.look_set_any().available().is_err() {
+            return Err(DeserializeError::generic(
+                "one-pass DFA requires unavailable Unicode word boundary data",
+            ));
+        }
+        if nfa.pattern_len().as_u64() > PatternEpsilons::PATTERN_ID_LIMIT {
+            return Err(DeserializeError::generic(
+                "one-pass DFA has too many patterns",
+            ));
+        }
+        let explicit_slot_len = nfa.group_info().explicit_slot_len();
+        if explicit_slot_len > Slots::LIMIT {
+            return Err(DeserializeError::generic(
+                "one-pass DFA has too many explicit capturing groups",
+            ));
+        }
+
+        if self.table.is_empty() || self.table.len() % self.stride() != 0 {
+            return Err(DeserializeError::generic(
+                "one-pass transition table has invalid length",
+            ));
+        }
+        let state_len = self.state_len();
+        if self.min_match_id == DEAD
+            || (self.min_match_id != StateID::MAX
+                && self.min_match_id.as_usize() > state_len)
+        {
+            return Err(DeserializeError::generic(
+                "one-pass DFA has invalid minimum match state ID",
+            ));
+        }
+        let look_set_any = nfa.look_set_any();
+        let is_valid = |epsilons: Epsilons| {
+            let slots = u64::from(epsilons.slots().0);
+            epsilons.looks().subtract(look_set_any).is_empty()
+                && slots >> explicit_slot_len == 0
+        };
+        for i in 0..state_len {
+            let sid = StateID::new_unchecked(i);
+            let offset = i << self.stride2();
+            for &trans in self.table[offset..][..self.alphabet_len()].iter() {
+                if trans.state_id().as_usize() >= state_len {
+                    return Err(DeserializeError::generic(
+                        "one-pass transition has invalid state ID",
+                    ));
+                }
+                if !is_valid(trans.epsilons()) {
+                    return Err(DeserializeError::generic(
+                        "one-pass transition has invalid epsilons",
+                    ));
+                }
+            }
+            let pateps = self.pattern_epsilons(sid);
+            match pateps.pattern_id() {
+                None if sid >= self.min_match_id => {
+                    return Err(DeserializeError::generic(
+                        "one-pass match state has no pattern ID",
+                    ));
+                }
+                Some(_) if sid < self.min_match_id => {
+                    return Err(DeserializeError::generic(
+                        "one-pass non-match state has a pattern ID",
+                    ));
+                }
+                Some(pid) if pid.as_usize() >= nfa.pattern_len() => {
+                    return Err(DeserializeError::generic(
+                        "one-pass match state has invalid pattern ID",
+                    ));
+                }
+                _ => {}
+            }
+            if !is_valid(pateps.epsilons()) {
+                return Err(DeserializeError::generic(
+                    "one-pass match state has invalid epsilons",
+                ));
+            }
+        }
+
+        let starts_len = if self.config.get_starts_for_each_pattern() {
+            nfa.pattern_len() + 1
+        } else {
+            1
+        };
+        if self.starts.len() != starts_len {
+            return Err(DeserializeError::generic(
+                "one-pass DFA has wrong number of start states",
+            ));
+        }
+        if self.starts.iter().any(|sid| sid.as_usize() >= state_len) {
+            return Err(DeserializeError::generic(
+                "one-pass start state has invalid state ID",
+            ));
+        }
+        Ok(())
+    }
+}
+
 impl DFA {
     /// Executes an anchored leftmost forward search, and returns true if and
     /// only if this one-pass DFA matches the given haystack.
@@ -2130,7 +2575,14 @@ impl DFA {
         };
         let leftmost_first =
             matches!(self.config.get_match_kind(), MatchKind::LeftmostFirst);
+        let mut cancel_at = input.cancel_check_fwd(input.start());
         for at in input.start()..input.end() {
+            if at >= cancel_at {
+                if input.is_cancelled() {
+                    return Err(MatchError::cancelled(at));
+                }
+                cancel_at = at.saturating_add(CANCEL_CHECK_INTERVAL);
+            }
             let sid = next_sid;
             let trans = self.transition(sid, input.haystack()[at]);
             next_sid = trans.state_id();
@@ -2994,6 +3446,9 @@ enum BuildErrorKind {
     TooManyStates { limit: u64 },
     TooManyPatterns { limit: u64 },
     UnsupportedLook { look: Look },
+    UnsupportedLookAround,
+    UnsupportedBackreference,
+    UnsupportedAtomic,
     ExceededSizeLimit { limit: usize },
     NotOnePass { msg: &'static str },
 }
@@ -3019,6 +3474,18 @@ impl BuildError {
         BuildError { kind: BuildErrorKind::UnsupportedLook { look } }
     }
 
+    fn unsupported_look_around() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedLookAround }
+    }
+
+    fn unsupported_backreference() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedBackreference }
+    }
+
+    fn unsupported_atomic() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedAtomic }
+    }
+
     fn exceeded_size_limit(limit: usize) -> BuildError {
         BuildError { kind: BuildErrorKind::ExceededSizeLimit { limit } }
     }
@@ -3063,6 +3530,19 @@ impl core::fmt::Display for BuildError {
                 "one-pass DFA does not support the {:?} assertion",
                 look,
             ),
+            UnsupportedLookAround => write!(
+                f,
+                "one-pass DFA does not support look-around \
+                 with a sub-expression",
+            ),
+            UnsupportedBackreference => {
+                write!(f, "one-pass DFA does not support backreferences")
+            }
+            UnsupportedAtomic => write!(
+                f,
+                "one-pass DFA does not support atomic groups \
+                 or possessive repetitions",
+            ),
             ExceededSizeLimit { limit } => write!(
                 f,
                 "one-pass DFA exceeded size limit of {:?} during building",
@@ -3189,4 +3669,48 @@ mod tests {
     fn is_not_one_pass_bigger() {
         assert!(DFA::new(r"\w*\s").is_err());
     }
+
+    #[test]
+    fn serialize_roundtrip() {
+        let re = DFA::builder()
+            .configure(DFA::config().starts_for_each_pattern(true))
+            .build_many(&[r"(?<a>[a-z]+)(?<b>[0-9]+)(?-u:\b)", r"="])
+            .unwrap();
+        let bytes = re.to_bytes_native_endian();
+        let (got, nread) = DFA::from_bytes(&bytes).unwrap();
+        assert_eq!(bytes.len(), nread);
+        assert_eq!(re.table, got.table);
+        assert_eq!(re.starts, got.starts);
+        assert_eq!(re.min_match_id, got.min_match_id);
+        assert_eq!(re.stride2, got.stride2);
+        assert_eq!(
+            re.get_config().get_size_limit(),
+            got.get_config().get_size_limit()
+        );
+        assert_eq!(re.to_bytes_native_endian(), got.to_bytes_native_endian());
+    }
+
+    // Every truncated or corrupted serialization of a one-pass DFA must
+    // either fail to deserialize or deserialize without panicking. Searching
+    // with a corrupted DFA that passes validation must also never panic.
+    #[cfg(not(miri))]
+    #[test]
+    fn deserialize_invalid() {
+        let re = DFA::new(r"(?<name>[a-z]+) *= *([0-9]{2,})$").unwrap();
+        let bytes = re.to_bytes_native_endian();
+        for len in 0..bytes.len() {
+            assert!(DFA::from_bytes(&bytes[..len]).is_err());
+        }
+        for i in 0..bytes.len() {
+            let mut corrupt = bytes.clone();
+            corrupt[i] = corrupt[i].wrapping_add(1);
+            let re = match DFA::from_bytes(&corrupt) {
+                Ok((re, _)) => re,
+                Err(_) => continue,
+            };
+            let (mut cache, mut caps) =
+                (re.create_cache(), re.create_captures());
+            re.captures(&mut cache, "foo = 123", &mut caps);
+        }
+    }
 }
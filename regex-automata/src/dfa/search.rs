@@ -6,7 +6,7 @@ use crate::{
     util::{
         prefilter::Prefilter,
         primitives::StateID,
-        search::{Anchored, HalfMatch, Input, Span},
+        search::{Anchored, HalfMatch, Input, Span, CANCEL_CHECK_INTERVAL},
     },
     MatchError,
 };
@@ -53,6 +53,10 @@ fn find_fwd_imp<A: Automaton + ?Sized>(
     let mut mat = None;
     let mut sid = init_fwd(dfa, input)?;
     let mut at = input.start();
+    // The position at which we next check whether the search has been
+    // cancelled. The unrolled loop below never scans past it, so that the
+    // check happens at least once every CANCEL_CHECK_INTERVAL bytes.
+    let mut cancel_at = input.cancel_check_fwd(at);
     // This could just be a closure, but then I think it would be unsound
     // because it would need to be safe to invoke. This way, the lack of safety
     // is clearer in the code below.
@@ -81,6 +85,13 @@ fn find_fwd_imp<A: Automaton + ?Sized>(
         }
     }
     while at < input.end() {
+        if at >= cancel_at {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
+            cancel_at = at.saturating_add(CANCEL_CHECK_INTERVAL);
+        }
+        let end = core::cmp::min(input.end(), cancel_at);
         // SAFETY: There are two safety invariants we need to uphold here in
         // the loops below: that 'sid' and 'prev_sid' are valid state IDs
         // for this DFA, and that 'at' is a valid index into 'haystack'.
@@ -95,9 +106,9 @@ fn find_fwd_imp<A: Automaton + ?Sized>(
         // this extra work to make the search loop fast. The same reasoning and
         // benchmarks apply here.
         let mut prev_sid;
-        while at < input.end() {
+        while at < end {
             prev_sid = unsafe { next_unchecked!(sid, at) };
-            if dfa.is_special_state(prev_sid) || at + 3 >= input.end() {
+            if dfa.is_special_state(prev_sid) || at + 3 >= end {
                 core::mem::swap(&mut prev_sid, &mut sid);
                 break;
             }
@@ -122,6 +133,13 @@ fn find_fwd_imp<A: Automaton + ?Sized>(
             }
             at += 1;
         }
+        // If the unrolled loop ran off the end of its window without
+        // breaking, then every byte before 'at' has been consumed and 'sid'
+        // is not special. So there is nothing to handle below, and bumping
+        // 'at' would skip over the byte at the window boundary.
+        if at >= end {
+            continue;
+        }
         if dfa.is_special_state(sid) {
             if dfa.is_start_state(sid) {
                 if let Some(ref pre) = pre {
@@ -220,6 +238,9 @@ fn find_rev_imp<A: Automaton + ?Sized>(
     }
 
     let mut at = input.end() - 1;
+    // See 'find_fwd' for how cancellation checks work. This is the same, but
+    // in reverse.
+    let mut cancel_at = input.cancel_check_rev(at);
     macro_rules! next_unchecked {
         ($sid:expr, $at:expr) => {{
             let byte = *input.haystack().get_unchecked($at);
@@ -227,12 +248,18 @@ fn find_rev_imp<A: Automaton + ?Sized>(
         }};
     }
     loop {
+        if at <= cancel_at {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
+            cancel_at = at.saturating_sub(CANCEL_CHECK_INTERVAL);
+        }
+        let start = core::cmp::max(input.start(), cancel_at);
         // SAFETY: See comments in 'find_fwd' for a safety argument.
         let mut prev_sid;
-        while at >= input.start() {
+        while at >= start {
             prev_sid = unsafe { next_unchecked!(sid, at) };
-            if dfa.is_special_state(prev_sid)
-                || at <= input.start().saturating_add(3)
+            if dfa.is_special_state(prev_sid) || at <= start.saturating_add(3)
             {
                 core::mem::swap(&mut prev_sid, &mut sid);
                 break;
@@ -368,7 +395,14 @@ fn find_overlapping_fwd_imp<A: Automaton + ?Sized>(
     // it seems like most find_overlapping searches will have higher match
     // counts, and thus, throughput is perhaps not as important. But if you
     // have a use case for something faster, feel free to file an issue.
+    let mut cancel_at = input.cancel_check_fwd(state.at);
     while state.at < input.end() {
+        if state.at >= cancel_at {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(state.at));
+            }
+            cancel_at = state.at.saturating_add(CANCEL_CHECK_INTERVAL);
+        }
         sid = dfa.next_state(sid, input.haystack()[state.at]);
         if dfa.is_special_state(sid) {
             state.id = Some(sid);
@@ -490,7 +524,14 @@ pub(crate) fn find_overlapping_rev<A: Automaton + ?Sized>(
             sid
         }
     };
+    let mut cancel_at = input.cancel_check_rev(state.at);
     while !state.rev_eoi {
+        if state.at <= cancel_at {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(state.at));
+            }
+            cancel_at = state.at.saturating_sub(CANCEL_CHECK_INTERVAL);
+        }
         sid = dfa.next_state(sid, input.haystack()[state.at]);
         if dfa.is_special_state(sid) {
             state.id = Some(sid);
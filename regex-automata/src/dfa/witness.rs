@@ -0,0 +1,783 @@
+/*!
+Routines for generating example strings matched by a DFA or an NFA.
+
+This module provides a [`Generator`], which can enumerate the strings
+matched by a [`dense::DFA`] or a [`thompson::NFA`] in shortlex order (that
+is, shortest strings first, and strings of the same length in lexicographic
+order), or produce a stream of randomly chosen strings that match.
+
+A string is considered matched when an anchored search over the entire
+string reports a match that ends at the end of the string. This is the same
+notion of a DFA's language as the one used by routines like
+[`dense::DFA::is_subset`].
+
+# Example
+
+This example shows how to list the first few strings matched by a pattern.
+
+```
+use regex_automata::{dfa::witness::{Config, Generator}, nfa::thompson::NFA};
+
+let nfa = NFA::new(r"[ab]{1,2}c")?;
+let gen = Generator::from_nfa(Config::new(), &nfa)?;
+let got: Vec<Vec<u8>> = gen.iter().take(4).collect();
+assert_eq!(got, vec![
+    b"ac".to_vec(), b"bc".to_vec(), b"aac".to_vec(), b"abc".to_vec(),
+]);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    dfa::{
+        dense::{self, BuildError},
+        Automaton, StartKind,
+    },
+    nfa::thompson,
+    util::{
+        alphabet::ByteClassSet,
+        primitives::PatternID,
+        search::{Anchored, MatchKind},
+        start,
+    },
+};
+
+/// The number of states in the automaton used to recognize valid UTF-8.
+const UTF8_STATES: usize = 8;
+
+/// The default limit on the length of random samples, beyond the minimum
+/// length, when neither a maximum length is configured nor the language is
+/// finite.
+const DEFAULT_SAMPLE_LEN: usize = 32;
+
+/// The configuration used for a [`Generator`].
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    min_len: usize,
+    max_len: Option<usize>,
+    utf8: bool,
+    pattern: Option<PatternID>,
+}
+
+impl Config {
+    /// Return a new default generator configuration.
+    pub fn new() -> Config {
+        Config::default()
+    }
+
+    /// Only generate strings whose length, in bytes, is at least the given
+    /// length.
+    ///
+    /// By default, there is no lower bound.
+    pub fn min_len(mut self, len: usize) -> Config {
+        self.min_len = len;
+        self
+    }
+
+    /// Only generate strings whose length, in bytes, is at most the given
+    /// length.
+    ///
+    /// By default, there is no upper bound. In that case, enumerating the
+    /// strings of a language with infinitely many strings never ends, and
+    /// random samples are limited to strings of at most 32 bytes beyond the
+    /// configured minimum length.
+    pub fn max_len(mut self, len: Option<usize>) -> Config {
+        self.max_len = len;
+        self
+    }
+
+    /// When enabled, only strings that are valid UTF-8 are generated.
+    ///
+    /// This is disabled by default, since DFAs and NFAs may match arbitrary
+    /// bytes.
+    pub fn utf8(mut self, yes: bool) -> Config {
+        self.utf8 = yes;
+        self
+    }
+
+    /// Only generate strings matched by the given pattern.
+    ///
+    /// By default, strings matched by any pattern are generated.
+    pub fn pattern(mut self, pid: Option<PatternID>) -> Config {
+        self.pattern = pid;
+        self
+    }
+
+    /// Returns the minimum length of strings generated.
+    pub fn get_min_len(&self) -> usize {
+        self.min_len
+    }
+
+    /// Returns the maximum length of strings generated, if one is set.
+    pub fn get_max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Returns whether only valid UTF-8 strings are generated.
+    pub fn get_utf8(&self) -> bool {
+        self.utf8
+    }
+
+    /// Returns the pattern, if any, that generated strings must match.
+    pub fn get_pattern(&self) -> Option<PatternID> {
+        self.pattern
+    }
+}
+
+/// A generator of strings matched by a DFA.
+///
+/// A generator is built from either a dense DFA or an NFA. In the latter
+/// case, the NFA is first determinized. Once built, strings can be
+/// enumerated in shortlex order with [`Generator::iter`], or sampled at
+/// random with [`Generator::samples`].
+///
+/// A DFA given to a generator must support anchored searches, must not have
+/// any quit bytes and should be built with [`MatchKind::All`] semantics.
+/// Otherwise, some strings matched by the DFA may never be generated.
+///
+/// # Example
+///
+/// This shows how to find the shortest string matched by each pattern in
+/// a set.
+///
+/// ```
+/// use regex_automata::{
+///     dfa::witness::{Config, Generator},
+///     nfa::thompson::NFA,
+///     PatternID,
+/// };
+///
+/// let nfa = NFA::new_many(&[r"[0-9]{3}", r"[a-z]+@[a-z]+"])?;
+/// let mut shortest = vec![];
+/// for pid in nfa.patterns() {
+///     let config = Config::new().pattern(Some(pid));
+///     shortest.push(Generator::from_nfa(config, &nfa)?.shortest());
+/// }
+/// assert_eq!(shortest, vec![
+///     Some(b"000".to_vec()),
+///     Some(b"a@a".to_vec()),
+/// ]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Generator {
+    config: Config,
+    dfa: dense::OwnedDFA,
+    /// The number of UTF-8 automaton states tracked for each DFA state. This
+    /// is 1 when UTF-8 mode is disabled.
+    ustride: usize,
+    /// The index of the starting node. A node is a pair of a DFA state and
+    /// a UTF-8 automaton state.
+    start: usize,
+    /// Whether each node is an accepting node.
+    accept: Vec<bool>,
+    /// The distinct successors of every node reachable from the start node.
+    succs: Vec<Vec<usize>>,
+    /// Every node reachable from the start node.
+    reachable: Vec<usize>,
+    /// The length of the longest string generated, or `None` if there are
+    /// infinitely many strings. This ignores the configured length bounds.
+    longest: Option<usize>,
+    /// Whether any string is generated at all, ignoring length bounds.
+    nonempty: bool,
+}
+
+impl Generator {
+    /// Create a new generator for the strings matched by the given DFA.
+    ///
+    /// This returns an error if the DFA does not support anchored searches
+    /// or if it has any quit bytes.
+    pub fn from_dfa<T: AsRef<[u32]>>(
+        config: Config,
+        dfa: &dense::DFA<T>,
+    ) -> Result<Generator, BuildError> {
+        Generator::new(config, dfa.to_owned())
+    }
+
+    /// Create a new generator for the strings matched by the given NFA.
+    ///
+    /// This returns an error if the NFA could not be determinized, for
+    /// example, if it contains a Unicode word boundary.
+    pub fn from_nfa(
+        config: Config,
+        nfa: &thompson::NFA,
+    ) -> Result<Generator, BuildError> {
+        let dfa = dense::Builder::new()
+            .configure(
+                dense::Config::new()
+                    .match_kind(MatchKind::All)
+                    .start_kind(StartKind::Anchored)
+                    .minimize(true),
+            )
+            .build_from_nfa(nfa)?;
+        Generator::new(config, dfa)
+    }
+
+    fn new(
+        config: Config,
+        dfa: dense::OwnedDFA,
+    ) -> Result<Generator, BuildError> {
+        if !dfa.quitset().is_empty() {
+            return Err(BuildError::unsupported_language_quit());
+        }
+        let start_config = start::Config::new().anchored(Anchored::Yes);
+        let start_id = dfa
+            .start_state(&start_config)
+            .map_err(|_| BuildError::unsupported_language_unanchored())?;
+        let ustride = if config.utf8 { UTF8_STATES } else { 1 };
+        let len = dfa.state_len() * ustride;
+        let mut gen = Generator {
+            config,
+            dfa,
+            ustride,
+            start: 0,
+            accept: vec![false; len],
+            succs: vec![vec![]; len],
+            reachable: vec![],
+            longest: None,
+            nonempty: false,
+        };
+        gen.start = gen.dfa.to_index(start_id) * ustride;
+        gen.explore();
+        Ok(gen)
+    }
+
+    /// Returns the configuration used by this generator.
+    pub fn get_config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Returns the shortest string generated, if any. When there are
+    /// multiple shortest strings, the one that sorts first is returned.
+    pub fn shortest(&self) -> Option<Vec<u8>> {
+        self.iter().next()
+    }
+
+    /// Returns true if and only if this generator produces only finitely
+    /// many strings, ignoring the configured length bounds.
+    pub fn is_finite(&self) -> bool {
+        self.longest.is_some() || !self.nonempty
+    }
+
+    /// Returns an iterator over every generated string in shortlex order.
+    ///
+    /// If there are infinitely many strings and no maximum length is
+    /// configured, then this iterator never ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::witness::{Config, Generator},
+    ///     nfa::thompson::NFA,
+    /// };
+    ///
+    /// let nfa = NFA::new(r"(?:ab)*")?;
+    /// let config = Config::new().min_len(1).max_len(Some(6));
+    /// let gen = Generator::from_nfa(config, &nfa)?;
+    /// let got: Vec<Vec<u8>> = gen.iter().collect();
+    /// assert_eq!(got, vec![
+    ///     b"ab".to_vec(), b"abab".to_vec(), b"ababab".to_vec(),
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn iter(&self) -> Witnesses<'_> {
+        Witnesses {
+            gen: self,
+            levels: vec![],
+            len: self.config.min_len,
+            stack: vec![],
+            string: vec![],
+        }
+    }
+
+    /// Returns an infinite iterator of randomly chosen strings, using the
+    /// given seed. The same seed always produces the same strings.
+    ///
+    /// The length of each string is chosen uniformly at random from the
+    /// lengths for which a string exists. Each byte is then chosen uniformly
+    /// at random from those that can lead to a string of that length. This
+    /// means that the strings are not uniformly distributed.
+    ///
+    /// If no strings can be generated, then this iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     dfa::witness::{Config, Generator},
+    ///     nfa::thompson::NFA,
+    /// };
+    ///
+    /// let nfa = NFA::new(r"\p{Greek}{2,5}")?;
+    /// let config = Config::new().utf8(true);
+    /// let gen = Generator::from_nfa(config, &nfa)?;
+    /// for sample in gen.samples(42).take(10) {
+    ///     let s = std::str::from_utf8(&sample)?;
+    ///     assert!((2..=5).contains(&s.chars().count()));
+    /// }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn samples(&self, seed: u64) -> Samples<'_> {
+        let min = self.config.min_len;
+        let max = self
+            .max_len()
+            .unwrap_or_else(|| min.saturating_add(DEFAULT_SAMPLE_LEN));
+        let mut levels = vec![];
+        let mut lens = vec![];
+        if self.nonempty {
+            for len in min..=max {
+                if self.level(&mut levels, len)[self.start] {
+                    lens.push(len);
+                }
+            }
+        }
+        Samples {
+            gen: self,
+            levels,
+            lens,
+            rng: Rng::new(seed),
+            candidates: vec![],
+        }
+    }
+
+    /// Explore every node reachable from the start node and compute which
+    /// nodes accept, along with the length of the longest string accepted.
+    fn explore(&mut self) {
+        let mut set = ByteClassSet::empty();
+        set.add_classes(self.dfa.byte_classes());
+        if self.config.utf8 {
+            for &(start, end) in UTF8_RANGES {
+                set.set_range(start, end);
+            }
+        }
+        let classes = set.byte_classes();
+        let reps: Vec<u8> = classes
+            .representatives(0..=255)
+            .map(|unit| unit.as_u8().unwrap())
+            .collect();
+
+        let mut seen = vec![false; self.accept.len()];
+        seen[self.start] = true;
+        self.reachable.push(self.start);
+        let mut i = 0;
+        while i < self.reachable.len() {
+            let node = self.reachable[i];
+            i += 1;
+            self.accept[node] = self.is_accepting(node);
+            let mut succs = vec![];
+            for &byte in reps.iter() {
+                let next = match self.next(node, byte) {
+                    None => continue,
+                    Some(next) => next,
+                };
+                if !succs.contains(&next) {
+                    succs.push(next);
+                }
+                if !seen[next] {
+                    seen[next] = true;
+                    self.reachable.push(next);
+                }
+            }
+            self.succs[node] = succs;
+        }
+
+        // Find the nodes from which an accepting node is reachable.
+        let mut preds = vec![vec![]; self.accept.len()];
+        for &node in self.reachable.iter() {
+            for &next in self.succs[node].iter() {
+                preds[next].push(node);
+            }
+        }
+        let mut live = vec![false; self.accept.len()];
+        let mut stack: Vec<usize> = self
+            .reachable
+            .iter()
+            .copied()
+            .filter(|&node| self.accept[node])
+            .collect();
+        for &node in stack.iter() {
+            live[node] = true;
+        }
+        while let Some(node) = stack.pop() {
+            for &prev in preds[node].iter() {
+                if !live[prev] {
+                    live[prev] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+        self.nonempty = live[self.start];
+        if !self.nonempty {
+            self.longest = Some(0);
+            return;
+        }
+
+        // Peel off live nodes without live successors in topological order.
+        // If any live nodes remain, then they are on a cycle and there are
+        // infinitely many strings. Otherwise, the longest string falls out
+        // of the same traversal.
+        let mut outdeg = vec![0; self.accept.len()];
+        for &node in self.reachable.iter().filter(|&&n| live[n]) {
+            outdeg[node] =
+                self.succs[node].iter().filter(|&&n| live[n]).count();
+        }
+        let mut longest = vec![0; self.accept.len()];
+        let mut stack: Vec<usize> = self
+            .reachable
+            .iter()
+            .copied()
+            .filter(|&node| live[node] && outdeg[node] == 0)
+            .collect();
+        let mut peeled = 0;
+        while let Some(node) = stack.pop() {
+            peeled += 1;
+            for &prev in preds[node].iter() {
+                if !live[prev] {
+                    continue;
+                }
+                longest[prev] = longest[prev].max(longest[node] + 1);
+                outdeg[prev] -= 1;
+                if outdeg[prev] == 0 {
+                    stack.push(prev);
+                }
+            }
+        }
+        let live_len = self.reachable.iter().filter(|&&n| live[n]).count();
+        if peeled == live_len {
+            self.longest = Some(longest[self.start]);
+        }
+    }
+
+    /// Returns true if the given node accepts, i.e., if the string that
+    /// leads to it is generated.
+    fn is_accepting(&self, node: usize) -> bool {
+        if node % self.ustride != 0 {
+            return false;
+        }
+        let sid = self.dfa.to_state_id(node / self.ustride);
+        let eoi = self.dfa.next_eoi_state(sid);
+        if !self.dfa.is_match_state(eoi) {
+            return false;
+        }
+        match self.config.pattern {
+            None => true,
+            Some(pid) => (0..self.dfa.match_len(eoi))
+                .any(|i| self.dfa.match_pattern(eoi, i) == pid),
+        }
+    }
+
+    /// Returns the node reached from the given node by the given byte, or
+    /// `None` if the byte would make the string invalid UTF-8 in UTF-8 mode.
+    fn next(&self, node: usize, byte: u8) -> Option<usize> {
+        let sid = self.dfa.to_state_id(node / self.ustride);
+        let u = if self.config.utf8 {
+            utf8_next(node % self.ustride, byte)?
+        } else {
+            0
+        };
+        let next = self.dfa.next_state(sid, byte);
+        Some(self.dfa.to_index(next) * self.ustride + u)
+    }
+
+    /// Returns the set of nodes from which some string of exactly the given
+    /// length is generated, computing it (and all shorter lengths) if
+    /// necessary.
+    fn level<'a>(
+        &self,
+        levels: &'a mut Vec<Vec<bool>>,
+        len: usize,
+    ) -> &'a [bool] {
+        while levels.len() <= len {
+            let mut level = vec![false; self.accept.len()];
+            match levels.last() {
+                None => level.copy_from_slice(&self.accept),
+                Some(prev) => {
+                    for &node in self.reachable.iter() {
+                        level[node] =
+                            self.succs[node].iter().any(|&next| prev[next]);
+                    }
+                }
+            }
+            levels.push(level);
+        }
+        &levels[len]
+    }
+
+    /// Returns the length of the longest string that may be generated, or
+    /// `None` if there is no bound.
+    fn max_len(&self) -> Option<usize> {
+        match (self.config.max_len, self.longest) {
+            (Some(max), Some(longest)) => Some(max.min(longest)),
+            (max, longest) => max.or(longest),
+        }
+    }
+}
+
+/// An iterator over strings generated in shortlex order.
+///
+/// This is created by [`Generator::iter`].
+#[derive(Debug)]
+pub struct Witnesses<'g> {
+    gen: &'g Generator,
+    /// The sets of nodes from which a string of each length is generated.
+    levels: Vec<Vec<bool>>,
+    /// The length of the strings currently being enumerated.
+    len: usize,
+    /// The node for each byte in `string`, preceded by the start node, along
+    /// with the next byte to try from that node.
+    stack: Vec<(usize, usize)>,
+    /// The current prefix being extended.
+    string: Vec<u8>,
+}
+
+impl<'g> Iterator for Witnesses<'g> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        let gen = self.gen;
+        loop {
+            if self.stack.is_empty() {
+                if !gen.nonempty
+                    || gen.max_len().map_or(false, |m| self.len > m)
+                {
+                    return None;
+                }
+                if gen.level(&mut self.levels, self.len)[gen.start] {
+                    self.stack.push((gen.start, 0));
+                } else {
+                    self.len += 1;
+                    continue;
+                }
+            }
+            let depth = self.stack.len() - 1;
+            let remaining = self.len - depth;
+            if remaining == 0 {
+                let string = self.string.clone();
+                self.pop();
+                return Some(string);
+            }
+            let (node, ref mut byte) = *self.stack.last_mut().unwrap();
+            let level = &self.levels[remaining - 1];
+            let mut found = None;
+            while *byte <= 255 {
+                let b = *byte as u8;
+                *byte += 1;
+                if let Some(next) = gen.next(node, b) {
+                    if level[next] {
+                        found = Some((next, b));
+                        break;
+                    }
+                }
+            }
+            match found {
+                None => self.pop(),
+                Some((next, b)) => {
+                    self.stack.push((next, 0));
+                    self.string.push(b);
+                }
+            }
+        }
+    }
+}
+
+impl<'g> Witnesses<'g> {
+    /// Pop the current node, moving on to the next length once every string
+    /// of the current length has been enumerated.
+    fn pop(&mut self) {
+        self.stack.pop();
+        self.string.pop();
+        if self.stack.is_empty() {
+            self.len += 1;
+        }
+    }
+}
+
+/// An iterator over randomly chosen generated strings.
+///
+/// This is created by [`Generator::samples`].
+#[derive(Debug)]
+pub struct Samples<'g> {
+    gen: &'g Generator,
+    /// The sets of nodes from which a string of each length is generated.
+    levels: Vec<Vec<bool>>,
+    /// The lengths for which at least one string is generated.
+    lens: Vec<usize>,
+    rng: Rng,
+    /// Scratch space for the nodes that may follow the current one.
+    candidates: Vec<(usize, u8)>,
+}
+
+impl<'g> Iterator for Samples<'g> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        if self.lens.is_empty() {
+            return None;
+        }
+        let len = self.lens[self.rng.below(self.lens.len())];
+        let mut string = Vec::with_capacity(len);
+        let mut node = self.gen.start;
+        for remaining in (0..len).rev() {
+            let level = &self.levels[remaining];
+            self.candidates.clear();
+            for b in 0..=255 {
+                if let Some(next) = self.gen.next(node, b) {
+                    if level[next] {
+                        self.candidates.push((next, b));
+                    }
+                }
+            }
+            // There is always at least one candidate, since 'node' is in the
+            // level for 'remaining + 1'.
+            let (next, b) =
+                self.candidates[self.rng.below(self.candidates.len())];
+            string.push(b);
+            node = next;
+        }
+        Some(string)
+    }
+}
+
+/// A simple xorshift64* pseudo-random number generator.
+#[derive(Clone, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        // The state must never be zero.
+        Rng(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Returns a number in the range `0..n`. `n` must be non-zero.
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        let x = self.0.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        (x % (n as u64)) as usize
+    }
+}
+
+/// The byte ranges that the UTF-8 automaton distinguishes.
+const UTF8_RANGES: &[(u8, u8)] = &[
+    (0x00, 0x7F),
+    (0x80, 0x8F),
+    (0x90, 0x9F),
+    (0xA0, 0xBF),
+    (0xC0, 0xC1),
+    (0xC2, 0xDF),
+    (0xE0, 0xE0),
+    (0xE1, 0xEC),
+    (0xED, 0xED),
+    (0xEE, 0xEF),
+    (0xF0, 0xF0),
+    (0xF1, 0xF3),
+    (0xF4, 0xF4),
+    (0xF5, 0xFF),
+];
+
+/// A transition in an automaton that recognizes valid UTF-8.
+///
+/// State 0 is both the start state and the only accepting state. States 1
+/// and 2 expect one and two more continuation bytes, respectively. The
+/// remaining states expect a second byte in a restricted range, which
+/// rules out overlong encodings, surrogates and codepoints beyond
+/// `U+10FFFF`.
+fn utf8_next(state: usize, byte: u8) -> Option<usize> {
+    Some(match (state, byte) {
+        (0, 0x00..=0x7F) => 0,
+        (0, 0xC2..=0xDF) => 1,
+        (0, 0xE0) => 3,
+        (0, 0xE1..=0xEC) | (0, 0xEE..=0xEF) => 2,
+        (0, 0xED) => 4,
+        (0, 0xF0) => 6,
+        (0, 0xF1..=0xF3) => 5,
+        (0, 0xF4) => 7,
+        (1, 0x80..=0xBF) => 0,
+        (2, 0x80..=0xBF) => 1,
+        (3, 0xA0..=0xBF) => 1,
+        (4, 0x80..=0x9F) => 1,
+        (5, 0x80..=0xBF) => 2,
+        (6, 0x90..=0xBF) => 2,
+        (7, 0x80..=0x8F) => 2,
+        _ => return None,
+    })
+}
+
+#[cfg(all(test, feature = "syntax"))]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn gen(config: Config, pattern: &str) -> Generator {
+        let nfa = thompson::NFA::new(pattern).unwrap();
+        Generator::from_nfa(config, &nfa).unwrap()
+    }
+
+    fn gen_bytes(config: Config, pattern: &str) -> Generator {
+        let nfa = thompson::Compiler::new()
+            .syntax(crate::util::syntax::Config::new().utf8(false))
+            .configure(thompson::Config::new().utf8(false))
+            .build(pattern)
+            .unwrap();
+        Generator::from_nfa(config, &nfa).unwrap()
+    }
+
+    #[test]
+    fn shortlex_order() {
+        let got: Vec<Vec<u8>> =
+            gen(Config::new(), r"a|b[ab]|").iter().collect();
+        let expected: Vec<&[u8]> = vec![b"", b"a", b"ba", b"bb"];
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn finite() {
+        assert!(gen(Config::new(), r"a{2,5}").is_finite());
+        assert!(!gen(Config::new(), r"a{2,}").is_finite());
+        assert!(gen(Config::new(), r"[a&&b]").is_finite());
+        assert_eq!(None, gen(Config::new(), r"[a&&b]").shortest());
+        assert_eq!(0, gen(Config::new(), r"[a&&b]").samples(1).count());
+    }
+
+    #[test]
+    fn utf8_only() {
+        let config = Config::new().utf8(true).max_len(Some(4));
+        let g = gen_bytes(config, r"(?s-u:.)(?-u:\x80)");
+        for string in g.iter() {
+            assert!(core::str::from_utf8(&string).is_ok());
+        }
+        assert_eq!(Some(b"\xC2\x80".to_vec()), g.shortest());
+        let g = gen_bytes(Config::new().utf8(true), r"(?-u:\xFF)");
+        assert_eq!(None, g.shortest());
+    }
+
+    #[test]
+    fn look_around() {
+        let config = Config::new().max_len(Some(3));
+        let got: Vec<Vec<u8>> = gen(config, r"(?m)a$\n?a?").iter().collect();
+        let expected: Vec<&[u8]> = vec![b"a", b"a\n", b"a\na"];
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn samples_respect_bounds() {
+        let config = Config::new().min_len(3).max_len(Some(6));
+        let g = gen(config, r"[a-c]+x");
+        for sample in g.samples(7).take(100) {
+            assert!((3..=6).contains(&sample.len()), "{:?}", sample);
+            assert_eq!(Some(&b'x'), sample.last());
+        }
+        let first: Vec<Vec<u8>> = g.samples(7).take(10).collect();
+        let second: Vec<Vec<u8>> = g.samples(7).take(10).collect();
+        assert_eq!(first, second);
+    }
+}
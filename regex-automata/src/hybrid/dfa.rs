@@ -4056,6 +4056,18 @@ impl Builder {
         &self,
         nfa: thompson::NFA,
     ) -> Result<DFA, BuildError> {
+        if nfa.look_set_any().contains_segment() {
+            return Err(BuildError::unsupported_dfa_segment());
+        }
+        if nfa.has_look_around() {
+            return Err(BuildError::unsupported_dfa_look_around());
+        }
+        if nfa.has_backreference() {
+            return Err(BuildError::unsupported_dfa_backreference());
+        }
+        if nfa.has_atomic() {
+            return Err(BuildError::unsupported_dfa_atomic());
+        }
         let quitset = self.config.quit_set_from_nfa(&nfa)?;
         let classes = self.config.byte_classes_from_nfa(&nfa, &quitset);
         // Check that we can fit at least a few states into our cache,
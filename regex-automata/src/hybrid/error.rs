@@ -61,6 +61,33 @@ impl BuildError {
                    different regex engine";
         BuildError { kind: BuildErrorKind::Unsupported(msg) }
     }
+
+    pub(crate) fn unsupported_dfa_segment() -> BuildError {
+        let msg = "cannot build lazy DFAs for regexes with Unicode \
+                   segmentation boundaries (like '\\b{wb}' or '\\b{sb}'); \
+                   use a different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_dfa_look_around() -> BuildError {
+        let msg = "cannot build lazy DFAs for regexes with look-around \
+                   sub-expressions (like '(?=re)' or '(?<=re)'); use a \
+                   different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_dfa_backreference() -> BuildError {
+        let msg = "cannot build lazy DFAs for regexes with backreferences \
+                   (like '\\1'); use a different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
+
+    pub(crate) fn unsupported_dfa_atomic() -> BuildError {
+        let msg = "cannot build lazy DFAs for regexes with atomic groups or \
+                   possessive repetitions (like '(?>a*)' or 'a*+'); \
+                   use a different regex engine";
+        BuildError { kind: BuildErrorKind::Unsupported(msg) }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -5,7 +5,7 @@ use crate::{
     },
     util::{
         prefilter::Prefilter,
-        search::{HalfMatch, Input, MatchError, Span},
+        search::{HalfMatch, Input, MatchError, Span, CANCEL_CHECK_INTERVAL},
     },
 };
 
@@ -59,6 +59,10 @@ fn find_fwd_imp(
     let mut mat = None;
     let mut sid = init_fwd(dfa, cache, input)?;
     let mut at = input.start();
+    // The position at which we next check whether the search has been
+    // cancelled. The unrolled loop below never scans past it, so that the
+    // check happens at least once every CANCEL_CHECK_INTERVAL bytes.
+    let mut cancel_at = input.cancel_check_fwd(at);
     // This could just be a closure, but then I think it would be unsound
     // because it would need to be safe to invoke. This way, the lack of safety
     // is clearer in the code below.
@@ -83,6 +87,13 @@ fn find_fwd_imp(
     }
     cache.search_start(at);
     while at < input.end() {
+        if at >= cancel_at {
+            if input.is_cancelled() {
+                cache.search_finish(at);
+                return Err(MatchError::cancelled(at));
+            }
+            cancel_at = at.saturating_add(CANCEL_CHECK_INTERVAL);
+        }
         if sid.is_tagged() {
             cache.search_update(at);
             sid = dfa
@@ -191,10 +202,11 @@ fn find_fwd_imp(
             // time when compared to the originally lazy DFA in the regex crate.
             // So I've removed the second loop unrolling that targets the
             // self-transition case.
+            let end = core::cmp::min(input.end(), cancel_at);
             let mut prev_sid = sid;
-            while at < input.end() {
+            while at < end {
                 prev_sid = unsafe { next_unchecked!(sid, at) };
-                if prev_sid.is_tagged() || at + 3 >= input.end() {
+                if prev_sid.is_tagged() || at + 3 >= end {
                     core::mem::swap(&mut prev_sid, &mut sid);
                     break;
                 }
@@ -219,6 +231,13 @@ fn find_fwd_imp(
                 }
                 at += 1;
             }
+            // If the unrolled loop ran off the end of its window without
+            // breaking, then every byte before 'at' has been consumed and
+            // 'sid' is untagged. So there is nothing to handle below, and
+            // bumping 'at' would skip over the byte at the window boundary.
+            if at >= end {
+                continue;
+            }
             // If we quit out of the code above with an unknown state ID at
             // any point, then we need to re-compute that transition using
             // 'next_state', which will do NFA powerset construction for us.
@@ -329,6 +348,9 @@ fn find_rev_imp(
     }
 
     let mut at = input.end() - 1;
+    // See 'find_fwd' for how cancellation checks work. This is the same, but
+    // in reverse.
+    let mut cancel_at = input.cancel_check_rev(at);
     macro_rules! next_unchecked {
         ($sid:expr, $at:expr) => {{
             let byte = *input.haystack().get_unchecked($at);
@@ -337,6 +359,13 @@ fn find_rev_imp(
     }
     cache.search_start(at);
     loop {
+        if at <= cancel_at {
+            if input.is_cancelled() {
+                cache.search_finish(at);
+                return Err(MatchError::cancelled(at));
+            }
+            cancel_at = at.saturating_sub(CANCEL_CHECK_INTERVAL);
+        }
         if sid.is_tagged() {
             cache.search_update(at);
             sid = dfa
@@ -365,12 +394,11 @@ fn find_rev_imp(
             // the unrolling but add in bounds checks, then we get 2.86s.
             //
             // NOTE: I used 'OpenSubtitles2018.raw.sample.en' for 'bigfile'.
+            let start = core::cmp::max(input.start(), cancel_at);
             let mut prev_sid = sid;
-            while at >= input.start() {
+            while at >= start {
                 prev_sid = unsafe { next_unchecked!(sid, at) };
-                if prev_sid.is_tagged()
-                    || at <= input.start().saturating_add(3)
-                {
+                if prev_sid.is_tagged() || at <= start.saturating_add(3) {
                     core::mem::swap(&mut prev_sid, &mut sid);
                     break;
                 }
@@ -502,7 +530,15 @@ fn find_overlapping_fwd_imp(
     // and thus, throughput is perhaps not as important. But if you have a use
     // case for something faster, feel free to file an issue.
     cache.search_start(state.at);
+    let mut cancel_at = input.cancel_check_fwd(state.at);
     while state.at < input.end() {
+        if state.at >= cancel_at {
+            if input.is_cancelled() {
+                cache.search_finish(state.at);
+                return Err(MatchError::cancelled(state.at));
+            }
+            cancel_at = state.at.saturating_add(CANCEL_CHECK_INTERVAL);
+        }
         sid = dfa
             .next_state(cache, sid, input.haystack()[state.at])
             .map_err(|_| gave_up(state.at))?;
@@ -614,7 +650,15 @@ pub(crate) fn find_overlapping_rev(
         }
     };
     cache.search_start(state.at);
+    let mut cancel_at = input.cancel_check_rev(state.at);
     while !state.rev_eoi {
+        if state.at <= cancel_at {
+            if input.is_cancelled() {
+                cache.search_finish(state.at);
+                return Err(MatchError::cancelled(state.at));
+            }
+            cancel_at = state.at.saturating_sub(CANCEL_CHECK_INTERVAL);
+        }
         sid = dfa
             .next_state(cache, sid, input.haystack()[state.at])
             .map_err(|_| gave_up(state.at))?;
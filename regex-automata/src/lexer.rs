@@ -0,0 +1,679 @@
+/*!
+Provides a lexer that splits a haystack into tokens using an ordered list of
+rules.
+
+This module is home to a [`Lexer`], which is built from a sequence of named
+rules via a [`Builder`]. Each rule is a regex pattern. A lexer compiles all
+of its rules into a single multi-pattern [`meta::Regex`](crate::meta::Regex)
+and then repeatedly runs anchored searches at the current position of the
+haystack to determine which rule produces the next token.
+
+# Match semantics
+
+At each position, the rule that produces the longest non-empty match wins.
+When more than one rule produces a match of that same longest length, the
+rule that was added to the builder first wins. This is sometimes called
+"maximal munch" and corresponds to how most hand written tokenizers behave.
+For example, with the rules `if` and `[a-z]+` (in that order), the haystack
+`if` produces a single token for the `if` rule since it was added first,
+while the haystack `iffy` produces a single token for the `[a-z]+` rule since
+its match is longer. Adding the rules in the opposite order would make `if`
+lex as an identifier too.
+
+Empty matches never produce tokens. A rule that can only match the empty
+string at a particular position is treated as if it didn't match at all.
+
+# Skip rules and errors
+
+Rules added via [`Builder::skip`] are matched exactly like other rules, but
+the tokens they produce are not reported. This is typically used for
+whitespace and comments.
+
+When no rule matches at the current position, the lexer reports a
+[`LexError`] and recovers by skipping ahead, one codepoint at a time, to the
+next position at which some rule (including a skip rule) matches. The error
+covers all of the input that was skipped. Lexing then continues normally from
+that position.
+
+# Line and column numbers
+
+Every token and error reports the line and column at which it starts. Both are
+1-based. Lines are terminated by `\n`. Columns count codepoints, so a
+multi-byte UTF-8 encoded codepoint only advances the column by one. Line and
+column numbers are always computed relative to the beginning of the haystack,
+even when the search span given via [`Input`] starts later.
+
+# Example
+
+```
+use regex_automata::{lexer::Lexer, Span};
+
+let lexer = Lexer::builder()
+    .rule("keyword", r"let|in")
+    .rule("ident", r"[a-z]+")
+    .rule("number", r"[0-9]+")
+    .rule("equals", r"=")
+    .skip("space", r"\s+")
+    .build()?;
+
+let hay = "let x = 5\nin letter";
+let toks: Vec<(&str, &str, usize, usize)> = lexer
+    .tokens(hay)
+    .map(|result| {
+        let tok = result.unwrap();
+        let name = lexer.rule_name(tok.rule());
+        (name, &hay[tok.range()], tok.line(), tok.column())
+    })
+    .collect();
+assert_eq!(toks, vec![
+    ("keyword", "let", 1, 1),
+    ("ident", "x", 1, 5),
+    ("equals", "=", 1, 7),
+    ("number", "5", 1, 9),
+    ("keyword", "in", 2, 1),
+    ("ident", "letter", 2, 4),
+]);
+
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+*/
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    meta::{self, BuildError, Regex},
+    util::{
+        primitives::PatternID,
+        search::{Anchored, Input, Match, MatchKind, PatternSet, Span},
+        syntax, utf8,
+    },
+};
+
+/// A lexer built from an ordered list of rules.
+///
+/// A lexer is created via a [`Builder`], which can be obtained with
+/// [`Lexer::builder`]. Each rule is identified by the [`PatternID`] of its
+/// pattern, which corresponds to the order in which the rules were added to
+/// the builder, starting at `0`. This includes skip rules.
+///
+/// Like a [`meta::Regex`], a lexer is safe to use from multiple threads
+/// simultaneously and is cheap to clone. Any mutable scratch space required
+/// for searching is managed internally.
+///
+/// See the [module documentation](self) for more details about the match
+/// semantics of a lexer.
+///
+/// # Example
+///
+/// This example shows how errors are reported and how lexing recovers from
+/// them.
+///
+/// ```
+/// use regex_automata::{lexer::Lexer, Span};
+///
+/// let lexer = Lexer::builder()
+///     .rule("number", r"[0-9]+")
+///     .skip("space", r"\s+")
+///     .build()?;
+///
+/// let mut it = lexer.tokens("12 ?! 34");
+/// let tok = it.next().unwrap()?;
+/// assert_eq!(Span::from(0..2), tok.span());
+///
+/// let err = it.next().unwrap().unwrap_err();
+/// assert_eq!(Span::from(3..5), err.span());
+/// assert_eq!((1, 4), (err.line(), err.column()));
+///
+/// let tok = it.next().unwrap()?;
+/// assert_eq!(Span::from(6..8), tok.span());
+/// assert!(it.next().is_none());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Lexer {
+    re: Regex,
+    rules: Vec<Rule>,
+}
+
+/// The name and kind of a single rule in a lexer.
+#[derive(Clone, Debug)]
+struct Rule {
+    name: String,
+    skip: bool,
+}
+
+impl Lexer {
+    /// Return a builder for configuring the construction of a `Lexer`.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// Returns an iterator over all tokens in the given haystack.
+    ///
+    /// Each item yielded is either a [`Token`] or, when no rule matches at
+    /// the current position, a [`LexError`]. Tokens produced by skip rules
+    /// are never yielded. Lexing continues after an error, so callers that
+    /// want to stop at the first error should do so explicitly.
+    ///
+    /// The search span of the given `Input` determines which part of the
+    /// haystack is lexed. Its anchor mode and "earliest" setting are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// This shows how to lex only a portion of a haystack. Notice that line
+    /// and column numbers are still relative to the start of the haystack.
+    ///
+    /// ```
+    /// use regex_automata::{lexer::Lexer, Input};
+    ///
+    /// let lexer = Lexer::builder().rule("word", r"\w+").build()?;
+    /// let hay = "skip\nthis";
+    /// let mut it = lexer.tokens(Input::new(hay).range(5..));
+    /// let tok = it.next().unwrap()?;
+    /// assert_eq!("this", &hay[tok.range()]);
+    /// assert_eq!((2, 1), (tok.line(), tok.column()));
+    /// assert!(it.next().is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tokens<'l, 'h, I: Into<Input<'h>>>(
+        &'l self,
+        input: I,
+    ) -> Tokens<'l, 'h> {
+        let input = input.into();
+        let at = input.start();
+        let mut pos = Position { line: 1, column: 1 };
+        pos.advance(&input.haystack()[..at]);
+        let patset = PatternSet::new(self.re.pattern_len());
+        Tokens { lexer: self, input, at, pos, patset }
+    }
+
+    /// Returns the name of the rule corresponding to the given pattern ID.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given pattern ID does not correspond to a rule in
+    /// this lexer.
+    pub fn rule_name(&self, rule: PatternID) -> &str {
+        &self.rules[rule].name
+    }
+
+    /// Returns true if and only if the given pattern ID corresponds to a
+    /// skip rule.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given pattern ID does not correspond to a rule in
+    /// this lexer.
+    pub fn is_skip(&self, rule: PatternID) -> bool {
+        self.rules[rule].skip
+    }
+
+    /// Returns the total number of rules in this lexer, including skip
+    /// rules.
+    pub fn rule_len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns the underlying multi-pattern regex used by this lexer.
+    ///
+    /// The regex has one pattern for each rule and uses
+    /// [`MatchKind::All`] semantics.
+    pub fn regex(&self) -> &Regex {
+        &self.re
+    }
+
+    /// Finds the rule that produces the longest non-empty match starting
+    /// at the beginning of the given input's span. Ties are broken in favor
+    /// of the rule with the smallest pattern ID.
+    fn longest(
+        &self,
+        input: &mut Input<'_>,
+        patset: &mut PatternSet,
+    ) -> Option<Match> {
+        input.set_anchored(Anchored::Yes);
+        let m = self.re.search(input)?;
+        if m.is_empty() {
+            return None;
+        }
+        // With 'MatchKind::All', the match above is the longest one, but its
+        // pattern ID isn't necessarily the one that was added first. So we
+        // find every pattern that matches somewhere in the span of the
+        // longest match and pick the first one that can match all of it.
+        input.set_end(m.end());
+        patset.clear();
+        self.re.which_overlapping_matches(input, patset);
+        for pid in patset.iter() {
+            input.set_anchored(Anchored::Pattern(pid));
+            if self.re.search(input).map_or(false, |c| c.end() == m.end()) {
+                return Some(Match::new(pid, m.span()));
+            }
+        }
+        Some(m)
+    }
+}
+
+/// A builder for configuring and constructing a [`Lexer`].
+///
+/// Rules are added with [`Builder::rule`] and [`Builder::skip`]. The order in
+/// which they are added matters: it determines the pattern ID of each rule
+/// and it breaks ties between rules that match the same amount of input.
+///
+/// Rule names are only used for reporting and need not be unique. For
+/// example, two rules for different string literal syntaxes might both be
+/// named `string`.
+///
+/// The underlying regex may be configured via [`Builder::configure`] and
+/// [`Builder::syntax`]. The match kind of the regex is always set to
+/// [`MatchKind::All`], regardless of the configuration given.
+///
+/// # Example
+///
+/// This example shows how to enable case insensitive matching for all rules.
+///
+/// ```
+/// use regex_automata::{lexer::Lexer, util::syntax};
+///
+/// let lexer = Lexer::builder()
+///     .syntax(syntax::Config::new().case_insensitive(true))
+///     .rule("select", r"select")
+///     .rule("ident", r"[a-z]+")
+///     .build()?;
+/// let tok = lexer.tokens("SELECT").next().unwrap()?;
+/// assert_eq!("select", lexer.rule_name(tok.rule()));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Builder {
+    config: meta::Config,
+    syntax: syntax::Config,
+    patterns: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+impl Builder {
+    /// Creates a new lexer builder with no rules.
+    pub fn new() -> Builder {
+        Builder {
+            config: meta::Config::new(),
+            syntax: syntax::Config::new(),
+            patterns: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Builds a lexer from the rules added to this builder.
+    ///
+    /// If there was a problem parsing or compiling any of the rules, then an
+    /// error is returned. The error reports the pattern ID of the offending
+    /// rule.
+    ///
+    /// A lexer with no rules may be built. It reports an error for any
+    /// non-empty haystack.
+    pub fn build(&self) -> Result<Lexer, BuildError> {
+        let config = self.config.clone().match_kind(MatchKind::All);
+        let re = meta::Builder::new()
+            .configure(config)
+            .syntax(self.syntax)
+            .build_many(&self.patterns)?;
+        Ok(Lexer { re, rules: self.rules.clone() })
+    }
+
+    /// Adds a rule whose matches are reported as tokens.
+    pub fn rule(&mut self, name: &str, pattern: &str) -> &mut Builder {
+        self.add(name, pattern, false)
+    }
+
+    /// Adds a rule whose matches are consumed but not reported as tokens.
+    ///
+    /// This is typically used for whitespace and comments. Matches of skip
+    /// rules take part in choosing the longest match just like any other
+    /// rule.
+    pub fn skip(&mut self, name: &str, pattern: &str) -> &mut Builder {
+        self.add(name, pattern, true)
+    }
+
+    /// Configure the underlying regex used by the lexer.
+    ///
+    /// The match kind set on the given configuration is ignored.
+    pub fn configure(&mut self, config: meta::Config) -> &mut Builder {
+        self.config = self.config.overwrite(config);
+        self
+    }
+
+    /// Configure the syntax options used when parsing each rule's pattern.
+    pub fn syntax(&mut self, config: syntax::Config) -> &mut Builder {
+        self.syntax = config;
+        self
+    }
+
+    fn add(&mut self, name: &str, pattern: &str, skip: bool) -> &mut Builder {
+        self.patterns.push(String::from(pattern));
+        self.rules.push(Rule { name: String::from(name), skip });
+        self
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Builder {
+        Builder::new()
+    }
+}
+
+/// An iterator over the tokens in a haystack.
+///
+/// This iterator yields `Result<Token, LexError>` values. It is created by
+/// [`Lexer::tokens`].
+///
+/// The lifetime parameters are as follows:
+///
+/// * `'l` represents the lifetime of the lexer.
+/// * `'h` represents the lifetime of the haystack being lexed.
+#[derive(Debug)]
+pub struct Tokens<'l, 'h> {
+    lexer: &'l Lexer,
+    input: Input<'h>,
+    at: usize,
+    pos: Position,
+    patset: PatternSet,
+}
+
+impl<'l, 'h> Tokens<'l, 'h> {
+    /// Returns the current `Input` associated with this iterator.
+    ///
+    /// The start position of the returned `Input` is the offset at which
+    /// the next token (or error) begins.
+    pub fn input(&self) -> Input<'h> {
+        let mut input = self.input.clone();
+        input.set_start(self.at);
+        input
+    }
+
+    /// Runs a search for the longest rule match at the given offset.
+    fn longest_at(&mut self, at: usize) -> Option<Match> {
+        let mut input = self.input.clone();
+        input.set_start(at);
+        self.lexer.longest(&mut input, &mut self.patset)
+    }
+
+    /// Moves the current offset to `end`, updating the line and column.
+    fn advance(&mut self, end: usize) {
+        self.pos.advance(&self.input.haystack()[self.at..end]);
+        self.at = end;
+    }
+}
+
+impl<'l, 'h> Iterator for Tokens<'l, 'h> {
+    type Item = Result<Token, LexError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<Token, LexError>> {
+        loop {
+            if self.at >= self.input.end() {
+                return None;
+            }
+            let pos = self.pos;
+            if let Some(m) = self.longest_at(self.at) {
+                self.advance(m.end());
+                if self.lexer.is_skip(m.pattern()) {
+                    continue;
+                }
+                let (line, column) = (pos.line, pos.column);
+                return Some(Ok(Token { m, line, column }));
+            }
+            let start = self.at;
+            let mut end = start;
+            loop {
+                end = match utf8::decode(&self.input.haystack()[end..]) {
+                    Some(Ok(ch)) => end + ch.len_utf8(),
+                    Some(Err(_)) | None => end + 1,
+                };
+                if end >= self.input.end() || self.longest_at(end).is_some() {
+                    break;
+                }
+            }
+            let end = core::cmp::min(end, self.input.end());
+            self.advance(end);
+            let span = Span { start, end };
+            let (line, column) = (pos.line, pos.column);
+            return Some(Err(LexError { span, line, column }));
+        }
+    }
+}
+
+impl<'l, 'h> core::iter::FusedIterator for Tokens<'l, 'h> {}
+
+/// A single token produced by a [`Lexer`].
+///
+/// A token records the rule that produced it, the span of the haystack it
+/// covers and the line and column at which it starts.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Token {
+    m: Match,
+    line: usize,
+    column: usize,
+}
+
+impl Token {
+    /// Returns the pattern ID of the rule that produced this token.
+    ///
+    /// The name of the rule can be retrieved with [`Lexer::rule_name`].
+    #[inline]
+    pub fn rule(&self) -> PatternID {
+        self.m.pattern()
+    }
+
+    /// Returns the span of the haystack covered by this token.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.m.span()
+    }
+
+    /// Returns the starting offset of this token.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.m.start()
+    }
+
+    /// Returns the ending offset of this token, exclusive.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.m.end()
+    }
+
+    /// Returns the span of this token as a range.
+    #[inline]
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.m.range()
+    }
+
+    /// Returns the 1-based line number at which this token starts.
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the 1-based column number, in codepoints, at which this token
+    /// starts.
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+/// An error that occurs when no rule matches at some position in a haystack.
+///
+/// The span of the error covers all of the input that was skipped before
+/// some rule matched again (or the end of the search was reached).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LexError {
+    span: Span,
+    line: usize,
+    column: usize,
+}
+
+impl LexError {
+    /// Returns the span of the haystack that no rule matched.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the span of the haystack that no rule matched as a range.
+    #[inline]
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.span.range()
+    }
+
+    /// Returns the 1-based line number at which the unmatched input starts.
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the 1-based column number, in codepoints, at which the
+    /// unmatched input starts.
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LexError {}
+
+impl core::fmt::Display for LexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "no lexer rule matches at line {}, column {}",
+            self.line, self.column,
+        )
+    }
+}
+
+/// A line and column position in a haystack.
+#[derive(Clone, Copy, Debug)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    /// Advance this position past all of the given bytes.
+    ///
+    /// Columns are only incremented for bytes that don't look like UTF-8
+    /// continuation bytes, such that every codepoint counts once.
+    fn advance(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            if b == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            } else if b & 0b1100_0000 != 0b1000_0000 {
+                self.column += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn lex(lexer: &Lexer, hay: &str) -> Vec<(String, usize, usize)> {
+        lexer
+            .tokens(hay)
+            .map(|r| match r {
+                Ok(t) => {
+                    let name = lexer.rule_name(t.rule());
+                    (
+                        alloc::format!("{}:{}", name, &hay[t.range()]),
+                        t.line(),
+                        t.column(),
+                    )
+                }
+                Err(e) => (
+                    alloc::format!("error:{}", &hay[e.range()]),
+                    e.line(),
+                    e.column(),
+                ),
+            })
+            .collect()
+    }
+
+    fn tok(s: &str, line: usize, column: usize) -> (String, usize, usize) {
+        (String::from(s), line, column)
+    }
+
+    #[test]
+    fn longest_then_first() {
+        let lexer = Lexer::builder()
+            .rule("if", r"if")
+            .rule("ident", r"[a-z]+")
+            .rule("int", r"[0-9]+")
+            .rule("float", r"[0-9]+\.[0-9]+")
+            .skip("ws", r"\s+")
+            .build()
+            .unwrap();
+        assert_eq!(
+            lex(&lexer, "if iffy 1.5 12"),
+            vec![
+                tok("if:if", 1, 1),
+                tok("ident:iffy", 1, 4),
+                tok("float:1.5", 1, 9),
+                tok("int:12", 1, 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_recover() {
+        let lexer = Lexer::builder()
+            .rule("word", r"[a-z]+")
+            .skip("ws", r" +")
+            .build()
+            .unwrap();
+        assert_eq!(
+            lex(&lexer, "ab ☃☃ cd\n ♥x"),
+            vec![
+                tok("word:ab", 1, 1),
+                tok("error:☃☃", 1, 4),
+                tok("word:cd", 1, 7),
+                tok("error:\n", 1, 9),
+                tok("error:♥", 2, 2),
+                tok("word:x", 2, 3),
+            ]
+        );
+        assert_eq!(lex(&lexer, "?!"), vec![tok("error:?!", 1, 1)]);
+    }
+
+    #[test]
+    fn empty_matches_ignored() {
+        let lexer = Lexer::builder()
+            .rule("maybe", r"a*")
+            .rule("b", r"b")
+            .build()
+            .unwrap();
+        assert_eq!(
+            lex(&lexer, "aabxb"),
+            vec![
+                tok("maybe:aa", 1, 1),
+                tok("b:b", 1, 3),
+                tok("error:x", 1, 4),
+                tok("b:b", 1, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn no_rules() {
+        let lexer = Lexer::builder().build().unwrap();
+        assert_eq!(lex(&lexer, ""), vec![]);
+        assert_eq!(lex(&lexer, "ab"), vec![tok("error:ab", 1, 1)]);
+    }
+}
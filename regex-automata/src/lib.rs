@@ -472,6 +472,10 @@ increased binary size.
   [Unicode `Age` property](https://www.unicode.org/reports/tr44/tr44-24.html#Character_Age).
   This makes it possible to use classes like `\p{Age:6.0}` to refer to all
   codepoints first introduced in Unicode 6.0
+* **unicode-block** -
+  Provide the data for the
+  [Unicode `Block` property](https://www.unicode.org/reports/tr44/tr44-24.html#Block).
+  This makes it possible to use classes like `\p{blk=Greek_And_Coptic}`.
 * **unicode-bool** -
   Provide the data for numerous Unicode boolean properties. The full list
   is not included here, but contains properties like `Alphabetic`, `Emoji`,
@@ -479,11 +483,27 @@ increased binary size.
 * **unicode-case** -
   Provide the data for case insensitive matching using
   [Unicode's "simple loose matches" specification](https://www.unicode.org/reports/tr18/#Simple_Loose_Matches).
+* **unicode-east-asian-width** -
+  Provide the data for the
+  [Unicode `East_Asian_Width` property](https://www.unicode.org/reports/tr11/).
+  This makes it possible to use classes like `\p{ea=Wide}`.
 * **unicode-gencat** -
   Provide the data for
   [Unicode general categories](https://www.unicode.org/reports/tr44/tr44-24.html#General_Category_Values).
   This includes, but is not limited to, `Decimal_Number`, `Letter`,
   `Math_Symbol`, `Number` and `Punctuation`.
+* **unicode-line-break** -
+  Provide the data for the
+  [Unicode `Line_Break` property](https://www.unicode.org/reports/tr14/).
+  This makes it possible to use classes like `\p{lb=Ideographic}`.
+* **unicode-name** -
+  Provide the data for looking up codepoints by their
+  [Unicode name](https://www.unicode.org/reports/tr44/tr44-24.html#Name).
+  This makes it possible to use escapes like `\N{GREEK SMALL LETTER ALPHA}`.
+* **unicode-numeric-type** -
+  Provide the data for the
+  [Unicode `Numeric_Type` property](https://www.unicode.org/reports/tr44/tr44-24.html#Numeric_Type).
+  This makes it possible to use classes like `\p{nt=Decimal}`.
 * **unicode-perl** -
   Provide the data for supporting the Unicode-aware Perl character classes,
   corresponding to `\w`, `\s` and `\d`. This is also necessary for using
@@ -519,13 +539,18 @@ of the regex engines in this crate. The `syntax` feature also enables `alloc`.
 * **meta** - Enables the meta regex engine. This also enables the `syntax` and
 `nfa-pikevm` features, as both are the minimal requirements needed. The meta
 regex engine benefits from enabling any of the other regex engines and will
-use them automatically when appropriate.
-* **nfa** - Enables all NFA related features below.
+use them automatically when appropriate. This also enables the [`lexer`]
+module, which is built on top of the meta regex engine.
+* **nfa** - Enables all NFA related features below except `nfa-backref`.
     * **nfa-thompson** - Enables the Thompson NFA APIs. This enables `alloc`.
     * **nfa-pikevm** - Enables the PikeVM regex engine. This enables
     `nfa-thompson`.
     * **nfa-backtrack** - Enables the bounded backtracker regex engine. This
     enables `nfa-thompson`.
+    * **nfa-backref** - Enables the backtracking regex engine that supports
+    backreferences, and permits the meta regex engine to use it when
+    [`meta::Config::backreferences`](crate::meta::Config::backreferences) is
+    enabled. This enables `nfa-thompson`.
 * **dfa** - Enables all DFA related features below.
     * **dfa-build** - Enables APIs for determinizing DFAs from NFAs. This
     enables `nfa-thompson` and `dfa-search`.
@@ -548,6 +573,7 @@ enables `alloc` and `nfa-thompson`.
     all(
         feature = "std",
         feature = "nfa",
+        feature = "nfa-backref",
         feature = "dfa",
         feature = "hybrid"
     ),
@@ -566,6 +592,7 @@ enables `alloc` and `nfa-thompson`.
     not(all(
         feature = "std",
         feature = "nfa",
+        feature = "nfa-backref",
         feature = "dfa",
         feature = "hybrid"
     )),
@@ -590,6 +617,7 @@ enables `alloc` and `nfa-thompson`.
     not(all(
         feature = "std",
         feature = "nfa",
+        feature = "nfa-backref",
         feature = "dfa",
         feature = "hybrid",
         feature = "perf-literal-substring",
@@ -642,6 +670,8 @@ pub mod dfa;
 #[cfg(feature = "hybrid")]
 pub mod hybrid;
 #[cfg(feature = "meta")]
+pub mod lexer;
+#[cfg(feature = "meta")]
 pub mod meta;
 #[cfg(feature = "nfa-thompson")]
 pub mod nfa;
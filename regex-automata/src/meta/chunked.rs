@@ -0,0 +1,301 @@
+/*!
+This module defines bespoke DFA search routines (one for the lazy DFA and one
+for the fully compiled DFA) for haystacks made up of chunks.
+
+The normal DFA search routines require a contiguous haystack, but a DFA search
+is really just a loop that feeds one byte at a time to a state machine. The
+only state carried from one byte to the next is the current DFA state. So it
+is straight-forward to run the same loop over each chunk in turn, which lets
+us find matches that span chunk boundaries without ever copying the haystack.
+
+As with the normal routines, a forward search finds the end of the leftmost
+match and a reverse anchored search from there finds its start. The latter
+walks the chunks backwards.
+
+The only context that a DFA needs outside of the bytes it searches is the byte
+immediately before the search starts (to pick its start state) and the byte
+immediately after it ends (to handle look-ahead at the end). Both are read
+directly from the chunks, so a match is reported precisely when a search of
+the entire haystack as one contiguous slice would report it.
+
+The lazy DFA routine also reports its progress through the cache with
+`Cache::search_update`, just like the normal routines do, so that the lazy
+DFA can still tell when it's being used inefficiently and give up.
+*/
+
+use crate::{
+    meta::error::RetryFailError,
+    util::{chunks::Chunks, start},
+    Anchored, HalfMatch, Match, MatchError,
+};
+
+#[cfg(feature = "dfa-build")]
+pub(crate) fn dfa_try_search(
+    re: &crate::dfa::regex::Regex<crate::meta::wrappers::DenseDFA>,
+    chunks: &Chunks<'_>,
+    start: usize,
+) -> Result<Option<Match>, RetryFailError> {
+    let end = match dfa_try_search_half_fwd(re.forward(), chunks, start)? {
+        None => return Ok(None),
+        Some(end) => end,
+    };
+    // An empty match at the start of the search can't start anywhere else,
+    // so we can skip the reverse search.
+    if start == end.offset() {
+        return Ok(Some(Match::new(end.pattern(), start..start)));
+    }
+    let hm =
+        dfa_try_search_half_rev(re.reverse(), chunks, start, end.offset())?
+            .expect("reverse search must match if forward search does");
+    Ok(Some(Match::new(end.pattern(), hm.offset()..end.offset())))
+}
+
+#[cfg(feature = "hybrid")]
+pub(crate) fn hybrid_try_search(
+    re: &crate::hybrid::regex::Regex,
+    cache: &mut crate::hybrid::regex::Cache,
+    chunks: &Chunks<'_>,
+    start: usize,
+) -> Result<Option<Match>, RetryFailError> {
+    let (fcache, rcache) = cache.as_parts_mut();
+    let end =
+        match hybrid_try_search_half_fwd(re.forward(), fcache, chunks, start)?
+        {
+            None => return Ok(None),
+            Some(end) => end,
+        };
+    // An empty match at the start of the search can't start anywhere else,
+    // so we can skip the reverse search.
+    if start == end.offset() {
+        return Ok(Some(Match::new(end.pattern(), start..start)));
+    }
+    let hm = hybrid_try_search_half_rev(
+        re.reverse(),
+        rcache,
+        chunks,
+        start,
+        end.offset(),
+    )?
+    .expect("reverse search must match if forward search does");
+    Ok(Some(Match::new(end.pattern(), hm.offset()..end.offset())))
+}
+
+#[cfg(feature = "dfa-build")]
+fn dfa_try_search_half_fwd(
+    dfa: &crate::meta::wrappers::DenseDFA,
+    chunks: &Chunks<'_>,
+    start: usize,
+) -> Result<Option<HalfMatch>, MatchError> {
+    use crate::dfa::{Automaton, StartError};
+
+    let look_behind = start.checked_sub(1).and_then(|at| chunks.get(at));
+    let config =
+        start::Config::new().anchored(Anchored::No).look_behind(look_behind);
+    let mut sid = dfa.start_state(&config).map_err(|err| match err {
+        StartError::Quit { byte } => MatchError::quit(byte, start - 1),
+        StartError::UnsupportedAnchored { mode } => {
+            MatchError::unsupported_anchored(mode)
+        }
+    })?;
+    let mut mat = None;
+    for (offset, bytes) in chunks.range(start, chunks.end()) {
+        for (i, &byte) in bytes.iter().enumerate() {
+            let at = offset + i;
+            sid = dfa.next_state(sid, byte);
+            if dfa.is_special_state(sid) {
+                if dfa.is_match_state(sid) {
+                    let pattern = dfa.match_pattern(sid, 0);
+                    mat = Some(HalfMatch::new(pattern, at));
+                } else if dfa.is_dead_state(sid) {
+                    return Ok(mat);
+                } else if dfa.is_quit_state(sid) {
+                    return Err(MatchError::quit(byte, at));
+                }
+                // Otherwise, this is a start or an accelerated state. We
+                // don't use prefilters or acceleration here, so there's
+                // nothing to do but keep going.
+            }
+        }
+    }
+    sid = dfa.next_eoi_state(sid);
+    if dfa.is_match_state(sid) {
+        let pattern = dfa.match_pattern(sid, 0);
+        mat = Some(HalfMatch::new(pattern, chunks.end()));
+    }
+    Ok(mat)
+}
+
+#[cfg(feature = "dfa-build")]
+fn dfa_try_search_half_rev(
+    dfa: &crate::meta::wrappers::DenseDFA,
+    chunks: &Chunks<'_>,
+    start: usize,
+    end: usize,
+) -> Result<Option<HalfMatch>, MatchError> {
+    use crate::dfa::{Automaton, StartError};
+
+    let config = start::Config::new()
+        .anchored(Anchored::Yes)
+        .look_behind(chunks.get(end));
+    let mut sid = dfa.start_state(&config).map_err(|err| match err {
+        StartError::Quit { byte } => MatchError::quit(byte, end),
+        StartError::UnsupportedAnchored { mode } => {
+            MatchError::unsupported_anchored(mode)
+        }
+    })?;
+    let mut mat = None;
+    for (offset, bytes) in chunks.range(start, end).rev() {
+        for (i, &byte) in bytes.iter().enumerate().rev() {
+            let at = offset + i;
+            sid = dfa.next_state(sid, byte);
+            if dfa.is_special_state(sid) {
+                if dfa.is_match_state(sid) {
+                    let pattern = dfa.match_pattern(sid, 0);
+                    mat = Some(HalfMatch::new(pattern, at + 1));
+                } else if dfa.is_dead_state(sid) {
+                    return Ok(mat);
+                } else if dfa.is_quit_state(sid) {
+                    return Err(MatchError::quit(byte, at));
+                }
+            }
+        }
+    }
+    match start.checked_sub(1).and_then(|at| chunks.get(at)) {
+        Some(byte) => {
+            sid = dfa.next_state(sid, byte);
+            if dfa.is_quit_state(sid) {
+                return Err(MatchError::quit(byte, start - 1));
+            }
+        }
+        None => sid = dfa.next_eoi_state(sid),
+    }
+    if dfa.is_match_state(sid) {
+        let pattern = dfa.match_pattern(sid, 0);
+        mat = Some(HalfMatch::new(pattern, start));
+    }
+    Ok(mat)
+}
+
+#[cfg(feature = "hybrid")]
+fn hybrid_try_search_half_fwd(
+    dfa: &crate::hybrid::dfa::DFA,
+    cache: &mut crate::hybrid::dfa::Cache,
+    chunks: &Chunks<'_>,
+    start: usize,
+) -> Result<Option<HalfMatch>, MatchError> {
+    use crate::hybrid::StartError;
+
+    let look_behind = start.checked_sub(1).and_then(|at| chunks.get(at));
+    let config =
+        start::Config::new().anchored(Anchored::No).look_behind(look_behind);
+    let mut sid =
+        dfa.start_state(cache, &config).map_err(|err| match err {
+            StartError::Cache { .. } => MatchError::gave_up(start),
+            StartError::Quit { byte } => MatchError::quit(byte, start - 1),
+            StartError::UnsupportedAnchored { mode } => {
+                MatchError::unsupported_anchored(mode)
+            }
+        })?;
+    let mut mat = None;
+    cache.search_start(start);
+    for (offset, bytes) in chunks.range(start, chunks.end()) {
+        for (i, &byte) in bytes.iter().enumerate() {
+            let at = offset + i;
+            cache.search_update(at);
+            sid = dfa
+                .next_state(cache, sid, byte)
+                .map_err(|_| MatchError::gave_up(at))?;
+            if sid.is_tagged() {
+                if sid.is_match() {
+                    let pattern = dfa.match_pattern(cache, sid, 0);
+                    mat = Some(HalfMatch::new(pattern, at));
+                } else if sid.is_dead() {
+                    cache.search_finish(at);
+                    return Ok(mat);
+                } else if sid.is_quit() {
+                    cache.search_finish(at);
+                    return Err(MatchError::quit(byte, at));
+                }
+                // Otherwise, this is a start state. We don't use prefilters
+                // here, so there's nothing to do but keep going.
+            }
+        }
+    }
+    sid = dfa
+        .next_eoi_state(cache, sid)
+        .map_err(|_| MatchError::gave_up(chunks.end()))?;
+    if sid.is_match() {
+        let pattern = dfa.match_pattern(cache, sid, 0);
+        mat = Some(HalfMatch::new(pattern, chunks.end()));
+    }
+    cache.search_finish(chunks.end());
+    Ok(mat)
+}
+
+#[cfg(feature = "hybrid")]
+fn hybrid_try_search_half_rev(
+    dfa: &crate::hybrid::dfa::DFA,
+    cache: &mut crate::hybrid::dfa::Cache,
+    chunks: &Chunks<'_>,
+    start: usize,
+    end: usize,
+) -> Result<Option<HalfMatch>, MatchError> {
+    use crate::hybrid::StartError;
+
+    let config = start::Config::new()
+        .anchored(Anchored::Yes)
+        .look_behind(chunks.get(end));
+    let mut sid =
+        dfa.start_state(cache, &config).map_err(|err| match err {
+            StartError::Cache { .. } => MatchError::gave_up(end),
+            StartError::Quit { byte } => MatchError::quit(byte, end),
+            StartError::UnsupportedAnchored { mode } => {
+                MatchError::unsupported_anchored(mode)
+            }
+        })?;
+    let mut mat = None;
+    cache.search_start(end);
+    for (offset, bytes) in chunks.range(start, end).rev() {
+        for (i, &byte) in bytes.iter().enumerate().rev() {
+            let at = offset + i;
+            cache.search_update(at);
+            sid = dfa
+                .next_state(cache, sid, byte)
+                .map_err(|_| MatchError::gave_up(at))?;
+            if sid.is_tagged() {
+                if sid.is_match() {
+                    let pattern = dfa.match_pattern(cache, sid, 0);
+                    mat = Some(HalfMatch::new(pattern, at + 1));
+                } else if sid.is_dead() {
+                    cache.search_finish(at);
+                    return Ok(mat);
+                } else if sid.is_quit() {
+                    cache.search_finish(at);
+                    return Err(MatchError::quit(byte, at));
+                }
+            }
+        }
+    }
+    match start.checked_sub(1).and_then(|at| chunks.get(at)) {
+        Some(byte) => {
+            sid = dfa
+                .next_state(cache, sid, byte)
+                .map_err(|_| MatchError::gave_up(start))?;
+            if sid.is_quit() {
+                cache.search_finish(start);
+                return Err(MatchError::quit(byte, start - 1));
+            }
+        }
+        None => {
+            sid = dfa
+                .next_eoi_state(cache, sid)
+                .map_err(|_| MatchError::gave_up(start))?;
+        }
+    }
+    if sid.is_match() {
+        let pattern = dfa.match_pattern(cache, sid, 0);
+        mat = Some(HalfMatch::new(pattern, start));
+    }
+    cache.search_finish(start);
+    Ok(mat)
+}
@@ -229,11 +229,18 @@ impl From<MatchError> for RetryFailError {
         match *merr.kind() {
             Quit { offset, .. } => RetryFailError::from_offset(offset),
             GaveUp { offset } => RetryFailError::from_offset(offset),
+            // A cancelled search is retried like any other failure, but
+            // every fallback engine checks the cancellation flag too. So the
+            // retry stops immediately and reports the cancellation itself.
+            Cancelled { offset } => RetryFailError::from_offset(offset),
             // These can never occur because we avoid them by construction
             // or with higher level control flow logic. For example, the
             // backtracker's wrapper will never hand out a backtracker engine
-            // when the haystack would be too long.
-            HaystackTooLong { .. } | UnsupportedAnchored { .. } => {
+            // when the haystack would be too long. (And the backreference
+            // engine's errors are reported directly, never retried.)
+            HaystackTooLong { .. }
+            | UnsupportedAnchored { .. }
+            | StepLimitExceeded { .. } => {
                 unreachable!("found impossible error in meta engine: {}", merr)
             }
         }
@@ -43,7 +43,7 @@ use crate::{
 
 #[cfg(feature = "dfa-build")]
 pub(crate) fn dfa_try_search_half_rev(
-    dfa: &crate::dfa::dense::DFA<alloc::vec::Vec<u32>>,
+    dfa: &crate::meta::wrappers::DenseDFA,
     input: &Input<'_>,
     min_start: usize,
 ) -> Result<Option<HalfMatch>, RetryError> {
@@ -188,7 +188,7 @@ pub(crate) fn hybrid_try_search_half_rev(
 #[cfg(feature = "dfa-build")]
 #[cfg_attr(feature = "perf-inline", inline(always))]
 fn dfa_eoi_rev(
-    dfa: &crate::dfa::dense::DFA<alloc::vec::Vec<u32>>,
+    dfa: &crate::meta::wrappers::DenseDFA,
     input: &Input<'_>,
     sid: &mut crate::util::primitives::StateID,
     mat: &mut Option<HalfMatch>,
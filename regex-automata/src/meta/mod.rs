@@ -45,11 +45,13 @@ level routines such as [`Regex::search_with`].
 pub use self::{
     error::BuildError,
     regex::{
-        Builder, Cache, CapturesMatches, Config, FindMatches, Regex, Split,
-        SplitN,
+        Builder, Cache, CapturesMatches, ChunkMatches, Config, FindMatches,
+        RFindMatches, Regex, Split, SplitN,
     },
 };
 
+#[cfg(any(feature = "dfa-build", feature = "hybrid"))]
+mod chunked;
 mod error;
 #[cfg(any(feature = "dfa-build", feature = "hybrid"))]
 mod limited;
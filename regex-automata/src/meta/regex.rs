@@ -19,14 +19,25 @@ use crate::{
     nfa::thompson::WhichCaptures,
     util::{
         captures::{Captures, GroupInfo},
+        chunks::Chunks,
         iter,
         pool::{Pool, PoolGuard},
         prefilter::Prefilter,
         primitives::{NonMaxUsize, PatternID},
-        search::{HalfMatch, Input, Match, MatchKind, PatternSet, Span},
+        search::{
+            HalfMatch, Input, Match, MatchError, MatchKind, PatternSet, Span,
+        },
+        wire::{self, DeserializeError, Endian, Reader, Writer},
     },
 };
 
+/// The label used in the header of a serialized meta regex.
+const LABEL: &str = "rust-regex-automata-meta-regex";
+
+/// The format version of a serialized meta regex. This must be incremented
+/// whenever the format changes.
+const VERSION: u32 = 3;
+
 /// A type alias for our pool of meta::Cache that fixes the type parameters to
 /// what we use for the meta regex below.
 type CachePool = Pool<Cache, CachePoolFn>;
@@ -557,6 +568,27 @@ impl Regex {
         self.search(&input.into())
     }
 
+    /// Executes a reverse search and returns the last match that is found,
+    /// if one exists.
+    ///
+    /// See [`Regex::rsearch`] for precisely which match is reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new("foo[0-9]+")?;
+    /// let haystack = "foo1 foo2 foo12345";
+    /// assert_eq!(Some(Match::must(0, 10..18)), re.rfind(haystack));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn rfind<'h, I: Into<Input<'h>>>(&self, input: I) -> Option<Match> {
+        self.rsearch(&input.into())
+    }
+
     /// Executes a leftmost forward search and writes the spans of capturing
     /// groups that participated in a match into the provided [`Captures`]
     /// value. If no match was found, then [`Captures::is_match`] is guaranteed
@@ -616,6 +648,125 @@ impl Regex {
         FindMatches { re: self, cache, it }
     }
 
+    /// Returns an iterator over all non-overlapping matches in the given
+    /// haystack, starting from the end of the haystack and proceeding
+    /// backwards. If no match exists, then the iterator yields no elements.
+    ///
+    /// Each match is found with [`Regex::rsearch`] on the part of the
+    /// haystack that precedes the previous match. Like [`Regex::find_iter`],
+    /// an empty match is never yielded if it starts where the previous match
+    /// did.
+    ///
+    /// The matches yielded are often the same as those yielded by
+    /// [`Regex::find_iter`], just in reverse order. But this isn't always the
+    /// case, since a forward search always resumes where the previous match
+    /// ended, while a reverse search resumes where it started. For example,
+    /// `aa` matches `0..2` in `aaa` when searching forwards, but `1..3` when
+    /// searching backwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new("foo[0-9]+")?;
+    /// let haystack = "foo1 foo12 foo123";
+    /// let matches: Vec<Match> = re.rfind_iter(haystack).collect();
+    /// assert_eq!(matches, vec![
+    ///     Match::must(0, 11..17),
+    ///     Match::must(0, 5..10),
+    ///     Match::must(0, 0..4),
+    /// ]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn rfind_iter<'r, 'h, I: Into<Input<'h>>>(
+        &'r self,
+        input: I,
+    ) -> RFindMatches<'r, 'h> {
+        let cache = self.pool.get();
+        RFindMatches { re: self, cache, input: input.into(), last_start: None }
+    }
+
+    /// Executes a leftmost search over a haystack made up of chunks and
+    /// returns the first match that is found, if one exists.
+    ///
+    /// Each chunk is given along with its absolute offset, as described by
+    /// [`Chunks`]. The offsets of the match returned are absolute offsets
+    /// too. This is useful for searching text that isn't stored contiguously,
+    /// like the text in a rope, without copying it first. Matches may span
+    /// any number of chunks.
+    ///
+    /// See [`Regex::search_chunks`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the offset of a chunk doesn't immediately follow the
+    /// previous chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new(r"\bfoo[0-9]+\b")?;
+    /// let chunks = [(0, &b"xfoo1 f"[..]), (7, b"oo12"), (11, b"3 bar")];
+    /// assert_eq!(Some(Match::must(0, 6..12)), re.find_chunks(chunks));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn find_chunks<'h, I>(&self, chunks: I) -> Option<Match>
+    where
+        I: IntoIterator<Item = (usize, &'h [u8])>,
+    {
+        let chunks = Chunks::new(chunks);
+        self.search_chunks(&chunks, chunks.start())
+    }
+
+    /// Returns an iterator over all non-overlapping leftmost matches in a
+    /// haystack made up of chunks. If no match exists, then the iterator
+    /// yields no elements.
+    ///
+    /// This yields the same matches as [`Regex::find_iter`] does for the
+    /// concatenation of the chunks, except that each match's offsets are
+    /// absolute offsets as given by the chunks. See [`Regex::find_chunks`]
+    /// for more details.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the offset of a chunk doesn't immediately follow the
+    /// previous chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new("foo[0-9]+")?;
+    /// let chunks = [(100, &b"foo1 fo"[..]), (107, b"o12 foo"), (114, b"123")];
+    /// let matches: Vec<Match> = re.find_chunks_iter(chunks).collect();
+    /// assert_eq!(matches, vec![
+    ///     Match::must(0, 100..104),
+    ///     Match::must(0, 105..110),
+    ///     Match::must(0, 111..117),
+    /// ]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn find_chunks_iter<'r, 'h, I>(
+        &'r self,
+        chunks: I,
+    ) -> ChunkMatches<'r, 'h>
+    where
+        I: IntoIterator<Item = (usize, &'h [u8])>,
+    {
+        let cache = self.pool.get();
+        let chunks = Chunks::new(chunks);
+        let at = Some(chunks.start());
+        ChunkMatches { re: self, cache, chunks, at, last_end: None }
+    }
+
     /// Returns an iterator over all non-overlapping `Captures` values. If no
     /// match exists, then the iterator yields no elements.
     ///
@@ -944,6 +1095,124 @@ impl Regex {
         result
     }
 
+    /// Returns the start and end offset of the last match, found by searching
+    /// backwards from the end of the input. If no match exists, then `None`
+    /// is returned.
+    ///
+    /// The match reported is always one that a forward search would report
+    /// too. Namely, if a match `m` is returned, then [`Regex::search`] also
+    /// returns `m` when the start of its input is set to `m.start()`.
+    ///
+    /// Specifically, this first finds the last position at which any match
+    /// starts, and the end `e` of the match that a forward search reports
+    /// from there. It then returns the match that a forward search reports
+    /// from the earliest position from which that match also ends at `e`.
+    /// This makes it possible to find a match like `123` for `[0-9]+` in
+    /// `abc123`, even though a match also starts at each of `2` and `3`.
+    ///
+    /// When the search is anchored, this is equivalent to [`Regex::search`],
+    /// since a match can only start at the beginning of the input.
+    ///
+    /// # Time complexity
+    ///
+    /// This runs a reverse search to find where the last match starts,
+    /// followed by a forward search to find where it ends and another reverse
+    /// search to find where it could start earlier. The reverse searches use
+    /// a DFA when one is available, and otherwise (or when the DFA gives up)
+    /// a PikeVM, so they are linear in the size of the input.
+    ///
+    /// Regexes that can't be searched in reverse at all (such as those with
+    /// atomic groups or segmentation boundaries, or when both
+    /// [`Config::dfa`] and [`Config::hybrid`] are disabled) instead run a
+    /// forward search from every starting position in the input. This is
+    /// much slower, and may take time quadratic in the size of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Input, Match};
+    ///
+    /// let re = Regex::new(r"[0-9]+")?;
+    /// let input = Input::new("abc123 def456");
+    /// assert_eq!(Some(Match::must(0, 10..13)), re.rsearch(&input));
+    ///
+    /// // Searching only a prefix of the haystack finds an earlier match.
+    /// let input = input.range(..9);
+    /// assert_eq!(Some(Match::must(0, 3..6)), re.rsearch(&input));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn rsearch(&self, input: &Input<'_>) -> Option<Match> {
+        if self.imp.info.is_impossible(input) {
+            return None;
+        }
+        let mut guard = self.pool.get();
+        let result = self.imp.strat.rsearch(&mut guard, input);
+        // See 'Regex::search' for why we put the guard back explicitly.
+        PoolGuard::put(guard);
+        result
+    }
+
+    /// Executes a leftmost forward search over a haystack made up of chunks,
+    /// starting at the absolute offset `start`, and returns the first match
+    /// found, if one exists.
+    ///
+    /// This reports the same match as [`Regex::search`] would for an
+    /// unanchored [`Input`] over the concatenation of the chunks, with its
+    /// span starting at `start`. The only differences are that all offsets
+    /// are absolute offsets as given by the chunks, and that the chunks are
+    /// never copied into a contiguous haystack. In particular, the bytes
+    /// before `start` are used to check look-behind assertions like `\b`.
+    ///
+    /// Unlike with an `Input`, a search of chunks can't be anchored, made to
+    /// stop at the earliest match or cancelled.
+    ///
+    /// # Time complexity
+    ///
+    /// When a full or lazy DFA is available, the search walks the chunks one
+    /// byte at a time with it, and walks them backwards from the end of a
+    /// match to find its start. Otherwise (or if the lazy DFA gives up), the
+    /// PikeVM is run over the chunks, using a small copy of the bytes around
+    /// each chunk boundary to check look-around assertions. Literal searches
+    /// that bypass regex engines entirely search each chunk on its own.
+    ///
+    /// Regexes that use features only some regex engines support (bounded
+    /// look-around, backreferences, atomic groups or approximate matching)
+    /// may need to copy the chunks into one contiguous buffer before
+    /// searching it.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `start` isn't within the bounds of the haystack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, util::chunks::Chunks, Match};
+    ///
+    /// let re = Regex::new(r"\b[0-9]+\b")?;
+    /// let chunks = Chunks::new([(0, &b"ab12 3"[..]), (6, b"45 6")]);
+    /// assert_eq!(Some(Match::must(0, 5..8)), re.search_chunks(&chunks, 0));
+    /// // The search knows that 9 doesn't follow a word boundary, since 8 is
+    /// // a word character.
+    /// assert_eq!(Some(Match::must(0, 9..10)), re.search_chunks(&chunks, 8));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn search_chunks(
+        &self,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        let mut guard = self.pool.get();
+        let result = self.search_chunks_with(&mut guard, chunks, start);
+        // See 'Regex::search' for why we put the guard back explicitly.
+        PoolGuard::put(guard);
+        result
+    }
+
     /// Returns the end offset of the leftmost match. If no match exists, then
     /// `None` is returned.
     ///
@@ -1248,6 +1517,85 @@ impl Regex {
         self.imp.strat.search(cache, input)
     }
 
+    /// This is like [`Regex::rsearch`], but requires the caller to
+    /// explicitly pass a [`Cache`].
+    ///
+    /// # Why pass a `Cache` explicitly?
+    ///
+    /// Passing a `Cache` explicitly will bypass the use of an internal memory
+    /// pool used by `Regex` to get a `Cache` for a search. See
+    /// [`Regex::search_with`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Input, Match};
+    ///
+    /// let re = Regex::new(r"Samwise|Sam")?;
+    /// let mut cache = re.create_cache();
+    /// let input = Input::new("Sam and Samwise");
+    /// assert_eq!(
+    ///     Some(Match::must(0, 8..15)),
+    ///     re.rsearch_with(&mut cache, &input),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn rsearch_with(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Option<Match> {
+        if self.imp.info.is_impossible(input) {
+            return None;
+        }
+        self.imp.strat.rsearch(cache, input)
+    }
+
+    /// This is like [`Regex::search_chunks`], but requires the caller to
+    /// explicitly pass a [`Cache`].
+    ///
+    /// # Why pass a `Cache` explicitly?
+    ///
+    /// Passing a `Cache` explicitly will bypass the use of an internal memory
+    /// pool used by `Regex` to get a `Cache` for a search. See
+    /// [`Regex::search_with`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `start` isn't within the bounds of the haystack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, util::chunks::Chunks, Match};
+    ///
+    /// let re = Regex::new(r"Samwise|Sam")?;
+    /// let mut cache = re.create_cache();
+    /// let chunks = Chunks::new([(0, &b"Sam and Sam"[..]), (11, b"wise")]);
+    /// assert_eq!(
+    ///     Some(Match::must(0, 8..15)),
+    ///     re.search_chunks_with(&mut cache, &chunks, 1),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn search_chunks_with(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        assert!(
+            chunks.start() <= start && start <= chunks.end(),
+            "search start {} is not within the bounds of the haystack",
+            start,
+        );
+        self.imp.strat.search_chunks(cache, chunks, start)
+    }
+
     /// This is like [`Regex::search_half`], but requires the caller to
     /// explicitly pass a [`Cache`].
     ///
@@ -1493,6 +1841,165 @@ impl Regex {
     }
 }
 
+/// Fallible search routines.
+///
+/// Every search routine on a `Regex` is infallible, except in two cases.
+/// Firstly, when [backreferences](Config::backreferences) are enabled, a
+/// search may exceed its [step limit](Config::backref_step_limit). Secondly,
+/// a search may be cancelled via a flag set with [`Input::cancel_flag`]. The
+/// infallible routines panic when either happens. These routines return an
+/// error instead.
+impl Regex {
+    /// Returns the start and end offset of the leftmost match, or an error if
+    /// the search could not complete.
+    ///
+    /// This is like [`Regex::search`], but it returns an error instead of
+    /// panicking when the search fails.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the regex uses
+    /// [backreferences](Config::backreferences) and the search exceeds
+    /// the [step limit](Config::backref_step_limit). In that case,
+    /// the error kind is
+    /// [`MatchErrorKind::StepLimitExceeded`](crate::MatchErrorKind::StepLimitExceeded).
+    ///
+    /// This also returns an error when the search is cancelled via the flag
+    /// set by [`Input::cancel_flag`]. In that case, the error kind is
+    /// [`MatchErrorKind::Cancelled`](crate::MatchErrorKind::Cancelled).
+    /// Cancellation is cooperative: the flag is only checked periodically,
+    /// so a search may still complete normally after the flag is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Input, MatchErrorKind};
+    ///
+    /// # if !cfg!(feature = "nfa-backref") { return Ok(()); }
+    /// let re = Regex::builder()
+    ///     .configure(
+    ///         Regex::config()
+    ///             .backreferences(true)
+    ///             .backref_step_limit(Some(1_000)),
+    ///     )
+    ///     .build(r"^(a|aa)+\1$")?;
+    ///
+    /// let err = re.try_search(&Input::new("aaaaaaaaaaaaaaaaaaaaaaaaaab"))
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     &MatchErrorKind::StepLimitExceeded { limit: 1_000 },
+    ///     err.kind(),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_search(
+        &self,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
+        if self.imp.info.is_impossible(input) {
+            return Ok(None);
+        }
+        let mut guard = self.pool.get();
+        let result = self.imp.strat.try_search(&mut guard, input);
+        // See 'Regex::search' for why we put the guard back explicitly.
+        PoolGuard::put(guard);
+        result
+    }
+
+    /// This is like [`Regex::try_search`], but requires the caller to
+    /// explicitly pass a [`Cache`].
+    ///
+    /// Passing a `Cache` explicitly will bypass the use of an internal memory
+    /// pool used by `Regex` to get a `Cache` for a search. See
+    /// [`Regex::search_with`] for more details.
+    #[inline]
+    pub fn try_search_with(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
+        if self.imp.info.is_impossible(input) {
+            return Ok(None);
+        }
+        self.imp.strat.try_search(cache, input)
+    }
+
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided [`Captures`]
+    /// value, or returns an error if the search could not complete.
+    ///
+    /// This is like [`Regex::search_captures_with`], but it returns an error
+    /// instead of panicking when the search fails. When an error is returned,
+    /// [`Captures::is_match`] is guaranteed to return `false`.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the regex uses
+    /// [backreferences](Config::backreferences) and the search exceeds the
+    /// [step limit](Config::backref_step_limit), or when the search is
+    /// cancelled via the flag set by [`Input::cancel_flag`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Input, Span};
+    ///
+    /// # if !cfg!(feature = "nfa-backref") { return Ok(()); }
+    /// let re = Regex::builder()
+    ///     .configure(Regex::config().backreferences(true))
+    ///     .build(r"(?<word>\w+) \k<word>")?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    ///
+    /// let input = Input::new("it is is fine");
+    /// re.try_search_captures_with(&mut cache, &input, &mut caps)?;
+    /// assert_eq!(Some(Span::from(3..8)), caps.get_group(0));
+    /// assert_eq!(Some(Span::from(3..5)), caps.get_group_by_name("word"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_search_captures_with(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        caps: &mut Captures,
+    ) -> Result<(), MatchError> {
+        caps.set_pattern(None);
+        let pid =
+            self.try_search_slots_with(cache, input, caps.slots_mut())?;
+        caps.set_pattern(pid);
+        Ok(())
+    }
+
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided `slots`, or
+    /// returns an error if the search could not complete.
+    ///
+    /// This is like [`Regex::search_slots_with`], but it returns an error
+    /// instead of panicking when the search fails.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the regex uses
+    /// [backreferences](Config::backreferences) and the search exceeds the
+    /// [step limit](Config::backref_step_limit), or when the search is
+    /// cancelled via the flag set by [`Input::cancel_flag`].
+    #[inline]
+    pub fn try_search_slots_with(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        if self.imp.info.is_impossible(input) {
+            return Ok(None);
+        }
+        self.imp.strat.try_search_slots(cache, input, slots)
+    }
+}
+
 /// Various non-search routines for querying properties of a `Regex` and
 /// convenience routines for creating [`Captures`] and [`Cache`] values.
 impl Regex {
@@ -1858,41 +2365,302 @@ impl Regex {
     /// ```
     /// use regex_automata::meta::Regex;
     ///
-    /// // A simple literal is very likely to be accelerated.
-    /// let re = Regex::new(r"foo")?;
-    /// assert!(re.is_accelerated());
+    /// // A simple literal is very likely to be accelerated.
+    /// let re = Regex::new(r"foo")?;
+    /// assert!(re.is_accelerated());
+    ///
+    /// // A regex with no literals is likely to not be accelerated.
+    /// let re = Regex::new(r"\w")?;
+    /// assert!(!re.is_accelerated());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn is_accelerated(&self) -> bool {
+        self.imp.strat.is_accelerated()
+    }
+
+    /// Return the total approximate heap memory, in bytes, used by this `Regex`.
+    ///
+    /// Note that currently, there is no high level configuration for setting
+    /// a limit on the specific value returned by this routine. Instead, the
+    /// following routines can be used to control heap memory at a bit of a
+    /// lower level:
+    ///
+    /// * [`Config::nfa_size_limit`] controls how big _any_ of the NFAs are
+    /// allowed to be.
+    /// * [`Config::onepass_size_limit`] controls how big the one-pass DFA is
+    /// allowed to be.
+    /// * [`Config::hybrid_cache_capacity`] controls how much memory the lazy
+    /// DFA is permitted to allocate to store its transition table.
+    /// * [`Config::dfa_size_limit`] controls how big a fully compiled DFA is
+    /// allowed to be.
+    /// * [`Config::dfa_state_limit`] controls the conditions under which the
+    /// meta regex engine will even attempt to build a fully compiled DFA.
+    #[inline]
+    pub fn memory_usage(&self) -> usize {
+        self.imp.strat.memory_usage()
+    }
+}
+
+/// Serialization and deserialization.
+impl Regex {
+    /// Serialize this regex to a new byte vector in little endian format.
+    ///
+    /// The bytes returned can be turned back into an equivalent regex with
+    /// [`Regex::from_bytes`] or [`Regex::from_bytes_owned`] on a little
+    /// endian target. This makes it possible to build a regex ahead of time
+    /// (say, in a build script) and load it at runtime without paying for
+    /// parsing the pattern or for compiling it into an NFA and, when one was
+    /// built, a fully compiled DFA.
+    ///
+    /// The format includes the configuration of the regex, the Thompson
+    /// NFAs it was built from, the literals used for its prefilters and the
+    /// strategy chosen for executing searches. Fully compiled DFAs, when
+    /// used, are included as well. Cheaper regex engines (like the PikeVM or
+    /// the one-pass DFA) are rebuilt from the NFA when deserializing. The
+    /// lazy DFA, as always, builds its transition table during search.
+    ///
+    /// Like [`dense::DFA::to_bytes_little_endian`](crate::dfa::dense::DFA),
+    /// this returns the number of padding bytes at the beginning of the
+    /// vector along with the vector itself. The serialized regex starts at
+    /// `bytes[pad..]`, which is aligned to a `u32`. This alignment is
+    /// required for deserializing with [`Regex::from_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new(r"[a-z]+[0-9]+")?;
+    /// let (bytes, pad) = re.to_bytes_little_endian();
+    ///
+    /// # if !cfg!(target_endian = "little") { return Ok(()); }
+    /// let (re, _) = Regex::from_bytes_owned(&bytes[pad..])?;
+    /// assert_eq!(Some(Match::must(0, 4..10)), re.find("123 abc123"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_little_endian(&self) -> (Vec<u8>, usize) {
+        self.to_bytes::<wire::LE>()
+    }
+
+    /// Serialize this regex to a new byte vector in big endian format.
+    ///
+    /// The bytes returned can be turned back into an equivalent regex with
+    /// [`Regex::from_bytes`] or [`Regex::from_bytes_owned`] on a big endian
+    /// target.
+    ///
+    /// See [`Regex::to_bytes_little_endian`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new(r"[a-z]+[0-9]+")?;
+    /// let (bytes, pad) = re.to_bytes_big_endian();
+    ///
+    /// # if !cfg!(target_endian = "big") { return Ok(()); }
+    /// let (re, _) = Regex::from_bytes_owned(&bytes[pad..])?;
+    /// assert_eq!(Some(Match::must(0, 4..10)), re.find("123 abc123"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_big_endian(&self) -> (Vec<u8>, usize) {
+        self.to_bytes::<wire::BE>()
+    }
+
+    /// Serialize this regex to a new byte vector in the native endianness of
+    /// the current target.
+    ///
+    /// This is convenient when the regex is only ever deserialized on the
+    /// same target that it was serialized on. Otherwise, when building a
+    /// regex for a different target, use one of
+    /// [`Regex::to_bytes_little_endian`] or [`Regex::to_bytes_big_endian`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new_many(&[r"\w+@\w+", r"[0-9]+"])?;
+    /// let (bytes, pad) = re.to_bytes_native_endian();
+    ///
+    /// let (re, _) = Regex::from_bytes_owned(&bytes[pad..])?;
+    /// let got: Vec<Match> = re.find_iter("a@b 12").collect();
+    /// assert_eq!(got, vec![Match::must(0, 0..3), Match::must(1, 4..6)]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_native_endian(&self) -> (Vec<u8>, usize) {
+        self.to_bytes::<wire::NE>()
+    }
+
+    /// Deserialize a regex from the given bytes, which must have been
+    /// produced by one of the `Regex::to_bytes_*` routines using the native
+    /// endianness of the current target.
+    ///
+    /// Upon success, the regex is returned along with the number of bytes
+    /// read from `slice`.
+    ///
+    /// This is the zero-copy way of deserializing a regex: the transition
+    /// tables of any fully compiled DFAs are borrowed directly from `slice`,
+    /// in the same way as [`dense::DFA::from_bytes`](crate::dfa::dense::DFA).
+    /// Since a regex can't carry a lifetime, this requires the slice to live
+    /// for the `'static` lifetime, e.g., by embedding it in the binary with
+    /// `include_bytes!` or by leaking it. The slice must also be aligned to
+    /// a `u32`. The bytes returned by the `Regex::to_bytes_*` routines,
+    /// starting at the padding offset returned with them, always are. Up to
+    /// 7 leading NUL bytes in `slice` are skipped, as with DFAs. When
+    /// embedding a serialized regex with `include_bytes!`, the same wrapper
+    /// type trick as documented for
+    /// [`dense::DFA::from_bytes`](crate::dfa::dense::DFA) can be used to
+    /// guarantee alignment.
+    ///
+    /// Everything else in a meta regex (its NFAs, literals and so on) is
+    /// made up of many variable length pieces that are decoded into heap
+    /// allocations. Those are cheap to decode relative to compiling a DFA,
+    /// and deserializing never parses a pattern or compiles an NFA.
+    ///
+    /// To deserialize a regex from a slice that doesn't live for the
+    /// `'static` lifetime or that isn't aligned, use
+    /// [`Regex::from_bytes_owned`], which copies the DFA tables instead.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if `slice` isn't aligned to a `u32`, if the
+    /// bytes given don't correspond to a serialized regex, if the regex was
+    /// serialized with an incompatible format version or endianness, or if
+    /// it fails validation. Validation is comparable to
+    /// [`dense::DFA::from_bytes`](crate::dfa::dense::DFA): every state
+    /// identifier, pattern identifier and capture slot in the NFAs is
+    /// checked, and any fully compiled DFAs are deserialized with their own
+    /// validating routines.
+    ///
+    /// A serialized regex may refer to regex engines or prefilters that
+    /// aren't available in the current build of this crate (for example,
+    /// when the `dfa-build` feature is disabled). In that case, the regex
+    /// falls back to the engines that are available. It still reports the
+    /// same matches.
+    ///
+    /// # Safety
+    ///
+    /// This routine never results in undefined behavior, even when given
+    /// untrusted input. Invalid data that is not detected by validation
+    /// may however result in incorrect search results or panics.
+    ///
+    /// # Example
+    ///
+    /// This shows how to round trip a regex with capturing groups through
+    /// its serialized form. The buffer is leaked so that the regex can
+    /// borrow from it.
+    ///
+    /// ```
+    /// use regex_automata::meta::Regex;
+    ///
+    /// let re = Regex::new(r"(?<year>[0-9]{4})-(?<month>[0-9]{2})")?;
+    /// let (bytes, pad) = re.to_bytes_native_endian();
+    /// let bytes: &'static [u8] = bytes.leak();
+    /// let (re, _) = Regex::from_bytes(&bytes[pad..])?;
     ///
-    /// // A regex with no literals is likely to not be accelerated.
-    /// let re = Regex::new(r"\w")?;
-    /// assert!(!re.is_accelerated());
+    /// let mut caps = re.create_captures();
+    /// re.captures("on 2010-03", &mut caps);
+    /// assert_eq!(Some(3..7), caps.get_group_by_name("year").map(|s| s.range()));
+    /// assert_eq!(Some(8..10), caps.get_group_by_name("month").map(|s| s.range()));
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    #[inline]
-    pub fn is_accelerated(&self) -> bool {
-        self.imp.strat.is_accelerated()
+    ///
+    /// # Example: invalid input
+    ///
+    /// Corrupted, truncated or unaligned input results in an error.
+    ///
+    /// ```
+    /// use regex_automata::meta::Regex;
+    ///
+    /// let re = Regex::new(r"foo[0-9]+")?;
+    /// let (bytes, pad) = re.to_bytes_native_endian();
+    /// let bytes: &'static [u8] = bytes.leak();
+    /// assert!(Regex::from_bytes(&bytes[pad..bytes.len() - 1]).is_err());
+    /// assert!(Regex::from_bytes(&bytes[pad + 1..]).is_err());
+    /// assert!(Regex::from_bytes(b"not a regex").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes(
+        slice: &'static [u8],
+    ) -> Result<(Regex, usize), DeserializeError> {
+        let pad = wire::skip_initial_padding(slice);
+        let mut rdr = Reader::new_static(&slice[pad..])?;
+        let re = Regex::read_from(&mut rdr)?;
+        Ok((re, pad + rdr.nread()))
     }
 
-    /// Return the total approximate heap memory, in bytes, used by this `Regex`.
+    /// Deserialize a regex from the given bytes by copying them, where the
+    /// bytes must have been produced by one of the `Regex::to_bytes_*`
+    /// routines using the native endianness of the current target.
     ///
-    /// Note that currently, there is no high level configuration for setting
-    /// a limit on the specific value returned by this routine. Instead, the
-    /// following routines can be used to control heap memory at a bit of a
-    /// lower level:
+    /// Upon success, the regex is returned along with the number of bytes
+    /// read from `slice`.
     ///
-    /// * [`Config::nfa_size_limit`] controls how big _any_ of the NFAs are
-    /// allowed to be.
-    /// * [`Config::onepass_size_limit`] controls how big the one-pass DFA is
-    /// allowed to be.
-    /// * [`Config::hybrid_cache_capacity`] controls how much memory the lazy
-    /// DFA is permitted to allocate to store its transition table.
-    /// * [`Config::dfa_size_limit`] controls how big a fully compiled DFA is
-    /// allowed to be.
-    /// * [`Config::dfa_state_limit`] controls the conditions under which the
-    /// meta regex engine will even attempt to build a fully compiled DFA.
-    #[inline]
-    pub fn memory_usage(&self) -> usize {
-        self.imp.strat.memory_usage()
+    /// Unlike [`Regex::from_bytes`], the regex returned owns all of its data,
+    /// including the transition tables of any fully compiled DFAs, which are
+    /// copied out of `slice`. In exchange, `slice` may have any lifetime and
+    /// has no alignment requirements.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error in the same circumstances as
+    /// [`Regex::from_bytes`], except that `slice` need not be aligned.
+    ///
+    /// # Safety
+    ///
+    /// This routine never results in undefined behavior, even when given
+    /// untrusted input. Invalid data that is not detected by validation
+    /// may however result in incorrect search results or panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::new(r"[0-9]{4}-[0-9]{2}")?;
+    /// let (bytes, pad) = re.to_bytes_native_endian();
+    /// let (re, _) = Regex::from_bytes_owned(&bytes[pad..])?;
+    /// // The bytes can be dropped since the regex doesn't borrow them.
+    /// drop(bytes);
+    /// assert_eq!(Some(Match::must(0, 3..10)), re.find("on 2010-03"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes_owned(
+        slice: &[u8],
+    ) -> Result<(Regex, usize), DeserializeError> {
+        let pad = wire::skip_initial_padding(slice);
+        let mut rdr = Reader::new(&slice[pad..]);
+        let re = Regex::read_from(&mut rdr)?;
+        Ok((re, pad + rdr.nread()))
+    }
+
+    fn read_from(rdr: &mut Reader<'_>) -> Result<Regex, DeserializeError> {
+        rdr.header(LABEL, VERSION)?;
+        let info = RegexInfo::read_from(rdr)?;
+        let strat = strategy::read_from(&info, rdr)?;
+        let pool = {
+            let strat = Arc::clone(&strat);
+            let create: CachePoolFn = Box::new(move || strat.create_cache());
+            Pool::new(create)
+        };
+        Ok(Regex { imp: Arc::new(RegexI { strat, info }), pool })
+    }
+
+    fn to_bytes<E: Endian>(&self) -> (Vec<u8>, usize) {
+        let mut wtr = Writer::new::<E>();
+        wtr.header(LABEL, VERSION);
+        self.imp.info.write_to(&mut wtr);
+        self.imp.strat.write_to(&mut wtr);
+        wtr.into_aligned_bytes()
     }
 }
 
@@ -1914,8 +2682,8 @@ pub(crate) struct RegexInfo(Arc<RegexInfoI>);
 #[derive(Clone, Debug)]
 struct RegexInfoI {
     config: Config,
-    props: Vec<hir::Properties>,
-    props_union: hir::Properties,
+    props: Vec<Props>,
+    props_union: Props,
 }
 
 impl RegexInfo {
@@ -1927,7 +2695,8 @@ impl RegexInfo {
         for hir in hirs.iter() {
             props.push(hir.properties().clone());
         }
-        let props_union = hir::Properties::union(&props);
+        let props_union = Props::new(&hir::Properties::union(&props));
+        let props = props.iter().map(Props::new).collect();
 
         RegexInfo(Arc::new(RegexInfoI { config, props, props_union }))
     }
@@ -1936,11 +2705,11 @@ impl RegexInfo {
         &self.0.config
     }
 
-    pub(crate) fn props(&self) -> &[hir::Properties] {
+    pub(crate) fn props(&self) -> &[Props] {
         &self.0.props
     }
 
-    pub(crate) fn props_union(&self) -> &hir::Properties {
+    pub(crate) fn props_union(&self) -> &Props {
         &self.0.props_union
     }
 
@@ -1949,8 +2718,31 @@ impl RegexInfo {
     }
 
     pub(crate) fn memory_usage(&self) -> usize {
-        self.props().iter().map(|p| p.memory_usage()).sum::<usize>()
-            + self.props_union().memory_usage()
+        core::mem::size_of_val(self.props())
+    }
+
+    fn write_to(&self, wtr: &mut Writer) {
+        self.config().write_to(wtr);
+        wtr.usize(self.props().len());
+        for props in self.props().iter() {
+            props.write_to(wtr);
+        }
+        self.props_union().write_to(wtr);
+    }
+
+    fn read_from(rdr: &mut Reader<'_>) -> Result<RegexInfo, DeserializeError> {
+        let config = Config::read_from(rdr)?;
+        // Every pattern's properties occupy more than 8 bytes.
+        let len = rdr.len(8, "pattern count")?;
+        if len > PatternID::LIMIT {
+            return Err(DeserializeError::generic("too many patterns"));
+        }
+        let mut props = Vec::with_capacity(len);
+        for _ in 0..len {
+            props.push(Props::read_from(rdr)?);
+        }
+        let props_union = Props::read_from(rdr)?;
+        Ok(RegexInfo(Arc::new(RegexInfoI { config, props, props_union })))
     }
 
     /// Returns true when the search is guaranteed to be anchored. That is,
@@ -2007,6 +2799,11 @@ impl RegexInfo {
         {
             return true;
         }
+        // Edits can make a match shorter or longer than anything the regex
+        // can match on its own, so the length checks below don't apply.
+        if self.config().is_approximate() {
+            return false;
+        }
         // If the haystack is smaller than the minimum length required, then
         // we know there can be no match.
         let minlen = match self.props_union().minimum_len() {
@@ -2036,6 +2833,103 @@ impl RegexInfo {
     }
 }
 
+/// The properties of a regex pattern that the meta regex engine uses.
+///
+/// This is the subset of `hir::Properties` that we need after building a
+/// regex. We copy it out into our own type so that it can be serialized along
+/// with the rest of a `Regex`.
+#[derive(Clone, Debug)]
+pub(crate) struct Props {
+    explicit_captures_len: usize,
+    static_explicit_captures_len: Option<usize>,
+    look_set: hir::LookSet,
+    look_set_prefix: hir::LookSet,
+    look_set_suffix: hir::LookSet,
+    minimum_len: Option<usize>,
+    maximum_len: Option<usize>,
+    is_alternation_literal: bool,
+}
+
+impl Props {
+    fn new(props: &hir::Properties) -> Props {
+        Props {
+            explicit_captures_len: props.explicit_captures_len(),
+            static_explicit_captures_len: props.static_explicit_captures_len(),
+            look_set: props.look_set(),
+            look_set_prefix: props.look_set_prefix(),
+            look_set_suffix: props.look_set_suffix(),
+            minimum_len: props.minimum_len(),
+            maximum_len: props.maximum_len(),
+            is_alternation_literal: props.is_alternation_literal(),
+        }
+    }
+
+    /// See `hir::Properties::explicit_captures_len`.
+    pub(crate) fn explicit_captures_len(&self) -> usize {
+        self.explicit_captures_len
+    }
+
+    /// See `hir::Properties::static_explicit_captures_len`.
+    pub(crate) fn static_explicit_captures_len(&self) -> Option<usize> {
+        self.static_explicit_captures_len
+    }
+
+    /// See `hir::Properties::look_set`.
+    pub(crate) fn look_set(&self) -> hir::LookSet {
+        self.look_set
+    }
+
+    /// See `hir::Properties::look_set_prefix`.
+    pub(crate) fn look_set_prefix(&self) -> hir::LookSet {
+        self.look_set_prefix
+    }
+
+    /// See `hir::Properties::look_set_suffix`.
+    pub(crate) fn look_set_suffix(&self) -> hir::LookSet {
+        self.look_set_suffix
+    }
+
+    /// See `hir::Properties::minimum_len`.
+    pub(crate) fn minimum_len(&self) -> Option<usize> {
+        self.minimum_len
+    }
+
+    /// See `hir::Properties::maximum_len`.
+    pub(crate) fn maximum_len(&self) -> Option<usize> {
+        self.maximum_len
+    }
+
+    /// See `hir::Properties::is_alternation_literal`.
+    pub(crate) fn is_alternation_literal(&self) -> bool {
+        self.is_alternation_literal
+    }
+
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.usize(self.explicit_captures_len);
+        wtr.option_usize(self.static_explicit_captures_len);
+        wtr.u32(self.look_set.bits);
+        wtr.u32(self.look_set_prefix.bits);
+        wtr.u32(self.look_set_suffix.bits);
+        wtr.option_usize(self.minimum_len);
+        wtr.option_usize(self.maximum_len);
+        wtr.bool(self.is_alternation_literal);
+    }
+
+    fn read_from(rdr: &mut Reader<'_>) -> Result<Props, DeserializeError> {
+        Ok(Props {
+            explicit_captures_len: rdr.usize("explicit captures length")?,
+            static_explicit_captures_len: rdr
+                .option_usize("static explicit captures length")?,
+            look_set: hir::LookSet { bits: rdr.u32("look set")? },
+            look_set_prefix: hir::LookSet { bits: rdr.u32("look set")? },
+            look_set_suffix: hir::LookSet { bits: rdr.u32("look set")? },
+            minimum_len: rdr.option_usize("minimum length")?,
+            maximum_len: rdr.option_usize("maximum length")?,
+            is_alternation_literal: rdr.bool("alternation literal")?,
+        })
+    }
+}
+
 /// An iterator over all non-overlapping matches.
 ///
 /// The iterator yields a [`Match`] value until no more matches could be found.
@@ -2097,6 +2991,150 @@ impl<'r, 'h> Iterator for FindMatches<'r, 'h> {
 
 impl<'r, 'h> core::iter::FusedIterator for FindMatches<'r, 'h> {}
 
+/// An iterator over all non-overlapping matches, in reverse order.
+///
+/// The iterator yields a [`Match`] value until no more matches could be found.
+///
+/// The lifetime parameters are as follows:
+///
+/// * `'r` represents the lifetime of the `Regex` that produced this iterator.
+/// * `'h` represents the lifetime of the haystack being searched.
+///
+/// This iterator can be created with the [`Regex::rfind_iter`] method.
+#[derive(Debug)]
+pub struct RFindMatches<'r, 'h> {
+    re: &'r Regex,
+    cache: CachePoolGuard<'r>,
+    input: Input<'h>,
+    /// The start of the last match yielded, if any.
+    last_start: Option<usize>,
+}
+
+impl<'r, 'h> RFindMatches<'r, 'h> {
+    /// Returns the `Regex` value that created this iterator.
+    #[inline]
+    pub fn regex(&self) -> &'r Regex {
+        self.re
+    }
+
+    /// Returns the current `Input` associated with this iterator.
+    ///
+    /// The `end` position on the given `Input` may change during iteration,
+    /// but all other values are guaranteed to remain invariant.
+    #[inline]
+    pub fn input<'s>(&'s self) -> &'s Input<'h> {
+        &self.input
+    }
+}
+
+impl<'r, 'h> Iterator for RFindMatches<'r, 'h> {
+    type Item = Match;
+
+    #[inline]
+    fn next(&mut self) -> Option<Match> {
+        let RFindMatches {
+            re,
+            ref mut cache,
+            ref mut input,
+            ref mut last_start,
+        } = *self;
+        loop {
+            let m = re.rsearch_with(cache, input)?;
+            // This mirrors how forward iteration handles an empty match that
+            // ends where the previous match did: we skip it and search again,
+            // just without the position at which it was found. Since every
+            // match ends at or before the end of 'input', an empty match at
+            // the start of the previous match is at the end of 'input'.
+            if m.is_empty() && Some(m.start()) == *last_start {
+                if input.end() == input.start() {
+                    return None;
+                }
+                input.set_end(input.end() - 1);
+                continue;
+            }
+            *last_start = Some(m.start());
+            input.set_end(m.start());
+            return Some(m);
+        }
+    }
+}
+
+impl<'r, 'h> core::iter::FusedIterator for RFindMatches<'r, 'h> {}
+
+/// An iterator over all non-overlapping leftmost matches in a haystack made
+/// up of chunks.
+///
+/// The iterator yields a [`Match`] value until no more matches could be found.
+///
+/// The lifetime parameters are as follows:
+///
+/// * `'r` represents the lifetime of the `Regex` that produced this iterator.
+/// * `'h` represents the lifetime of the chunks being searched.
+///
+/// This iterator can be created with the [`Regex::find_chunks_iter`] method.
+#[derive(Debug)]
+pub struct ChunkMatches<'r, 'h> {
+    re: &'r Regex,
+    cache: CachePoolGuard<'r>,
+    chunks: Chunks<'h>,
+    /// The position at which to start the next search, or `None` once the
+    /// iterator is exhausted.
+    at: Option<usize>,
+    /// The end of the last match yielded, if any.
+    last_end: Option<usize>,
+}
+
+impl<'r, 'h> ChunkMatches<'r, 'h> {
+    /// Returns the `Regex` value that created this iterator.
+    #[inline]
+    pub fn regex(&self) -> &'r Regex {
+        self.re
+    }
+
+    /// Returns the haystack being searched by this iterator.
+    #[inline]
+    pub fn chunks<'s>(&'s self) -> &'s Chunks<'h> {
+        &self.chunks
+    }
+}
+
+impl<'r, 'h> Iterator for ChunkMatches<'r, 'h> {
+    type Item = Match;
+
+    #[inline]
+    fn next(&mut self) -> Option<Match> {
+        let ChunkMatches {
+            re,
+            ref mut cache,
+            ref chunks,
+            ref mut at,
+            ref mut last_end,
+        } = *self;
+        loop {
+            let start = (*at)?;
+            let m = match re.search_chunks_with(cache, chunks, start) {
+                None => {
+                    *at = None;
+                    return None;
+                }
+                Some(m) => m,
+            };
+            // Just like 'util::iter::Searcher', we never yield an empty match
+            // that ends where the previous match did. Instead, we look for a
+            // match starting at the next position.
+            if m.is_empty() && Some(m.end()) == *last_end {
+                *at = m.end().checked_add(1).filter(|&at| at <= chunks.end());
+                continue;
+            }
+            *at = Some(m.end());
+            *last_end = Some(m.end());
+            return Some(m);
+        }
+    }
+}
+
+impl<'r, 'h> core::iter::FusedIterator for ChunkMatches<'r, 'h> {}
+
 /// An iterator over all non-overlapping leftmost matches with their capturing
 /// groups.
 ///
@@ -2332,6 +3370,8 @@ pub struct Cache {
     pub(crate) onepass: wrappers::OnePassCache,
     pub(crate) hybrid: wrappers::HybridCache,
     pub(crate) revhybrid: wrappers::ReverseHybridCache,
+    pub(crate) revpikevm: wrappers::ReversePikeVMCache,
+    pub(crate) backref: wrappers::BackrefCache,
 }
 
 impl Cache {
@@ -2396,6 +3436,8 @@ impl Cache {
         bytes += self.onepass.memory_usage();
         bytes += self.hybrid.memory_usage();
         bytes += self.revhybrid.memory_usage();
+        bytes += self.revpikevm.memory_usage();
+        bytes += self.backref.memory_usage();
         bytes
     }
 }
@@ -2449,6 +3491,9 @@ pub struct Config {
     backtrack: Option<bool>,
     byte_classes: Option<bool>,
     line_terminator: Option<u8>,
+    max_edits: Option<Option<usize>>,
+    backreferences: Option<bool>,
+    backref_step_limit: Option<Option<usize>>,
 }
 
 impl Config {
@@ -2973,6 +4018,56 @@ impl Config {
         Config { line_terminator: Some(byte), ..self }
     }
 
+    /// Enable approximate matching with the given edit budget.
+    ///
+    /// When set to `Some(k)` with `k > 0`, a match is reported for any
+    /// substring of the haystack that can be turned into a string matched by
+    /// the regex with at most `k` edits, where an edit is the insertion,
+    /// deletion or substitution of a single byte. Which match is reported is
+    /// determined by the configured [match semantics](Config::match_kind),
+    /// where taking an edit always has a lower priority than matching a byte
+    /// exactly. With leftmost semantics, this means the leftmost match within
+    /// the budget is reported, even if a match with fewer edits starts later.
+    ///
+    /// Since edits are counted in bytes, a match may split a UTF-8 encoded
+    /// codepoint, even when the pattern itself can only match valid UTF-8.
+    ///
+    /// Approximate matching is only supported by the
+    /// [`PikeVM`](crate::nfa::thompson::pikevm::PikeVM). So when this
+    /// is enabled, all other regex engines and all prefilters are disabled
+    /// regardless of their configuration. Searches still take time linear in
+    /// the size of the haystack, but they will be considerably slower than
+    /// exact searches. See [`pikevm::Config::max_edits`] for more details.
+    ///
+    /// This is disabled by default, which is equivalent to `Some(0)`.
+    ///
+    /// [`pikevm::Config::max_edits`]: crate::nfa::thompson::pikevm::Config::max_edits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// let re = Regex::builder()
+    ///     .configure(Regex::config().max_edits(Some(2)))
+    ///     .build(r"(?<first>\w+) (?<last>Smith)")?;
+    /// let mut caps = re.create_captures();
+    ///
+    /// re.captures("Jane Smyth", &mut caps);
+    /// assert_eq!(Some(Match::must(0, 0..10)), caps.get_match());
+    /// let first = caps.get_group_by_name("first").map(|span| span.range());
+    /// assert_eq!(Some(0..4), first);
+    ///
+    /// // The leftmost match wins, even when a later one is exact.
+    /// let hay = "Jane Smyth, John Smith";
+    /// assert_eq!(Some(Match::must(0, 0..10)), re.find(hay));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_edits(self, limit: Option<usize>) -> Config {
+        Config { max_edits: Some(limit), ..self }
+    }
+
     /// Toggle whether the hybrid NFA/DFA (also known as the "lazy DFA") should
     /// be available for use by the meta regex engine.
     ///
@@ -3031,6 +4126,72 @@ impl Config {
         Config { backtrack: Some(yes), ..self }
     }
 
+    /// Toggle support for backreferences, like `\1` or `\k<name>`.
+    ///
+    /// When enabled, patterns given to [`Builder::build`] and
+    /// [`Builder::build_many`] may contain backreferences. (See
+    /// [`syntax::Config::backreferences`] for the syntax.) Any regex that
+    /// actually contains a backreference is then executed by the
+    /// [backreference backtracker](crate::nfa::thompson::backref), and none
+    /// of the other regex engines or prefilters are used. Regexes without
+    /// backreferences are unaffected by this option.
+    ///
+    /// Unlike every other regex engine in this crate, the backreference
+    /// backtracker may take exponential time. So every search is bounded by
+    /// a [step limit](Config::backref_step_limit). If a search exceeds it,
+    /// then the fallible search routines like [`Regex::try_search`] return
+    /// an error, and the infallible routines like [`Regex::find`] panic.
+    ///
+    /// Backreferences require all capturing groups to be tracked, so building
+    /// a regex with backreferences fails if [`Config::which_captures`] is
+    /// anything other than [`WhichCaptures::All`]. Leftmost searches always
+    /// use leftmost-first match semantics, regardless of
+    /// [`Config::match_kind`].
+    ///
+    /// When the `nfa-backref` crate feature is enabled, then this is disabled
+    /// by default. Otherwise, if the crate feature is disabled, then this is
+    /// always disabled, regardless of its setting by the caller. It is also
+    /// always disabled when [approximate matching](Config::max_edits) is
+    /// enabled.
+    ///
+    /// [`syntax::Config::backreferences`]: crate::util::syntax::Config::backreferences
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{meta::Regex, Match};
+    ///
+    /// # if !cfg!(feature = "nfa-backref") { return Ok(()); }
+    /// // Backreferences are a syntax error by default.
+    /// assert!(Regex::new(r"(\w+) \1").is_err());
+    ///
+    /// let re = Regex::builder()
+    ///     .configure(Regex::config().backreferences(true))
+    ///     .build(r"(\w+) \1")?;
+    /// let hay = "Paris in the the spring";
+    /// assert_eq!(Some(Match::must(0, 9..16)), re.find(hay));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn backreferences(self, yes: bool) -> Config {
+        Config { backreferences: Some(yes), ..self }
+    }
+
+    /// Set the maximum number of steps that a single search may take when
+    /// the regex uses [backreferences](Config::backreferences).
+    ///
+    /// When set to `None`, searches may take an unbounded number of steps.
+    /// See [`backref::Config::step_limit`] for more details.
+    ///
+    /// This has no effect on regexes without backreferences.
+    ///
+    /// The default is `Some(10_000_000)`.
+    ///
+    /// [`backref::Config::step_limit`]: crate::nfa::thompson::backref::Config::step_limit
+    pub fn backref_step_limit(self, limit: Option<usize>) -> Config {
+        Config { backref_step_limit: Some(limit), ..self }
+    }
+
     /// Returns the match kind on this configuration, as set by
     /// [`Config::match_kind`].
     ///
@@ -3144,14 +4305,30 @@ impl Config {
         self.line_terminator.unwrap_or(b'\n')
     }
 
+    /// Returns the edit budget for approximate matching, as set by
+    /// [`Config::max_edits`].
+    ///
+    /// If it was not explicitly set, then a default value is returned.
+    pub fn get_max_edits(&self) -> Option<usize> {
+        self.max_edits.unwrap_or(None)
+    }
+
+    /// Returns true when approximate matching is enabled, i.e., when the edit
+    /// budget is non-zero.
+    pub(crate) fn is_approximate(&self) -> bool {
+        self.get_max_edits().map_or(false, |k| k > 0)
+    }
+
     /// Returns whether the hybrid NFA/DFA regex engine may be used, as set by
     /// [`Config::hybrid`].
     ///
-    /// If it was not explicitly set, then a default value is returned.
+    /// If it was not explicitly set, then a default value is returned. This
+    /// always returns false when [approximate matching](Config::max_edits)
+    /// is enabled.
     pub fn get_hybrid(&self) -> bool {
         #[cfg(feature = "hybrid")]
         {
-            self.hybrid.unwrap_or(true)
+            self.hybrid.unwrap_or(true) && !self.is_approximate()
         }
         #[cfg(not(feature = "hybrid"))]
         {
@@ -3162,11 +4339,13 @@ impl Config {
     /// Returns whether the DFA regex engine may be used, as set by
     /// [`Config::dfa`].
     ///
-    /// If it was not explicitly set, then a default value is returned.
+    /// If it was not explicitly set, then a default value is returned. This
+    /// always returns false when [approximate matching](Config::max_edits)
+    /// is enabled.
     pub fn get_dfa(&self) -> bool {
         #[cfg(feature = "dfa-build")]
         {
-            self.dfa.unwrap_or(true)
+            self.dfa.unwrap_or(true) && !self.is_approximate()
         }
         #[cfg(not(feature = "dfa-build"))]
         {
@@ -3177,11 +4356,13 @@ impl Config {
     /// Returns whether the one-pass DFA regex engine may be used, as set by
     /// [`Config::onepass`].
     ///
-    /// If it was not explicitly set, then a default value is returned.
+    /// If it was not explicitly set, then a default value is returned. This
+    /// always returns false when [approximate matching](Config::max_edits)
+    /// is enabled.
     pub fn get_onepass(&self) -> bool {
         #[cfg(feature = "dfa-onepass")]
         {
-            self.onepass.unwrap_or(true)
+            self.onepass.unwrap_or(true) && !self.is_approximate()
         }
         #[cfg(not(feature = "dfa-onepass"))]
         {
@@ -3192,11 +4373,13 @@ impl Config {
     /// Returns whether the bounded backtracking regex engine may be used, as
     /// set by [`Config::backtrack`].
     ///
-    /// If it was not explicitly set, then a default value is returned.
+    /// If it was not explicitly set, then a default value is returned. This
+    /// always returns false when [approximate matching](Config::max_edits)
+    /// is enabled.
     pub fn get_backtrack(&self) -> bool {
         #[cfg(feature = "nfa-backtrack")]
         {
-            self.backtrack.unwrap_or(true)
+            self.backtrack.unwrap_or(true) && !self.is_approximate()
         }
         #[cfg(not(feature = "nfa-backtrack"))]
         {
@@ -3204,6 +4387,31 @@ impl Config {
         }
     }
 
+    /// Returns whether backreferences are supported, as set by
+    /// [`Config::backreferences`].
+    ///
+    /// If it was not explicitly set, then a default value is returned. This
+    /// always returns false when [approximate matching](Config::max_edits)
+    /// is enabled.
+    pub fn get_backreferences(&self) -> bool {
+        #[cfg(feature = "nfa-backref")]
+        {
+            self.backreferences.unwrap_or(false) && !self.is_approximate()
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            false
+        }
+    }
+
+    /// Returns the step limit for searches with backreferences, as set by
+    /// [`Config::backref_step_limit`].
+    ///
+    /// If it was not explicitly set, then a default value is returned.
+    pub fn get_backref_step_limit(&self) -> Option<usize> {
+        self.backref_step_limit.unwrap_or(Some(10_000_000))
+    }
+
     /// Overwrite the default configuration such that the options in `o` are
     /// always used. If an option in `o` is not set, then the corresponding
     /// option in `self` is used. If it's not set in `self` either, then it
@@ -3230,7 +4438,155 @@ impl Config {
             backtrack: o.backtrack.or(self.backtrack),
             byte_classes: o.byte_classes.or(self.byte_classes),
             line_terminator: o.line_terminator.or(self.line_terminator),
+            max_edits: o.max_edits.or(self.max_edits),
+            backreferences: o.backreferences.or(self.backreferences),
+            backref_step_limit: o
+                .backref_step_limit
+                .or(self.backref_step_limit),
+        }
+    }
+
+    /// Serialize this configuration. Options that haven't been set are
+    /// recorded as such, so that the deserialized configuration is exactly
+    /// the same as this one.
+    fn write_to(&self, wtr: &mut Writer) {
+        fn opt_bool(wtr: &mut Writer, yes: Option<bool>) {
+            wtr.u8(yes.map_or(0, |yes| 1 + u8::from(yes)));
+        }
+        fn opt_limit(wtr: &mut Writer, limit: Option<Option<usize>>) {
+            wtr.bool(limit.is_some());
+            wtr.option_usize(limit.flatten());
+        }
+
+        wtr.u8(match self.match_kind {
+            None => 0,
+            Some(MatchKind::All) => 1,
+            Some(MatchKind::LeftmostFirst) => 2,
+        });
+        opt_bool(wtr, self.utf8_empty);
+        opt_bool(wtr, self.autopre);
+        match self.pre {
+            None => wtr.u8(0),
+            Some(None) => wtr.u8(1),
+            Some(Some(ref pre)) => {
+                wtr.u8(2);
+                pre.write_to(wtr);
+            }
+        }
+        wtr.u8(match self.which_captures {
+            None => 0,
+            Some(WhichCaptures::All) => 1,
+            Some(WhichCaptures::Implicit) => 2,
+            Some(WhichCaptures::None) => 3,
+        });
+        opt_limit(wtr, self.nfa_size_limit);
+        opt_limit(wtr, self.onepass_size_limit);
+        wtr.option_usize(self.hybrid_cache_capacity);
+        opt_bool(wtr, self.hybrid);
+        opt_bool(wtr, self.dfa);
+        opt_limit(wtr, self.dfa_size_limit);
+        opt_limit(wtr, self.dfa_state_limit);
+        opt_bool(wtr, self.onepass);
+        opt_bool(wtr, self.backtrack);
+        opt_bool(wtr, self.byte_classes);
+        wtr.bool(self.line_terminator.is_some());
+        wtr.u8(self.line_terminator.unwrap_or(0));
+        opt_limit(wtr, self.max_edits);
+        opt_bool(wtr, self.backreferences);
+        opt_limit(wtr, self.backref_step_limit);
+    }
+
+    /// Deserialize a configuration written by `Config::write_to`.
+    fn read_from(rdr: &mut Reader<'_>) -> Result<Config, DeserializeError> {
+        fn opt_bool(
+            rdr: &mut Reader<'_>,
+        ) -> Result<Option<bool>, DeserializeError> {
+            match rdr.u8("config option")? {
+                0 => Ok(None),
+                1 => Ok(Some(false)),
+                2 => Ok(Some(true)),
+                _ => Err(DeserializeError::generic("invalid config option")),
+            }
         }
+        fn opt_limit(
+            rdr: &mut Reader<'_>,
+        ) -> Result<Option<Option<usize>>, DeserializeError> {
+            let is_set = rdr.bool("config limit")?;
+            let limit = rdr.option_usize("config limit")?;
+            Ok(if is_set { Some(limit) } else { None })
+        }
+
+        let match_kind = match rdr.u8("match kind")? {
+            0 => None,
+            1 => Some(MatchKind::All),
+            2 => Some(MatchKind::LeftmostFirst),
+            _ => return Err(DeserializeError::generic("invalid match kind")),
+        };
+        let utf8_empty = opt_bool(rdr)?;
+        let autopre = opt_bool(rdr)?;
+        let pre = match rdr.u8("prefilter option")? {
+            0 => None,
+            1 => Some(None),
+            // If the prefilter can't be rebuilt, then we just record that
+            // none is used, which is always correct.
+            2 => Some(Prefilter::read_from(rdr)?),
+            _ => {
+                return Err(DeserializeError::generic(
+                    "invalid prefilter option",
+                ))
+            }
+        };
+        let which_captures = match rdr.u8("which captures")? {
+            0 => None,
+            1 => Some(WhichCaptures::All),
+            2 => Some(WhichCaptures::Implicit),
+            3 => Some(WhichCaptures::None),
+            _ => {
+                return Err(DeserializeError::generic(
+                    "invalid which captures option",
+                ))
+            }
+        };
+        let nfa_size_limit = opt_limit(rdr)?;
+        let onepass_size_limit = opt_limit(rdr)?;
+        let hybrid_cache_capacity = rdr.option_usize("cache capacity")?;
+        let hybrid = opt_bool(rdr)?;
+        let dfa = opt_bool(rdr)?;
+        let dfa_size_limit = opt_limit(rdr)?;
+        let dfa_state_limit = opt_limit(rdr)?;
+        let onepass = opt_bool(rdr)?;
+        let backtrack = opt_bool(rdr)?;
+        let byte_classes = opt_bool(rdr)?;
+        let has_line_terminator = rdr.bool("line terminator")?;
+        let line_terminator = rdr.u8("line terminator")?;
+        let max_edits = opt_limit(rdr)?;
+        let backreferences = opt_bool(rdr)?;
+        let backref_step_limit = opt_limit(rdr)?;
+        Ok(Config {
+            match_kind,
+            utf8_empty,
+            autopre,
+            pre,
+            which_captures,
+            nfa_size_limit,
+            onepass_size_limit,
+            hybrid_cache_capacity,
+            hybrid,
+            dfa,
+            dfa_size_limit,
+            dfa_state_limit,
+            onepass,
+            backtrack,
+            byte_classes,
+            line_terminator: if has_line_terminator {
+                Some(line_terminator)
+            } else {
+                None
+            },
+            max_edits,
+            backreferences,
+            backref_step_limit,
+        })
     }
 }
 
@@ -3419,10 +4775,13 @@ impl Builder {
                 }
             }
         }
+        let mut ast = self.ast.clone();
+        if self.config.get_backreferences() {
+            ast.backreferences(true);
+        }
         let (mut asts, mut hirs) = (vec![], vec![]);
         for (pid, p) in patterns.iter().with_pattern_ids() {
-            let ast = self
-                .ast
+            let ast = ast
                 .build()
                 .parse(p.as_ref())
                 .map_err(|err| BuildError::ast(pid, err))?;
@@ -170,8 +170,13 @@ fn top_concat(mut hir: &Hir) -> Option<Vec<Hir>> {
             | HirKind::Literal(_)
             | HirKind::Class(_)
             | HirKind::Look(_)
+            | HirKind::LookAround(_)
             | HirKind::Repetition(_)
-            | HirKind::Alternation(_) => return None,
+            | HirKind::Alternation(_)
+            | HirKind::Intersection(_)
+            | HirKind::Complement(_)
+            | HirKind::Backreference(_)
+            | HirKind::Atomic(_) => return None,
             HirKind::Capture(hir::Capture { ref sub, .. }) => sub,
             HirKind::Concat(ref subs) => {
                 // We are careful to only do the flattening/copy when we know
@@ -206,7 +211,14 @@ fn flatten(hir: &Hir) -> Hir {
         HirKind::Literal(hir::Literal(ref x)) => Hir::literal(x.clone()),
         HirKind::Class(ref x) => Hir::class(x.clone()),
         HirKind::Look(ref x) => Hir::look(x.clone()),
+        // Capturing groups are never permitted inside of look-around, so
+        // there is nothing to remove.
+        HirKind::LookAround(ref x) => Hir::look_around(x.clone()),
+        // Regexes with backreferences are never searched with a reverse
+        // inner strategy, so keeping the (now dangling) group index is fine.
+        HirKind::Backreference(index) => Hir::backreference(*index),
         HirKind::Repetition(ref x) => Hir::repetition(x.with(flatten(&x.sub))),
+        HirKind::Atomic(ref x) => Hir::atomic(flatten(x)),
         // This is the interesting case. We just drop the group information
         // entirely and use the child HIR itself.
         HirKind::Capture(hir::Capture { ref sub, .. }) => flatten(sub),
@@ -216,5 +228,8 @@ fn flatten(hir: &Hir) -> Hir {
         HirKind::Concat(ref xs) => {
             Hir::concat(xs.iter().map(|x| flatten(x)).collect())
         }
+        // Like look-around, capturing groups are never permitted inside of
+        // intersections or complements.
+        HirKind::Intersection(_) | HirKind::Complement(_) => hir.clone(),
     }
 }
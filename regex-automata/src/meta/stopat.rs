@@ -50,7 +50,7 @@ use crate::{meta::error::RetryFailError, HalfMatch, Input, MatchError};
 
 #[cfg(feature = "dfa-build")]
 pub(crate) fn dfa_try_search_half_fwd(
-    dfa: &crate::dfa::dense::DFA<alloc::vec::Vec<u32>>,
+    dfa: &crate::meta::wrappers::DenseDFA,
     input: &Input<'_>,
 ) -> Result<Result<HalfMatch, usize>, RetryFailError> {
     use crate::dfa::{accel, Automaton};
@@ -141,7 +141,7 @@ pub(crate) fn hybrid_try_search_half_fwd(
 #[cfg(feature = "dfa-build")]
 #[cfg_attr(feature = "perf-inline", inline(always))]
 fn dfa_eoi_fwd(
-    dfa: &crate::dfa::dense::DFA<alloc::vec::Vec<u32>>,
+    dfa: &crate::meta::wrappers::DenseDFA,
     input: &Input<'_>,
     sid: &mut crate::util::primitives::StateID,
     mat: &mut Option<HalfMatch>,
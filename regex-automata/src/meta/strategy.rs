@@ -3,9 +3,9 @@ use core::{
     panic::{RefUnwindSafe, UnwindSafe},
 };
 
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec, vec::Vec};
 
-use regex_syntax::hir::{literal, Hir};
+use regex_syntax::hir::{literal, Hir, HirKind};
 
 use crate::{
     meta::{
@@ -16,10 +16,15 @@ use crate::{
     nfa::thompson::{self, WhichCaptures, NFA},
     util::{
         captures::{Captures, GroupInfo},
+        chunks::Chunks,
         look::LookMatcher,
         prefilter::{self, Prefilter, PrefilterI},
         primitives::{NonMaxUsize, PatternID},
-        search::{Anchored, HalfMatch, Input, Match, MatchKind, PatternSet},
+        search::{
+            Anchored, HalfMatch, Input, Match, MatchError, MatchKind,
+            PatternSet, Span,
+        },
+        wire::{DeserializeError, Reader, Writer},
     },
 };
 
@@ -73,16 +78,146 @@ pub(super) trait Strategy:
         input: &Input<'_>,
         patset: &mut PatternSet,
     );
+
+    /// Returns the last match in the given input, as described by
+    /// `Regex::rsearch`.
+    ///
+    /// The default implementation only uses forward searches, and runs one
+    /// for every starting position in the worst case. Strategies that have a
+    /// reverse lazy DFA override it with something much faster.
+    fn rsearch(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
+        rsearch_fallback(self, cache, input)
+    }
+
+    /// Returns the leftmost match in the given haystack made up of chunks,
+    /// starting at the absolute offset `start`, as described by
+    /// `Regex::search_chunks`.
+    ///
+    /// The default implementation copies the haystack into a contiguous
+    /// buffer and searches that. Strategies that can search the chunks
+    /// directly override it.
+    fn search_chunks(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        search_chunks_fallback(self, cache, chunks, start)
+    }
+
+    /// Like `search`, but returns an error instead of panicking when the
+    /// search fails. Only strategies whose searches can fail need to override
+    /// this.
+    ///
+    /// Since the infallible search never looks at the cancellation flag, this
+    /// checks it once up front, so that a search whose flag is already set
+    /// always reports an error regardless of strategy.
+    fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
+        if input.is_cancelled() {
+            return Err(MatchError::cancelled(input.start()));
+        }
+        Ok(self.search(cache, input))
+    }
+
+    /// Like `search_slots`, but returns an error instead of panicking when the
+    /// search fails. Only strategies whose searches can fail need to override
+    /// this.
+    ///
+    /// Like `try_search`, this checks the cancellation flag once up front.
+    fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        if input.is_cancelled() {
+            return Err(MatchError::cancelled(input.start()));
+        }
+        Ok(self.search_slots(cache, input, slots))
+    }
+
+    /// Serialize this strategy such that it can be rebuilt by `read_from`
+    /// without the original patterns.
+    fn write_to(&self, wtr: &mut Writer);
+}
+
+/// Deserialize a strategy written by `Strategy::write_to` for a regex with
+/// the given info.
+///
+/// Strategies are rebuilt from the pieces that are expensive to compute
+/// (NFAs, literals and fully compiled DFAs). The regex engines built from
+/// those pieces cheaply (like the PikeVM or the one-pass DFA) are rebuilt
+/// from scratch.
+///
+/// Some strategies are only optimizations that rely on regex engines (like
+/// full DFAs or particular prefilters) that may be unavailable in this build
+/// of the crate. In that case, we fall back to the core strategy. If even
+/// that isn't possible, then an error is returned.
+pub(super) fn read_from(
+    info: &RegexInfo,
+    rdr: &mut Reader<'_>,
+) -> Result<Arc<dyn Strategy>, DeserializeError> {
+    let strat: Arc<dyn Strategy> = match rdr.u8("meta strategy")? {
+        STRATEGY_PRE => Pre::read_from(info, PreKind::Choice, rdr)?,
+        STRATEGY_PRE_AHO_CORASICK => {
+            Pre::read_from(info, PreKind::AhoCorasick, rdr)?
+        }
+        STRATEGY_CORE => Arc::new(Core::read_from(info, rdr)?),
+        STRATEGY_REVERSE_ANCHORED => {
+            let core = Core::read_from(info, rdr)?;
+            match ReverseAnchored::new(core) {
+                Ok(ra) => Arc::new(ra),
+                Err(core) => Arc::new(core),
+            }
+        }
+        STRATEGY_REVERSE_SUFFIX => ReverseSuffix::read_from(info, rdr)?,
+        STRATEGY_REVERSE_INNER => ReverseInner::read_from(info, rdr)?,
+        STRATEGY_BACKREF => Arc::new(Backref::read_from(info, rdr)?),
+        _ => {
+            return Err(DeserializeError::generic(
+                "unrecognized meta strategy",
+            ))
+        }
+    };
+    Ok(strat)
 }
 
+// The tags used to identify each strategy in its serialized form.
+const STRATEGY_PRE: u8 = 0;
+const STRATEGY_PRE_AHO_CORASICK: u8 = 1;
+const STRATEGY_CORE: u8 = 2;
+const STRATEGY_REVERSE_ANCHORED: u8 = 3;
+const STRATEGY_REVERSE_SUFFIX: u8 = 4;
+const STRATEGY_REVERSE_INNER: u8 = 5;
+const STRATEGY_BACKREF: u8 = 6;
+
 pub(super) fn new(
     info: &RegexInfo,
     hirs: &[&Hir],
 ) -> Result<Arc<dyn Strategy>, BuildError> {
+    // Backreferences can only be handled by one regex engine, so there is
+    // nothing else to choose. In particular, prefilters are skipped, since
+    // literal extraction can't see through backreferences anyway.
+    if info.config().get_backreferences()
+        && hirs.iter().any(|hir| has_backreference(hir))
+    {
+        debug!("using backreference strategy");
+        return Ok(Arc::new(Backref::new(info.clone(), hirs)?));
+    }
     // At this point, we're committed to a regex engine of some kind. So pull
     // out a prefilter if we can, which will feed to each of the constituent
     // regex engines.
-    let pre = if info.is_always_anchored_start() {
+    let pre = if info.config().is_approximate() {
+        // Prefilters (and literal matchers in general) only know how to find
+        // exact occurrences of literals, which approximate matches need not
+        // contain.
+        debug!("skipping literal extraction since matching is approximate");
+        None
+    } else if info.is_always_anchored_start() {
         // PERF: I'm not sure we necessarily want to do this... We may want to
         // run a prefilter for quickly rejecting in some cases. The problem
         // is that anchored searches overlap quite a bit with the use case
@@ -189,16 +324,30 @@ pub(super) fn new(
 struct Pre<P> {
     pre: P,
     group_info: GroupInfo,
+    /// How `pre` was chosen. This, along with `lits`, is only used to
+    /// serialize this strategy.
+    kind: PreKind,
+    /// The literals that `pre` searches for.
+    lits: Arc<[Vec<u8>]>,
+}
+
+/// How the prefilter for a `Pre` strategy was chosen from its literals.
+#[derive(Clone, Copy, Debug)]
+enum PreKind {
+    /// The prefilter was chosen by `prefilter::Choice::new`.
+    Choice,
+    /// Aho-Corasick was used unconditionally.
+    AhoCorasick,
 }
 
 impl<P: PrefilterI> Pre<P> {
-    fn new(pre: P) -> Arc<dyn Strategy> {
+    fn new(pre: P, kind: PreKind, lits: Arc<[Vec<u8>]>) -> Arc<dyn Strategy> {
         // The only thing we support when we use prefilters directly as a
         // strategy is the start and end of the overall match for a single
         // pattern. In other words, exactly one implicit capturing group. Which
         // is exactly what we use here for a GroupInfo.
         let group_info = GroupInfo::new([[None::<&str>]]).unwrap();
-        Arc::new(Pre { pre, group_info })
+        Arc::new(Pre { pre, group_info, kind, lits })
     }
 }
 
@@ -283,23 +432,43 @@ impl Pre<()> {
             prefixes.len(),
             prefixes,
         );
-        let choice = match prefilter::Choice::new(kind, prefixes) {
-            Some(choice) => choice,
-            None => {
-                debug!(
-                    "regex bypass failed because no prefilter could be built"
-                );
-                return None;
+        let lits = prefixes.iter().map(|lit| lit.as_bytes().to_vec());
+        let strat = Pre::from_literals(PreKind::Choice, lits.collect());
+        if strat.is_none() {
+            debug!("regex bypass failed because no prefilter could be built");
+        }
+        strat
+    }
+
+    /// Build a strategy that searches for the given literals with leftmost
+    /// first semantics, where the prefilter used is chosen according to
+    /// `kind`.
+    ///
+    /// This returns `None` if no such prefilter could be built.
+    fn from_literals(
+        kind: PreKind,
+        lits: Arc<[Vec<u8>]>,
+    ) -> Option<Arc<dyn Strategy>> {
+        use crate::util::prefilter::AhoCorasick;
+
+        let choice = match kind {
+            PreKind::Choice => {
+                prefilter::Choice::new(MatchKind::LeftmostFirst, &lits[..])?
+            }
+            PreKind::AhoCorasick => {
+                let ac =
+                    AhoCorasick::new(MatchKind::LeftmostFirst, &lits[..])?;
+                return Some(Pre::new(ac, kind, lits));
             }
         };
         let strat: Arc<dyn Strategy> = match choice {
-            prefilter::Choice::Memchr(pre) => Pre::new(pre),
-            prefilter::Choice::Memchr2(pre) => Pre::new(pre),
-            prefilter::Choice::Memchr3(pre) => Pre::new(pre),
-            prefilter::Choice::Memmem(pre) => Pre::new(pre),
-            prefilter::Choice::Teddy(pre) => Pre::new(pre),
-            prefilter::Choice::ByteSet(pre) => Pre::new(pre),
-            prefilter::Choice::AhoCorasick(pre) => Pre::new(pre),
+            prefilter::Choice::Memchr(pre) => Pre::new(pre, kind, lits),
+            prefilter::Choice::Memchr2(pre) => Pre::new(pre, kind, lits),
+            prefilter::Choice::Memchr3(pre) => Pre::new(pre, kind, lits),
+            prefilter::Choice::Memmem(pre) => Pre::new(pre, kind, lits),
+            prefilter::Choice::Teddy(pre) => Pre::new(pre, kind, lits),
+            prefilter::Choice::ByteSet(pre) => Pre::new(pre, kind, lits),
+            prefilter::Choice::AhoCorasick(pre) => Pre::new(pre, kind, lits),
         };
         Some(strat)
     }
@@ -315,11 +484,34 @@ impl Pre<()> {
         info: &RegexInfo,
         hirs: &[&Hir],
     ) -> Option<Arc<dyn Strategy>> {
-        use crate::util::prefilter::AhoCorasick;
-
         let lits = crate::meta::literal::alternation_literals(info, hirs)?;
-        let ac = AhoCorasick::new(MatchKind::LeftmostFirst, &lits)?;
-        Some(Pre::new(ac))
+        Pre::from_literals(PreKind::AhoCorasick, lits.into())
+    }
+
+    /// Deserialize the literals written by `Pre::write_to` and rebuild the
+    /// strategy from them.
+    fn read_from(
+        info: &RegexInfo,
+        kind: PreKind,
+        rdr: &mut Reader<'_>,
+    ) -> Result<Arc<dyn Strategy>, DeserializeError> {
+        if info.pattern_len() != 1 {
+            return Err(DeserializeError::generic(
+                "literal strategy requires exactly one pattern",
+            ));
+        }
+        // Every literal occupies at least the 8 bytes used for its length.
+        let len = rdr.len(8, "literal count")?;
+        let mut lits = Vec::with_capacity(len);
+        for _ in 0..len {
+            lits.push(rdr.bytes("literal")?.to_vec());
+        }
+        Pre::from_literals(kind, lits.into()).ok_or_else(|| {
+            DeserializeError::generic(
+                "could not build literal searcher, \
+                 perhaps because crate features are disabled",
+            )
+        })
     }
 }
 
@@ -366,6 +558,8 @@ impl<P: PrefilterI> Strategy for Pre<P> {
             onepass: wrappers::OnePassCache::none(),
             hybrid: wrappers::HybridCache::none(),
             revhybrid: wrappers::ReverseHybridCache::none(),
+            revpikevm: wrappers::ReversePikeVMCache::none(),
+            backref: wrappers::BackrefCache::none(),
         }
     }
 
@@ -437,6 +631,60 @@ impl<P: PrefilterI> Strategy for Pre<P> {
             patset.insert(PatternID::ZERO);
         }
     }
+
+    fn search_chunks(
+        &self,
+        _cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        // Every match is one of our literals, so a match can only cross the
+        // end of a chunk if it starts less than 'maxlen' bytes before it. So
+        // we search each chunk on its own, and search a copy of the bytes
+        // around the end of each chunk for matches that start close to it.
+        let maxlen = self.lits.iter().map(|lit| lit.len()).max().unwrap_or(0);
+        let to_match = |offset: usize, sp: Span| {
+            Match::new(PatternID::ZERO, offset + sp.start..offset + sp.end)
+        };
+        let mut seam = Vec::new();
+        for (offset, bytes) in chunks.range(start, chunks.end()) {
+            let end = offset + bytes.len();
+            let span = Span::from(0..bytes.len());
+            if let Some(sp) = self.pre.find(bytes, span) {
+                // If every literal fits after the start of this match, then
+                // it's the same match we'd find in the entire haystack.
+                if sp.start + maxlen <= bytes.len() || end == chunks.end() {
+                    return Some(to_match(offset, sp));
+                }
+            }
+            let reach = maxlen.saturating_sub(1);
+            let lo = core::cmp::max(offset, end.saturating_sub(reach));
+            let hi = core::cmp::min(chunks.end(), end.saturating_add(reach));
+            seam.clear();
+            chunks.copy_range(lo, hi, &mut seam);
+            if let Some(sp) = self.pre.find(&seam, Span::from(0..seam.len())) {
+                if lo + sp.start < end {
+                    return Some(to_match(lo, sp));
+                }
+            }
+        }
+        // The only match we could have missed is an empty one at the end of
+        // the haystack, when the search starts there.
+        self.pre
+            .find(&[], Span::from(0..0))
+            .map(|sp| to_match(chunks.end(), sp))
+    }
+
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(match self.kind {
+            PreKind::Choice => STRATEGY_PRE,
+            PreKind::AhoCorasick => STRATEGY_PRE_AHO_CORASICK,
+        });
+        wtr.usize(self.lits.len());
+        for lit in self.lits.iter() {
+            wtr.bytes(lit);
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -446,6 +694,7 @@ struct Core {
     nfa: NFA,
     nfarev: Option<NFA>,
     pikevm: wrappers::PikeVM,
+    pikevmrev: wrappers::ReversePikeVM,
     backtrack: wrappers::BoundedBacktracker,
     onepass: wrappers::OnePass,
     hybrid: wrappers::Hybrid,
@@ -458,14 +707,7 @@ impl Core {
         pre: Option<Prefilter>,
         hirs: &[&Hir],
     ) -> Result<Core, BuildError> {
-        let mut lookm = LookMatcher::new();
-        lookm.set_line_terminator(info.config().get_line_terminator());
-        let thompson_config = thompson::Config::new()
-            .utf8(info.config().get_utf8_empty())
-            .nfa_size_limit(info.config().get_nfa_size_limit())
-            .shrink(false)
-            .which_captures(info.config().get_which_captures())
-            .look_matcher(lookm);
+        let thompson_config = thompson_config(&info);
         let nfa = thompson::Compiler::new()
             .configure(thompson_config.clone())
             .build_many_from_hir(hirs)
@@ -490,55 +732,176 @@ impl Core {
         // we know we aren't going to use the lazy DFA. So we do a config check
         // up front, which is in practice the only way we won't try to use the
         // DFA.
-        let (nfarev, hybrid, dfa) =
-            if !info.config().get_hybrid() && !info.config().get_dfa() {
-                (None, wrappers::Hybrid::none(), wrappers::DFA::none())
+        // Atomic groups can't be simulated by a DFA, and they can't be
+        // compiled into a reverse NFA either. So there's no point in trying.
+        // Similarly, DFAs can't handle segmentation boundaries at all.
+        let (nfarev, hybrid, dfa) = if (!info.config().get_hybrid()
+            && !info.config().get_dfa())
+            || nfa.has_atomic()
+            || nfa.look_set_any().contains_segment()
+        {
+            (None, wrappers::Hybrid::none(), wrappers::DFA::none())
+        } else {
+            // FIXME: Technically, we don't quite yet KNOW that we need
+            // a reverse NFA. It's possible for the DFAs below to both
+            // fail to build just based on the forward NFA. In which case,
+            // building the reverse NFA was totally wasted work. But...
+            // fixing this requires breaking DFA construction apart into
+            // two pieces: one for the forward part and another for the
+            // reverse part. Quite annoying. Making it worse, when building
+            // both DFAs fails, it's quite likely that the NFA is large and
+            // that it will take quite some time to build the reverse NFA
+            // too. So... it's really probably worth it to do this!
+            let nfarev = thompson::Compiler::new()
+                // Currently, reverse NFAs don't support capturing groups,
+                // so we MUST disable them. But even if we didn't have to,
+                // we would, because nothing in this crate does anything
+                // useful with capturing groups in reverse. And of course,
+                // the lazy DFA ignores capturing groups in all cases.
+                .configure(
+                    thompson_config
+                        .clone()
+                        .which_captures(WhichCaptures::None)
+                        .reverse(true),
+                )
+                .build_many_from_hir(hirs)
+                .map_err(BuildError::nfa)?;
+            let dfa = if !info.config().get_dfa() {
+                wrappers::DFA::none()
             } else {
-                // FIXME: Technically, we don't quite yet KNOW that we need
-                // a reverse NFA. It's possible for the DFAs below to both
-                // fail to build just based on the forward NFA. In which case,
-                // building the reverse NFA was totally wasted work. But...
-                // fixing this requires breaking DFA construction apart into
-                // two pieces: one for the forward part and another for the
-                // reverse part. Quite annoying. Making it worse, when building
-                // both DFAs fails, it's quite likely that the NFA is large and
-                // that it will take quite some time to build the reverse NFA
-                // too. So... it's really probably worth it to do this!
-                let nfarev = thompson::Compiler::new()
-                    // Currently, reverse NFAs don't support capturing groups,
-                    // so we MUST disable them. But even if we didn't have to,
-                    // we would, because nothing in this crate does anything
-                    // useful with capturing groups in reverse. And of course,
-                    // the lazy DFA ignores capturing groups in all cases.
-                    .configure(
-                        thompson_config
-                            .clone()
-                            .which_captures(WhichCaptures::None)
-                            .reverse(true),
-                    )
-                    .build_many_from_hir(hirs)
-                    .map_err(BuildError::nfa)?;
-                let dfa = if !info.config().get_dfa() {
-                    wrappers::DFA::none()
-                } else {
-                    wrappers::DFA::new(&info, pre.clone(), &nfa, &nfarev)
-                };
-                let hybrid = if !info.config().get_hybrid() {
-                    wrappers::Hybrid::none()
-                } else if dfa.is_some() {
-                    debug!("skipping lazy DFA because we have a full DFA");
-                    wrappers::Hybrid::none()
-                } else {
-                    wrappers::Hybrid::new(&info, pre.clone(), &nfa, &nfarev)
-                };
-                (Some(nfarev), hybrid, dfa)
+                wrappers::DFA::new(&info, pre.clone(), &nfa, &nfarev)
+            };
+            let hybrid = if !info.config().get_hybrid() {
+                wrappers::Hybrid::none()
+            } else if dfa.is_some() {
+                debug!("skipping lazy DFA because we have a full DFA");
+                wrappers::Hybrid::none()
+            } else {
+                wrappers::Hybrid::new(&info, pre.clone(), &nfa, &nfarev)
             };
+            (Some(nfarev), hybrid, dfa)
+        };
+        let pikevmrev = match nfarev {
+            Some(ref nfarev) => wrappers::ReversePikeVM::new(&info, nfarev),
+            None => wrappers::ReversePikeVM::none(),
+        };
+        Ok(Core {
+            info,
+            pre,
+            nfa,
+            nfarev,
+            pikevm,
+            pikevmrev,
+            backtrack,
+            onepass,
+            hybrid,
+            dfa,
+        })
+    }
+
+    /// Runs a reverse search for the start of a match with the reverse NFA.
+    ///
+    /// This uses a DFA when one is available (the full DFA only supports
+    /// anchored searches), and falls back to the reverse PikeVM when none is
+    /// or when the DFA gives up. Either way, the search is linear in the size
+    /// of the haystack.
+    ///
+    /// This must only be called when the reverse PikeVM is available.
+    fn search_half_rev(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Option<HalfMatch> {
+        let anchored = input.get_anchored().is_anchored();
+        let result = if let Some(e) = self.dfa.get(input).filter(|_| anchored)
+        {
+            Some(e.try_search_half_rev(input))
+        } else if let Some(e) = self.hybrid.get(input) {
+            Some(e.try_search_half_rev(&mut cache.hybrid, input))
+        } else {
+            None
+        };
+        match result {
+            None => {}
+            Some(Ok(hm)) => return hm,
+            Some(Err(_err)) => {
+                trace!("DFA reverse search failed: {}", _err);
+            }
+        }
+        trace!(
+            "using reverse PikeVM for reverse search at {:?}",
+            input.get_span()
+        );
+        expect_search(
+            self.pikevmrev.try_search_half_rev(&mut cache.revpikevm, input),
+        )
+    }
+
+    /// Deserialize a core engine written by `Core::write_to`.
+    ///
+    /// This mirrors `Core::new`, except the NFAs and full DFAs are read
+    /// instead of compiled.
+    fn read_from(
+        info: &RegexInfo,
+        rdr: &mut Reader<'_>,
+    ) -> Result<Core, DeserializeError> {
+        let info = info.clone();
+        let pre = if rdr.bool("prefilter")? {
+            // If the prefilter can't be rebuilt, then we just go without
+            // one. It's only an optimization.
+            Prefilter::read_from(rdr)?
+        } else {
+            None
+        };
+        let nfa = NFA::read_from(rdr)?;
+        let nfarev = if rdr.bool("reverse NFA")? {
+            Some(NFA::read_from(rdr)?)
+        } else {
+            None
+        };
+        if nfa.is_reverse()
+            || nfa.pattern_len() != info.pattern_len()
+            || nfarev.as_ref().map_or(false, |nfarev| {
+                !nfarev.is_reverse()
+                    || nfarev.pattern_len() != info.pattern_len()
+            })
+        {
+            return Err(DeserializeError::generic(
+                "NFAs do not match their meta regex",
+            ));
+        }
+        let dfa = wrappers::DFA::read_from(&info, pre.clone(), rdr)?;
+        let pikevm =
+            wrappers::PikeVM::new(&info, pre.clone(), &nfa).map_err(|_| {
+                DeserializeError::generic("could not build PikeVM")
+            })?;
+        let backtrack =
+            wrappers::BoundedBacktracker::new(&info, pre.clone(), &nfa)
+                .map_err(|_| {
+                    DeserializeError::generic(
+                        "could not build bounded backtracker",
+                    )
+                })?;
+        let onepass = wrappers::OnePass::new(&info, &nfa);
+        let hybrid = match nfarev {
+            Some(ref nfarev)
+                if info.config().get_hybrid() && !dfa.is_some() =>
+            {
+                wrappers::Hybrid::new(&info, pre.clone(), &nfa, nfarev)
+            }
+            _ => wrappers::Hybrid::none(),
+        };
+        let pikevmrev = match nfarev {
+            Some(ref nfarev) => wrappers::ReversePikeVM::new(&info, nfarev),
+            None => wrappers::ReversePikeVM::none(),
+        };
         Ok(Core {
             info,
             pre,
             nfa,
             nfarev,
             pikevm,
+            pikevmrev,
             backtrack,
             onepass,
             hybrid,
@@ -546,6 +909,21 @@ impl Core {
         })
     }
 
+    /// Serialize the parts of this core engine that are needed to rebuild
+    /// it. The other regex engines are rebuilt from these.
+    fn write_parts(&self, wtr: &mut Writer) {
+        wtr.bool(self.pre.is_some());
+        if let Some(ref pre) = self.pre {
+            pre.write_to(wtr);
+        }
+        self.nfa.write_to(wtr);
+        wtr.bool(self.nfarev.is_some());
+        if let Some(ref nfarev) = self.nfarev {
+            nfarev.write_to(wtr);
+        }
+        self.dfa.write_to(wtr);
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn try_search_mayfail(
         &self,
@@ -563,11 +941,16 @@ impl Core {
         }
     }
 
+    // The "nofail" routines below use regex engines that can't fail in a
+    // way that warrants retrying the search with a different engine. The only
+    // error they can return is a cancellation of the search, which is
+    // reported to the caller as-is.
+
     fn search_nofail(
         &self,
         cache: &mut Cache,
         input: &Input<'_>,
-    ) -> Option<Match> {
+    ) -> Result<Option<Match>, MatchError> {
         let caps = &mut cache.capmatches;
         caps.set_pattern(None);
         // We manually inline 'try_search_slots_nofail' here because we need to
@@ -578,33 +961,33 @@ impl Core {
         // mutability), but that's more annoying than this IMO.
         let pid = if let Some(ref e) = self.onepass.get(input) {
             trace!("using OnePass for search at {:?}", input.get_span());
-            e.search_slots(&mut cache.onepass, input, caps.slots_mut())
+            e.search_slots(&mut cache.onepass, input, caps.slots_mut())?
         } else if let Some(ref e) = self.backtrack.get(input) {
             trace!(
                 "using BoundedBacktracker for search at {:?}",
                 input.get_span()
             );
-            e.search_slots(&mut cache.backtrack, input, caps.slots_mut())
+            e.search_slots(&mut cache.backtrack, input, caps.slots_mut())?
         } else {
             trace!("using PikeVM for search at {:?}", input.get_span());
             let e = self.pikevm.get();
-            e.search_slots(&mut cache.pikevm, input, caps.slots_mut())
+            e.search_slots(&mut cache.pikevm, input, caps.slots_mut())?
         };
         caps.set_pattern(pid);
-        caps.get_match()
+        Ok(caps.get_match())
     }
 
     fn search_half_nofail(
         &self,
         cache: &mut Cache,
         input: &Input<'_>,
-    ) -> Option<HalfMatch> {
+    ) -> Result<Option<HalfMatch>, MatchError> {
         // Only the lazy/full DFA returns half-matches, since the DFA requires
         // a reverse scan to find the start position. These fallback regex
         // engines can find the start and end in a single pass, so we just do
         // that and throw away the start offset to conform to the API.
         let m = self.search_nofail(cache, input)?;
-        Some(HalfMatch::new(m.pattern(), m.end()))
+        Ok(m.map(|m| HalfMatch::new(m.pattern(), m.end())))
     }
 
     fn search_slots_nofail(
@@ -612,7 +995,7 @@ impl Core {
         cache: &mut Cache,
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
-    ) -> Option<PatternID> {
+    ) -> Result<Option<PatternID>, MatchError> {
         if let Some(ref e) = self.onepass.get(input) {
             trace!(
                 "using OnePass for capture search at {:?}",
@@ -635,13 +1018,18 @@ impl Core {
         }
     }
 
-    fn is_match_nofail(&self, cache: &mut Cache, input: &Input<'_>) -> bool {
+    fn is_match_nofail(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<bool, MatchError> {
         if let Some(ref e) = self.onepass.get(input) {
             trace!(
                 "using OnePass for is-match search at {:?}",
                 input.get_span()
             );
-            e.search_slots(&mut cache.onepass, input, &mut []).is_some()
+            let pid = e.search_slots(&mut cache.onepass, input, &mut [])?;
+            Ok(pid.is_some())
         } else if let Some(ref e) = self.backtrack.get(input) {
             trace!(
                 "using BoundedBacktracker for is-match search at {:?}",
@@ -678,6 +1066,8 @@ impl Strategy for Core {
             onepass: self.onepass.create_cache(),
             hybrid: self.hybrid.create_cache(),
             revhybrid: wrappers::ReverseHybridCache::none(),
+            revpikevm: wrappers::ReversePikeVMCache::none(),
+            backref: wrappers::BackrefCache::none(),
         }
     }
 
@@ -687,6 +1077,7 @@ impl Strategy for Core {
         cache.backtrack.reset(&self.backtrack);
         cache.onepass.reset(&self.onepass);
         cache.hybrid.reset(&self.hybrid);
+        cache.revpikevm.reset();
     }
 
     fn is_accelerated(&self) -> bool {
@@ -704,29 +1095,7 @@ impl Strategy for Core {
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn search(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
-        // We manually inline try_search_mayfail here because letting the
-        // compiler do it seems to produce pretty crappy codegen.
-        return if let Some(e) = self.dfa.get(input) {
-            trace!("using full DFA for full search at {:?}", input.get_span());
-            match e.try_search(input) {
-                Ok(x) => x,
-                Err(_err) => {
-                    trace!("full DFA search failed: {}", _err);
-                    self.search_nofail(cache, input)
-                }
-            }
-        } else if let Some(e) = self.hybrid.get(input) {
-            trace!("using lazy DFA for full search at {:?}", input.get_span());
-            match e.try_search(&mut cache.hybrid, input) {
-                Ok(x) => x,
-                Err(_err) => {
-                    trace!("lazy DFA search failed: {}", _err);
-                    self.search_nofail(cache, input)
-                }
-            }
-        } else {
-            self.search_nofail(cache, input)
-        };
+        expect_search(self.try_search(cache, input))
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -744,7 +1113,7 @@ impl Strategy for Core {
                 Ok(x) => x,
                 Err(_err) => {
                     trace!("full DFA half search failed: {}", _err);
-                    self.search_half_nofail(cache, input)
+                    expect_search(self.search_half_nofail(cache, input))
                 }
             }
         } else if let Some(e) = self.hybrid.get(input) {
@@ -753,11 +1122,11 @@ impl Strategy for Core {
                 Ok(x) => x,
                 Err(_err) => {
                     trace!("lazy DFA half search failed: {}", _err);
-                    self.search_half_nofail(cache, input)
+                    expect_search(self.search_half_nofail(cache, input))
                 }
             }
         } else {
-            self.search_half_nofail(cache, input)
+            expect_search(self.search_half_nofail(cache, input))
         }
     }
 
@@ -772,7 +1141,7 @@ impl Strategy for Core {
                 Ok(x) => x.is_some(),
                 Err(_err) => {
                     trace!("full DFA half search failed: {}", _err);
-                    self.is_match_nofail(cache, input)
+                    expect_search(self.is_match_nofail(cache, input))
                 }
             }
         } else if let Some(e) = self.hybrid.get(input) {
@@ -784,11 +1153,11 @@ impl Strategy for Core {
                 Ok(x) => x.is_some(),
                 Err(_err) => {
                     trace!("lazy DFA half search failed: {}", _err);
-                    self.is_match_nofail(cache, input)
+                    expect_search(self.is_match_nofail(cache, input))
                 }
             }
         } else {
-            self.is_match_nofail(cache, input)
+            expect_search(self.is_match_nofail(cache, input))
         }
     }
 
@@ -799,6 +1168,47 @@ impl Strategy for Core {
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
     ) -> Option<PatternID> {
+        expect_search(self.try_search_slots(cache, input, slots))
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
+        // We manually inline try_search_mayfail here because letting the
+        // compiler do it seems to produce pretty crappy codegen.
+        return if let Some(e) = self.dfa.get(input) {
+            trace!("using full DFA for full search at {:?}", input.get_span());
+            match e.try_search(input) {
+                Ok(x) => Ok(x),
+                Err(_err) => {
+                    trace!("full DFA search failed: {}", _err);
+                    self.search_nofail(cache, input)
+                }
+            }
+        } else if let Some(e) = self.hybrid.get(input) {
+            trace!("using lazy DFA for full search at {:?}", input.get_span());
+            match e.try_search(&mut cache.hybrid, input) {
+                Ok(x) => Ok(x),
+                Err(_err) => {
+                    trace!("lazy DFA search failed: {}", _err);
+                    self.search_nofail(cache, input)
+                }
+            }
+        } else {
+            self.search_nofail(cache, input)
+        };
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
         // Even if the regex has explicit capture groups, if the caller didn't
         // provide any explicit slots, then it doesn't make sense to try and do
         // extra work to get offsets for those slots. Ideally the caller should
@@ -806,9 +1216,12 @@ impl Strategy for Core {
         // we try to save the caller from themselves if they do.
         if !self.is_capture_search_needed(slots.len()) {
             trace!("asked for slots unnecessarily, trying fast path");
-            let m = self.search(cache, input)?;
+            let m = match self.try_search(cache, input)? {
+                None => return Ok(None),
+                Some(m) => m,
+            };
             copy_match_to_slots(m, slots);
-            return Some(m.pattern());
+            return Ok(Some(m.pattern()));
         }
         // If the onepass DFA is available for this search (which only happens
         // when it's anchored), then skip running a fallible DFA. The onepass
@@ -828,7 +1241,7 @@ impl Strategy for Core {
         }
         let m = match self.try_search_mayfail(cache, input) {
             Some(Ok(Some(m))) => m,
-            Some(Ok(None)) => return None,
+            Some(Ok(None)) => return Ok(None),
             Some(Err(_err)) => {
                 trace!("fast capture search failed: {}", _err);
                 return self.search_slots_nofail(cache, input, slots);
@@ -851,10 +1264,13 @@ impl Strategy for Core {
             .clone()
             .span(m.start()..m.end())
             .anchored(Anchored::Pattern(m.pattern()));
-        Some(
-            self.search_slots_nofail(cache, &input, slots)
-                .expect("should find a match"),
-        )
+        let pid = self.search_slots_nofail(cache, &input, slots)?;
+        Ok(Some(pid.expect("should find a match")))
+    }
+
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(STRATEGY_CORE);
+        self.write_parts(wtr);
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -896,7 +1312,97 @@ impl Strategy for Core {
             input.get_span()
         );
         let e = self.pikevm.get();
-        e.which_overlapping_matches(&mut cache.pikevm, input, patset)
+        expect_search(e.which_overlapping_matches(
+            &mut cache.pikevm,
+            input,
+            patset,
+        ))
+    }
+
+    fn rsearch(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
+        if input.get_anchored().is_anchored() {
+            return self.search(cache, input);
+        }
+        if !self.pikevmrev.is_some() {
+            return rsearch_fallback(self, cache, input);
+        }
+        // The reverse engines use 'MatchKind::All' semantics, so the first
+        // match they see when scanning backwards is the last position at
+        // which any match starts.
+        let revinput = input.clone().earliest(true);
+        let start = self.search_half_rev(cache, &revinput)?.offset();
+        let fwdinput =
+            input.clone().span(start..input.end()).anchored(Anchored::Yes);
+        let m = self
+            .search(cache, &fwdinput)
+            .expect("reverse match implies there must be a forward match");
+        // Now find the earliest start of any match ending where 'm' does.
+        // A forward search from that position doesn't necessarily end there
+        // too, but it usually does, so this bounds how far back we need to
+        // look before confirming it with forward searches.
+        let revinput =
+            input.clone().span(input.start()..m.end()).anchored(Anchored::Yes);
+        let min_start = self
+            .search_half_rev(cache, &revinput)
+            .expect("forward match implies reverse match")
+            .offset();
+        Some(rsearch_extend(self, cache, input, min_start, m))
+    }
+
+    fn search_chunks(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        let utf8empty = self.nfa.has_empty() && self.nfa.is_utf8();
+        // The wrappers only use an 'Input' to decide whether an engine can
+        // be used for a particular search, which neither DFA cares about.
+        let input = Input::new("");
+        let mut start = start;
+        loop {
+            let result = if let Some(e) = self.dfa.get(&input) {
+                trace!("using full DFA for chunked search at {:?}", start);
+                e.try_search_chunks(chunks, start)
+            } else if let Some(e) = self.hybrid.get(&input) {
+                trace!("using lazy DFA for chunked search at {:?}", start);
+                e.try_search_chunks(&mut cache.hybrid, chunks, start)
+            } else {
+                break;
+            };
+            match result {
+                Ok(None) => return None,
+                Ok(Some(m)) => {
+                    // This mirrors 'empty::skip_splits_fwd'. Only an empty
+                    // match can split a codepoint, and the only way to get
+                    // past one is to look for a match starting at the next
+                    // position.
+                    if !utf8empty || chunks.is_char_boundary(m.end()) {
+                        return Some(m);
+                    }
+                    start += 1;
+                }
+                Err(_err) => {
+                    trace!("DFA chunked search failed: {}", _err);
+                    break;
+                }
+            }
+        }
+        let e = self.pikevm.get();
+        if !e.supports_chunks() {
+            return search_chunks_fallback(self, cache, chunks, start);
+        }
+        trace!("using PikeVM for chunked search at {:?}", start);
+        let caps = &mut cache.capmatches;
+        caps.set_pattern(None);
+        let pid = e.search_chunks_slots(
+            &mut cache.pikevm,
+            chunks,
+            start,
+            caps.slots_mut(),
+        );
+        caps.set_pattern(pid);
+        caps.get_match()
     }
 }
 
@@ -1007,19 +1513,7 @@ impl Strategy for ReverseAnchored {
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn search(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
-        if input.get_anchored().is_anchored() {
-            return self.core.search(cache, input);
-        }
-        match self.try_search_half_anchored_rev(cache, input) {
-            Err(_err) => {
-                trace!("fast reverse anchored search failed: {}", _err);
-                self.core.search_nofail(cache, input)
-            }
-            Ok(None) => None,
-            Ok(Some(hm)) => {
-                Some(Match::new(hm.pattern(), hm.offset()..input.end()))
-            }
-        }
+        expect_search(self.try_search(cache, input))
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -1034,7 +1528,7 @@ impl Strategy for ReverseAnchored {
         match self.try_search_half_anchored_rev(cache, input) {
             Err(_err) => {
                 trace!("fast reverse anchored search failed: {}", _err);
-                self.core.search_half_nofail(cache, input)
+                expect_search(self.core.search_half_nofail(cache, input))
             }
             Ok(None) => None,
             Ok(Some(hm)) => {
@@ -1057,7 +1551,7 @@ impl Strategy for ReverseAnchored {
         match self.try_search_half_anchored_rev(cache, input) {
             Err(_err) => {
                 trace!("fast reverse anchored search failed: {}", _err);
-                self.core.is_match_nofail(cache, input)
+                expect_search(self.core.is_match_nofail(cache, input))
             }
             Ok(None) => false,
             Ok(Some(_)) => true,
@@ -1071,32 +1565,68 @@ impl Strategy for ReverseAnchored {
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
     ) -> Option<PatternID> {
+        expect_search(self.try_search_slots(cache, input, slots))
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
         if input.get_anchored().is_anchored() {
-            return self.core.search_slots(cache, input, slots);
+            return self.core.try_search(cache, input);
         }
         match self.try_search_half_anchored_rev(cache, input) {
             Err(_err) => {
                 trace!("fast reverse anchored search failed: {}", _err);
-                self.core.search_slots_nofail(cache, input, slots)
+                self.core.search_nofail(cache, input)
             }
-            Ok(None) => None,
+            Ok(None) => Ok(None),
             Ok(Some(hm)) => {
-                if !self.core.is_capture_search_needed(slots.len()) {
-                    trace!("asked for slots unnecessarily, skipping captures");
-                    let m = Match::new(hm.pattern(), hm.offset()..input.end());
-                    copy_match_to_slots(m, slots);
-                    return Some(m.pattern());
-                }
-                let start = hm.offset();
-                let input = input
-                    .clone()
-                    .span(start..input.end())
+                Ok(Some(Match::new(hm.pattern(), hm.offset()..input.end())))
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        if input.get_anchored().is_anchored() {
+            return self.core.try_search_slots(cache, input, slots);
+        }
+        match self.try_search_half_anchored_rev(cache, input) {
+            Err(_err) => {
+                trace!("fast reverse anchored search failed: {}", _err);
+                self.core.search_slots_nofail(cache, input, slots)
+            }
+            Ok(None) => Ok(None),
+            Ok(Some(hm)) => {
+                if !self.core.is_capture_search_needed(slots.len()) {
+                    trace!("asked for slots unnecessarily, skipping captures");
+                    let m = Match::new(hm.pattern(), hm.offset()..input.end());
+                    copy_match_to_slots(m, slots);
+                    return Ok(Some(m.pattern()));
+                }
+                let start = hm.offset();
+                let input = input
+                    .clone()
+                    .span(start..input.end())
                     .anchored(Anchored::Pattern(hm.pattern()));
                 self.core.search_slots_nofail(cache, &input, slots)
             }
         }
     }
 
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(STRATEGY_REVERSE_ANCHORED);
+        self.core.write_parts(wtr);
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn which_overlapping_matches(
         &self,
@@ -1110,6 +1640,21 @@ impl Strategy for ReverseAnchored {
         // I'm currently focus more on the single pattern case.
         self.core.which_overlapping_matches(cache, input, patset)
     }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn rsearch(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
+        self.core.rsearch(cache, input)
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn search_chunks(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        self.core.search_chunks(cache, chunks, start)
+    }
 }
 
 #[derive(Debug)]
@@ -1207,6 +1752,31 @@ impl ReverseSuffix {
         Ok(ReverseSuffix { core, pre })
     }
 
+    /// Deserialize a reverse suffix strategy written by
+    /// `ReverseSuffix::write_to`.
+    ///
+    /// If the suffix prefilter can't be rebuilt, or if there is no DFA to do
+    /// reverse searches with, then this falls back to the core engine.
+    fn read_from(
+        info: &RegexInfo,
+        rdr: &mut Reader<'_>,
+    ) -> Result<Arc<dyn Strategy>, DeserializeError> {
+        let core = Core::read_from(info, rdr)?;
+        let pre = Prefilter::read_from(rdr)?;
+        match pre {
+            Some(pre) if core.hybrid.is_some() || core.dfa.is_some() => {
+                Ok(Arc::new(ReverseSuffix { core, pre }))
+            }
+            _ => {
+                debug!(
+                    "falling back to core engine because the reverse suffix \
+                     optimization can't be used with this build"
+                );
+                Ok(Arc::new(core))
+            }
+        }
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn try_search_half_start(
         &self,
@@ -1319,45 +1889,7 @@ impl Strategy for ReverseSuffix {
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn search(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
-        if input.get_anchored().is_anchored() {
-            return self.core.search(cache, input);
-        }
-        match self.try_search_half_start(cache, input) {
-            Err(RetryError::Quadratic(_err)) => {
-                trace!("reverse suffix optimization failed: {}", _err);
-                self.core.search(cache, input)
-            }
-            Err(RetryError::Fail(_err)) => {
-                trace!("reverse suffix reverse fast search failed: {}", _err);
-                self.core.search_nofail(cache, input)
-            }
-            Ok(None) => None,
-            Ok(Some(hm_start)) => {
-                let fwdinput = input
-                    .clone()
-                    .anchored(Anchored::Pattern(hm_start.pattern()))
-                    .span(hm_start.offset()..input.end());
-                match self.try_search_half_fwd(cache, &fwdinput) {
-                    Err(_err) => {
-                        trace!(
-                            "reverse suffix forward fast search failed: {}",
-                            _err
-                        );
-                        self.core.search_nofail(cache, input)
-                    }
-                    Ok(None) => {
-                        unreachable!(
-                            "suffix match plus reverse match implies \
-						     there must be a match",
-                        )
-                    }
-                    Ok(Some(hm_end)) => Some(Match::new(
-                        hm_start.pattern(),
-                        hm_start.offset()..hm_end.offset(),
-                    )),
-                }
-            }
-        }
+        expect_search(self.try_search(cache, input))
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -1379,7 +1911,7 @@ impl Strategy for ReverseSuffix {
                     "reverse suffix reverse fast half search failed: {}",
                     _err
                 );
-                self.core.search_half_nofail(cache, input)
+                expect_search(self.core.search_half_nofail(cache, input))
             }
             Ok(None) => None,
             Ok(Some(hm_start)) => {
@@ -1402,7 +1934,9 @@ impl Strategy for ReverseSuffix {
                             "reverse suffix forward fast search failed: {}",
                             _err
                         );
-                        self.core.search_half_nofail(cache, input)
+                        expect_search(
+                            self.core.search_half_nofail(cache, input),
+                        )
                     }
                     Ok(None) => {
                         unreachable!(
@@ -1424,14 +1958,14 @@ impl Strategy for ReverseSuffix {
         match self.try_search_half_start(cache, input) {
             Err(RetryError::Quadratic(_err)) => {
                 trace!("reverse suffix half optimization failed: {}", _err);
-                self.core.is_match_nofail(cache, input)
+                expect_search(self.core.is_match_nofail(cache, input))
             }
             Err(RetryError::Fail(_err)) => {
                 trace!(
                     "reverse suffix reverse fast half search failed: {}",
                     _err
                 );
-                self.core.is_match_nofail(cache, input)
+                expect_search(self.core.is_match_nofail(cache, input))
             }
             Ok(None) => false,
             Ok(Some(_)) => true,
@@ -1445,14 +1979,74 @@ impl Strategy for ReverseSuffix {
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
     ) -> Option<PatternID> {
+        expect_search(self.try_search_slots(cache, input, slots))
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
+        if input.get_anchored().is_anchored() {
+            return self.core.try_search(cache, input);
+        }
+        match self.try_search_half_start(cache, input) {
+            Err(RetryError::Quadratic(_err)) => {
+                trace!("reverse suffix optimization failed: {}", _err);
+                self.core.try_search(cache, input)
+            }
+            Err(RetryError::Fail(_err)) => {
+                trace!("reverse suffix reverse fast search failed: {}", _err);
+                self.core.search_nofail(cache, input)
+            }
+            Ok(None) => Ok(None),
+            Ok(Some(hm_start)) => {
+                let fwdinput = input
+                    .clone()
+                    .anchored(Anchored::Pattern(hm_start.pattern()))
+                    .span(hm_start.offset()..input.end());
+                match self.try_search_half_fwd(cache, &fwdinput) {
+                    Err(_err) => {
+                        trace!(
+                            "reverse suffix forward fast search failed: {}",
+                            _err
+                        );
+                        self.core.search_nofail(cache, input)
+                    }
+                    Ok(None) => {
+                        unreachable!(
+                            "suffix match plus reverse match implies \
+						     there must be a match",
+                        )
+                    }
+                    Ok(Some(hm_end)) => Ok(Some(Match::new(
+                        hm_start.pattern(),
+                        hm_start.offset()..hm_end.offset(),
+                    ))),
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
         if input.get_anchored().is_anchored() {
-            return self.core.search_slots(cache, input, slots);
+            return self.core.try_search_slots(cache, input, slots);
         }
         if !self.core.is_capture_search_needed(slots.len()) {
             trace!("asked for slots unnecessarily, trying fast path");
-            let m = self.search(cache, input)?;
+            let m = match self.try_search(cache, input)? {
+                None => return Ok(None),
+                Some(m) => m,
+            };
             copy_match_to_slots(m, slots);
-            return Some(m.pattern());
+            return Ok(Some(m.pattern()));
         }
         let hm_start = match self.try_search_half_start(cache, input) {
             Err(RetryError::Quadratic(_err)) => {
@@ -1460,7 +2054,7 @@ impl Strategy for ReverseSuffix {
                     "reverse suffix captures optimization failed: {}",
                     _err
                 );
-                return self.core.search_slots(cache, input, slots);
+                return self.core.try_search_slots(cache, input, slots);
             }
             Err(RetryError::Fail(_err)) => {
                 trace!(
@@ -1469,7 +2063,7 @@ impl Strategy for ReverseSuffix {
                 );
                 return self.core.search_slots_nofail(cache, input, slots);
             }
-            Ok(None) => return None,
+            Ok(None) => return Ok(None),
             Ok(Some(hm_start)) => hm_start,
         };
         trace!(
@@ -1486,6 +2080,12 @@ impl Strategy for ReverseSuffix {
         self.core.search_slots_nofail(cache, &input, slots)
     }
 
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(STRATEGY_REVERSE_SUFFIX);
+        self.core.write_parts(wtr);
+        self.pre.write_to(wtr);
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn which_overlapping_matches(
         &self,
@@ -1495,6 +2095,21 @@ impl Strategy for ReverseSuffix {
     ) {
         self.core.which_overlapping_matches(cache, input, patset)
     }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn rsearch(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
+        self.core.rsearch(cache, input)
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn search_chunks(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        self.core.search_chunks(cache, chunks, start)
+    }
 }
 
 #[derive(Debug)]
@@ -1622,6 +2237,52 @@ impl ReverseInner {
         Ok(ReverseInner { core, preinner, nfarev, hybrid, dfa })
     }
 
+    /// Deserialize a reverse inner strategy written by
+    /// `ReverseInner::write_to`.
+    ///
+    /// If the inner prefilter can't be rebuilt, or if there are no DFAs to
+    /// do reverse searches with, then this falls back to the core engine.
+    fn read_from(
+        info: &RegexInfo,
+        rdr: &mut Reader<'_>,
+    ) -> Result<Arc<dyn Strategy>, DeserializeError> {
+        let core = Core::read_from(info, rdr)?;
+        let preinner = Prefilter::read_from(rdr)?;
+        let nfarev = NFA::read_from(rdr)?;
+        if !nfarev.is_reverse() || nfarev.pattern_len() != 1 {
+            return Err(DeserializeError::generic(
+                "reverse inner NFA must be a reverse NFA with one pattern",
+            ));
+        }
+        let dfa = wrappers::ReverseDFA::read_from(&nfarev, rdr)?;
+        // This mirrors how the lazy DFA is built in 'ReverseInner::new'.
+        let hybrid = if !core.info.config().get_hybrid() || dfa.is_some() {
+            wrappers::ReverseHybrid::none()
+        } else {
+            wrappers::ReverseHybrid::new(&core.info, &nfarev)
+        };
+        let has_dfa = core.hybrid.is_some() || core.dfa.is_some();
+        let has_rev_dfa = hybrid.is_some() || dfa.is_some();
+        match preinner {
+            Some(preinner) if has_dfa && has_rev_dfa => {
+                Ok(Arc::new(ReverseInner {
+                    core,
+                    preinner,
+                    nfarev,
+                    hybrid,
+                    dfa,
+                }))
+            }
+            _ => {
+                debug!(
+                    "falling back to core engine because the reverse inner \
+                     optimization can't be used with this build"
+                );
+                Ok(Arc::new(core))
+            }
+        }
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn try_search_full(
         &self,
@@ -1781,20 +2442,7 @@ impl Strategy for ReverseInner {
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn search(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
-        if input.get_anchored().is_anchored() {
-            return self.core.search(cache, input);
-        }
-        match self.try_search_full(cache, input) {
-            Err(RetryError::Quadratic(_err)) => {
-                trace!("reverse inner optimization failed: {}", _err);
-                self.core.search(cache, input)
-            }
-            Err(RetryError::Fail(_err)) => {
-                trace!("reverse inner fast search failed: {}", _err);
-                self.core.search_nofail(cache, input)
-            }
-            Ok(matornot) => matornot,
-        }
+        expect_search(self.try_search(cache, input))
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -1813,7 +2461,7 @@ impl Strategy for ReverseInner {
             }
             Err(RetryError::Fail(_err)) => {
                 trace!("reverse inner fast half search failed: {}", _err);
-                self.core.search_half_nofail(cache, input)
+                expect_search(self.core.search_half_nofail(cache, input))
             }
             Ok(None) => None,
             Ok(Some(m)) => Some(HalfMatch::new(m.pattern(), m.end())),
@@ -1828,11 +2476,11 @@ impl Strategy for ReverseInner {
         match self.try_search_full(cache, input) {
             Err(RetryError::Quadratic(_err)) => {
                 trace!("reverse inner half optimization failed: {}", _err);
-                self.core.is_match_nofail(cache, input)
+                expect_search(self.core.is_match_nofail(cache, input))
             }
             Err(RetryError::Fail(_err)) => {
                 trace!("reverse inner fast half search failed: {}", _err);
-                self.core.is_match_nofail(cache, input)
+                expect_search(self.core.is_match_nofail(cache, input))
             }
             Ok(None) => false,
             Ok(Some(_)) => true,
@@ -1846,25 +2494,60 @@ impl Strategy for ReverseInner {
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
     ) -> Option<PatternID> {
+        expect_search(self.try_search_slots(cache, input, slots))
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
         if input.get_anchored().is_anchored() {
-            return self.core.search_slots(cache, input, slots);
+            return self.core.try_search(cache, input);
+        }
+        match self.try_search_full(cache, input) {
+            Err(RetryError::Quadratic(_err)) => {
+                trace!("reverse inner optimization failed: {}", _err);
+                self.core.try_search(cache, input)
+            }
+            Err(RetryError::Fail(_err)) => {
+                trace!("reverse inner fast search failed: {}", _err);
+                self.core.search_nofail(cache, input)
+            }
+            Ok(matornot) => Ok(matornot),
+        }
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        if input.get_anchored().is_anchored() {
+            return self.core.try_search_slots(cache, input, slots);
         }
         if !self.core.is_capture_search_needed(slots.len()) {
             trace!("asked for slots unnecessarily, trying fast path");
-            let m = self.search(cache, input)?;
+            let m = match self.try_search(cache, input)? {
+                None => return Ok(None),
+                Some(m) => m,
+            };
             copy_match_to_slots(m, slots);
-            return Some(m.pattern());
+            return Ok(Some(m.pattern()));
         }
         let m = match self.try_search_full(cache, input) {
             Err(RetryError::Quadratic(_err)) => {
                 trace!("reverse inner captures optimization failed: {}", _err);
-                return self.core.search_slots(cache, input, slots);
+                return self.core.try_search_slots(cache, input, slots);
             }
             Err(RetryError::Fail(_err)) => {
                 trace!("reverse inner fast captures search failed: {}", _err);
                 return self.core.search_slots_nofail(cache, input, slots);
             }
-            Ok(None) => return None,
+            Ok(None) => return Ok(None),
             Ok(Some(m)) => m,
         };
         trace!(
@@ -1880,6 +2563,14 @@ impl Strategy for ReverseInner {
         self.core.search_slots_nofail(cache, &input, slots)
     }
 
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(STRATEGY_REVERSE_INNER);
+        self.core.write_parts(wtr);
+        self.preinner.write_to(wtr);
+        self.nfarev.write_to(wtr);
+        self.dfa.write_to(wtr);
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn which_overlapping_matches(
         &self,
@@ -1889,6 +2580,284 @@ impl Strategy for ReverseInner {
     ) {
         self.core.which_overlapping_matches(cache, input, patset)
     }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn rsearch(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
+        self.core.rsearch(cache, input)
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn search_chunks(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Option<Match> {
+        self.core.search_chunks(cache, chunks, start)
+    }
+}
+
+/// A strategy for regexes with backreferences, which can only be executed by
+/// the backreference backtracker.
+///
+/// Since that backtracker may exceed its step limit, this is the only
+/// strategy whose searches can fail. The infallible search routines panic
+/// when that happens.
+#[derive(Debug)]
+struct Backref {
+    info: RegexInfo,
+    backref: wrappers::BackrefEngine,
+}
+
+impl Backref {
+    fn new(info: RegexInfo, hirs: &[&Hir]) -> Result<Backref, BuildError> {
+        let nfa = thompson::Compiler::new()
+            .configure(thompson_config(&info))
+            .build_many_from_hir(hirs)
+            .map_err(BuildError::nfa)?;
+        // OK because this strategy is only used when backreferences are
+        // enabled, which in turn requires the backtracker to be available.
+        let backref = wrappers::BackrefEngine::new(&info, &nfa)?.unwrap();
+        Ok(Backref { info, backref })
+    }
+
+    /// Deserialize a backreference strategy written by `Backref::write_to`.
+    fn read_from(
+        info: &RegexInfo,
+        rdr: &mut Reader<'_>,
+    ) -> Result<Backref, DeserializeError> {
+        let info = info.clone();
+        let nfa = NFA::read_from(rdr)?;
+        if nfa.is_reverse() || nfa.pattern_len() != info.pattern_len() {
+            return Err(DeserializeError::generic(
+                "NFA does not match its meta regex",
+            ));
+        }
+        let backref = wrappers::BackrefEngine::new(&info, &nfa)
+            .ok()
+            .flatten()
+            .ok_or_else(|| {
+                DeserializeError::generic(
+                    "could not build backreference backtracker",
+                )
+            })?;
+        Ok(Backref { info, backref })
+    }
+}
+
+impl Strategy for Backref {
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn group_info(&self) -> &GroupInfo {
+        self.backref.get_nfa().group_info()
+    }
+
+    fn create_cache(&self) -> Cache {
+        Cache {
+            capmatches: Captures::all(self.group_info().clone()),
+            pikevm: wrappers::PikeVMCache::none(),
+            backtrack: wrappers::BoundedBacktrackerCache::none(),
+            onepass: wrappers::OnePassCache::none(),
+            hybrid: wrappers::HybridCache::none(),
+            revhybrid: wrappers::ReverseHybridCache::none(),
+            revpikevm: wrappers::ReversePikeVMCache::none(),
+            backref: self.backref.create_cache(),
+        }
+    }
+
+    fn reset_cache(&self, cache: &mut Cache) {
+        self.backref.reset_cache(&mut cache.backref);
+    }
+
+    fn is_accelerated(&self) -> bool {
+        false
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.info.memory_usage() + self.backref.get_nfa().memory_usage()
+    }
+
+    fn search(&self, cache: &mut Cache, input: &Input<'_>) -> Option<Match> {
+        expect_search(self.try_search(cache, input))
+    }
+
+    fn search_half(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Option<HalfMatch> {
+        let m = self.search(cache, input)?;
+        Some(HalfMatch::new(m.pattern(), m.end()))
+    }
+
+    fn is_match(&self, cache: &mut Cache, input: &Input<'_>) -> bool {
+        let input = input.clone().earliest(true);
+        expect_search(self.try_search_slots(cache, &input, &mut [])).is_some()
+    }
+
+    fn search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Option<PatternID> {
+        expect_search(self.try_search_slots(cache, input, slots))
+    }
+
+    fn which_overlapping_matches(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        patset: &mut PatternSet,
+    ) {
+        expect_search(self.backref.try_which_overlapping_matches(
+            &mut cache.backref,
+            input,
+            patset,
+        ))
+    }
+
+    fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<Match>, MatchError> {
+        let caps = &mut cache.capmatches;
+        caps.set_pattern(None);
+        let pid = self.backref.try_search_slots(
+            &mut cache.backref,
+            input,
+            caps.slots_mut(),
+        )?;
+        caps.set_pattern(pid);
+        Ok(caps.get_match())
+    }
+
+    fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        self.backref.try_search_slots(&mut cache.backref, input, slots)
+    }
+
+    fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(STRATEGY_BACKREF);
+        self.backref.get_nfa().write_to(wtr);
+    }
+}
+
+/// Returns the configuration used to compile the forward NFA for a regex
+/// with the given info.
+fn thompson_config(info: &RegexInfo) -> thompson::Config {
+    let mut lookm = LookMatcher::new();
+    lookm.set_line_terminator(info.config().get_line_terminator());
+    thompson::Config::new()
+        .utf8(info.config().get_utf8_empty())
+        .nfa_size_limit(info.config().get_nfa_size_limit())
+        .shrink(false)
+        .which_captures(info.config().get_which_captures())
+        .look_matcher(lookm)
+}
+
+/// Returns true if and only if the given expression contains a
+/// backreference anywhere.
+fn has_backreference(hir: &Hir) -> bool {
+    let mut stack = vec![hir];
+    while let Some(hir) = stack.pop() {
+        if let HirKind::Backreference(_) = *hir.kind() {
+            return true;
+        }
+        stack.extend(hir.kind().subs());
+    }
+    false
+}
+
+/// Returns the last match in the given input, as described by
+/// `Regex::rsearch`, using only forward searches.
+///
+/// This runs an anchored search at every position in the input, starting at
+/// the end, until one finds a match. So it's quite slow, but works with every
+/// strategy.
+fn rsearch_fallback<S: Strategy + ?Sized>(
+    strat: &S,
+    cache: &mut Cache,
+    input: &Input<'_>,
+) -> Option<Match> {
+    if input.get_anchored().is_anchored() {
+        return strat.search(cache, input);
+    }
+    trace!("using forward searches for reverse search");
+    let m = (input.start()..=input.end()).rev().find_map(|start| {
+        let input =
+            input.clone().span(start..input.end()).anchored(Anchored::Yes);
+        strat.search(cache, &input)
+    })?;
+    Some(rsearch_extend(strat, cache, input, input.start(), m))
+}
+
+/// Given the match `m` that a forward search finds from the last position at
+/// which a match starts, this returns the match that a forward search finds
+/// from the earliest position at which it ends where `m` does.
+///
+/// Only positions at or after `min_start` are tried. If none of them before
+/// the start of `m` has a match ending where `m` does, then `m` is returned.
+fn rsearch_extend<S: Strategy + ?Sized>(
+    strat: &S,
+    cache: &mut Cache,
+    input: &Input<'_>,
+    min_start: usize,
+    m: Match,
+) -> Match {
+    for start in min_start..m.start() {
+        let input =
+            input.clone().span(start..input.end()).anchored(Anchored::Yes);
+        if let Some(m2) = strat.search(cache, &input) {
+            if m2.end() == m.end() {
+                return m2;
+            }
+        }
+    }
+    m
+}
+
+/// Returns the leftmost match in the given haystack made up of chunks, as
+/// described by `Regex::search_chunks`, by copying the haystack into a
+/// contiguous buffer.
+///
+/// This is used by strategies (or regexes) that need regex engines which can
+/// only search contiguous haystacks.
+fn search_chunks_fallback<S: Strategy + ?Sized>(
+    strat: &S,
+    cache: &mut Cache,
+    chunks: &Chunks<'_>,
+    start: usize,
+) -> Option<Match> {
+    trace!("copying chunks for search at {:?}", start);
+    let mut haystack = Vec::with_capacity(chunks.len());
+    chunks.copy_range(chunks.start(), chunks.end(), &mut haystack);
+    let offset = chunks.start();
+    let input = Input::new(&haystack).range(start - offset..);
+    let m = strat.search(cache, &input)?;
+    Some(Match::new(m.pattern(), offset + m.start()..offset + m.end()))
+}
+
+/// Unwraps the result of a search that the caller asked to be infallible.
+///
+/// The only searches that can fail are those using backreferences, which
+/// fail when they exceed their step limit, and those that are cancelled via
+/// the flag on `Input`. Callers that need to handle that should use the
+/// fallible `try_*` search routines instead.
+#[cfg_attr(feature = "perf-inline", inline(always))]
+fn expect_search<T>(result: Result<T, MatchError>) -> T {
+    match result {
+        Ok(t) => t,
+        Err(err) => panic!(
+            "meta regex search failed, use a fallible search routine \
+             like Regex::try_search to handle this: {}",
+            err,
+        ),
+    }
 }
 
 /// Copies the offsets in the given match to the corresponding positions in
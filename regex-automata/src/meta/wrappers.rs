@@ -24,16 +24,19 @@ regex engine that needs them. If an engine is unavailable or not used, then a
 cache for it will *not* actually be allocated.
 */
 
-use alloc::vec::Vec;
-
 use crate::{
     meta::{
         error::{BuildError, RetryError, RetryFailError},
         regex::RegexInfo,
     },
     nfa::thompson::{pikevm, NFA},
-    util::{prefilter::Prefilter, primitives::NonMaxUsize},
-    HalfMatch, Input, Match, MatchKind, PatternID, PatternSet,
+    util::{
+        chunks::Chunks,
+        prefilter::Prefilter,
+        primitives::NonMaxUsize,
+        wire::{AlignedBytes, DeserializeError, Reader, Writer},
+    },
+    HalfMatch, Input, Match, MatchError, MatchKind, PatternID, PatternSet,
 };
 
 #[cfg(feature = "dfa-build")]
@@ -42,8 +45,12 @@ use crate::dfa;
 use crate::dfa::onepass;
 #[cfg(feature = "hybrid")]
 use crate::hybrid;
+#[cfg(feature = "nfa-backref")]
+use crate::nfa::thompson::backref;
 #[cfg(feature = "nfa-backtrack")]
 use crate::nfa::thompson::backtrack;
+#[cfg(feature = "dfa-build")]
+use alloc::borrow::Cow;
 
 #[derive(Debug)]
 pub(crate) struct PikeVM(PikeVMEngine);
@@ -78,7 +85,8 @@ impl PikeVMEngine {
     ) -> Result<PikeVMEngine, BuildError> {
         let pikevm_config = pikevm::Config::new()
             .match_kind(info.config().get_match_kind())
-            .prefilter(pre);
+            .prefilter(pre)
+            .max_edits(info.config().get_max_edits());
         let engine = pikevm::Builder::new()
             .configure(pikevm_config)
             .build_from_nfa(nfa.clone())
@@ -87,13 +95,19 @@ impl PikeVMEngine {
         Ok(PikeVMEngine(engine))
     }
 
+    // The PikeVM never fails, except when a search is cancelled. So the
+    // routines below only ever return a cancellation error.
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     pub(crate) fn is_match(
         &self,
         cache: &mut PikeVMCache,
         input: &Input<'_>,
-    ) -> bool {
-        self.0.is_match(cache.0.as_mut().unwrap(), input.clone())
+    ) -> Result<bool, MatchError> {
+        let input = input.clone().earliest(true);
+        self.0
+            .try_search_slots(cache.0.as_mut().unwrap(), &input, &mut [])
+            .map(|pid| pid.is_some())
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -102,8 +116,8 @@ impl PikeVMEngine {
         cache: &mut PikeVMCache,
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
-    ) -> Option<PatternID> {
-        self.0.search_slots(cache.0.as_mut().unwrap(), input, slots)
+    ) -> Result<Option<PatternID>, MatchError> {
+        self.0.try_search_slots(cache.0.as_mut().unwrap(), input, slots)
     }
 
     #[cfg_attr(feature = "perf-inline", inline(always))]
@@ -112,13 +126,32 @@ impl PikeVMEngine {
         cache: &mut PikeVMCache,
         input: &Input<'_>,
         patset: &mut PatternSet,
-    ) {
-        self.0.which_overlapping_matches(
+    ) -> Result<(), MatchError> {
+        self.0.try_which_overlapping_matches(
             cache.0.as_mut().unwrap(),
             input,
             patset,
         )
     }
+
+    pub(crate) fn supports_chunks(&self) -> bool {
+        self.0.supports_chunks()
+    }
+
+    pub(crate) fn search_chunks_slots(
+        &self,
+        cache: &mut PikeVMCache,
+        chunks: &Chunks<'_>,
+        start: usize,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Option<PatternID> {
+        self.0.search_chunks_slots(
+            cache.0.as_mut().unwrap(),
+            chunks,
+            start,
+            slots,
+        )
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -142,6 +175,82 @@ impl PikeVMCache {
     }
 }
 
+/// A PikeVM that runs a reverse NFA.
+///
+/// This is only used as a fallback for reverse searches that can't use a
+/// reverse lazy DFA, either because it isn't available or because it gave
+/// up. Unlike running forward searches from every position, it keeps those
+/// searches linear in the size of the haystack.
+#[derive(Debug)]
+pub(crate) struct ReversePikeVM(Option<pikevm::PikeVM>);
+
+impl ReversePikeVM {
+    pub(crate) fn none() -> ReversePikeVM {
+        ReversePikeVM(None)
+    }
+
+    pub(crate) fn new(info: &RegexInfo, nfarev: &NFA) -> ReversePikeVM {
+        // Approximate matching doesn't have a reverse counterpart, so there
+        // is no point in searching the reverse NFA for exact matches only.
+        if info.config().get_max_edits().is_some() {
+            return ReversePikeVM::none();
+        }
+        // Like the reverse DFAs, we hard-code the match semantics and don't
+        // use any prefilters.
+        let pikevm_config =
+            pikevm::Config::new().match_kind(MatchKind::All).prefilter(None);
+        let result = pikevm::Builder::new()
+            .configure(pikevm_config)
+            .build_from_nfa(nfarev.clone());
+        match result {
+            Ok(engine) => {
+                debug!("reverse PikeVM built");
+                ReversePikeVM(Some(engine))
+            }
+            Err(_err) => {
+                debug!("reverse PikeVM failed to build: {}", _err);
+                ReversePikeVM::none()
+            }
+        }
+    }
+
+    pub(crate) fn is_some(&self) -> bool {
+        self.0.is_some()
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn try_search_half_rev(
+        &self,
+        cache: &mut ReversePikeVMCache,
+        input: &Input<'_>,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        let engine = self.0.as_ref().unwrap();
+        // The cache is only created on first use, since this engine is only
+        // used as a fallback and most regexes never need it.
+        let cache = cache.0.get_or_insert_with(|| engine.create_cache());
+        engine.try_search_half_rev(cache, input)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ReversePikeVMCache(Option<pikevm::Cache>);
+
+impl ReversePikeVMCache {
+    pub(crate) fn none() -> ReversePikeVMCache {
+        ReversePikeVMCache(None)
+    }
+
+    pub(crate) fn reset(&mut self) {
+        // Since the cache is created lazily, the simplest way to make it
+        // usable with another regex is to drop it.
+        self.0 = None;
+    }
+
+    pub(crate) fn memory_usage(&self) -> usize {
+        self.0.as_ref().map_or(0, |c| c.memory_usage())
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct BoundedBacktracker(Option<BoundedBacktrackerEngine>);
 
@@ -204,6 +313,7 @@ impl BoundedBacktrackerEngine {
         {
             if !info.config().get_backtrack()
                 || info.config().get_match_kind() != MatchKind::LeftmostFirst
+                || nfa.has_atomic()
             {
                 return Ok(None);
             }
@@ -229,15 +339,13 @@ impl BoundedBacktrackerEngine {
         &self,
         cache: &mut BoundedBacktrackerCache,
         input: &Input<'_>,
-    ) -> bool {
+    ) -> Result<bool, MatchError> {
         #[cfg(feature = "nfa-backtrack")]
         {
-            // OK because we only permit access to this engine when we know
-            // the haystack is short enough for the backtracker to run without
-            // reporting an error.
-            self.0
-                .try_is_match(cache.0.as_mut().unwrap(), input.clone())
-                .unwrap()
+            // We only permit access to this engine when we know the haystack
+            // is short enough for the backtracker to run without reporting
+            // an error. So the only error possible here is cancellation.
+            self.0.try_is_match(cache.0.as_mut().unwrap(), input.clone())
         }
         #[cfg(not(feature = "nfa-backtrack"))]
         {
@@ -253,15 +361,13 @@ impl BoundedBacktrackerEngine {
         cache: &mut BoundedBacktrackerCache,
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
-    ) -> Option<PatternID> {
+    ) -> Result<Option<PatternID>, MatchError> {
         #[cfg(feature = "nfa-backtrack")]
         {
-            // OK because we only permit access to this engine when we know
-            // the haystack is short enough for the backtracker to run without
-            // reporting an error.
-            self.0
-                .try_search_slots(cache.0.as_mut().unwrap(), input, slots)
-                .unwrap()
+            // We only permit access to this engine when we know the haystack
+            // is short enough for the backtracker to run without reporting
+            // an error. So the only error possible here is cancellation.
+            self.0.try_search_slots(cache.0.as_mut().unwrap(), input, slots)
         }
         #[cfg(not(feature = "nfa-backtrack"))]
         {
@@ -338,6 +444,152 @@ impl BoundedBacktrackerCache {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct BackrefEngine(
+    #[cfg(feature = "nfa-backref")] backref::Backtracker,
+    #[cfg(not(feature = "nfa-backref"))] (),
+);
+
+impl BackrefEngine {
+    pub(crate) fn new(
+        info: &RegexInfo,
+        nfa: &NFA,
+    ) -> Result<Option<BackrefEngine>, BuildError> {
+        #[cfg(feature = "nfa-backref")]
+        {
+            if !info.config().get_backreferences() {
+                return Ok(None);
+            }
+            let backref_config = backref::Config::new()
+                .step_limit(info.config().get_backref_step_limit());
+            let engine = backref::Builder::new()
+                .configure(backref_config)
+                .build_from_nfa(nfa.clone())
+                .map_err(BuildError::nfa)?;
+            debug!(
+                "backreference Backtracker built (step limit: {:?})",
+                engine.get_config().get_step_limit(),
+            );
+            Ok(Some(BackrefEngine(engine)))
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn create_cache(&self) -> BackrefCache {
+        #[cfg(feature = "nfa-backref")]
+        {
+            BackrefCache(Some(self.0.create_cache()))
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+
+    pub(crate) fn reset_cache(&self, cache: &mut BackrefCache) {
+        #[cfg(feature = "nfa-backref")]
+        {
+            cache.0.as_mut().unwrap().reset(&self.0);
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+
+    pub(crate) fn get_nfa(&self) -> &NFA {
+        #[cfg(feature = "nfa-backref")]
+        {
+            self.0.get_nfa()
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn try_search_slots(
+        &self,
+        cache: &mut BackrefCache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        #[cfg(feature = "nfa-backref")]
+        {
+            self.0.try_search_slots(cache.0.as_mut().unwrap(), input, slots)
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn try_which_overlapping_matches(
+        &self,
+        cache: &mut BackrefCache,
+        input: &Input<'_>,
+        patset: &mut PatternSet,
+    ) -> Result<(), MatchError> {
+        #[cfg(feature = "nfa-backref")]
+        {
+            self.0.try_which_overlapping_matches(
+                cache.0.as_mut().unwrap(),
+                input,
+                patset,
+            )
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct BackrefCache(
+    #[cfg(feature = "nfa-backref")] Option<backref::Cache>,
+    #[cfg(not(feature = "nfa-backref"))] (),
+);
+
+impl BackrefCache {
+    pub(crate) fn none() -> BackrefCache {
+        #[cfg(feature = "nfa-backref")]
+        {
+            BackrefCache(None)
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            BackrefCache(())
+        }
+    }
+
+    pub(crate) fn memory_usage(&self) -> usize {
+        #[cfg(feature = "nfa-backref")]
+        {
+            self.0.as_ref().map_or(0, |c| c.memory_usage())
+        }
+        #[cfg(not(feature = "nfa-backref"))]
+        {
+            0
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct OnePass(Option<OnePassEngine>);
 
@@ -427,14 +679,12 @@ impl OnePassEngine {
         cache: &mut OnePassCache,
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
-    ) -> Option<PatternID> {
+    ) -> Result<Option<PatternID>, MatchError> {
         #[cfg(feature = "dfa-onepass")]
         {
-            // OK because we only permit getting a OnePassEngine when we know
-            // the search is anchored and thus an error cannot occur.
-            self.0
-                .try_search_slots(cache.0.as_mut().unwrap(), input, slots)
-                .unwrap()
+            // We only permit getting a OnePassEngine when we know the search
+            // is anchored, so the only error possible here is cancellation.
+            self.0.try_search_slots(cache.0.as_mut().unwrap(), input, slots)
         }
         #[cfg(not(feature = "dfa-onepass"))]
         {
@@ -741,6 +991,27 @@ impl HybridEngine {
         }
     }
 
+    pub(crate) fn try_search_chunks(
+        &self,
+        cache: &mut HybridCache,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Result<Option<Match>, RetryFailError> {
+        #[cfg(feature = "hybrid")]
+        {
+            let cache = cache.0.as_mut().unwrap();
+            crate::meta::chunked::hybrid_try_search(
+                &self.0, cache, chunks, start,
+            )
+        }
+        #[cfg(not(feature = "hybrid"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+
     #[inline]
     pub(crate) fn try_which_overlapping_matches(
         &self,
@@ -829,6 +1100,54 @@ impl DFA {
         DFA(DFAEngine::new(info, pre, nfa, nfarev))
     }
 
+    /// Write the forward and reverse DFAs, if they exist.
+    pub(crate) fn write_to(&self, wtr: &mut Writer) {
+        match self.0 {
+            None => wtr.bool(false),
+            Some(ref _engine) => {
+                wtr.bool(true);
+                #[cfg(feature = "dfa-build")]
+                {
+                    write_dense_dfa(wtr, _engine.0.forward());
+                    write_dense_dfa(wtr, _engine.0.reverse());
+                }
+            }
+        }
+    }
+
+    /// Read the DFAs written by `DFA::write_to`.
+    ///
+    /// The prefilter given should be the same one that was given to the
+    /// DFA when it was originally built. (Prefilters are never part of a
+    /// serialized DFA.)
+    ///
+    /// If DFAs were written but full DFA support isn't available, then this
+    /// skips over them and returns a DFA wrapper that is never used.
+    pub(crate) fn read_from(
+        info: &RegexInfo,
+        pre: Option<Prefilter>,
+        rdr: &mut Reader<'_>,
+    ) -> Result<DFA, DeserializeError> {
+        if !rdr.bool("full DFA")? {
+            return Ok(DFA::none());
+        }
+        let fwd = rdr.aligned_bytes("forward DFA")?;
+        let rev = rdr.aligned_bytes("reverse DFA")?;
+        #[cfg(feature = "dfa-build")]
+        {
+            let mut fwd = read_dense_dfa(info.pattern_len(), fwd)?;
+            fwd.set_prefilter(pre);
+            let rev = read_dense_dfa(info.pattern_len(), rev)?;
+            let engine = dfa::regex::Builder::new().build_from_dfas(fwd, rev);
+            Ok(DFA(Some(DFAEngine(engine))))
+        }
+        #[cfg(not(feature = "dfa-build"))]
+        {
+            let _ = (info, pre, fwd, rev);
+            Ok(DFA::none())
+        }
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     pub(crate) fn get(&self, _input: &Input<'_>) -> Option<&DFAEngine> {
         let engine = self.0.as_ref()?;
@@ -846,7 +1165,7 @@ impl DFA {
 
 #[derive(Debug)]
 pub(crate) struct DFAEngine(
-    #[cfg(feature = "dfa-build")] dfa::regex::Regex,
+    #[cfg(feature = "dfa-build")] dfa::regex::Regex<DenseDFA>,
     #[cfg(not(feature = "dfa-build"))] (),
 );
 
@@ -928,7 +1247,10 @@ impl DFAEngine {
                     return None;
                 }
             };
-            let engine = dfa::regex::Builder::new().build_from_dfas(fwd, rev);
+            let engine = dfa::regex::Builder::new().build_from_dfas(
+                fwd.map_tables(Cow::Owned),
+                rev.map_tables(Cow::Owned),
+            );
             debug!(
                 "fully compiled forward and reverse DFAs built, {} bytes",
                 engine.forward().memory_usage()
@@ -1034,6 +1356,23 @@ impl DFAEngine {
         }
     }
 
+    pub(crate) fn try_search_chunks(
+        &self,
+        chunks: &Chunks<'_>,
+        start: usize,
+    ) -> Result<Option<Match>, RetryFailError> {
+        #[cfg(feature = "dfa-build")]
+        {
+            crate::meta::chunked::dfa_try_search(&self.0, chunks, start)
+        }
+        #[cfg(not(feature = "dfa-build"))]
+        {
+            // Impossible to reach because this engine is never constructed
+            // if the requisite features aren't enabled.
+            unreachable!()
+        }
+    }
+
     #[inline]
     pub(crate) fn try_which_overlapping_matches(
         &self,
@@ -1082,6 +1421,10 @@ impl ReverseHybrid {
         ReverseHybrid(ReverseHybridEngine::new(info, nfarev))
     }
 
+    pub(crate) fn is_some(&self) -> bool {
+        self.0.is_some()
+    }
+
     pub(crate) fn create_cache(&self) -> ReverseHybridCache {
         ReverseHybridCache::new(self)
     }
@@ -1229,6 +1572,45 @@ impl ReverseDFA {
         ReverseDFA(ReverseDFAEngine::new(info, nfarev))
     }
 
+    /// Write the reverse DFA, if it exists.
+    pub(crate) fn write_to(&self, wtr: &mut Writer) {
+        match self.0 {
+            None => wtr.bool(false),
+            Some(ref _engine) => {
+                wtr.bool(true);
+                #[cfg(feature = "dfa-build")]
+                {
+                    write_dense_dfa(wtr, &_engine.0);
+                }
+            }
+        }
+    }
+
+    /// Read the DFA written by `ReverseDFA::write_to`. The DFA must have
+    /// been built from the given reverse NFA.
+    ///
+    /// If a DFA was written but full DFA support isn't available, then this
+    /// skips over it and returns a DFA wrapper that is never used.
+    pub(crate) fn read_from(
+        nfarev: &NFA,
+        rdr: &mut Reader<'_>,
+    ) -> Result<ReverseDFA, DeserializeError> {
+        if !rdr.bool("full reverse DFA")? {
+            return Ok(ReverseDFA::none());
+        }
+        let rev = rdr.aligned_bytes("reverse DFA")?;
+        #[cfg(feature = "dfa-build")]
+        {
+            let rev = read_dense_dfa(nfarev.pattern_len(), rev)?;
+            Ok(ReverseDFA(Some(ReverseDFAEngine(rev))))
+        }
+        #[cfg(not(feature = "dfa-build"))]
+        {
+            let _ = (nfarev, rev);
+            Ok(ReverseDFA::none())
+        }
+    }
+
     #[cfg_attr(feature = "perf-inline", inline(always))]
     pub(crate) fn get(&self, _input: &Input<'_>) -> Option<&ReverseDFAEngine> {
         let engine = self.0.as_ref()?;
@@ -1246,7 +1628,7 @@ impl ReverseDFA {
 
 #[derive(Debug)]
 pub(crate) struct ReverseDFAEngine(
-    #[cfg(feature = "dfa-build")] dfa::dense::DFA<Vec<u32>>,
+    #[cfg(feature = "dfa-build")] DenseDFA,
     #[cfg(not(feature = "dfa-build"))] (),
 );
 
@@ -1307,7 +1689,7 @@ impl ReverseDFAEngine {
                 "fully compiled reverse DFA built, {} bytes",
                 rev.memory_usage()
             );
-            Some(ReverseDFAEngine(rev))
+            Some(ReverseDFAEngine(rev.map_tables(Cow::Owned)))
         }
         #[cfg(not(feature = "dfa-build"))]
         {
@@ -1349,3 +1731,57 @@ impl ReverseDFAEngine {
         }
     }
 }
+
+/// The type of the fully compiled DFAs used by the meta regex engine.
+///
+/// Its transition tables are owned when the DFA is built, but are borrowed
+/// when it is deserialized from a `'static` slice by `Regex::from_bytes`.
+#[cfg(feature = "dfa-build")]
+pub(crate) type DenseDFA = dfa::dense::DFA<Cow<'static, [u32]>>;
+
+/// Write the given dense DFA as a length prefixed sequence of bytes, using
+/// the writer's endianness. The DFA is aligned to a `u32` within the bytes
+/// written.
+#[cfg(feature = "dfa-build")]
+fn write_dense_dfa(wtr: &mut Writer, dfa: &DenseDFA) {
+    let (bytes, pad) = if wtr.is_little_endian() {
+        dfa.to_bytes_little_endian()
+    } else {
+        dfa.to_bytes_big_endian()
+    };
+    wtr.aligned_bytes(&bytes[pad..]);
+}
+
+/// Read a dense DFA written by `write_dense_dfa` and check that it has the
+/// given number of patterns.
+///
+/// When the bytes live for the `'static` lifetime, the DFA borrows its
+/// transition tables from them without copying. Otherwise, the bytes are
+/// copied into an aligned buffer and the DFA owns its tables. Either way,
+/// the DFA is fully validated by `DFA::from_bytes`.
+#[cfg(feature = "dfa-build")]
+fn read_dense_dfa(
+    pattern_len: usize,
+    bytes: AlignedBytes<'_>,
+) -> Result<DenseDFA, DeserializeError> {
+    use crate::util::wire::alloc_aligned_buffer;
+
+    let dfa = match bytes {
+        AlignedBytes::Static(bytes) => {
+            let (dfa, _) = dfa::dense::DFA::from_bytes(bytes)?;
+            dfa.map_tables(Cow::Borrowed)
+        }
+        AlignedBytes::Borrowed(bytes) => {
+            let (mut buf, pad) = alloc_aligned_buffer::<u32>(bytes.len());
+            buf[pad..].copy_from_slice(bytes);
+            let (dfa, _) = dfa::dense::DFA::from_bytes(&buf[pad..])?;
+            dfa.to_owned().map_tables(Cow::Owned)
+        }
+    };
+    if dfa.pattern_len() != pattern_len {
+        return Err(DeserializeError::generic(
+            "DFA has a different number of patterns than its regex",
+        ));
+    }
+    Ok(dfa)
+}
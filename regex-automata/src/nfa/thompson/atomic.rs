@@ -0,0 +1,461 @@
+/*!
+Provides support for atomic groups in the PikeVM.
+
+An atomic group, like `(?>a|ab)`, matches its sub-expression in the same way
+a backtracking engine would, but once a match of the sub-expression has been
+found, none of the other ways of matching it from the same starting position
+are ever tried. So `(?>a|ab)c` does not match `abc`, because the group commits
+to matching `a` and `c` then fails to match `b`. Possessive repetitions, like
+`a*+`, are atomic groups around a greedy repetition.
+
+The PikeVM doesn't backtrack, so it can't discard alternatives after the
+fact. Instead, before a search begins, we compute for every union state
+inside of an atomic group and every position in the search span the one
+alternative that a backtracking engine would commit to. That is, the first
+alternative (in preference order) from which the end of the group can be
+reached. The epsilon closure in the PikeVM then only follows that alternative.
+This works because the first successful way of matching a group from a union
+state at a particular position doesn't depend on how the union state was
+reached.
+
+Whether the end of a group can be reached from a state at a position only
+depends on the same question for states at the same position (via epsilon
+transitions) or at the next position (via byte transitions). So the
+positions are processed from right to left, and for each position, every
+state in the group is explored with a depth first search in preference order.
+Nested groups are processed before the groups containing them, since a union
+inside of a nested group always follows the alternative chosen for it.
+
+Like a backtracking engine, the depth first search refuses to revisit a state
+at the same position while it's still exploring it. This can only happen for
+sub-expressions that match the empty string inside of a repetition, e.g.,
+`(?>(a?)*)`.
+
+The cost of this precomputation is proportional to the length of the search
+span multiplied by the number of states inside of atomic groups. Like the
+results for look-around assertions, the choices are reused by later searches
+over the same haystack from an iterator, as long as the search span still
+ends at the same position.
+*/
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    nfa::thompson::{lookaround, State, NFA},
+    util::{
+        primitives::{SmallIndex, StateID},
+        search::Input,
+    },
+};
+
+/// The sentinel used in the choices table when no alternative of a union
+/// state reaches the end of its atomic group.
+const NONE: u32 = u32::MAX;
+
+/// A cache of the alternatives that each union state inside of an atomic
+/// group commits to for the current search.
+///
+/// This is embedded in the caches of regex engines that support atomic
+/// groups without backtracking.
+#[derive(Clone, Debug)]
+pub(crate) struct Cache {
+    /// For each union state inside of an atomic group, the alternative it
+    /// commits to at each position in the search span. The choice for the
+    /// union with dense index `i` at position `at` is stored at
+    /// `i * width + (at - offset)`.
+    choices: Vec<u32>,
+    /// The start of the span of the current search.
+    offset: usize,
+    /// The number of positions in the span of the current search. This is
+    /// one more than the length of the span since the end of the span is
+    /// also a position at which a group may be matched.
+    width: usize,
+    /// The identifier of the haystack that `choices` was computed for, if it
+    /// had one. Since the choices at a position only depend on the haystack
+    /// and the end of the search span, `choices` can be reused by any later
+    /// search over the same haystack with the same end and a start that is
+    /// not before `offset`.
+    haystack_id: Option<usize>,
+    /// The start of each atomic group's row in `curr` and `next`, indexed by
+    /// group index.
+    rows: Vec<usize>,
+    /// Whether the end of the group can be reached from each state in each
+    /// atomic group, at the position currently being processed.
+    curr: Vec<Reach>,
+    /// Like `curr`, but for the position after the one currently being
+    /// processed.
+    next: Vec<Reach>,
+    /// A stack of states and the next alternative to explore from each,
+    /// used for the depth first search.
+    stack: Vec<(StateID, usize)>,
+}
+
+/// Whether the end of an atomic group can be reached from a state.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Reach {
+    /// The state hasn't been explored yet.
+    Unknown,
+    /// The state is currently being explored.
+    Exploring,
+    /// The end of the group can't be reached.
+    No,
+    /// The end of the group can be reached.
+    Yes,
+}
+
+/// The result of following a single alternative out of a state.
+enum Step {
+    /// The state can reach the end of its group without exploring further.
+    Yes,
+    /// The state has no more alternatives to follow.
+    No,
+    /// The given state must be explored.
+    Explore(StateID),
+}
+
+impl Cache {
+    /// Create a new cache for the atomic groups in the given NFA.
+    pub(crate) fn new(nfa: &NFA) -> Cache {
+        let mut cache = Cache {
+            choices: vec![],
+            offset: 0,
+            width: 0,
+            haystack_id: None,
+            rows: vec![],
+            curr: vec![],
+            next: vec![],
+            stack: vec![],
+        };
+        cache.reset(nfa);
+        cache
+    }
+
+    /// Reset this cache such that it can be used for the given NFA.
+    pub(crate) fn reset(&mut self, nfa: &NFA) {
+        self.choices.clear();
+        self.offset = 0;
+        self.width = 0;
+        self.haystack_id = None;
+        self.rows.clear();
+        let mut len = 0;
+        for group in nfa.atomic_groups().iter() {
+            self.rows.push(len);
+            len += group.states().len();
+        }
+        self.curr.clear();
+        self.curr.resize(len, Reach::Unknown);
+        self.next.clear();
+        self.next.resize(len, Reach::Unknown);
+        self.stack.clear();
+    }
+
+    /// Returns the heap memory usage, in bytes, of this cache.
+    pub(crate) fn memory_usage(&self) -> usize {
+        use core::mem::size_of;
+
+        self.choices.len() * size_of::<u32>()
+            + self.rows.len() * size_of::<usize>()
+            + (self.curr.len() + self.next.len()) * size_of::<Reach>()
+            + self.stack.len() * size_of::<(StateID, usize)>()
+    }
+
+    /// Compute the alternative that each union state inside of an atomic
+    /// group in the given NFA commits to, for every position in the span of
+    /// the given search.
+    ///
+    /// The look-around cache given must already be set up for the same
+    /// search.
+    ///
+    /// This is a no-op if the NFA has no atomic groups, or if the choices
+    /// computed for a previous search over the same haystack are still valid
+    /// for the given search.
+    pub(crate) fn setup_search(
+        &mut self,
+        nfa: &NFA,
+        input: &Input<'_>,
+        look_around: &lookaround::Cache,
+    ) {
+        if !nfa.has_atomic() || input.is_done() {
+            return;
+        }
+        if self.haystack_id.is_some()
+            && self.haystack_id == input.get_haystack_id()
+            && self.offset <= input.start()
+            && input.end() == self.offset + self.width - 1
+        {
+            return;
+        }
+        self.haystack_id = input.get_haystack_id();
+        self.offset = input.start();
+        self.width = input.end() - input.start() + 1;
+        self.choices.clear();
+        self.choices.resize(nfa.atomic_unions_len() * self.width, NONE);
+        // Nothing can be reached via a byte transition out of the last
+        // position, which we model by an initial row where nothing can be
+        // reached.
+        for reach in self.next.iter_mut() {
+            *reach = Reach::No;
+        }
+        for at in (input.start()..=input.end()).rev() {
+            for reach in self.curr.iter_mut() {
+                *reach = Reach::Unknown;
+            }
+            for &index in nfa.atomic_order().iter() {
+                // Exploring from the start of the group first means that the
+                // states in a loop that can match the empty string are
+                // explored in the same order as a backtracking engine
+                // entering the group would explore them.
+                let group = &nfa.atomic_groups()[index];
+                self.explore(
+                    nfa,
+                    input,
+                    look_around,
+                    index,
+                    group.start(),
+                    at,
+                );
+                for &sid in group.states().iter() {
+                    self.explore(nfa, input, look_around, index, sid, at);
+                }
+            }
+            core::mem::swap(&mut self.curr, &mut self.next);
+        }
+    }
+
+    /// Returns the alternative that the given union state commits to at the
+    /// given position, or `None` if the end of its atomic group can't be
+    /// reached from it.
+    ///
+    /// The state given must be a union state inside of an atomic group, and
+    /// the position must be within the span of the search most recently
+    /// given to `setup_search`.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn choice(
+        &self,
+        nfa: &NFA,
+        sid: StateID,
+        at: usize,
+    ) -> Option<StateID> {
+        let (union, _) = nfa.atomic_union(sid)?;
+        let choice = self.choices[union * self.width + (at - self.offset)];
+        if choice == NONE {
+            return None;
+        }
+        alternate(nfa, sid, choice as usize)
+    }
+
+    /// Determine whether the end of the atomic group with the given index
+    /// can be reached from `root` at position `at`, along with every state
+    /// explored along the way that hasn't been explored yet.
+    fn explore(
+        &mut self,
+        nfa: &NFA,
+        input: &Input<'_>,
+        look_around: &lookaround::Cache,
+        index: SmallIndex,
+        root: StateID,
+        at: usize,
+    ) {
+        let group = &nfa.atomic_groups()[index];
+        let row = self.rows[index];
+        let reach_of = |curr: &[Reach], sid: StateID| {
+            group.position(sid).map_or(Reach::No, |i| curr[row + i])
+        };
+        if reach_of(&self.curr, root) != Reach::Unknown {
+            return;
+        }
+        // OK because we just looked up 'root' in this group.
+        self.curr[row + group.position(root).unwrap()] = Reach::Exploring;
+        self.stack.push((root, 0));
+        while let Some(&(sid, alt)) = self.stack.last() {
+            // If the previously explored alternative reaches the end of the
+            // group, then so does this state.
+            if alt > 0 {
+                let step = self.step(
+                    nfa,
+                    input,
+                    look_around,
+                    index,
+                    (sid, alt - 1),
+                    at,
+                );
+                if let Step::Explore(prev) = step {
+                    if reach_of(&self.curr, prev) == Reach::Yes {
+                        self.finish(
+                            nfa,
+                            group.position(sid).unwrap() + row,
+                            sid,
+                            Some(alt - 1),
+                            index,
+                            at,
+                        );
+                        continue;
+                    }
+                }
+            }
+            match self.step(nfa, input, look_around, index, (sid, alt), at) {
+                Step::Yes => {
+                    let i = row + group.position(sid).unwrap();
+                    self.finish(nfa, i, sid, Some(alt), index, at);
+                }
+                Step::No => {
+                    let i = row + group.position(sid).unwrap();
+                    self.finish(nfa, i, sid, None, index, at);
+                }
+                Step::Explore(next) => {
+                    // OK because the stack is non-empty.
+                    self.stack.last_mut().unwrap().1 += 1;
+                    if let Some(i) = group.position(next) {
+                        if self.curr[row + i] == Reach::Unknown {
+                            self.curr[row + i] = Reach::Exploring;
+                            self.stack.push((next, 0));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record whether the end of the group with the given index can be
+    /// reached from the state on top of the stack, and pop it off. `i` is
+    /// the location of the state in `curr`, and `choice` is the alternative
+    /// that reaches the end of the group, if any.
+    fn finish(
+        &mut self,
+        nfa: &NFA,
+        i: usize,
+        sid: StateID,
+        choice: Option<usize>,
+        index: SmallIndex,
+        at: usize,
+    ) {
+        self.stack.pop();
+        self.curr[i] = if choice.is_some() { Reach::Yes } else { Reach::No };
+        if let Some((union, innermost)) = nfa.atomic_union(sid) {
+            if innermost == index {
+                let choice = choice.map_or(NONE, |alt| alt as u32);
+                self.choices[union * self.width + (at - self.offset)] = choice;
+            }
+        }
+    }
+
+    /// Follow the alternative numbered `alt` out of `sid` at position `at`,
+    /// where `sid` is in the atomic group with the given index. The state
+    /// and alternative are given as a pair, just as they appear on the
+    /// stack.
+    fn step(
+        &self,
+        nfa: &NFA,
+        input: &Input<'_>,
+        look_around: &lookaround::Cache,
+        index: SmallIndex,
+        (sid, alt): (StateID, usize),
+        at: usize,
+    ) -> Step {
+        let single = |next: StateID| {
+            if alt == 0 {
+                Step::Explore(next)
+            } else {
+                Step::No
+            }
+        };
+        match *nfa.state(sid) {
+            State::ByteRange { .. } | State::Sparse(_) | State::Dense(_) => {
+                if alt > 0 || at >= input.end() {
+                    return Step::No;
+                }
+                let next = match transition(nfa, input.haystack(), at, sid) {
+                    None => return Step::No,
+                    Some(next) => next,
+                };
+                let group = &nfa.atomic_groups()[index];
+                let reach = group
+                    .position(next)
+                    .map_or(Reach::No, |i| self.next[self.rows[index] + i]);
+                if reach == Reach::Yes {
+                    Step::Yes
+                } else {
+                    Step::No
+                }
+            }
+            State::AtomicEnd { index: end, next } => {
+                if end != index {
+                    single(next)
+                } else if alt == 0 {
+                    Step::Yes
+                } else {
+                    Step::No
+                }
+            }
+            State::Look { look, next } => {
+                if nfa.look_matcher().matches(look, input.haystack(), at) {
+                    single(next)
+                } else {
+                    Step::No
+                }
+            }
+            State::LookAround { index: la, next } => {
+                if look_around.holds(la, at) {
+                    single(next)
+                } else {
+                    Step::No
+                }
+            }
+            State::AtomicStart { next, .. } | State::Capture { next, .. } => {
+                single(next)
+            }
+            State::Union { .. } | State::BinaryUnion { .. } => {
+                let innermost = nfa.atomic_union(sid).map(|(_, g)| g);
+                if innermost == Some(index) {
+                    return alternate(nfa, sid, alt)
+                        .map_or(Step::No, Step::Explore);
+                }
+                // This union is inside of a nested atomic group, whose
+                // choices have already been computed.
+                match self.choice(nfa, sid, at) {
+                    Some(next) => single(next),
+                    None => Step::No,
+                }
+            }
+            State::Backreference { .. }
+            | State::Fail
+            | State::Match { .. } => Step::No,
+        }
+    }
+}
+
+/// Returns the alternative numbered `alt` out of the given union state, if
+/// it exists.
+fn alternate(nfa: &NFA, sid: StateID, alt: usize) -> Option<StateID> {
+    match *nfa.state(sid) {
+        State::Union { ref alternates } => alternates.get(alt).copied(),
+        State::BinaryUnion { alt1, alt2 } => match alt {
+            0 => Some(alt1),
+            1 => Some(alt2),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Return the state to transition to from `sid` on the byte at position `at`
+/// in the haystack, if one exists.
+fn transition(
+    nfa: &NFA,
+    haystack: &[u8],
+    at: usize,
+    sid: StateID,
+) -> Option<StateID> {
+    match *nfa.state(sid) {
+        State::ByteRange { ref trans } => {
+            if trans.matches(haystack, at) {
+                Some(trans.next)
+            } else {
+                None
+            }
+        }
+        State::Sparse(ref sparse) => sparse.matches(haystack, at),
+        State::Dense(ref dense) => dense.matches(haystack, at),
+        _ => None,
+    }
+}
@@ -0,0 +1,1407 @@
+/*!
+An NFA backed backtracking regex engine that supports backreferences.
+
+This module provides a [`Backtracker`] that works by simulating an NFA using
+the classical backtracking algorithm. Unlike the
+[`BoundedBacktracker`](thompson::backtrack::BoundedBacktracker), it does not
+remember which parts of the search it has already explored. This is what
+permits it to support [backreferences](State::Backreference) (like `\1`),
+whose behavior depends on the spans of capturing groups and not just on the
+current NFA state and haystack position. But it also means that this engine
+can take exponential time in the worst case.
+
+To avoid running for an unbounded amount of time, every search is given a
+budget of steps via [`Config::step_limit`]. When a search exceeds its budget,
+it stops and returns a [`MatchErrorKind::StepLimitExceeded`] error. A search
+may also be cancelled early with [`Input::cancel_flag`], which the
+backtracker checks every so many steps.
+
+Since backreferences are not part of the default syntax, regexes must be
+compiled with [`syntax::Config::backreferences`] enabled in order to use them.
+The [`Builder`] for this engine does that automatically.
+
+[`MatchErrorKind::StepLimitExceeded`]: crate::MatchErrorKind::StepLimitExceeded
+[`syntax::Config::backreferences`]: crate::util::syntax::Config::backreferences
+*/
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    nfa::thompson::{self, lookaround, BuildError, State, NFA},
+    util::{
+        captures::Captures,
+        empty, iter,
+        primitives::{NonMaxUsize, PatternID, SmallIndex, StateID},
+        search::{
+            Anchored, HalfMatch, Input, Match, MatchError, PatternSet,
+            CANCEL_CHECK_INTERVAL,
+        },
+    },
+};
+
+/// The configuration used for building a backreference backtracker.
+///
+/// A backtracker configuration is a simple data object that is typically used
+/// with [`Builder::configure`].
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    step_limit: Option<Option<usize>>,
+}
+
+impl Config {
+    /// Return a new default regex configuration.
+    pub fn new() -> Config {
+        Config::default()
+    }
+
+    /// Set the maximum number of steps that a single search may take.
+    ///
+    /// A step corresponds to visiting one NFA state at one position in the
+    /// haystack. If a search needs more steps than this limit permits, then
+    /// it stops and returns a
+    /// [`MatchErrorKind::StepLimitExceeded`](crate::MatchErrorKind::StepLimitExceeded)
+    /// error. Since this engine can take exponential time in the worst case,
+    /// this is the main way to guarantee that a search terminates in a
+    /// reasonable amount of time.
+    ///
+    /// When set to `None`, searches may take an unbounded number of steps.
+    ///
+    /// The default is `Some(10_000_000)`, which is the same as the default
+    /// match limit in PCRE2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::backref::Backtracker,
+    ///     MatchErrorKind,
+    /// };
+    ///
+    /// let re = Backtracker::builder()
+    ///     .configure(Backtracker::config().step_limit(Some(1_000)))
+    ///     .build(r"^(a+)+\1$")?;
+    /// let mut cache = re.create_cache();
+    ///
+    /// let haystack = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaab";
+    /// let err = re.try_is_match(&mut cache, haystack).unwrap_err();
+    /// assert_eq!(
+    ///     &MatchErrorKind::StepLimitExceeded { limit: 1_000 },
+    ///     err.kind(),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn step_limit(mut self, limit: Option<usize>) -> Config {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    /// Returns the configured step limit, if one is set.
+    pub fn get_step_limit(&self) -> Option<usize> {
+        self.step_limit.unwrap_or(Some(10_000_000))
+    }
+
+    /// Overwrite the default configuration such that the options in `o` are
+    /// always used. If an option in `o` is not set, then the corresponding
+    /// option in `self` is used. If it's not set in `self` either, then it
+    /// remains not set.
+    pub(crate) fn overwrite(&self, o: Config) -> Config {
+        Config { step_limit: o.step_limit.or(self.step_limit) }
+    }
+}
+
+/// A builder for a backreference backtracker.
+///
+/// This builder permits configuring options for the syntax of a pattern, the
+/// NFA construction and the `Backtracker` construction.
+///
+/// Unlike the builders for other regex engines, the syntax configuration of
+/// this builder always has
+/// [`syntax::Config::backreferences`](crate::util::syntax::Config::backreferences)
+/// enabled, since supporting them is the whole point of this engine.
+///
+/// # Example
+///
+/// This example shows how to disable Unicode mode in the syntax, which
+/// permits `\w` to match only ASCII word bytes.
+///
+/// ```
+/// use regex_automata::{
+///     nfa::thompson::backref::Backtracker,
+///     util::syntax,
+///     Match,
+/// };
+///
+/// let re = Backtracker::builder()
+///     .syntax(syntax::Config::new().unicode(false))
+///     .build(r"(\w+) \1")?;
+/// let mut cache = re.create_cache();
+///
+/// let haystack = "Δ Δ ab ab";
+/// assert_eq!(Some(Match::must(0, 6..11)), re.try_find(&mut cache, haystack)?);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Builder {
+    config: Config,
+    #[cfg(feature = "syntax")]
+    thompson: thompson::Compiler,
+}
+
+impl Builder {
+    /// Create a new Backtracker builder with its default configuration.
+    pub fn new() -> Builder {
+        #[cfg(feature = "syntax")]
+        let mut thompson = thompson::Compiler::new();
+        #[cfg(feature = "syntax")]
+        thompson
+            .syntax(crate::util::syntax::Config::new().backreferences(true));
+        Builder {
+            config: Config::default(),
+            #[cfg(feature = "syntax")]
+            thompson,
+        }
+    }
+
+    /// Build a `Backtracker` from the given pattern.
+    ///
+    /// If there was a problem parsing or compiling the pattern, then an error
+    /// is returned.
+    #[cfg(feature = "syntax")]
+    pub fn build(&self, pattern: &str) -> Result<Backtracker, BuildError> {
+        self.build_many(&[pattern])
+    }
+
+    /// Build a `Backtracker` from the given patterns.
+    #[cfg(feature = "syntax")]
+    pub fn build_many<P: AsRef<str>>(
+        &self,
+        patterns: &[P],
+    ) -> Result<Backtracker, BuildError> {
+        let nfa = self.thompson.build_many(patterns)?;
+        self.build_from_nfa(nfa)
+    }
+
+    /// Build a `Backtracker` directly from its NFA.
+    ///
+    /// Note that when using this method, any configuration that applies to the
+    /// construction of the NFA itself will of course be ignored, since the NFA
+    /// given here is already built.
+    pub fn build_from_nfa(&self, nfa: NFA) -> Result<Backtracker, BuildError> {
+        nfa.look_set_any().available().map_err(BuildError::word)?;
+        Ok(Backtracker { config: self.config.clone(), nfa })
+    }
+
+    /// Apply the given `Backtracker` configuration options to this builder.
+    pub fn configure(&mut self, config: Config) -> &mut Builder {
+        self.config = self.config.overwrite(config);
+        self
+    }
+
+    /// Set the syntax configuration for this builder using
+    /// [`syntax::Config`](crate::util::syntax::Config).
+    ///
+    /// This permits setting things like case insensitivity, Unicode and multi
+    /// line mode. Backreferences are always enabled, regardless of the
+    /// configuration given.
+    ///
+    /// These settings only apply when constructing a `Backtracker` directly
+    /// from a pattern.
+    #[cfg(feature = "syntax")]
+    pub fn syntax(
+        &mut self,
+        config: crate::util::syntax::Config,
+    ) -> &mut Builder {
+        self.thompson.syntax(config.backreferences(true));
+        self
+    }
+
+    /// Set the Thompson NFA configuration for this builder using
+    /// [`nfa::thompson::Config`](crate::nfa::thompson::Config).
+    ///
+    /// Note that backreferences require every capturing group to be compiled,
+    /// so building a regex with backreferences fails if
+    /// [`thompson::Config::which_captures`] is set to anything other than
+    /// [`WhichCaptures::All`](thompson::WhichCaptures::All).
+    ///
+    /// These settings only apply when constructing a `Backtracker` directly
+    /// from a pattern.
+    #[cfg(feature = "syntax")]
+    pub fn thompson(&mut self, config: thompson::Config) -> &mut Builder {
+        self.thompson.configure(config);
+        self
+    }
+}
+
+/// A backtracking regex engine that supports backreferences.
+///
+/// This regex engine only implements leftmost-first match semantics and
+/// only supports leftmost searches. It supports every NFA that the
+/// [`BoundedBacktracker`](thompson::backtrack::BoundedBacktracker) supports,
+/// along with NFAs containing [`State::Backreference`] states. A
+/// backreference matches exactly the bytes most recently matched by the
+/// capturing group it refers to. The comparison is always byte-for-byte,
+/// which is why the parser rejects backreferences when case insensitive mode
+/// is enabled. If the group has not matched, then the backreference does not
+/// match.
+///
+/// # Searches may return an error!
+///
+/// Supporting backreferences means giving up on the time complexity
+/// guarantees of every other regex engine in this crate. This engine may
+/// take exponential time. To avoid running forever, each search is bounded
+/// by a [step limit](Config::step_limit). If a search exceeds that limit,
+/// then it returns an error and callers cannot know whether a match exists
+/// or not. Therefore, this regex engine _only_ exposes fallible search
+/// routines.
+///
+/// Zero-width loops (like in `(a*)*`) are only ever followed once at each
+/// position, just like in every other regex engine in this crate, so such
+/// patterns do not cause a search to run forever on their own.
+///
+/// # Example
+///
+/// ```
+/// use regex_automata::{nfa::thompson::backref::Backtracker, Span};
+///
+/// let re = Backtracker::new(r#"(?<q>['"])(\w+)\k<q>"#)?;
+/// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+///
+/// re.try_captures(&mut cache, r#"say "hello' or 'hi'"#, &mut caps)?;
+/// assert_eq!(Some(Span::from(15..19)), caps.get_group(0));
+/// assert_eq!(Some(Span::from(16..18)), caps.get_group(2));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Backtracker {
+    config: Config,
+    nfa: NFA,
+}
+
+impl Backtracker {
+    /// Parse the given regular expression using the default configuration and
+    /// return the corresponding `Backtracker`.
+    ///
+    /// If you want a non-default configuration, then use the [`Builder`] to
+    /// set your own configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::backref::Backtracker, Match};
+    ///
+    /// let re = Backtracker::new(r"(\w+) \1")?;
+    /// let mut cache = re.create_cache();
+    /// assert_eq!(
+    ///     Some(Match::must(0, 4..15)),
+    ///     re.try_find(&mut cache, "the quick quick fox")?,
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "syntax")]
+    pub fn new(pattern: &str) -> Result<Backtracker, BuildError> {
+        Backtracker::builder().build(pattern)
+    }
+
+    /// Like `new`, but parses multiple patterns into a single "multi regex."
+    /// This similarly uses the default regex configuration.
+    ///
+    /// Backreferences always refer to groups in the same pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::backref::Backtracker, Match};
+    ///
+    /// let re = Backtracker::new_many(&[r"(\d)\1", r"([a-z])\1"])?;
+    /// let mut cache = re.create_cache();
+    ///
+    /// let mut it = re.try_find_iter(&mut cache, "ab 11 cdd 23");
+    /// assert_eq!(Some(Ok(Match::must(0, 3..5))), it.next());
+    /// assert_eq!(Some(Ok(Match::must(1, 7..9))), it.next());
+    /// assert_eq!(None, it.next());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "syntax")]
+    pub fn new_many<P: AsRef<str>>(
+        patterns: &[P],
+    ) -> Result<Backtracker, BuildError> {
+        Backtracker::builder().build_many(patterns)
+    }
+
+    /// Like `new`, but builds a backtracker directly from an NFA. This is
+    /// useful if you already have an NFA, or even if you hand-assembled the
+    /// NFA.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::{backref::Backtracker, NFA},
+    ///     util::syntax,
+    ///     Match,
+    /// };
+    ///
+    /// let nfa = NFA::compiler()
+    ///     .syntax(syntax::Config::new().backreferences(true))
+    ///     .build(r"(a|b)\1")?;
+    /// let re = Backtracker::new_from_nfa(nfa)?;
+    /// let mut cache = re.create_cache();
+    /// assert_eq!(Some(Match::must(0, 1..3)), re.try_find(&mut cache, "abba")?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_from_nfa(nfa: NFA) -> Result<Backtracker, BuildError> {
+        Backtracker::builder().build_from_nfa(nfa)
+    }
+
+    /// Return a default configuration for a `Backtracker`.
+    ///
+    /// This is a convenience routine to avoid needing to import the `Config`
+    /// type when customizing the construction of a `Backtracker`.
+    pub fn config() -> Config {
+        Config::new()
+    }
+
+    /// Return a builder for configuring the construction of a `Backtracker`.
+    ///
+    /// This is a convenience routine to avoid needing to import the
+    /// [`Builder`] type in common cases.
+    pub fn builder() -> Builder {
+        Builder::new()
+    }
+
+    /// Create a new cache for this regex.
+    ///
+    /// The cache returned should only be used for searches for this
+    /// regex. If you want to reuse the cache for another regex, then you
+    /// must call [`Cache::reset`] with that regex (or, equivalently,
+    /// [`Backtracker::reset_cache`]).
+    pub fn create_cache(&self) -> Cache {
+        Cache::new(self)
+    }
+
+    /// Create a new empty set of capturing groups that is guaranteed to be
+    /// valid for the search APIs on this `Backtracker`.
+    ///
+    /// A `Captures` value created for a specific `Backtracker` cannot be used
+    /// with any other `Backtracker`.
+    pub fn create_captures(&self) -> Captures {
+        Captures::all(self.get_nfa().group_info().clone())
+    }
+
+    /// Reset the given cache such that it can be used for searching with
+    /// this `Backtracker` (and only this `Backtracker`).
+    ///
+    /// A cache reset permits reusing memory already allocated in this cache
+    /// with a different `Backtracker`.
+    pub fn reset_cache(&self, cache: &mut Cache) {
+        cache.reset(self);
+    }
+
+    /// Returns the total number of patterns compiled into this `Backtracker`.
+    ///
+    /// In the case of a `Backtracker` that contains no patterns, this returns
+    /// `0`.
+    pub fn pattern_len(&self) -> usize {
+        self.nfa.pattern_len()
+    }
+
+    /// Return the config for this `Backtracker`.
+    #[inline]
+    pub fn get_config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Returns a reference to the underlying NFA.
+    #[inline]
+    pub fn get_nfa(&self) -> &NFA {
+        &self.nfa
+    }
+}
+
+impl Backtracker {
+    /// Returns true if and only if this regex matches the given haystack.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search could not complete. For this
+    /// regex engine, this only occurs when the search exceeds the configured
+    /// [step limit](Config::step_limit), or when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// When a search cannot complete, callers cannot know whether a match
+    /// exists or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::nfa::thompson::backref::Backtracker;
+    ///
+    /// let re = Backtracker::new(r"^(a+)b\1$")?;
+    /// let mut cache = re.create_cache();
+    ///
+    /// assert!(re.try_is_match(&mut cache, "aabaa")?);
+    /// assert!(!re.try_is_match(&mut cache, "aaba")?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_is_match<'h, I: Into<Input<'h>>>(
+        &self,
+        cache: &mut Cache,
+        input: I,
+    ) -> Result<bool, MatchError> {
+        let input = input.into().earliest(true);
+        self.try_search_slots(cache, &input, &mut []).map(|pid| pid.is_some())
+    }
+
+    /// Executes a leftmost forward search and returns a `Match` if one exists.
+    ///
+    /// This routine only includes the overall match span. To get
+    /// access to the individual spans of each capturing group, use
+    /// [`Backtracker::try_captures`].
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search could not complete. For this
+    /// regex engine, this only occurs when the search exceeds the configured
+    /// [step limit](Config::step_limit), or when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// When a search cannot complete, callers cannot know whether a match
+    /// exists or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::backref::Backtracker, Match};
+    ///
+    /// let re = Backtracker::new(r"<(\w+)>[^<]*</\1>")?;
+    /// let mut cache = re.create_cache();
+    /// let haystack = "<a><b>x</b></a>";
+    /// assert_eq!(Some(Match::must(0, 3..11)), re.try_find(&mut cache, haystack)?);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_find<'h, I: Into<Input<'h>>>(
+        &self,
+        cache: &mut Cache,
+        input: I,
+    ) -> Result<Option<Match>, MatchError> {
+        let input = input.into();
+        let ginfo = self.get_nfa().group_info();
+        let mut slots = vec![None; ginfo.implicit_slot_len()];
+        let pid = match self.try_search_slots(cache, &input, &mut slots)? {
+            None => return Ok(None),
+            Some(pid) => pid,
+        };
+        let start = match slots[pid.as_usize() * 2] {
+            None => return Ok(None),
+            Some(s) => s.get(),
+        };
+        let end = match slots[pid.as_usize() * 2 + 1] {
+            None => return Ok(None),
+            Some(s) => s.get(),
+        };
+        Ok(Some(Match::new(pid, start..end)))
+    }
+
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided [`Captures`]
+    /// value. If no match was found, then [`Captures::is_match`] is guaranteed
+    /// to return `false`.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search could not complete. For this
+    /// regex engine, this only occurs when the search exceeds the configured
+    /// [step limit](Config::step_limit), or when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// When a search cannot complete, callers cannot know whether a match
+    /// exists or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::backref::Backtracker, Span};
+    ///
+    /// let re = Backtracker::new(r"(\w)(\w)\2\1")?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    ///
+    /// re.try_captures(&mut cache, "a noon", &mut caps)?;
+    /// assert!(caps.is_match());
+    /// assert_eq!(Some(Span::from(2..6)), caps.get_group(0));
+    /// assert_eq!(Some(Span::from(2..3)), caps.get_group(1));
+    /// assert_eq!(Some(Span::from(3..4)), caps.get_group(2));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_captures<'h, I: Into<Input<'h>>>(
+        &self,
+        cache: &mut Cache,
+        input: I,
+        caps: &mut Captures,
+    ) -> Result<(), MatchError> {
+        self.try_search(cache, &input.into(), caps)
+    }
+
+    /// Returns an iterator over all non-overlapping leftmost matches in the
+    /// given bytes. If no match exists, then the iterator yields no elements.
+    ///
+    /// If the regex engine returns an error at any point, then the iterator
+    /// will yield that error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::backref::Backtracker,
+    ///     Match, MatchError,
+    /// };
+    ///
+    /// let re = Backtracker::new(r"(.)\1")?;
+    /// let mut cache = re.create_cache();
+    ///
+    /// let text = "aabccdeff";
+    /// let result: Result<Vec<Match>, MatchError> =
+    ///     re.try_find_iter(&mut cache, text).collect();
+    /// let matches = result?;
+    /// assert_eq!(matches, vec![
+    ///     Match::must(0, 0..2),
+    ///     Match::must(0, 3..5),
+    ///     Match::must(0, 7..9),
+    /// ]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_find_iter<'r, 'c, 'h, I: Into<Input<'h>>>(
+        &'r self,
+        cache: &'c mut Cache,
+        input: I,
+    ) -> TryFindMatches<'r, 'c, 'h> {
+        let caps = Captures::matches(self.get_nfa().group_info().clone());
+        let it = iter::Searcher::new(input.into());
+        TryFindMatches { re: self, cache, caps, it }
+    }
+
+    /// Returns an iterator over all non-overlapping `Captures` values. If no
+    /// match exists, then the iterator yields no elements.
+    ///
+    /// This yields the same matches as [`Backtracker::try_find_iter`], but it
+    /// includes the spans of all capturing groups that participate in each
+    /// match.
+    ///
+    /// If the regex engine returns an error at any point, then the iterator
+    /// will yield that error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::backref::Backtracker, Span};
+    ///
+    /// let re = Backtracker::new(r"(\w+)=\1")?;
+    /// let mut cache = re.create_cache();
+    ///
+    /// let text = "a=a bc=db de=de";
+    /// let mut spans = vec![];
+    /// for result in re.try_captures_iter(&mut cache, text) {
+    ///     let caps = result?;
+    ///     spans.push(caps.get_group(1).unwrap());
+    /// }
+    /// assert_eq!(spans, vec![Span::from(0..1), Span::from(10..12)]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_captures_iter<'r, 'c, 'h, I: Into<Input<'h>>>(
+        &'r self,
+        cache: &'c mut Cache,
+        input: I,
+    ) -> TryCapturesMatches<'r, 'c, 'h> {
+        let caps = self.create_captures();
+        let it = iter::Searcher::new(input.into());
+        TryCapturesMatches { re: self, cache, caps, it }
+    }
+}
+
+impl Backtracker {
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided [`Captures`]
+    /// value. If no match was found, then [`Captures::is_match`] is guaranteed
+    /// to return `false`.
+    ///
+    /// This is like [`Backtracker::try_captures`], but it accepts a concrete
+    /// `&Input` instead of an `Into<Input>`.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search could not complete. For this
+    /// regex engine, this only occurs when the search exceeds the configured
+    /// [step limit](Config::step_limit), or when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// When a search cannot complete, callers cannot know whether a match
+    /// exists or not.
+    ///
+    /// # Example: specific pattern search
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::backref::Backtracker,
+    ///     Anchored, Input, Match, PatternID,
+    /// };
+    ///
+    /// let re = Backtracker::new_many(&[r"(\w)\1", r"(\w)(\w)\2"])?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// let haystack = "abb";
+    ///
+    /// let expected = Some(Match::must(1, 0..3));
+    /// re.try_search(&mut cache, &Input::new(haystack), &mut caps)?;
+    /// assert_eq!(expected, caps.get_match());
+    ///
+    /// let expected = Some(Match::must(0, 1..3));
+    /// let input = Input::new(haystack)
+    ///     .range(1..)
+    ///     .anchored(Anchored::Pattern(PatternID::must(0)));
+    /// re.try_search(&mut cache, &input, &mut caps)?;
+    /// assert_eq!(expected, caps.get_match());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        caps: &mut Captures,
+    ) -> Result<(), MatchError> {
+        caps.set_pattern(None);
+        let pid = self.try_search_slots(cache, input, caps.slots_mut())?;
+        caps.set_pattern(pid);
+        Ok(())
+    }
+
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided `slots`, and
+    /// returns the matching pattern ID. The contents of the slots for patterns
+    /// other than the matching pattern are unspecified. If no match was found,
+    /// then `None` is returned and the contents of all `slots` is unspecified.
+    ///
+    /// This is like [`Backtracker::try_search`], but it accepts a raw slots
+    /// slice instead of a `Captures` value. This is useful in contexts where
+    /// you don't want or need to allocate a `Captures`.
+    ///
+    /// It is legal to pass _any_ number of slots to this routine. If the regex
+    /// engine would otherwise write a slot offset that doesn't fit in the
+    /// provided slice, then it is simply skipped. Since backreferences need
+    /// the spans of the groups they refer to, this engine always tracks every
+    /// capturing group internally, regardless of how many slots are given.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search could not complete. For this
+    /// regex engine, this only occurs when the search exceeds the configured
+    /// [step limit](Config::step_limit), or when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// When a search cannot complete, callers cannot know whether a match
+    /// exists or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::backref::Backtracker,
+    ///     PatternID, Input,
+    /// };
+    ///
+    /// let re = Backtracker::new_many(&[r"(\pL)\1", r"(\d)\1"])?;
+    /// let mut cache = re.create_cache();
+    /// let input = Input::new("!@#1233");
+    ///
+    /// let mut slots = [None; 4];
+    /// let pid = re.try_search_slots(&mut cache, &input, &mut slots)?;
+    /// assert_eq!(Some(PatternID::must(1)), pid);
+    ///
+    /// let slot_start = pid.unwrap().as_usize() * 2;
+    /// let slot_end = slot_start + 1;
+    /// assert_eq!(Some(5), slots[slot_start].map(|s| s.get()));
+    /// assert_eq!(Some(7), slots[slot_end].map(|s| s.get()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
+        for slot in slots.iter_mut() {
+            *slot = None;
+        }
+        let hm = match self.try_search_slots_imp(cache, input)? {
+            None => return Ok(None),
+            Some(hm) => hm,
+        };
+        let len = core::cmp::min(slots.len(), cache.slots.len());
+        slots[..len].copy_from_slice(&cache.slots[..len]);
+        Ok(Some(hm.pattern()))
+    }
+
+    /// Writes the set of patterns that match anywhere in the given search
+    /// configuration to `patset`.
+    ///
+    /// Unlike leftmost searches, this explores every way in which each
+    /// pattern might match, and so it always scans the entire search span.
+    /// If `patset` becomes full, then the search stops early.
+    ///
+    /// This routine does not clear `patset` before searching.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search could not complete. For this
+    /// regex engine, this only occurs when the search exceeds the configured
+    /// [step limit](Config::step_limit), or when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// When a search cannot complete, `patset` may contain some, but not all,
+    /// of the patterns that match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::backref::Backtracker,
+    ///     Input, PatternSet,
+    /// };
+    ///
+    /// let re = Backtracker::new_many(&[r"(\w)\1", r"\d", r"(\d)\1"])?;
+    /// let mut cache = re.create_cache();
+    /// let input = Input::new("aa 1 23");
+    /// let mut patset = PatternSet::new(re.pattern_len());
+    /// re.try_which_overlapping_matches(&mut cache, &input, &mut patset)?;
+    /// let expected = vec![0, 1];
+    /// let got: Vec<usize> = patset.iter().map(|p| p.as_usize()).collect();
+    /// assert_eq!(expected, got);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_which_overlapping_matches(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        patset: &mut PatternSet,
+    ) -> Result<(), MatchError> {
+        cache.setup_search(self, input);
+        if input.is_done() {
+            return Ok(());
+        }
+        let (anchored, start_id) = match self.start_config(input) {
+            None => return Ok(()),
+            Some(config) => config,
+        };
+        for at in input.start()..=input.end() {
+            cache.stack.push(Frame::Step { sid: start_id, at });
+            while let Some(hm) = self.backtrack(cache, input)? {
+                patset.insert(hm.pattern());
+                if patset.is_full() {
+                    return Ok(());
+                }
+            }
+            if anchored {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// This is the actual implementation of `try_search_slots` that reports
+    /// a match in terms of the cache's slots. It accounts for the special
+    /// case when the NFA has UTF-8 mode enabled and can match the empty
+    /// string, in which case, empty matches that split a codepoint are
+    /// skipped.
+    #[inline(never)]
+    fn try_search_slots_imp(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
+        let hm = match self.search_imp(cache, input)? {
+            None => return Ok(None),
+            Some(hm) if !utf8empty => return Ok(Some(hm)),
+            Some(hm) => hm,
+        };
+        empty::skip_splits_fwd(input, hm, hm.offset(), |input| {
+            Ok(self.search_imp(cache, input)?.map(|hm| (hm, hm.offset())))
+        })
+    }
+
+    /// The implementation of standard leftmost backtracking search.
+    ///
+    /// Capturing group spans are always written to the cache's slots, since
+    /// backreferences need them regardless of what the caller asked for.
+    fn search_imp(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        cache.setup_search(self, input);
+        if input.is_done() {
+            return Ok(None);
+        }
+        let (anchored, start_id) = match self.start_config(input) {
+            None => return Ok(None),
+            Some(config) => config,
+        };
+        for at in input.start()..=input.end() {
+            cache.stack.push(Frame::Step { sid: start_id, at });
+            if let Some(hm) = self.backtrack(cache, input)? {
+                return Ok(Some(hm));
+            }
+            if anchored {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns whether the given search is anchored and the state it starts
+    /// in. If the search is for a pattern that doesn't exist, then `None` is
+    /// returned.
+    fn start_config(&self, input: &Input<'_>) -> Option<(bool, StateID)> {
+        match input.get_anchored() {
+            // As with the bounded backtracker, we always use the anchored
+            // starting state. An unanchored search is implemented by trying
+            // each position in the search span in turn.
+            Anchored::No => Some((
+                self.nfa.is_always_start_anchored(),
+                self.nfa.start_anchored(),
+            )),
+            Anchored::Yes => Some((true, self.nfa.start_anchored())),
+            Anchored::Pattern(pid) => {
+                self.nfa.start_pattern(pid).map(|sid| (true, sid))
+            }
+        }
+    }
+
+    /// Run the frames on the backtracking stack until either a match is found
+    /// or the stack is exhausted.
+    ///
+    /// When a match is found, any remaining frames are left on the stack.
+    /// Calling this routine again will then continue looking for other ways
+    /// to match, which is how overlapping searches are implemented.
+    fn backtrack(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        while let Some(frame) = cache.stack.pop() {
+            match frame {
+                Frame::Step { sid, at } => {
+                    if let Some(hm) = self.step(cache, input, sid, at)? {
+                        return Ok(Some(hm));
+                    }
+                }
+                Frame::RestoreCapture { slot, offset } => {
+                    cache.slots[slot] = offset;
+                }
+                Frame::RestoreOpen { index, offset } => {
+                    cache.opens[index] = offset;
+                }
+                Frame::RestoreMark { sid, at } => {
+                    cache.marks[sid] = at;
+                }
+                // Reaching the start of an atomic group while backtracking
+                // means every way of matching it has been tried, so there is
+                // nothing left to cut.
+                Frame::Atomic { .. } => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// Execute a "step" in the backtracking algorithm.
+    ///
+    /// As with the bounded backtracker, this keeps going until it either runs
+    /// out of things to try or finds a match. Alternative branches are pushed
+    /// on to the stack, to be tried by `backtrack` if this one fails.
+    ///
+    /// Capturing group spans are not committed to their slots until the
+    /// group ends. This is so that a backreference inside the group it refers
+    /// to (e.g., `(a\1)*`) sees the span from the group's previous iteration.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn step(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        mut sid: StateID,
+        mut at: usize,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        loop {
+            if let Some(limit) = self.get_config().get_step_limit() {
+                if cache.steps >= limit {
+                    return Err(MatchError::step_limit_exceeded(limit));
+                }
+            }
+            if cache.steps % CANCEL_CHECK_INTERVAL == 0 && input.is_cancelled()
+            {
+                return Err(MatchError::cancelled(at));
+            }
+            cache.steps += 1;
+            match *self.nfa.state(sid) {
+                State::ByteRange { ref trans } => {
+                    if at >= input.end() {
+                        return Ok(None);
+                    }
+                    if !trans.matches(input.haystack(), at) {
+                        return Ok(None);
+                    }
+                    sid = trans.next;
+                    at += 1;
+                }
+                State::Sparse(ref sparse) => {
+                    if at >= input.end() {
+                        return Ok(None);
+                    }
+                    sid = match sparse.matches(input.haystack(), at) {
+                        None => return Ok(None),
+                        Some(sid) => sid,
+                    };
+                    at += 1;
+                }
+                State::Dense(ref dense) => {
+                    if at >= input.end() {
+                        return Ok(None);
+                    }
+                    sid = match dense.matches(input.haystack(), at) {
+                        None => return Ok(None),
+                        Some(sid) => sid,
+                    };
+                    at += 1;
+                }
+                State::Look { look, next } => {
+                    // OK because we don't permit building a searcher with a
+                    // Unicode word boundary if the requisite Unicode data is
+                    // unavailable.
+                    if !self.nfa.look_matcher().matches_inline(
+                        look,
+                        input.haystack(),
+                        at,
+                    ) {
+                        return Ok(None);
+                    }
+                    sid = next;
+                }
+                State::LookAround { index, next } => {
+                    if !cache.look_around.holds(index, at) {
+                        return Ok(None);
+                    }
+                    sid = next;
+                }
+                State::Backreference { pattern_id, group_index, next } => {
+                    // OK because the NFA builder (and deserializer) check
+                    // that every backreference refers to a real group.
+                    let slot = self
+                        .nfa
+                        .group_info()
+                        .slot(pattern_id, group_index.as_usize())
+                        .unwrap();
+                    let (start, end) =
+                        match (cache.slots[slot], cache.slots[slot + 1]) {
+                            (Some(start), Some(end)) => {
+                                (start.get(), end.get())
+                            }
+                            _ => return Ok(None),
+                        };
+                    let len = end - start;
+                    if len > input.end() - at {
+                        return Ok(None);
+                    }
+                    let hay = input.haystack();
+                    if hay[start..end] != hay[at..at + len] {
+                        return Ok(None);
+                    }
+                    sid = next;
+                    at += len;
+                }
+                State::Union { ref alternates } => {
+                    if !cache.mark(sid, at) {
+                        return Ok(None);
+                    }
+                    sid = match alternates.get(0) {
+                        None => return Ok(None),
+                        Some(&sid) => sid,
+                    };
+                    cache.stack.extend(
+                        alternates[1..]
+                            .iter()
+                            .copied()
+                            .rev()
+                            .map(|sid| Frame::Step { sid, at }),
+                    );
+                }
+                State::BinaryUnion { alt1, alt2 } => {
+                    if !cache.mark(sid, at) {
+                        return Ok(None);
+                    }
+                    sid = alt1;
+                    cache.stack.push(Frame::Step { sid: alt2, at });
+                }
+                State::Capture { next, slot, .. } => {
+                    let offset = NonMaxUsize::new(at);
+                    if slot.as_usize() % 2 == 0 {
+                        let index = slot.as_usize() / 2;
+                        cache.stack.push(Frame::RestoreOpen {
+                            index,
+                            offset: cache.opens[index],
+                        });
+                        cache.opens[index] = offset;
+                    } else {
+                        let (start, end) = (slot.as_usize() - 1, slot);
+                        cache.stack.push(Frame::RestoreCapture {
+                            slot: start,
+                            offset: cache.slots[start],
+                        });
+                        cache.stack.push(Frame::RestoreCapture {
+                            slot: end.as_usize(),
+                            offset: cache.slots[end],
+                        });
+                        cache.slots[start] = cache.opens[start / 2];
+                        cache.slots[end] = offset;
+                    }
+                    sid = next;
+                }
+                State::AtomicStart { index, next } => {
+                    cache.stack.push(Frame::Atomic { index });
+                    sid = next;
+                }
+                State::AtomicEnd { index, next } => {
+                    atomic_cut(&mut cache.stack, index);
+                    sid = next;
+                }
+                State::Fail => return Ok(None),
+                State::Match { pattern_id } => {
+                    return Ok(Some(HalfMatch::new(pattern_id, at)));
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over all non-overlapping matches for a fallible search.
+///
+/// The iterator yields a `Result<Match, MatchError` value until no more
+/// matches could be found.
+///
+/// The lifetime parameters are as follows:
+///
+/// * `'r` represents the lifetime of the Backtracker.
+/// * `'c` represents the lifetime of the Backtracker's cache.
+/// * `'h` represents the lifetime of the haystack being searched.
+///
+/// This iterator can be created with the [`Backtracker::try_find_iter`]
+/// method.
+#[derive(Debug)]
+pub struct TryFindMatches<'r, 'c, 'h> {
+    re: &'r Backtracker,
+    cache: &'c mut Cache,
+    caps: Captures,
+    it: iter::Searcher<'h>,
+}
+
+impl<'r, 'c, 'h> Iterator for TryFindMatches<'r, 'c, 'h> {
+    type Item = Result<Match, MatchError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<Match, MatchError>> {
+        // Splitting 'self' apart seems necessary to appease borrowck.
+        let TryFindMatches { re, ref mut cache, ref mut caps, ref mut it } =
+            *self;
+        it.try_advance(|input| {
+            re.try_search(cache, input, caps)?;
+            Ok(caps.get_match())
+        })
+        .transpose()
+    }
+}
+
+/// An iterator over all non-overlapping leftmost matches, with their capturing
+/// groups, for a fallible search.
+///
+/// The iterator yields a `Result<Captures, MatchError>` value until no more
+/// matches could be found.
+///
+/// The lifetime parameters are as follows:
+///
+/// * `'r` represents the lifetime of the Backtracker.
+/// * `'c` represents the lifetime of the Backtracker's cache.
+/// * `'h` represents the lifetime of the haystack being searched.
+///
+/// This iterator can be created with the [`Backtracker::try_captures_iter`]
+/// method.
+#[derive(Debug)]
+pub struct TryCapturesMatches<'r, 'c, 'h> {
+    re: &'r Backtracker,
+    cache: &'c mut Cache,
+    caps: Captures,
+    it: iter::Searcher<'h>,
+}
+
+impl<'r, 'c, 'h> Iterator for TryCapturesMatches<'r, 'c, 'h> {
+    type Item = Result<Captures, MatchError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<Captures, MatchError>> {
+        // Splitting 'self' apart seems necessary to appease borrowck.
+        let TryCapturesMatches { re, ref mut cache, ref mut caps, ref mut it } =
+            *self;
+        let _ = it
+            .try_advance(|input| {
+                re.try_search(cache, input, caps)?;
+                Ok(caps.get_match())
+            })
+            .transpose()?;
+        if caps.is_match() {
+            Some(Ok(caps.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+/// A cache represents mutable state that a [`Backtracker`] requires during a
+/// search.
+///
+/// For a given [`Backtracker`], its corresponding cache may be created either
+/// via [`Backtracker::create_cache`], or via [`Cache::new`]. They are
+/// equivalent in every way, except the former does not require explicitly
+/// importing `Cache`.
+///
+/// A particular `Cache` is coupled with the [`Backtracker`] from which it was
+/// created. It may only be used with that `Backtracker`. A cache and its
+/// allocations may be re-purposed via [`Cache::reset`], in which case, it can
+/// only be used with the new `Backtracker` (and not the old one).
+#[derive(Clone, Debug)]
+pub struct Cache {
+    /// Stack used on the heap for doing backtracking instead of the
+    /// traditional recursive approach. We don't want recursion because then
+    /// we're likely to hit a stack overflow for bigger regexes.
+    stack: Vec<Frame>,
+    /// The committed spans of every capturing group on the current path.
+    /// Backreferences read from these.
+    slots: Vec<Option<NonMaxUsize>>,
+    /// The start offset of every capturing group that has been opened, but
+    /// not necessarily closed, on the current path. The group that owns
+    /// slots `2i` and `2i+1` stores its open offset at index `i`.
+    opens: Vec<Option<NonMaxUsize>>,
+    /// For each union state, the position at which it was most recently
+    /// visited on the current path. If a path visits the same union state
+    /// twice at the same position, then it went around a loop without
+    /// consuming any input, and following it again can't lead anywhere new.
+    marks: Vec<Option<usize>>,
+    /// The number of steps taken so far in the current search.
+    steps: usize,
+    /// Where each look-around assertion with a sub-expression holds for the
+    /// current search. This is empty when the NFA has no such assertions.
+    look_around: lookaround::Cache,
+}
+
+impl Cache {
+    /// Create a new [`Backtracker`] cache.
+    ///
+    /// A potentially more convenient routine to create a cache is
+    /// [`Backtracker::create_cache`], as it does not require also importing
+    /// the `Cache` type.
+    ///
+    /// If you want to reuse the returned `Cache` with some other
+    /// `Backtracker`, then you must call [`Cache::reset`] with the desired
+    /// `Backtracker`.
+    pub fn new(re: &Backtracker) -> Cache {
+        let mut cache = Cache {
+            stack: vec![],
+            slots: vec![],
+            opens: vec![],
+            marks: vec![],
+            steps: 0,
+            look_around: lookaround::Cache::new(re.get_nfa()),
+        };
+        cache.reset(re);
+        cache
+    }
+
+    /// Reset this cache such that it can be used for searching with a
+    /// different [`Backtracker`].
+    ///
+    /// A cache reset permits reusing memory already allocated in this cache
+    /// with a different `Backtracker`.
+    pub fn reset(&mut self, re: &Backtracker) {
+        let slot_len = re.get_nfa().group_info().slot_len();
+        self.stack.clear();
+        self.slots.clear();
+        self.slots.resize(slot_len, None);
+        self.opens.clear();
+        self.opens.resize(slot_len / 2, None);
+        self.marks.clear();
+        self.marks.resize(re.get_nfa().states().len(), None);
+        self.steps = 0;
+        self.look_around.reset(re.get_nfa());
+    }
+
+    /// Returns the heap memory usage, in bytes, of this cache.
+    ///
+    /// This does **not** include the stack size used up by this cache. To
+    /// compute that, use `std::mem::size_of::<Cache>()`.
+    pub fn memory_usage(&self) -> usize {
+        use core::mem::size_of;
+
+        self.stack.len() * size_of::<Frame>()
+            + (self.slots.len() + self.opens.len())
+                * size_of::<Option<NonMaxUsize>>()
+            + self.marks.len() * size_of::<Option<usize>>()
+            + self.look_around.memory_usage()
+    }
+
+    /// Clears this cache. This should be called at the start of every search
+    /// to ensure we start with a clean slate.
+    fn setup_search(&mut self, re: &Backtracker, input: &Input<'_>) {
+        self.stack.clear();
+        for slot in self.slots.iter_mut() {
+            *slot = None;
+        }
+        for open in self.opens.iter_mut() {
+            *open = None;
+        }
+        for mark in self.marks.iter_mut() {
+            *mark = None;
+        }
+        self.steps = 0;
+        self.look_around.setup_search(re.get_nfa(), input);
+    }
+
+    /// Record that the given union state was visited at the given position on
+    /// the current path. If it was already visited at this position, then
+    /// this returns false and nothing is recorded.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn mark(&mut self, sid: StateID, at: usize) -> bool {
+        if self.marks[sid] == Some(at) {
+            return false;
+        }
+        self.stack.push(Frame::RestoreMark { sid, at: self.marks[sid] });
+        self.marks[sid] = Some(at);
+        true
+    }
+}
+
+/// Represents a stack frame on the heap while doing backtracking.
+///
+/// Every change to the state of the current path (capturing group spans and
+/// union state marks) pushes a frame that undoes it. So when backtracking
+/// pops back to a `Step` frame for an alternative branch, the path state is
+/// exactly what it was when that branch was pushed.
+#[derive(Clone, Debug)]
+enum Frame {
+    /// Look for a match starting at `sid` and the given position in the
+    /// haystack.
+    Step { sid: StateID, at: usize },
+    /// Reset the given committed `slot` to the given `offset`.
+    RestoreCapture { slot: usize, offset: Option<NonMaxUsize> },
+    /// Reset the open offset of the group at the given `index`.
+    RestoreOpen { index: usize, offset: Option<NonMaxUsize> },
+    /// Reset the mark of the given union state.
+    RestoreMark { sid: StateID, at: Option<usize> },
+    /// Marks the start of the atomic group with the given index. When the end
+    /// of the group is reached, every `Step` frame pushed after this marker
+    /// is discarded, such that no other way of matching the group is tried.
+    Atomic { index: SmallIndex },
+}
+
+/// Discard every `Step` frame pushed since the most recent marker for the
+/// atomic group with the given index, along with the marker itself.
+///
+/// All of the frames that restore the state of the current path are kept,
+/// since they are still needed if backtracking continues past the group.
+fn atomic_cut(stack: &mut Vec<Frame>, index: SmallIndex) {
+    let start = match stack.iter().rposition(
+        |frame| matches!(*frame, Frame::Atomic { index: i } if i == index),
+    ) {
+        None => return,
+        Some(start) => start,
+    };
+    let mut keep = start;
+    for i in start + 1..stack.len() {
+        match stack[i] {
+            Frame::Step { .. } | Frame::Atomic { .. } => {}
+            Frame::RestoreCapture { .. }
+            | Frame::RestoreOpen { .. }
+            | Frame::RestoreMark { .. } => {
+                stack.swap(keep, i);
+                keep += 1;
+            }
+        }
+    }
+    stack.truncate(keep);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A backreference inside the group it refers to sees the span from the
+    // group's previous iteration, and never matches on the first one.
+    #[test]
+    fn self_reference() {
+        let re = Backtracker::new(r"^(a|b\1)+$").unwrap();
+        let mut cache = re.create_cache();
+        assert!(re.try_is_match(&mut cache, "aba").unwrap());
+        assert!(re.try_is_match(&mut cache, "abaaba").unwrap());
+        assert!(!re.try_is_match(&mut cache, "abb").unwrap());
+        assert!(!re.try_is_match(&mut cache, "b").unwrap());
+    }
+
+    // Empty loops must not make the search run until it exceeds its budget.
+    #[test]
+    fn empty_loops() {
+        let re = Backtracker::builder()
+            .configure(Backtracker::config().step_limit(Some(10_000)))
+            .build(r"(a*)*(b|\1)*c")
+            .unwrap();
+        let mut cache = re.create_cache();
+        assert_eq!(
+            Some(Match::must(0, 0..6)),
+            re.try_find(&mut cache, "aaabbc").unwrap(),
+        );
+        assert_eq!(None, re.try_find(&mut cache, "aaabb").unwrap());
+    }
+
+    #[test]
+    fn unset_group() {
+        let re = Backtracker::new(r"(?:(a)|b)\1").unwrap();
+        let mut cache = re.create_cache();
+        assert_eq!(
+            Some(Match::must(0, 1..3)),
+            re.try_find(&mut cache, "baa").unwrap(),
+        );
+        assert_eq!(None, re.try_find(&mut cache, "bb").unwrap());
+    }
+
+    #[test]
+    fn step_limit_is_per_search() {
+        let re = Backtracker::builder()
+            .configure(Backtracker::config().step_limit(Some(100)))
+            .build(r"(\w)\1")
+            .unwrap();
+        let mut cache = re.create_cache();
+        let matches: Vec<Match> = re
+            .try_find_iter(&mut cache, "aa bb cc dd ee ff")
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(6, matches.len());
+    }
+}
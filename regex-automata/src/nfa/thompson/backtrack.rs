@@ -13,7 +13,7 @@ because it does less book-keeping.
 use alloc::{vec, vec::Vec};
 
 use crate::{
-    nfa::thompson::{self, BuildError, State, NFA},
+    nfa::thompson::{self, lookaround, BuildError, State, NFA},
     util::{
         captures::Captures,
         empty, iter,
@@ -301,6 +301,15 @@ impl Builder {
         nfa: NFA,
     ) -> Result<BoundedBacktracker, BuildError> {
         nfa.look_set_any().available().map_err(BuildError::word)?;
+        if nfa.has_backreference() {
+            return Err(BuildError::unsupported_backreference());
+        }
+        // The visited set assumes that whether a match can be found from a
+        // state at a position doesn't depend on how it was reached. Atomic
+        // groups break that assumption.
+        if nfa.has_atomic() {
+            return Err(BuildError::unsupported_atomic());
+        }
         Ok(BoundedBacktracker { config: self.config.clone(), nfa })
     }
 
@@ -840,7 +849,8 @@ impl BoundedBacktracker {
     ///
     /// This routine only errors if the search could not complete. For this
     /// backtracking regex engine, this only occurs when the haystack length
-    /// exceeds [`BoundedBacktracker::max_haystack_len`].
+    /// exceeds [`BoundedBacktracker::max_haystack_len`], or when the search
+    /// is cancelled via [`Input::cancel_flag`].
     ///
     /// When a search cannot complete, callers cannot know whether a match
     /// exists or not.
@@ -915,7 +925,8 @@ impl BoundedBacktracker {
     ///
     /// This routine only errors if the search could not complete. For this
     /// backtracking regex engine, this only occurs when the haystack length
-    /// exceeds [`BoundedBacktracker::max_haystack_len`].
+    /// exceeds [`BoundedBacktracker::max_haystack_len`], or when the search
+    /// is cancelled via [`Input::cancel_flag`].
     ///
     /// When a search cannot complete, callers cannot know whether a match
     /// exists or not.
@@ -985,7 +996,8 @@ impl BoundedBacktracker {
     ///
     /// This routine only errors if the search could not complete. For this
     /// backtracking regex engine, this only occurs when the haystack length
-    /// exceeds [`BoundedBacktracker::max_haystack_len`].
+    /// exceeds [`BoundedBacktracker::max_haystack_len`], or when the search
+    /// is cancelled via [`Input::cancel_flag`].
     ///
     /// When a search cannot complete, callers cannot know whether a match
     /// exists or not.
@@ -1126,7 +1138,8 @@ impl BoundedBacktracker {
     ///
     /// This routine only errors if the search could not complete. For this
     /// backtracking regex engine, this only occurs when the haystack length
-    /// exceeds [`BoundedBacktracker::max_haystack_len`].
+    /// exceeds [`BoundedBacktracker::max_haystack_len`], or when the search
+    /// is cancelled via [`Input::cancel_flag`].
     ///
     /// When a search cannot complete, callers cannot know whether a match
     /// exists or not.
@@ -1247,7 +1260,8 @@ impl BoundedBacktracker {
     ///
     /// This routine only errors if the search could not complete. For this
     /// backtracking regex engine, this only occurs when the haystack length
-    /// exceeds [`BoundedBacktracker::max_haystack_len`].
+    /// exceeds [`BoundedBacktracker::max_haystack_len`], or when the search
+    /// is cancelled via [`Input::cancel_flag`].
     ///
     /// When a search cannot complete, callers cannot know whether a match
     /// exists or not.
@@ -1392,8 +1406,14 @@ impl BoundedBacktracker {
                 Some(sid) => (true, sid),
             },
         };
+        // The amount of work done for each starting position is bounded by
+        // the size of the visited set, so it suffices to check whether the
+        // search has been cancelled before each one.
         if anchored {
             let at = input.start();
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
             return Ok(self.backtrack(cache, input, at, start_id, slots));
         }
         let pre = self.get_config().get_prefilter();
@@ -1406,6 +1426,9 @@ impl BoundedBacktracker {
                     Some(ref span) => at = span.start,
                 }
             }
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
             if let Some(hm) = self.backtrack(cache, input, at, start_id, slots)
             {
                 return Ok(Some(hm));
@@ -1519,6 +1542,12 @@ impl BoundedBacktracker {
                     }
                     sid = next;
                 }
+                State::LookAround { index, next } => {
+                    if !cache.look_around.holds(index, at) {
+                        return None;
+                    }
+                    sid = next;
+                }
                 State::Union { ref alternates } => {
                     sid = match alternates.get(0) {
                         None => return None,
@@ -1546,7 +1575,13 @@ impl BoundedBacktracker {
                     }
                     sid = next;
                 }
-                State::Fail => return None,
+                // NFAs with backreferences or atomic groups are rejected when
+                // building a bounded backtracker, so these are never reached
+                // in practice.
+                State::Fail
+                | State::Backreference { .. }
+                | State::AtomicStart { .. }
+                | State::AtomicEnd { .. } => return None,
                 State::Match { pattern_id } => {
                     return Some(HalfMatch::new(pattern_id, at));
                 }
@@ -1661,6 +1696,9 @@ pub struct Cache {
     /// what "bounds" the backtracking and prevents it from having worst case
     /// exponential time.
     visited: Visited,
+    /// Where each look-around assertion with a sub-expression holds for the
+    /// current search. This is empty when the NFA has no such assertions.
+    look_around: lookaround::Cache,
 }
 
 impl Cache {
@@ -1674,7 +1712,11 @@ impl Cache {
     /// `BoundedBacktracker`, then you must call [`Cache::reset`] with the
     /// desired `BoundedBacktracker`.
     pub fn new(re: &BoundedBacktracker) -> Cache {
-        Cache { stack: vec![], visited: Visited::new(re) }
+        Cache {
+            stack: vec![],
+            visited: Visited::new(re),
+            look_around: lookaround::Cache::new(re.get_nfa()),
+        }
     }
 
     /// Reset this cache such that it can be used for searching with different
@@ -1720,6 +1762,7 @@ impl Cache {
     /// ```
     pub fn reset(&mut self, re: &BoundedBacktracker) {
         self.visited.reset(re);
+        self.look_around.reset(re.get_nfa());
     }
 
     /// Returns the heap memory usage, in bytes, of this cache.
@@ -1729,6 +1772,7 @@ impl Cache {
     pub fn memory_usage(&self) -> usize {
         self.stack.len() * core::mem::size_of::<Frame>()
             + self.visited.memory_usage()
+            + self.look_around.memory_usage()
     }
 
     /// Clears this cache. This should be called at the start of every search
@@ -1748,6 +1792,7 @@ impl Cache {
     ) -> Result<(), MatchError> {
         self.stack.clear();
         self.visited.setup_search(re, input)?;
+        self.look_around.setup_search(re.get_nfa(), input);
         Ok(())
     }
 }
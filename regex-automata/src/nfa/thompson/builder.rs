@@ -59,6 +59,28 @@ enum State {
     /// A conditional epsilon transition satisfied via some sort of
     /// look-around.
     Look { look: Look, next: StateID },
+    /// A conditional epsilon transition satisfied via a look-around
+    /// assertion with a sub-expression. The index refers to the builder's
+    /// sequence of look-around sub-expressions.
+    LookAround { index: SmallIndex, next: StateID },
+    /// A transition that matches the text most recently matched by the
+    /// given capturing group.
+    Backreference {
+        /// The ID of the pattern that the backreference belongs to.
+        pattern_id: PatternID,
+        /// The capture group index being referred to, relative to its
+        /// pattern.
+        group_index: SmallIndex,
+        /// The next state that this state should transition to.
+        next: StateID,
+    },
+    /// An unconditional epsilon transition that marks the start of an
+    /// atomic group. The index identifies the group, and is shared with the
+    /// state marking its end.
+    AtomicStart { index: SmallIndex, next: StateID },
+    /// An unconditional epsilon transition that marks the end of an atomic
+    /// group.
+    AtomicEnd { index: SmallIndex, next: StateID },
     /// An empty state that records the start of a capture location. This is an
     /// unconditional epsilon transition like `Empty`, except it can be used to
     /// record position information for a capture group when using the NFA for
@@ -151,6 +173,10 @@ impl State {
             State::Empty { .. }
             | State::ByteRange { .. }
             | State::Look { .. }
+            | State::LookAround { .. }
+            | State::Backreference { .. }
+            | State::AtomicStart { .. }
+            | State::AtomicEnd { .. }
             | State::CaptureStart { .. }
             | State::CaptureEnd { .. }
             | State::Fail
@@ -232,6 +258,10 @@ impl State {
 /// simulation to record the current input position to a specific location in
 /// memory. This is intended to represent the ending location of a capturing
 /// group.
+/// * [`add_atomic_start`](Builder::add_atomic_start) and
+/// [`add_atomic_end`](Builder::add_atomic_end): Add states with a single
+/// unconditional epsilon transition that mark the start and end of an atomic
+/// group, respectively.
 /// * [`add_fail`](Builder::add_fail): Adds a state that never transitions to
 /// another state.
 /// * [`add_match`](Builder::add_match): Add a state that indicates a match has
@@ -337,6 +367,12 @@ pub struct Builder {
     /// The first capture group for each pattern is always unnamed and is thus
     /// always None.
     captures: Vec<Vec<Option<Arc<str>>>>,
+    /// The look-around sub-expressions referenced by `LookAround` states,
+    /// indexed by the `index` of each such state.
+    look_arounds: Vec<nfa::LookAround>,
+    /// The number of atomic groups added so far. Each group is identified by
+    /// the index of its `AtomicStart` state, which is assigned sequentially.
+    atomic_len: usize,
     /// The combined memory used by each of the 'State's in 'states'. This
     /// only includes heap usage by each state, and not the size of the state
     /// itself. In other words, this tracks heap memory used that isn't
@@ -373,6 +409,8 @@ impl Builder {
         self.states.clear();
         self.start_pattern.clear();
         self.captures.clear();
+        self.look_arounds.clear();
+        self.atomic_len = 0;
         self.memory_states = 0;
     }
 
@@ -437,6 +475,7 @@ impl Builder {
 
         nfa.set_starts(start_anchored, start_unanchored, &self.start_pattern);
         nfa.set_captures(&self.captures).map_err(BuildError::captures)?;
+        nfa.set_look_arounds(&self.look_arounds);
         // The idea here is to convert our intermediate states to their final
         // form. The only real complexity here is the process of converting
         // transitions, which are expressed in terms of state IDs. The new
@@ -470,6 +509,40 @@ impl Builder {
                 State::Look { look, next } => {
                     remap[sid] = nfa.add(nfa::State::Look { look, next });
                 }
+                State::LookAround { index, next } => {
+                    remap[sid] =
+                        nfa.add(nfa::State::LookAround { index, next });
+                }
+                State::Backreference { pattern_id, group_index, next } => {
+                    // A backreference to a group that was never added can
+                    // never match, and is almost certainly a bug in the
+                    // caller.
+                    if nfa
+                        .group_info()
+                        .slot(pattern_id, group_index.as_usize())
+                        .is_none()
+                    {
+                        return Err(BuildError::invalid_capture_index(
+                            group_index.as_u32(),
+                        ));
+                    }
+                    remap[sid] = nfa.add(nfa::State::Backreference {
+                        pattern_id,
+                        group_index,
+                        next,
+                    });
+                }
+                // Atomic group markers are unconditional epsilon
+                // transitions, but we can't remove them because engines use
+                // them to delimit the group.
+                State::AtomicStart { index, next } => {
+                    remap[sid] =
+                        nfa.add(nfa::State::AtomicStart { index, next });
+                }
+                State::AtomicEnd { index, next } => {
+                    remap[sid] =
+                        nfa.add(nfa::State::AtomicEnd { index, next });
+                }
                 State::CaptureStart { pattern_id, group_index, next } => {
                     // We can't remove this empty state because of the side
                     // effect of capturing an offset for this capture slot.
@@ -818,6 +891,135 @@ impl Builder {
         self.add(State::Look { look, next })
     }
 
+    /// Add a "look-around" NFA state.
+    ///
+    /// A "look-around" NFA state corresponds to a state with exactly one
+    /// *conditional* epsilon transition to another NFA state, where the
+    /// condition is a look-around assertion with a sub-expression (like
+    /// `(?=a)` or `(?<!b)`). The sub-expression is given by its own NFA. See
+    /// [`LookAround`](nfa::LookAround) for more details.
+    ///
+    /// Callers may provide a "dummy" state ID (typically [`StateID::ZERO`]),
+    /// and then change it later with [`patch`](Builder::patch).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the state identifier space is exhausted, or if
+    /// the configured heap size limit has been exceeded.
+    pub fn add_look_around(
+        &mut self,
+        next: StateID,
+        look_around: nfa::LookAround,
+    ) -> Result<StateID, BuildError> {
+        let len = self.look_arounds.len();
+        let index = SmallIndex::new(len)
+            .map_err(|_| BuildError::too_many_states(len))?;
+        self.memory_states += look_around.nfa().memory_usage();
+        self.look_arounds.push(look_around);
+        self.add(State::LookAround { index, next })
+    }
+
+    /// Add a "backreference" NFA state.
+    ///
+    /// A "backreference" NFA state corresponds to a state with exactly one
+    /// transition to another state, which is taken by consuming exactly the
+    /// bytes most recently matched by the given capturing group in the
+    /// current pattern. If that group hasn't matched, then the transition is
+    /// never taken.
+    ///
+    /// The capturing group must be added to the current pattern (via
+    /// [`Builder::add_capture_start`]) before the NFA is built, but it may
+    /// be added after this state.
+    ///
+    /// Callers may provide a "dummy" state ID (typically [`StateID::ZERO`]),
+    /// and then change it later with [`patch`](Builder::patch).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the state identifier space is exhausted, if
+    /// the configured heap size limit has been exceeded or if the given group
+    /// index is too big.
+    ///
+    /// # Panics
+    ///
+    /// This panics if a pattern is not currently being built.
+    pub fn add_backreference(
+        &mut self,
+        next: StateID,
+        group_index: u32,
+    ) -> Result<StateID, BuildError> {
+        let pattern_id = self.current_pattern_id();
+        let group_index = match SmallIndex::try_from(group_index) {
+            Err(_) => {
+                return Err(BuildError::invalid_capture_index(group_index))
+            }
+            Ok(group_index) => group_index,
+        };
+        self.add(State::Backreference { pattern_id, group_index, next })
+    }
+
+    /// Add an "atomic start" NFA state.
+    ///
+    /// An "atomic start" NFA state corresponds to a state with exactly one
+    /// unconditional epsilon transition to another state. It marks the start
+    /// of a new atomic group, whose end must be marked by a corresponding
+    /// state added via [`Builder::add_atomic_end`]. Every path from this
+    /// state that leads to a match must pass through its "atomic end" state.
+    ///
+    /// An atomic group matches the first way (in preference order) that its
+    /// states can reach its end, and never backtracks into the group once
+    /// that end has been reached. Atomic groups are only supported by the
+    /// [`PikeVM`](crate::nfa::thompson::pikevm::PikeVM) and the
+    /// backtracking engines.
+    ///
+    /// Callers may provide a "dummy" state ID (typically [`StateID::ZERO`]),
+    /// and then change it later with [`patch`](Builder::patch).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the state identifier space is exhausted, or if
+    /// the configured heap size limit has been exceeded.
+    pub fn add_atomic_start(
+        &mut self,
+        next: StateID,
+    ) -> Result<StateID, BuildError> {
+        let index = SmallIndex::new(self.atomic_len)
+            .map_err(|_| BuildError::too_many_states(self.atomic_len))?;
+        let id = self.add(State::AtomicStart { index, next })?;
+        self.atomic_len += 1;
+        Ok(id)
+    }
+
+    /// Add an "atomic end" NFA state for the atomic group started by the
+    /// given "atomic start" state.
+    ///
+    /// An "atomic end" NFA state corresponds to a state with exactly one
+    /// unconditional epsilon transition to another state. See
+    /// [`Builder::add_atomic_start`] for more details.
+    ///
+    /// Callers may provide a "dummy" state ID (typically [`StateID::ZERO`]),
+    /// and then change it later with [`patch`](Builder::patch).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the state identifier space is exhausted, or if
+    /// the configured heap size limit has been exceeded.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `start` does not refer to an "atomic start" state.
+    pub fn add_atomic_end(
+        &mut self,
+        start: StateID,
+        next: StateID,
+    ) -> Result<StateID, BuildError> {
+        let index = match self.states[start] {
+            State::AtomicStart { index, .. } => index,
+            _ => panic!("expected an atomic start state"),
+        };
+        self.add(State::AtomicEnd { index, next })
+    }
+
     /// Add a "start capture" NFA state.
     ///
     /// A "start capture" NFA state corresponds to a state with exactly one
@@ -1159,6 +1361,18 @@ impl Builder {
             State::Look { ref mut next, .. } => {
                 *next = to;
             }
+            State::LookAround { ref mut next, .. } => {
+                *next = to;
+            }
+            State::Backreference { ref mut next, .. } => {
+                *next = to;
+            }
+            State::AtomicStart { ref mut next, .. } => {
+                *next = to;
+            }
+            State::AtomicEnd { ref mut next, .. } => {
+                *next = to;
+            }
             State::Union { ref mut alternates } => {
                 alternates.push(to);
                 self.memory_states += mem::size_of::<StateID>();
@@ -14,7 +14,7 @@ use crate::{
         error::BuildError,
         literal_trie::LiteralTrie,
         map::{Utf8BoundedMap, Utf8SuffixKey, Utf8SuffixMap},
-        nfa::{Transition, NFA},
+        nfa::{LookAround, LookAroundKind, Transition, NFA},
         range_trie::RangeTrie,
     },
     util::{
@@ -1003,10 +1003,14 @@ impl Compiler {
             Class(Class::Bytes(ref c)) => self.c_byte_class(c),
             Class(Class::Unicode(ref c)) => self.c_unicode_class(c),
             Look(ref look) => self.c_look(look),
+            LookAround(ref x) => self.c_look_around(x),
             Repetition(ref rep) => self.c_repetition(rep),
             Capture(ref c) => self.c_cap(c.index, c.name.as_deref(), &c.sub),
             Concat(ref es) => self.c_concat(es.iter().map(|e| self.c(e))),
             Alternation(ref es) => self.c_alt_slice(es),
+            Intersection(_) | Complement(_) => self.c_boolean(expr),
+            Backreference(index) => self.c_backreference(index),
+            Atomic(ref sub) => self.c_atomic(sub),
         }
     }
 
@@ -1567,11 +1571,250 @@ impl Compiler {
             hir::Look::WordEndHalfAscii => Look::WordEndHalfAscii,
             hir::Look::WordStartHalfUnicode => Look::WordStartHalfUnicode,
             hir::Look::WordEndHalfUnicode => Look::WordEndHalfUnicode,
+            hir::Look::WordSegment => Look::WordSegment,
+            hir::Look::SentenceSegment => Look::SentenceSegment,
         };
         let id = self.add_look(look)?;
         Ok(ThompsonRef { start: id, end: id })
     }
 
+    /// Compile the given HIR look-around assertion with a sub-expression to
+    /// an NFA look-around state.
+    ///
+    /// The sub-expression is compiled to its own NFA. A look-ahead uses a
+    /// reverse NFA and a look-behind uses a forward NFA, which permits
+    /// computing where the assertion holds with a single pass over the
+    /// haystack. Note that this is independent of whether the NFA being
+    /// compiled is itself reversed, since look-around assertions are always
+    /// evaluated at positions in the original haystack.
+    fn c_look_around(
+        &self,
+        look_around: &hir::LookAround,
+    ) -> Result<ThompsonRef, BuildError> {
+        let kind = match look_around.kind {
+            hir::LookAroundKind::PositiveLookAhead => {
+                LookAroundKind::PositiveLookAhead
+            }
+            hir::LookAroundKind::NegativeLookAhead => {
+                LookAroundKind::NegativeLookAhead
+            }
+            hir::LookAroundKind::PositiveLookBehind => {
+                LookAroundKind::PositiveLookBehind
+            }
+            hir::LookAroundKind::NegativeLookBehind => {
+                LookAroundKind::NegativeLookBehind
+            }
+        };
+        let config = self
+            .config
+            .clone()
+            .reverse(!kind.is_look_behind())
+            .utf8(false)
+            .which_captures(WhichCaptures::None);
+        let nfa = Compiler::new()
+            .configure(config)
+            .build_from_hir(&look_around.sub)?;
+        let max_len = look_around.sub.properties().maximum_len();
+        let id = self.builder.borrow_mut().add_look_around(
+            StateID::ZERO,
+            LookAround::new(kind, nfa, max_len),
+        )?;
+        Ok(ThompsonRef { start: id, end: id })
+    }
+
+    /// Compile a backreference to the capturing group with the given index.
+    ///
+    /// This requires that every capturing group is compiled, since the
+    /// backreference needs the slots of the group it refers to. In
+    /// particular, this means backreferences are never supported in reverse
+    /// NFAs.
+    fn c_backreference(&self, index: u32) -> Result<ThompsonRef, BuildError> {
+        if !matches!(self.config.get_which_captures(), WhichCaptures::All) {
+            return Err(BuildError::unsupported_backreference_captures());
+        }
+        let id = self
+            .builder
+            .borrow_mut()
+            .add_backreference(StateID::ZERO, index)?;
+        Ok(ThompsonRef { start: id, end: id })
+    }
+
+    /// Compile an atomic group around the given sub-expression.
+    ///
+    /// The sub-expression is bracketed by a pair of epsilon states that
+    /// regex engines use to discard alternative ways of matching the
+    /// sub-expression once one has been found. Since which alternative is
+    /// found first depends on the direction of the search, atomic groups are
+    /// not supported in reverse NFAs.
+    fn c_atomic(&self, sub: &Hir) -> Result<ThompsonRef, BuildError> {
+        if self.is_reverse() {
+            return Err(BuildError::unsupported_atomic_reverse());
+        }
+        let start =
+            self.builder.borrow_mut().add_atomic_start(StateID::ZERO)?;
+        let inner = self.c(sub)?;
+        let end =
+            self.builder.borrow_mut().add_atomic_end(start, StateID::ZERO)?;
+        self.patch(start, inner.start)?;
+        self.patch(inner.end, end)?;
+        Ok(ThompsonRef { start, end })
+    }
+
+    /// Compile an intersection or complement of HIR expressions.
+    ///
+    /// Neither operation has a straight-forward Thompson construction. So
+    /// instead, every operand that isn't itself an intersection or complement
+    /// is compiled to a DFA, and the boolean combination of those DFAs is
+    /// computed via a product construction. The resulting DFA is then
+    /// embedded into this NFA, with one NFA state for each DFA state. This
+    /// means that every regex engine supports these operations, but it also
+    /// means that compiling them can take exponential time and space in the
+    /// worst case.
+    ///
+    /// Since the DFAs are built from NFAs compiled using this compiler's
+    /// configuration, it all works out in reverse mode too. That is, the
+    /// product of reversed languages is the reverse of the product.
+    #[cfg(feature = "dfa-build")]
+    fn c_boolean(&self, expr: &Hir) -> Result<ThompsonRef, BuildError> {
+        use alloc::collections::btree_map::{BTreeMap, Entry};
+
+        use crate::dfa::{dense, determinize::Product, Automaton};
+
+        /// Flatten the boolean operations at the root of the given HIR into
+        /// a product, and compile every other operand into a DFA.
+        fn product(
+            compiler: &Compiler,
+            expr: &Hir,
+            dfas: &mut Vec<dense::OwnedDFA>,
+        ) -> Result<Product, BuildError> {
+            match *expr.kind() {
+                hir::HirKind::Intersection(ref subs) => {
+                    let mut products = vec![];
+                    for sub in subs.iter() {
+                        products.push(product(compiler, sub, dfas)?);
+                    }
+                    return Ok(Product::Intersection(products));
+                }
+                hir::HirKind::Complement(ref sub) => {
+                    let sub = product(compiler, sub, dfas)?;
+                    return Ok(Product::Complement(alloc::boxed::Box::new(
+                        sub,
+                    )));
+                }
+                _ => {}
+            }
+            // Look-around assertions are defined in terms of the haystack
+            // surrounding a match, but a DFA for an operand only sees the
+            // part of the haystack that the operand matches. So we don't
+            // support them.
+            let config = compiler
+                .config
+                .clone()
+                .utf8(false)
+                .which_captures(WhichCaptures::None);
+            let nfa =
+                Compiler::new().configure(config).build_from_hir(expr)?;
+            if !nfa.look_set_any().is_empty() || nfa.has_look_around() {
+                return Err(BuildError::unsupported_boolean_look());
+            }
+            let dfa = dense::Builder::new()
+                .configure(compiler.dense_config())
+                .build_from_nfa(&nfa)
+                .map_err(BuildError::boolean)?;
+            dfas.push(dfa);
+            Ok(Product::DFA(dfas.len() - 1))
+        }
+
+        let mut dfas = vec![];
+        let product = product(self, expr, &mut dfas)?;
+        let refs: Vec<&dense::OwnedDFA> = dfas.iter().collect();
+        let dfa = dense::Builder::new()
+            .configure(self.dense_config().minimize(true))
+            .build_from_product(&refs, &product)
+            .map_err(BuildError::boolean)?;
+        drop(dfas);
+
+        // Since no operand has any look-around assertions, the anchored start
+        // state is always universal.
+        let start = dfa
+            .universal_start_state(crate::util::search::Anchored::Yes)
+            .expect("product DFA has a universal anchored start state");
+        // Every DFA state gets an NFA union state, which is created before
+        // anything else so that the sparse states below can refer to them.
+        // Its alternates are a sparse state with the DFA state's transitions
+        // and, if the DFA state is accepting, the end of this sub-expression.
+        // The transitions are listed first in order to make the match greedy.
+        let end = self.add_empty()?;
+        let mut nodes = BTreeMap::new();
+        let mut stack = vec![start];
+        nodes.insert(start, self.add_union()?);
+        let mut order = vec![];
+        while let Some(sid) = stack.pop() {
+            order.push(sid);
+            for b in 0..=255u8 {
+                let next = dfa.next_state(sid, b);
+                if dfa.is_dead_state(next) || dfa.is_quit_state(next) {
+                    continue;
+                }
+                if let Entry::Vacant(e) = nodes.entry(next) {
+                    e.insert(self.add_union()?);
+                    stack.push(next);
+                }
+            }
+        }
+        for sid in order {
+            let mut ranges: Vec<Transition> = vec![];
+            for b in 0..=255u8 {
+                let next = match nodes.get(&dfa.next_state(sid, b)) {
+                    None => continue,
+                    Some(&next) => next,
+                };
+                match ranges.last_mut() {
+                    Some(t)
+                        if t.next == next && t.end.wrapping_add(1) == b =>
+                    {
+                        t.end = b;
+                    }
+                    _ => ranges.push(Transition { start: b, end: b, next }),
+                }
+            }
+            let node = nodes[&sid];
+            if !ranges.is_empty() {
+                let sparse = self.add_sparse(ranges)?;
+                self.patch(node, sparse)?;
+            }
+            if dfa.is_match_state(dfa.next_eoi_state(sid)) {
+                self.patch(node, end)?;
+            }
+        }
+        Ok(ThompsonRef { start: nodes[&start], end })
+    }
+
+    /// Compile an intersection or complement of HIR expressions.
+    ///
+    /// This always returns an error, since these operations require the
+    /// `dfa-build` feature.
+    #[cfg(not(feature = "dfa-build"))]
+    fn c_boolean(&self, _: &Hir) -> Result<ThompsonRef, BuildError> {
+        Err(BuildError::unsupported_boolean())
+    }
+
+    /// Returns the dense DFA configuration used to build the operands of an
+    /// intersection or complement, and the product of those operands.
+    #[cfg(feature = "dfa-build")]
+    fn dense_config(&self) -> crate::dfa::dense::Config {
+        use crate::dfa::{dense, StartKind};
+
+        // Every string matched by an operand must lead to a match state, so
+        // we use the standard textbook construction.
+        dense::Config::new()
+            .match_kind(crate::util::search::MatchKind::All)
+            .start_kind(StartKind::Anchored)
+            .unicode_word_boundary(false)
+            .dfa_size_limit(self.config.get_nfa_size_limit())
+            .determinize_size_limit(self.config.get_nfa_size_limit())
+    }
+
     /// Compile the given byte string to a concatenation of bytes.
     fn c_literal(&self, bytes: &[u8]) -> Result<ThompsonRef, BuildError> {
         self.c_concat(bytes.iter().copied().map(|b| self.c_range(b, b)))
@@ -73,6 +73,34 @@ enum BuildErrorKind {
     /// should support it at some point.
     #[cfg(feature = "syntax")]
     UnsupportedCaptures,
+    /// An error that occurs when a backreference is compiled without
+    /// capture states for every capturing group. This includes every reverse
+    /// NFA.
+    #[cfg(feature = "syntax")]
+    UnsupportedBackreferenceCaptures,
+    /// An error that occurs when a regex engine that doesn't support
+    /// backreferences is built from an NFA that contains one.
+    UnsupportedBackreference,
+    /// An error that occurs when an atomic group or possessive repetition is
+    /// compiled into a reverse NFA.
+    #[cfg(feature = "syntax")]
+    UnsupportedAtomicReverse,
+    /// An error that occurs when a regex engine that doesn't support atomic
+    /// groups is built from an NFA that contains one.
+    UnsupportedAtomic,
+    /// An error that occurs when an intersection or complement is compiled
+    /// without the `dfa-build` feature enabled.
+    #[cfg(all(feature = "syntax", not(feature = "dfa-build")))]
+    UnsupportedBoolean,
+    /// An error that occurs when an operand of an intersection or complement
+    /// contains a look-around assertion.
+    #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+    UnsupportedBooleanLook,
+    /// An error that occurred while building the DFA used to compile an
+    /// intersection or complement. This usually occurs when the DFA exceeds
+    /// the configured NFA size limit.
+    #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+    Boolean(alloc::boxed::Box<crate::dfa::dense::BuildError>),
 }
 
 impl BuildError {
@@ -127,6 +155,40 @@ impl BuildError {
     pub(crate) fn unsupported_captures() -> BuildError {
         BuildError { kind: BuildErrorKind::UnsupportedCaptures }
     }
+
+    #[cfg(feature = "syntax")]
+    pub(crate) fn unsupported_backreference_captures() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedBackreferenceCaptures }
+    }
+
+    pub(crate) fn unsupported_backreference() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedBackreference }
+    }
+
+    #[cfg(feature = "syntax")]
+    pub(crate) fn unsupported_atomic_reverse() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedAtomicReverse }
+    }
+
+    pub(crate) fn unsupported_atomic() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedAtomic }
+    }
+
+    #[cfg(all(feature = "syntax", not(feature = "dfa-build")))]
+    pub(crate) fn unsupported_boolean() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedBoolean }
+    }
+
+    #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+    pub(crate) fn unsupported_boolean_look() -> BuildError {
+        BuildError { kind: BuildErrorKind::UnsupportedBooleanLook }
+    }
+
+    #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+    pub(crate) fn boolean(err: crate::dfa::dense::BuildError) -> BuildError {
+        let err = alloc::boxed::Box::new(err);
+        BuildError { kind: BuildErrorKind::Boolean(err) }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -136,6 +198,9 @@ impl std::error::Error for BuildError {
             #[cfg(feature = "syntax")]
             BuildErrorKind::Syntax(ref err) => Some(err),
             BuildErrorKind::Captures(ref err) => Some(err),
+            BuildErrorKind::Word(ref err) => Some(err),
+            #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+            BuildErrorKind::Boolean(ref err) => Some(&**err),
             _ => None,
         }
     }
@@ -180,6 +245,44 @@ impl core::fmt::Display for BuildError {
                 "currently captures must be disabled when compiling \
                  a reverse NFA",
             ),
+            #[cfg(feature = "syntax")]
+            BuildErrorKind::UnsupportedBackreferenceCaptures => write!(
+                f,
+                "backreferences require a forward NFA with all capture \
+                 states enabled",
+            ),
+            BuildErrorKind::UnsupportedBackreference => write!(
+                f,
+                "this regex engine does not support backreferences; \
+                 use the backtracker in 'nfa::thompson::backref' instead",
+            ),
+            #[cfg(feature = "syntax")]
+            BuildErrorKind::UnsupportedAtomicReverse => write!(
+                f,
+                "atomic groups and possessive repetitions are not \
+                 supported in a reverse NFA",
+            ),
+            BuildErrorKind::UnsupportedAtomic => write!(
+                f,
+                "this regex engine does not support atomic groups or \
+                 possessive repetitions",
+            ),
+            #[cfg(all(feature = "syntax", not(feature = "dfa-build")))]
+            BuildErrorKind::UnsupportedBoolean => write!(
+                f,
+                "intersection and complement require the \
+                 'dfa-build' crate feature",
+            ),
+            #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+            BuildErrorKind::UnsupportedBooleanLook => write!(
+                f,
+                "look-around assertions are not supported inside of \
+                 intersection or complement",
+            ),
+            #[cfg(all(feature = "syntax", feature = "dfa-build"))]
+            BuildErrorKind::Boolean(_) => {
+                write!(f, "error building DFA for intersection or complement",)
+            }
         }
     }
 }
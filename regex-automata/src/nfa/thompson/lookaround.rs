@@ -0,0 +1,339 @@
+/*!
+Provides support for evaluating look-around assertions with a sub-expression.
+
+A [`State::LookAround`] state holds at a position in a haystack if and only if
+its sub-expression matches immediately after (look-ahead) or immediately
+before (look-behind) that position. Evaluating such an assertion naively at
+each position visited by a search would require running a separate search for
+each position, which would make the overall search time quadratic in the size
+of the haystack.
+
+Instead, before a search begins, we compute where each look-around assertion
+holds for every position in the search span. This is done with a single pass
+over the haystack for each assertion, by running an unanchored set-based
+simulation of the sub-expression's NFA. For look-behind, the sub-expression's
+NFA is a forward NFA, and so a match ending at position `i` means the
+assertion holds at `i`. For look-ahead, the sub-expression's NFA is a reverse
+NFA run from right to left, and so a match "ending" at position `i` (in the
+reverse direction) means the assertion holds at `i`.
+
+Since the position at which an assertion holds doesn't depend on which
+states of the main NFA are active, the results can be looked up in constant
+time while computing epsilon closures in the PikeVM or the bounded
+backtracker.
+
+The cost of this precomputation is proportional to the length of the search
+span plus the maximum length of each sub-expression (when known). When the
+maximum length of a look-ahead sub-expression isn't known, its pass starts at
+the end of the haystack. Iterators run many searches over the same haystack,
+each starting where the previous one left off, so the results are kept and
+reused by later searches whose span is covered by them. (See
+`Input::with_haystack_id`.) This way, finding all matches in a haystack
+requires only one pass for each assertion.
+*/
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    nfa::thompson::{LookAround, State, NFA},
+    util::{
+        primitives::{SmallIndex, StateID},
+        search::Input,
+        sparse_set::{SparseSet, SparseSets},
+    },
+};
+
+/// A cache of where each look-around assertion in an NFA holds for the
+/// current search.
+///
+/// This is embedded in the caches of regex engines that support
+/// [`State::LookAround`] states.
+#[derive(Clone, Debug)]
+pub(crate) struct Cache {
+    /// For each look-around assertion, whether it holds at each position in
+    /// the search span. The results for look-around `i` at position `at`
+    /// are stored at `i * width + (at - offset)`.
+    holds: Vec<bool>,
+    /// The start of the span of the current search.
+    offset: usize,
+    /// The number of positions in the span of the current search. This is
+    /// one more than the length of the span since a look-around may be
+    /// evaluated at the end of the span.
+    width: usize,
+    /// The identifier of the haystack that `holds` was computed for, if it
+    /// had one. Since whether an assertion holds at a position only depends
+    /// on the haystack, `holds` can be reused by any later search over the
+    /// same haystack whose span is within the span `holds` was computed for.
+    haystack_id: Option<usize>,
+    /// The current and next sets of states used to simulate each
+    /// sub-expression's NFA.
+    sets: SparseSets,
+    /// A stack used for computing epsilon closures.
+    stack: Vec<StateID>,
+}
+
+impl Cache {
+    /// Create a new cache for evaluating the look-around assertions in the
+    /// given NFA.
+    pub(crate) fn new(nfa: &NFA) -> Cache {
+        let mut cache = Cache {
+            holds: vec![],
+            offset: 0,
+            width: 0,
+            haystack_id: None,
+            sets: SparseSets::new(0),
+            stack: vec![],
+        };
+        cache.reset(nfa);
+        cache
+    }
+
+    /// Reset this cache such that it can be used for the given NFA.
+    pub(crate) fn reset(&mut self, nfa: &NFA) {
+        let capacity = nfa
+            .look_arounds()
+            .iter()
+            .map(|la| la.nfa().states().len())
+            .max()
+            .unwrap_or(0);
+        self.holds.clear();
+        self.offset = 0;
+        self.width = 0;
+        self.haystack_id = None;
+        self.sets.resize(capacity);
+        self.stack.clear();
+    }
+
+    /// Returns the heap memory usage, in bytes, of this cache.
+    pub(crate) fn memory_usage(&self) -> usize {
+        use core::mem::size_of;
+
+        self.holds.len() * size_of::<bool>()
+            + self.sets.memory_usage()
+            + self.stack.len() * size_of::<StateID>()
+    }
+
+    /// Compute where each look-around assertion in the given NFA holds for
+    /// every position in the span of the given search.
+    ///
+    /// This is a no-op if the NFA has no look-around assertions, or if the
+    /// results computed for a previous search over the same haystack already
+    /// cover the span of the given search.
+    pub(crate) fn setup_search(&mut self, nfa: &NFA, input: &Input<'_>) {
+        if !nfa.has_look_around() || input.is_done() {
+            return;
+        }
+        if self.haystack_id.is_some()
+            && self.haystack_id == input.get_haystack_id()
+            && self.offset <= input.start()
+            && input.end() < self.offset + self.width
+        {
+            return;
+        }
+        self.haystack_id = input.get_haystack_id();
+        self.offset = input.start();
+        self.width = input.end() - input.start() + 1;
+        self.holds.clear();
+        self.holds.resize(nfa.look_arounds().len() * self.width, false);
+        for (i, la) in nfa.look_arounds().iter().enumerate() {
+            let start = i * self.width;
+            let holds = &mut self.holds[start..start + self.width];
+            let (sets, stack) = (&mut self.sets, &mut self.stack);
+            if la.kind().is_look_behind() {
+                forward(la, input, self.offset, holds, sets, stack);
+            } else {
+                reverse(la, input, self.offset, holds, sets, stack);
+            }
+        }
+    }
+
+    /// Returns true if and only if the look-around assertion at the given
+    /// index holds at the given position.
+    ///
+    /// The position given must be within the span of the search most
+    /// recently given to `setup_search`.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    pub(crate) fn holds(&self, index: SmallIndex, at: usize) -> bool {
+        self.holds[index.as_usize() * self.width + (at - self.offset)]
+    }
+}
+
+/// Compute where the given look-behind assertion holds for every position in
+/// the span of the given search, starting at `offset`.
+///
+/// This runs the look-behind's forward NFA from the earliest position at
+/// which a match of its sub-expression could end at or after the start of
+/// the search.
+fn forward(
+    la: &LookAround,
+    input: &Input<'_>,
+    offset: usize,
+    holds: &mut [bool],
+    sets: &mut SparseSets,
+    stack: &mut Vec<StateID>,
+) {
+    let nfa = la.nfa();
+    let haystack = input.haystack();
+    let negated = la.kind().is_negated();
+    let mut at = match la.max_len() {
+        None => 0,
+        Some(max_len) => input.start().saturating_sub(max_len),
+    };
+    sets.clear();
+    loop {
+        closure(
+            nfa,
+            haystack,
+            at,
+            nfa.start_anchored(),
+            &mut sets.set1,
+            stack,
+        );
+        if at >= offset {
+            holds[at - offset] = is_match(nfa, &sets.set1) != negated;
+        }
+        if at >= input.end() {
+            break;
+        }
+        for sid in sets.set1.iter() {
+            if let Some(next) = transition(nfa, haystack, at, sid) {
+                closure(nfa, haystack, at + 1, next, &mut sets.set2, stack);
+            }
+        }
+        sets.swap();
+        sets.set2.clear();
+        at += 1;
+    }
+}
+
+/// Compute where the given look-ahead assertion holds for every position in
+/// the span of the given search, starting at `offset`.
+///
+/// This runs the look-ahead's reverse NFA from right to left, starting at
+/// the latest position at which a match of its sub-expression could start at
+/// or before the end of the search.
+fn reverse(
+    la: &LookAround,
+    input: &Input<'_>,
+    offset: usize,
+    holds: &mut [bool],
+    sets: &mut SparseSets,
+    stack: &mut Vec<StateID>,
+) {
+    let nfa = la.nfa();
+    let haystack = input.haystack();
+    let negated = la.kind().is_negated();
+    let mut at = match la.max_len() {
+        None => haystack.len(),
+        Some(max_len) => {
+            core::cmp::min(haystack.len(), input.end().saturating_add(max_len))
+        }
+    };
+    sets.clear();
+    loop {
+        closure(
+            nfa,
+            haystack,
+            at,
+            nfa.start_anchored(),
+            &mut sets.set1,
+            stack,
+        );
+        if at <= input.end() {
+            holds[at - offset] = is_match(nfa, &sets.set1) != negated;
+        }
+        if at <= input.start() {
+            break;
+        }
+        for sid in sets.set1.iter() {
+            if let Some(next) = transition(nfa, haystack, at - 1, sid) {
+                closure(nfa, haystack, at - 1, next, &mut sets.set2, stack);
+            }
+        }
+        sets.swap();
+        sets.set2.clear();
+        at -= 1;
+    }
+}
+
+/// Add the epsilon closure of `sid` at position `at` to `set`.
+///
+/// Since we only care about whether a match exists and not about match
+/// priority or capturing groups, this is a simple depth first traversal.
+fn closure(
+    nfa: &NFA,
+    haystack: &[u8],
+    at: usize,
+    sid: StateID,
+    set: &mut SparseSet,
+    stack: &mut Vec<StateID>,
+) {
+    stack.push(sid);
+    while let Some(sid) = stack.pop() {
+        if !set.insert(sid) {
+            continue;
+        }
+        match *nfa.state(sid) {
+            State::ByteRange { .. }
+            | State::Sparse(_)
+            | State::Dense(_)
+            | State::Fail
+            | State::Match { .. } => {}
+            State::Look { look, next } => {
+                // A reverse NFA has its assertions reversed so that they can
+                // be evaluated on a reversed haystack. Since we always
+                // evaluate assertions on the original haystack, we undo that
+                // here.
+                let look =
+                    if nfa.is_reverse() { look.reversed() } else { look };
+                if nfa.look_matcher().matches(look, haystack, at) {
+                    stack.push(next);
+                }
+            }
+            // Nested look-around and backreferences inside of look-around
+            // are not supported, and the compiler never produces them. If a
+            // hand-built NFA contains them anyway, then we treat them as
+            // never matching.
+            State::LookAround { .. } | State::Backreference { .. } => {}
+            State::Union { ref alternates } => {
+                stack.extend(alternates.iter().copied());
+            }
+            State::BinaryUnion { alt1, alt2 } => {
+                stack.push(alt2);
+                stack.push(alt1);
+            }
+            State::Capture { next, .. }
+            | State::AtomicStart { next, .. }
+            | State::AtomicEnd { next, .. } => {
+                stack.push(next);
+            }
+        }
+    }
+}
+
+/// Return the state to transition to from `sid` on the byte at position `at`
+/// in the haystack, if one exists.
+fn transition(
+    nfa: &NFA,
+    haystack: &[u8],
+    at: usize,
+    sid: StateID,
+) -> Option<StateID> {
+    match *nfa.state(sid) {
+        State::ByteRange { ref trans } => {
+            if trans.matches(haystack, at) {
+                Some(trans.next)
+            } else {
+                None
+            }
+        }
+        State::Sparse(ref sparse) => sparse.matches(haystack, at),
+        State::Dense(ref dense) => dense.matches(haystack, at),
+        _ => None,
+    }
+}
+
+/// Returns true if and only if the given set contains a match state.
+fn is_match(nfa: &NFA, set: &SparseSet) -> bool {
+    set.iter().any(|sid| matches!(*nfa.state(sid), State::Match { .. }))
+}
@@ -12,6 +12,8 @@ string into something that can run a search looks roughly like this:
 * An `Hir` is compiled into a [`NFA`].
 * The `NFA` is then used to build one of a few different regex engines:
   * An `NFA` is used directly in the `PikeVM` and `BoundedBacktracker` engines.
+  * An `NFA` with [backreferences](State::Backreference) can only be used by
+  the [`Backtracker`](backref::Backtracker) engine.
   * An `NFA` is used by a [hybrid NFA/DFA](crate::hybrid) to build out a DFA's
   transition table at search time.
   * An `NFA`, assuming it is one-pass, is used to build a full
@@ -53,6 +55,10 @@ an `NFA` is to serve as a byte-code of sorts for a virtual machine. It can be
 seen as a sequence of instructions for how to match a regex.
 */
 
+#[cfg(feature = "nfa-pikevm")]
+mod atomic;
+#[cfg(feature = "nfa-backref")]
+pub mod backref;
 #[cfg(feature = "nfa-backtrack")]
 pub mod backtrack;
 mod builder;
@@ -61,6 +67,12 @@ mod compiler;
 mod error;
 #[cfg(feature = "syntax")]
 mod literal_trie;
+#[cfg(any(
+    feature = "nfa-backref",
+    feature = "nfa-backtrack",
+    feature = "nfa-pikevm"
+))]
+mod lookaround;
 #[cfg(feature = "syntax")]
 mod map;
 mod nfa;
@@ -73,8 +85,8 @@ pub use self::{
     builder::Builder,
     error::BuildError,
     nfa::{
-        DenseTransitions, PatternIter, SparseTransitions, State, Transition,
-        NFA,
+        DenseTransitions, LookAround, LookAroundKind, PatternIter,
+        SparseTransitions, State, Transition, NFA,
     },
 };
 #[cfg(feature = "syntax")]
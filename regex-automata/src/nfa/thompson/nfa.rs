@@ -17,6 +17,7 @@ use crate::{
             IteratorIndexExt, PatternID, PatternIDIter, SmallIndex, StateID,
         },
         sparse_set::SparseSet,
+        wire::{self, DeserializeError, Endian, Reader, Writer},
     },
 };
 
@@ -819,6 +820,135 @@ impl NFA {
         self.0.has_empty
     }
 
+    /// Returns true if and only if this NFA has at least one
+    /// [`LookAround`](State::LookAround) state.
+    ///
+    /// Look-around states with a sub-expression, like `(?=a)` or `(?<!b)`,
+    /// are only supported by the
+    /// [`PikeVM`](crate::nfa::thompson::pikevm::PikeVM) and the
+    /// [`BoundedBacktracker`](crate::nfa::thompson::backtrack::BoundedBacktracker).
+    /// Building any of the DFA engines from an NFA for which this returns
+    /// true will fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::nfa::thompson::NFA;
+    ///
+    /// let nfa = NFA::new(r"(?<=\$)[0-9]+")?;
+    /// assert!(nfa.has_look_around());
+    /// assert_eq!(1, nfa.look_arounds().len());
+    ///
+    /// // Simple assertions like '\b' do not count.
+    /// let nfa = NFA::new(r"\b[0-9]+\b")?;
+    /// assert!(!nfa.has_look_around());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn has_look_around(&self) -> bool {
+        !self.0.look_arounds.is_empty()
+    }
+
+    /// Returns true if and only if this NFA has at least one
+    /// [`Backreference`](State::Backreference) state.
+    ///
+    /// Backreferences are only supported by the
+    /// [`Backtracker`](crate::nfa::thompson::backref::Backtracker), which
+    /// requires the `nfa-backref` crate feature. Building any other regex
+    /// engine from an NFA for which this returns true will fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::NFA, util::syntax};
+    ///
+    /// let nfa = NFA::compiler()
+    ///     .syntax(syntax::Config::new().backreferences(true))
+    ///     .build(r"(\w+) \1")?;
+    /// assert!(nfa.has_backreference());
+    ///
+    /// let nfa = NFA::new(r"(\w+) (\w+)")?;
+    /// assert!(!nfa.has_backreference());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn has_backreference(&self) -> bool {
+        self.0.has_backreference
+    }
+
+    /// Returns the look-around sub-expressions in this NFA. The index of
+    /// each element corresponds to the `index` of the
+    /// [`LookAround`](State::LookAround) states that refer to it.
+    #[inline]
+    pub fn look_arounds(&self) -> &[LookAround] {
+        &self.0.look_arounds
+    }
+
+    /// Returns true if and only if this NFA has at least one atomic group,
+    /// i.e., at least one [`AtomicStart`](State::AtomicStart) state.
+    ///
+    /// Atomic groups, like `(?>a*)`, and possessive repetitions, like `a*+`,
+    /// are only supported by the
+    /// [`PikeVM`](crate::nfa::thompson::pikevm::PikeVM) and the
+    /// [`Backtracker`](crate::nfa::thompson::backref::Backtracker). Building
+    /// any other regex engine from an NFA for which this returns true will
+    /// fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::nfa::thompson::NFA;
+    ///
+    /// let nfa = NFA::new(r"(?>a|ab)c")?;
+    /// assert!(nfa.has_atomic());
+    ///
+    /// let nfa = NFA::new(r"(?:a|ab)c")?;
+    /// assert!(!nfa.has_atomic());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn has_atomic(&self) -> bool {
+        !self.0.atomic_groups.is_empty()
+    }
+
+    /// Returns the atomic groups in this NFA, indexed by the `index` of
+    /// their [`AtomicStart`](State::AtomicStart) states.
+    #[inline]
+    pub(crate) fn atomic_groups(&self) -> &[AtomicGroup] {
+        &self.0.atomic_groups
+    }
+
+    /// Returns the indices of the atomic groups in this NFA such that every
+    /// group comes after all of the groups nested inside of it.
+    #[inline]
+    pub(crate) fn atomic_order(&self) -> &[SmallIndex] {
+        &self.0.atomic_order
+    }
+
+    /// Returns the total number of union states that occur inside of an
+    /// atomic group.
+    #[inline]
+    pub(crate) fn atomic_unions_len(&self) -> usize {
+        self.0.atomic_unions.len()
+    }
+
+    /// If the given state is a union inside of an atomic group, then this
+    /// returns a dense index for that union (less than
+    /// `atomic_unions_len()`) along with the index of the innermost atomic
+    /// group containing it.
+    #[inline]
+    pub(crate) fn atomic_union(
+        &self,
+        sid: StateID,
+    ) -> Option<(usize, SmallIndex)> {
+        let unions = &self.0.atomic_unions;
+        let i = unions.binary_search_by_key(&sid, |&(sid, _)| sid).ok()?;
+        Some((i, unions[i].1))
+    }
+
     /// Whether UTF-8 mode is enabled for this NFA or not.
     ///
     /// When UTF-8 mode is enabled, all matches reported by a regex engine
@@ -1173,10 +1303,662 @@ impl NFA {
             + self.0.states.len() * size_of::<State>()
             + self.0.start_pattern.len() * size_of::<StateID>()
             + self.0.group_info.memory_usage()
+            + self.0.look_arounds.len() * size_of::<LookAround>()
+            + self
+                .0
+                .look_arounds
+                .iter()
+                .map(|la| la.nfa().memory_usage())
+                .sum::<usize>()
+            + self.0.atomic_groups.len() * size_of::<AtomicGroup>()
+            + self
+                .0
+                .atomic_groups
+                .iter()
+                .map(|g| g.states.len() * size_of::<StateID>())
+                .sum::<usize>()
+            + self.0.atomic_order.len() * size_of::<SmallIndex>()
+            + self.0.atomic_unions.len() * size_of::<(StateID, SmallIndex)>()
             + self.0.memory_extra
     }
 }
 
+/// The label written at the beginning of every serialized NFA.
+const LABEL: &str = "rust-regex-automata-nfa-thompson";
+
+/// The version of the NFA serialization format. This should be bumped
+/// whenever the format changes in an incompatible way.
+const VERSION: u32 = 1;
+
+/// The deepest nesting of look-around sub-expression NFAs that we permit
+/// when deserializing an NFA. This guards against blowing the stack on
+/// untrusted input. It matches the default nesting limit of the parser.
+const LOOK_AROUND_NEST_LIMIT: usize = 250;
+
+/// Serialization and deserialization.
+impl NFA {
+    /// Serialize this NFA to a new byte vector in little endian format.
+    ///
+    /// The bytes returned can be turned back into an equivalent NFA with
+    /// [`NFA::from_bytes`] on a little endian target. This makes it possible
+    /// to compile an NFA ahead of time (say, in a build script) and load it
+    /// at runtime without parsing or compiling any patterns. In particular,
+    /// regex engines built directly from an NFA, like the
+    /// [`PikeVM`](crate::nfa::thompson::pikevm::PikeVM) and the
+    /// [`BoundedBacktracker`](crate::nfa::thompson::backtrack::BoundedBacktracker),
+    /// can then be used without the `syntax` feature.
+    ///
+    /// The format includes every state in the NFA, its starting states, its
+    /// capturing groups (including their names), the configuration of its
+    /// look-around matcher, its byte classes and the NFAs of any
+    /// look-around sub-expressions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::{pikevm::PikeVM, NFA}, Match};
+    ///
+    /// let nfa = NFA::new(r"[a-z]+[0-9]+")?;
+    /// let bytes = nfa.to_bytes_little_endian();
+    ///
+    /// # if !cfg!(target_endian = "little") { return Ok(()); }
+    /// let (nfa, _) = NFA::from_bytes(&bytes)?;
+    /// let re = PikeVM::new_from_nfa(nfa)?;
+    /// let mut cache = re.create_cache();
+    /// assert_eq!(
+    ///     Some(Match::must(0, 4..10)),
+    ///     re.find(&mut cache, "123 abc123"),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_little_endian(&self) -> Vec<u8> {
+        self.to_bytes::<wire::LE>()
+    }
+
+    /// Serialize this NFA to a new byte vector in big endian format.
+    ///
+    /// The bytes returned can be turned back into an equivalent NFA with
+    /// [`NFA::from_bytes`] on a big endian target.
+    ///
+    /// See [`NFA::to_bytes_little_endian`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::{pikevm::PikeVM, NFA}, Match};
+    ///
+    /// let nfa = NFA::new(r"[a-z]+[0-9]+")?;
+    /// let bytes = nfa.to_bytes_big_endian();
+    ///
+    /// # if !cfg!(target_endian = "big") { return Ok(()); }
+    /// let (nfa, _) = NFA::from_bytes(&bytes)?;
+    /// let re = PikeVM::new_from_nfa(nfa)?;
+    /// let mut cache = re.create_cache();
+    /// assert_eq!(
+    ///     Some(Match::must(0, 4..10)),
+    ///     re.find(&mut cache, "123 abc123"),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_big_endian(&self) -> Vec<u8> {
+        self.to_bytes::<wire::BE>()
+    }
+
+    /// Serialize this NFA to a new byte vector in the native endianness of
+    /// the current target.
+    ///
+    /// This is convenient when the NFA is only ever deserialized on the same
+    /// target that it was serialized on. Otherwise, when building an NFA for
+    /// a different target, use one of [`NFA::to_bytes_little_endian`] or
+    /// [`NFA::to_bytes_big_endian`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::{backtrack::BoundedBacktracker, NFA},
+    ///     Match,
+    /// };
+    ///
+    /// let nfa = NFA::new_many(&[r"\w+@\w+", r"[0-9]+"])?;
+    /// let bytes = nfa.to_bytes_native_endian();
+    ///
+    /// let (nfa, _) = NFA::from_bytes(&bytes)?;
+    /// let re = BoundedBacktracker::new_from_nfa(nfa)?;
+    /// let mut cache = re.create_cache();
+    /// let got: Vec<Match> =
+    ///     re.try_find_iter(&mut cache, "a@b 12").collect::<Result<_, _>>()?;
+    /// assert_eq!(got, vec![Match::must(0, 0..3), Match::must(1, 4..6)]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes_native_endian(&self) -> Vec<u8> {
+        self.to_bytes::<wire::NE>()
+    }
+
+    /// Deserialize an NFA from the given bytes, which must have been
+    /// produced by one of the `NFA::to_bytes_*` routines using the native
+    /// endianness of the current target.
+    ///
+    /// Upon success, the NFA is returned along with the number of bytes read
+    /// from `slice`.
+    ///
+    /// Unlike the DFAs in this crate, an NFA always owns its data.
+    /// Deserializing one copies its states out of the slice given. The slice
+    /// given has no alignment requirements.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the bytes given don't correspond to a
+    /// serialized NFA, if the NFA was serialized with an incompatible format
+    /// version or endianness, or if it fails validation. Validation checks
+    /// that every state identifier refers to a state in the NFA, that every
+    /// capture state agrees with the NFA's capturing groups, that every
+    /// match state refers to one of its patterns and that its byte classes
+    /// agree with its transitions.
+    ///
+    /// # Safety
+    ///
+    /// This routine never results in undefined behavior, even when given
+    /// untrusted input. Since the NFA doesn't use any unsafe code, an NFA
+    /// that passes validation but is otherwise nonsensical may however
+    /// result in incorrect search results or panics.
+    ///
+    /// # Example
+    ///
+    /// This shows how to round trip an NFA with capturing groups through its
+    /// serialized form and use it to report the spans of those groups.
+    ///
+    /// ```
+    /// use regex_automata::nfa::thompson::{pikevm::PikeVM, NFA};
+    ///
+    /// let nfa = NFA::new(r"(?<year>[0-9]{4})-(?<month>[0-9]{2})")?;
+    /// let bytes = nfa.to_bytes_native_endian();
+    /// let (nfa, _) = NFA::from_bytes(&bytes)?;
+    ///
+    /// let re = PikeVM::new_from_nfa(nfa)?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// re.captures(&mut cache, "on 2010-03", &mut caps);
+    /// assert_eq!(Some(3..7), caps.get_group_by_name("year").map(|s| s.range()));
+    /// assert_eq!(Some(8..10), caps.get_group_by_name("month").map(|s| s.range()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Example: invalid input
+    ///
+    /// Corrupted or truncated input results in an error.
+    ///
+    /// ```
+    /// use regex_automata::nfa::thompson::NFA;
+    ///
+    /// let nfa = NFA::new(r"foo[0-9]+")?;
+    /// let bytes = nfa.to_bytes_native_endian();
+    /// assert!(NFA::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    /// assert!(NFA::from_bytes(b"not an NFA").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes(slice: &[u8]) -> Result<(NFA, usize), DeserializeError> {
+        let mut rdr = Reader::new(slice);
+        rdr.header(LABEL, VERSION)?;
+        let nfa = NFA::read_from(&mut rdr)?;
+        Ok((nfa, rdr.nread()))
+    }
+
+    fn to_bytes<E: Endian>(&self) -> Vec<u8> {
+        let mut wtr = Writer::new::<E>();
+        wtr.header(LABEL, VERSION);
+        self.write_to(&mut wtr);
+        wtr.into_bytes()
+    }
+
+    /// Write this NFA to the given writer.
+    ///
+    /// Unlike the public `NFA::to_bytes_*` routines, this doesn't write any
+    /// header. Namely, it's meant to embed an NFA in the serialized form of
+    /// something else, like a meta regex.
+    pub(crate) fn write_to(&self, wtr: &mut Writer) {
+        let nfa = &*self.0;
+        wtr.bool(nfa.utf8);
+        wtr.bool(nfa.reverse);
+        wtr.u8(nfa.look_matcher.get_line_terminator());
+
+        wtr.state_id(nfa.start_anchored);
+        wtr.state_id(nfa.start_unanchored);
+        wtr.usize(nfa.start_pattern.len());
+        for &sid in nfa.start_pattern.iter() {
+            wtr.state_id(sid);
+        }
+
+        // Note that the number of patterns in the group info may be zero even
+        // when the NFA has patterns, i.e., when capture states are disabled.
+        let group_info = &nfa.group_info;
+        wtr.usize(group_info.pattern_len());
+        for pid in (0..group_info.pattern_len()).map(PatternID::must) {
+            wtr.usize(group_info.group_len(pid));
+            for name in group_info.pattern_names(pid) {
+                wtr.bool(name.is_some());
+                wtr.bytes(name.unwrap_or("").as_bytes());
+            }
+        }
+
+        wtr.usize(nfa.look_arounds.len());
+        for la in nfa.look_arounds.iter() {
+            wtr.u8(match la.kind() {
+                LookAroundKind::PositiveLookAhead => 0,
+                LookAroundKind::NegativeLookAhead => 1,
+                LookAroundKind::PositiveLookBehind => 2,
+                LookAroundKind::NegativeLookBehind => 3,
+            });
+            wtr.option_usize(la.max_len());
+            la.nfa().write_to(wtr);
+        }
+
+        wtr.usize(nfa.states.len());
+        for state in nfa.states.iter() {
+            match *state {
+                State::ByteRange { trans } => {
+                    wtr.u8(0);
+                    write_transition(wtr, trans);
+                }
+                State::Sparse(ref sparse) => {
+                    wtr.u8(1);
+                    wtr.usize(sparse.transitions.len());
+                    for &trans in sparse.transitions.iter() {
+                        write_transition(wtr, trans);
+                    }
+                }
+                // The builder never adds dense states, and `Inner::add`
+                // rejects them.
+                State::Dense(_) => unreachable!(),
+                State::Look { look, next } => {
+                    wtr.u8(2);
+                    wtr.u32(look.as_repr());
+                    wtr.state_id(next);
+                }
+                State::LookAround { index, next } => {
+                    wtr.u8(3);
+                    wtr.u32(index.as_u32());
+                    wtr.state_id(next);
+                }
+                State::Union { ref alternates } => {
+                    wtr.u8(4);
+                    wtr.usize(alternates.len());
+                    for &sid in alternates.iter() {
+                        wtr.state_id(sid);
+                    }
+                }
+                State::BinaryUnion { alt1, alt2 } => {
+                    wtr.u8(5);
+                    wtr.state_id(alt1);
+                    wtr.state_id(alt2);
+                }
+                State::Capture { next, pattern_id, group_index, slot } => {
+                    wtr.u8(6);
+                    wtr.state_id(next);
+                    wtr.pattern_id(pattern_id);
+                    wtr.u32(group_index.as_u32());
+                    wtr.u32(slot.as_u32());
+                }
+                State::Fail => wtr.u8(7),
+                State::Match { pattern_id } => {
+                    wtr.u8(8);
+                    wtr.pattern_id(pattern_id);
+                }
+                State::Backreference { pattern_id, group_index, next } => {
+                    wtr.u8(9);
+                    wtr.pattern_id(pattern_id);
+                    wtr.u32(group_index.as_u32());
+                    wtr.state_id(next);
+                }
+                State::AtomicStart { index, next } => {
+                    wtr.u8(10);
+                    wtr.u32(index.as_u32());
+                    wtr.state_id(next);
+                }
+                State::AtomicEnd { index, next } => {
+                    wtr.u8(11);
+                    wtr.u32(index.as_u32());
+                    wtr.state_id(next);
+                }
+            }
+        }
+
+        // The byte classes are always recomputed from the states when reading
+        // an NFA, but we write them anyway so that we can check that the NFA
+        // read is the one that was written.
+        for byte in 0..=255 {
+            wtr.u8(nfa.byte_classes.get(byte));
+        }
+    }
+
+    /// Read an NFA written by `NFA::write_to`.
+    ///
+    /// Like `NFA::write_to`, this doesn't read any header.
+    ///
+    /// This validates the NFA read. That is, every state identifier is
+    /// checked to be in bounds, every capture state is checked to agree with
+    /// the capturing groups of the NFA and so on. If the data is invalid,
+    /// then an error is returned. Since NFAs don't use any unsafe code, an
+    /// NFA that passes validation but is otherwise nonsensical may produce
+    /// incorrect results or panic when used, but it will never lead to
+    /// undefined behavior.
+    pub(crate) fn read_from(
+        rdr: &mut Reader<'_>,
+    ) -> Result<NFA, DeserializeError> {
+        NFA::read_from_nested(rdr, 0)
+    }
+
+    fn read_from_nested(
+        rdr: &mut Reader<'_>,
+        depth: usize,
+    ) -> Result<NFA, DeserializeError> {
+        if depth > LOOK_AROUND_NEST_LIMIT {
+            return Err(DeserializeError::generic(
+                "NFA look-around sub-expressions are nested too deeply",
+            ));
+        }
+        let mut nfa = Inner::default();
+        nfa.set_utf8(rdr.bool("NFA UTF-8 mode")?);
+        nfa.set_reverse(rdr.bool("NFA reverse mode")?);
+        // The look matcher must be set before adding any states, since it
+        // influences how byte classes are computed.
+        let mut lookm = LookMatcher::new();
+        lookm.set_line_terminator(rdr.u8("NFA line terminator")?);
+        nfa.set_look_matcher(lookm);
+
+        let start_anchored = rdr.state_id("NFA anchored start state")?;
+        let start_unanchored = rdr.state_id("NFA unanchored start state")?;
+        let pattern_len = rdr.len(StateID::SIZE, "NFA pattern count")?;
+        if pattern_len > PatternID::LIMIT {
+            return Err(DeserializeError::generic("too many NFA patterns"));
+        }
+        let mut start_pattern = Vec::with_capacity(pattern_len);
+        for _ in 0..pattern_len {
+            start_pattern.push(rdr.state_id("NFA pattern start state")?);
+        }
+        nfa.set_starts(start_anchored, start_unanchored, &start_pattern);
+
+        // Every pattern has at least one group, and every group occupies at
+        // least 9 bytes.
+        let group_pattern_len = rdr.len(8 + 9, "NFA group pattern count")?;
+        let mut captures = Vec::with_capacity(group_pattern_len);
+        for _ in 0..group_pattern_len {
+            let group_len = rdr.len(9, "NFA group count")?;
+            let mut names = Vec::with_capacity(group_len);
+            for _ in 0..group_len {
+                let has_name = rdr.bool("NFA group name")?;
+                let name = rdr.bytes("NFA group name")?;
+                if !has_name {
+                    names.push(None);
+                    continue;
+                }
+                let name = core::str::from_utf8(name).map_err(|_| {
+                    DeserializeError::generic("NFA group name is not UTF-8")
+                })?;
+                names.push(Some(Arc::from(name)));
+            }
+            captures.push(names);
+        }
+        if group_pattern_len != 0 && group_pattern_len != pattern_len {
+            return Err(DeserializeError::generic(
+                "NFA capturing groups do not match its patterns",
+            ));
+        }
+        nfa.set_captures(&captures).map_err(|_| {
+            DeserializeError::generic("invalid NFA capturing groups")
+        })?;
+
+        let look_arounds_len = rdr.len(1, "NFA look-around count")?;
+        let mut look_arounds = Vec::with_capacity(look_arounds_len);
+        for _ in 0..look_arounds_len {
+            let kind = match rdr.u8("NFA look-around kind")? {
+                0 => LookAroundKind::PositiveLookAhead,
+                1 => LookAroundKind::NegativeLookAhead,
+                2 => LookAroundKind::PositiveLookBehind,
+                3 => LookAroundKind::NegativeLookBehind,
+                _ => {
+                    return Err(DeserializeError::generic(
+                        "unrecognized NFA look-around kind",
+                    ))
+                }
+            };
+            let max_len = rdr.option_usize("NFA look-around length")?;
+            let sub = NFA::read_from_nested(rdr, depth + 1)?;
+            if kind.is_look_behind() == sub.is_reverse() {
+                return Err(DeserializeError::generic(
+                    "NFA look-around has sub-expression in wrong direction",
+                ));
+            }
+            look_arounds.push(LookAround::new(kind, sub, max_len));
+        }
+        nfa.set_look_arounds(&look_arounds);
+
+        // Every state occupies at least 1 byte.
+        let states_len = rdr.len(1, "NFA state count")?;
+        if states_len > StateID::LIMIT {
+            return Err(DeserializeError::generic("too many NFA states"));
+        }
+        let mut atomic_starts = vec![];
+        let mut atomic_ends = vec![];
+        for _ in 0..states_len {
+            let state = match rdr.u8("NFA state kind")? {
+                0 => State::ByteRange { trans: read_transition(rdr)? },
+                1 => {
+                    let len = rdr.len(6, "NFA sparse transition count")?;
+                    let mut transitions = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        let trans = read_transition(rdr)?;
+                        let is_sorted = transitions
+                            .last()
+                            .map_or(true, |t: &Transition| {
+                                t.end < trans.start
+                            });
+                        if !is_sorted {
+                            return Err(DeserializeError::generic(
+                                "NFA sparse transitions are not sorted",
+                            ));
+                        }
+                        transitions.push(trans);
+                    }
+                    let transitions = transitions.into_boxed_slice();
+                    State::Sparse(SparseTransitions { transitions })
+                }
+                2 => {
+                    let repr = rdr.u32("NFA look assertion")?;
+                    let look = Look::from_repr(repr).ok_or_else(|| {
+                        DeserializeError::generic(
+                            "unrecognized NFA look assertion",
+                        )
+                    })?;
+                    State::Look { look, next: rdr.state_id("NFA state")? }
+                }
+                3 => {
+                    let index = read_small_index(rdr, "NFA look-around")?;
+                    if index.as_usize() >= look_arounds.len() {
+                        return Err(DeserializeError::generic(
+                            "invalid NFA look-around index",
+                        ));
+                    }
+                    State::LookAround {
+                        index,
+                        next: rdr.state_id("NFA state")?,
+                    }
+                }
+                4 => {
+                    let len = rdr.len(4, "NFA union count")?;
+                    let mut alternates = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        alternates.push(rdr.state_id("NFA state")?);
+                    }
+                    let alternates = alternates.into_boxed_slice();
+                    State::Union { alternates }
+                }
+                5 => State::BinaryUnion {
+                    alt1: rdr.state_id("NFA state")?,
+                    alt2: rdr.state_id("NFA state")?,
+                },
+                6 => {
+                    let next = rdr.state_id("NFA state")?;
+                    let pattern_id = rdr.pattern_id("NFA capture pattern")?;
+                    let group_index =
+                        read_small_index(rdr, "NFA capture group")?;
+                    let slot = read_small_index(rdr, "NFA capture slot")?;
+                    let start = nfa
+                        .group_info()
+                        .slot(pattern_id, group_index.as_usize())
+                        .ok_or_else(|| {
+                            DeserializeError::generic(
+                                "invalid NFA capture group",
+                            )
+                        })?;
+                    if slot.as_usize() != start && slot.as_usize() != start + 1
+                    {
+                        return Err(DeserializeError::generic(
+                            "NFA capture slot does not match its group",
+                        ));
+                    }
+                    State::Capture { next, pattern_id, group_index, slot }
+                }
+                7 => State::Fail,
+                8 => {
+                    let pattern_id = rdr.pattern_id("NFA match pattern")?;
+                    if pattern_id.as_usize() >= pattern_len {
+                        return Err(DeserializeError::generic(
+                            "invalid NFA match pattern",
+                        ));
+                    }
+                    State::Match { pattern_id }
+                }
+                9 => {
+                    let pattern_id =
+                        rdr.pattern_id("NFA backreference pattern")?;
+                    let group_index =
+                        read_small_index(rdr, "NFA backreference group")?;
+                    let exists = nfa
+                        .group_info()
+                        .slot(pattern_id, group_index.as_usize())
+                        .is_some();
+                    if !exists {
+                        return Err(DeserializeError::generic(
+                            "invalid NFA backreference group",
+                        ));
+                    }
+                    let next = rdr.state_id("NFA state")?;
+                    State::Backreference { pattern_id, group_index, next }
+                }
+                10 => {
+                    let index = read_small_index(rdr, "NFA atomic index")?;
+                    let next = rdr.state_id("NFA state")?;
+                    atomic_starts.push(index);
+                    State::AtomicStart { index, next }
+                }
+                11 => {
+                    let index = read_small_index(rdr, "NFA atomic index")?;
+                    let next = rdr.state_id("NFA state")?;
+                    atomic_ends.push(index);
+                    State::AtomicEnd { index, next }
+                }
+                _ => {
+                    return Err(DeserializeError::generic(
+                        "unrecognized NFA state kind",
+                    ))
+                }
+            };
+            nfa.add(state);
+        }
+
+        // Finally, check that every state ID refers to a state that exists.
+        let is_valid = |sid: StateID| sid.as_usize() < states_len;
+        let all_valid = is_valid(start_anchored)
+            && is_valid(start_unanchored)
+            && start_pattern.iter().all(|&sid| is_valid(sid))
+            && nfa.states.iter().all(|state| match *state {
+                State::ByteRange { trans } => is_valid(trans.next),
+                State::Sparse(ref sparse) => {
+                    sparse.transitions.iter().all(|t| is_valid(t.next))
+                }
+                State::Dense(ref dense) => {
+                    dense.transitions.iter().all(|&sid| is_valid(sid))
+                }
+                State::Look { next, .. }
+                | State::LookAround { next, .. }
+                | State::Backreference { next, .. }
+                | State::AtomicStart { next, .. }
+                | State::AtomicEnd { next, .. }
+                | State::Capture { next, .. } => is_valid(next),
+                State::Union { ref alternates } => {
+                    alternates.iter().all(|&sid| is_valid(sid))
+                }
+                State::BinaryUnion { alt1, alt2 } => {
+                    is_valid(alt1) && is_valid(alt2)
+                }
+                State::Fail | State::Match { .. } => true,
+            });
+        if !all_valid {
+            return Err(DeserializeError::generic(
+                "NFA contains invalid state identifier",
+            ));
+        }
+        // Atomic group indices must be sequential and each one must be opened
+        // by precisely one 'AtomicStart' state.
+        atomic_starts.sort();
+        let atomic_valid = atomic_starts
+            .iter()
+            .enumerate()
+            .all(|(i, index)| i == index.as_usize())
+            && atomic_ends
+                .iter()
+                .all(|index| index.as_usize() < atomic_starts.len());
+        if !atomic_valid {
+            return Err(DeserializeError::generic(
+                "NFA contains invalid atomic group index",
+            ));
+        }
+        let nfa = nfa.into_nfa();
+        for byte in 0..=255 {
+            if rdr.u8("NFA byte class")? != nfa.byte_classes().get(byte) {
+                return Err(DeserializeError::generic(
+                    "NFA byte classes do not match its transitions",
+                ));
+            }
+        }
+        Ok(nfa)
+    }
+}
+
+fn write_transition(wtr: &mut Writer, trans: Transition) {
+    wtr.u8(trans.start);
+    wtr.u8(trans.end);
+    wtr.state_id(trans.next);
+}
+
+fn read_transition(
+    rdr: &mut Reader<'_>,
+) -> Result<Transition, DeserializeError> {
+    let start = rdr.u8("NFA transition start")?;
+    let end = rdr.u8("NFA transition end")?;
+    let next = rdr.state_id("NFA transition")?;
+    if start > end {
+        return Err(DeserializeError::generic("invalid NFA transition range"));
+    }
+    Ok(Transition { start, end, next })
+}
+
+fn read_small_index(
+    rdr: &mut Reader<'_>,
+    what: &'static str,
+) -> Result<SmallIndex, DeserializeError> {
+    let index = rdr.u32(what)?;
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| SmallIndex::new(index).ok())
+        .ok_or_else(|| DeserializeError::generic("invalid NFA index"))
+}
+
 impl fmt::Debug for NFA {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -1236,6 +2018,8 @@ pub(super) struct Inner {
     byte_classes: ByteClasses,
     /// Whether this NFA has a `Capture` state anywhere.
     has_capture: bool,
+    /// Whether this NFA has a `Backreference` state anywhere.
+    has_backreference: bool,
     /// When the empty string is in the language matched by this NFA.
     has_empty: bool,
     /// Whether UTF-8 mode is enabled for this NFA. Briefly, this means that
@@ -1255,6 +2039,18 @@ pub(super) struct Inner {
     /// The union of all look-around assertions that occur as a zero-length
     /// prefix for any of the patterns in this NFA.
     look_set_prefix_any: LookSet,
+    /// The look-around sub-expressions referenced by the `LookAround` states
+    /// in this NFA, indexed by the `index` of each such state.
+    look_arounds: Vec<LookAround>,
+    /// The atomic groups in this NFA, indexed by the `index` of each
+    /// `AtomicStart` state. This is computed in `into_nfa`.
+    atomic_groups: Vec<AtomicGroup>,
+    /// The indices of the atomic groups such that inner groups come before
+    /// the groups containing them.
+    atomic_order: Vec<SmallIndex>,
+    /// Every union state inside of an atomic group, paired with the innermost
+    /// atomic group containing it. Sorted by state ID.
+    atomic_unions: Vec<(StateID, SmallIndex)>,
     /*
     /// The intersection of all look-around assertions that occur as a
     /// zero-length prefix for any of the patterns in this NFA.
@@ -1319,6 +2115,18 @@ impl Inner {
                         prefix_any = prefix_any.insert(look);
                         stack.push(next);
                     }
+                    State::LookAround { next, .. } => {
+                        stack.push(next);
+                    }
+                    // A backreference might match the empty string, so we
+                    // conservatively treat it like an epsilon transition.
+                    State::Backreference { next, .. } => {
+                        stack.push(next);
+                    }
+                    State::AtomicStart { next, .. }
+                    | State::AtomicEnd { next, .. } => {
+                        stack.push(next);
+                    }
                     State::Union { ref alternates } => {
                         // Order doesn't matter here, since we're just dealing
                         // with look-around sets. But if we do richer analysis
@@ -1338,9 +2146,100 @@ impl Inner {
             self.look_set_prefix_any =
                 self.look_set_prefix_any.union(prefix_any);
         }
+        self.compute_atomic_groups();
         NFA(Arc::new(self))
     }
 
+    /// Computes the states making up each atomic group in this NFA, along
+    /// with the innermost atomic group of every union state.
+    ///
+    /// The states of an atomic group are all of the states reachable from its
+    /// `AtomicStart` state without going through its `AtomicEnd` state. (The
+    /// `AtomicEnd` state itself is included.)
+    fn compute_atomic_groups(&mut self) {
+        let mut starts = vec![];
+        for (i, state) in self.states.iter().enumerate() {
+            if let State::AtomicStart { index, next } = *state {
+                starts.push((index, next, StateID::new_unchecked(i)));
+            }
+        }
+        if starts.is_empty() {
+            return;
+        }
+        starts.sort_by_key(|&(index, _, _)| index);
+
+        let mut stack = vec![];
+        let mut seen = SparseSet::new(self.states.len());
+        let mut groups = Vec::with_capacity(starts.len());
+        for &(index, next, _) in starts.iter() {
+            seen.clear();
+            stack.push(next);
+            while let Some(sid) = stack.pop() {
+                if !seen.insert(sid) {
+                    continue;
+                }
+                match self.states[sid] {
+                    State::ByteRange { ref trans } => stack.push(trans.next),
+                    State::Sparse(ref sparse) => {
+                        stack.extend(sparse.transitions.iter().map(|t| t.next))
+                    }
+                    State::Dense(ref dense) => {
+                        stack.extend(dense.transitions.iter())
+                    }
+                    State::AtomicEnd { index: end, next } => {
+                        if end != index {
+                            stack.push(next);
+                        }
+                    }
+                    State::Look { next, .. }
+                    | State::LookAround { next, .. }
+                    | State::Backreference { next, .. }
+                    | State::AtomicStart { next, .. }
+                    | State::Capture { next, .. } => stack.push(next),
+                    State::Union { ref alternates } => {
+                        stack.extend(alternates.iter())
+                    }
+                    State::BinaryUnion { alt1, alt2 } => {
+                        stack.push(alt2);
+                        stack.push(alt1);
+                    }
+                    State::Fail | State::Match { .. } => {}
+                }
+            }
+            let mut states: Vec<StateID> = seen.iter().collect();
+            states.sort();
+            groups.push(AtomicGroup {
+                start: next,
+                states: states.into_boxed_slice(),
+            });
+        }
+
+        // Groups nested inside of another group always have strictly fewer
+        // states, so sorting by size puts inner groups first.
+        let mut order: Vec<SmallIndex> =
+            starts.iter().map(|&(index, _, _)| index).collect();
+        order.sort_by_key(|index| groups[index.as_usize()].states.len());
+
+        let mut unions: Vec<(StateID, SmallIndex)> = vec![];
+        seen.clear();
+        for &index in order.iter() {
+            for &sid in groups[index.as_usize()].states.iter() {
+                let is_union = matches!(
+                    self.states[sid],
+                    State::Union { .. } | State::BinaryUnion { .. }
+                );
+                if is_union && seen.insert(sid) {
+                    unions.push((sid, index));
+                }
+            }
+        }
+        unions.sort_by_key(|&(sid, _)| sid);
+
+        self.atomic_groups = groups;
+        self.atomic_order = order;
+        self.atomic_unions = unions;
+    }
+
     /// Returns the capturing group info for this NFA.
     pub(super) fn group_info(&self) -> &GroupInfo {
         &self.group_info
@@ -1371,7 +2270,13 @@ impl Inner {
             State::Capture { .. } => {
                 self.has_capture = true;
             }
-            State::Union { .. }
+            State::Backreference { .. } => {
+                self.has_backreference = true;
+            }
+            State::LookAround { .. }
+            | State::AtomicStart { .. }
+            | State::AtomicEnd { .. }
+            | State::Union { .. }
             | State::BinaryUnion { .. }
             | State::Fail
             | State::Match { .. } => {}
@@ -1415,6 +2320,11 @@ impl Inner {
         self.look_matcher = m;
     }
 
+    /// Set the look-around sub-expressions for this NFA.
+    pub(super) fn set_look_arounds(&mut self, look_arounds: &[LookAround]) {
+        self.look_arounds = look_arounds.to_vec();
+    }
+
     /// Set the capturing groups for this NFA.
     ///
     /// The given slice should contain the capturing groups for each pattern,
@@ -1545,6 +2455,71 @@ pub enum State {
         /// satisfied.
         next: StateID,
     },
+    /// A conditional epsilon transition satisfied via a look-around
+    /// assertion with a sub-expression, e.g., `(?=a)` or `(?<!b)`.
+    ///
+    /// Unlike [`Look`](State::Look), evaluating this assertion requires
+    /// running the NFA of its sub-expression against the haystack. Regex
+    /// engines that support this state do so by computing, ahead of a search,
+    /// the positions at which the assertion holds. This keeps the search time
+    /// linear in the size of the haystack.
+    LookAround {
+        /// The index of the look-around sub-expression in this NFA. See
+        /// [`NFA::look_arounds`].
+        index: SmallIndex,
+        /// The state to transition to if the look-around assertion is
+        /// satisfied.
+        next: StateID,
+    },
+    /// A transition that matches exactly the text most recently matched by
+    /// a capturing group, i.e., a backreference like `\1`.
+    ///
+    /// This transition consumes as many bytes as the capturing group last
+    /// matched, which may be zero. If the capturing group hasn't matched
+    /// anything yet, then the transition is never taken. Since the bytes
+    /// consumed depend on the path taken to reach this state, it cannot be
+    /// simulated by the PikeVM or any DFA. Only the
+    /// [`Backtracker`](crate::nfa::thompson::backref::Backtracker) engine
+    /// (behind the `nfa-backref` crate feature) supports it.
+    Backreference {
+        /// The ID of the pattern that this backreference belongs to.
+        pattern_id: PatternID,
+        /// The index of the capturing group being referred to. This is
+        /// relative to the pattern, just like in
+        /// [`Capture`](State::Capture) states.
+        group_index: SmallIndex,
+        /// The state to transition to after matching the text of the
+        /// capturing group.
+        next: StateID,
+    },
+    /// An unconditional epsilon transition that marks the beginning of an
+    /// atomic group, e.g., `(?>a*)`, or of a possessive repetition, e.g.,
+    /// `a*+`.
+    ///
+    /// Once a match of the group's sub-expression has been found (i.e., the
+    /// corresponding [`AtomicEnd`](State::AtomicEnd) state has been reached),
+    /// none of the other ways of matching the sub-expression from the same
+    /// starting position are ever tried. Only the
+    /// [`PikeVM`](crate::nfa::thompson::pikevm::PikeVM) and the
+    /// [`Backtracker`](crate::nfa::thompson::backref::Backtracker) support
+    /// this state. Building any other regex engine from an NFA containing it
+    /// will fail.
+    AtomicStart {
+        /// The index of this atomic group. Indices are assigned sequentially
+        /// starting at `0`, and each index is used by precisely one
+        /// `AtomicStart` state.
+        index: SmallIndex,
+        /// The state to transition to unconditionally.
+        next: StateID,
+    },
+    /// An unconditional epsilon transition that marks the end of an atomic
+    /// group. See [`AtomicStart`](State::AtomicStart) for more details.
+    AtomicEnd {
+        /// The index of the atomic group that this state closes.
+        index: SmallIndex,
+        /// The state to transition to unconditionally.
+        next: StateID,
+    },
     /// An alternation such that there exists an epsilon transition to all
     /// states in `alternates`, where matches found via earlier transitions
     /// are preferred over later transitions.
@@ -1657,9 +2632,13 @@ impl State {
             State::ByteRange { .. }
             | State::Sparse { .. }
             | State::Dense { .. }
+            | State::Backreference { .. }
             | State::Fail
             | State::Match { .. } => false,
             State::Look { .. }
+            | State::LookAround { .. }
+            | State::AtomicStart { .. }
+            | State::AtomicEnd { .. }
             | State::Union { .. }
             | State::BinaryUnion { .. }
             | State::Capture { .. } => true,
@@ -1671,6 +2650,10 @@ impl State {
         match *self {
             State::ByteRange { .. }
             | State::Look { .. }
+            | State::LookAround { .. }
+            | State::Backreference { .. }
+            | State::AtomicStart { .. }
+            | State::AtomicEnd { .. }
             | State::BinaryUnion { .. }
             | State::Capture { .. }
             | State::Match { .. }
@@ -1707,6 +2690,10 @@ impl State {
                 }
             }
             State::Look { ref mut next, .. } => *next = remap[*next],
+            State::LookAround { ref mut next, .. } => *next = remap[*next],
+            State::Backreference { ref mut next, .. } => *next = remap[*next],
+            State::AtomicStart { ref mut next, .. } => *next = remap[*next],
+            State::AtomicEnd { ref mut next, .. } => *next = remap[*next],
             State::Union { ref mut alternates } => {
                 for alt in alternates.iter_mut() {
                     *alt = remap[*alt];
@@ -1748,6 +2735,39 @@ impl fmt::Debug for State {
             State::Look { ref look, next } => {
                 write!(f, "{:?} => {:?}", look, next.as_usize())
             }
+            State::LookAround { index, next } => {
+                write!(
+                    f,
+                    "look-around({:?}) => {:?}",
+                    index.as_usize(),
+                    next.as_usize()
+                )
+            }
+            State::Backreference { pattern_id, group_index, next } => {
+                write!(
+                    f,
+                    "backref(pid={:?}, group={:?}) => {:?}",
+                    pattern_id.as_usize(),
+                    group_index.as_usize(),
+                    next.as_usize(),
+                )
+            }
+            State::AtomicStart { index, next } => {
+                write!(
+                    f,
+                    "atomic-start({:?}) => {:?}",
+                    index.as_usize(),
+                    next.as_usize()
+                )
+            }
+            State::AtomicEnd { index, next } => {
+                write!(
+                    f,
+                    "atomic-end({:?}) => {:?}",
+                    index.as_usize(),
+                    next.as_usize()
+                )
+            }
             State::Union { ref alternates } => {
                 let alts = alternates
                     .iter()
@@ -1782,6 +2802,142 @@ impl fmt::Debug for State {
     }
 }
 
+/// A look-around assertion with a sub-expression, as referenced by a
+/// [`LookAround`](State::LookAround) state.
+///
+/// A look-around assertion holds at a position in a haystack when its
+/// sub-expression matches (or, for negated assertions, does not match)
+/// immediately after that position (for look-ahead) or immediately before
+/// that position (for look-behind). The sub-expression is always matched
+/// against the entire haystack, regardless of the bounds of a search.
+///
+/// The sub-expression is represented by its own NFA. For look-ahead, this NFA
+/// must be a [reverse](NFA::is_reverse) NFA, and for look-behind, it must be
+/// a forward NFA. This permits a regex engine to compute where an assertion
+/// holds for every position in a search with a single pass over the haystack.
+#[derive(Clone, Debug)]
+pub struct LookAround {
+    kind: LookAroundKind,
+    nfa: NFA,
+    max_len: Option<usize>,
+}
+
+impl LookAround {
+    /// Create a new look-around assertion of the given kind whose
+    /// sub-expression is matched by the given NFA.
+    ///
+    /// `max_len` should be an upper bound on the length of any match of the
+    /// sub-expression, if one is known. Regex engines use this to limit how
+    /// much of the haystack outside of a search they need to examine. If the
+    /// bound given is not correct, then searches may return incorrect
+    /// results.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `kind` is a look-ahead and `nfa` is not a reverse NFA,
+    /// or if `kind` is a look-behind and `nfa` is a reverse NFA.
+    pub fn new(
+        kind: LookAroundKind,
+        nfa: NFA,
+        max_len: Option<usize>,
+    ) -> LookAround {
+        assert_eq!(
+            !kind.is_look_behind(),
+            nfa.is_reverse(),
+            "look-ahead requires a reverse NFA \
+             and look-behind requires a forward NFA",
+        );
+        LookAround { kind, nfa, max_len }
+    }
+
+    /// Returns the kind of this look-around assertion.
+    #[inline]
+    pub fn kind(&self) -> LookAroundKind {
+        self.kind
+    }
+
+    /// Returns the NFA for the sub-expression of this look-around assertion.
+    #[inline]
+    pub fn nfa(&self) -> &NFA {
+        &self.nfa
+    }
+
+    /// Returns an upper bound on the length of any match of the
+    /// sub-expression, if one is known.
+    #[inline]
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+}
+
+/// The kind of a look-around assertion with a sub-expression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LookAroundKind {
+    /// `(?=a)`
+    PositiveLookAhead,
+    /// `(?!a)`
+    NegativeLookAhead,
+    /// `(?<=a)`
+    PositiveLookBehind,
+    /// `(?<!a)`
+    NegativeLookBehind,
+}
+
+impl LookAroundKind {
+    /// Returns true if and only if this is a look-behind assertion.
+    #[inline]
+    pub fn is_look_behind(self) -> bool {
+        matches!(
+            self,
+            LookAroundKind::PositiveLookBehind
+                | LookAroundKind::NegativeLookBehind
+        )
+    }
+
+    /// Returns true if and only if this is a negative assertion, i.e., one
+    /// that holds when its sub-expression does not match.
+    #[inline]
+    pub fn is_negated(self) -> bool {
+        matches!(
+            self,
+            LookAroundKind::NegativeLookAhead
+                | LookAroundKind::NegativeLookBehind
+        )
+    }
+}
+
+/// The states making up a single atomic group in an NFA.
+///
+/// This is computed when an NFA is built and is used by the regex engines
+/// that support atomic groups.
+#[derive(Clone, Debug)]
+pub(crate) struct AtomicGroup {
+    /// The state that the group's `AtomicStart` state transitions to.
+    start: StateID,
+    /// All states reachable from the group's `AtomicStart` state without
+    /// passing through its `AtomicEnd` state, including the `AtomicEnd`
+    /// state itself. Sorted by state ID.
+    states: Box<[StateID]>,
+}
+
+impl AtomicGroup {
+    /// Returns the first state of this group's sub-expression.
+    pub(crate) fn start(&self) -> StateID {
+        self.start
+    }
+
+    /// Returns the states in this group, sorted by state ID.
+    pub(crate) fn states(&self) -> &[StateID] {
+        &self.states
+    }
+
+    /// Returns the position of the given state in `states()`, if it is in
+    /// this group.
+    pub(crate) fn position(&self, sid: StateID) -> Option<usize> {
+        self.states.binary_search(&sid).ok()
+    }
+}
+
 /// A sequence of transitions used to represent a sparse state.
 ///
 /// This is the primary representation of a [`Sparse`](State::Sparse) state.
@@ -2096,4 +3252,50 @@ mod tests {
         assert_eq!(None, find("ab", 1, 2));
         assert_eq!(None, find("ab", 2, 2));
     }
+
+    #[test]
+    fn serialize_always_and_never_match() {
+        for nfa in [NFA::always_match(), NFA::never_match()] {
+            let bytes = nfa.to_bytes_native_endian();
+            let (got, nread) = NFA::from_bytes(&bytes).unwrap();
+            assert_eq!(bytes.len(), nread);
+            assert_eq!(nfa.states(), got.states());
+            assert_eq!(nfa.pattern_len(), got.pattern_len());
+        }
+    }
+
+    #[cfg(feature = "syntax")]
+    #[test]
+    fn serialize_look_around() {
+        let nfa = NFA::new(r"(?<=foo)bar(?!baz)").unwrap();
+        let bytes = nfa.to_bytes_native_endian();
+        let (nfa, _) = NFA::from_bytes(&bytes).unwrap();
+        assert_eq!(2, nfa.look_arounds().len());
+
+        let re = PikeVM::new_from_nfa(nfa).unwrap();
+        let mut cache = re.create_cache();
+        let mut find =
+            |haystack| re.find(&mut cache, haystack).map(|m| m.range());
+        assert_eq!(Some(3..6), find("foobar"));
+        assert_eq!(None, find("foobarbaz"));
+        assert_eq!(None, find("fobar"));
+    }
+
+    // Every truncated or corrupted serialization of an NFA must either fail
+    // to deserialize or deserialize without panicking.
+    #[cfg(feature = "syntax")]
+    #[test]
+    fn deserialize_invalid() {
+        let nfa = NFA::new_many(&[r"(?<name>[a-z]+)(?<=b)", r"[0-9]{2,}\b"])
+            .unwrap();
+        let bytes = nfa.to_bytes_native_endian();
+        for len in 0..bytes.len() {
+            assert!(NFA::from_bytes(&bytes[..len]).is_err());
+        }
+        for i in 0..bytes.len() {
+            let mut corrupt = bytes.clone();
+            corrupt[i] = corrupt[i].wrapping_add(1);
+            let _ = NFA::from_bytes(&corrupt);
+        }
+    }
 }
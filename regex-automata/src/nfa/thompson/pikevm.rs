@@ -11,14 +11,16 @@ use core::cell::RefCell;
 use alloc::{vec, vec::Vec};
 
 use crate::{
-    nfa::thompson::{self, BuildError, State, NFA},
+    nfa::thompson::{self, atomic, lookaround, BuildError, State, NFA},
     util::{
         captures::Captures,
+        chunks::Chunks,
         empty, iter,
         prefilter::Prefilter,
         primitives::{NonMaxUsize, PatternID, SmallIndex, StateID},
         search::{
-            Anchored, HalfMatch, Input, Match, MatchKind, PatternSet, Span,
+            Anchored, HalfMatch, Input, Match, MatchError, MatchKind,
+            PatternSet, Span,
         },
         sparse_set::SparseSet,
     },
@@ -66,6 +68,7 @@ std::thread_local! {
 pub struct Config {
     match_kind: Option<MatchKind>,
     pre: Option<Option<Prefilter>>,
+    max_edits: Option<Option<usize>>,
 }
 
 impl Config {
@@ -161,6 +164,66 @@ impl Config {
         self
     }
 
+    /// Enable approximate matching with the given edit budget.
+    ///
+    /// When set to `Some(k)` with `k > 0`, a match is reported for any
+    /// substring of the haystack that can be turned into a string matched by
+    /// the regex with at most `k` edits. An edit is the insertion, deletion
+    /// or substitution of a single byte. (Edits are counted in bytes and not
+    /// codepoints, so a match may split a UTF-8 encoded codepoint.)
+    ///
+    /// Which match is reported is determined by the match semantics in use,
+    /// just like for exact matching, where taking an edit always has a lower
+    /// priority than matching a byte exactly. For example, with
+    /// [`MatchKind::LeftmostFirst`], the match reported always starts at the
+    /// leftmost position at which any match within the budget starts. But
+    /// it does not necessarily have the fewest edits of all such matches.
+    /// A match with fewer edits that starts later is never preferred, since
+    /// looking for one would require scanning the rest of the haystack.
+    ///
+    /// Each edit in the budget multiplies the amount of state tracked by a
+    /// search, but the time taken by a search remains linear in the size of
+    /// the haystack for any fixed budget. Note that prefilters are never used
+    /// when approximate matching is enabled.
+    ///
+    /// This is disabled by default, which is equivalent to `Some(0)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{nfa::thompson::pikevm::PikeVM, Match};
+    ///
+    /// let re = PikeVM::builder()
+    ///     .configure(PikeVM::config().max_edits(Some(1)))
+    ///     .build(r"needle")?;
+    /// let mut cache = re.create_cache();
+    ///
+    /// // One substitution.
+    /// assert_eq!(
+    ///     Some(Match::must(0, 4..10)),
+    ///     re.find(&mut cache, "hay neeble hay"),
+    /// );
+    /// // One deletion.
+    /// assert_eq!(
+    ///     Some(Match::must(0, 4..9)),
+    ///     re.find(&mut cache, "hay nedle hay"),
+    /// );
+    /// // The leftmost match is preferred, even when an exact match comes
+    /// // later.
+    /// assert_eq!(
+    ///     Some(Match::must(0, 0..6)),
+    ///     re.find(&mut cache, "neeble hayneedle"),
+    /// );
+    /// // Two edits is too many.
+    /// assert_eq!(None, re.find(&mut cache, "hay nedble hay"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_edits(mut self, limit: Option<usize>) -> Config {
+        self.max_edits = Some(limit);
+        self
+    }
+
     /// Returns the match semantics set in this configuration.
     pub fn get_match_kind(&self) -> MatchKind {
         self.match_kind.unwrap_or(MatchKind::LeftmostFirst)
@@ -171,6 +234,18 @@ impl Config {
         self.pre.as_ref().unwrap_or(&None).as_ref()
     }
 
+    /// Returns the edit budget for approximate matching, if one was set by
+    /// [`Config::max_edits`].
+    pub fn get_max_edits(&self) -> Option<usize> {
+        self.max_edits.unwrap_or(None)
+    }
+
+    /// Returns the edit budget as a number, where `0` means approximate
+    /// matching is disabled.
+    fn edits(&self) -> usize {
+        self.get_max_edits().unwrap_or(0)
+    }
+
     /// Overwrite the default configuration such that the options in `o` are
     /// always used. If an option in `o` is not set, then the corresponding
     /// option in `self` is used. If it's not set in `self` either, then it
@@ -179,6 +254,7 @@ impl Config {
         Config {
             match_kind: o.match_kind.or(self.match_kind),
             pre: o.pre.or_else(|| self.pre.clone()),
+            max_edits: o.max_edits.or(self.max_edits),
         }
     }
 }
@@ -278,6 +354,24 @@ impl Builder {
     /// given here is already built.
     pub fn build_from_nfa(&self, nfa: NFA) -> Result<PikeVM, BuildError> {
         nfa.look_set_any().available().map_err(BuildError::word)?;
+        if nfa.has_backreference() {
+            return Err(BuildError::unsupported_backreference());
+        }
+        if nfa.has_atomic() && self.config.edits() > 0 {
+            return Err(BuildError::unsupported_atomic());
+        }
+        // Approximate matching tracks a copy of every NFA state for each
+        // possible edit cost, and every such copy needs an identifier.
+        let states = self
+            .config
+            .edits()
+            .checked_add(1)
+            .and_then(|costs| nfa.states().len().checked_mul(costs));
+        match states {
+            Some(states) if states <= StateID::LIMIT => {}
+            Some(states) => return Err(BuildError::too_many_states(states)),
+            None => return Err(BuildError::too_many_states(usize::MAX)),
+        }
         Ok(PikeVM { config: self.config.clone(), nfa })
     }
 
@@ -327,7 +421,7 @@ impl Builder {
 /// error at search time. It supports all [`Anchored`] configurations, never
 /// quits and works on haystacks of arbitrary length.
 ///
-/// There are two caveats to mention though:
+/// There are three caveats to mention though:
 ///
 /// * If an invalid pattern ID is given to a search via [`Anchored::Pattern`],
 /// then the PikeVM will report "no match." This is consistent with all other
@@ -336,6 +430,11 @@ impl Builder {
 /// that has insufficient capacity to store all valid pattern IDs, then if a
 /// match occurs for a `PatternID` that cannot be inserted, it is silently
 /// dropped as if it did not match.
+/// * If a search is given a cancellation flag via [`Input::cancel_flag`] and
+/// that flag is set before the search completes, then the search stops and
+/// the infallible routines panic. Use [`PikeVM::try_search_slots`] and
+/// [`PikeVM::try_which_overlapping_matches`] to handle cancellation as an
+/// error instead.
 ///
 /// # Advice
 ///
@@ -1094,10 +1193,78 @@ impl PikeVM {
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
     ) -> Option<PatternID> {
+        self.try_search_slots(cache, input, slots).unwrap()
+    }
+
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided [`Captures`]
+    /// value, or returns an error if the search was cancelled.
+    ///
+    /// This is like [`PikeVM::search`], except it returns an error instead
+    /// of panicking when the search is cancelled via [`Input::cancel_flag`].
+    /// When an error is returned, [`Captures::is_match`] is guaranteed to
+    /// return `false`.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search was cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicBool;
+    ///
+    /// use regex_automata::{nfa::thompson::pikevm::PikeVM, Input, Match};
+    ///
+    /// let re = PikeVM::new(r"[a-z]+")?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    ///
+    /// let cancel = AtomicBool::new(true);
+    /// let input = Input::new("foo").cancel_flag(&cancel);
+    /// assert!(re.try_search(&mut cache, &input, &mut caps).is_err());
+    /// assert!(!caps.is_match());
+    ///
+    /// let input = Input::new("foo");
+    /// re.try_search(&mut cache, &input, &mut caps)?;
+    /// assert_eq!(Some(Match::must(0, 0..3)), caps.get_match());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn try_search(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        caps: &mut Captures,
+    ) -> Result<(), MatchError> {
+        caps.set_pattern(None);
+        let pid = self.try_search_slots(cache, input, caps.slots_mut())?;
+        caps.set_pattern(pid);
+        Ok(())
+    }
+
+    /// Executes a leftmost forward search and writes the spans of capturing
+    /// groups that participated in a match into the provided `slots`, or
+    /// returns an error if the search was cancelled.
+    ///
+    /// This is like [`PikeVM::search_slots`], except it returns an error
+    /// instead of panicking when the search is cancelled via
+    /// [`Input::cancel_flag`].
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search was cancelled.
+    #[inline]
+    pub fn try_search_slots(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<PatternID>, MatchError> {
         let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
         if !utf8empty {
             let hm = self.search_slots_imp(cache, input, slots)?;
-            return Some(hm.pattern());
+            return Ok(hm.map(|hm| hm.pattern()));
         }
         // There is an unfortunate special case where if the regex can
         // match the empty string and UTF-8 mode is enabled, the search
@@ -1113,22 +1280,59 @@ impl PikeVM {
         let min = self.get_nfa().group_info().implicit_slot_len();
         if slots.len() >= min {
             let hm = self.search_slots_imp(cache, input, slots)?;
-            return Some(hm.pattern());
+            return Ok(hm.map(|hm| hm.pattern()));
         }
         if self.get_nfa().pattern_len() == 1 {
             let mut enough = [None, None];
-            let got = self.search_slots_imp(cache, input, &mut enough);
+            let got = self.search_slots_imp(cache, input, &mut enough)?;
             // This is OK because we know `enough` is strictly bigger than
             // `slots`, otherwise this special case isn't reached.
             slots.copy_from_slice(&enough[..slots.len()]);
-            return got.map(|hm| hm.pattern());
+            return Ok(got.map(|hm| hm.pattern()));
         }
         let mut enough = vec![None; min];
-        let got = self.search_slots_imp(cache, input, &mut enough);
+        let got = self.search_slots_imp(cache, input, &mut enough)?;
         // This is OK because we know `enough` is strictly bigger than `slots`,
         // otherwise this special case isn't reached.
         slots.copy_from_slice(&enough[..slots.len()]);
-        got.map(|hm| hm.pattern())
+        Ok(got.map(|hm| hm.pattern()))
+    }
+
+    /// Executes a reverse search and returns the start of a match, if one
+    /// exists. The search scans from the end of the input toward its start.
+    ///
+    /// This requires that this PikeVM was built from a
+    /// [reverse](thompson::Config::reverse) NFA without capturing groups,
+    /// atomic groups or backreferences. This is what the meta regex engine
+    /// uses when a reverse lazy DFA is unavailable or gives up, so that its
+    /// reverse searches remain linear in the size of the haystack.
+    ///
+    /// Capturing groups are not supported, and the match semantics are the
+    /// same as the reverse DFA engines: an unanchored search with
+    /// [`MatchKind::All`] and `earliest` enabled reports the last position at
+    /// which any match starts, while an anchored search without `earliest`
+    /// reports the first position at which a match ending at `input.end()`
+    /// starts.
+    ///
+    /// This routine only errors if the search was cancelled.
+    pub(crate) fn try_search_half_rev(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        debug_assert!(self.nfa.is_reverse());
+        debug_assert!(!self.nfa.has_atomic());
+        debug_assert_eq!(0, self.config.edits());
+        let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
+        let hm = match self.search_rev_imp(cache, input)? {
+            None => return Ok(None),
+            Some(hm) if !utf8empty => return Ok(Some(hm)),
+            Some(hm) => hm,
+        };
+        empty::skip_splits_rev(input, hm, hm.offset(), |input| {
+            let got = self.search_rev_imp(cache, input)?;
+            Ok(got.map(|hm| (hm, hm.offset())))
+        })
     }
 
     /// This is the actual implementation of `search_slots_imp` that
@@ -1141,20 +1345,18 @@ impl PikeVM {
         cache: &mut Cache,
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
-    ) -> Option<HalfMatch> {
+    ) -> Result<Option<HalfMatch>, MatchError> {
         let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
-        let hm = match self.search_imp(cache, input, slots) {
-            None => return None,
-            Some(hm) if !utf8empty => return Some(hm),
+        let hm = match self.search_imp(cache, input, slots)? {
+            None => return Ok(None),
+            Some(hm) if !utf8empty => return Ok(Some(hm)),
             Some(hm) => hm,
         };
         empty::skip_splits_fwd(input, hm, hm.offset(), |input| {
             Ok(self
-                .search_imp(cache, input, slots)
+                .search_imp(cache, input, slots)?
                 .map(|hm| (hm, hm.offset())))
         })
-        // OK because the PikeVM never errors.
-        .unwrap()
     }
 
     /// Writes the set of patterns that match anywhere in the given search
@@ -1211,8 +1413,146 @@ impl PikeVM {
         input: &Input<'_>,
         patset: &mut PatternSet,
     ) {
+        self.try_which_overlapping_matches(cache, input, patset).unwrap()
+    }
+
+    /// Writes the set of patterns that match anywhere in the given search
+    /// configuration to `patset`, or returns an error if the search was
+    /// cancelled.
+    ///
+    /// This is like [`PikeVM::which_overlapping_matches`], except it returns
+    /// an error instead of panicking when the search is cancelled via
+    /// [`Input::cancel_flag`]. When an error is returned, `patset` may
+    /// contain some, but not all, of the matching patterns.
+    ///
+    /// # Errors
+    ///
+    /// This routine only errors if the search was cancelled.
+    #[inline]
+    pub fn try_which_overlapping_matches(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        patset: &mut PatternSet,
+    ) -> Result<(), MatchError> {
         self.which_overlapping_imp(cache, input, patset)
     }
+
+    /// Executes a leftmost forward search over a haystack made up of chunks,
+    /// starting at the absolute offset `start`, and writes the spans of
+    /// capturing groups that participated in a match into the provided
+    /// [`Captures`] value. If no match was found, then [`Captures::is_match`]
+    /// is guaranteed to return `false`.
+    ///
+    /// This is like [`PikeVM::search`] with an unanchored [`Input`] whose
+    /// span starts at `start`, except the haystack is never copied into one
+    /// contiguous slice. All offsets reported are absolute offsets. The bytes
+    /// before `start` are only used to check look-behind assertions, such as
+    /// `\b`.
+    ///
+    /// Searches of chunks can't be cancelled and never use a prefilter.
+    ///
+    /// # Panics
+    ///
+    /// This panics when [`PikeVM::supports_chunks`] returns `false`, or when
+    /// `start` isn't within the bounds of the haystack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::{
+    ///     nfa::thompson::pikevm::PikeVM, util::chunks::Chunks, Match, Span,
+    /// };
+    ///
+    /// let re = PikeVM::new(r"\b([a-z]+)([0-9]+)\b")?;
+    /// let (mut cache, mut caps) = (re.create_cache(), re.create_captures());
+    /// let chunks = Chunks::new([(100, &b"xy foo"[..]), (106, b"1"), (107, b"23 z")]);
+    ///
+    /// re.search_chunks(&mut cache, &chunks, 100, &mut caps);
+    /// assert_eq!(Some(Match::must(0, 103..109)), caps.get_match());
+    /// assert_eq!(Some(Span::from(103..106)), caps.get_group(1));
+    /// assert_eq!(Some(Span::from(106..109)), caps.get_group(2));
+    ///
+    /// // Starting in the middle of 'foo' means that there's no word boundary
+    /// // at the start, and so no match.
+    /// re.search_chunks(&mut cache, &chunks, 104, &mut caps);
+    /// assert!(!caps.is_match());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn search_chunks(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+        caps: &mut Captures,
+    ) {
+        caps.set_pattern(None);
+        let pid =
+            self.search_chunks_slots(cache, chunks, start, caps.slots_mut());
+        caps.set_pattern(pid);
+    }
+
+    /// Executes a leftmost forward search over a haystack made up of chunks,
+    /// and writes the spans of capturing groups that participated in a match
+    /// into the provided `slots`.
+    ///
+    /// This is like [`PikeVM::search_chunks`], but it uses raw slots like
+    /// [`PikeVM::search_slots`] does.
+    ///
+    /// # Panics
+    ///
+    /// This panics when [`PikeVM::supports_chunks`] returns `false`, or when
+    /// `start` isn't within the bounds of the haystack.
+    #[inline]
+    pub fn search_chunks_slots(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Option<PatternID> {
+        assert!(
+            self.supports_chunks(),
+            "PikeVM cannot search chunks with this NFA or configuration",
+        );
+        assert!(
+            chunks.start() <= start && start <= chunks.end(),
+            "search start {} is not within the bounds of the haystack",
+            start,
+        );
+        let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
+        let mut start = start;
+        loop {
+            let hm = self.search_chunks_imp(cache, chunks, start, slots)?;
+            // This mirrors 'empty::skip_splits_fwd'. Only an empty match can
+            // split a codepoint, and the only way to get past one is to look
+            // for a match starting at the next position.
+            if !utf8empty || chunks.is_char_boundary(hm.offset()) {
+                return Some(hm.pattern());
+            }
+            if start == chunks.end() {
+                return None;
+            }
+            start += 1;
+        }
+    }
+
+    /// Returns true if and only if this PikeVM can search a haystack made up
+    /// of chunks with [`PikeVM::search_chunks`].
+    ///
+    /// This is true unless the NFA contains bounded look-around assertions,
+    /// backreferences or atomic groups, or approximate matching is enabled
+    /// via [`Config::max_edits`]. Each of these needs access to parts of the
+    /// haystack that are arbitrarily far away from the current position.
+    #[inline]
+    pub fn supports_chunks(&self) -> bool {
+        !self.nfa.has_look_around()
+            && !self.nfa.has_backreference()
+            && !self.nfa.has_atomic()
+            && self.config.edits() == 0
+    }
 }
 
 impl PikeVM {
@@ -1226,10 +1566,13 @@ impl PikeVM {
         cache: &mut Cache,
         input: &Input<'_>,
         slots: &mut [Option<NonMaxUsize>],
-    ) -> Option<HalfMatch> {
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        if self.config.edits() > 0 {
+            return self.search_approx_imp(cache, input, slots);
+        }
         cache.setup_search(slots.len());
         if input.is_done() {
-            return None;
+            return Ok(None);
         }
         // Why do we even care about this? Well, in our 'Captures'
         // representation, we use usize::MAX as a sentinel to indicate "no
@@ -1248,13 +1591,21 @@ impl PikeVM {
         let allmatches =
             self.config.get_match_kind().continue_past_first_match();
         let (anchored, start_id) = match self.start_config(input) {
-            None => return None,
+            None => return Ok(None),
             Some(config) => config,
         };
 
         let pre =
             if anchored { None } else { self.get_config().get_prefilter() };
-        let Cache { ref mut stack, ref mut curr, ref mut next } = cache;
+        cache.look_around.setup_search(&self.nfa, input);
+        cache.atomic.setup_search(&self.nfa, input, &cache.look_around);
+        let Cache {
+            ref mut stack,
+            ref mut curr,
+            ref mut next,
+            ref look_around,
+            ref atomic,
+        } = cache;
         let mut hm = None;
         // Yes, our search doesn't end at input.end(), but includes it. This
         // is necessary because matches are delayed by one byte, just like
@@ -1269,6 +1620,11 @@ impl PikeVM {
         // match state.)
         let mut at = input.start();
         while at <= input.end() {
+            // Processing a single position is expensive enough that checking
+            // whether we've been cancelled at every one is cheap by comparison.
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
             // If we have no states left to visit, then there are some cases
             // where we know we can quit early or even skip ahead.
             if curr.set.is_empty() {
@@ -1361,10 +1717,29 @@ impl PikeVM {
                 // transitions, and thus must be able to write offsets to the
                 // slots given which are later copied to slot values in 'curr'.
                 let slots = next.slot_table.all_absent();
-                self.epsilon_closure(stack, slots, curr, input, at, start_id);
+                self.epsilon_closure(
+                    stack,
+                    look_around,
+                    atomic,
+                    slots,
+                    curr,
+                    input,
+                    at,
+                    0,
+                    start_id,
+                );
             }
-            if let Some(pid) = self.nexts(stack, curr, next, input, at, slots)
-            {
+            if let Some(pid) = self.nexts(
+                stack,
+                look_around,
+                atomic,
+                curr,
+                next,
+                input,
+                at,
+                0,
+                slots,
+            ) {
                 hm = Some(HalfMatch::new(pid, at));
             }
             // Unless the caller asked us to return early, we need to mush on
@@ -1379,6 +1754,216 @@ impl PikeVM {
             at += 1;
         }
         instrument!(|c| c.eprint(&self.nfa));
+        Ok(hm)
+    }
+
+    /// The implementation of reverse search.
+    ///
+    /// This is 'search_imp' with the haystack scanned from right to left,
+    /// and without prefilters or capturing groups. Just like in a forward
+    /// search, matches are delayed: a match state in 'curr' at position 'at'
+    /// means a match starts at 'at'.
+    fn search_rev_imp(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        cache.setup_search(0);
+        if input.is_done() {
+            return Ok(None);
+        }
+        instrument!(|c| c.reset(&self.nfa));
+
+        let allmatches =
+            self.config.get_match_kind().continue_past_first_match();
+        let (anchored, start_id) = match self.start_config(input) {
+            None => return Ok(None),
+            Some(config) => config,
+        };
+        cache.look_around.setup_search(&self.nfa, input);
+        cache.atomic.setup_search(&self.nfa, input, &cache.look_around);
+        let Cache {
+            ref mut stack,
+            ref mut curr,
+            ref mut next,
+            ref look_around,
+            ref atomic,
+        } = cache;
+        let mut hm = None;
+        let mut at = input.end();
+        loop {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
+            if curr.set.is_empty() {
+                if hm.is_some() && !allmatches {
+                    break;
+                }
+                if anchored && at < input.end() {
+                    break;
+                }
+            }
+            // See 'search_imp' for why we simulate the unanchored prefix
+            // like this.
+            if (hm.is_none() || allmatches) && (!anchored || at == input.end())
+            {
+                let slots = next.slot_table.all_absent();
+                self.epsilon_closure(
+                    stack,
+                    look_around,
+                    atomic,
+                    slots,
+                    curr,
+                    input,
+                    at,
+                    0,
+                    start_id,
+                );
+            }
+            // This is 'nexts', except each state consumes the byte
+            // immediately before 'at' and moves to 'at - 1'. No slots are
+            // written.
+            instrument!(|c| c.record_state_set(&curr.set));
+            let ActiveStates { ref set, ref mut slot_table } = *curr;
+            for sid in set.iter() {
+                instrument!(|c| c.record_step(sid));
+                let next_sid = match *self.nfa.state(sid) {
+                    State::Match { pattern_id } => {
+                        hm = Some(HalfMatch::new(pattern_id, at));
+                        if !allmatches {
+                            break;
+                        }
+                        continue;
+                    }
+                    // There are no more bytes to consume at the start of the
+                    // search, so the only thing left to do is look for
+                    // matches.
+                    _ if at <= input.start() => continue,
+                    State::ByteRange { ref trans } => {
+                        if !trans.matches(input.haystack(), at - 1) {
+                            continue;
+                        }
+                        trans.next
+                    }
+                    State::Sparse(ref sparse) => {
+                        match sparse.matches(input.haystack(), at - 1) {
+                            None => continue,
+                            Some(next_sid) => next_sid,
+                        }
+                    }
+                    State::Dense(ref dense) => {
+                        match dense.matches(input.haystack(), at - 1) {
+                            None => continue,
+                            Some(next_sid) => next_sid,
+                        }
+                    }
+                    _ => continue,
+                };
+                self.epsilon_closure(
+                    stack,
+                    look_around,
+                    atomic,
+                    slot_table.for_state(sid),
+                    next,
+                    input,
+                    at - 1,
+                    0,
+                    next_sid,
+                );
+            }
+            if (input.get_earliest() && hm.is_some()) || at <= input.start() {
+                break;
+            }
+            core::mem::swap(curr, next);
+            next.set.clear();
+            at -= 1;
+        }
+        instrument!(|c| c.eprint(&self.nfa));
+        Ok(hm)
+    }
+
+    /// The implementation of leftmost search over a haystack made up of
+    /// chunks.
+    ///
+    /// This is 'search_imp' without prefilters or cancellation, except that
+    /// positions are absolute offsets into 'chunks'. Each position is
+    /// processed using a contiguous window of the haystack around it, which
+    /// is usually one of the chunks. Positions close to a chunk boundary use
+    /// a small copy of the bytes around the boundary instead, so that
+    /// assertions like '\b' can see both sides of it. Since the NFA states
+    /// supported here never look further than that, the result is the same
+    /// as if the whole haystack had been searched at once.
+    fn search_chunks_imp(
+        &self,
+        cache: &mut Cache,
+        chunks: &Chunks<'_>,
+        start: usize,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Option<HalfMatch> {
+        cache.setup_search(slots.len());
+        instrument!(|c| c.reset(&self.nfa));
+
+        let allmatches =
+            self.config.get_match_kind().continue_past_first_match();
+        let anchored = self.nfa.is_always_start_anchored();
+        let start_id = self.nfa.start_anchored();
+        let Cache {
+            ref mut stack,
+            ref mut curr,
+            ref mut next,
+            ref look_around,
+            ref atomic,
+        } = cache;
+        let mut seam = Vec::new();
+        let mut hm = None;
+        let mut at = start;
+        'search: while at <= chunks.end() {
+            let window = chunks.window(at, &mut seam);
+            let input = Input::new(window.haystack);
+            let offset = window.offset;
+            while at <= window.last {
+                if curr.set.is_empty() {
+                    if hm.is_some() && !allmatches {
+                        break 'search;
+                    }
+                    if anchored && at > start {
+                        break 'search;
+                    }
+                }
+                if (!hm.is_some() || allmatches) && (!anchored || at == start)
+                {
+                    let slots = next.slot_table.all_absent();
+                    self.epsilon_closure(
+                        stack,
+                        look_around,
+                        atomic,
+                        slots,
+                        curr,
+                        &input,
+                        at - offset,
+                        offset,
+                        start_id,
+                    );
+                }
+                if let Some(pid) = self.nexts(
+                    stack,
+                    look_around,
+                    atomic,
+                    curr,
+                    next,
+                    &input,
+                    at - offset,
+                    offset,
+                    slots,
+                ) {
+                    hm = Some(HalfMatch::new(pid, at));
+                }
+                core::mem::swap(curr, next);
+                next.set.clear();
+                at += 1;
+            }
+        }
+        instrument!(|c| c.eprint(&self.nfa));
         hm
     }
 
@@ -1394,7 +1979,7 @@ impl PikeVM {
         cache: &mut Cache,
         input: &Input<'_>,
         patset: &mut PatternSet,
-    ) {
+    ) -> Result<(), MatchError> {
         // NOTE: This is effectively a copy of 'search_imp' above, but with no
         // captures support and instead writes patterns that matched directly
         // to 'patset'. See that routine for better commentary about what's
@@ -1408,9 +1993,12 @@ impl PikeVM {
         // and composition, so it seems like good sense to have the PikeVM
         // match that behavior.
 
+        if self.config.edits() > 0 {
+            return self.which_overlapping_approx_imp(cache, input, patset);
+        }
         cache.setup_search(0);
         if input.is_done() {
-            return;
+            return Ok(());
         }
         assert!(
             input.haystack().len() < core::usize::MAX,
@@ -1421,12 +2009,23 @@ impl PikeVM {
         let allmatches =
             self.config.get_match_kind().continue_past_first_match();
         let (anchored, start_id) = match self.start_config(input) {
-            None => return,
+            None => return Ok(()),
             Some(config) => config,
         };
 
-        let Cache { ref mut stack, ref mut curr, ref mut next } = cache;
+        cache.look_around.setup_search(&self.nfa, input);
+        cache.atomic.setup_search(&self.nfa, input, &cache.look_around);
+        let Cache {
+            ref mut stack,
+            ref mut curr,
+            ref mut next,
+            ref look_around,
+            ref atomic,
+        } = cache;
         for at in input.start()..=input.end() {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
             let any_matches = !patset.is_empty();
             if curr.set.is_empty() {
                 if any_matches && !allmatches {
@@ -1438,9 +2037,28 @@ impl PikeVM {
             }
             if !any_matches || allmatches {
                 let slots = &mut [];
-                self.epsilon_closure(stack, slots, curr, input, at, start_id);
+                self.epsilon_closure(
+                    stack,
+                    look_around,
+                    atomic,
+                    slots,
+                    curr,
+                    input,
+                    at,
+                    0,
+                    start_id,
+                );
             }
-            self.nexts_overlapping(stack, curr, next, input, at, patset);
+            self.nexts_overlapping(
+                stack,
+                look_around,
+                atomic,
+                curr,
+                next,
+                input,
+                at,
+                patset,
+            );
             // If we found a match and filled our set, then there is no more
             // additional info that we can provide. Thus, we can quit. We also
             // quit if the caller asked us to stop at the earliest point that
@@ -1452,6 +2070,7 @@ impl PikeVM {
             next.set.clear();
         }
         instrument!(|c| c.eprint(&self.nfa));
+        Ok(())
     }
 
     /// Process the active states in 'curr' to find the states (written to
@@ -1467,17 +2086,30 @@ impl PikeVM {
     fn nexts(
         &self,
         stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        atomic: &atomic::Cache,
         curr: &mut ActiveStates,
         next: &mut ActiveStates,
         input: &Input<'_>,
         at: usize,
+        offset: usize,
         slots: &mut [Option<NonMaxUsize>],
     ) -> Option<PatternID> {
         instrument!(|c| c.record_state_set(&curr.set));
         let mut pid = None;
         let ActiveStates { ref set, ref mut slot_table } = *curr;
         for sid in set.iter() {
-            pid = match self.next(stack, slot_table, next, input, at, sid) {
+            pid = match self.next(
+                stack,
+                look_around,
+                atomic,
+                slot_table,
+                next,
+                input,
+                at,
+                offset,
+                sid,
+            ) {
                 None => continue,
                 Some(pid) => Some(pid),
             };
@@ -1495,6 +2127,8 @@ impl PikeVM {
     fn nexts_overlapping(
         &self,
         stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        atomic: &atomic::Cache,
         curr: &mut ActiveStates,
         next: &mut ActiveStates,
         input: &Input<'_>,
@@ -1505,8 +2139,17 @@ impl PikeVM {
         let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
         let ActiveStates { ref set, ref mut slot_table } = *curr;
         for sid in set.iter() {
-            let pid = match self.next(stack, slot_table, next, input, at, sid)
-            {
+            let pid = match self.next(
+                stack,
+                look_around,
+                atomic,
+                slot_table,
+                next,
+                input,
+                at,
+                0,
+                sid,
+            ) {
                 None => continue,
                 Some(pid) => pid,
             };
@@ -1541,16 +2184,23 @@ impl PikeVM {
     fn next(
         &self,
         stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        atomic: &atomic::Cache,
         curr_slot_table: &mut SlotTable,
         next: &mut ActiveStates,
         input: &Input<'_>,
         at: usize,
+        offset: usize,
         sid: StateID,
     ) -> Option<PatternID> {
         instrument!(|c| c.record_step(sid));
         match *self.nfa.state(sid) {
             State::Fail
             | State::Look { .. }
+            | State::LookAround { .. }
+            | State::Backreference { .. }
+            | State::AtomicStart { .. }
+            | State::AtomicEnd { .. }
             | State::Union { .. }
             | State::BinaryUnion { .. }
             | State::Capture { .. } => None,
@@ -1561,7 +2211,15 @@ impl PikeVM {
                     // adding 1 will never wrap.
                     let at = at.wrapping_add(1);
                     self.epsilon_closure(
-                        stack, slots, next, input, at, trans.next,
+                        stack,
+                        look_around,
+                        atomic,
+                        slots,
+                        next,
+                        input,
+                        at,
+                        offset,
+                        trans.next,
                     );
                 }
                 None
@@ -1573,7 +2231,15 @@ impl PikeVM {
                     // adding 1 will never wrap.
                     let at = at.wrapping_add(1);
                     self.epsilon_closure(
-                        stack, slots, next, input, at, next_sid,
+                        stack,
+                        look_around,
+                        atomic,
+                        slots,
+                        next,
+                        input,
+                        at,
+                        offset,
+                        next_sid,
                     );
                 }
                 None
@@ -1585,7 +2251,15 @@ impl PikeVM {
                     // adding 1 will never wrap.
                     let at = at.wrapping_add(1);
                     self.epsilon_closure(
-                        stack, slots, next, input, at, next_sid,
+                        stack,
+                        look_around,
+                        atomic,
+                        slots,
+                        next,
+                        input,
+                        at,
+                        offset,
+                        next_sid,
                     );
                 }
                 None
@@ -1611,10 +2285,13 @@ impl PikeVM {
     fn epsilon_closure(
         &self,
         stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        atomic: &atomic::Cache,
         curr_slots: &mut [Option<NonMaxUsize>],
         next: &mut ActiveStates,
         input: &Input<'_>,
         at: usize,
+        offset: usize,
         sid: StateID,
     ) {
         instrument!(|c| {
@@ -1629,7 +2306,15 @@ impl PikeVM {
                 }
                 FollowEpsilon::Explore(sid) => {
                     self.epsilon_closure_explore(
-                        stack, curr_slots, next, input, at, sid,
+                        stack,
+                        look_around,
+                        atomic,
+                        curr_slots,
+                        next,
+                        input,
+                        at,
+                        offset,
+                        sid,
                     );
                 }
             }
@@ -1660,14 +2345,21 @@ impl PikeVM {
     /// conditional epsilon transitions (like look-around) are satisfied at
     /// the current position. If they aren't, then the epsilon closure won't
     /// include them.
+    ///
+    /// 'offset' is added to 'at' whenever it is recorded in a slot. It is
+    /// only non-zero when 'input' is a window into a bigger haystack, as is
+    /// the case when searching chunks.
     #[cfg_attr(feature = "perf-inline", inline(always))]
     fn epsilon_closure_explore(
         &self,
         stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        atomic: &atomic::Cache,
         curr_slots: &mut [Option<NonMaxUsize>],
         next: &mut ActiveStates,
         input: &Input<'_>,
         at: usize,
+        offset: usize,
         mut sid: StateID,
     ) {
         // We can avoid pushing some state IDs on to our stack in precisely
@@ -1688,11 +2380,21 @@ impl PikeVM {
                 | State::Match { .. }
                 | State::ByteRange { .. }
                 | State::Sparse { .. }
-                | State::Dense { .. } => {
+                | State::Dense { .. }
+                | State::Backreference { .. } => {
                     next.slot_table.for_state(sid).copy_from_slice(curr_slots);
                     return;
                 }
                 State::Look { look, next } => {
+                    // A reverse NFA has its assertions reversed so that they
+                    // can be evaluated on a reversed haystack. Since we always
+                    // evaluate assertions on the original haystack, we undo
+                    // that here.
+                    let look = if self.nfa.is_reverse() {
+                        look.reversed()
+                    } else {
+                        look
+                    };
                     // OK because we don't permit building a searcher with a
                     // Unicode word boundary if the requisite Unicode data is
                     // unavailable.
@@ -1705,6 +2407,27 @@ impl PikeVM {
                     }
                     sid = next;
                 }
+                State::LookAround { index, next } => {
+                    if !look_around.holds(index, at) {
+                        return;
+                    }
+                    sid = next;
+                }
+                State::AtomicStart { next, .. }
+                | State::AtomicEnd { next, .. } => {
+                    sid = next;
+                }
+                // A union inside of an atomic group only ever follows the
+                // alternative that the group commits to at this position.
+                State::Union { .. } | State::BinaryUnion { .. }
+                    if self.nfa.has_atomic()
+                        && self.nfa.atomic_union(sid).is_some() =>
+                {
+                    sid = match atomic.choice(&self.nfa, sid, at) {
+                        None => return,
+                        Some(sid) => sid,
+                    };
+                }
                 State::Union { ref alternates } => {
                     sid = match alternates.get(0) {
                         None => return,
@@ -1739,8 +2462,11 @@ impl PikeVM {
                             slot,
                             offset: curr_slots[slot],
                         });
-                        // OK because length of a slice must fit into an isize.
-                        curr_slots[slot] = Some(NonMaxUsize::new(at).unwrap());
+                        // OK because length of a slice must fit into an isize,
+                        // and the end of a chunked haystack is checked to be
+                        // representable when it's built.
+                        curr_slots[slot] =
+                            Some(NonMaxUsize::new(offset + at).unwrap());
                     }
                     sid = next;
                 }
@@ -1784,6 +2510,553 @@ impl PikeVM {
     }
 }
 
+/// The implementation of approximate matching.
+///
+/// This is kept separate from the search routines above so that exact
+/// matching, which is by far the common case, doesn't pay for any of the
+/// book-keeping needed here.
+///
+/// Approximate matching works by simulating the product of the NFA with the
+/// edit costs `0..=k`, where each (state, cost) pair is tracked as its own
+/// "state" in the sets of active states. (See 'approx_key'.) On top of the
+/// usual NFA transitions, every state with byte transitions gets three kinds
+/// of edit transitions, each of which increases the cost by one:
+///
+/// * A substitution consumes any byte and moves to any of the state's
+/// transition targets.
+/// * An insertion consumes any byte and stays in the same state.
+/// * A deletion moves to any of the state's transition targets without
+/// consuming a byte. Since no input is consumed, it is followed during
+/// epsilon closure just like an unconditional epsilon transition.
+///
+/// Since the number of distinct active states is bounded by 'nstates *
+/// (k+1)', a search is still linear in the size of the haystack for a fixed
+/// 'k'.
+///
+/// Edit transitions are always followed after the exact transitions out of
+/// the same state, so they have a lower priority. Otherwise, match
+/// priority works exactly as it does for exact searches: once a match is
+/// found, states with a lower priority are dropped and no new search is
+/// started. In particular, this means a search never continues past the end
+/// of a match just to look for one with fewer edits, which would make
+/// iterating over all matches quadratic.
+impl PikeVM {
+    /// The approximate analog of 'search_imp'. See that routine for
+    /// commentary about the overall structure of a search.
+    fn search_approx_imp(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Result<Option<HalfMatch>, MatchError> {
+        cache.setup_search(slots.len());
+        if input.is_done() {
+            return Ok(None);
+        }
+        assert!(
+            input.haystack().len() < core::usize::MAX,
+            "byte slice lengths must be less than usize MAX",
+        );
+
+        let allmatches =
+            self.config.get_match_kind().continue_past_first_match();
+        let (anchored, start_id) = match self.start_config(input) {
+            None => return Ok(None),
+            Some(config) => config,
+        };
+
+        cache.look_around.setup_search(&self.nfa, input);
+        let Cache {
+            ref mut stack,
+            ref mut curr,
+            ref mut next,
+            ref look_around,
+            atomic: _,
+        } = cache;
+        let limit = self.config.edits();
+        let mut hm = None;
+        let mut at = input.start();
+        while at <= input.end() {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
+            if curr.set.is_empty() {
+                if hm.is_some() && !allmatches {
+                    break;
+                }
+                if anchored && at > input.start() {
+                    break;
+                }
+            }
+            if (hm.is_none() || allmatches)
+                && (!anchored || at == input.start())
+            {
+                let slots = next.slot_table.all_absent();
+                self.epsilon_closure_approx(
+                    stack,
+                    look_around,
+                    slots,
+                    curr,
+                    input,
+                    at,
+                    self.approx_key(start_id, 0),
+                    limit,
+                );
+            }
+            if let Some(pid) = self.nexts_approx(
+                stack,
+                look_around,
+                curr,
+                next,
+                input,
+                at,
+                limit,
+                slots,
+            ) {
+                hm = Some(HalfMatch::new(pid, at));
+            }
+            if input.get_earliest() && hm.is_some() {
+                break;
+            }
+            core::mem::swap(curr, next);
+            next.set.clear();
+            at += 1;
+        }
+        Ok(hm)
+    }
+
+    /// The approximate analog of 'which_overlapping_imp'.
+    ///
+    /// Since this only reports which patterns match within the edit budget,
+    /// every active state is permitted to use the entire budget.
+    fn which_overlapping_approx_imp(
+        &self,
+        cache: &mut Cache,
+        input: &Input<'_>,
+        patset: &mut PatternSet,
+    ) -> Result<(), MatchError> {
+        cache.setup_search(0);
+        if input.is_done() {
+            return Ok(());
+        }
+        assert!(
+            input.haystack().len() < core::usize::MAX,
+            "byte slice lengths must be less than usize MAX",
+        );
+
+        let allmatches =
+            self.config.get_match_kind().continue_past_first_match();
+        let (anchored, start_id) = match self.start_config(input) {
+            None => return Ok(()),
+            Some(config) => config,
+        };
+
+        cache.look_around.setup_search(&self.nfa, input);
+        let Cache {
+            ref mut stack,
+            ref mut curr,
+            ref mut next,
+            ref look_around,
+            atomic: _,
+        } = cache;
+        let limit = self.config.edits();
+        for at in input.start()..=input.end() {
+            if input.is_cancelled() {
+                return Err(MatchError::cancelled(at));
+            }
+            let any_matches = !patset.is_empty();
+            if curr.set.is_empty() {
+                if any_matches && !allmatches {
+                    break;
+                }
+                if anchored && at > input.start() {
+                    break;
+                }
+            }
+            if (!any_matches || allmatches)
+                && (!anchored || at == input.start())
+            {
+                let slots = &mut [];
+                self.epsilon_closure_approx(
+                    stack,
+                    look_around,
+                    slots,
+                    curr,
+                    input,
+                    at,
+                    self.approx_key(start_id, 0),
+                    limit,
+                );
+            }
+            self.nexts_overlapping_approx(
+                stack,
+                look_around,
+                curr,
+                next,
+                input,
+                at,
+                limit,
+                patset,
+            );
+            if patset.is_full() || input.get_earliest() {
+                break;
+            }
+            core::mem::swap(curr, next);
+            next.set.clear();
+        }
+        Ok(())
+    }
+
+    /// The approximate analog of 'nexts'.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn nexts_approx(
+        &self,
+        stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        curr: &mut ActiveStates,
+        next: &mut ActiveStates,
+        input: &Input<'_>,
+        at: usize,
+        limit: usize,
+        slots: &mut [Option<NonMaxUsize>],
+    ) -> Option<PatternID> {
+        let mut pid = None;
+        let ActiveStates { ref set, ref mut slot_table } = *curr;
+        for key in set.iter() {
+            pid = match self.next_approx(
+                stack,
+                look_around,
+                slot_table,
+                next,
+                input,
+                at,
+                key,
+                limit,
+            ) {
+                None => continue,
+                Some(pid) => Some(pid),
+            };
+            slots.copy_from_slice(slot_table.for_state(key));
+            if !self.config.get_match_kind().continue_past_first_match() {
+                break;
+            }
+        }
+        pid
+    }
+
+    /// The approximate analog of 'nexts_overlapping'.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn nexts_overlapping_approx(
+        &self,
+        stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        curr: &mut ActiveStates,
+        next: &mut ActiveStates,
+        input: &Input<'_>,
+        at: usize,
+        limit: usize,
+        patset: &mut PatternSet,
+    ) {
+        let utf8empty = self.get_nfa().has_empty() && self.get_nfa().is_utf8();
+        let ActiveStates { ref set, ref mut slot_table } = *curr;
+        for key in set.iter() {
+            let pid = match self.next_approx(
+                stack,
+                look_around,
+                slot_table,
+                next,
+                input,
+                at,
+                key,
+                limit,
+            ) {
+                None => continue,
+                Some(pid) => pid,
+            };
+            if utf8empty && !input.is_char_boundary(at) {
+                continue;
+            }
+            let _ = patset.try_insert(pid);
+            if !self.config.get_match_kind().continue_past_first_match() {
+                break;
+            }
+        }
+    }
+
+    /// The approximate analog of 'next'.
+    ///
+    /// In addition to following the transition for the byte at 'at' (if one
+    /// exists), this also follows substitutions and insertions out of the
+    /// state identified by 'key', so long as doing so doesn't exceed 'limit'.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn next_approx(
+        &self,
+        stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        curr_slot_table: &mut SlotTable,
+        next: &mut ActiveStates,
+        input: &Input<'_>,
+        at: usize,
+        key: StateID,
+        limit: usize,
+    ) -> Option<PatternID> {
+        let (sid, cost) = self.approx_unkey(key);
+        let exact = match *self.nfa.state(sid) {
+            State::Fail
+            | State::Look { .. }
+            | State::LookAround { .. }
+            | State::Backreference { .. }
+            | State::AtomicStart { .. }
+            | State::AtomicEnd { .. }
+            | State::Union { .. }
+            | State::BinaryUnion { .. }
+            | State::Capture { .. } => return None,
+            State::ByteRange { ref trans } => {
+                if trans.matches(input.haystack(), at) {
+                    Some(trans.next)
+                } else {
+                    None
+                }
+            }
+            State::Sparse(ref sparse) => sparse.matches(input.haystack(), at),
+            State::Dense(ref dense) => dense.matches(input.haystack(), at),
+            State::Match { pattern_id } => return Some(pattern_id),
+        };
+        // OK because 'at <= haystack.len() < usize::MAX', so adding 1 will
+        // never wrap.
+        let next_at = at.wrapping_add(1);
+        if let Some(next_sid) = exact {
+            self.epsilon_closure_approx(
+                stack,
+                look_around,
+                curr_slot_table.for_state(key),
+                next,
+                input,
+                next_at,
+                self.approx_key(next_sid, cost),
+                limit,
+            );
+        }
+        if cost < limit && at < input.end() {
+            self.for_each_edit_target(sid, |next_sid| {
+                self.epsilon_closure_approx(
+                    stack,
+                    look_around,
+                    curr_slot_table.for_state(key),
+                    next,
+                    input,
+                    next_at,
+                    self.approx_key(next_sid, cost + 1),
+                    limit,
+                );
+            });
+            self.epsilon_closure_approx(
+                stack,
+                look_around,
+                curr_slot_table.for_state(key),
+                next,
+                input,
+                next_at,
+                self.approx_key(sid, cost + 1),
+                limit,
+            );
+        }
+        None
+    }
+
+    /// The approximate analog of 'epsilon_closure'.
+    ///
+    /// The closure starts at the state identified by 'key' (which combines
+    /// an NFA state with a cost) and additionally follows deletions, so long
+    /// as doing so doesn't exceed 'limit'.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn epsilon_closure_approx(
+        &self,
+        stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        curr_slots: &mut [Option<NonMaxUsize>],
+        next: &mut ActiveStates,
+        input: &Input<'_>,
+        at: usize,
+        key: StateID,
+        limit: usize,
+    ) {
+        // Every state ID pushed on to the stack here is a key produced by
+        // 'approx_key' and not a plain NFA state ID.
+        stack.push(FollowEpsilon::Explore(key));
+        while let Some(frame) = stack.pop() {
+            match frame {
+                FollowEpsilon::RestoreCapture { slot, offset: pos } => {
+                    curr_slots[slot] = pos;
+                }
+                FollowEpsilon::Explore(key) => {
+                    self.epsilon_closure_explore_approx(
+                        stack,
+                        look_around,
+                        curr_slots,
+                        next,
+                        input,
+                        at,
+                        key,
+                        limit,
+                    );
+                }
+            }
+        }
+    }
+
+    /// The approximate analog of 'epsilon_closure_explore'.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn epsilon_closure_explore_approx(
+        &self,
+        stack: &mut Vec<FollowEpsilon>,
+        look_around: &lookaround::Cache,
+        curr_slots: &mut [Option<NonMaxUsize>],
+        next: &mut ActiveStates,
+        input: &Input<'_>,
+        at: usize,
+        mut key: StateID,
+        limit: usize,
+    ) {
+        loop {
+            if !self.insert_approx(&mut next.set, key) {
+                return;
+            }
+            let (sid, cost) = self.approx_unkey(key);
+            match *self.nfa.state(sid) {
+                // NFAs with backreferences are rejected when building a
+                // PikeVM, so they are treated just like fail states here.
+                State::Fail
+                | State::Match { .. }
+                | State::Backreference { .. } => {
+                    next.slot_table.for_state(key).copy_from_slice(curr_slots);
+                    return;
+                }
+                State::ByteRange { .. }
+                | State::Sparse { .. }
+                | State::Dense { .. } => {
+                    next.slot_table.for_state(key).copy_from_slice(curr_slots);
+                    if cost < limit {
+                        self.for_each_edit_target(sid, |next_sid| {
+                            let key = self.approx_key(next_sid, cost + 1);
+                            stack.push(FollowEpsilon::Explore(key));
+                        });
+                    }
+                    return;
+                }
+                State::Look { look, next } => {
+                    if !self.nfa.look_matcher().matches_inline(
+                        look,
+                        input.haystack(),
+                        at,
+                    ) {
+                        return;
+                    }
+                    key = self.approx_key(next, cost);
+                }
+                State::LookAround { index, next } => {
+                    if !look_around.holds(index, at) {
+                        return;
+                    }
+                    key = self.approx_key(next, cost);
+                }
+                // NFAs with atomic groups are rejected when building a PikeVM
+                // for approximate matching, so these are never reached.
+                State::AtomicStart { next, .. }
+                | State::AtomicEnd { next, .. } => {
+                    key = self.approx_key(next, cost);
+                }
+                State::Union { ref alternates } => {
+                    key = match alternates.get(0) {
+                        None => return,
+                        Some(&sid) => self.approx_key(sid, cost),
+                    };
+                    stack.extend(alternates[1..].iter().rev().map(|&sid| {
+                        FollowEpsilon::Explore(self.approx_key(sid, cost))
+                    }));
+                }
+                State::BinaryUnion { alt1, alt2 } => {
+                    key = self.approx_key(alt1, cost);
+                    let alt2 = self.approx_key(alt2, cost);
+                    stack.push(FollowEpsilon::Explore(alt2));
+                }
+                State::Capture { next, slot, .. } => {
+                    if slot.as_usize() < curr_slots.len() {
+                        stack.push(FollowEpsilon::RestoreCapture {
+                            slot,
+                            offset: curr_slots[slot],
+                        });
+                        // OK because length of a slice must fit into an isize.
+                        curr_slots[slot] = Some(NonMaxUsize::new(at).unwrap());
+                    }
+                    key = self.approx_key(next, cost);
+                }
+            }
+        }
+    }
+
+    /// Insert the given key into the given set of active states.
+    ///
+    /// This returns false if the key is already in the set, or if the same
+    /// NFA state is already in the set with a lower cost. In the latter case,
+    /// the state already in the set has a higher priority and can do
+    /// everything the new one can for fewer edits, so there is no point in
+    /// tracking both.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn insert_approx(&self, set: &mut SparseSet, key: StateID) -> bool {
+        let (sid, cost) = self.approx_unkey(key);
+        if (0..cost).any(|c| set.contains(self.approx_key(sid, c))) {
+            return false;
+        }
+        set.insert(key)
+    }
+
+    /// Call 'f' with every state reachable from 'sid' via a byte transition.
+    /// These are the targets of substitutions and deletions. Nothing happens
+    /// for states without byte transitions.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn for_each_edit_target(&self, sid: StateID, mut f: impl FnMut(StateID)) {
+        match *self.nfa.state(sid) {
+            State::ByteRange { ref trans } => f(trans.next),
+            State::Sparse(ref sparse) => {
+                for t in sparse.transitions.iter() {
+                    f(t.next);
+                }
+            }
+            State::Dense(ref dense) => {
+                // Dense transitions typically repeat the same target for
+                // long runs of bytes, so skip over the repeats.
+                let mut prev = StateID::ZERO;
+                for &next_sid in dense.transitions.iter() {
+                    if next_sid != StateID::ZERO && next_sid != prev {
+                        f(next_sid);
+                    }
+                    prev = next_sid;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Return the key used to track the given NFA state at the given edit
+    /// cost in the sets of active states and their slot tables.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn approx_key(&self, sid: StateID, cost: usize) -> StateID {
+        let costs = self.config.edits() + 1;
+        // OK because the builder guarantees that 'nstates * costs' never
+        // exceeds the number of available state IDs.
+        StateID::new_unchecked(sid.as_usize() * costs + cost)
+    }
+
+    /// The inverse of 'approx_key'.
+    #[cfg_attr(feature = "perf-inline", inline(always))]
+    fn approx_unkey(&self, key: StateID) -> (StateID, usize) {
+        let costs = self.config.edits() + 1;
+        let sid = StateID::new_unchecked(key.as_usize() / costs);
+        (sid, key.as_usize() % costs)
+    }
+}
+
 /// An iterator over all non-overlapping matches for a particular search.
 ///
 /// The iterator yields a [`Match`] value until no more matches could be found.
@@ -1886,6 +3159,13 @@ pub struct Cache {
     /// The next set of states we're building that will be explored for the
     /// next byte in the haystack.
     next: ActiveStates,
+    /// Where each look-around assertion with a sub-expression holds for the
+    /// current search. This is empty when the NFA has no such assertions.
+    look_around: lookaround::Cache,
+    /// The alternative that each union state inside of an atomic group
+    /// commits to for the current search. This is empty when the NFA has no
+    /// atomic groups.
+    atomic: atomic::Cache,
 }
 
 impl Cache {
@@ -1902,6 +3182,8 @@ impl Cache {
             stack: vec![],
             curr: ActiveStates::new(re),
             next: ActiveStates::new(re),
+            look_around: lookaround::Cache::new(re.get_nfa()),
+            atomic: atomic::Cache::new(re.get_nfa()),
         }
     }
 
@@ -1945,6 +3227,8 @@ impl Cache {
     pub fn reset(&mut self, re: &PikeVM) {
         self.curr.reset(re);
         self.next.reset(re);
+        self.look_around.reset(re.get_nfa());
+        self.atomic.reset(re.get_nfa());
     }
 
     /// Returns the heap memory usage, in bytes, of this cache.
@@ -1956,6 +3240,8 @@ impl Cache {
         (self.stack.len() * size_of::<FollowEpsilon>())
             + self.curr.memory_usage()
             + self.next.memory_usage()
+            + self.look_around.memory_usage()
+            + self.atomic.memory_usage()
     }
 
     /// Clears this cache. This should be called at the start of every search
@@ -2020,7 +3306,11 @@ impl ActiveStates {
     /// Reset this set of active states such that it can be used with the given
     /// PikeVM (and only that PikeVM).
     fn reset(&mut self, re: &PikeVM) {
-        self.set.resize(re.get_nfa().states().len());
+        // When approximate matching is enabled, every NFA state is tracked
+        // once for each possible edit cost. The builder guarantees that this
+        // doesn't exceed the number of available state identifiers.
+        let costs = re.get_config().edits() + 1;
+        self.set.resize(re.get_nfa().states().len() * costs);
         self.slot_table.reset(re);
     }
 
@@ -2098,7 +3388,9 @@ impl SlotTable {
         let len = nfa
             .states()
             .len()
-            .checked_mul(self.slots_per_state)
+            // Approximate matching needs a row for each edit cost.
+            .checked_mul(re.get_config().edits() + 1)
+            .and_then(|x| x.checked_mul(self.slots_per_state))
             // Add space to account for scratch space used during a search.
             .and_then(|x| x.checked_add(self.slots_for_captures))
             // It seems like this could actually panic on legitimate inputs on
@@ -2141,6 +3433,10 @@ impl SlotTable {
 
     /// Return a mutable slice of the slots for the given state.
     ///
+    /// When approximate matching is enabled, the "state" given here is a
+    /// key combining an NFA state with an edit cost. (See
+    /// 'PikeVM::approx_key'.)
+    ///
     /// Note that the length of the slice returned may be less than the total
     /// number of slots available for this state. In particular, the length
     /// always matches the number of slots indicated via 'setup_search'.
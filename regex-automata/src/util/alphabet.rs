@@ -716,6 +716,18 @@ impl ByteClassSet {
         }
     }
 
+    /// Add the boundaries between the equivalence classes in the map given
+    /// to this byte class set. That is, any two bytes that are in distinct
+    /// classes in `classes` are guaranteed to be in distinct classes in the
+    /// map produced by this set.
+    pub(crate) fn add_classes(&mut self, classes: &ByteClasses) {
+        for b in 0..=254u8 {
+            if classes.get(b) != classes.get(b + 1) {
+                self.0.add(b);
+            }
+        }
+    }
+
     /// Convert this boolean set to a map that maps all byte values to their
     /// corresponding equivalence class. The last mapping indicates the largest
     /// equivalence class identifier (which is never bigger than 255).
@@ -814,7 +826,8 @@ impl ByteSet {
         let mut nread = 0;
         let (low, nr) = wire::try_read_u128(slice, "byte set low bucket")?;
         nread += nr;
-        let (high, nr) = wire::try_read_u128(slice, "byte set high bucket")?;
+        let (high, nr) =
+            wire::try_read_u128(&slice[nread..], "byte set high bucket")?;
         nread += nr;
         Ok((ByteSet { bits: BitSet([low, high]) }, nread))
     }
@@ -1136,4 +1149,18 @@ mod tests {
         let expected = vec![Unit::u8(b'z'), Unit::u8(b'\x7B')];
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn byte_set_roundtrip() {
+        let mut set = ByteSet::empty();
+        set.add(b'a');
+        set.add(0x80);
+        set.add(0xFF);
+
+        let mut buf = vec![0; set.write_to_len()];
+        let nwrite = set.write_to::<wire::LE>(&mut buf).unwrap();
+        let (got, nread) = ByteSet::from_bytes(&buf).unwrap();
+        assert_eq!(nwrite, nread);
+        assert_eq!(set, got);
+    }
 }
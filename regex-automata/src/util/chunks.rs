@@ -0,0 +1,270 @@
+/*!
+Provides a haystack type for searching text that isn't stored contiguously.
+
+Text editors and other programs that deal with large, frequently edited
+buffers often store their text in a rope or a piece table. Such a buffer can
+be viewed as a sequence of byte chunks, each with its absolute offset in the
+buffer. The [`Chunks`] type describes a haystack in exactly those terms, so
+that it can be searched without first copying it into a single contiguous
+`Vec<u8>`.
+
+Only some regex engines can search a [`Chunks`] haystack directly. See
+[`meta::Regex::search_chunks`](crate::meta::Regex::search_chunks) for the
+highest level API and
+[`PikeVM::search_chunks`](crate::nfa::thompson::pikevm::PikeVM::search_chunks)
+for the lowest level one.
+*/
+
+use alloc::vec::Vec;
+
+use crate::util::primitives::NonMaxUsize;
+
+/// The number of bytes on either side of a position that may be inspected
+/// when checking whether a look-around assertion is satisfied there.
+///
+/// The assertions that look the furthest are the Unicode word boundaries,
+/// which decode one codepoint in each direction. A codepoint is never more
+/// than 4 bytes long in UTF-8.
+pub(crate) const LOOK_CONTEXT: usize = 4;
+
+/// A haystack made up of a sequence of byte chunks.
+///
+/// Each chunk is given along with its absolute offset. The first chunk's
+/// offset is the start of the haystack and each subsequent chunk must start
+/// precisely where the previous one ended. All match offsets reported for
+/// searches of a `Chunks` haystack are absolute offsets.
+///
+/// The start of the haystack is treated like the start of any other
+/// haystack. For example, `^` matches there even if the chunks were taken
+/// from the middle of a larger buffer.
+///
+/// Building a `Chunks` value only collects the slices given and never copies
+/// the bytes in them.
+///
+/// # Example
+///
+/// ```
+/// use regex_automata::util::chunks::Chunks;
+///
+/// let chunks = Chunks::new([(10, &b"foo"[..]), (13, b""), (13, b"barbaz")]);
+/// assert_eq!(10, chunks.start());
+/// assert_eq!(19, chunks.end());
+/// assert_eq!(Some(b'b'), chunks.get(13));
+/// assert_eq!(None, chunks.get(19));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chunks<'h> {
+    /// The non-empty chunks, in order, along with their absolute offsets.
+    chunks: Vec<(usize, &'h [u8])>,
+    start: usize,
+    end: usize,
+}
+
+impl<'h> Chunks<'h> {
+    /// Create a new haystack from the given chunks and their absolute
+    /// offsets.
+    ///
+    /// Empty chunks are permitted and are ignored. If no chunks are given,
+    /// then the haystack is empty and starts at offset `0`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the offset of a chunk isn't equal to the offset of the
+    /// previous chunk plus its length, or if the end of a chunk would
+    /// overflow a `usize`.
+    pub fn new<I>(chunks: I) -> Chunks<'h>
+    where
+        I: IntoIterator<Item = (usize, &'h [u8])>,
+    {
+        let mut it = chunks.into_iter();
+        let (start, first) = match it.next() {
+            None => return Chunks { chunks: Vec::new(), start: 0, end: 0 },
+            Some(chunk) => chunk,
+        };
+        let mut haystack = Chunks { chunks: Vec::new(), start, end: start };
+        haystack.push(start, first);
+        for (offset, bytes) in it {
+            haystack.push(offset, bytes);
+        }
+        haystack
+    }
+
+    fn push(&mut self, offset: usize, bytes: &'h [u8]) {
+        assert_eq!(
+            self.end, offset,
+            "chunk offset {} does not follow the previous chunk, \
+             which ends at {}",
+            offset, self.end,
+        );
+        self.end = offset
+            .checked_add(bytes.len())
+            .expect("chunk end offset overflows usize");
+        // Every offset in the haystack must be representable by the slots
+        // used by the regex engines.
+        assert!(
+            NonMaxUsize::new(self.end).is_some(),
+            "haystack end offset must be less than usize MAX",
+        );
+        if !bytes.is_empty() {
+            self.chunks.push((offset, bytes));
+        }
+    }
+
+    /// Returns the absolute offset at which this haystack starts.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the absolute offset at which this haystack ends.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the total number of bytes in this haystack.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if and only if this haystack contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the byte at the given absolute offset, if it's in this
+    /// haystack.
+    #[inline]
+    pub fn get(&self, at: usize) -> Option<u8> {
+        if at < self.start || at >= self.end {
+            return None;
+        }
+        let (offset, bytes) = self.chunks[self.index(at)];
+        Some(bytes[at - offset])
+    }
+
+    /// Returns an iterator over the non-empty chunks in this haystack, along
+    /// with their absolute offsets.
+    #[inline]
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (usize, &'h [u8])> + '_ {
+        self.chunks.iter().copied()
+    }
+
+    /// Returns true if and only if the given absolute offset doesn't split
+    /// the UTF-8 encoding of a codepoint.
+    ///
+    /// Like [`Input::is_char_boundary`](crate::Input::is_char_boundary), this
+    /// doesn't check that the haystack is valid UTF-8.
+    #[inline]
+    pub(crate) fn is_char_boundary(&self, at: usize) -> bool {
+        match self.get(at) {
+            None => at == self.end,
+            Some(b) => b <= 0b0111_1111 || b >= 0b1100_0000,
+        }
+    }
+
+    /// Returns the index of the chunk containing the given absolute offset.
+    /// If the offset is the end of the haystack, then the index of the last
+    /// chunk is returned.
+    ///
+    /// This panics if there are no chunks.
+    #[inline]
+    fn index(&self, at: usize) -> usize {
+        self.chunks.partition_point(|&(offset, _)| offset <= at).max(1) - 1
+    }
+
+    /// Returns the parts of the chunks that cover the given range of absolute
+    /// offsets, in order. Each part is paired with its absolute offset.
+    ///
+    /// This panics if the range isn't within the bounds of this haystack.
+    pub(crate) fn range(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> impl DoubleEndedIterator<Item = (usize, &'h [u8])> + '_ {
+        assert!(self.start <= start && start <= end && end <= self.end);
+        let chunks = if start == end {
+            &self.chunks[..0]
+        } else {
+            &self.chunks[self.index(start)..=self.index(end - 1)]
+        };
+        chunks.iter().map(move |&(offset, bytes)| {
+            let lo = start.saturating_sub(offset);
+            let hi = core::cmp::min(bytes.len(), end - offset);
+            (offset + lo, &bytes[lo..hi])
+        })
+    }
+
+    /// Copies the bytes in the given range of absolute offsets to the end of
+    /// `buf`.
+    ///
+    /// This panics if the range isn't within the bounds of this haystack.
+    pub(crate) fn copy_range(
+        &self,
+        start: usize,
+        end: usize,
+        buf: &mut Vec<u8>,
+    ) {
+        for (_, bytes) in self.range(start, end) {
+            buf.extend_from_slice(bytes);
+        }
+    }
+
+    /// Returns a contiguous window of this haystack that can be used in place
+    /// of the entire haystack to search at the absolute offset `at`,
+    /// including checking any look-around assertions there.
+    ///
+    /// The window returned is either a slice of one of the chunks or, when
+    /// `at` is too close to a chunk boundary, a copy of the bytes around `at`
+    /// written to `seam`. The window starts at the absolute offset
+    /// `window.offset` and it can be used for every position from `at` up to
+    /// and including `window.last`, which is always at least `at`.
+    ///
+    /// This panics if `at` isn't within the bounds of this haystack.
+    pub(crate) fn window<'a>(
+        &'a self,
+        at: usize,
+        seam: &'a mut Vec<u8>,
+    ) -> Window<'a> {
+        assert!(self.start <= at && at <= self.end);
+        if self.chunks.is_empty() {
+            return Window { haystack: &[], offset: at, last: at };
+        }
+        let (offset, bytes) = self.chunks[self.index(at)];
+        let end = offset + bytes.len();
+        // The chunk can be used for every position with enough context on
+        // both sides, where the edges of the haystack count as enough.
+        let behind = at - offset >= LOOK_CONTEXT || offset == self.start;
+        let last = if end == self.end {
+            Some(end)
+        } else {
+            end.checked_sub(LOOK_CONTEXT)
+        };
+        if let Some(last) = last.filter(|&last| behind && at <= last) {
+            return Window { haystack: bytes, offset, last };
+        }
+        let lo = core::cmp::max(self.start, at.saturating_sub(LOOK_CONTEXT));
+        let hi = core::cmp::min(self.end, at.saturating_add(2 * LOOK_CONTEXT));
+        seam.clear();
+        self.copy_range(lo, hi, seam);
+        let last = if hi == self.end { hi } else { hi - LOOK_CONTEXT };
+        Window { haystack: seam, offset: lo, last }
+    }
+}
+
+/// A contiguous part of a [`Chunks`] haystack, as returned by
+/// `Chunks::window`.
+#[derive(Debug)]
+pub(crate) struct Window<'a> {
+    /// The bytes in this window.
+    pub(crate) haystack: &'a [u8],
+    /// The absolute offset of the first byte in this window.
+    pub(crate) offset: usize,
+    /// The last absolute offset (inclusive) at which this window may be used
+    /// to search.
+    pub(crate) last: usize,
+}
@@ -250,6 +250,10 @@ pub(crate) fn next(
             | thompson::State::BinaryUnion { .. }
             | thompson::State::Fail
             | thompson::State::Look { .. }
+            | thompson::State::LookAround { .. }
+            | thompson::State::Backreference { .. }
+            | thompson::State::AtomicStart { .. }
+            | thompson::State::AtomicEnd { .. }
             | thompson::State::Capture { .. } => {}
             thompson::State::Match { pattern_id } => {
                 // Notice here that we are calling the NEW state a match
@@ -405,6 +409,14 @@ pub(crate) fn epsilon_closure(
                     }
                     id = next;
                 }
+                // DFAs cannot be built from NFAs with look-around,
+                // backreference or atomic states, and the DFA builders check
+                // for this before determinizing. So we should never get here,
+                // but if we do, we treat the state as never being satisfied.
+                thompson::State::LookAround { .. }
+                | thompson::State::Backreference { .. }
+                | thompson::State::AtomicStart { .. }
+                | thompson::State::AtomicEnd { .. } => break,
                 thompson::State::Union { ref alternates } => {
                     id = match alternates.get(0) {
                         None => break,
@@ -553,6 +565,15 @@ pub(crate) fn add_nfa_states(
             // Capture states we definitely do not need to record, since they
             // are unconditional epsilon transitions with no branching.
             thompson::State::Capture { .. } => {}
+            // Look-around, backreference and atomic states are never
+            // satisfied during determinization (see 'epsilon_closure'), so we
+            // record them just like fail states.
+            thompson::State::LookAround { .. }
+            | thompson::State::Backreference { .. }
+            | thompson::State::AtomicStart { .. }
+            | thompson::State::AtomicEnd { .. } => {
+                builder.add_nfa_state_id(nfa_id);
+            }
             // It's not totally clear whether we need to record fail states or
             // not, but we do so out of an abundance of caution. Since they are
             // quite rare in practice, there isn't much cost to recording them.
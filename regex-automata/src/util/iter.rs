@@ -163,7 +163,7 @@ impl<'h> Searcher<'h> {
     /// engine. The closure may borrow any additional state that is needed,
     /// such as a prefilter scanner.
     pub fn new(input: Input<'h>) -> Searcher<'h> {
-        Searcher { input, last_match_end: None }
+        Searcher { input: input.with_haystack_id(), last_match_end: None }
     }
 
     /// Returns the current `Input` used by this searcher.
@@ -132,6 +132,28 @@ pub enum Look {
     /// a position at either the end of the haystack or where the following
     /// character is not a word character.
     WordEndHalfUnicode = 1 << 17,
+    /// Match a Unicode word segmentation boundary. That is, this matches a
+    /// position that separates two words according to the default word
+    /// boundary rules in [UAX #29]. Unlike `WordUnicode`, this matches
+    /// between any two words (including punctuation and whitespace), and
+    /// never within words like `can't` or numbers like `3.14`.
+    ///
+    /// To keep searches linear, the rules only look at a few hundred bytes
+    /// around each position, so the results may differ from UAX #29 inside
+    /// very long runs of characters like regional indicators.
+    ///
+    /// [UAX #29]: https://unicode.org/reports/tr29/#Word_Boundaries
+    WordSegment = 1 << 18,
+    /// Match a Unicode sentence segmentation boundary. That is, this matches
+    /// a position that separates two sentences according to the default
+    /// sentence boundary rules in [UAX #29].
+    ///
+    /// To keep searches linear, the rules only look at a few hundred bytes
+    /// around each position, so the results may differ from UAX #29 after
+    /// very long runs of spaces or closing punctuation.
+    ///
+    /// [UAX #29]: https://unicode.org/reports/tr29/#Sentence_Boundaries
+    SentenceSegment = 1 << 19,
 }
 
 impl Look {
@@ -161,6 +183,8 @@ impl Look {
             Look::WordEndHalfAscii => Look::WordStartHalfAscii,
             Look::WordStartHalfUnicode => Look::WordEndHalfUnicode,
             Look::WordEndHalfUnicode => Look::WordStartHalfUnicode,
+            Look::WordSegment => Look::WordSegment,
+            Look::SentenceSegment => Look::SentenceSegment,
         }
     }
 
@@ -181,24 +205,26 @@ impl Look {
     #[inline]
     pub const fn from_repr(repr: u32) -> Option<Look> {
         match repr {
-            0b00_0000_0000_0000_0001 => Some(Look::Start),
-            0b00_0000_0000_0000_0010 => Some(Look::End),
-            0b00_0000_0000_0000_0100 => Some(Look::StartLF),
-            0b00_0000_0000_0000_1000 => Some(Look::EndLF),
-            0b00_0000_0000_0001_0000 => Some(Look::StartCRLF),
-            0b00_0000_0000_0010_0000 => Some(Look::EndCRLF),
-            0b00_0000_0000_0100_0000 => Some(Look::WordAscii),
-            0b00_0000_0000_1000_0000 => Some(Look::WordAsciiNegate),
-            0b00_0000_0001_0000_0000 => Some(Look::WordUnicode),
-            0b00_0000_0010_0000_0000 => Some(Look::WordUnicodeNegate),
-            0b00_0000_0100_0000_0000 => Some(Look::WordStartAscii),
-            0b00_0000_1000_0000_0000 => Some(Look::WordEndAscii),
-            0b00_0001_0000_0000_0000 => Some(Look::WordStartUnicode),
-            0b00_0010_0000_0000_0000 => Some(Look::WordEndUnicode),
-            0b00_0100_0000_0000_0000 => Some(Look::WordStartHalfAscii),
-            0b00_1000_0000_0000_0000 => Some(Look::WordEndHalfAscii),
-            0b01_0000_0000_0000_0000 => Some(Look::WordStartHalfUnicode),
-            0b10_0000_0000_0000_0000 => Some(Look::WordEndHalfUnicode),
+            0b0000_0000_0000_0000_0001 => Some(Look::Start),
+            0b0000_0000_0000_0000_0010 => Some(Look::End),
+            0b0000_0000_0000_0000_0100 => Some(Look::StartLF),
+            0b0000_0000_0000_0000_1000 => Some(Look::EndLF),
+            0b0000_0000_0000_0001_0000 => Some(Look::StartCRLF),
+            0b0000_0000_0000_0010_0000 => Some(Look::EndCRLF),
+            0b0000_0000_0000_0100_0000 => Some(Look::WordAscii),
+            0b0000_0000_0000_1000_0000 => Some(Look::WordAsciiNegate),
+            0b0000_0000_0001_0000_0000 => Some(Look::WordUnicode),
+            0b0000_0000_0010_0000_0000 => Some(Look::WordUnicodeNegate),
+            0b0000_0000_0100_0000_0000 => Some(Look::WordStartAscii),
+            0b0000_0000_1000_0000_0000 => Some(Look::WordEndAscii),
+            0b0000_0001_0000_0000_0000 => Some(Look::WordStartUnicode),
+            0b0000_0010_0000_0000_0000 => Some(Look::WordEndUnicode),
+            0b0000_0100_0000_0000_0000 => Some(Look::WordStartHalfAscii),
+            0b0000_1000_0000_0000_0000 => Some(Look::WordEndHalfAscii),
+            0b0001_0000_0000_0000_0000 => Some(Look::WordStartHalfUnicode),
+            0b0010_0000_0000_0000_0000 => Some(Look::WordEndHalfUnicode),
+            0b0100_0000_0000_0000_0000 => Some(Look::WordSegment),
+            0b1000_0000_0000_0000_0000 => Some(Look::SentenceSegment),
             _ => None,
         }
     }
@@ -231,6 +257,8 @@ impl Look {
             Look::WordEndHalfAscii => '▷',
             Look::WordStartHalfUnicode => '◀',
             Look::WordEndHalfUnicode => '▶',
+            Look::WordSegment => 'w',
+            Look::SentenceSegment => 's',
         }
     }
 }
@@ -372,6 +400,14 @@ impl LookSet {
             || self.contains(Look::WordEndHalfAscii)
     }
 
+    /// Returns true if and only if this set contains any Unicode word or
+    /// sentence segmentation boundary assertions.
+    #[inline]
+    pub fn contains_segment(self) -> bool {
+        self.contains(Look::WordSegment)
+            || self.contains(Look::SentenceSegment)
+    }
+
     /// Returns an iterator over all of the look-around assertions in this set.
     #[inline]
     pub fn iter(self) -> LookSetIter {
@@ -484,7 +520,10 @@ impl LookSet {
     ///
     /// Specifically, this returns an error when the the
     /// `unicode-word-boundary` feature is _not_ enabled _and_ this set
-    /// contains a Unicode word boundary assertion.
+    /// contains a Unicode word boundary assertion. Similarly, this returns an
+    /// error when this set contains a Unicode segmentation boundary assertion
+    /// and any of the `syntax`, `unicode-segment` or `unicode-bool` features
+    /// are not enabled.
     ///
     /// It can be useful to use this on the result of
     /// [`NFA::look_set_any`](crate::nfa::thompson::NFA::look_set_any)
@@ -494,6 +533,9 @@ impl LookSet {
         if self.contains_word_unicode() {
             UnicodeWordBoundaryError::check()?;
         }
+        if self.contains_segment() {
+            segment::check()?;
+        }
         Ok(())
     }
 }
@@ -694,6 +736,10 @@ impl LookMatcher {
             Look::WordEndHalfUnicode => {
                 self.is_word_end_half_unicode(haystack, at).unwrap()
             }
+            Look::WordSegment => self.is_word_segment(haystack, at).unwrap(),
+            Look::SentenceSegment => {
+                self.is_sentence_segment(haystack, at).unwrap()
+            }
         }
     }
 
@@ -828,6 +874,16 @@ impl LookMatcher {
                 return false;
             }
         }
+        if set.contains(Look::WordSegment) {
+            if !self.is_word_segment(haystack, at).unwrap() {
+                return false;
+            }
+        }
+        if set.contains(Look::SentenceSegment) {
+            if !self.is_sentence_segment(haystack, at).unwrap() {
+                return false;
+            }
+        }
         true
     }
 
@@ -893,6 +949,9 @@ impl LookMatcher {
                     b1 = b2;
                 }
             }
+            // DFAs can't handle segmentation boundaries at all, so there's
+            // no point in splitting up the byte classes for them.
+            Look::WordSegment | Look::SentenceSegment => {}
         }
     }
 
@@ -1258,6 +1317,50 @@ impl LookMatcher {
             };
         Ok(!word_after)
     }
+
+    /// Returns true when [`Look::WordSegment`] is satisfied `at` the given
+    /// position in `haystack`.
+    ///
+    /// # Panics
+    ///
+    /// This may panic when `at > haystack.len()`. Note that `at ==
+    /// haystack.len()` is legal and guaranteed not to panic.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the Unicode word break tables are not
+    /// available. Specifically, this only occurs when any of the `syntax`,
+    /// `unicode-segment` or `unicode-bool` features are not enabled.
+    #[inline]
+    pub fn is_word_segment(
+        &self,
+        haystack: &[u8],
+        at: usize,
+    ) -> Result<bool, UnicodeWordBoundaryError> {
+        segment::is_word_boundary(haystack, at)
+    }
+
+    /// Returns true when [`Look::SentenceSegment`] is satisfied `at` the
+    /// given position in `haystack`.
+    ///
+    /// # Panics
+    ///
+    /// This may panic when `at > haystack.len()`. Note that `at ==
+    /// haystack.len()` is legal and guaranteed not to panic.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the Unicode sentence break tables are not
+    /// available. Specifically, this only occurs when any of the `syntax`,
+    /// `unicode-segment` or `unicode-bool` features are not enabled.
+    #[inline]
+    pub fn is_sentence_segment(
+        &self,
+        haystack: &[u8],
+        at: usize,
+    ) -> Result<bool, UnicodeWordBoundaryError> {
+        segment::is_sentence_boundary(haystack, at)
+    }
 }
 
 impl Default for LookMatcher {
@@ -1276,13 +1379,31 @@ impl Default for LookMatcher {
 ///
 /// This error can only occur when the `unicode-word-boundary` feature is
 /// disabled.
+///
+/// This error is also used when the data tables necessary for the Unicode
+/// segmentation boundaries `\b{wb}` and `\b{sb}` are unavailable. This can
+/// only occur when any of the `syntax`, `unicode-segment` or `unicode-bool`
+/// features are disabled.
 #[derive(Clone, Debug)]
-pub struct UnicodeWordBoundaryError(());
+pub struct UnicodeWordBoundaryError {
+    /// Whether the missing tables are the ones for segmentation boundaries
+    /// instead of the ones for `\w`.
+    segment: bool,
+}
 
 impl UnicodeWordBoundaryError {
     #[cfg(not(feature = "unicode-word-boundary"))]
     pub(crate) fn new() -> UnicodeWordBoundaryError {
-        UnicodeWordBoundaryError(())
+        UnicodeWordBoundaryError { segment: false }
+    }
+
+    #[cfg(not(all(
+        feature = "syntax",
+        feature = "unicode-segment",
+        feature = "unicode-bool",
+    )))]
+    pub(crate) fn segment() -> UnicodeWordBoundaryError {
+        UnicodeWordBoundaryError { segment: true }
     }
 
     /// Returns an error if and only if Unicode word boundary data is
@@ -1297,6 +1418,14 @@ impl std::error::Error for UnicodeWordBoundaryError {}
 
 impl core::fmt::Display for UnicodeWordBoundaryError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.segment {
+            return write!(
+                f,
+                "Unicode-aware \\b{{wb}} and \\b{{sb}} are unavailable \
+                 because the requisite data tables are missing, please \
+                 enable the syntax, unicode-segment and unicode-bool features"
+            );
+        }
         write!(
             f,
             "Unicode-aware \\b and \\B are unavailable because the \
@@ -1696,6 +1825,555 @@ mod is_word_char {
     }
 }
 
+/// A module that implements the word and sentence boundary rules from
+/// [UAX #29] using regex-syntax's data tables.
+///
+/// Most of the rules only look at the characters on either side of a
+/// position, but some need more context than that. For example, whether a
+/// boundary exists between two regional indicators depends on how many
+/// regional indicators precede them. Since each position is checked on its
+/// own, scanning all of that context could make a search quadratic. So the
+/// rules only ever see a window of a few hundred bytes around a position, as
+/// if the haystack ended there. This only changes the result for unusually
+/// long runs of regional indicators, spaces, closing punctuation or
+/// ignorable characters. (Windows start at multiples of [`CONTEXT_LEN`], so
+/// that long runs of regional indicators are still split into pairs.)
+///
+/// Positions that split the UTF-8 encoding of a codepoint are never
+/// boundaries. Otherwise, each byte that isn't part of a valid UTF-8
+/// encoding is treated as a character with the `Other` property value.
+///
+/// [UAX #29]: https://unicode.org/reports/tr29/
+#[cfg(all(
+    feature = "syntax",
+    feature = "unicode-segment",
+    feature = "unicode-bool",
+))]
+mod segment {
+    use regex_syntax::{
+        try_is_extended_pictographic, try_sentence_break, try_word_break,
+    };
+
+    use crate::util::utf8;
+
+    /// The minimum number of bytes on either side of a position that the
+    /// segmentation rules may look at.
+    const CONTEXT_LEN: usize = 128;
+
+    pub(super) fn check() -> Result<(), super::UnicodeWordBoundaryError> {
+        Ok(())
+    }
+
+    pub(super) fn is_word_boundary(
+        haystack: &[u8],
+        at: usize,
+    ) -> Result<bool, super::UnicodeWordBoundaryError> {
+        use self::WordBreak::*;
+
+        // WB1, WB2: Break at the start and end of text, unless the text is
+        // empty.
+        if at == 0 || at == haystack.len() {
+            return Ok(!haystack.is_empty());
+        }
+        if splits_codepoint(haystack, at) {
+            return Ok(false);
+        }
+        let (haystack, at) = context(haystack, at);
+        let (lch, _) = prev(haystack, at).unwrap();
+        let (rch, rlen) = next(haystack, at).unwrap();
+        let (l, r) = (WordBreak::of(lch), WordBreak::of(rch));
+        // WB3: CR × LF
+        if l == CR && r == LF {
+            return Ok(false);
+        }
+        // WB3a, WB3b: Break before and after newlines.
+        if l.is_separator() || r.is_separator() {
+            return Ok(true);
+        }
+        // WB3c: ZWJ × \p{Extended_Pictographic}
+        if l == ZWJ && rch.map_or(false, is_extended_pictographic) {
+            return Ok(false);
+        }
+        // WB3d: WSegSpace × WSegSpace
+        if l == WSegSpace && r == WSegSpace {
+            return Ok(false);
+        }
+        // WB4: X (Extend | Format | ZWJ)* → X
+        if r.is_ignorable() {
+            return Ok(false);
+        }
+        // From here on, characters followed by ignorable characters are
+        // treated as if they were just the first character, so the left
+        // side needs to skip over them. The right side can't be ignorable.
+        let (l, lstart) = prev_base::<WordBreak>(haystack, at).unwrap();
+        let before_l = || prev_base::<WordBreak>(haystack, lstart);
+        let after_r = || next_base::<WordBreak>(haystack, at + rlen);
+        let ahletter = |wb| matches!(wb, ALetter | HebrewLetter);
+        let midletter = |wb| matches!(wb, MidLetter | MidNumLet | SingleQuote);
+        let midnum = |wb| matches!(wb, MidNum | MidNumLet | SingleQuote);
+        // WB5, WB8, WB9, WB10: Don't break within sequences of letters and
+        // digits.
+        if (ahletter(l) || l == Numeric) && (ahletter(r) || r == Numeric) {
+            return Ok(false);
+        }
+        // WB6: AHLetter × (MidLetter | MidNumLetQ) AHLetter
+        if ahletter(l) && midletter(r) && after_r().map_or(false, ahletter) {
+            return Ok(false);
+        }
+        // WB7: AHLetter (MidLetter | MidNumLetQ) × AHLetter
+        if midletter(l)
+            && ahletter(r)
+            && before_l().map_or(false, |(wb, _)| ahletter(wb))
+        {
+            return Ok(false);
+        }
+        // WB7a: Hebrew_Letter × Single_Quote
+        if l == HebrewLetter && r == SingleQuote {
+            return Ok(false);
+        }
+        // WB7b: Hebrew_Letter × Double_Quote Hebrew_Letter
+        if l == HebrewLetter
+            && r == DoubleQuote
+            && after_r() == Some(HebrewLetter)
+        {
+            return Ok(false);
+        }
+        // WB7c: Hebrew_Letter Double_Quote × Hebrew_Letter
+        if l == DoubleQuote
+            && r == HebrewLetter
+            && before_l().map_or(false, |(wb, _)| wb == HebrewLetter)
+        {
+            return Ok(false);
+        }
+        // WB11: Numeric (MidNum | MidNumLetQ) × Numeric
+        if midnum(l)
+            && r == Numeric
+            && before_l().map_or(false, |(wb, _)| wb == Numeric)
+        {
+            return Ok(false);
+        }
+        // WB12: Numeric × (MidNum | MidNumLetQ) Numeric
+        if l == Numeric && midnum(r) && after_r() == Some(Numeric) {
+            return Ok(false);
+        }
+        // WB13: Katakana × Katakana
+        if l == Katakana && r == Katakana {
+            return Ok(false);
+        }
+        // WB13a: (AHLetter | Numeric | Katakana | ExtendNumLet) ×
+        // ExtendNumLet
+        if (ahletter(l) || matches!(l, Numeric | Katakana | ExtendNumLet))
+            && r == ExtendNumLet
+        {
+            return Ok(false);
+        }
+        // WB13b: ExtendNumLet × (AHLetter | Numeric | Katakana)
+        if l == ExtendNumLet
+            && (ahletter(r) || matches!(r, Numeric | Katakana))
+        {
+            return Ok(false);
+        }
+        // WB15, WB16: Don't break between pairs of regional indicators.
+        if l == RegionalIndicator && r == RegionalIndicator {
+            let mut count = 0;
+            let mut pos = at;
+            while let Some((RegionalIndicator, start)) =
+                prev_base::<WordBreak>(haystack, pos)
+            {
+                count += 1;
+                pos = start;
+            }
+            return Ok(count % 2 == 0);
+        }
+        // WB999: Any ÷ Any
+        Ok(true)
+    }
+
+    pub(super) fn is_sentence_boundary(
+        haystack: &[u8],
+        at: usize,
+    ) -> Result<bool, super::UnicodeWordBoundaryError> {
+        use self::SentenceBreak::*;
+
+        // SB1, SB2: Break at the start and end of text, unless the text is
+        // empty.
+        if at == 0 || at == haystack.len() {
+            return Ok(!haystack.is_empty());
+        }
+        if splits_codepoint(haystack, at) {
+            return Ok(false);
+        }
+        let (haystack, at) = context(haystack, at);
+        let (lch, _) = prev(haystack, at).unwrap();
+        let (rch, _) = next(haystack, at).unwrap();
+        let (l, r) = (SentenceBreak::of(lch), SentenceBreak::of(rch));
+        // SB3: CR × LF
+        if l == CR && r == LF {
+            return Ok(false);
+        }
+        // SB4: ParaSep ÷
+        if l.is_separator() {
+            return Ok(true);
+        }
+        // SB5: X (Extend | Format)* → X
+        if r.is_ignorable() {
+            return Ok(false);
+        }
+        // Every remaining rule except for SB998 requires the position to
+        // follow `SATerm Close* Sp*`, so look for that first.
+        let mut base = prev_base::<SentenceBreak>(haystack, at);
+        let mut sp = false;
+        while let Some((Sp, start)) = base {
+            sp = true;
+            base = prev_base::<SentenceBreak>(haystack, start);
+        }
+        let mut close = false;
+        while let Some((Close, start)) = base {
+            close = true;
+            base = prev_base::<SentenceBreak>(haystack, start);
+        }
+        let (term, term_start) = match base {
+            Some((term @ (ATerm | STerm), start)) => (term, start),
+            // SB998: Any × Any
+            _ => return Ok(false),
+        };
+        // SB6: ATerm × Numeric
+        if term == ATerm && !close && !sp && r == Numeric {
+            return Ok(false);
+        }
+        // SB7: (Upper | Lower) ATerm × Upper
+        if term == ATerm
+            && !close
+            && !sp
+            && r == Upper
+            && matches!(
+                prev_base::<SentenceBreak>(haystack, term_start),
+                Some((Upper | Lower, _)),
+            )
+        {
+            return Ok(false);
+        }
+        // SB8: ATerm Close* Sp* ×
+        // (¬(OLetter | Upper | Lower | ParaSep | SATerm))* Lower
+        if term == ATerm && is_lower_next(haystack, at) {
+            return Ok(false);
+        }
+        // SB8a: SATerm Close* Sp* × (SContinue | SATerm)
+        if matches!(r, SContinue | STerm | ATerm) {
+            return Ok(false);
+        }
+        // SB9: SATerm Close* × (Close | Sp | ParaSep)
+        if !sp && (matches!(r, Close | Sp) || r.is_separator()) {
+            return Ok(false);
+        }
+        // SB10: SATerm Close* Sp* × (Sp | ParaSep)
+        if r == Sp || r.is_separator() {
+            return Ok(false);
+        }
+        // SB11: SATerm Close* Sp* ParaSep? ÷
+        Ok(true)
+    }
+
+    /// Returns true when the first character at or after `at` that is one
+    /// of `OLetter`, `Upper`, `Lower`, `ParaSep` or `SATerm` is `Lower`.
+    fn is_lower_next(haystack: &[u8], mut at: usize) -> bool {
+        use self::SentenceBreak::*;
+
+        while let Some((ch, len)) = next(haystack, at) {
+            match SentenceBreak::of(ch) {
+                Lower => return true,
+                OLetter | Upper | Sep | CR | LF | STerm | ATerm => {
+                    return false
+                }
+                _ => at += len,
+            }
+        }
+        false
+    }
+
+    /// A property that classifies characters for one of the segmentation
+    /// algorithms.
+    trait Property: Copy {
+        /// Returns the property value of the given character. Invalid UTF-8
+        /// is represented by `None`.
+        fn of(ch: Option<char>) -> Self;
+
+        /// Returns true for characters that are attached to the character
+        /// preceding them, i.e., those covered by WB4 or SB5.
+        fn is_ignorable(self) -> bool;
+
+        /// Returns true for characters that ignorable characters can't be
+        /// attached to.
+        fn is_separator(self) -> bool;
+    }
+
+    /// The values of the `Word_Break` property.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum WordBreak {
+        ALetter,
+        CR,
+        DoubleQuote,
+        Extend,
+        ExtendNumLet,
+        Format,
+        HebrewLetter,
+        Katakana,
+        LF,
+        MidLetter,
+        MidNum,
+        MidNumLet,
+        Newline,
+        Numeric,
+        RegionalIndicator,
+        SingleQuote,
+        WSegSpace,
+        ZWJ,
+        Other,
+    }
+
+    impl Property for WordBreak {
+        fn of(ch: Option<char>) -> WordBreak {
+            use self::WordBreak::*;
+
+            let ch = match ch {
+                None => return Other,
+                Some(ch) => ch,
+            };
+            let name = try_word_break(ch).expect(
+                "since syntax, unicode-segment and unicode-bool are all \
+                 enabled, it is expected that try_word_break succeeds",
+            );
+            match name {
+                "ALetter" => ALetter,
+                "CR" => CR,
+                "Double_Quote" => DoubleQuote,
+                "Extend" => Extend,
+                "ExtendNumLet" => ExtendNumLet,
+                "Format" => Format,
+                "Hebrew_Letter" => HebrewLetter,
+                "Katakana" => Katakana,
+                "LF" => LF,
+                "MidLetter" => MidLetter,
+                "MidNum" => MidNum,
+                "MidNumLet" => MidNumLet,
+                "Newline" => Newline,
+                "Numeric" => Numeric,
+                "Regional_Indicator" => RegionalIndicator,
+                "Single_Quote" => SingleQuote,
+                "WSegSpace" => WSegSpace,
+                "ZWJ" => ZWJ,
+                _ => Other,
+            }
+        }
+
+        fn is_ignorable(self) -> bool {
+            matches!(
+                self,
+                WordBreak::Extend | WordBreak::Format | WordBreak::ZWJ
+            )
+        }
+
+        fn is_separator(self) -> bool {
+            matches!(self, WordBreak::Newline | WordBreak::CR | WordBreak::LF)
+        }
+    }
+
+    /// The values of the `Sentence_Break` property.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum SentenceBreak {
+        ATerm,
+        CR,
+        Close,
+        Extend,
+        Format,
+        LF,
+        Lower,
+        Numeric,
+        OLetter,
+        SContinue,
+        STerm,
+        Sep,
+        Sp,
+        Upper,
+        Other,
+    }
+
+    impl Property for SentenceBreak {
+        fn of(ch: Option<char>) -> SentenceBreak {
+            use self::SentenceBreak::*;
+
+            let ch = match ch {
+                None => return Other,
+                Some(ch) => ch,
+            };
+            let name = try_sentence_break(ch).expect(
+                "since syntax, unicode-segment and unicode-bool are all \
+                 enabled, it is expected that try_sentence_break succeeds",
+            );
+            match name {
+                "ATerm" => ATerm,
+                "CR" => CR,
+                "Close" => Close,
+                "Extend" => Extend,
+                "Format" => Format,
+                "LF" => LF,
+                "Lower" => Lower,
+                "Numeric" => Numeric,
+                "OLetter" => OLetter,
+                "SContinue" => SContinue,
+                "STerm" => STerm,
+                "Sep" => Sep,
+                "Sp" => Sp,
+                "Upper" => Upper,
+                _ => Other,
+            }
+        }
+
+        fn is_ignorable(self) -> bool {
+            matches!(self, SentenceBreak::Extend | SentenceBreak::Format)
+        }
+
+        fn is_separator(self) -> bool {
+            matches!(
+                self,
+                SentenceBreak::Sep | SentenceBreak::CR | SentenceBreak::LF
+            )
+        }
+    }
+
+    fn is_extended_pictographic(ch: char) -> bool {
+        try_is_extended_pictographic(ch).expect(
+            "since syntax, unicode-segment and unicode-bool are all \
+             enabled, it is expected that try_is_extended_pictographic \
+             succeeds",
+        )
+    }
+
+    /// Returns the property value of the character ending at `at` along
+    /// with the offset at which it starts, where any ignorable characters
+    /// are treated as part of the character preceding them.
+    ///
+    /// Ignorable characters that follow a separator or the start of the
+    /// haystack aren't attached to anything. In that case, the first of them
+    /// is returned.
+    fn prev_base<P: Property>(
+        haystack: &[u8],
+        mut at: usize,
+    ) -> Option<(P, usize)> {
+        let mut ignored = None;
+        while let Some((ch, len)) = prev(haystack, at) {
+            let prop = P::of(ch);
+            at -= len;
+            if !prop.is_ignorable() {
+                if prop.is_separator() && ignored.is_some() {
+                    break;
+                }
+                return Some((prop, at));
+            }
+            ignored = Some((prop, at));
+        }
+        ignored
+    }
+
+    /// Returns the property value of the first character at or after `at`
+    /// that isn't ignorable.
+    ///
+    /// This should only be used when the character preceding `at` isn't a
+    /// separator, such that any ignorable characters at `at` are attached to
+    /// it.
+    fn next_base<P: Property>(haystack: &[u8], mut at: usize) -> Option<P> {
+        while let Some((ch, len)) = next(haystack, at) {
+            let prop = P::of(ch);
+            if !prop.is_ignorable() {
+                return Some(prop);
+            }
+            at += len;
+        }
+        None
+    }
+
+    /// Returns the part of the haystack that the segmentation rules may look
+    /// at for the position `at`, along with the offset of `at` in it.
+    ///
+    /// The window starts at the last multiple of [`CONTEXT_LEN`] that is at
+    /// least [`CONTEXT_LEN`] bytes before `at`, and ends [`CONTEXT_LEN`]
+    /// bytes after `at`. Its bounds are then moved outwards such that they
+    /// don't split the encoding of a codepoint.
+    fn context(haystack: &[u8], at: usize) -> (&[u8], usize) {
+        let mut start =
+            at.saturating_sub(CONTEXT_LEN) / CONTEXT_LEN * CONTEXT_LEN;
+        while start > 0 && !utf8::is_boundary(haystack, start) {
+            start -= 1;
+        }
+        let mut end = at.saturating_add(CONTEXT_LEN).min(haystack.len());
+        while end < haystack.len() && !utf8::is_boundary(haystack, end) {
+            end += 1;
+        }
+        (&haystack[start..end], at - start)
+    }
+
+    /// Returns the character ending at `at` along with its length in bytes.
+    /// A byte that isn't part of a valid UTF-8 encoding is returned as a
+    /// `None` character of length 1.
+    fn prev(haystack: &[u8], at: usize) -> Option<(Option<char>, usize)> {
+        Some(match utf8::decode_last(&haystack[..at])? {
+            Ok(ch) => (Some(ch), ch.len_utf8()),
+            Err(_) => (None, 1),
+        })
+    }
+
+    /// Returns the character starting at `at` along with its length in
+    /// bytes. A byte that isn't part of a valid UTF-8 encoding is returned
+    /// as a `None` character of length 1.
+    fn next(haystack: &[u8], at: usize) -> Option<(Option<char>, usize)> {
+        Some(match utf8::decode(&haystack[at..])? {
+            Ok(ch) => (Some(ch), ch.len_utf8()),
+            Err(_) => (None, 1),
+        })
+    }
+
+    /// Returns true if `at` falls strictly inside the valid UTF-8 encoding
+    /// of a codepoint.
+    fn splits_codepoint(haystack: &[u8], at: usize) -> bool {
+        if utf8::is_boundary(haystack, at) {
+            return false;
+        }
+        (at.saturating_sub(3)..at).any(|start| {
+            match utf8::decode(&haystack[start..]) {
+                Some(Ok(ch)) => start + ch.len_utf8() > at,
+                _ => false,
+            }
+        })
+    }
+}
+
+/// A module that always returns an error when the data tables needed for
+/// Unicode segmentation boundaries are unavailable.
+#[cfg(not(all(
+    feature = "syntax",
+    feature = "unicode-segment",
+    feature = "unicode-bool",
+)))]
+mod segment {
+    pub(super) fn check() -> Result<(), super::UnicodeWordBoundaryError> {
+        Err(super::UnicodeWordBoundaryError::segment())
+    }
+
+    pub(super) fn is_word_boundary(
+        _haystack: &[u8],
+        _at: usize,
+    ) -> Result<bool, super::UnicodeWordBoundaryError> {
+        Err(super::UnicodeWordBoundaryError::segment())
+    }
+
+    pub(super) fn is_sentence_boundary(
+        _haystack: &[u8],
+        _at: usize,
+    ) -> Result<bool, super::UnicodeWordBoundaryError> {
+        Err(super::UnicodeWordBoundaryError::segment())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2411,6 +3089,90 @@ mod tests {
         assert!(testlook!(look, "𝛃𐆀", 8));
     }
 
+    #[test]
+    #[cfg(all(
+        not(miri),
+        feature = "syntax",
+        feature = "unicode-segment",
+        feature = "unicode-bool"
+    ))]
+    fn look_matches_word_segment() {
+        let look = Look::WordSegment;
+
+        // Boundaries around and between words and spaces.
+        assert!(testlook!(look, "a b", 0));
+        assert!(testlook!(look, "a b", 1));
+        assert!(testlook!(look, "a b", 2));
+        assert!(testlook!(look, "a b", 3));
+        assert!(testlook!(look, "ab.", 2));
+
+        // Contractions and decimal numbers are single words.
+        assert!(!testlook!(look, "can't", 3));
+        assert!(!testlook!(look, "can't", 4));
+        assert!(!testlook!(look, "3.14", 1));
+        assert!(!testlook!(look, "3.14", 2));
+        assert!(testlook!(look, "3.14", 4));
+
+        // Runs of horizontal whitespace are kept together.
+        assert!(!testlook!(look, "a  b", 2));
+
+        // Combining marks attach to what precedes them.
+        assert!(!testlook!(look, "e\u{301}x", 1));
+        assert!(!testlook!(look, "e\u{301}x", 3));
+
+        // Regional indicators pair up. Each indicator is 4 bytes.
+        assert!(!testlook!(look, "🇫🇷🇩🇪", 4));
+        assert!(testlook!(look, "🇫🇷🇩🇪", 8));
+        assert!(!testlook!(look, "🇫🇷🇩🇪", 12));
+        // Even in runs that are longer than the context the rules look at.
+        let h = "🇫".repeat(1000);
+        assert!(!testlook!(look, h, 1996));
+        assert!(testlook!(look, h, 2000));
+
+        // CRLF is never split.
+        assert!(!testlook!(look, "\r\n", 1));
+        assert!(testlook!(look, "a\r\n", 1));
+
+        // Never a boundary in an empty haystack or inside a codepoint.
+        assert!(!testlook!(look, "", 0));
+        assert!(!testlook!(look, "𝛃", 1));
+        assert!(!testlook!(look, "𝛃", 2));
+    }
+
+    #[test]
+    #[cfg(all(
+        not(miri),
+        feature = "syntax",
+        feature = "unicode-segment",
+        feature = "unicode-bool"
+    ))]
+    fn look_matches_sentence_segment() {
+        let look = Look::SentenceSegment;
+
+        // Sentences break after terminal punctuation and spaces.
+        let h = "Hello. World.";
+        assert!(testlook!(look, h, 0));
+        assert!(testlook!(look, h, 7));
+        assert!(testlook!(look, h, h.len()));
+        assert!(!testlook!(look, h, 6));
+        assert!(!testlook!(look, h, 3));
+
+        // A period followed by a lowercase word is not a sentence end.
+        assert!(!testlook!(look, "Mr. smith is here.", 4));
+
+        // Question marks and exclamation points always end sentences.
+        assert!(testlook!(look, "Why? Because.", 5));
+
+        // Paragraph separators end sentences.
+        assert!(testlook!(look, "a\nb", 2));
+        assert!(!testlook!(look, "a\nb", 1));
+        assert!(!testlook!(look, "a\r\nb", 2));
+
+        // Never a boundary in an empty haystack or inside a codepoint.
+        assert!(!testlook!(look, "", 0));
+        assert!(!testlook!(look, "𝛃", 1));
+    }
+
     #[test]
     fn look_set() {
         let mut f = LookSet::default();
@@ -2512,6 +3274,19 @@ mod tests {
         assert!(f.contains(Look::WordEndHalfUnicode));
         f = f.remove(Look::WordEndHalfUnicode);
         assert!(!f.contains(Look::WordEndHalfUnicode));
+
+        f = f.insert(Look::WordSegment);
+        assert!(f.contains(Look::WordSegment));
+        assert!(f.contains_segment());
+        f = f.remove(Look::WordSegment);
+        assert!(!f.contains(Look::WordSegment));
+
+        f = f.insert(Look::SentenceSegment);
+        assert!(f.contains(Look::SentenceSegment));
+        assert!(f.contains_segment());
+        f = f.remove(Look::SentenceSegment);
+        assert!(!f.contains(Look::SentenceSegment));
+        assert!(!f.contains_segment());
     }
 
     #[test]
@@ -2520,7 +3295,7 @@ mod tests {
         assert_eq!(0, set.iter().count());
 
         let set = LookSet::full();
-        assert_eq!(18, set.iter().count());
+        assert_eq!(20, set.iter().count());
 
         let set =
             LookSet::empty().insert(Look::StartLF).insert(Look::WordUnicode);
@@ -2542,6 +3317,6 @@ mod tests {
         let res = alloc::format!("{:?}", LookSet::empty());
         assert_eq!("∅", res);
         let res = alloc::format!("{:?}", LookSet::full());
-        assert_eq!("Az^$rRbB𝛃𝚩<>〈〉◁▷◀▶", res);
+        assert_eq!("Az^$rRbB𝛃𝚩<>〈〉◁▷◀▶ws", res);
     }
 }
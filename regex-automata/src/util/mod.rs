@@ -12,6 +12,8 @@ walking its state graph directly.
 * `captures` contains APIs for dealing with capture group matches and their
 mapping to "slots" used inside an NFA graph. This is also where you can find
 iterators over capture group names.
+* `chunks` contains a haystack type for searching text that is split into
+chunks, such as the text in a rope.
 * `escape` contains types for pretty-printing raw byte slices as strings.
 * `iter` contains API helpers for writing regex iterators.
 * `lazy` contains a no-std and no-alloc variant of `lazy_static!` and
@@ -30,6 +32,8 @@ with the `regex-syntax` crate.
 pub mod alphabet;
 #[cfg(feature = "alloc")]
 pub mod captures;
+#[cfg(feature = "alloc")]
+pub mod chunks;
 pub mod escape;
 #[cfg(feature = "alloc")]
 pub mod interpolate;
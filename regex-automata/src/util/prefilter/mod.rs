@@ -46,12 +46,14 @@ use core::{
 };
 
 #[cfg(feature = "alloc")]
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 
 #[cfg(feature = "syntax")]
 use regex_syntax::hir::{literal, Hir};
 
 use crate::util::search::{MatchKind, Span};
+#[cfg(feature = "alloc")]
+use crate::util::wire::{DeserializeError, Reader, Writer};
 
 pub(crate) use crate::util::prefilter::{
     aho_corasick::AhoCorasick,
@@ -148,6 +150,14 @@ pub struct Prefilter {
     is_fast: bool,
     #[cfg(feature = "alloc")]
     max_needle_len: usize,
+    /// The match semantics and needles this prefilter was built from. We
+    /// hang on to these so that a prefilter can be serialized (as part of a
+    /// meta regex) by recording what it was built from, and then rebuilt
+    /// when deserialized.
+    #[cfg(feature = "alloc")]
+    kind: MatchKind,
+    #[cfg(feature = "alloc")]
+    needles: Arc<[Vec<u8>]>,
 }
 
 impl Prefilter {
@@ -204,25 +214,28 @@ impl Prefilter {
         kind: MatchKind,
         needles: &[B],
     ) -> Option<Prefilter> {
-        Choice::new(kind, needles).and_then(|choice| {
-            let max_needle_len =
-                needles.iter().map(|b| b.as_ref().len()).max().unwrap_or(0);
-            Prefilter::from_choice(choice, max_needle_len)
-        })
+        Choice::new(kind, needles)
+            .and_then(|choice| Prefilter::from_choice(choice, kind, needles))
     }
 
     /// This turns a prefilter selection into a `Prefilter`. That is, in turns
     /// the enum given into a trait object.
-    fn from_choice(
+    fn from_choice<B: AsRef<[u8]>>(
         choice: Choice,
-        max_needle_len: usize,
+        kind: MatchKind,
+        needles: &[B],
     ) -> Option<Prefilter> {
         #[cfg(not(feature = "alloc"))]
         {
+            let _ = (kind, needles);
             None
         }
         #[cfg(feature = "alloc")]
         {
+            let max_needle_len =
+                needles.iter().map(|b| b.as_ref().len()).max().unwrap_or(0);
+            let needles: Arc<[Vec<u8>]> =
+                needles.iter().map(|b| b.as_ref().to_vec()).collect();
             let pre: Arc<dyn PrefilterI> = match choice {
                 Choice::Memchr(p) => Arc::new(p),
                 Choice::Memchr2(p) => Arc::new(p),
@@ -233,7 +246,7 @@ impl Prefilter {
                 Choice::AhoCorasick(p) => Arc::new(p),
             };
             let is_fast = pre.is_fast();
-            Some(Prefilter { pre, is_fast, max_needle_len })
+            Some(Prefilter { pre, is_fast, max_needle_len, kind, needles })
         }
     }
 
@@ -417,6 +430,8 @@ impl Prefilter {
         #[cfg(feature = "alloc")]
         {
             self.pre.memory_usage()
+                + self.needles.iter().map(|n| n.len()).sum::<usize>()
+                + self.needles.len() * core::mem::size_of::<Vec<u8>>()
         }
     }
 
@@ -462,6 +477,48 @@ impl Prefilter {
             self.is_fast
         }
     }
+
+    /// Serialize this prefilter by writing the match semantics and needles
+    /// it was built from.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn write_to(&self, wtr: &mut Writer) {
+        wtr.u8(match self.kind {
+            MatchKind::All => 0,
+            MatchKind::LeftmostFirst => 1,
+        });
+        wtr.usize(self.needles.len());
+        for needle in self.needles.iter() {
+            wtr.bytes(needle);
+        }
+    }
+
+    /// Deserialize a prefilter written by `Prefilter::write_to`.
+    ///
+    /// Since a prefilter is rebuilt from its needles, this returns `None`
+    /// when a prefilter could not be built. This can happen, for example,
+    /// when the prefilter was serialized by a build of this crate with more
+    /// literal search features enabled than the one deserializing it.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn read_from(
+        rdr: &mut Reader<'_>,
+    ) -> Result<Option<Prefilter>, DeserializeError> {
+        let kind = match rdr.u8("prefilter match kind")? {
+            0 => MatchKind::All,
+            1 => MatchKind::LeftmostFirst,
+            _ => {
+                return Err(DeserializeError::generic(
+                    "unrecognized prefilter match kind",
+                ))
+            }
+        };
+        // Every needle occupies at least the 8 bytes used for its length.
+        let len = rdr.len(8, "prefilter needle count")?;
+        let mut needles = Vec::with_capacity(len);
+        for _ in 0..len {
+            needles.push(rdr.bytes("prefilter needle")?);
+        }
+        Ok(Prefilter::new(kind, &needles))
+    }
 }
 
 /// A trait for abstracting over prefilters. Basically, a prefilter is
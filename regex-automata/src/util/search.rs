@@ -6,7 +6,10 @@ at the crate root due to the universality of most of the types and routines in
 this module.
 */
 
-use core::ops::{Range, RangeBounds};
+use core::{
+    ops::{Range, RangeBounds},
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use crate::util::{escape::DebugByte, primitives::PatternID, utf8};
 
@@ -27,6 +30,7 @@ use crate::util::{escape::DebugByte, primitives::PatternID, utf8};
 /// only search for one pattern in particular.
 /// * Whether to perform an anchored on unanchored search.
 /// * Whether to report a match as early as possible.
+/// * A flag that another thread can set to cancel the search.
 ///
 /// All of these parameters, except for the haystack, have sensible default
 /// values. This means that the minimal search configuration is simply a call
@@ -104,8 +108,16 @@ pub struct Input<'h> {
     span: Span,
     anchored: Anchored,
     earliest: bool,
+    cancel: Option<&'h AtomicBool>,
+    /// An identifier shared by every search over this haystack that is
+    /// derived from the same iterator. See `Input::with_haystack_id`.
+    haystack_id: Option<usize>,
 }
 
+/// The number of haystack positions a regex engine may scan between checks of
+/// an [`Input`]'s cancellation flag.
+pub(crate) const CANCEL_CHECK_INTERVAL: usize = 1 << 12;
+
 impl<'h> Input<'h> {
     /// Create a new search configuration for the given haystack.
     #[inline]
@@ -120,6 +132,8 @@ impl<'h> Input<'h> {
             span: Span { start: 0, end: haystack.len() },
             anchored: Anchored::No,
             earliest: false,
+            cancel: None,
+            haystack_id: None,
         }
     }
 
@@ -396,6 +410,46 @@ impl<'h> Input<'h> {
         self
     }
 
+    /// Provide a flag that cancels this search once it is set to `true`.
+    ///
+    /// Cancellation is cooperative. Regex engines in this crate check the
+    /// flag periodically while scanning the haystack, and when they observe
+    /// that it has been set, they stop and return a
+    /// [`MatchErrorKind::Cancelled`] error instead of a result. This makes it
+    /// possible to bound the latency of a search over a large haystack, for
+    /// example, by setting the flag from another thread once a deadline has
+    /// passed.
+    ///
+    /// Since cancellation is reported as an error, it is only observable
+    /// through fallible search routines. Infallible search routines panic
+    /// when a search is cancelled.
+    ///
+    /// The flag is only read, never reset, by regex engines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// use regex_automata::{meta::Regex, Input, MatchErrorKind};
+    ///
+    /// let re = Regex::new(r"[a-z]+[0-9]")?;
+    /// let cancel = AtomicBool::new(false);
+    /// let input = Input::new("abc1").cancel_flag(&cancel);
+    /// assert!(re.try_search(&input)?.is_some());
+    ///
+    /// cancel.store(true, Ordering::Relaxed);
+    /// let err = re.try_search(&input).unwrap_err();
+    /// assert!(matches!(err.kind(), MatchErrorKind::Cancelled { .. }));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline]
+    pub fn cancel_flag(mut self, flag: &'h AtomicBool) -> Input<'h> {
+        self.set_cancel_flag(Some(flag));
+        self
+    }
+
     /// Set the span for this search configuration.
     ///
     /// This is like the [`Input::span`] method, except this mutates the
@@ -579,6 +633,29 @@ impl<'h> Input<'h> {
         self.earliest = yes;
     }
 
+    /// Set or clear the flag that cancels this search.
+    ///
+    /// This is like [`Input::cancel_flag`], except it mutates the search
+    /// configuration in place and permits removing a previously set flag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::sync::atomic::AtomicBool;
+    ///
+    /// use regex_automata::Input;
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let mut input = Input::new("foobar");
+    /// assert!(input.get_cancel_flag().is_none());
+    /// input.set_cancel_flag(Some(&cancel));
+    /// assert!(input.get_cancel_flag().is_some());
+    /// ```
+    #[inline]
+    pub fn set_cancel_flag(&mut self, flag: Option<&'h AtomicBool>) {
+        self.cancel = flag;
+    }
+
     /// Return a borrow of the underlying haystack as a slice of bytes.
     ///
     /// # Example
@@ -719,6 +796,110 @@ impl<'h> Input<'h> {
         self.earliest
     }
 
+    /// Return the flag that cancels this search, if one was set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_automata::Input;
+    ///
+    /// let input = Input::new("foobar");
+    /// assert!(input.get_cancel_flag().is_none());
+    /// ```
+    #[inline]
+    pub fn get_cancel_flag(&self) -> Option<&'h AtomicBool> {
+        self.cancel
+    }
+
+    /// Return true if and only if this search has a cancellation flag and it
+    /// has been set.
+    ///
+    /// Regex engines call this periodically during a search and stop with a
+    /// [`MatchErrorKind::Cancelled`] error when it returns true.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// use regex_automata::Input;
+    ///
+    /// let cancel = AtomicBool::new(false);
+    /// let input = Input::new("foobar").cancel_flag(&cancel);
+    /// assert!(!input.is_cancelled());
+    /// cancel.store(true, Ordering::Relaxed);
+    /// assert!(input.is_cancelled());
+    /// ```
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.map_or(false, |flag| flag.load(Ordering::Relaxed))
+    }
+
+    /// Returns the position at which a forward search currently at `at`
+    /// should next check whether it has been cancelled.
+    ///
+    /// This is `at` itself when this search has a cancellation flag, so that
+    /// a search that was cancelled before it started stops right away. When
+    /// there is no flag, this returns a position that a search never reaches.
+    #[inline]
+    pub(crate) fn cancel_check_fwd(&self, at: usize) -> usize {
+        if self.cancel.is_some() {
+            at
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// Returns the position at which a reverse search currently at `at`
+    /// should next check whether it has been cancelled.
+    ///
+    /// This is like `cancel_check_fwd`, except when there is no flag, this
+    /// returns `0`. A reverse search that reaches the beginning of the
+    /// haystack then performs a needless but harmless check.
+    #[inline]
+    pub(crate) fn cancel_check_rev(&self, at: usize) -> usize {
+        if self.cancel.is_some() {
+            at
+        } else {
+            0
+        }
+    }
+
+    /// Assign a fresh identifier to this search's haystack.
+    ///
+    /// Since an `Input` borrows its haystack, the haystack can't change while
+    /// this `Input` or any clone of it is alive. Regex engines may therefore
+    /// reuse anything they computed from the haystack for one search in a
+    /// later search with the same identifier, even if its span differs. This
+    /// is used by iterators, which run many searches over the same haystack,
+    /// so that precomputations for look-around and atomic groups are done
+    /// once per haystack instead of once per match.
+    ///
+    /// No identifier is ever handed out twice. If they run out, or if the
+    /// target doesn't support the necessary atomics, then the `Input` is
+    /// returned without an identifier.
+    pub(crate) fn with_haystack_id(mut self) -> Input<'h> {
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            use core::sync::atomic::AtomicUsize;
+
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            self.haystack_id = COUNTER
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| {
+                    id.checked_add(1)
+                })
+                .ok();
+        }
+        self
+    }
+
+    /// Returns the identifier of this search's haystack, if one was assigned
+    /// by `Input::with_haystack_id`.
+    #[inline]
+    pub(crate) fn get_haystack_id(&self) -> Option<usize> {
+        self.haystack_id
+    }
+
     /// Return true if and only if this search can never return any other
     /// matches.
     ///
@@ -778,6 +959,7 @@ impl<'h> core::fmt::Debug for Input<'h> {
             .field("span", &self.span)
             .field("anchored", &self.anchored)
             .field("earliest", &self.earliest)
+            .field("cancel", &self.cancel)
             .finish()
     }
 }
@@ -1843,6 +2025,25 @@ impl MatchError {
     pub fn unsupported_anchored(mode: Anchored) -> MatchError {
         MatchError::new(MatchErrorKind::UnsupportedAnchored { mode })
     }
+
+    /// Create a new "step limit exceeded" error. The given `limit` corresponds
+    /// to the maximum number of steps the search was permitted to take.
+    ///
+    /// This is the same as calling `MatchError::new` with a
+    /// [`MatchErrorKind::StepLimitExceeded`] kind.
+    pub fn step_limit_exceeded(limit: usize) -> MatchError {
+        MatchError::new(MatchErrorKind::StepLimitExceeded { limit })
+    }
+
+    /// Create a new "cancelled" error. The given `offset` corresponds to the
+    /// location in the haystack at which the search observed that it was
+    /// cancelled.
+    ///
+    /// This is the same as calling `MatchError::new` with a
+    /// [`MatchErrorKind::Cancelled`] kind.
+    pub fn cancelled(offset: usize) -> MatchError {
+        MatchError::new(MatchErrorKind::Cancelled { offset })
+    }
 }
 
 /// The underlying kind of a [`MatchError`].
@@ -1892,6 +2093,23 @@ pub enum MatchErrorKind {
         /// The anchored mode given that is unsupported.
         mode: Anchored,
     },
+    /// This error occurs when a regex engine that may take exponential time
+    /// exceeded its configured budget of steps before the search could
+    /// complete.
+    ///
+    /// Currently, the only way for this to occur is via the backtracking
+    /// engine that supports backreferences, i.e.,
+    /// [`nfa::thompson::backref::Backtracker`](crate::nfa::thompson::backref::Backtracker).
+    StepLimitExceeded {
+        /// The maximum number of steps that the search was permitted to take.
+        limit: usize,
+    },
+    /// This error occurs when the flag set via [`Input::cancel_flag`] was
+    /// observed to be set before the search could complete.
+    Cancelled {
+        /// The offset at which the search stopped.
+        offset: usize,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -1928,6 +2146,12 @@ impl core::fmt::Display for MatchError {
                     pid.as_usize(),
                 )
             }
+            MatchErrorKind::StepLimitExceeded { limit } => {
+                write!(f, "search exceeded step limit of {}", limit)
+            }
+            MatchErrorKind::Cancelled { offset } => {
+                write!(f, "search was cancelled at offset {}", offset)
+            }
         }
     }
 }
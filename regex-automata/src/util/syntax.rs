@@ -16,7 +16,7 @@ use alloc::{vec, vec::Vec};
 use regex_syntax::{
     ast,
     hir::{self, Hir},
-    Error, ParserBuilder,
+    Dialect, Error, ParserBuilder,
 };
 
 /// A convenience routine for parsing a pattern into an HIR value with the
@@ -144,9 +144,12 @@ pub fn parse_many_with<P: AsRef<str>>(
 #[derive(Clone, Copy, Debug)]
 pub struct Config {
     case_insensitive: bool,
+    full_case_folding: bool,
+    turkic_case_folding: bool,
     multi_line: bool,
     dot_matches_new_line: bool,
     crlf: bool,
+    grapheme: bool,
     line_terminator: u8,
     swap_greed: bool,
     ignore_whitespace: bool,
@@ -154,6 +157,10 @@ pub struct Config {
     utf8: bool,
     nest_limit: u32,
     octal: bool,
+    boolean_operators: bool,
+    backreferences: bool,
+    possessive_quantifiers: bool,
+    dialect: Dialect,
 }
 
 impl Config {
@@ -162,9 +169,12 @@ impl Config {
         // These defaults match the ones used in regex-syntax.
         Config {
             case_insensitive: false,
+            full_case_folding: false,
+            turkic_case_folding: false,
             multi_line: false,
             dot_matches_new_line: false,
             crlf: false,
+            grapheme: false,
             line_terminator: b'\n',
             swap_greed: false,
             ignore_whitespace: false,
@@ -172,6 +182,10 @@ impl Config {
             utf8: true,
             nest_limit: 250,
             octal: false,
+            boolean_operators: false,
+            backreferences: false,
+            possessive_quantifiers: false,
+            dialect: Dialect::Default,
         }
     }
 
@@ -188,6 +202,37 @@ impl Config {
         self
     }
 
+    /// Enable or disable full case folding in case insensitive mode.
+    ///
+    /// By default this is disabled, and case insensitive matching uses
+    /// simple case folding. When enabled, a literal or non-negated class
+    /// that contains a codepoint whose full case folding is a sequence of
+    /// codepoints also matches anything that case folds to that sequence.
+    /// For example, `(?i)ß` matches `SS` and `(?i)ﬁ` matches `fi`.
+    /// Conversely, a sequence of case insensitive characters that spells out
+    /// such a case folding also matches the codepoint, so `(?i)ss` matches
+    /// `ß`.
+    ///
+    /// This only has an effect when Unicode mode and case insensitive mode
+    /// are both enabled.
+    pub fn full_case_folding(mut self, yes: bool) -> Config {
+        self.full_case_folding = yes;
+        self
+    }
+
+    /// Enable or disable Turkic tailoring of case folding.
+    ///
+    /// By default this is disabled. When enabled, case insensitive matching
+    /// relates `I` to `ı` and `İ` to `i`, as is done in Turkish and
+    /// Azerbaijani, instead of relating `I` to `i`.
+    ///
+    /// This only has an effect when Unicode mode and case insensitive mode
+    /// are both enabled.
+    pub fn turkic_case_folding(mut self, yes: bool) -> Config {
+        self.turkic_case_folding = yes;
+        self
+    }
+
     /// Enable or disable the multi-line matching flag by default.
     ///
     /// When this is enabled, the `^` and `$` look-around assertions will
@@ -241,6 +286,21 @@ impl Config {
         self
     }
 
+    /// Enable or disable the "grapheme mode" flag by default.
+    ///
+    /// By default this is disabled. It may alternatively be selectively
+    /// enabled in the regular expression itself via the `g` flag.
+    ///
+    /// When grapheme mode is enabled (along with Unicode mode), `.` and
+    /// negated bracketed character classes like `[^a-z]` match an entire
+    /// extended grapheme cluster (just like `\X`) instead of a single
+    /// codepoint. The cluster's first codepoint must be one that would
+    /// otherwise have been matched.
+    pub fn grapheme(mut self, yes: bool) -> Config {
+        self.grapheme = yes;
+        self
+    }
+
     /// Sets the line terminator for use with `(?u-s:.)` and `(?-us:.)`.
     ///
     /// Namely, instead of `.` (by default) matching everything except for `\n`,
@@ -379,6 +439,80 @@ impl Config {
         self
     }
 
+    /// Enable the boolean operators `&` (intersection) and `~` (complement).
+    ///
+    /// When enabled, `a&b` matches a string only if both `a` and `b` match
+    /// it, and `~a` matches every string that `a` does not match. For
+    /// example, `[a-z]+&~(?:.*foo.*)` matches any run of lowercase letters
+    /// that does not contain `foo`.
+    ///
+    /// Both operators are compiled by building a DFA for each operand and
+    /// combining them with a product construction. This requires the
+    /// `dfa-build` crate feature, and can take exponential time in the
+    /// worst case. Capturing groups and look-around assertions are not
+    /// permitted inside either operator.
+    ///
+    /// See [`ParserBuilder::boolean_operators`] for more details.
+    ///
+    /// This is disabled by default.
+    pub fn boolean_operators(mut self, yes: bool) -> Config {
+        self.boolean_operators = yes;
+        self
+    }
+
+    /// Enable backreferences, e.g., `\1` or `\k<name>`.
+    ///
+    /// When enabled, `\N` matches exactly the text most recently matched by
+    /// the capturing group with index `N`, and `\k<name>` does the same for
+    /// the group with the given name. Since this comparison is always exact,
+    /// backreferences are rejected when case insensitive matching is enabled
+    /// for them, e.g., `(?i)(a)\1` is an error.
+    ///
+    /// Backreferences cannot be implemented by finite automata, and so
+    /// almost every regex engine in this crate will refuse to build from a
+    /// pattern that uses them. The exception is the backtracking engine in
+    /// `nfa::thompson::backref`, which requires the `nfa-backref` crate
+    /// feature and which can take exponential time in the worst case.
+    ///
+    /// See [`ParserBuilder::backreferences`] for more details.
+    ///
+    /// This is disabled by default.
+    pub fn backreferences(mut self, yes: bool) -> Config {
+        self.backreferences = yes;
+        self
+    }
+
+    /// Enable possessive repetition operators, e.g., `a*+` or `a{2,}+`.
+    ///
+    /// A possessive repetition matches as much as it can and never gives
+    /// any of it back, just like the equivalent atomic group `(?>a*)`.
+    /// Atomic groups are always supported. Only the PikeVM and the
+    /// backtracker in `nfa::thompson::backref` can execute them, so the
+    /// other regex engines will refuse to build from a pattern that uses
+    /// either one.
+    ///
+    /// See [`ParserBuilder::possessive_quantifiers`] for more details.
+    ///
+    /// This is disabled by default.
+    pub fn possessive_quantifiers(mut self, yes: bool) -> Config {
+        self.possessive_quantifiers = yes;
+        self
+    }
+
+    /// Set the dialect of concrete syntax accepted by the parser.
+    ///
+    /// The PCRE dialect accepts syntax like `\Q...\E`, `\h`, `\R`, `\Z`
+    /// and `(?#comment)` from PCRE and Oniguruma, and reports precise errors
+    /// for PCRE features that aren't supported, such as recursion.
+    ///
+    /// See [`ParserBuilder::dialect`] for more details.
+    ///
+    /// This is set to `Dialect::Default` by default.
+    pub fn dialect(mut self, dialect: Dialect) -> Config {
+        self.dialect = dialect;
+        self
+    }
+
     /// Returns whether "unicode" mode is enabled.
     pub fn get_unicode(&self) -> bool {
         self.unicode
@@ -389,6 +523,16 @@ impl Config {
         self.case_insensitive
     }
 
+    /// Returns whether full case folding is enabled.
+    pub fn get_full_case_folding(&self) -> bool {
+        self.full_case_folding
+    }
+
+    /// Returns whether Turkic tailoring of case folding is enabled.
+    pub fn get_turkic_case_folding(&self) -> bool {
+        self.turkic_case_folding
+    }
+
     /// Returns whether "multi line" mode is enabled.
     pub fn get_multi_line(&self) -> bool {
         self.multi_line
@@ -404,6 +548,11 @@ impl Config {
         self.crlf
     }
 
+    /// Returns whether "grapheme" mode is enabled.
+    pub fn get_grapheme(&self) -> bool {
+        self.grapheme
+    }
+
     /// Returns the line terminator in this syntax configuration.
     pub fn get_line_terminator(&self) -> u8 {
         self.line_terminator
@@ -434,20 +583,47 @@ impl Config {
         self.octal
     }
 
+    /// Returns whether the boolean operators `&` and `~` are enabled.
+    pub fn get_boolean_operators(&self) -> bool {
+        self.boolean_operators
+    }
+
+    /// Returns whether backreferences are enabled.
+    pub fn get_backreferences(&self) -> bool {
+        self.backreferences
+    }
+
+    /// Returns whether possessive repetition operators are enabled.
+    pub fn get_possessive_quantifiers(&self) -> bool {
+        self.possessive_quantifiers
+    }
+
+    /// Returns the dialect of concrete syntax accepted by the parser.
+    pub fn get_dialect(&self) -> Dialect {
+        self.dialect
+    }
+
     /// Applies this configuration to the given parser.
     pub(crate) fn apply(&self, builder: &mut ParserBuilder) {
         builder
             .unicode(self.unicode)
             .case_insensitive(self.case_insensitive)
+            .full_case_folding(self.full_case_folding)
+            .turkic_case_folding(self.turkic_case_folding)
             .multi_line(self.multi_line)
             .dot_matches_new_line(self.dot_matches_new_line)
             .crlf(self.crlf)
+            .grapheme(self.grapheme)
             .line_terminator(self.line_terminator)
             .swap_greed(self.swap_greed)
             .ignore_whitespace(self.ignore_whitespace)
             .utf8(self.utf8)
             .nest_limit(self.nest_limit)
-            .octal(self.octal);
+            .octal(self.octal)
+            .boolean_operators(self.boolean_operators)
+            .backreferences(self.backreferences)
+            .possessive_quantifiers(self.possessive_quantifiers)
+            .dialect(self.dialect);
     }
 
     /// Applies this configuration to the given AST parser.
@@ -455,7 +631,11 @@ impl Config {
         builder
             .ignore_whitespace(self.ignore_whitespace)
             .nest_limit(self.nest_limit)
-            .octal(self.octal);
+            .octal(self.octal)
+            .boolean_operators(self.boolean_operators)
+            .backreferences(self.backreferences)
+            .possessive_quantifiers(self.possessive_quantifiers)
+            .dialect(self.dialect);
     }
 
     /// Applies this configuration to the given AST-to-HIR translator.
@@ -466,8 +646,11 @@ impl Config {
         builder
             .unicode(self.unicode)
             .case_insensitive(self.case_insensitive)
+            .full_case_folding(self.full_case_folding)
+            .turkic_case_folding(self.turkic_case_folding)
             .multi_line(self.multi_line)
             .crlf(self.crlf)
+            .grapheme(self.grapheme)
             .dot_matches_new_line(self.dot_matches_new_line)
             .line_terminator(self.line_terminator)
             .swap_greed(self.swap_greed)
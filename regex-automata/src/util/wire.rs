@@ -436,7 +436,8 @@ pub(crate) fn read_label(
             ));
         }
     };
-    let len = first_nul + padding_len(first_nul);
+    // +1 for the NUL terminator, which is included in the padded length.
+    let len = (first_nul + 1) + padding_len(first_nul + 1);
     if slice.len() < len {
         return Err(DeserializeError::generic(
             "could not find properly sized label at start of serialized object"
@@ -848,6 +849,343 @@ pub(crate) fn padding_len(non_padding_len: usize) -> usize {
     (4 - (non_padding_len & 0b11)) & 0b11
 }
 
+/// A growable buffer for serializing objects whose size isn't known up front.
+///
+/// Unlike the DFAs in this crate, some objects (like NFAs and meta regexes)
+/// are made up of many variable length pieces that are decoded into their
+/// own heap allocations. Computing their serialized size ahead of time would
+/// require duplicating most of the serialization logic, so this just appends
+/// to a `Vec<u8>` instead.
+///
+/// Every integer is written with the endianness chosen at construction. Every
+/// `usize` is written as a `u64`.
+///
+/// The endianness is chosen at runtime (instead of being a type parameter)
+/// so that this can be passed through trait objects.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub(crate) struct Writer {
+    buf: Vec<u8>,
+    little_endian: bool,
+    write_u32: fn(u32, &mut [u8]),
+    write_u64: fn(u64, &mut [u8]),
+}
+
+#[cfg(feature = "alloc")]
+impl Writer {
+    /// Create a new empty writer that writes integers with the endianness
+    /// `E`.
+    pub(crate) fn new<E: Endian>() -> Writer {
+        let mut one = [0; 2];
+        E::write_u16(1, &mut one);
+        Writer {
+            buf: vec![],
+            little_endian: one[0] == 1,
+            write_u32: E::write_u32,
+            write_u64: E::write_u64,
+        }
+    }
+
+    /// Returns true if and only if this writer writes integers in little
+    /// endian format.
+    ///
+    /// This is useful for embedding objects (like dense DFAs) that provide
+    /// their own serialization routines for each endianness.
+    pub(crate) fn is_little_endian(&self) -> bool {
+        self.little_endian
+    }
+
+    /// Return the bytes written so far.
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Return the bytes written so far in a buffer whose contents start at
+    /// an address aligned to a `u32`, along with the number of padding bytes
+    /// at the beginning of the buffer that precede the contents.
+    ///
+    /// This is required for the alignment of anything written with
+    /// [`Writer::aligned_bytes`] to carry over to the buffer returned.
+    pub(crate) fn into_aligned_bytes(self) -> (Vec<u8>, usize) {
+        let (mut buf, pad) = alloc_aligned_buffer::<u32>(self.buf.len());
+        buf[pad..].copy_from_slice(&self.buf);
+        (buf, pad)
+    }
+
+    /// Write a NUL terminated label, an endianness check and a version
+    /// number. This is meant to be read back with [`Reader::header`].
+    pub(crate) fn header(&mut self, label: &str, version: u32) {
+        let start = self.buf.len();
+        self.buf.resize(start + write_label_len(label), 0);
+        // OK because we just made sure there's enough room, and the labels
+        // used in this crate are always valid.
+        write_label(label, &mut self.buf[start..]).unwrap();
+        self.u32(0xFEFF);
+        self.u32(version);
+    }
+
+    pub(crate) fn u8(&mut self, n: u8) {
+        self.buf.push(n);
+    }
+
+    pub(crate) fn bool(&mut self, yes: bool) {
+        self.u8(u8::from(yes));
+    }
+
+    pub(crate) fn u32(&mut self, n: u32) {
+        let start = self.buf.len();
+        self.buf.resize(start + size_of::<u32>(), 0);
+        (self.write_u32)(n, &mut self.buf[start..]);
+    }
+
+    pub(crate) fn u64(&mut self, n: u64) {
+        let start = self.buf.len();
+        self.buf.resize(start + size_of::<u64>(), 0);
+        (self.write_u64)(n, &mut self.buf[start..]);
+    }
+
+    pub(crate) fn usize(&mut self, n: usize) {
+        // OK because we never support targets with more than 64-bit
+        // pointers.
+        self.u64(u64::try_from(n).unwrap());
+    }
+
+    pub(crate) fn option_usize(&mut self, n: Option<usize>) {
+        self.bool(n.is_some());
+        self.usize(n.unwrap_or(0));
+    }
+
+    pub(crate) fn state_id(&mut self, sid: StateID) {
+        self.u32(sid.as_u32());
+    }
+
+    pub(crate) fn pattern_id(&mut self, pid: PatternID) {
+        self.u32(pid.as_u32());
+    }
+
+    /// Write a length prefixed sequence of bytes.
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        self.usize(bytes.len());
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Write a length prefixed sequence of bytes, followed by as many NUL
+    /// bytes as are needed for the sequence to start at an offset that is a
+    /// multiple of 4.
+    ///
+    /// This is used for embedding objects (like dense DFAs) that can be
+    /// deserialized without copying, since those require their bytes to be
+    /// aligned to a `u32`.
+    pub(crate) fn aligned_bytes(&mut self, bytes: &[u8]) {
+        self.usize(bytes.len());
+        let pad = padding_len(self.buf.len());
+        self.buf.resize(self.buf.len() + pad, 0);
+        self.buf.extend_from_slice(bytes);
+    }
+}
+
+/// A cursor for deserializing objects written by a [`Writer`].
+///
+/// Every read is bounds checked and returns an error when the underlying
+/// slice is too short. Like the other deserialization routines in this
+/// module, integers are always read in native endianness.
+#[derive(Debug)]
+pub(crate) struct Reader<'a> {
+    slice: &'a [u8],
+    nread: usize,
+    /// The entire slice being read, when it lives for the `'static`
+    /// lifetime. This permits borrowing the bytes read with
+    /// [`Reader::aligned_bytes`] for the `'static` lifetime.
+    whole: Option<&'static [u8]>,
+}
+
+/// A sequence of bytes read by [`Reader::aligned_bytes`].
+#[derive(Debug)]
+pub(crate) enum AlignedBytes<'a> {
+    /// The bytes live for the `'static` lifetime and are aligned to a `u32`.
+    Static(&'static [u8]),
+    /// The bytes are borrowed from a slice that doesn't live for the
+    /// `'static` lifetime. They may not be aligned.
+    Borrowed(&'a [u8]),
+}
+
+impl<'a> Reader<'a> {
+    /// Create a new reader that starts at the beginning of the given slice.
+    pub(crate) fn new(slice: &'a [u8]) -> Reader<'a> {
+        Reader { slice, nread: 0, whole: None }
+    }
+
+    /// Create a new reader that starts at the beginning of the given slice,
+    /// where the slice lives for the `'static` lifetime and is aligned to a
+    /// `u32`.
+    ///
+    /// Anything read with [`Reader::aligned_bytes`] is borrowed from the
+    /// slice given instead of from the reader.
+    pub(crate) fn new_static(
+        slice: &'static [u8],
+    ) -> Result<Reader<'static>, DeserializeError> {
+        check_alignment::<u32>(slice)?;
+        Ok(Reader { slice, nread: 0, whole: Some(slice) })
+    }
+
+    /// Returns the total number of bytes read so far.
+    pub(crate) fn nread(&self) -> usize {
+        self.nread
+    }
+
+    /// Read and check a header written by [`Writer::header`].
+    pub(crate) fn header(
+        &mut self,
+        label: &'static str,
+        version: u32,
+    ) -> Result<(), DeserializeError> {
+        let nr = read_label(self.slice, label)?;
+        self.advance(nr);
+        let nr = read_endianness_check(self.slice)?;
+        self.advance(nr);
+        let nr = read_version(self.slice, version)?;
+        self.advance(nr);
+        Ok(())
+    }
+
+    pub(crate) fn u8(
+        &mut self,
+        what: &'static str,
+    ) -> Result<u8, DeserializeError> {
+        check_slice_len(self.slice, 1, what)?;
+        let n = self.slice[0];
+        self.advance(1);
+        Ok(n)
+    }
+
+    pub(crate) fn bool(
+        &mut self,
+        what: &'static str,
+    ) -> Result<bool, DeserializeError> {
+        match self.u8(what)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(DeserializeError::generic("invalid boolean value")),
+        }
+    }
+
+    pub(crate) fn u32(
+        &mut self,
+        what: &'static str,
+    ) -> Result<u32, DeserializeError> {
+        let (n, nr) = try_read_u32(self.slice, what)?;
+        self.advance(nr);
+        Ok(n)
+    }
+
+    pub(crate) fn u64(
+        &mut self,
+        what: &'static str,
+    ) -> Result<u64, DeserializeError> {
+        check_slice_len(self.slice, size_of::<u64>(), what)?;
+        let bytes: [u8; 8] =
+            self.slice[..size_of::<u64>()].try_into().unwrap();
+        self.advance(size_of::<u64>());
+        Ok(u64::from_ne_bytes(bytes))
+    }
+
+    pub(crate) fn usize(
+        &mut self,
+        what: &'static str,
+    ) -> Result<usize, DeserializeError> {
+        usize::try_from(self.u64(what)?)
+            .map_err(|_| DeserializeError::invalid_usize(what))
+    }
+
+    pub(crate) fn option_usize(
+        &mut self,
+        what: &'static str,
+    ) -> Result<Option<usize>, DeserializeError> {
+        let is_some = self.bool(what)?;
+        let n = self.usize(what)?;
+        Ok(if is_some { Some(n) } else { None })
+    }
+
+    /// Read a length that counts items that each occupy at least
+    /// `min_item_size` bytes in the remaining input.
+    ///
+    /// This returns an error if there aren't enough bytes remaining for that
+    /// many items. This is useful for rejecting bogus lengths before using
+    /// them to allocate memory.
+    pub(crate) fn len(
+        &mut self,
+        min_item_size: usize,
+        what: &'static str,
+    ) -> Result<usize, DeserializeError> {
+        let len = self.usize(what)?;
+        check_slice_len(self.slice, mul(len, min_item_size, what)?, what)?;
+        Ok(len)
+    }
+
+    pub(crate) fn state_id(
+        &mut self,
+        what: &'static str,
+    ) -> Result<StateID, DeserializeError> {
+        let (sid, nr) = try_read_state_id(self.slice, what)?;
+        self.advance(nr);
+        Ok(sid)
+    }
+
+    pub(crate) fn pattern_id(
+        &mut self,
+        what: &'static str,
+    ) -> Result<PatternID, DeserializeError> {
+        check_slice_len(self.slice, PatternID::SIZE, what)?;
+        let (pid, nr) = read_pattern_id(self.slice, what)?;
+        self.advance(nr);
+        Ok(pid)
+    }
+
+    /// Read a length prefixed sequence of bytes.
+    pub(crate) fn bytes(
+        &mut self,
+        what: &'static str,
+    ) -> Result<&'a [u8], DeserializeError> {
+        let len = self.len(1, what)?;
+        let bytes = &self.slice[..len];
+        self.advance(len);
+        Ok(bytes)
+    }
+
+    /// Read a length prefixed sequence of bytes written by
+    /// [`Writer::aligned_bytes`].
+    ///
+    /// When this reader was created by [`Reader::new_static`], the bytes are
+    /// borrowed for the `'static` lifetime and are guaranteed to be aligned
+    /// to a `u32`.
+    pub(crate) fn aligned_bytes(
+        &mut self,
+        what: &'static str,
+    ) -> Result<AlignedBytes<'a>, DeserializeError> {
+        let len = self.usize(what)?;
+        let pad = padding_len(self.nread);
+        check_slice_len(self.slice, pad, what)?;
+        if self.slice[..pad].iter().any(|&b| b != 0) {
+            return Err(DeserializeError::generic("invalid padding bytes"));
+        }
+        self.advance(pad);
+        check_slice_len(self.slice, len, what)?;
+        let bytes = match self.whole {
+            None => AlignedBytes::Borrowed(&self.slice[..len]),
+            Some(whole) => {
+                AlignedBytes::Static(&whole[self.nread..self.nread + len])
+            }
+        };
+        self.advance(len);
+        Ok(bytes)
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.slice = &self.slice[n..];
+        self.nread += n;
+    }
+}
+
 /// A simple trait for writing code generic over endianness.
 ///
 /// This is similar to what byteorder provides, but we only need a very small
@@ -863,6 +1201,11 @@ pub(crate) trait Endian {
     /// this panics.
     fn write_u32(n: u32, dst: &mut [u8]);
 
+    /// Writes a u64 to the given destination buffer in a particular
+    /// endianness. If the destination buffer has a length smaller than 8, then
+    /// this panics.
+    fn write_u64(n: u64, dst: &mut [u8]);
+
     /// Writes a u128 to the given destination buffer in a particular
     /// endianness. If the destination buffer has a length smaller than 16,
     /// then this panics.
@@ -888,6 +1231,10 @@ impl Endian for LE {
         dst[..4].copy_from_slice(&n.to_le_bytes());
     }
 
+    fn write_u64(n: u64, dst: &mut [u8]) {
+        dst[..8].copy_from_slice(&n.to_le_bytes());
+    }
+
     fn write_u128(n: u128, dst: &mut [u8]) {
         dst[..16].copy_from_slice(&n.to_le_bytes());
     }
@@ -902,6 +1249,10 @@ impl Endian for BE {
         dst[..4].copy_from_slice(&n.to_be_bytes());
     }
 
+    fn write_u64(n: u64, dst: &mut [u8]) {
+        dst[..8].copy_from_slice(&n.to_be_bytes());
+    }
+
     fn write_u128(n: u128, dst: &mut [u8]) {
         dst[..16].copy_from_slice(&n.to_be_bytes());
     }
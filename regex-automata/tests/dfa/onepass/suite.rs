@@ -55,8 +55,44 @@ fn no_byte_classes() -> Result<()> {
     Ok(())
 }
 
+/// Tests the one-pass DFA after round tripping it through its serialized
+/// form. Start states for each pattern are enabled so that they are
+/// serialized too.
+#[test]
+fn serialization() -> Result<()> {
+    let mut builder = DFA::builder();
+    builder.configure(DFA::config().starts_for_each_pattern(true));
+    TestRunner::new()?
+        .expand(EXPANSIONS, |t| t.compiles())
+        .test_iter(suite()?.iter(), compiler_serialized(builder))
+        .assert();
+    Ok(())
+}
+
 fn compiler(
+    builder: onepass::Builder,
+) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
+    compiler_map(builder, Ok)
+}
+
+/// Like `compiler`, but serializes and then deserializes each DFA before
+/// searching with it.
+fn compiler_serialized(
+    builder: onepass::Builder,
+) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
+    compiler_map(builder, |re| {
+        let bytes = re.to_bytes_native_endian();
+        let (re, nread) = DFA::from_bytes(&bytes)?;
+        assert_eq!(bytes.len(), nread);
+        Ok(re)
+    })
+}
+
+/// Builds a one-pass DFA for each test and then passes it through `map`
+/// before searching with it.
+fn compiler_map(
     mut builder: onepass::Builder,
+    mut map: impl FnMut(DFA) -> Result<DFA>,
 ) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
     move |test, regexes| {
         // Check if our regex contains things that aren't supported by DFAs.
@@ -85,6 +121,7 @@ fn compiler(
                 return Err(err.into());
             }
         };
+        let re = map(re)?;
         let mut cache = re.create_cache();
         Ok(CompiledRegex::compiled(move |test| -> TestResult {
             run_test(&re, &mut cache, test)
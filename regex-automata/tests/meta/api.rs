@@ -0,0 +1,651 @@
+use regex_automata::{meta::Regex, Input, Match};
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Serializes the given regex into a leaked buffer, such that it can be
+/// deserialized without copying, and returns the serialized bytes.
+fn to_static_bytes(re: &Regex) -> &'static [u8] {
+    let (bytes, pad) = re.to_bytes_native_endian();
+    &bytes.leak()[pad..]
+}
+
+/// Round trips the given regex through its serialized form and checks that
+/// the deserialized regex finds the same matches in the given haystack.
+fn roundtrip(re: &Regex, haystack: &str) -> Result<()> {
+    let bytes = to_static_bytes(re);
+    let (re2, nread) = Regex::from_bytes(bytes)?;
+    assert_eq!(bytes.len(), nread);
+    roundtrip_check(re, &re2, haystack);
+    let (re2, nread) = Regex::from_bytes_owned(bytes)?;
+    assert_eq!(bytes.len(), nread);
+    roundtrip_check(re, &re2, haystack);
+    Ok(())
+}
+
+fn roundtrip_check(re: &Regex, re2: &Regex, haystack: &str) {
+    let expected: Vec<Match> = re.find_iter(haystack).collect();
+    let got: Vec<Match> = re2.find_iter(haystack).collect();
+    assert_eq!(expected, got);
+    assert_eq!(re.captures_len(), re2.captures_len());
+    assert_eq!(re.static_captures_len(), re2.static_captures_len());
+    assert_eq!(
+        re.get_config().get_match_kind(),
+        re2.get_config().get_match_kind()
+    );
+}
+
+// Tests that each of the meta regex engine's strategies survives a round
+// trip through its serialized form.
+#[test]
+fn serialize_strategies() -> Result<()> {
+    let hay = "foo bar quux 123 foobar Sherlock Holmes xyz@example.com\n";
+    // A pure literal search.
+    roundtrip(&Regex::new("foo|bar")?, hay)?;
+    // A large alternation of literals, which uses Aho-Corasick.
+    let words: Vec<String> = (0..500).map(|i| format!("w{}x", i)).collect();
+    roundtrip(&Regex::new(&words.join("|"))?, "w1x w499x w500x")?;
+    // The core engine.
+    roundtrip(&Regex::new(r"(\w+)\s+(\w+)")?, hay)?;
+    // Reverse anchored.
+    roundtrip(&Regex::new(r"[a-z]+\n$")?, hay)?;
+    // Reverse suffix.
+    roundtrip(&Regex::new(r"[a-z]+Holmes")?, "SherlockHolmes")?;
+    // Reverse inner.
+    roundtrip(&Regex::new(r"\w+@\w+\.com")?, hay)?;
+    // Backreferences.
+    #[cfg(feature = "nfa-backref")]
+    {
+        let re = Regex::builder()
+            .configure(Regex::config().backreferences(true))
+            .build(r"(\w)\1")?;
+        roundtrip(&re, "foo bar quux 1233")?;
+    }
+    // Multiple patterns with All semantics.
+    let re = Regex::builder()
+        .configure(Regex::config().match_kind(regex_automata::MatchKind::All))
+        .build_many(&[r"\w+", r"\d+"])?;
+    let (re2, _) = Regex::from_bytes(to_static_bytes(&re))?;
+    let mut patset1 = regex_automata::PatternSet::new(2);
+    let mut patset2 = regex_automata::PatternSet::new(2);
+    re.which_overlapping_matches(&Input::new(hay), &mut patset1);
+    re2.which_overlapping_matches(&Input::new(hay), &mut patset2);
+    assert_eq!(patset1, patset2);
+    Ok(())
+}
+
+// Tests that every truncation of a serialized regex is rejected.
+#[test]
+fn deserialize_truncated() -> Result<()> {
+    let re = Regex::new(r"(?<word>[a-z]+)[0-9]+|\bfoo(?=bar)")?;
+    let bytes = to_static_bytes(&re);
+    for i in 0..bytes.len() {
+        assert!(Regex::from_bytes(&bytes[..i]).is_err(), "length {}", i);
+        assert!(Regex::from_bytes_owned(&bytes[..i]).is_err(), "length {}", i);
+    }
+    Ok(())
+}
+
+// Tests that deserializing without copying requires aligned bytes, while
+// deserializing by copying works with bytes at any alignment.
+#[test]
+fn deserialize_alignment() -> Result<()> {
+    // A small regex like this one uses fully compiled DFAs, whose tables
+    // are borrowed when deserializing without copying.
+    let re = Regex::new(r"[a-z]+[0-9]+")?;
+    let (bytes, pad) = re.to_bytes_native_endian();
+    let mut unaligned = vec![b'x'];
+    unaligned.extend_from_slice(&bytes[pad..]);
+    let unaligned: &'static [u8] = unaligned.leak();
+    if unaligned.as_ptr() as usize % 4 == 1 {
+        assert!(Regex::from_bytes(&unaligned[1..]).is_err());
+    }
+    let (re2, nread) = Regex::from_bytes_owned(&unaligned[1..])?;
+    assert_eq!(bytes.len() - pad, nread);
+    assert_eq!(Some(Match::must(0, 4..10)), re2.find("123 abc123"));
+
+    let (re2, nread) = Regex::from_bytes(to_static_bytes(&re))?;
+    assert_eq!(bytes.len() - pad, nread);
+    assert_eq!(Some(Match::must(0, 4..10)), re2.find("123 abc123"));
+    Ok(())
+}
+
+// Tests that corrupting any single byte of a serialized regex never leads to
+// a panic while deserializing. (It might still succeed, since not every byte
+// is meaningful.)
+#[test]
+fn deserialize_corrupted() -> Result<()> {
+    let re = Regex::new(r"(a|b)+\w(?<=b)")?;
+    let (bytes, pad) = re.to_bytes_native_endian();
+    for i in pad..bytes.len() {
+        let mut corrupt = bytes.clone();
+        corrupt[i] = corrupt[i].wrapping_add(1);
+        let _ = Regex::from_bytes_owned(&corrupt[pad..]);
+    }
+    Ok(())
+}
+
+// Tests that approximate matching bypasses the optimizations that only work
+// for exact matching, and that it survives a round trip through the
+// serialized form.
+#[test]
+fn approximate() -> Result<()> {
+    let config = Regex::config().max_edits(Some(1));
+    // Without approximate matching, this would be handled by a literal
+    // search alone.
+    let re = Regex::builder().configure(config.clone()).build("foo|bar")?;
+    assert_eq!(Some(Match::must(0, 4..7)), re.find("xyz fxo"));
+    roundtrip(&re, "foo fxo bar br baz")?;
+    let (re2, _) = Regex::from_bytes(to_static_bytes(&re))?;
+    assert_eq!(Some(1), re2.get_config().get_max_edits());
+
+    // The haystacks here are too short and too long for an exact match, which
+    // would otherwise be detected before running any regex engine.
+    let re = Regex::builder().configure(config.clone()).build("^abc$")?;
+    assert_eq!(Some(Match::must(0, 0..2)), re.find("ab"));
+    assert_eq!(Some(Match::must(0, 0..4)), re.find("abxc"));
+    assert_eq!(None, re.find("xabcx"));
+    // But anchors still must match exactly.
+    assert_eq!(None, re.find(Input::new("zabc").range(1..)));
+
+    // The leftmost match is preferred over one with fewer edits.
+    let re = Regex::builder().configure(config).build(r"[0-9]{3}-[0-9]{4}")?;
+    let hay = "call 555-12x4 or 555-1234";
+    assert_eq!(Some(Match::must(0, 5..13)), re.find(hay));
+    let got: Vec<Match> = re.find_iter(hay).collect();
+    assert_eq!(got, vec![Match::must(0, 5..13), Match::must(0, 16..25)]);
+
+    // Each search stops shortly after its match instead of scanning the rest
+    // of the haystack for a better one, so finding every match in a long
+    // haystack takes linear time.
+    let re = Regex::builder()
+        .configure(Regex::config().max_edits(Some(1)))
+        .build("needle")?;
+    let hay = "neeble ".repeat(50_000);
+    assert_eq!(50_000, re.find_iter(&hay).count());
+    Ok(())
+}
+
+// Tests that intersection and complement work with each of the regex engines
+// used by the meta regex engine, since they are compiled into the NFA that
+// every engine is built from.
+#[test]
+fn boolean_operators() -> Result<()> {
+    use regex_automata::util::syntax;
+
+    let syntax = syntax::Config::new().boolean_operators(true);
+    let configs = [
+        Regex::config(),
+        Regex::config().hybrid(false),
+        Regex::config().hybrid(false).dfa(false),
+        Regex::config().hybrid(false).dfa(false).onepass(false),
+        Regex::config()
+            .hybrid(false)
+            .dfa(false)
+            .onepass(false)
+            .backtrack(false),
+    ];
+    for config in configs {
+        let build = |pattern: &str| {
+            Regex::builder()
+                .configure(config.clone())
+                .syntax(syntax)
+                .build(pattern)
+        };
+
+        let re = build(r"\b(?:[a-z]+&~(?:.*foo.*))\b")?;
+        let got: Vec<Match> = re.find_iter("xfooy abc foo barbaz").collect();
+        assert_eq!(got, vec![Match::must(0, 6..9), Match::must(0, 14..20)]);
+
+        // Capturing groups outside of boolean operators are fine.
+        let re = build(r"([0-9]+)-(?:[a-z]+&~(?:cat))\b")?;
+        let mut caps = re.create_captures();
+        re.captures("1-cat 22-dog", &mut caps);
+        assert_eq!(Some(Match::must(0, 6..12)), caps.get_match());
+        assert_eq!(Some((6..8).into()), caps.get_group(1));
+
+        // A double complement is the same as no complement.
+        let re = build(r"~~(?:ab)")?;
+        assert_eq!(Some(Match::must(0, 1..3)), re.find("xab"));
+
+        // An empty-matching complement.
+        let re = build(r"^(?:~(?:a+))$")?;
+        assert!(re.is_match(""));
+        assert!(re.is_match("ab"));
+        assert!(!re.is_match("aaa"));
+
+        assert!(build(r"a&(b)").is_err());
+        assert!(build(r"a&(?:^a)").is_err());
+        assert!(build(r"~(?:\b)").is_err());
+    }
+    Ok(())
+}
+
+// Tests that backreferences are only supported when enabled, and that a
+// search exceeding its step limit is reported as an error.
+#[cfg(feature = "nfa-backref")]
+#[test]
+fn backreferences() -> Result<()> {
+    use regex_automata::{MatchErrorKind, PatternSet};
+
+    // Without the option, backreferences are a syntax error.
+    assert!(Regex::new(r"(a)\1").is_err());
+
+    let config = Regex::config().backreferences(true);
+    let build = |pattern: &str| {
+        Regex::builder().configure(config.clone()).build(pattern)
+    };
+
+    // Regexes without backreferences are unaffected.
+    let re = build(r"[a-z]+")?;
+    assert_eq!(Some(Match::must(0, 1..4)), re.find("1abc2"));
+
+    let re = build(r"(?<tag>\w+)>.*?</\k<tag>")?;
+    assert_eq!(Some(Match::must(0, 1..12)), re.find("<b>x</i>y</b>z"));
+    let mut caps = re.create_captures();
+    re.captures("<i>x</i>", &mut caps);
+    assert_eq!(Some((1..2).into()), caps.get_group_by_name("tag"));
+
+    let re = Regex::builder()
+        .configure(config.clone())
+        .build_many(&[r"(\d)\1", r"(\w)\1"])?;
+    let mut patset = PatternSet::new(re.pattern_len());
+    re.which_overlapping_matches(&Input::new("x 11"), &mut patset);
+    assert_eq!(2, patset.len());
+
+    // Backreferences need all capturing groups.
+    use regex_automata::nfa::thompson::WhichCaptures;
+    let result = Regex::builder()
+        .configure(config.clone().which_captures(WhichCaptures::Implicit))
+        .build(r"(a)\1");
+    assert!(result.is_err());
+
+    let re = Regex::builder()
+        .configure(config.clone().backref_step_limit(Some(100)))
+        .build(r"(a*)*b\1")?;
+    let hay = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    let err = re.try_search(&Input::new(hay)).unwrap_err();
+    assert_eq!(&MatchErrorKind::StepLimitExceeded { limit: 100 }, err.kind());
+    assert_eq!(Ok(None), re.try_search(&Input::new("")));
+    Ok(())
+}
+
+// Tests that atomic groups and possessive repetitions give the same results
+// in every regex engine that supports them, and that the other regex engines
+// refuse to build from them.
+#[test]
+fn atomic() -> Result<()> {
+    use regex_automata::{
+        dfa::{dense, onepass},
+        hybrid,
+        nfa::thompson::{backtrack, pikevm},
+        util::syntax,
+    };
+
+    let syntax = syntax::Config::new().possessive_quantifiers(true);
+    // Without the option, 'a*+' is a repetition of a repetition.
+    assert_eq!(Some(Match::must(0, 0..3)), Regex::new(r"a*+a")?.find("aaa"));
+
+    let tests: &[(&str, &str, &[Match])] = &[
+        (r"a*+a", "aaa", &[]),
+        (r"a++b", "aab b", &[Match::must(0, 0..3)]),
+        (r"a?+a", "a aa", &[Match::must(0, 2..4)]),
+        (r"a{1,2}+a", "aa aaa", &[Match::must(0, 3..6)]),
+        (r"\d++\.", "12. 3", &[Match::must(0, 0..3)]),
+        (r"(?>foobar|foo)bar", "foobar foobarbar", &[Match::must(0, 7..16)]),
+    ];
+    for &(pattern, hay, expected) in tests {
+        let re = Regex::builder().syntax(syntax).build(pattern)?;
+        let got: Vec<Match> = re.find_iter(hay).collect();
+        assert_eq!(expected, &*got, "meta: {}", pattern);
+        roundtrip(&re, hay)?;
+
+        let re = pikevm::PikeVM::builder().syntax(syntax).build(pattern)?;
+        let mut cache = re.create_cache();
+        let got: Vec<Match> = re.find_iter(&mut cache, hay).collect();
+        assert_eq!(expected, &*got, "pikevm: {}", pattern);
+
+        #[cfg(feature = "nfa-backref")]
+        {
+            use regex_automata::nfa::thompson::backref::Backtracker;
+
+            let re = Backtracker::builder().syntax(syntax).build(pattern)?;
+            let mut cache = re.create_cache();
+            let got: Vec<Match> = re
+                .try_find_iter(&mut cache, hay)
+                .collect::<std::result::Result<_, _>>()?;
+            assert_eq!(expected, &*got, "backref: {}", pattern);
+        }
+
+        assert!(dense::Builder::new().syntax(syntax).build(pattern).is_err());
+        assert!(hybrid::dfa::Builder::new()
+            .syntax(syntax)
+            .build(pattern)
+            .is_err());
+        assert!(onepass::Builder::new()
+            .syntax(syntax)
+            .build(pattern)
+            .is_err());
+        assert!(backtrack::BoundedBacktracker::builder()
+            .syntax(syntax)
+            .build(pattern)
+            .is_err());
+    }
+
+    // Atomic groups and backreferences can be used together.
+    #[cfg(feature = "nfa-backref")]
+    {
+        let re = Regex::builder()
+            .configure(Regex::config().backreferences(true))
+            .syntax(syntax)
+            .build(r"(\w)\1++\w")?;
+        assert_eq!(Some(Match::must(0, 4..8)), re.find("aaa bbbc"));
+    }
+    Ok(())
+}
+
+// Tests that a search is cancelled by a flag on its input in every regex
+// engine, and that an unset flag doesn't change any search results.
+#[test]
+fn cancellation() -> Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use regex_automata::{
+        dfa::{dense, onepass, regex::Regex as DenseRegex, Automaton},
+        hybrid,
+        nfa::thompson::{backtrack, pikevm},
+        Anchored, MatchErrorKind,
+    };
+
+    let cancel = AtomicBool::new(false);
+    // A haystack long enough that every engine checks the flag many times.
+    let mut hay = "abc ".repeat(5_000);
+    hay.push_str("foo123");
+    let expected = Some(Match::must(0, 20_000..20_006));
+    let configs = [
+        Regex::config(),
+        Regex::config().hybrid(false),
+        Regex::config().hybrid(false).dfa(false),
+        Regex::config().hybrid(false).dfa(false).backtrack(false),
+    ];
+    for config in configs {
+        let re = Regex::builder().configure(config).build(r"foo[0-9]+")?;
+        let input = Input::new(&hay).cancel_flag(&cancel);
+        cancel.store(false, Ordering::Relaxed);
+        assert_eq!(expected, re.try_search(&input)?);
+        let mut caps = re.create_captures();
+        re.search_captures(&input, &mut caps);
+        assert_eq!(expected, caps.get_match());
+
+        cancel.store(true, Ordering::Relaxed);
+        let err = re.try_search(&input).unwrap_err();
+        assert!(matches!(*err.kind(), MatchErrorKind::Cancelled { .. }));
+        let mut cache = re.create_cache();
+        assert!(re
+            .try_search_captures_with(&mut cache, &input, &mut caps)
+            .is_err());
+        assert!(!caps.is_match());
+        // Anchored searches use the one-pass DFA when it's available.
+        let input = input.clone().anchored(Anchored::Yes).range(20_000..);
+        assert!(re.try_search(&input).is_err());
+    }
+
+    let input = Input::new(&hay).cancel_flag(&cancel);
+    cancel.store(false, Ordering::Relaxed);
+    let dfa = DenseRegex::new(r"foo[0-9]+")?;
+    assert_eq!(expected, dfa.try_search(&input)?);
+    let hre = hybrid::regex::Regex::new(r"foo[0-9]+")?;
+    let mut hcache = hre.create_cache();
+    assert_eq!(expected, hre.try_search(&mut hcache, &input)?);
+    let vm = pikevm::PikeVM::new(r"foo[0-9]+")?;
+    let (mut vmcache, mut caps) = (vm.create_cache(), vm.create_captures());
+    vm.try_search(&mut vmcache, &input, &mut caps)?;
+    assert_eq!(expected, caps.get_match());
+    let bt = backtrack::BoundedBacktracker::new(r"foo[0-9]+")?;
+    let mut btcache = bt.create_cache();
+    let short = input.clone().range(19_990..);
+    assert_eq!(expected, bt.try_find(&mut btcache, short.clone())?);
+    let op = onepass::DFA::new(r"foo[0-9]+")?;
+    let mut opcache = op.create_cache();
+    let anchored = input.clone().range(20_000..).anchored(Anchored::Yes);
+    op.try_search(&mut opcache, &anchored, &mut caps)?;
+    assert_eq!(expected, caps.get_match());
+
+    cancel.store(true, Ordering::Relaxed);
+    let is_cancelled = |err: regex_automata::MatchError| {
+        matches!(*err.kind(), MatchErrorKind::Cancelled { .. })
+    };
+    assert!(is_cancelled(dfa.try_search(&input).unwrap_err()));
+    let rev = dense::DFA::new(r"foo[0-9]+")?;
+    assert!(is_cancelled(rev.try_search_rev(&input).unwrap_err()));
+    assert!(is_cancelled(hre.try_search(&mut hcache, &input).unwrap_err()));
+    let err = vm.try_search(&mut vmcache, &input, &mut caps).unwrap_err();
+    assert!(is_cancelled(err));
+    assert!(is_cancelled(bt.try_find(&mut btcache, short).unwrap_err()));
+    let err = op.try_search(&mut opcache, &anchored, &mut caps).unwrap_err();
+    assert!(is_cancelled(err));
+    Ok(())
+}
+
+// Tests that giving a search a cancellation flag that is never set doesn't
+// change its results, even when matches straddle the points at which the flag
+// is checked.
+#[test]
+fn cancellation_unset() -> Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use regex_automata::{dfa::regex::Regex as DenseRegex, hybrid};
+
+    let cancel = AtomicBool::new(false);
+    let patterns = [r"[a-z]y", r"x[0-9]+", r"(?:ab)+y"];
+    for pattern in patterns {
+        let re = Regex::new(pattern)?;
+        let dfa = DenseRegex::new(pattern)?;
+        let hre = hybrid::regex::Regex::new(pattern)?;
+        let mut hcache = hre.create_cache();
+        for len in [4090, 4094, 4095, 4096, 4097, 8190, 8192, 12_300] {
+            for suffix in ["y", "1", "aby"] {
+                let hay = format!("{}{}", "x".repeat(len), suffix);
+                let plain = Input::new(&hay);
+                let flagged = Input::new(&hay).cancel_flag(&cancel);
+                let expected = re.find(plain.clone());
+                assert_eq!(expected, re.try_search(&flagged)?);
+                assert_eq!(expected, dfa.try_search(&plain)?);
+                assert_eq!(expected, dfa.try_search(&flagged)?);
+                assert_eq!(expected, hre.try_search(&mut hcache, &plain)?);
+                assert_eq!(expected, hre.try_search(&mut hcache, &flagged)?);
+            }
+        }
+    }
+    let hay = format!("{}y", "x".repeat(4096));
+    let input = Input::new(&hay).cancel_flag(&cancel);
+    let re = Regex::new(r"[a-z]y")?;
+    assert_eq!(Some(Match::must(0, 4095..4097)), re.try_search(&input)?);
+
+    // Regexes that are answered by a prefilter alone never look at the flag
+    // while searching, but a flag that is already set must still be honored.
+    cancel.store(true, Ordering::Relaxed);
+    for pattern in [r"foo", r"foo|bar"] {
+        let re = Regex::new(pattern)?;
+        let input = Input::new("xxfoo").cancel_flag(&cancel);
+        assert!(re.try_search(&input).is_err());
+        let mut cache = re.create_cache();
+        let mut caps = re.create_captures();
+        assert!(re
+            .try_search_captures_with(&mut cache, &input, &mut caps)
+            .is_err());
+    }
+    Ok(())
+}
+
+// Tests that reverse searches give the same results whether or not they use
+// a lazy DFA, and that every match they report is also reported by a forward
+// search starting where the match does.
+#[test]
+fn rfind() -> Result<()> {
+    let tests: &[(&str, &str, &[(usize, usize)])] = &[
+        (r"[0-9]+", "abc123 def456", &[(10, 13), (3, 6)]),
+        (r"foo[0-9]+", "foo1 foo12 foo123", &[(11, 17), (5, 10), (0, 4)]),
+        // Uses the reverse suffix optimization.
+        (r"[a-z]+ing", "tingling sing", &[(9, 13), (0, 8)]),
+        // Uses the reverse anchored optimization.
+        (r"\w+\z", "hello world", &[(6, 11)]),
+        // Uses the reverse inner optimization.
+        (r"\w+@\w+", "a@b cc@dd", &[(4, 9), (0, 3)]),
+        // Uses only a prefilter.
+        (r"foo|bar", "foobar bar", &[(7, 10), (3, 6), (0, 3)]),
+        (r"aa", "aaa", &[(1, 3)]),
+        (r"a*", "baa", &[(1, 3), (0, 0)]),
+        (r"", "☃", &[(3, 3), (0, 0)]),
+        (r"a.*b", "axxab", &[(0, 5)]),
+        (r"\b\w+\b", "δ ab", &[(3, 5), (0, 2)]),
+        (r"Samwise|Sam", "Sam and Samwise", &[(8, 15), (0, 3)]),
+        (r"(?m)^[a-z]+$", "ab\ncd", &[(3, 5), (0, 2)]),
+        (r"\Bb+", "abb b", &[(1, 3)]),
+        (r"\w+z\b", "az δδz δδ", &[(3, 8), (0, 2)]),
+    ];
+    let configs = [
+        Regex::config(),
+        Regex::config().dfa(false),
+        Regex::config().hybrid(false),
+        Regex::config().hybrid(false).dfa(false).auto_prefilter(false),
+    ];
+    for &(pattern, hay, expected) in tests {
+        for config in configs.iter() {
+            let re =
+                Regex::builder().configure(config.clone()).build(pattern)?;
+            let got: Vec<(usize, usize)> =
+                re.rfind_iter(hay).map(|m| (m.start(), m.end())).collect();
+            assert_eq!(expected, &*got, "{:?} on {:?}", pattern, hay);
+            assert_eq!(
+                expected.first().map(|&(s, e)| Match::must(0, s..e)),
+                re.rfind(hay),
+            );
+
+            let mut end = hay.len();
+            for &(start, _) in expected {
+                let input = Input::new(hay).range(start..end);
+                let m = re.search(&input).unwrap();
+                assert!(got.contains(&(m.start(), m.end())));
+                end = start;
+            }
+        }
+    }
+
+    let re = Regex::new(r"[a-z]+")?;
+    let input = Input::new("abc def").anchored(regex_automata::Anchored::Yes);
+    assert_eq!(Some(Match::must(0, 0..3)), re.rsearch(&input));
+    Ok(())
+}
+
+// Tests that reverse searches stay linear when the lazy DFA gives up. Here,
+// it quits as soon as it sees a non-ASCII byte because of the Unicode word
+// boundary. Running a forward search from every position instead would scan
+// to the end of the haystack each time, which would take far too long.
+#[test]
+fn rfind_linear() -> Result<()> {
+    let re = Regex::new(r"\w+z\b")?;
+    let hay = format!("az {}", "δ".repeat(100_000));
+    assert_eq!(Some(Match::must(0, 0..2)), re.rfind(&hay));
+    assert_eq!(None, re.rfind(&hay[3..]));
+    Ok(())
+}
+
+// Checking a word or sentence boundary may need to look at the characters
+// around it, but that shouldn't make searching long runs of them quadratic.
+#[test]
+fn segment_linear() -> Result<()> {
+    let re = Regex::new(r"\b{wb}\p{RI}")?;
+    let hay = "\u{1F1FA}".repeat(20_000);
+    let starts: Vec<usize> = re.find_iter(&hay).map(|m| m.start()).collect();
+    // Even very long runs of regional indicators are split into pairs.
+    assert_eq!(10_000, starts.len());
+    assert!(starts.iter().all(|&start| start % 8 == 0));
+
+    let re = Regex::new(r"\b{sb}")?;
+    let hay = format!("x.{}a", " ".repeat(20_000));
+    let starts: Vec<usize> = re.find_iter(&hay).map(|m| m.start()).collect();
+    assert_eq!(vec![0, hay.len()], starts);
+    Ok(())
+}
+
+#[test]
+fn chunks() -> Result<()> {
+    use regex_automata::util::chunks::Chunks;
+
+    let tests: &[(&str, &str)] = &[
+        (r"[0-9]+", "abc123 def456"),
+        (r"foo[0-9]+", "foo1 foo12 foo123"),
+        // Uses the reverse suffix optimization.
+        (r"[a-z]+ing", "tingling sing"),
+        // Uses the reverse anchored optimization.
+        (r"\w+\z", "hello world"),
+        // Uses the reverse inner optimization.
+        (r"\w+@\w+", "a@b cc@dd"),
+        // Uses only a prefilter.
+        (r"foo|bar", "foobar bar"),
+        (r"Samwise|Sam", "Sam and Samwise"),
+        (r"aa", "aaa"),
+        (r"a*", "baa"),
+        (r"", "☃δ"),
+        (r"\b\w+\b", "δ ab ☃ξξ"),
+        (r"(?-u)\b\w+\b", "ab cd"),
+        (r"\B", "abc δξ"),
+        (r"(?m)^\w+$", "ab\ncd\nef"),
+        (r"(?mR)^[a-z]*$", "ab\r\n\r\ncd\r\n"),
+        (r"^a|b$", "abab"),
+        // Needs the fallback since backreferences aren't supported.
+        #[cfg(feature = "nfa-backref")]
+        (r"(\w)\1", "abba ccd"),
+    ];
+    let config = Regex::config().backreferences(true);
+    let configs = [
+        config.clone(),
+        config.clone().dfa(false),
+        config.clone().hybrid(false),
+        config.clone().hybrid(false).dfa(false).auto_prefilter(false),
+    ];
+    for &(pattern, hay) in tests {
+        for config in configs.iter() {
+            let re =
+                Regex::builder().configure(config.clone()).build(pattern)?;
+            let expected: Vec<(usize, usize)> = re
+                .find_iter(hay)
+                .map(|m| (m.start() + 7, m.end() + 7))
+                .collect();
+            for size in 1..=hay.len() {
+                let chunks: Vec<(usize, &[u8])> = hay
+                    .as_bytes()
+                    .chunks(size)
+                    .enumerate()
+                    .map(|(i, chunk)| (7 + i * size, chunk))
+                    .collect();
+                let got: Vec<(usize, usize)> = re
+                    .find_chunks_iter(chunks.iter().copied())
+                    .map(|m| (m.start(), m.end()))
+                    .collect();
+                assert_eq!(
+                    expected, got,
+                    "{:?} on {:?} with chunk size {}",
+                    pattern, hay, size,
+                );
+
+                let chunks = Chunks::new(chunks);
+                for start in 0..=hay.len() {
+                    let input = Input::new(hay).range(start..);
+                    let expected = re
+                        .search(&input)
+                        .map(|m| (m.start() + 7, m.end() + 7));
+                    let got = re
+                        .search_chunks(&chunks, start + 7)
+                        .map(|m| (m.start(), m.end()));
+                    assert_eq!(
+                        expected, got,
+                        "{:?} on {:?} with chunk size {} from {}",
+                        pattern, hay, size, start,
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -103,6 +103,36 @@ fn no_dfa_hybrid_onepass_backtrack() -> Result<()> {
     Ok(())
 }
 
+/// Tests the default configuration after round tripping each regex through
+/// its serialized form.
+#[test]
+fn serialization() -> Result<()> {
+    let builder = Regex::builder();
+    let mut runner = TestRunner::new()?;
+    runner
+        .expand(&["is_match", "find", "captures"], |test| test.compiles())
+        .blacklist_iter(BLACKLIST)
+        .test_iter(suite()?.iter(), compiler_serialized(builder))
+        .assert();
+    Ok(())
+}
+
+/// Tests the default configuration minus the full DFA after round tripping
+/// each regex through its serialized form. This exercises rebuilding the
+/// lazy DFA when deserializing.
+#[test]
+fn serialization_no_dfa() -> Result<()> {
+    let mut builder = Regex::builder();
+    builder.configure(Regex::config().dfa(false));
+    let mut runner = TestRunner::new()?;
+    runner
+        .expand(&["is_match", "find", "captures"], |test| test.compiles())
+        .blacklist_iter(BLACKLIST)
+        .test_iter(suite()?.iter(), compiler_serialized(builder))
+        .assert();
+    Ok(())
+}
+
 fn compiler(
     mut builder: meta::Builder,
 ) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
@@ -117,6 +147,28 @@ fn compiler(
     }
 }
 
+/// Like `compiler`, but serializes and then deserializes each regex before
+/// searching with it.
+fn compiler_serialized(
+    mut builder: meta::Builder,
+) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
+    move |test, regexes| {
+        if !configure_meta_builder(test, &mut builder) {
+            return Ok(CompiledRegex::skip());
+        }
+        let (bytes, pad) =
+            builder.build_many(&regexes)?.to_bytes_native_endian();
+        // Leak the bytes so that the DFA tables can be borrowed from them
+        // without copying.
+        let bytes: &'static [u8] = &bytes.leak()[pad..];
+        let (re, nread) = Regex::from_bytes(bytes)?;
+        assert_eq!(bytes.len(), nread);
+        Ok(CompiledRegex::compiled(move |test| -> TestResult {
+            run_test(&re, test)
+        }))
+    }
+}
+
 fn run_test(re: &Regex, test: &RegexTest) -> TestResult {
     let input = create_input(test);
     match test.additional_name() {
@@ -97,6 +97,37 @@ fn min_visited_capacity() -> Result<()> {
     Ok(())
 }
 
+/// Tests the bounded backtracker when it is built from an NFA that was
+/// serialized and then deserialized.
+#[test]
+fn serialization() -> Result<()> {
+    let mut runner = TestRunner::new()?;
+    runner.expand(&["is_match", "find", "captures"], |test| test.compiles());
+    runner.blacklist("expensive/backtrack-blow-visited-capacity");
+    runner
+        .test_iter(suite()?.iter(), move |test, regexes| {
+            let mut builder = BoundedBacktracker::builder();
+            if !configure_backtrack_builder(test, &mut builder) {
+                return Ok(CompiledRegex::skip());
+            }
+            let nfa = NFA::compiler()
+                .configure(config_thompson(test))
+                .syntax(config_syntax(test))
+                .build_many(regexes)?;
+            let bytes = nfa.to_bytes_native_endian();
+            let (nfa, nread) = NFA::from_bytes(&bytes)?;
+            assert_eq!(bytes.len(), nread);
+
+            let re = builder.build_from_nfa(nfa)?;
+            let mut cache = re.create_cache();
+            Ok(CompiledRegex::compiled(move |test| -> TestResult {
+                run_test(&re, &mut cache, test)
+            }))
+        })
+        .assert();
+    Ok(())
+}
+
 fn compiler(
     mut builder: backtrack::Builder,
 ) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
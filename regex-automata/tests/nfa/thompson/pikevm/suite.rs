@@ -50,6 +50,109 @@ fn prefilter() -> Result<()> {
     Ok(())
 }
 
+/// Tests the PikeVM when it is built from an NFA that was serialized and
+/// then deserialized.
+#[test]
+fn serialization() -> Result<()> {
+    let my_compiler = |test: &RegexTest, regexes: &[String]| {
+        let mut builder = PikeVM::builder();
+        if !configure_pikevm_builder(test, &mut builder) {
+            return Ok(CompiledRegex::skip());
+        }
+        let nfa = thompson::NFA::compiler()
+            .configure(config_thompson(test))
+            .syntax(config_syntax(test))
+            .build_many(regexes)?;
+        let bytes = nfa.to_bytes_native_endian();
+        let (nfa, nread) = thompson::NFA::from_bytes(&bytes)?;
+        assert_eq!(bytes.len(), nread);
+
+        let re = builder.build_from_nfa(nfa)?;
+        let mut cache = re.create_cache();
+        Ok(CompiledRegex::compiled(move |test| -> TestResult {
+            run_test(&re, &mut cache, test)
+        }))
+    };
+    let mut runner = TestRunner::new()?;
+    runner.expand(&["is_match", "find", "captures"], |test| test.compiles());
+    runner.test_iter(suite()?.iter(), my_compiler).assert();
+    Ok(())
+}
+
+/// Tests approximate matching by comparing it with a brute force search over
+/// every substring of the haystack for literal patterns.
+#[test]
+fn approximate() -> Result<()> {
+    // The classic dynamic programming edit distance between two strings.
+    fn distance(a: &[u8], b: &[u8]) -> usize {
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for i in 1..=a.len() {
+            let mut prev = row[0];
+            row[0] = i;
+            for j in 1..=b.len() {
+                let sub = prev + usize::from(a[i - 1] != b[j - 1]);
+                prev = row[j];
+                row[j] = sub.min(row[j] + 1).min(row[j - 1] + 1);
+            }
+        }
+        row[b.len()]
+    }
+
+    // A simple deterministic source of haystacks over a small alphabet, so
+    // that near misses are common.
+    let mut seed: u32 = 0x2545_F491;
+    let mut haystacks = vec![];
+    for len in 0..12 {
+        for _ in 0..20 {
+            let haystack: Vec<u8> = (0..len)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 17;
+                    seed ^= seed << 5;
+                    b"abc"[(seed % 3) as usize]
+                })
+                .collect();
+            haystacks.push(haystack);
+        }
+    }
+    for needle in ["abc", "aab", "abca", "cc"] {
+        for k in 1..=2 {
+            let re = PikeVM::builder()
+                .configure(PikeVM::config().max_edits(Some(k)))
+                .build(needle)?;
+            let mut cache = re.create_cache();
+            for haystack in haystacks.iter() {
+                // The leftmost start of any substring within the budget.
+                let leftmost = (0..=haystack.len()).find(|&start| {
+                    (start..=haystack.len()).any(|end| {
+                        let d =
+                            distance(&haystack[start..end], needle.as_bytes());
+                        d <= k
+                    })
+                });
+                let got = re.find(&mut cache, haystack.as_slice());
+                if let Some(ref m) = got {
+                    let d = distance(&haystack[m.range()], needle.as_bytes());
+                    assert!(d <= k, "{:?} has {} edits", m, d);
+                }
+                assert_eq!(
+                    leftmost,
+                    got.map(|m| m.start()),
+                    "needle: {:?}, k: {}, haystack: {:?}",
+                    needle,
+                    k,
+                    String::from_utf8_lossy(haystack),
+                );
+                assert_eq!(
+                    leftmost.is_some(),
+                    re.is_match(&mut cache, haystack.as_slice()),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
 fn compiler(
     mut builder: pikevm::Builder,
 ) -> impl FnMut(&RegexTest, &[String]) -> Result<CompiledRegex> {
@@ -0,0 +1,195 @@
+use std::io::{stdout, Write};
+
+use {
+    bstr::ByteSlice,
+    lexopt::{Arg, Parser},
+    regex_automata::{
+        dfa::{dense, witness, StartKind},
+        MatchKind,
+    },
+};
+
+use crate::args::{self, Usage};
+
+pub fn run(p: &mut Parser) -> anyhow::Result<()> {
+    const USAGE: &'static str = "\
+Prints example strings matched by each of the given patterns.
+
+By default, strings are printed in shortlex order. That is, shortest strings
+first, and strings of the same length in lexicographic order. Thus, the first
+string printed for each pattern is a shortest string that it matches. With
+--random, strings are instead chosen at random.
+
+Each line of output is of the form 'pattern:\"string\"', where the string has
+any non-printable or non-ASCII bytes escaped. A pattern that matches nothing
+is printed as 'pattern:<none>'.
+
+A string is considered matched by a pattern only when the pattern matches the
+entire string.
+
+USAGE:
+    regex-cli generate examples [<pattern> ...]
+
+TIP:
+    use -h for short docs and --help for long docs
+
+OPTIONS:
+%options%
+";
+
+    let mut config = Config::default();
+    let mut patterns = args::patterns::Config::positional();
+    let mut syntax = args::syntax::Config::default();
+    let mut thompson = args::thompson::Config::default();
+    args::configure(
+        p,
+        USAGE,
+        &mut [&mut config, &mut patterns, &mut syntax, &mut thompson],
+    )?;
+
+    let pats = patterns.get()?;
+    let asts = syntax.asts(&pats)?;
+    let hirs = syntax.hirs(&pats, &asts)?;
+    let nfa = thompson.from_hirs(&hirs)?;
+    // Language operations need every match to be recorded and an anchored
+    // start state, so this DFA is always built with the same configuration.
+    let dfa = dense::Builder::new()
+        .configure(
+            dense::Config::new()
+                .match_kind(MatchKind::All)
+                .start_kind(StartKind::Anchored)
+                .minimize(true),
+        )
+        .build_from_nfa(&nfa)?;
+
+    let mut out = stdout().lock();
+    for pid in nfa.patterns() {
+        let wconfig = witness::Config::new()
+            .min_len(config.min_len)
+            .max_len(config.max_len)
+            .utf8(config.utf8)
+            .pattern(Some(pid));
+        let gen = witness::Generator::from_dfa(wconfig, &dfa)?;
+        let strings: Vec<Vec<u8>> = match config.seed {
+            None => gen.iter().take(config.count).collect(),
+            Some(seed) => gen.samples(seed).take(config.count).collect(),
+        };
+        if strings.is_empty() {
+            writeln!(out, "{}:<none>", pid.as_usize())?;
+        }
+        for string in strings.iter() {
+            writeln!(out, "{}:\"{}\"", pid.as_usize(), string.escape_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+struct Config {
+    count: usize,
+    min_len: usize,
+    max_len: Option<usize>,
+    utf8: bool,
+    seed: Option<u64>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            count: 10,
+            min_len: 0,
+            max_len: None,
+            utf8: false,
+            seed: None,
+        }
+    }
+}
+
+impl args::Configurable for Config {
+    fn configure(
+        &mut self,
+        p: &mut Parser,
+        arg: &mut Arg,
+    ) -> anyhow::Result<bool> {
+        match *arg {
+            Arg::Short('n') | Arg::Long("count") => {
+                self.count = args::parse(p, "-n/--count")?;
+            }
+            Arg::Long("min-len") => {
+                self.min_len = args::parse(p, "--min-len")?;
+            }
+            Arg::Long("max-len") => {
+                self.max_len = args::parse_maybe(p, "--max-len")?;
+            }
+            Arg::Long("utf8") => {
+                self.utf8 = true;
+            }
+            Arg::Long("random") => {
+                self.seed = Some(self.seed.unwrap_or(0));
+            }
+            Arg::Long("seed") => {
+                self.seed = Some(args::parse(p, "--seed")?);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn usage(&self) -> &[Usage] {
+        const USAGES: &'static [Usage] = &[
+            Usage::new(
+                "-n, --count <number>",
+                "The number of strings to print for each pattern.",
+                r#"
+The number of strings to print for each pattern. The default is 10.
+"#,
+            ),
+            Usage::new(
+                "--min-len <number>",
+                "Only print strings of at least this many bytes.",
+                r#"
+Only print strings whose length, in bytes, is at least the number given.
+"#,
+            ),
+            Usage::new(
+                "--max-len <number>",
+                "Only print strings of at most this many bytes.",
+                r#"
+Only print strings whose length, in bytes, is at most the number given. Use
+'none' to disable the limit, which is the default.
+
+When there is no limit and --random is given, strings are limited to 32 bytes
+beyond the minimum length, unless the pattern only matches finitely many
+strings.
+"#,
+            ),
+            Usage::new(
+                "--utf8",
+                "Only print strings that are valid UTF-8.",
+                r#"
+Only print strings that are valid UTF-8. By default, any string of bytes
+matched by the pattern may be printed. This matters for patterns that can
+match invalid UTF-8, such as those with Unicode mode disabled.
+"#,
+            ),
+            Usage::new(
+                "--random",
+                "Print randomly chosen strings.",
+                r#"
+Print randomly chosen strings instead of the first strings in shortlex order.
+Unless --seed is given, the random number generator is seeded with 0, so the
+output is the same from run to run.
+"#,
+            ),
+            Usage::new(
+                "--seed <number>",
+                "Print randomly chosen strings using this seed.",
+                r#"
+Print randomly chosen strings, using the given number to seed the random
+number generator. This implies --random.
+"#,
+            ),
+        ];
+        USAGES
+    }
+}
@@ -406,7 +406,10 @@ fn count_capturing_groups_ast(ast: &regex_syntax::ast::Ast) -> usize {
         | Ast::Assertion(_)
         | Ast::ClassUnicode(_)
         | Ast::ClassPerl(_)
-        | Ast::ClassBracketed(_) => 0,
+        | Ast::ClassBracketed(_)
+        | Ast::Backreference(_)
+        | Ast::GraphemeCluster(_)
+        | Ast::NewlineSequence(_) => 0,
         Ast::Repetition(ref rep) => count_capturing_groups_ast(&*rep.ast),
         Ast::Group(ref group) => {
             let this = if group.is_capturing() { 1 } else { 0 };
@@ -418,5 +421,9 @@ fn count_capturing_groups_ast(ast: &regex_syntax::ast::Ast) -> usize {
         Ast::Concat(ref concat) => {
             concat.asts.iter().map(count_capturing_groups_ast).sum()
         }
+        Ast::Intersection(ref x) => {
+            x.asts.iter().map(count_capturing_groups_ast).sum()
+        }
+        Ast::Complement(ref x) => count_capturing_groups_ast(&*x.ast),
     }
 }
@@ -1,5 +1,6 @@
 use crate::args;
 
+mod examples;
 mod fowler;
 mod serialize;
 mod unicode;
@@ -13,6 +14,7 @@ USAGE:
     regex-cli generate <command>
 
 COMMANDS:
+    examples    Print example strings matched by patterns.
     fowler      Convert Glenn Fowler's test suite to TOML files.
     serialize   Serialize DFAs and generate Rust code to load them.
     unicode     Generate all Unicode tables required for the regex project.
@@ -20,6 +22,7 @@ COMMANDS:
 
 pub fn run(p: &mut lexopt::Parser) -> anyhow::Result<()> {
     match &*args::next_as_command(USAGE, p)? {
+        "examples" => examples::run(p),
         "fowler" => fowler::run(p),
         "serialize" => serialize::run(p),
         "unicode" => unicode::run(p),
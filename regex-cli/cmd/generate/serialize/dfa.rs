@@ -1,14 +1,14 @@
 // The code in this module honestly sucks. I did at one point try and make it a
 // little more composable, particularly with respect to the stuff that writes
 // the Rust code, but it became an unintelligble mess. Instead, I squashed
-// it down into four functions: dense DFAs, dense regexes, sparse DFAs and
-// sparse regexes. And each of those functions handles the 'regex-automata',
-// 'once-cell' and 'lazy-static' variants. So that's 12 different variants.
-// There's *some* sharing within each function at least...
+// it down into five functions: dense DFAs, dense regexes, sparse DFAs,
+// sparse regexes and one-pass DFAs. And each of those functions handles the
+// 'regex-automata', 'once-cell' and 'lazy-static' variants. So that's 15
+// different variants. There's *some* sharing within each function at least...
 //
 // With that said, I don't expect this code generation task to expand much.
 // We'll probably support std's OnceCell once that stabilizes, but otherwise,
-// I think we'll be stuck with just full DFAs for the time being. If and when
+// I think we'll be stuck with just DFAs for the time being. If and when
 // the code generation task expands to other objects (NFAs?), maybe we should
 // reconsider how this code is structured.
 
@@ -20,7 +20,7 @@ use std::{
 use {
     anyhow::Context,
     lexopt::{Arg, Parser, ValueExt},
-    regex_automata::dfa::{dense, sparse},
+    regex_automata::dfa::{dense, onepass, sparse},
 };
 
 use crate::{
@@ -252,6 +252,55 @@ OPTIONS:
     Ok(())
 }
 
+pub fn run_onepass(p: &mut Parser) -> anyhow::Result<()> {
+    const USAGE: &'static str = "\
+Serializes a one-pass DFA to disk.
+
+Since a one-pass DFA is always deserialized using only safe code, the --safe
+flag has no effect.
+
+USAGE:
+    regex-cli generate serialize onepass <name> <outdir> [<pattern> ...]
+
+TIP:
+    use -h for short docs and --help for long docs
+
+OPTIONS:
+%options%
+";
+
+    let mut config = Config::default();
+    let mut patterns = args::patterns::Config::positional();
+    let mut syntax = args::syntax::Config::default();
+    let mut thompson = args::thompson::Config::default();
+    let mut onepass = args::onepass::Config::default();
+    args::configure(
+        p,
+        USAGE,
+        &mut [
+            // This needs to come first, as it greedily parses the first
+            // two positional parameters, and then 'patterns' takes the rest.
+            &mut config,
+            &mut patterns,
+            &mut syntax,
+            &mut thompson,
+            &mut onepass,
+        ],
+    )?;
+
+    let pats = patterns.get()?;
+    let asts = syntax.asts(&pats)?;
+    let hirs = syntax.hirs(&pats, &asts)?;
+    let nfa = thompson.from_hirs(&hirs)?;
+    let dfa = onepass.from_nfa(&nfa)?;
+
+    let wtr = config.writer()?;
+    wtr.write_onepass_bytes(&dfa)?;
+    wtr.write_onepass_rust()?;
+
+    Ok(())
+}
+
 #[derive(Debug, Default)]
 struct Config {
     name: Option<String>,
@@ -437,6 +486,18 @@ impl Writer {
         Ok(())
     }
 
+    fn write_onepass_bytes(&self, dfa: &onepass::DFA) -> anyhow::Result<()> {
+        let big_bytes = dfa.to_bytes_big_endian();
+        let lil_bytes = dfa.to_bytes_little_endian();
+        let big_path = self.outdir.join(self.big_name(""));
+        let lil_path = self.outdir.join(self.lil_name(""));
+        std::fs::write(&big_path, &big_bytes)
+            .with_context(|| format!("{}", big_path.display()))?;
+        std::fs::write(&lil_path, &lil_bytes)
+            .with_context(|| format!("{}", lil_path.display()))?;
+        Ok(())
+    }
+
     fn write_dfa_dense_rust(&self) -> anyhow::Result<()> {
         if matches!(self.rust_kind, RustKind::None) {
             return Ok(());
@@ -618,6 +679,88 @@ lazy_static::lazy_static! {{
         Ok(())
     }
 
+    fn write_onepass_rust(&self) -> anyhow::Result<()> {
+        if matches!(self.rust_kind, RustKind::None) {
+            return Ok(());
+        }
+        let outpath = self.outdir.join(format!("{}.rs", self.modname));
+        let mut wtr = std::fs::File::create(&outpath)
+            .with_context(|| outpath.display().to_string())?;
+
+        let auto_gen_message = self.auto_gen_message().trim().to_string();
+        let name = &self.varname;
+        let bigname = self.big_name("");
+        let lilname = self.lil_name("");
+        let deserialize = format!(
+            r##"
+    #[cfg(target_endian = "big")]
+    static BYTES: &'static [u8] = include_bytes!("{bigname}");
+    #[cfg(target_endian = "little")]
+    static BYTES: &'static [u8] = include_bytes!("{lilname}");
+    let (dfa, _) =
+        DFA::from_bytes(BYTES).expect("serialized DFA should be valid");
+    dfa
+"##,
+        );
+        match self.rust_kind {
+            RustKind::RegexAutomata => {
+                writeln!(
+                    wtr,
+                    r##"
+{auto_gen_message}
+
+use regex_automata::{{
+    dfa::onepass::DFA,
+    util::lazy::Lazy,
+}};
+
+pub static {name}: Lazy<DFA> = Lazy::new(|| {{
+    {deserialize}
+}});
+"##,
+                )?;
+            }
+            RustKind::OnceCell => {
+                writeln!(
+                    wtr,
+                    r##"
+{auto_gen_message}
+
+use {{
+    once_cell::sync::Lazy,
+    regex_automata::dfa::onepass::DFA,
+}};
+
+pub static {name}: Lazy<DFA> = Lazy::new(|| {{
+    {deserialize}
+}});
+"##,
+                )?;
+            }
+            RustKind::LazyStatic => {
+                writeln!(
+                    wtr,
+                    r##"
+{auto_gen_message}
+
+use regex_automata::dfa::onepass::DFA;
+
+lazy_static::lazy_static! {{
+    pub static ref {name}: DFA = {{
+        {deserialize}
+    }};
+}}
+"##,
+                )?;
+            }
+            RustKind::None => unreachable!(),
+        }
+        if self.rustfmt {
+            util::rustfmt(&outpath)?;
+        }
+        Ok(())
+    }
+
     fn write_regex_dense_rust(&self) -> anyhow::Result<()> {
         if matches!(self.rust_kind, RustKind::None) {
             return Ok(());
@@ -13,10 +13,12 @@ USAGE:
 ENGINES:
     dense    Serialize fully compiled dense DFAs or dense regex DFAs.
     sparse   Serialize fully compiled sparse DFAs or sparse regex DFAs.
+    onepass  Serialize a one-pass DFA.
 ";
     match &*args::next_as_command(USAGE, p)? {
         "dense" => dfa::run_dense(p),
         "sparse" => dfa::run_sparse(p),
+        "onepass" => dfa::run_onepass(p),
         unk => anyhow::bail!("unrecognized command '{}'", unk),
     }
 }
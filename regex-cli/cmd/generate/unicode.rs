@@ -1,4 +1,6 @@
 use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
     fs::File,
     io::Write,
     path::{Path, PathBuf},
@@ -23,7 +25,8 @@ Generates all Unicode tables for the regex project.
 Most Unicode tables are generated into the regex-syntax library.
 
 Note that this requires that the 'ucd-generate' tool be installed and in your
-PATH. The 'ucd-generate' tool is what is responsible for reading from the
+PATH. (A few tables that 'ucd-generate' doesn't support, like the Block property
+and character names, are generated by this command directly.) The 'ucd-generate' tool is what is responsible for reading from the
 Unicode Character Database (UCD) and converting tables of codepoints into Rust
 code that is embedded into the regex library.
 
@@ -74,7 +77,12 @@ USAGE:
     gen(d.join("property_names.rs"), &["property-names", &ucd])?;
     gen(
         d.join("property_values.rs"),
-        &["property-values", &ucd, "--include", "gc,script,scx,age,gcb,wb,sb"],
+        &[
+            "property-values",
+            &ucd,
+            "--include",
+            "gc,script,scx,age,gcb,wb,sb,blk,ea,lb,nt",
+        ],
     )?;
     gen(d.join("script.rs"), &["script", &ucd, "--chars"])?;
     gen(
@@ -84,6 +92,36 @@ USAGE:
     gen(d.join("sentence_break.rs"), &["sentence-break", &ucd, "--chars"])?;
     gen(d.join("word_break.rs"), &["word-break", &ucd, "--chars"])?;
 
+    // ucd-generate doesn't know about these properties, about character
+    // names or about full case folding, so we generate their tables
+    // ourselves. The property tables have the same shape as the ones above,
+    // e.g., the word break table.
+    let aliases = PropertyValueAliases::read(ucd)?;
+    gen_enumerated(d.join("block.rs"), ucd, &aliases, "blk", "Blocks.txt")?;
+    gen_enumerated(
+        d.join("east_asian_width.rs"),
+        ucd,
+        &aliases,
+        "ea",
+        "EastAsianWidth.txt",
+    )?;
+    gen_enumerated(
+        d.join("line_break.rs"),
+        ucd,
+        &aliases,
+        "lb",
+        "LineBreak.txt",
+    )?;
+    gen_enumerated(
+        d.join("numeric_type.rs"),
+        ucd,
+        &aliases,
+        "nt",
+        "extracted/DerivedNumericType.txt",
+    )?;
+    gen_names(d.join("name.rs"), ucd, &aliases)?;
+    gen_case_folding_full(d.join("case_folding_full.rs"), ucd, &aliases)?;
+
     // These generate the \w, \d and \s Unicode-aware character classes for
     // regex-syntax. \d and \s are technically part of the general category
     // and boolean properties generated above. However, these are generated
@@ -204,3 +242,395 @@ fn ucdgen(args: &[&str]) -> anyhow::Result<Vec<u8>> {
     );
     Ok(out.stdout)
 }
+
+/// The prefixes of character names that are derived from the codepoint, as
+/// described by rule NR2 in UAX#44. Each name is the prefix followed by the
+/// codepoint in hexadecimal (with at least 4 digits).
+const DERIVED_NAME_PREFIXES: &[&str] = &[
+    "CJK COMPATIBILITY IDEOGRAPH-",
+    "CJK UNIFIED IDEOGRAPH-",
+    "EGYPTIAN HIEROGLYPH-",
+    "KHITAN SMALL SCRIPT CHARACTER-",
+    "NUSHU CHARACTER-",
+    "TANGUT IDEOGRAPH-",
+];
+
+/// The property value aliases from `PropertyValueAliases.txt`, along with
+/// the version of Unicode they're from.
+#[derive(Debug)]
+struct PropertyValueAliases {
+    version: String,
+    /// A map from property abbreviation to a map from normalized property
+    /// value alias to canonical (long) property value name.
+    by_property: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl PropertyValueAliases {
+    fn read(ucd: &str) -> anyhow::Result<PropertyValueAliases> {
+        let data = read_ucd_file(ucd, "PropertyValueAliases.txt")?;
+        // The first line looks like '# PropertyValueAliases-15.0.0.txt'.
+        let version = data
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("# PropertyValueAliases-"))
+            .and_then(|rest| rest.strip_suffix(".txt"))
+            .ok_or_else(|| anyhow::anyhow!("could not find Unicode version"))?
+            .to_string();
+        let mut by_property: BTreeMap<String, BTreeMap<String, String>> =
+            BTreeMap::new();
+        for line in data.lines() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> =
+                line.split(';').map(|f| f.trim()).collect();
+            anyhow::ensure!(fields.len() >= 3, "invalid line: {:?}", line);
+            // The Canonical_Combining_Class property has an extra numeric
+            // field before its aliases. We don't need it anyway.
+            if fields[0] == "ccc" {
+                continue;
+            }
+            let canonical = fields[2];
+            let values = by_property.entry(fields[0].to_string()).or_default();
+            for alias in &fields[1..] {
+                values.insert(
+                    symbolic_name_normalize(alias),
+                    canonical.to_string(),
+                );
+            }
+        }
+        Ok(PropertyValueAliases { version, by_property })
+    }
+
+    /// Returns the canonical name of the given value of the given property.
+    fn canonical(&self, property: &str, value: &str) -> anyhow::Result<&str> {
+        self.by_property
+            .get(property)
+            .and_then(|values| values.get(&symbolic_name_normalize(value)))
+            .map(|canonical| canonical.as_str())
+            .ok_or_else(|| {
+                anyhow::anyhow!("unrecognized value {value:?} for {property}")
+            })
+    }
+
+    /// Returns the header to put at the top of a generated table.
+    fn header(&self, source: &str) -> String {
+        format!(
+            "\
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   regex-cli generate unicode
+//
+// from {source}.
+//
+// Unicode version: {version}.
+
+",
+            version = self.version,
+        )
+    }
+}
+
+/// Generate a table for an enumerated property from the UCD data file given,
+/// where each line maps a codepoint or a range of codepoints to a property
+/// value. Codepoints not listed get the value from the file's `@missing`
+/// lines, if any.
+///
+/// The table maps each canonical property value to the sorted codepoint
+/// ranges that have that value. Values that no scalar value has (like the
+/// surrogate line break class) are omitted.
+fn gen_enumerated<P: AsRef<Path>>(
+    dest: P,
+    ucd: &str,
+    aliases: &PropertyValueAliases,
+    property: &str,
+    file: &str,
+) -> anyhow::Result<()> {
+    let data = read_ucd_file(ucd, file)?;
+    let mut values: Vec<Option<&str>> = vec![None; 0x110000];
+    let missing = data.lines().filter_map(|l| l.strip_prefix("# @missing:"));
+    let listed = data.lines().map(|l| l.split('#').next().unwrap());
+    // Defaults come first, so that they are overwritten by explicit entries.
+    for line in missing.chain(listed) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split(';').map(|f| f.trim());
+        let (range, value) = match (fields.next(), fields.next()) {
+            (Some(range), Some(value)) => (range, value),
+            _ => anyhow::bail!("{file}: invalid line: {line:?}"),
+        };
+        let (start, end) = parse_codepoint_range(range)?;
+        let canonical = aliases.canonical(property, value)?;
+        for cp in start..=end {
+            values[cp as usize] = Some(canonical);
+        }
+    }
+
+    let mut by_value: BTreeMap<&str, Vec<(char, char)>> = BTreeMap::new();
+    for (cp, value) in values.iter().enumerate() {
+        let (Some(c), Some(value)) = (char::from_u32(cp as u32), *value)
+        else {
+            continue;
+        };
+        let ranges = by_value.entry(value).or_default();
+        match ranges.last_mut() {
+            Some(&mut (_, ref mut end)) if *end as u32 + 1 == cp as u32 => {
+                *end = c;
+            }
+            _ => ranges.push((c, c)),
+        }
+    }
+
+    let mut out = aliases.header(file);
+    out.push_str(
+        "pub const BY_NAME: \
+         &'static [(&'static str, &'static [(char, char)])] = &[\n",
+    );
+    for value in by_value.keys() {
+        writeln!(out, "({:?}, {}),", value, const_name(value))?;
+    }
+    out.push_str("];\n\n");
+    for (value, ranges) in by_value.iter() {
+        writeln!(
+            out,
+            "pub const {}: &'static [(char, char)] = &[",
+            const_name(value)
+        )?;
+        for &(start, end) in ranges.iter() {
+            writeln!(out, "({:?}, {:?}),", start, end)?;
+        }
+        out.push_str("];\n\n");
+    }
+    write_and_format(dest, &out)
+}
+
+/// Generate a table for looking up codepoints by their name or name alias.
+///
+/// Names are normalized according to UAX44-LM2, so that lookups can be done
+/// with a binary search after normalizing the name being looked up in the
+/// same way. Names derived from the codepoint (see `DERIVED_NAME_PREFIXES`)
+/// are stored as ranges instead, and Hangul syllable names are left out
+/// entirely since they are easy to compute.
+///
+/// Normalization makes the name of U+1180 HANGUL JUNGSEONG O-E the same as
+/// the name of U+116C HANGUL JUNGSEONG OE, so the former is left out and must
+/// be handled specially.
+fn gen_names<P: AsRef<Path>>(
+    dest: P,
+    ucd: &str,
+    aliases: &PropertyValueAliases,
+) -> anyhow::Result<()> {
+    let mut names: BTreeMap<String, char> = BTreeMap::new();
+    let mut derived: Vec<(String, char, char)> = vec![];
+    let mut add_derived = |prefix: &str, c: char| {
+        let key = character_name_normalize(prefix.trim_end_matches('-'));
+        match derived.last_mut() {
+            Some(&mut (ref k, _, ref mut end))
+                if *k == key && *end as u32 + 1 == c as u32 =>
+            {
+                *end = c;
+            }
+            _ => derived.push((key, c, c)),
+        }
+    };
+    let mut add = |name: &str, c: char| -> anyhow::Result<()> {
+        if c == '\u{1180}' {
+            return Ok(());
+        }
+        let key = character_name_normalize(name);
+        if let Some(old) = names.insert(key.clone(), c) {
+            anyhow::ensure!(
+                old == c,
+                "{key:?} refers to both {old:?} and {c:?}",
+            );
+        }
+        Ok(())
+    };
+
+    let data = read_ucd_file(ucd, "UnicodeData.txt")?;
+    let mut range_start = None;
+    for line in data.lines() {
+        let fields: Vec<&str> = line.split(';').collect();
+        anyhow::ensure!(fields.len() >= 2, "invalid line: {:?}", line);
+        let cp = u32::from_str_radix(fields[0], 16)?;
+        let name = fields[1];
+        if name.starts_with('<') {
+            // Ranges are given by two consecutive lines with names like
+            // '<CJK Ideograph Extension A, First>' and '<..., Last>'. Only
+            // ideograph ranges have derived names. Others, like Hangul
+            // syllables or private use, either have computed names or none.
+            if name.ends_with(", First>") {
+                range_start = Some(cp);
+            } else if name.ends_with(", Last>") {
+                let start = range_start.take().unwrap();
+                let prefix = if name.starts_with("<CJK Ideograph") {
+                    "CJK UNIFIED IDEOGRAPH-"
+                } else if name.starts_with("<Tangut Ideograph") {
+                    "TANGUT IDEOGRAPH-"
+                } else {
+                    continue;
+                };
+                for cp in start..=cp {
+                    add_derived(prefix, char::from_u32(cp).unwrap());
+                }
+            }
+            continue;
+        }
+        let Some(c) = char::from_u32(cp) else { continue };
+        let prefix = DERIVED_NAME_PREFIXES
+            .iter()
+            .find(|&&p| name == format!("{p}{cp:04X}"));
+        match prefix {
+            Some(prefix) => add_derived(prefix, c),
+            None => add(name, c)?,
+        }
+    }
+
+    let data = read_ucd_file(ucd, "NameAliases.txt")?;
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        anyhow::ensure!(fields.len() >= 2, "invalid line: {:?}", line);
+        let cp = u32::from_str_radix(fields[0], 16)?;
+        let Some(c) = char::from_u32(cp) else { continue };
+        add(fields[1], c)?;
+    }
+
+    let mut out = aliases.header("UnicodeData.txt and NameAliases.txt");
+    out.push_str("pub const NAMES: &'static [(&'static str, char)] = &[\n");
+    for (name, c) in names.iter() {
+        writeln!(out, "({:?}, {:?}),", name, c)?;
+    }
+    out.push_str("];\n\n");
+    out.push_str(
+        "pub const DERIVED_NAMES: \
+         &'static [(&'static str, char, char)] = &[\n",
+    );
+    for (prefix, start, end) in derived.iter() {
+        writeln!(out, "({:?}, {:?}, {:?}),", prefix, start, end)?;
+    }
+    out.push_str("];\n");
+    write_and_format(dest, &out)
+}
+
+/// Generate a table mapping each codepoint to its full case folding, for
+/// those codepoints whose full case folding differs from their simple case
+/// folding. These are exactly the entries with status `F` in
+/// `CaseFolding.txt`, and their case foldings always have more than one
+/// codepoint.
+fn gen_case_folding_full<P: AsRef<Path>>(
+    dest: P,
+    ucd: &str,
+    aliases: &PropertyValueAliases,
+) -> anyhow::Result<()> {
+    let file = "CaseFolding.txt";
+    let data = read_ucd_file(ucd, file)?;
+    let mut folds: Vec<(char, Vec<char>)> = vec![];
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').map(|f| f.trim()).collect();
+        anyhow::ensure!(fields.len() >= 3, "{file}: invalid line: {line:?}");
+        if fields[1] != "F" {
+            continue;
+        }
+        let parse = |cp: &str| -> anyhow::Result<char> {
+            u32::from_str_radix(cp, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| anyhow::anyhow!("invalid codepoint: {cp:?}"))
+        };
+        let c = parse(fields[0])?;
+        let fold = fields[2]
+            .split_whitespace()
+            .map(parse)
+            .collect::<anyhow::Result<Vec<char>>>()?;
+        anyhow::ensure!(fold.len() > 1, "{file}: invalid line: {line:?}");
+        folds.push((c, fold));
+    }
+    folds.sort();
+
+    let mut out = aliases.header("the full (F) mappings in CaseFolding.txt");
+    out.push_str(
+        "pub const CASE_FOLDING_FULL: \
+         &'static [(char, &'static [char])] = &[\n",
+    );
+    for (c, fold) in folds.iter() {
+        writeln!(out, "({:?}, &{:?}),", c, fold)?;
+    }
+    out.push_str("];\n");
+    write_and_format(dest, &out)
+}
+
+/// Read the UCD data file at the given path relative to the UCD directory.
+fn read_ucd_file(ucd: &str, file: &str) -> anyhow::Result<String> {
+    let path = Path::new(ucd).join(file);
+    std::fs::read_to_string(&path)
+        .with_context(|| format!("{}", path.display()))
+}
+
+/// Parse a codepoint range like `0041..005A` or a single codepoint like
+/// `0041`.
+fn parse_codepoint_range(range: &str) -> anyhow::Result<(u32, u32)> {
+    let parse = |cp: &str| {
+        u32::from_str_radix(cp, 16)
+            .with_context(|| format!("invalid codepoint: {cp:?}"))
+    };
+    match range.split_once("..") {
+        None => Ok((parse(range)?, parse(range)?)),
+        Some((start, end)) => Ok((parse(start)?, parse(end)?)),
+    }
+}
+
+/// Write the given Rust source to the file path given and apply 'rustfmt'
+/// to it.
+fn write_and_format<P: AsRef<Path>>(dest: P, src: &str) -> anyhow::Result<()> {
+    let dest = dest.as_ref();
+    std::fs::write(dest, src)
+        .with_context(|| format!("{}", dest.display()))?;
+    util::rustfmt(dest)?;
+    Ok(())
+}
+
+/// Returns the name of the constant for the given canonical property value.
+fn const_name(value: &str) -> String {
+    value.to_ascii_uppercase().replace('-', "_")
+}
+
+/// Loosely normalize a property value alias, roughly according to
+/// UAX44-LM3. This is only used to canonicalize the property values found in
+/// UCD data files.
+fn symbolic_name_normalize(x: &str) -> String {
+    x.chars()
+        .filter(|&c| c != ' ' && c != '_' && c != '-' && c.is_ascii())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Normalize a character name according to UAX44-LM2. This must match what
+/// regex-syntax does when looking up a character by name.
+fn character_name_normalize(x: &str) -> String {
+    let bytes = x.as_bytes();
+    let mut normalized = String::new();
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b' ' || b == b'_' {
+            continue;
+        }
+        if b == b'-'
+            && i > 0
+            && bytes[i - 1].is_ascii_alphanumeric()
+            && bytes.get(i + 1).map_or(false, |b| b.is_ascii_alphanumeric())
+        {
+            continue;
+        }
+        normalized.push(char::from(b.to_ascii_uppercase()));
+    }
+    normalized
+}
@@ -85,6 +85,10 @@ fn skip_or_get_pattern(test: &RegexTest) -> Option<&str> {
     if pattern.contains(r"\p") || pattern.contains(r"\P") {
         return None;
     }
+    // regex-lite has no Unicode name data, so \N{...} is never supported.
+    if pattern.contains(r"\N") {
+        return None;
+    }
     // Similar deal for Perl classes, but we can abide them if the haystack
     // is ASCII-only.
     if !test.haystack().is_ascii() {
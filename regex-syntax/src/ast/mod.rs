@@ -91,6 +91,11 @@ pub enum ErrorKind {
     ClassRangeLiteral,
     /// An opening `[` was found with no corresponding closing `]`.
     ClassUnclosed,
+    /// A complement operator, `~`, was not followed by an expression to
+    /// complement. This occurs, for example, in the regexes `~`, `a~|b` and
+    /// `~*`. This is only possible when boolean operators are enabled in the
+    /// parser.
+    ComplementMissing,
     /// Note that this error variant is no longer used. Namely, a decimal
     /// number can only appear as a repetition quantifier. When the number
     /// in a repetition quantifier is empty, then it gets its own specialized
@@ -181,12 +186,36 @@ pub enum ErrorKind {
     /// escape is used. The octal escape is assumed to be an invocation of
     /// a backreference, which is the common case.
     UnsupportedBackreference,
-    /// When syntax similar to PCRE's look-around is used, this error is
-    /// returned. Some example syntaxes that are rejected include, but are
-    /// not necessarily limited to, `(?=re)`, `(?!re)`, `(?<=re)` and
-    /// `(?<!re)`. Note that all of these syntaxes are otherwise invalid; this
-    /// error is used to improve the user experience.
+    /// A backtracking control verb or start-of-pattern option, e.g.,
+    /// `(*SKIP)` or `(*UTF)`, was used. This is only produced when the
+    /// parser uses the [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    UnsupportedBacktrackingVerb,
+    /// A branch reset group, e.g., `(?|a|b)`, was used. This is only
+    /// produced when the parser uses the
+    /// [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    UnsupportedBranchReset,
+    /// A conditional group, e.g., `(?(1)a|b)`, was used. This is only
+    /// produced when the parser uses the
+    /// [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    UnsupportedConditional,
+    /// This error was previously returned when syntax similar to PCRE's
+    /// look-around was used, e.g., `(?=re)`, `(?!re)`, `(?<=re)` and
+    /// `(?<!re)`. Since look-around is now supported (see
+    /// [`GroupKind::LookAround`]), this error is no longer produced by the
+    /// parser. It is retained for compatibility.
     UnsupportedLookAround,
+    /// The `\G` assertion, which matches at the position where the current
+    /// search started, was used. This is only produced when the parser uses
+    /// the [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    UnsupportedMatchStartAnchor,
+    /// The `\K` escape, which resets the start of the reported match, was
+    /// used. This is only produced when the parser uses the
+    /// [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    UnsupportedMatchStartReset,
+    /// A recursive pattern or subroutine call, e.g., `(?R)`, `(?1)` or
+    /// `(?&name)`, was used. This is only produced when the parser uses the
+    /// [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    UnsupportedRecursion,
 }
 
 #[cfg(feature = "std")]
@@ -220,6 +249,9 @@ impl core::fmt::Display for ErrorKind {
                 write!(f, "invalid range boundary, must be a literal")
             }
             ClassUnclosed => write!(f, "unclosed character class"),
+            ComplementMissing => {
+                write!(f, "complement operator missing expression")
+            }
             DecimalEmpty => write!(f, "decimal literal empty"),
             DecimalInvalid => write!(f, "decimal literal invalid"),
             EscapeHexEmpty => write!(f, "hexadecimal literal empty"),
@@ -283,8 +315,8 @@ impl core::fmt::Display for ErrorKind {
                 write!(
                     f,
                     "unrecognized special word boundary assertion, \
-                     valid choices are: start, end, start-half \
-                     or end-half",
+                     valid choices are: start, end, start-half, \
+                     end-half, wb or sb",
                 )
             }
             SpecialWordOrRepetitionUnexpectedEof => {
@@ -301,11 +333,43 @@ impl core::fmt::Display for ErrorKind {
             UnsupportedBackreference => {
                 write!(f, "backreferences are not supported")
             }
+            UnsupportedBacktrackingVerb => write!(
+                f,
+                "backtracking control verbs and start-of-pattern \
+                 options, e.g., (*SKIP) or (*UTF), are not supported"
+            ),
+            UnsupportedBranchReset => {
+                write!(
+                    f,
+                    "branch reset groups, i.e., (?|...), are not supported"
+                )
+            }
+            UnsupportedConditional => {
+                write!(
+                    f,
+                    "conditional groups, e.g., (?(1)...), are not supported"
+                )
+            }
             UnsupportedLookAround => write!(
                 f,
                 "look-around, including look-ahead and look-behind, \
                  is not supported"
             ),
+            UnsupportedMatchStartAnchor => write!(
+                f,
+                "\\G, which matches where the search started, \
+                 is not supported"
+            ),
+            UnsupportedMatchStartReset => write!(
+                f,
+                "\\K, which resets the start of the match, \
+                 is not supported"
+            ),
+            UnsupportedRecursion => write!(
+                f,
+                "recursion and subroutine calls, e.g., (?R), (?1) \
+                 or (?&name), are not supported"
+            ),
         }
     }
 }
@@ -446,14 +510,18 @@ pub struct WithComments {
 /// A comment from a regular expression with an associated span.
 ///
 /// A regular expression can only contain comments when the `x` flag is
-/// enabled.
+/// enabled, or when the parser uses the
+/// [`Dialect::Pcre`](parse::Dialect::Pcre) dialect, which also supports
+/// comments written as `(?#...)`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Comment {
-    /// The span of this comment, including the beginning `#` and ending `\n`.
+    /// The span of this comment, including the beginning `#` and ending `\n`
+    /// (or, for `(?#...)` comments, the enclosing parentheses).
     pub span: Span,
     /// The comment text, starting with the first character following the `#`
-    /// and ending with the last character preceding the `\n`.
+    /// and ending with the last character preceding the `\n` (or the closing
+    /// `)`).
     pub comment: String,
 }
 
@@ -493,6 +561,20 @@ pub enum Ast {
     Alternation(Box<Alternation>),
     /// A concatenation of regular expressions.
     Concat(Box<Concat>),
+    /// An intersection of regular expressions, e.g., `a&b`. This is only
+    /// produced when boolean operators are enabled in the parser.
+    Intersection(Box<Intersection>),
+    /// The complement of a regular expression, e.g., `~a`. This is only
+    /// produced when boolean operators are enabled in the parser.
+    Complement(Box<Complement>),
+    /// A backreference to a capturing group, e.g., `\1` or `\k<name>`. This
+    /// is only produced when backreferences are enabled in the parser.
+    Backreference(Box<Backreference>),
+    /// An extended grapheme cluster, i.e., `\X`.
+    GraphemeCluster(Box<Span>),
+    /// A newline sequence, i.e., `\R`. This is only produced when the
+    /// parser uses the [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    NewlineSequence(Box<Span>),
 }
 
 impl Ast {
@@ -556,6 +638,31 @@ impl Ast {
         Ast::Concat(Box::new(e))
     }
 
+    /// Create a "intersection" AST item.
+    pub fn intersection(e: Intersection) -> Ast {
+        Ast::Intersection(Box::new(e))
+    }
+
+    /// Create a "complement" AST item.
+    pub fn complement(e: Complement) -> Ast {
+        Ast::Complement(Box::new(e))
+    }
+
+    /// Create a "backreference" AST item.
+    pub fn backreference(e: Backreference) -> Ast {
+        Ast::Backreference(Box::new(e))
+    }
+
+    /// Create a "grapheme cluster" AST item.
+    pub fn grapheme_cluster(span: Span) -> Ast {
+        Ast::GraphemeCluster(Box::new(span))
+    }
+
+    /// Create a "newline sequence" AST item.
+    pub fn newline_sequence(span: Span) -> Ast {
+        Ast::NewlineSequence(Box::new(span))
+    }
+
     /// Return the span of this abstract syntax tree.
     pub fn span(&self) -> &Span {
         match *self {
@@ -571,6 +678,11 @@ impl Ast {
             Ast::Group(ref x) => &x.span,
             Ast::Alternation(ref x) => &x.span,
             Ast::Concat(ref x) => &x.span,
+            Ast::Intersection(ref x) => &x.span,
+            Ast::Complement(ref x) => &x.span,
+            Ast::Backreference(ref x) => &x.span,
+            Ast::GraphemeCluster(ref span) => span,
+            Ast::NewlineSequence(ref span) => span,
         }
     }
 
@@ -592,12 +704,17 @@ impl Ast {
             | Ast::Dot(_)
             | Ast::Assertion(_)
             | Ast::ClassUnicode(_)
-            | Ast::ClassPerl(_) => false,
+            | Ast::ClassPerl(_)
+            | Ast::Backreference(_)
+            | Ast::GraphemeCluster(_)
+            | Ast::NewlineSequence(_) => false,
             Ast::ClassBracketed(_)
             | Ast::Repetition(_)
             | Ast::Group(_)
             | Ast::Alternation(_)
-            | Ast::Concat(_) => true,
+            | Ast::Concat(_)
+            | Ast::Intersection(_)
+            | Ast::Complement(_) => true,
         }
     }
 }
@@ -667,6 +784,76 @@ impl Concat {
     }
 }
 
+/// An intersection of regular expressions.
+///
+/// An intersection matches a string if and only if every one of its
+/// sub-expressions matches that same string. In the concrete syntax, `&`
+/// binds more loosely than concatenation but more tightly than alternation.
+/// So `ab&cd|ef` is equivalent to `(?:(?:ab)&(?:cd))|ef`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Intersection {
+    /// The span of this intersection.
+    pub span: Span,
+    /// The intersected regular expressions.
+    pub asts: Vec<Ast>,
+}
+
+impl Intersection {
+    /// Return this intersection as an AST.
+    ///
+    /// If this intersection contains zero ASTs, then `Ast::empty` is
+    /// returned. If this intersection contains exactly 1 AST, then the
+    /// corresponding AST is returned. Otherwise, `Ast::intersection` is
+    /// returned.
+    pub fn into_ast(mut self) -> Ast {
+        match self.asts.len() {
+            0 => Ast::empty(self.span),
+            1 => self.asts.pop().unwrap(),
+            _ => Ast::intersection(self),
+        }
+    }
+}
+
+/// The complement of a regular expression.
+///
+/// A complement matches a string if and only if its sub-expression does not
+/// match that same string. In the concrete syntax, `~` is a prefix operator
+/// that binds more tightly than repetition operators. So `~a*` is equivalent
+/// to `(?:~a)*` and not `~(?:a*)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Complement {
+    /// The span of this complement, including the `~`.
+    pub span: Span,
+    /// The regular expression being complemented.
+    pub ast: Box<Ast>,
+}
+
+/// A backreference to a capturing group.
+///
+/// A backreference matches exactly the text most recently matched by the
+/// capturing group it refers to. If that group did not participate in the
+/// match, then the backreference does not match anything.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Backreference {
+    /// The span of this backreference, including the leading `\`.
+    pub span: Span,
+    /// The capturing group being referred to.
+    pub kind: BackreferenceKind,
+}
+
+/// The way in which a backreference refers to its capturing group.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BackreferenceKind {
+    /// `\N`, where `N` is the index of a capturing group.
+    Index(u32),
+    /// `\k<name>`, where `name` is the name of a capturing group.
+    Named(String),
+}
+
 /// A single literal expression.
 ///
 /// A literal corresponds to a single Unicode scalar value. Literals may be
@@ -721,6 +908,16 @@ pub enum LiteralKind {
     /// The literal is written as a specially recognized escape, e.g., `\f`
     /// or `\n`.
     Special(SpecialLiteralKind),
+    /// The literal is written as a Unicode character name or name alias,
+    /// e.g., `\N{GREEK SMALL LETTER ALPHA}`. This contains the name exactly
+    /// as it was written between the braces.
+    ///
+    /// Unlike all other literals, resolving this one requires Unicode data.
+    /// The parser therefore does not look up the name. Instead, the `c` field
+    /// of a literal with this kind is always `U+FFFD`, and the codepoint is
+    /// resolved when translating to an `Hir`. Doing so requires the
+    /// `unicode-name` feature.
+    Named(String),
 }
 
 /// The type of a special literal.
@@ -802,6 +999,12 @@ pub enum ClassPerlKind {
     Space,
     /// Word characters.
     Word,
+    /// Horizontal whitespace, i.e., `\h`. This is only produced when the
+    /// parser uses the [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    HorizontalSpace,
+    /// Vertical whitespace, i.e., `\v`. This is only produced when the
+    /// parser uses the [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    VerticalSpace,
 }
 
 /// An ASCII character class.
@@ -1220,7 +1423,16 @@ impl ClassSetRange {
     ///
     /// The only case where a range is invalid is if its start is greater than
     /// its end.
+    ///
+    /// If either endpoint is a Unicode character name (`\N{...}`), then this
+    /// always returns true, since names are only resolved when translating
+    /// to an `Hir`. Such ranges are checked during translation instead.
     pub fn is_valid(&self) -> bool {
+        if matches!(self.start.kind, LiteralKind::Named(_))
+            || matches!(self.end.kind, LiteralKind::Named(_))
+        {
+            return true;
+        }
         self.start.c <= self.end.c
     }
 }
@@ -1324,6 +1536,10 @@ pub enum AssertionKind {
     StartText,
     /// `\z`
     EndText,
+    /// `\Z`, which matches at the end of the haystack or before a line
+    /// terminator at the end of the haystack. This is only produced when the
+    /// parser uses the [`Dialect::Pcre`](parse::Dialect::Pcre) dialect.
+    EndTextOptionalNewline,
     /// `\b`
     WordBoundary,
     /// `\B`
@@ -1340,6 +1556,10 @@ pub enum AssertionKind {
     WordBoundaryStartHalf,
     /// `\b{end-half}`
     WordBoundaryEndHalf,
+    /// `\b{wb}`
+    WordSegmentBoundary,
+    /// `\b{sb}`
+    SentenceSegmentBoundary,
 }
 
 /// A repetition operation applied to a regular expression.
@@ -1352,6 +1572,11 @@ pub struct Repetition {
     pub op: RepetitionOp,
     /// Whether this operation was applied greedily or not.
     pub greedy: bool,
+    /// Whether this operation is possessive or not, e.g., `a*+`. A
+    /// possessive repetition never gives back what it matched, which makes
+    /// it equivalent to the same greedy repetition inside of an atomic
+    /// group. A possessive repetition is always greedy.
+    pub possessive: bool,
     /// The regular expression under repetition.
     pub ast: Box<Ast>,
 }
@@ -1437,7 +1662,18 @@ impl Group {
     pub fn is_capturing(&self) -> bool {
         match self.kind {
             GroupKind::CaptureIndex(_) | GroupKind::CaptureName { .. } => true,
-            GroupKind::NonCapturing(_) => false,
+            GroupKind::NonCapturing(_)
+            | GroupKind::LookAround(_)
+            | GroupKind::Atomic => false,
+        }
+    }
+
+    /// If this group is a look-around assertion, then this returns its kind.
+    /// Otherwise, `None` is returned.
+    pub fn look_around(&self) -> Option<LookAroundKind> {
+        match self.kind {
+            GroupKind::LookAround(kind) => Some(kind),
+            _ => None,
         }
     }
 
@@ -1448,7 +1684,9 @@ impl Group {
         match self.kind {
             GroupKind::CaptureIndex(i) => Some(i),
             GroupKind::CaptureName { ref name, .. } => Some(name.index),
-            GroupKind::NonCapturing(_) => None,
+            GroupKind::NonCapturing(_)
+            | GroupKind::LookAround(_)
+            | GroupKind::Atomic => None,
         }
     }
 }
@@ -1468,6 +1706,28 @@ pub enum GroupKind {
     },
     /// `(?:a)` and `(?i:a)`
     NonCapturing(Flags),
+    /// `(?=a)`, `(?!a)`, `(?<=a)` and `(?<!a)`
+    LookAround(LookAroundKind),
+    /// `(?>a)`
+    Atomic,
+}
+
+/// The kind of a look-around assertion.
+///
+/// A look-around assertion is a group whose sub-expression must (or must
+/// not) match immediately before or after the current position, without
+/// consuming any of the haystack.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LookAroundKind {
+    /// `(?=a)`
+    PositiveLookAhead,
+    /// `(?!a)`
+    NegativeLookAhead,
+    /// `(?<=a)`
+    PositiveLookBehind,
+    /// `(?<!a)`
+    NegativeLookBehind,
 }
 
 /// A capture name.
@@ -1627,6 +1887,8 @@ pub enum Flag {
     CRLF,
     /// `x`
     IgnoreWhitespace,
+    /// `g`
+    Grapheme,
 }
 
 /// A custom `Drop` impl is used for `Ast` such that it uses constant stack
@@ -1643,12 +1905,17 @@ impl Drop for Ast {
             | Ast::Assertion(_)
             | Ast::ClassUnicode(_)
             | Ast::ClassPerl(_)
+            | Ast::Backreference(_)
+            | Ast::GraphemeCluster(_)
+            | Ast::NewlineSequence(_)
             // Bracketed classes are recursive, they get their own Drop impl.
             | Ast::ClassBracketed(_) => return,
             Ast::Repetition(ref x) if !x.ast.has_subexprs() => return,
             Ast::Group(ref x) if !x.ast.has_subexprs() => return,
             Ast::Alternation(ref x) if x.asts.is_empty() => return,
             Ast::Concat(ref x) if x.asts.is_empty() => return,
+            Ast::Intersection(ref x) if x.asts.is_empty() => return,
+            Ast::Complement(ref x) if !x.ast.has_subexprs() => return,
             _ => {}
         }
 
@@ -1664,6 +1931,9 @@ impl Drop for Ast {
                 | Ast::Assertion(_)
                 | Ast::ClassUnicode(_)
                 | Ast::ClassPerl(_)
+                | Ast::Backreference(_)
+                | Ast::GraphemeCluster(_)
+                | Ast::NewlineSequence(_)
                 // Bracketed classes are recursive, so they get their own Drop
                 // impl.
                 | Ast::ClassBracketed(_) => {}
@@ -1679,6 +1949,12 @@ impl Drop for Ast {
                 Ast::Concat(ref mut x) => {
                     stack.extend(x.asts.drain(..));
                 }
+                Ast::Intersection(ref mut x) => {
+                    stack.extend(x.asts.drain(..));
+                }
+                Ast::Complement(ref mut x) => {
+                    stack.push(mem::replace(&mut x.ast, empty_ast()));
+                }
             }
         }
     }
@@ -36,6 +36,9 @@ enum Primitive {
     Dot(Span),
     Perl(ast::ClassPerl),
     Unicode(ast::ClassUnicode),
+    Backreference(ast::Backreference),
+    GraphemeCluster(Span),
+    NewlineSequence(Span),
 }
 
 impl Primitive {
@@ -47,6 +50,9 @@ impl Primitive {
             Primitive::Dot(ref span) => span,
             Primitive::Perl(ref x) => &x.span,
             Primitive::Unicode(ref x) => &x.span,
+            Primitive::Backreference(ref x) => &x.span,
+            Primitive::GraphemeCluster(ref span) => span,
+            Primitive::NewlineSequence(ref span) => span,
         }
     }
 
@@ -58,13 +64,17 @@ impl Primitive {
             Primitive::Dot(span) => Ast::dot(span),
             Primitive::Perl(cls) => Ast::class_perl(cls),
             Primitive::Unicode(cls) => Ast::class_unicode(cls),
+            Primitive::Backreference(x) => Ast::backreference(x),
+            Primitive::GraphemeCluster(span) => Ast::grapheme_cluster(span),
+            Primitive::NewlineSequence(span) => Ast::newline_sequence(span),
         }
     }
 
     /// Convert this primitive into an item in a character class.
     ///
-    /// If this primitive is not a legal item (i.e., an assertion or a dot),
-    /// then return an error.
+    /// If this primitive is not a legal item (i.e., an assertion, a dot, a
+    /// grapheme cluster, a newline sequence or a backreference), then return
+    /// an error.
     fn into_class_set_item<P: Borrow<Parser>>(
         self,
         p: &ParserI<'_, P>,
@@ -99,6 +109,15 @@ impl Primitive {
     }
 }
 
+/// Returns true if the given AST is a complement operator whose operand has
+/// not been parsed yet.
+fn is_open_complement(ast: &Ast) -> bool {
+    match *ast {
+        Ast::Complement(ref x) => x.ast.is_empty(),
+        _ => false,
+    }
+}
+
 /// Returns true if the given character is a hexadecimal digit.
 fn is_hex(c: char) -> bool {
     ('0' <= c && c <= '9') || ('a' <= c && c <= 'f') || ('A' <= c && c <= 'F')
@@ -116,15 +135,84 @@ fn is_capture_char(c: char, first: bool) -> bool {
     }
 }
 
+/// The dialect of concrete syntax accepted by a parser.
+///
+/// A dialect only ever adds syntax on top of what this crate documents, or
+/// changes the meaning of a few escape sequences that other regex engines
+/// interpret differently. Either way, the resulting [`Ast`] uses the same
+/// types regardless of dialect, and so translating it to an `Hir` doesn't
+/// depend on the dialect used.
+///
+/// This type is marked as `non_exhaustive`. This means that adding a new
+/// variant is not considered a breaking change.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Dialect {
+    /// The syntax documented by this crate. This is the default.
+    Default,
+    /// Accept syntax from PCRE (and, where it agrees with PCRE, Oniguruma)
+    /// in addition to the default syntax. Specifically:
+    ///
+    /// * `\Q...\E` matches the text between `\Q` and `\E` literally,
+    /// including inside of character classes. A `\E` without a preceding
+    /// `\Q` is ignored, and a missing `\E` quotes the rest of the pattern.
+    /// * `(?#...)` is a comment and is ignored.
+    /// * `\h` and `\H` match horizontal whitespace and its complement.
+    /// * `\v` and `\V` match vertical whitespace and its complement. Note
+    /// that this means `\v` no longer matches only a vertical tab.
+    /// * `\R` matches any newline sequence, i.e., `\r\n`, `\n`, `\x0B`,
+    /// `\x0C`, `\r`, `\x85`, `\u2028` or `\u2029`. Unlike PCRE, a `\r\n`
+    /// matched by `\R` may be given back if the rest of the pattern requires
+    /// it.
+    /// * `\Z` matches at the end of the haystack or before a `\n` at the end
+    /// of the haystack. This is implemented with a look-ahead, so `\Z`
+    /// can't be used inside of a look-around assertion.
+    /// * `\x` may be followed by zero, one or two hexadecimal digits, e.g.,
+    /// `\x` is `\x00` and `\x9` is `\x09`.
+    /// * `\0` followed by up to two octal digits is an octal escape, even
+    /// when octal syntax is otherwise disabled. `\1` through `\9` are
+    /// always backreferences.
+    /// * `\p{^Greek}` is equivalent to `\P{Greek}`.
+    /// * Backreferences may also be written as `\g1`, `\g{1}`, `\g{-1}`
+    /// (relative to the most recently opened group), `\g{name}`,
+    /// `\k{name}`, `\k'name'` or `(?P=name)`. These still require
+    /// backreferences to be enabled. The printer writes them using the
+    /// default syntax, i.e., `\1` or `\k<name>`.
+    ///
+    /// PCRE syntax for features that this crate does not support is
+    /// reported with a specific error instead of a generic syntax error.
+    /// This includes backreferences when they aren't enabled, recursion and
+    /// subroutine calls (e.g., `(?R)`, `(?1)` or `\g<name>`), conditionals,
+    /// branch reset groups, backtracking control verbs such as `(*SKIP)`,
+    /// `\G` and `\K`.
+    ///
+    /// Note that `$` retains its meaning from this crate. That is, when
+    /// multi-line mode is disabled, it only matches at the end of the
+    /// haystack. Use `\Z` for PCRE's interpretation. Note also that
+    /// Oniguruma uses `\h` to mean a hexadecimal digit, but this dialect
+    /// follows PCRE.
+    Pcre,
+}
+
+impl Default for Dialect {
+    fn default() -> Dialect {
+        Dialect::Default
+    }
+}
+
 /// A builder for a regular expression parser.
 ///
 /// This builder permits modifying configuration options for the parser.
 #[derive(Clone, Debug)]
 pub struct ParserBuilder {
+    dialect: Dialect,
     ignore_whitespace: bool,
     nest_limit: u32,
     octal: bool,
     empty_min_range: bool,
+    boolean_operators: bool,
+    backreferences: bool,
+    possessive_quantifiers: bool,
 }
 
 impl Default for ParserBuilder {
@@ -137,10 +225,14 @@ impl ParserBuilder {
     /// Create a new parser builder with a default configuration.
     pub fn new() -> ParserBuilder {
         ParserBuilder {
+            dialect: Dialect::Default,
             ignore_whitespace: false,
             nest_limit: 250,
             octal: false,
             empty_min_range: false,
+            boolean_operators: false,
+            backreferences: false,
+            possessive_quantifiers: false,
         }
     }
 
@@ -149,9 +241,13 @@ impl ParserBuilder {
         Parser {
             pos: Cell::new(Position { offset: 0, line: 1, column: 1 }),
             capture_index: Cell::new(0),
+            dialect: self.dialect,
             nest_limit: self.nest_limit,
             octal: self.octal,
             empty_min_range: self.empty_min_range,
+            boolean_operators: self.boolean_operators,
+            backreferences: self.backreferences,
+            possessive_quantifiers: self.possessive_quantifiers,
             initial_ignore_whitespace: self.ignore_whitespace,
             ignore_whitespace: Cell::new(self.ignore_whitespace),
             comments: RefCell::new(vec![]),
@@ -236,6 +332,77 @@ impl ParserBuilder {
         self.empty_min_range = yes;
         self
     }
+
+    /// Enable the boolean operators `&` (intersection) and `~` (complement).
+    ///
+    /// When enabled, `a&b` matches a string if and only if both `a` and `b`
+    /// match that same string, and `~a` matches a string if and only if `a`
+    /// does not match it. The `&` operator binds more loosely than
+    /// concatenation but more tightly than alternation, while `~` is a
+    /// prefix operator that binds more tightly than repetition operators.
+    /// For example, `~ab` is equivalent to `(?:~a)b` and `~a*` is equivalent
+    /// to `(?:~a)*`. Use a group, as in `~(?:ab)`, to complement a longer
+    /// expression.
+    ///
+    /// When this is enabled, a literal `&` or `~` must be escaped outside of
+    /// character classes, e.g., `\&` or `\~`. Inside of character classes,
+    /// the meaning of `&&` and `~~` is unchanged.
+    ///
+    /// This is disabled by default.
+    pub fn boolean_operators(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.boolean_operators = yes;
+        self
+    }
+
+    /// Enable backreferences, e.g., `\1` or `\k<name>`.
+    ///
+    /// When enabled, `\N` (where `N` is a decimal number that doesn't start
+    /// with `0`) refers to the capturing group with index `N`, and
+    /// `\k<name>` refers to the capturing group with the given name. A
+    /// backreference matches exactly the text most recently matched by the
+    /// group it refers to.
+    ///
+    /// Backreferences cannot be implemented by finite automata, and so most
+    /// regex engines in this ecosystem will refuse to compile a regex that
+    /// uses them. Only enable this when the result will be executed by an
+    /// engine that supports them, such as the backtracking engine in
+    /// `regex-automata`, which provides no guarantee of linear time.
+    ///
+    /// When both this and [`ParserBuilder::octal`] are enabled, `\0`
+    /// followed by octal digits is still an octal escape, but `\1` through
+    /// `\9` are always backreferences.
+    ///
+    /// This is disabled by default.
+    pub fn backreferences(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.backreferences = yes;
+        self
+    }
+
+    /// Enable possessive repetition operators, e.g., `a*+`, `a++`, `a?+`
+    /// and `a{m,n}+`.
+    ///
+    /// A possessive repetition matches as much as it can, just like a
+    /// greedy repetition, but never gives any of it back. That is, `a*+`
+    /// is equivalent to the atomic group `(?>a*)`. Atomic groups are always
+    /// supported, regardless of this setting.
+    ///
+    /// This is disabled by default because, without it, a `+` following
+    /// a repetition operator repeats the preceding repetition. For example,
+    /// `a*+` is equivalent to `(?:a*)+` when this is disabled.
+    pub fn possessive_quantifiers(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.possessive_quantifiers = yes;
+        self
+    }
+
+    /// Set the dialect of concrete syntax accepted by the parser.
+    ///
+    /// See [`Dialect`] for the syntax each dialect accepts.
+    ///
+    /// This is set to [`Dialect::Default`] by default.
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut ParserBuilder {
+        self.dialect = dialect;
+        self
+    }
 }
 
 /// A regular expression parser.
@@ -251,6 +418,8 @@ pub struct Parser {
     pos: Cell<Position>,
     /// The current capture index.
     capture_index: Cell<u32>,
+    /// The dialect of concrete syntax accepted by the parser.
+    dialect: Dialect,
     /// The maximum number of open parens/brackets allowed. If the parser
     /// exceeds this number, then an error is returned.
     nest_limit: u32,
@@ -264,6 +433,15 @@ pub struct Parser {
     /// Whether the parser supports `{,n}` repetitions as an equivalent to
     /// `{0,n}.`
     empty_min_range: bool,
+    /// Whether the parser treats `&` and `~` as the intersection and
+    /// complement operators.
+    boolean_operators: bool,
+    /// Whether the parser supports backreferences such as `\1` and
+    /// `\k<name>`.
+    backreferences: bool,
+    /// Whether the parser treats a `+` following a repetition operator as
+    /// making that operator possessive.
+    possessive_quantifiers: bool,
     /// Whether whitespace should be ignored. When enabled, comments are
     /// also permitted.
     ignore_whitespace: Cell<bool>,
@@ -300,8 +478,8 @@ struct ParserI<'s, P> {
 }
 
 /// GroupState represents a single stack frame while parsing nested groups
-/// and alternations. Each frame records the state up to an opening parenthesis
-/// or a alternating bracket `|`.
+/// and alternations. Each frame records the state up to an opening
+/// parenthesis, an alternating bracket `|` or an intersection operator `&`.
 #[derive(Clone, Debug)]
 enum GroupState {
     /// This state is pushed whenever an opening group is found.
@@ -318,6 +496,15 @@ enum GroupState {
     /// stack, then this state should be modified to include the new
     /// alternation.
     Alternation(ast::Alternation),
+    /// This state is pushed whenever a new intersection operand is found. If
+    /// an intersection operand is found and this state is at the top of the
+    /// stack, then this state should be modified to include the new operand.
+    ///
+    /// Since `&` binds more tightly than `|`, this state is always popped
+    /// and added to an alternation (or group) before a new alternation
+    /// branch is started. So when present, it is always at the top of the
+    /// stack.
+    Intersection(ast::Intersection),
 }
 
 /// ClassState represents a single stack frame while parsing character classes.
@@ -470,6 +657,17 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         self.parser().ignore_whitespace.get()
     }
 
+    /// Return whether the parser accepts the PCRE dialect or not.
+    fn pcre(&self) -> bool {
+        self.parser().dialect == Dialect::Pcre
+    }
+
+    /// Return true if and only if the parser accepts the PCRE dialect and
+    /// the substring starting at the current position has the given prefix.
+    fn is_pcre_prefix(&self, prefix: &str) -> bool {
+        self.pcre() && self.pattern()[self.offset()..].starts_with(prefix)
+    }
+
     /// Return the character at the current position of the parser.
     ///
     /// This panics if the current position does not point to a valid char.
@@ -521,18 +719,26 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         }
     }
 
-    /// Returns true if and only if the parser is positioned at a look-around
-    /// prefix. The conditions under which this returns true must always
-    /// correspond to a regular expression that would otherwise be consider
-    /// invalid.
+    /// If the parser is positioned at a look-around prefix, then this bumps
+    /// the parser past the prefix and returns the kind of look-around
+    /// assertion. Otherwise, the parser is not moved and `None` is returned.
     ///
     /// This should only be called immediately after parsing the opening of
     /// a group or a set of flags.
-    fn is_lookaround_prefix(&self) -> bool {
-        self.bump_if("?=")
-            || self.bump_if("?!")
-            || self.bump_if("?<=")
-            || self.bump_if("?<!")
+    fn parse_lookaround_prefix(&self) -> Option<ast::LookAroundKind> {
+        use crate::ast::LookAroundKind::*;
+
+        if self.bump_if("?=") {
+            Some(PositiveLookAhead)
+        } else if self.bump_if("?!") {
+            Some(NegativeLookAhead)
+        } else if self.bump_if("?<=") {
+            Some(PositiveLookBehind)
+        } else if self.bump_if("?<!") {
+            Some(NegativeLookBehind)
+        } else {
+            None
+        }
     }
 
     /// Bump the parser, and if the `x` flag is enabled, bump through any
@@ -664,7 +870,9 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
     #[inline(never)]
     fn push_alternate(&self, mut concat: ast::Concat) -> Result<ast::Concat> {
         assert_eq!(self.char(), '|');
+        self.check_complement_closed(&concat)?;
         concat.span.end = self.pos();
+        let concat = self.pop_intersection(concat);
         self.push_or_add_alternation(concat);
         self.bump();
         Ok(ast::Concat { span: self.span(), asts: vec![] })
@@ -686,6 +894,112 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         }));
     }
 
+    /// Parse and push a single intersection operand on to the parser's
+    /// internal stack. If the top of the stack already has an intersection,
+    /// then add to that instead of pushing a new one.
+    ///
+    /// The concatenation given corresponds to a single intersection operand.
+    /// The concatenation returned starts the next operand and is empty.
+    ///
+    /// This assumes the parser is currently positioned at `&` and will advance
+    /// the parser to the character following `&`.
+    #[inline(never)]
+    fn push_intersect(&self, mut concat: ast::Concat) -> Result<ast::Concat> {
+        assert_eq!(self.char(), '&');
+        self.check_complement_closed(&concat)?;
+        concat.span.end = self.pos();
+        let mut stack = self.parser().stack_group.borrow_mut();
+        if let Some(&mut GroupState::Intersection(ref mut inter)) =
+            stack.last_mut()
+        {
+            inter.asts.push(concat.into_ast());
+        } else {
+            stack.push(GroupState::Intersection(ast::Intersection {
+                span: Span::new(concat.span.start, self.pos()),
+                asts: vec![concat.into_ast()],
+            }));
+        }
+        self.bump();
+        Ok(ast::Concat { span: self.span(), asts: vec![] })
+    }
+
+    /// If the top of the parser's internal stack is an intersection, then
+    /// pop it, add the given concatenation as its final operand and return
+    /// a concatenation containing just the intersection. Otherwise, the
+    /// given concatenation is returned unchanged.
+    ///
+    /// The end of the given concatenation's span should already be set.
+    fn pop_intersection(&self, concat: ast::Concat) -> ast::Concat {
+        let mut stack = self.parser().stack_group.borrow_mut();
+        let mut inter = match stack.pop() {
+            Some(GroupState::Intersection(inter)) => inter,
+            Some(state) => {
+                stack.push(state);
+                return concat;
+            }
+            None => return concat,
+        };
+        inter.span.end = concat.span.end;
+        inter.asts.push(concat.into_ast());
+        ast::Concat { span: inter.span, asts: vec![inter.into_ast()] }
+    }
+
+    /// Push a complement operator on to the end of the given concatenation.
+    /// Its operand is filled in by the next expression added to the
+    /// concatenation via `push_concat`.
+    ///
+    /// Until its operand is found, a complement is represented by an
+    /// `Ast::Complement` whose sub-expression is `Ast::Empty`. This never
+    /// occurs in a completed AST, since the operand of a complement is
+    /// always a non-empty expression like a literal, class or group.
+    ///
+    /// This assumes the parser is currently positioned at `~` and will advance
+    /// the parser to the character following `~`.
+    #[inline(never)]
+    fn push_complement(&self, mut concat: ast::Concat) -> ast::Concat {
+        assert_eq!(self.char(), '~');
+        let span = self.span_char();
+        self.bump();
+        concat.asts.push(Ast::complement(ast::Complement {
+            span,
+            ast: Box::new(Ast::empty(self.span())),
+        }));
+        concat
+    }
+
+    /// Push the given expression on to the end of the given concatenation.
+    ///
+    /// If the concatenation ends with one or more complement operators that
+    /// are still missing their operand, then the expression given becomes the
+    /// operand of the last such operator, which in turn becomes the operand
+    /// of the one before it, and so on.
+    fn push_concat(&self, concat: &mut ast::Concat, mut ast: Ast) {
+        let mut start = concat.asts.len();
+        while start > 0 && is_open_complement(&concat.asts[start - 1]) {
+            start -= 1;
+        }
+        let opens: Vec<Ast> = concat.asts.drain(start..).collect();
+        for mut open in opens.into_iter().rev() {
+            if let Ast::Complement(ref mut x) = open {
+                x.span.end = ast.span().end;
+                *x.ast = ast;
+            }
+            ast = open;
+        }
+        concat.asts.push(ast);
+    }
+
+    /// Returns an error if the given concatenation ends with a complement
+    /// operator that is missing its operand.
+    fn check_complement_closed(&self, concat: &ast::Concat) -> Result<()> {
+        match concat.asts.last() {
+            Some(ast) if is_open_complement(ast) => {
+                Err(self.error(*ast.span(), ast::ErrorKind::ComplementMissing))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Parse and push a group AST (and its parent concatenation) on to the
     /// parser's internal stack. Return a fresh concatenation corresponding
     /// to the group's sub-AST.
@@ -704,6 +1018,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         assert_eq!(self.char(), '(');
         match self.parse_group()? {
             Either::Left(set) => {
+                self.check_complement_closed(&concat)?;
                 let ignore = set.flags.flag_state(ast::Flag::IgnoreWhitespace);
                 if let Some(v) = ignore {
                     self.parser().ignore_whitespace.set(v);
@@ -745,6 +1060,9 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         use self::GroupState::*;
 
         assert_eq!(self.char(), ')');
+        self.check_complement_closed(&group_concat)?;
+        group_concat.span.end = self.pos();
+        let mut group_concat = self.pop_intersection(group_concat);
         let mut stack = self.parser().stack_group.borrow_mut();
         let (mut prior_concat, mut group, ignore_whitespace, alt) = match stack
             .pop()
@@ -756,14 +1074,14 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 Some(Group { concat, group, ignore_whitespace }) => {
                     (concat, group, ignore_whitespace, Some(alt))
                 }
-                None | Some(Alternation(_)) => {
+                None | Some(Alternation(_)) | Some(Intersection(_)) => {
                     return Err(self.error(
                         self.span_char(),
                         ast::ErrorKind::GroupUnopened,
                     ));
                 }
             },
-            None => {
+            None | Some(Intersection(_)) => {
                 return Err(self
                     .error(self.span_char(), ast::ErrorKind::GroupUnopened));
             }
@@ -782,7 +1100,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 group.ast = Box::new(group_concat.into_ast());
             }
         }
-        prior_concat.asts.push(Ast::group(group));
+        self.push_concat(&mut prior_concat, Ast::group(group));
         Ok(prior_concat)
     }
 
@@ -794,7 +1112,9 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
     /// This assumes that the parser has advanced to the end.
     #[inline(never)]
     fn pop_group_end(&self, mut concat: ast::Concat) -> Result<Ast> {
+        self.check_complement_closed(&concat)?;
         concat.span.end = self.pos();
+        let concat = self.pop_intersection(concat);
         let mut stack = self.parser().stack_group.borrow_mut();
         let ast = match stack.pop() {
             None => Ok(concat.into_ast()),
@@ -808,11 +1128,17 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                     self.error(group.span, ast::ErrorKind::GroupUnclosed)
                 );
             }
+            Some(GroupState::Intersection(_)) => {
+                // This is impossible since 'pop_intersection' above always
+                // pops an intersection at the top of the stack.
+                unreachable!()
+            }
         };
         // If we try to pop again, there should be nothing.
         match stack.pop() {
             None => ast,
-            Some(GroupState::Alternation(_)) => {
+            Some(GroupState::Alternation(_))
+            | Some(GroupState::Intersection(_)) => {
                 // This unreachable is unfortunate. This case can't happen
                 // because the only way we can be here is if there were two
                 // `GroupState::Alternation`s adjacent in the parser's stack,
@@ -989,12 +1315,27 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 break;
             }
             match self.char() {
+                '(' if self.is_pcre_prefix("(?#") => {
+                    self.parse_pcre_comment()?
+                }
+                '(' if self.is_pcre_prefix("(?P=") => {
+                    let backref = self.parse_pcre_named_backreference()?;
+                    let ast =
+                        self.backreference_primitive(backref)?.into_ast();
+                    self.push_concat(&mut concat, ast);
+                }
                 '(' => concat = self.push_group(concat)?,
                 ')' => concat = self.pop_group(concat)?,
                 '|' => concat = self.push_alternate(concat)?,
+                '&' if self.parser().boolean_operators => {
+                    concat = self.push_intersect(concat)?
+                }
+                '~' if self.parser().boolean_operators => {
+                    concat = self.push_complement(concat)
+                }
                 '[' => {
                     let class = self.parse_set_class()?;
-                    concat.asts.push(Ast::class_bracketed(class));
+                    self.push_concat(&mut concat, Ast::class_bracketed(class));
                 }
                 '?' => {
                     concat = self.parse_uncounted_repetition(
@@ -1017,7 +1358,17 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 '{' => {
                     concat = self.parse_counted_repetition(concat)?;
                 }
-                _ => concat.asts.push(self.parse_primitive()?.into_ast()),
+                '\\' if self.is_pcre_prefix(r"\Q")
+                    || self.is_pcre_prefix(r"\E") =>
+                {
+                    for lit in self.parse_pcre_quote() {
+                        self.push_concat(&mut concat, Ast::literal(lit));
+                    }
+                }
+                _ => {
+                    let ast = self.parse_primitive()?.into_ast();
+                    self.push_concat(&mut concat, ast);
+                }
             }
         }
         let ast = self.pop_group_end(concat)?;
@@ -1039,7 +1390,9 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
     /// This assumes that the parser is currently positioned at the repetition
     /// operator and advances the parser to the first character after the
     /// operator. (Note that the operator may include a single additional `?`,
-    /// which makes the operator ungreedy.)
+    /// which makes the operator ungreedy, or a single additional `+`, which
+    /// makes the operator possessive when possessive quantifiers are
+    /// enabled.)
     ///
     /// The caller should include the concatenation that is being built. The
     /// concatenation returned includes the repetition operator applied to the
@@ -1068,12 +1421,24 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                     self.error(self.span(), ast::ErrorKind::RepetitionMissing)
                 )
             }
+            Ast::Complement(ref x) if x.ast.is_empty() => {
+                return Err(
+                    self.error(x.span, ast::ErrorKind::ComplementMissing)
+                )
+            }
             _ => {}
         }
-        let mut greedy = true;
-        if self.bump() && self.char() == '?' {
-            greedy = false;
-            self.bump();
+        let (mut greedy, mut possessive) = (true, false);
+        if self.bump() {
+            if self.char() == '?' {
+                greedy = false;
+                self.bump();
+            } else if self.parser().possessive_quantifiers
+                && self.char() == '+'
+            {
+                possessive = true;
+                self.bump();
+            }
         }
         concat.asts.push(Ast::repetition(ast::Repetition {
             span: ast.span().with_end(self.pos()),
@@ -1082,6 +1447,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 kind,
             },
             greedy,
+            possessive,
             ast: Box::new(ast),
         }));
         Ok(concat)
@@ -1094,7 +1460,8 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
     /// This assumes that the parser is currently positioned at the opening `{`
     /// and advances the parser to the first character after the operator.
     /// (Note that the operator may include a single additional `?`, which
-    /// makes the operator ungreedy.)
+    /// makes the operator ungreedy, or a single additional `+`, which makes
+    /// the operator possessive when possessive quantifiers are enabled.)
     ///
     /// The caller should include the concatenation that is being built. The
     /// concatenation returned includes the repetition operator applied to the
@@ -1120,6 +1487,11 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                     self.error(self.span(), ast::ErrorKind::RepetitionMissing)
                 )
             }
+            Ast::Complement(ref x) if x.ast.is_empty() => {
+                return Err(
+                    self.error(x.span, ast::ErrorKind::ComplementMissing)
+                )
+            }
             _ => {}
         }
         if !self.bump_and_bump_space() {
@@ -1181,10 +1553,17 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
             ));
         }
 
-        let mut greedy = true;
-        if self.bump_and_bump_space() && self.char() == '?' {
-            greedy = false;
-            self.bump();
+        let (mut greedy, mut possessive) = (true, false);
+        if self.bump_and_bump_space() {
+            if self.char() == '?' {
+                greedy = false;
+                self.bump();
+            } else if self.parser().possessive_quantifiers
+                && self.char() == '+'
+            {
+                possessive = true;
+                self.bump();
+            }
         }
 
         let op_span = Span::new(start, self.pos());
@@ -1200,6 +1579,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 kind: ast::RepetitionKind::Range(range),
             },
             greedy,
+            possessive,
             ast: Box::new(ast),
         }));
         Ok(concat)
@@ -1229,11 +1609,22 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         let open_span = self.span_char();
         self.bump();
         self.bump_space();
-        if self.is_lookaround_prefix() {
-            return Err(self.error(
-                Span::new(open_span.start, self.span().end),
-                ast::ErrorKind::UnsupportedLookAround,
-            ));
+        if self.pcre() {
+            self.check_pcre_group(open_span)?;
+        }
+        if let Some(kind) = self.parse_lookaround_prefix() {
+            return Ok(Either::Right(ast::Group {
+                span: open_span,
+                kind: ast::GroupKind::LookAround(kind),
+                ast: Box::new(Ast::empty(self.span())),
+            }));
+        }
+        if self.bump_if("?>") {
+            return Ok(Either::Right(ast::Group {
+                span: open_span,
+                kind: ast::GroupKind::Atomic,
+                ast: Box::new(Ast::empty(self.span())),
+            }));
         }
         let inner_span = self.span();
         let mut starts_with_p = true;
@@ -1288,6 +1679,78 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         }
     }
 
+    /// Return an error if the parser is positioned immediately after the
+    /// opening `(` of a PCRE group construct that isn't supported, e.g.,
+    /// `(?R)` or `(*SKIP)`. Otherwise, the parser is not moved.
+    ///
+    /// For recursion and backtracking control verbs, the span of the error
+    /// extends to the closing `)`. For conditionals and branch resets, it
+    /// only covers the opening of the group, since the rest of the group
+    /// may be arbitrarily long.
+    fn check_pcre_group(&self, open_span: Span) -> Result<()> {
+        let rest = &self.pattern()[self.offset()..];
+        let is_recursion = match rest.strip_prefix('?') {
+            None => false,
+            Some(rest) => {
+                let number = rest
+                    .strip_prefix(|c| c == '+' || c == '-')
+                    .unwrap_or(rest);
+                rest.starts_with("R)")
+                    || rest.starts_with('&')
+                    || rest.starts_with("P>")
+                    || number.starts_with(|c: char| c.is_ascii_digit())
+            }
+        };
+        let (kind, prefix) = if rest.starts_with('*') {
+            (ast::ErrorKind::UnsupportedBacktrackingVerb, None)
+        } else if rest.starts_with("?(") {
+            (ast::ErrorKind::UnsupportedConditional, Some("?("))
+        } else if rest.starts_with("?|") {
+            (ast::ErrorKind::UnsupportedBranchReset, Some("?|"))
+        } else if is_recursion {
+            (ast::ErrorKind::UnsupportedRecursion, None)
+        } else {
+            return Ok(());
+        };
+        match prefix {
+            Some(prefix) => assert!(self.bump_if(prefix)),
+            None => {
+                while !self.is_eof() && self.char() != ')' {
+                    self.bump();
+                }
+                self.bump();
+            }
+        }
+        Err(self.error(Span::new(open_span.start, self.pos()), kind))
+    }
+
+    /// Parse a PCRE comment, i.e., `(?#...)`, and add it to the parser's list
+    /// of comments. This expects the parser to be positioned at the opening
+    /// `(` and advances the parser to the first character following the
+    /// closing `)`.
+    ///
+    /// As in PCRE, the comment ends at the first `)`. There is no way to
+    /// escape a `)` inside of a comment.
+    #[inline(never)]
+    fn parse_pcre_comment(&self) -> Result<()> {
+        let open_span = self.span_char();
+        assert!(self.bump_if("(?#"));
+        let text_start = self.offset();
+        while !self.is_eof() && self.char() != ')' {
+            self.bump();
+        }
+        if self.is_eof() {
+            return Err(self.error(open_span, ast::ErrorKind::GroupUnclosed));
+        }
+        let comment = self.pattern()[text_start..self.offset()].to_string();
+        self.bump();
+        self.parser().comments.borrow_mut().push(ast::Comment {
+            span: Span::new(open_span.start, self.pos()),
+            comment,
+        });
+        Ok(())
+    }
+
     /// Parses a capture group name. Assumes that the parser is positioned at
     /// the first character in the name following the opening `<` (and may
     /// possibly be EOF). This advances the parser to the first character
@@ -1417,6 +1880,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
             'u' => Ok(ast::Flag::Unicode),
             'R' => Ok(ast::Flag::CRLF),
             'x' => Ok(ast::Flag::IgnoreWhitespace),
+            'g' => Ok(ast::Flag::Grapheme),
             _ => {
                 Err(self
                     .error(self.span_char(), ast::ErrorKind::FlagUnrecognized))
@@ -1488,6 +1952,24 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         let c = self.char();
         // Put some of the more complicated routines into helpers.
         match c {
+            '1'..='9' if self.parser().backreferences || self.pcre() => {
+                let mut backref = self.parse_backreference_index()?;
+                backref.span.start = start;
+                return self.backreference_primitive(backref);
+            }
+            'k' if self.parser().backreferences || self.pcre() => {
+                let backref = self.parse_backreference_name(start)?;
+                return self.backreference_primitive(backref);
+            }
+            'g' if self.pcre() => {
+                let backref = self.parse_pcre_backreference(start)?;
+                return self.backreference_primitive(backref);
+            }
+            '0' if self.pcre() => {
+                let mut lit = self.parse_octal();
+                lit.span.start = start;
+                return Ok(Primitive::Literal(lit));
+            }
             '0'..='7' => {
                 if !self.parser().octal {
                     return Err(self.error(
@@ -1505,6 +1987,11 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                     ast::ErrorKind::UnsupportedBackreference,
                 ));
             }
+            'x' if self.pcre() && self.peek() != Some('{') => {
+                let mut lit = self.parse_pcre_hex();
+                lit.span.start = start;
+                return Ok(Primitive::Literal(lit));
+            }
             'x' | 'u' | 'U' => {
                 let mut lit = self.parse_hex()?;
                 lit.span.start = start;
@@ -1515,11 +2002,21 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 cls.span.start = start;
                 return Ok(Primitive::Unicode(cls));
             }
+            'N' if self.peek() == Some('{') => {
+                let mut lit = self.parse_unicode_name()?;
+                lit.span.start = start;
+                return Ok(Primitive::Literal(lit));
+            }
             'd' | 's' | 'w' | 'D' | 'S' | 'W' => {
                 let mut cls = self.parse_perl_class();
                 cls.span.start = start;
                 return Ok(Primitive::Perl(cls));
             }
+            'h' | 'v' | 'H' | 'V' if self.pcre() => {
+                let mut cls = self.parse_perl_class();
+                cls.span.start = start;
+                return Ok(Primitive::Perl(cls));
+            }
             _ => {}
         }
 
@@ -1547,6 +2044,30 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 c,
             }))
         };
+        if self.pcre() {
+            match c {
+                'R' => return Ok(Primitive::NewlineSequence(span)),
+                'Z' => {
+                    return Ok(Primitive::Assertion(ast::Assertion {
+                        span,
+                        kind: ast::AssertionKind::EndTextOptionalNewline,
+                    }))
+                }
+                'G' => {
+                    return Err(self.error(
+                        span,
+                        ast::ErrorKind::UnsupportedMatchStartAnchor,
+                    ))
+                }
+                'K' => {
+                    return Err(self.error(
+                        span,
+                        ast::ErrorKind::UnsupportedMatchStartReset,
+                    ))
+                }
+                _ => {}
+            }
+        }
         match c {
             'a' => special(ast::SpecialLiteralKind::Bell, '\x07'),
             'f' => special(ast::SpecialLiteralKind::FormFeed, '\x0C'),
@@ -1583,6 +2104,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                 span,
                 kind: ast::AssertionKind::NotWordBoundary,
             })),
+            'X' => Ok(Primitive::GraphemeCluster(span)),
             '<' => Ok(Primitive::Assertion(ast::Assertion {
                 span,
                 kind: ast::AssertionKind::WordBoundaryStartAngle,
@@ -1661,6 +2183,8 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
             "end" => ast::AssertionKind::WordBoundaryEnd,
             "start-half" => ast::AssertionKind::WordBoundaryStartHalf,
             "end-half" => ast::AssertionKind::WordBoundaryEndHalf,
+            "wb" => ast::AssertionKind::WordSegmentBoundary,
+            "sb" => ast::AssertionKind::SentenceSegmentBoundary,
             _ => {
                 return Err(self.error(
                     Span::new(start_contents, end),
@@ -1671,95 +2195,397 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         Ok(Some(kind))
     }
 
-    /// Parse an octal representation of a Unicode codepoint up to 3 digits
-    /// long. This expects the parser to be positioned at the first octal
-    /// digit and advances the parser to the first character immediately
-    /// following the octal number. This also assumes that parsing octal
-    /// escapes is enabled.
+    /// Parse a backreference by index, e.g., `\12`. This expects the parser
+    /// to be positioned at the first digit and advances the parser to the
+    /// first character following the number.
     ///
-    /// Assuming the preconditions are met, this routine can never fail.
+    /// Unlike counted repetitions, whitespace is never permitted within the
+    /// number, even when whitespace insensitive mode is enabled.
     #[inline(never)]
-    fn parse_octal(&self) -> ast::Literal {
-        assert!(self.parser().octal);
-        assert!('0' <= self.char() && self.char() <= '7');
+    fn parse_backreference_index(&self) -> Result<ast::Backreference> {
+        assert!('1' <= self.char() && self.char() <= '9');
         let start = self.pos();
-        // Parse up to two more digits.
-        while self.bump()
-            && '0' <= self.char()
-            && self.char() <= '7'
-            && self.pos().offset - start.offset <= 2
-        {}
+        while !self.is_eof() && self.char().is_ascii_digit() {
+            self.bump();
+        }
         let end = self.pos();
-        let octal = &self.pattern()[start.offset..end.offset];
-        // Parsing the octal should never fail since the above guarantees a
-        // valid number.
-        let codepoint =
-            u32::from_str_radix(octal, 8).expect("valid octal number");
-        // The max value for 3 digit octal is 0777 = 511 and [0, 511] has no
-        // invalid Unicode scalar values.
-        let c = char::from_u32(codepoint).expect("Unicode scalar value");
-        ast::Literal {
-            span: Span::new(start, end),
-            kind: ast::LiteralKind::Octal,
-            c,
+        let digits = &self.pattern()[start.offset..end.offset];
+        match u32::from_str_radix(digits, 10).ok() {
+            Some(index) => Ok(ast::Backreference {
+                span: Span::new(start, end),
+                kind: ast::BackreferenceKind::Index(index),
+            }),
+            None => Err(self
+                .error(Span::new(start, end), ast::ErrorKind::DecimalInvalid)),
         }
     }
 
-    /// Parse a hex representation of a Unicode codepoint. This handles both
-    /// hex notations, i.e., `\xFF` and `\x{FFFF}`. This expects the parser to
-    /// be positioned at the `x`, `u` or `U` prefix. The parser is advanced to
-    /// the first character immediately following the hexadecimal literal.
+    /// Parse a backreference by name, e.g., `\k<name>`. This expects the
+    /// parser to be positioned at the `k` and advances the parser to the
+    /// first character following the closing `>`. The given position should
+    /// correspond to the start of the backslash preceding the `k`.
+    ///
+    /// In the PCRE dialect, the name may also be delimited by `{...}` or
+    /// `'...'`.
     #[inline(never)]
-    fn parse_hex(&self) -> Result<ast::Literal> {
-        assert!(
-            self.char() == 'x' || self.char() == 'u' || self.char() == 'U'
-        );
-
-        let hex_kind = match self.char() {
-            'x' => ast::HexLiteralKind::X,
-            'u' => ast::HexLiteralKind::UnicodeShort,
-            _ => ast::HexLiteralKind::UnicodeLong,
-        };
-        if !self.bump_and_bump_space() {
+    fn parse_backreference_name(
+        &self,
+        start: Position,
+    ) -> Result<ast::Backreference> {
+        assert_eq!(self.char(), 'k');
+        if !self.bump() {
             return Err(
                 self.error(self.span(), ast::ErrorKind::EscapeUnexpectedEof)
             );
         }
-        if self.char() == '{' {
-            self.parse_hex_brace(hex_kind)
-        } else {
-            self.parse_hex_digits(hex_kind)
+        let close = match self.char() {
+            '<' => '>',
+            '{' if self.pcre() => '}',
+            '\'' if self.pcre() => '\'',
+            _ => {
+                return Err(self.error(
+                    Span::new(start, self.pos()),
+                    ast::ErrorKind::EscapeUnrecognized,
+                ))
+            }
+        };
+        if !self.bump() {
+            return Err(self
+                .error(self.span(), ast::ErrorKind::GroupNameUnexpectedEof));
         }
+        self.parse_backreference_name_until(start, close)
     }
 
-    /// Parse an N-digit hex representation of a Unicode codepoint. This
-    /// expects the parser to be positioned at the first digit and will advance
-    /// the parser to the first character immediately following the escape
-    /// sequence.
-    ///
-    /// The number of digits given must be 2 (for `\xNN`), 4 (for `\uNNNN`)
-    /// or 8 (for `\UNNNNNNNN`).
-    #[inline(never)]
-    fn parse_hex_digits(
+    /// Parse the name of a backreference up to and including the given
+    /// closing delimiter. This expects the parser to be positioned at the
+    /// first character of the name and advances the parser to the first
+    /// character following the delimiter. The given position should
+    /// correspond to the start of the backreference.
+    fn parse_backreference_name_until(
         &self,
-        kind: ast::HexLiteralKind,
-    ) -> Result<ast::Literal> {
-        let mut scratch = self.parser().scratch.borrow_mut();
-        scratch.clear();
-
-        let start = self.pos();
-        for i in 0..kind.digits() {
-            if i > 0 && !self.bump_and_bump_space() {
-                return Err(self
-                    .error(self.span(), ast::ErrorKind::EscapeUnexpectedEof));
-            }
-            if !is_hex(self.char()) {
+        start: Position,
+        close: char,
+    ) -> Result<ast::Backreference> {
+        let name_start = self.pos();
+        while self.char() != close {
+            if !is_capture_char(self.char(), self.pos() == name_start) {
                 return Err(self.error(
                     self.span_char(),
-                    ast::ErrorKind::EscapeHexInvalidDigit,
+                    ast::ErrorKind::GroupNameInvalid,
                 ));
             }
-            scratch.push(self.char());
+            if !self.bump() {
+                return Err(self.error(
+                    self.span(),
+                    ast::ErrorKind::GroupNameUnexpectedEof,
+                ));
+            }
+        }
+        let name_end = self.pos();
+        self.bump();
+        let name = &self.pattern()[name_start.offset..name_end.offset];
+        if name.is_empty() {
+            return Err(self.error(
+                Span::new(name_start, name_start),
+                ast::ErrorKind::GroupNameEmpty,
+            ));
+        }
+        Ok(ast::Backreference {
+            span: Span::new(start, self.pos()),
+            kind: ast::BackreferenceKind::Named(name.to_string()),
+        })
+    }
+
+    /// Parse a PCRE backreference that starts with `\g`, e.g., `\g1`,
+    /// `\g{-1}` or `\g{name}`. This expects the parser to be positioned at
+    /// the `g` and advances the parser to the first character following the
+    /// backreference. The given position should correspond to the start of
+    /// the backslash preceding the `g`.
+    ///
+    /// Since `\g<...>` and `\g'...'` are subroutine calls in PCRE, they
+    /// always result in an error.
+    #[inline(never)]
+    fn parse_pcre_backreference(
+        &self,
+        start: Position,
+    ) -> Result<ast::Backreference> {
+        assert!(self.pcre());
+        assert_eq!(self.char(), 'g');
+        if !self.bump() {
+            return Err(self.error(
+                Span::new(start, self.pos()),
+                ast::ErrorKind::EscapeUnexpectedEof,
+            ));
+        }
+        let braced = match self.char() {
+            '<' | '\'' => {
+                let close = if self.char() == '<' { '>' } else { '\'' };
+                while self.bump() && self.char() != close {}
+                self.bump();
+                return Err(self.error(
+                    Span::new(start, self.pos()),
+                    ast::ErrorKind::UnsupportedRecursion,
+                ));
+            }
+            '{' => {
+                if !self.bump() {
+                    return Err(self.error(
+                        Span::new(start, self.pos()),
+                        ast::ErrorKind::EscapeUnexpectedEof,
+                    ));
+                }
+                true
+            }
+            _ => false,
+        };
+        let relative = self.char() == '-';
+        if relative && !self.bump() {
+            return Err(self.error(
+                Span::new(start, self.pos()),
+                ast::ErrorKind::EscapeUnexpectedEof,
+            ));
+        }
+        if !self.char().is_ascii_digit() {
+            if braced && !relative {
+                return self.parse_backreference_name_until(start, '}');
+            }
+            return Err(self.error(
+                Span::new(start, self.pos()),
+                ast::ErrorKind::EscapeUnrecognized,
+            ));
+        }
+        let digits_start = self.pos();
+        while !self.is_eof() && self.char().is_ascii_digit() {
+            self.bump();
+        }
+        let digits_span = Span::new(digits_start, self.pos());
+        let digits = &self.pattern()[digits_start.offset..self.offset()];
+        let number = u32::from_str_radix(digits, 10).map_err(|_| {
+            self.error(digits_span, ast::ErrorKind::DecimalInvalid)
+        })?;
+        if braced {
+            if self.is_eof() {
+                return Err(self.error(
+                    Span::new(start, self.pos()),
+                    ast::ErrorKind::EscapeUnexpectedEof,
+                ));
+            }
+            if self.char() != '}' {
+                return Err(self.error(
+                    Span::new(start, self.span_char().end),
+                    ast::ErrorKind::EscapeUnrecognized,
+                ));
+            }
+            self.bump();
+        }
+        let index = if relative {
+            // `\g{-1}` refers to the most recently opened capturing group,
+            // whose index is the current capture index.
+            let current = self.parser().capture_index.get();
+            match (current + 1).checked_sub(number) {
+                Some(index) if number > 0 && index > 0 => index,
+                _ => {
+                    return Err(self
+                        .error(digits_span, ast::ErrorKind::DecimalInvalid))
+                }
+            }
+        } else {
+            number
+        };
+        Ok(ast::Backreference {
+            span: Span::new(start, self.pos()),
+            kind: ast::BackreferenceKind::Index(index),
+        })
+    }
+
+    /// Parse a PCRE backreference written as a group, i.e., `(?P=name)`.
+    /// This expects the parser to be positioned at the opening `(` and
+    /// advances the parser to the first character following the closing
+    /// `)`.
+    #[inline(never)]
+    fn parse_pcre_named_backreference(&self) -> Result<ast::Backreference> {
+        let start = self.pos();
+        assert!(self.bump_if("(?P="));
+        if self.is_eof() {
+            return Err(self
+                .error(self.span(), ast::ErrorKind::GroupNameUnexpectedEof));
+        }
+        self.parse_backreference_name_until(start, ')')
+    }
+
+    /// Convert the given backreference into a primitive, or return an error
+    /// if backreferences are disabled.
+    ///
+    /// In the PCRE dialect, backreferences are parsed even when they are
+    /// disabled so that this error points at the entire backreference.
+    fn backreference_primitive(
+        &self,
+        backref: ast::Backreference,
+    ) -> Result<Primitive> {
+        if !self.parser().backreferences {
+            return Err(self.error(
+                backref.span,
+                ast::ErrorKind::UnsupportedBackreference,
+            ));
+        }
+        Ok(Primitive::Backreference(backref))
+    }
+
+    /// Parse a PCRE quoted sequence, i.e., `\Q...\E`, into a sequence of
+    /// literals. This expects the parser to be positioned at the `\` of
+    /// either `\Q` or `\E` and advances the parser to the first character
+    /// following the closing `\E`, or to the end of the pattern if there is
+    /// no `\E`. A `\E` that isn't preceded by a `\Q` is ignored.
+    ///
+    /// Each literal is given a kind that, when printed, results in an
+    /// equivalent expression in the default dialect.
+    #[inline(never)]
+    fn parse_pcre_quote(&self) -> Vec<ast::Literal> {
+        assert!(self.is_pcre_prefix(r"\Q") || self.is_pcre_prefix(r"\E"));
+        let mut lits = vec![];
+        if self.bump_if(r"\E") {
+            return lits;
+        }
+        assert!(self.bump_if(r"\Q"));
+        while !self.is_eof() && !self.bump_if(r"\E") {
+            let c = self.char();
+            let kind = if is_meta_character(c) {
+                ast::LiteralKind::Meta
+            } else if is_escapeable_character(c) {
+                ast::LiteralKind::Superfluous
+            } else {
+                ast::LiteralKind::Verbatim
+            };
+            lits.push(ast::Literal { span: self.span_char(), kind, c });
+            self.bump();
+        }
+        lits
+    }
+
+    /// Parse an octal representation of a Unicode codepoint up to 3 digits
+    /// long. This expects the parser to be positioned at the first octal
+    /// digit and advances the parser to the first character immediately
+    /// following the octal number. This also assumes that parsing octal
+    /// escapes is enabled, or that the parser accepts the PCRE dialect.
+    ///
+    /// Assuming the preconditions are met, this routine can never fail.
+    #[inline(never)]
+    fn parse_octal(&self) -> ast::Literal {
+        assert!(self.parser().octal || self.pcre());
+        assert!('0' <= self.char() && self.char() <= '7');
+        let start = self.pos();
+        // Parse up to two more digits.
+        while self.bump()
+            && '0' <= self.char()
+            && self.char() <= '7'
+            && self.pos().offset - start.offset <= 2
+        {}
+        let end = self.pos();
+        let octal = &self.pattern()[start.offset..end.offset];
+        // Parsing the octal should never fail since the above guarantees a
+        // valid number.
+        let codepoint =
+            u32::from_str_radix(octal, 8).expect("valid octal number");
+        // The max value for 3 digit octal is 0777 = 511 and [0, 511] has no
+        // invalid Unicode scalar values.
+        let c = char::from_u32(codepoint).expect("Unicode scalar value");
+        ast::Literal {
+            span: Span::new(start, end),
+            kind: ast::LiteralKind::Octal,
+            c,
+        }
+    }
+
+    /// Parse a hex representation of a Unicode codepoint. This handles both
+    /// hex notations, i.e., `\xFF` and `\x{FFFF}`. This expects the parser to
+    /// be positioned at the `x`, `u` or `U` prefix. The parser is advanced to
+    /// the first character immediately following the hexadecimal literal.
+    #[inline(never)]
+    fn parse_hex(&self) -> Result<ast::Literal> {
+        assert!(
+            self.char() == 'x' || self.char() == 'u' || self.char() == 'U'
+        );
+
+        let hex_kind = match self.char() {
+            'x' => ast::HexLiteralKind::X,
+            'u' => ast::HexLiteralKind::UnicodeShort,
+            _ => ast::HexLiteralKind::UnicodeLong,
+        };
+        if !self.bump_and_bump_space() {
+            return Err(
+                self.error(self.span(), ast::ErrorKind::EscapeUnexpectedEof)
+            );
+        }
+        if self.char() == '{' {
+            self.parse_hex_brace(hex_kind)
+        } else {
+            self.parse_hex_digits(hex_kind)
+        }
+    }
+
+    /// Parse a PCRE hex escape without braces, i.e., `\x` followed by up to
+    /// two hexadecimal digits. No digits at all corresponds to `\x00`. This
+    /// expects the parser to be positioned at the `x` and advances the parser
+    /// to the first character following the digits.
+    ///
+    /// Since at most two digits are consumed, this can never fail.
+    #[inline(never)]
+    fn parse_pcre_hex(&self) -> ast::Literal {
+        assert!(self.pcre());
+        assert_eq!(self.char(), 'x');
+        self.bump();
+        let start = self.pos();
+        while !self.is_eof()
+            && is_hex(self.char())
+            && self.offset() - start.offset < 2
+        {
+            self.bump();
+        }
+        let end = self.pos();
+        let hex = &self.pattern()[start.offset..end.offset];
+        let codepoint = if hex.is_empty() {
+            0
+        } else {
+            u32::from_str_radix(hex, 16).expect("valid hex number")
+        };
+        // Two hex digits is at most 0xFF, which is always a valid scalar
+        // value.
+        let c = char::from_u32(codepoint).expect("Unicode scalar value");
+        ast::Literal {
+            span: Span::new(start, end),
+            kind: ast::LiteralKind::HexFixed(ast::HexLiteralKind::X),
+            c,
+        }
+    }
+
+    /// Parse an N-digit hex representation of a Unicode codepoint. This
+    /// expects the parser to be positioned at the first digit and will advance
+    /// the parser to the first character immediately following the escape
+    /// sequence.
+    ///
+    /// The number of digits given must be 2 (for `\xNN`), 4 (for `\uNNNN`)
+    /// or 8 (for `\UNNNNNNNN`).
+    #[inline(never)]
+    fn parse_hex_digits(
+        &self,
+        kind: ast::HexLiteralKind,
+    ) -> Result<ast::Literal> {
+        let mut scratch = self.parser().scratch.borrow_mut();
+        scratch.clear();
+
+        let start = self.pos();
+        for i in 0..kind.digits() {
+            if i > 0 && !self.bump_and_bump_space() {
+                return Err(self
+                    .error(self.span(), ast::ErrorKind::EscapeUnexpectedEof));
+            }
+            if !is_hex(self.char()) {
+                return Err(self.error(
+                    self.span_char(),
+                    ast::ErrorKind::EscapeHexInvalidDigit,
+                ));
+            }
+            scratch.push(self.char());
         }
         // The final bump just moves the parser past the literal, which may
         // be EOF.
@@ -1831,6 +2657,46 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         }
     }
 
+    /// Parse a Unicode character name escape, e.g.,
+    /// `\N{GREEK SMALL LETTER ALPHA}`. This expects the parser to be
+    /// positioned at the `N` and will advance the parser to the first
+    /// character following the closing brace `}`.
+    ///
+    /// The name is not looked up here, since that requires Unicode data.
+    /// The literal returned always uses `U+FFFD` as its codepoint, and the
+    /// name is resolved during translation.
+    ///
+    /// Whitespace inside the braces is kept even when insignificant
+    /// whitespace mode is enabled, since character names contain spaces.
+    #[inline(never)]
+    fn parse_unicode_name(&self) -> Result<ast::Literal> {
+        assert_eq!(self.char(), 'N');
+
+        let mut scratch = self.parser().scratch.borrow_mut();
+        scratch.clear();
+
+        self.bump();
+        let brace_pos = self.pos();
+        assert_eq!(self.char(), '{');
+        let start = self.span_char().end;
+        while self.bump() && self.char() != '}' {
+            scratch.push(self.char());
+        }
+        if self.is_eof() {
+            return Err(self.error(
+                Span::new(brace_pos, self.pos()),
+                ast::ErrorKind::EscapeUnexpectedEof,
+            ));
+        }
+        assert_eq!(self.char(), '}');
+        self.bump_and_bump_space();
+        Ok(ast::Literal {
+            span: Span::new(start, self.pos()),
+            kind: ast::LiteralKind::Named(scratch.clone()),
+            c: '\u{FFFD}',
+        })
+    }
+
     /// Parse a decimal number into a u32 while trimming leading and trailing
     /// whitespace.
     ///
@@ -1925,6 +2791,13 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
                         union,
                     );
                 }
+                '\\' if self.is_pcre_prefix(r"\Q")
+                    || self.is_pcre_prefix(r"\E") =>
+                {
+                    for lit in self.parse_pcre_quote() {
+                        union.push(ast::ClassSetItem::Literal(lit));
+                    }
+                }
                 _ => {
                     union.push(self.parse_set_class_range()?);
                 }
@@ -2170,7 +3043,7 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
         let mut scratch = self.parser().scratch.borrow_mut();
         scratch.clear();
 
-        let negated = self.char() == 'P';
+        let mut negated = self.char() == 'P';
         if !self.bump_and_bump_space() {
             return Err(
                 self.error(self.span(), ast::ErrorKind::EscapeUnexpectedEof)
@@ -2188,7 +3061,13 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
             assert_eq!(self.char(), '}');
             self.bump();
 
-            let name = scratch.as_str();
+            let mut name = scratch.as_str();
+            if self.pcre() {
+                if let Some(rest) = name.strip_prefix('^') {
+                    negated = !negated;
+                    name = rest;
+                }
+            }
             if let Some(i) = name.find("!=") {
                 (
                     start,
@@ -2254,6 +3133,10 @@ impl<'s, P: Borrow<Parser>> ParserI<'s, P> {
             'S' => (true, ast::ClassPerlKind::Space),
             'w' => (false, ast::ClassPerlKind::Word),
             'W' => (true, ast::ClassPerlKind::Word),
+            'h' => (false, ast::ClassPerlKind::HorizontalSpace),
+            'H' => (true, ast::ClassPerlKind::HorizontalSpace),
+            'v' => (false, ast::ClassPerlKind::VerticalSpace),
+            'V' => (true, ast::ClassPerlKind::VerticalSpace),
             c => panic!("expected valid Perl class but got '{}'", c),
         };
         ast::ClassPerl { span, kind, negated }
@@ -2321,7 +3204,10 @@ impl<'p, 's, P: Borrow<Parser>> ast::Visitor for NestLimiter<'p, 's, P> {
             | Ast::Dot(_)
             | Ast::Assertion(_)
             | Ast::ClassUnicode(_)
-            | Ast::ClassPerl(_) => {
+            | Ast::ClassPerl(_)
+            | Ast::Backreference(_)
+            | Ast::GraphemeCluster(_)
+            | Ast::NewlineSequence(_) => {
                 // These are all base cases, so we don't increment depth.
                 return Ok(());
             }
@@ -2330,6 +3216,8 @@ impl<'p, 's, P: Borrow<Parser>> ast::Visitor for NestLimiter<'p, 's, P> {
             Ast::Group(ref x) => &x.span,
             Ast::Alternation(ref x) => &x.span,
             Ast::Concat(ref x) => &x.span,
+            Ast::Intersection(ref x) => &x.span,
+            Ast::Complement(ref x) => &x.span,
         };
         self.increment_depth(span)
     }
@@ -2342,7 +3230,10 @@ impl<'p, 's, P: Borrow<Parser>> ast::Visitor for NestLimiter<'p, 's, P> {
             | Ast::Dot(_)
             | Ast::Assertion(_)
             | Ast::ClassUnicode(_)
-            | Ast::ClassPerl(_) => {
+            | Ast::ClassPerl(_)
+            | Ast::Backreference(_)
+            | Ast::GraphemeCluster(_)
+            | Ast::NewlineSequence(_) => {
                 // These are all base cases, so we don't decrement depth.
                 Ok(())
             }
@@ -2350,7 +3241,9 @@ impl<'p, 's, P: Borrow<Parser>> ast::Visitor for NestLimiter<'p, 's, P> {
             | Ast::Repetition(_)
             | Ast::Group(_)
             | Ast::Alternation(_)
-            | Ast::Concat(_) => {
+            | Ast::Concat(_)
+            | Ast::Intersection(_)
+            | Ast::Complement(_) => {
                 self.decrement_depth();
                 Ok(())
             }
@@ -2498,6 +3391,34 @@ mod tests {
         ParserI::new(parser, pattern)
     }
 
+    fn parser_boolean(pattern: &str) -> ParserI<'_, Parser> {
+        let parser = ParserBuilder::new().boolean_operators(true).build();
+        ParserI::new(parser, pattern)
+    }
+
+    fn parser_backreferences(pattern: &str) -> ParserI<'_, Parser> {
+        let parser = ParserBuilder::new().backreferences(true).build();
+        ParserI::new(parser, pattern)
+    }
+
+    fn parser_possessive(pattern: &str) -> ParserI<'_, Parser> {
+        let parser = ParserBuilder::new().possessive_quantifiers(true).build();
+        ParserI::new(parser, pattern)
+    }
+
+    fn parser_pcre(pattern: &str) -> ParserI<'_, Parser> {
+        let parser = ParserBuilder::new().dialect(Dialect::Pcre).build();
+        ParserI::new(parser, pattern)
+    }
+
+    fn parser_pcre_backreferences(pattern: &str) -> ParserI<'_, Parser> {
+        let parser = ParserBuilder::new()
+            .dialect(Dialect::Pcre)
+            .backreferences(true)
+            .build();
+        ParserI::new(parser, pattern)
+    }
+
     fn parser_nest_limit(
         pattern: &str,
         nest_limit: u32,
@@ -2657,6 +3578,7 @@ mod tests {
                     kind: ast::RepetitionKind::OneOrMore,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -2683,6 +3605,7 @@ mod tests {
                     kind: ast::RepetitionKind::ZeroOrMore,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(Ast::repetition(ast::Repetition {
                     span: span(0..2),
                     op: ast::RepetitionOp {
@@ -2690,6 +3613,7 @@ mod tests {
                         kind: ast::RepetitionKind::OneOrMore,
                     },
                     greedy: true,
+                    possessive: false,
                     ast: Box::new(lit('a', 0)),
                 })),
             }))
@@ -3074,6 +3998,7 @@ bar
                     kind: ast::RepetitionKind::ZeroOrMore,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3086,6 +4011,7 @@ bar
                     kind: ast::RepetitionKind::OneOrMore,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3099,6 +4025,7 @@ bar
                     kind: ast::RepetitionKind::ZeroOrOne,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3111,6 +4038,7 @@ bar
                     kind: ast::RepetitionKind::ZeroOrOne,
                 },
                 greedy: false,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3123,6 +4051,7 @@ bar
                     kind: ast::RepetitionKind::ZeroOrOne,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3138,6 +4067,7 @@ bar
                             kind: ast::RepetitionKind::ZeroOrOne,
                         },
                         greedy: true,
+                        possessive: false,
                         ast: Box::new(lit('a', 0)),
                     }),
                     lit('b', 2),
@@ -3156,6 +4086,7 @@ bar
                             kind: ast::RepetitionKind::ZeroOrOne,
                         },
                         greedy: false,
+                        possessive: false,
                         ast: Box::new(lit('a', 0)),
                     }),
                     lit('b', 3),
@@ -3175,6 +4106,7 @@ bar
                             kind: ast::RepetitionKind::ZeroOrOne,
                         },
                         greedy: true,
+                        possessive: false,
                         ast: Box::new(lit('b', 1)),
                     }),
                 ]
@@ -3189,6 +4121,7 @@ bar
                     kind: ast::RepetitionKind::ZeroOrOne,
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(group(
                     0..4,
                     1,
@@ -3209,6 +4142,7 @@ bar
                             kind: ast::RepetitionKind::ZeroOrOne,
                         },
                         greedy: true,
+                        possessive: false,
                         ast: Box::new(lit('a', 1)),
                     }),
                 ]
@@ -3288,32 +4222,131 @@ bar
     }
 
     #[test]
-    fn parse_counted_repetition() {
+    fn parse_possessive_repetition() {
         assert_eq!(
-            parser(r"a{5}").parse(),
+            parser_possessive(r"a*+").parse(),
             Ok(Ast::repetition(ast::Repetition {
-                span: span(0..4),
+                span: span(0..3),
                 op: ast::RepetitionOp {
-                    span: span(1..4),
-                    kind: ast::RepetitionKind::Range(
-                        ast::RepetitionRange::Exactly(5)
-                    ),
+                    span: span(1..3),
+                    kind: ast::RepetitionKind::ZeroOrMore,
                 },
                 greedy: true,
+                possessive: true,
                 ast: Box::new(lit('a', 0)),
             }))
         );
         assert_eq!(
-            parser(r"a{5,}").parse(),
-            Ok(Ast::repetition(ast::Repetition {
-                span: span(0..5),
-                op: ast::RepetitionOp {
+            parser_possessive(r"a?+b").parse(),
+            Ok(concat(
+                0..4,
+                vec![
+                    Ast::repetition(ast::Repetition {
+                        span: span(0..3),
+                        op: ast::RepetitionOp {
+                            span: span(1..3),
+                            kind: ast::RepetitionKind::ZeroOrOne,
+                        },
+                        greedy: true,
+                        possessive: true,
+                        ast: Box::new(lit('a', 0)),
+                    }),
+                    lit('b', 3),
+                ]
+            ))
+        );
+        assert_eq!(
+            parser_possessive(r"a{2,5}+").parse(),
+            Ok(Ast::repetition(ast::Repetition {
+                span: span(0..7),
+                op: ast::RepetitionOp {
+                    span: span(1..7),
+                    kind: ast::RepetitionKind::Range(
+                        ast::RepetitionRange::Bounded(2, 5)
+                    ),
+                },
+                greedy: true,
+                possessive: true,
+                ast: Box::new(lit('a', 0)),
+            }))
+        );
+        // A lazy operator followed by `+` is a repetition of a repetition.
+        assert_eq!(
+            parser_possessive(r"a+?+").parse(),
+            Ok(Ast::repetition(ast::Repetition {
+                span: span(0..4),
+                op: ast::RepetitionOp {
+                    span: span(3..4),
+                    kind: ast::RepetitionKind::OneOrMore,
+                },
+                greedy: true,
+                possessive: false,
+                ast: Box::new(Ast::repetition(ast::Repetition {
+                    span: span(0..3),
+                    op: ast::RepetitionOp {
+                        span: span(1..3),
+                        kind: ast::RepetitionKind::OneOrMore,
+                    },
+                    greedy: false,
+                    possessive: false,
+                    ast: Box::new(lit('a', 0)),
+                })),
+            }))
+        );
+        // Without possessive quantifiers enabled, `a*+` is `(?:a*)+`.
+        assert_eq!(
+            parser(r"a*+").parse(),
+            Ok(Ast::repetition(ast::Repetition {
+                span: span(0..3),
+                op: ast::RepetitionOp {
+                    span: span(2..3),
+                    kind: ast::RepetitionKind::OneOrMore,
+                },
+                greedy: true,
+                possessive: false,
+                ast: Box::new(Ast::repetition(ast::Repetition {
+                    span: span(0..2),
+                    op: ast::RepetitionOp {
+                        span: span(1..2),
+                        kind: ast::RepetitionKind::ZeroOrMore,
+                    },
+                    greedy: true,
+                    possessive: false,
+                    ast: Box::new(lit('a', 0)),
+                })),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_counted_repetition() {
+        assert_eq!(
+            parser(r"a{5}").parse(),
+            Ok(Ast::repetition(ast::Repetition {
+                span: span(0..4),
+                op: ast::RepetitionOp {
+                    span: span(1..4),
+                    kind: ast::RepetitionKind::Range(
+                        ast::RepetitionRange::Exactly(5)
+                    ),
+                },
+                greedy: true,
+                possessive: false,
+                ast: Box::new(lit('a', 0)),
+            }))
+        );
+        assert_eq!(
+            parser(r"a{5,}").parse(),
+            Ok(Ast::repetition(ast::Repetition {
+                span: span(0..5),
+                op: ast::RepetitionOp {
                     span: span(1..5),
                     kind: ast::RepetitionKind::Range(
                         ast::RepetitionRange::AtLeast(5)
                     ),
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3328,6 +4361,7 @@ bar
                     ),
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3342,6 +4376,7 @@ bar
                     ),
                 },
                 greedy: false,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3360,6 +4395,7 @@ bar
                             ),
                         },
                         greedy: true,
+                        possessive: false,
                         ast: Box::new(lit('b', 1)),
                     }),
                 ]
@@ -3380,6 +4416,7 @@ bar
                             ),
                         },
                         greedy: true,
+                        possessive: false,
                         ast: Box::new(lit('b', 1)),
                     }),
                     lit('c', 5),
@@ -3398,6 +4435,7 @@ bar
                     ),
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3412,6 +4450,7 @@ bar
                     ),
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3426,6 +4465,7 @@ bar
                     ),
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3440,6 +4480,7 @@ bar
                     ),
                 },
                 greedy: false,
+                possessive: false,
                 ast: Box::new(lit('a', 0)),
             }))
         );
@@ -3454,6 +4495,7 @@ bar
                     ),
                 },
                 greedy: true,
+                possessive: false,
                 ast: Box::new(Ast::assertion(ast::Assertion {
                     span: span(0..2),
                     kind: ast::AssertionKind::WordBoundary,
@@ -3736,33 +4778,232 @@ bar
     }
 
     #[test]
-    fn parse_unsupported_lookaround() {
+    fn parse_lookaround() {
+        use crate::ast::LookAroundKind::*;
+
+        assert_eq!(
+            parser(r"(?=a)").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..5),
+                kind: ast::GroupKind::LookAround(PositiveLookAhead),
+                ast: Box::new(lit('a', 3)),
+            }))
+        );
+        assert_eq!(
+            parser(r"(?!a)").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..5),
+                kind: ast::GroupKind::LookAround(NegativeLookAhead),
+                ast: Box::new(lit('a', 3)),
+            }))
+        );
+        assert_eq!(
+            parser(r"(?<=a)").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..6),
+                kind: ast::GroupKind::LookAround(PositiveLookBehind),
+                ast: Box::new(lit('a', 4)),
+            }))
+        );
+        assert_eq!(
+            parser(r"(?<!a)").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..6),
+                kind: ast::GroupKind::LookAround(NegativeLookBehind),
+                ast: Box::new(lit('a', 4)),
+            }))
+        );
+        assert_eq!(
+            parser(r"(?<=)").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..5),
+                kind: ast::GroupKind::LookAround(PositiveLookBehind),
+                ast: Box::new(Ast::empty(span(4..4))),
+            }))
+        );
+        // Look-around groups do not consume a capture index.
+        assert_eq!(
+            parser(r"(?=a)(b)").parse(),
+            Ok(concat(
+                0..8,
+                vec![
+                    Ast::group(ast::Group {
+                        span: span(0..5),
+                        kind: ast::GroupKind::LookAround(PositiveLookAhead),
+                        ast: Box::new(lit('a', 3)),
+                    }),
+                    group(5..8, 1, lit('b', 6)),
+                ]
+            ))
+        );
+
         assert_eq!(
-            parser(r"(?=a)").parse().unwrap_err(),
+            parser(r"(?=a").parse().unwrap_err(),
             TestError {
-                span: span(0..3),
-                kind: ast::ErrorKind::UnsupportedLookAround,
+                span: span(0..1),
+                kind: ast::ErrorKind::GroupUnclosed,
             }
         );
         assert_eq!(
-            parser(r"(?!a)").parse().unwrap_err(),
+            parser(r"(?<!").parse().unwrap_err(),
             TestError {
+                span: span(0..1),
+                kind: ast::ErrorKind::GroupUnclosed,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_boolean() {
+        fn inter(range: Range<usize>, asts: Vec<Ast>) -> Ast {
+            Ast::intersection(ast::Intersection { span: span(range), asts })
+        }
+        fn comp(range: Range<usize>, ast: Ast) -> Ast {
+            Ast::complement(ast::Complement {
+                span: span(range),
+                ast: Box::new(ast),
+            })
+        }
+
+        assert_eq!(
+            parser_boolean(r"a&b").parse(),
+            Ok(inter(0..3, vec![lit('a', 0), lit('b', 2)]))
+        );
+        assert_eq!(
+            parser_boolean(r"ab&c|d").parse(),
+            Ok(alt(
+                0..6,
+                vec![
+                    inter(
+                        0..4,
+                        vec![
+                            concat(0..2, vec![lit('a', 0), lit('b', 1)]),
+                            lit('c', 3),
+                        ]
+                    ),
+                    lit('d', 5),
+                ]
+            ))
+        );
+        assert_eq!(
+            parser_boolean(r"(?:a&b)c").parse(),
+            Ok(concat(
+                0..8,
+                vec![
+                    Ast::group(ast::Group {
+                        span: span(0..7),
+                        kind: ast::GroupKind::NonCapturing(ast::Flags {
+                            span: span(2..2),
+                            items: vec![],
+                        }),
+                        ast: Box::new(inter(
+                            3..6,
+                            vec![lit('a', 3), lit('b', 5)]
+                        )),
+                    }),
+                    lit('c', 7),
+                ]
+            ))
+        );
+        assert_eq!(parser_boolean(r"~a").parse(), Ok(comp(0..2, lit('a', 1))));
+        assert_eq!(
+            parser_boolean(r"~~a").parse(),
+            Ok(comp(0..3, comp(1..3, lit('a', 2))))
+        );
+        assert_eq!(
+            parser_boolean(r"~ab").parse(),
+            Ok(concat(0..3, vec![comp(0..2, lit('a', 1)), lit('b', 2)]))
+        );
+        assert_eq!(
+            parser_boolean(r"~a*").parse(),
+            Ok(Ast::repetition(ast::Repetition {
                 span: span(0..3),
-                kind: ast::ErrorKind::UnsupportedLookAround,
+                op: ast::RepetitionOp {
+                    span: span(2..3),
+                    kind: ast::RepetitionKind::ZeroOrMore,
+                },
+                greedy: true,
+                possessive: false,
+                ast: Box::new(comp(0..2, lit('a', 1))),
+            }))
+        );
+        // Escapes and classes are unaffected.
+        assert_eq!(
+            parser_boolean(r"\&\~").parse(),
+            Ok(concat(
+                0..4,
+                vec![meta_lit('&', span(0..2)), meta_lit('~', span(2..4)),]
+            ))
+        );
+        // Without the option enabled, both are literals.
+        assert_eq!(
+            parser(r"~a&b").parse(),
+            Ok(concat(
+                0..4,
+                vec![lit('~', 0), lit('a', 1), lit('&', 2), lit('b', 3)]
+            ))
+        );
+
+        assert_eq!(
+            parser_boolean(r"~").parse().unwrap_err(),
+            TestError {
+                span: span(0..1),
+                kind: ast::ErrorKind::ComplementMissing,
             }
         );
         assert_eq!(
-            parser(r"(?<=a)").parse().unwrap_err(),
+            parser_boolean(r"a~|b").parse().unwrap_err(),
             TestError {
-                span: span(0..4),
-                kind: ast::ErrorKind::UnsupportedLookAround,
+                span: span(1..2),
+                kind: ast::ErrorKind::ComplementMissing,
             }
         );
         assert_eq!(
-            parser(r"(?<!a)").parse().unwrap_err(),
+            parser_boolean(r"(~)").parse().unwrap_err(),
             TestError {
-                span: span(0..4),
-                kind: ast::ErrorKind::UnsupportedLookAround,
+                span: span(1..2),
+                kind: ast::ErrorKind::ComplementMissing,
+            }
+        );
+        assert_eq!(
+            parser_boolean(r"~*").parse().unwrap_err(),
+            TestError {
+                span: span(0..1),
+                kind: ast::ErrorKind::ComplementMissing,
+            }
+        );
+        assert_eq!(
+            parser_boolean(r"~&a").parse().unwrap_err(),
+            TestError {
+                span: span(0..1),
+                kind: ast::ErrorKind::ComplementMissing,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_atomic_group() {
+        assert_eq!(
+            parser(r"(?>a)").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..5),
+                kind: ast::GroupKind::Atomic,
+                ast: Box::new(lit('a', 3)),
+            }))
+        );
+        assert_eq!(
+            parser(r"(?>(a))").parse(),
+            Ok(Ast::group(ast::Group {
+                span: span(0..7),
+                kind: ast::GroupKind::Atomic,
+                ast: Box::new(group(3..6, 1, lit('a', 4))),
+            }))
+        );
+        assert_eq!(
+            parser(r"(?>a").parse().unwrap_err(),
+            TestError {
+                span: span(0..1),
+                kind: ast::ErrorKind::GroupUnclosed,
             }
         );
     }
@@ -4432,6 +5673,7 @@ bar
         assert_eq!(parser("U").parse_flag(), Ok(ast::Flag::SwapGreed));
         assert_eq!(parser("u").parse_flag(), Ok(ast::Flag::Unicode));
         assert_eq!(parser("R").parse_flag(), Ok(ast::Flag::CRLF));
+        assert_eq!(parser("g").parse_flag(), Ok(ast::Flag::Grapheme));
         assert_eq!(parser("x").parse_flag(), Ok(ast::Flag::IgnoreWhitespace));
 
         assert_eq!(
@@ -4574,6 +5816,20 @@ bar
                 kind: ast::AssertionKind::WordBoundaryEndHalf,
             }))
         );
+        assert_eq!(
+            parser(r"\b{wb}").parse_primitive(),
+            Ok(Primitive::Assertion(ast::Assertion {
+                span: span(0..6),
+                kind: ast::AssertionKind::WordSegmentBoundary,
+            }))
+        );
+        assert_eq!(
+            parser(r"\b{sb}").parse_primitive(),
+            Ok(Primitive::Assertion(ast::Assertion {
+                span: span(0..6),
+                kind: ast::AssertionKind::SentenceSegmentBoundary,
+            }))
+        );
         assert_eq!(
             parser(r"\<").parse_primitive(),
             Ok(Primitive::Assertion(ast::Assertion {
@@ -4595,6 +5851,17 @@ bar
                 kind: ast::AssertionKind::NotWordBoundary,
             }))
         );
+        assert_eq!(
+            parser(r"\X").parse_primitive(),
+            Ok(Primitive::GraphemeCluster(span(0..2)))
+        );
+        assert_eq!(
+            parser(r"[\X]").parse().unwrap_err(),
+            TestError {
+                span: span(1..3),
+                kind: ast::ErrorKind::ClassEscapeInvalid,
+            }
+        );
 
         // We also support superfluous escapes in most cases now too.
         for c in ['!', '@', '%', '"', '\'', '/', ' '] {
@@ -4786,22 +6053,493 @@ bar
     }
 
     #[test]
-    fn parse_hex_two() {
-        for i in 0..256 {
-            let pat = format!(r"\x{:02x}", i);
-            assert_eq!(
-                parser(&pat).parse_escape(),
-                Ok(Primitive::Literal(ast::Literal {
-                    span: span(0..pat.len()),
-                    kind: ast::LiteralKind::HexFixed(ast::HexLiteralKind::X),
-                    c: char::from_u32(i).unwrap(),
-                }))
-            );
-        }
-
+    fn parse_backreference() {
+        let backref = |span, kind| {
+            Ok(Primitive::Backreference(ast::Backreference { span, kind }))
+        };
         assert_eq!(
-            parser(r"\xF").parse_escape().unwrap_err(),
-            TestError {
+            parser_backreferences(r"\1").parse_escape(),
+            backref(span(0..2), ast::BackreferenceKind::Index(1))
+        );
+        assert_eq!(
+            parser_backreferences(r"\19").parse_escape(),
+            backref(span(0..3), ast::BackreferenceKind::Index(19))
+        );
+        assert_eq!(
+            parser_backreferences(r"\k<foo>").parse_escape(),
+            backref(span(0..7), ast::BackreferenceKind::Named(s("foo")))
+        );
+
+        // Whitespace never continues an index, even in verbose mode.
+        let pat = r"(?x)(a)\1 2";
+        assert_eq!(
+            parser_backreferences(pat).parse(),
+            Ok(concat(
+                0..11,
+                vec![
+                    flag_set(pat, 0..4, ast::Flag::IgnoreWhitespace, false),
+                    group(4..7, 1, lit('a', 5)),
+                    Ast::backreference(ast::Backreference {
+                        span: span(7..9),
+                        kind: ast::BackreferenceKind::Index(1),
+                    }),
+                    lit('2', 10),
+                ]
+            ))
+        );
+
+        // Octal escapes still work, but only when they begin with a 0.
+        assert_eq!(
+            ParserI::new(
+                ParserBuilder::new().octal(true).backreferences(true).build(),
+                r"\01",
+            )
+            .parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..3),
+                kind: ast::LiteralKind::Octal,
+                c: '\x01',
+            }))
+        );
+        assert_eq!(
+            parser_backreferences(r"\0").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::UnsupportedBackreference,
+            }
+        );
+        assert_eq!(
+            parser(r"\1").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::UnsupportedBackreference,
+            }
+        );
+
+        assert_eq!(
+            parser_backreferences(r"\99999999999").parse_escape().unwrap_err(),
+            TestError {
+                span: span(1..12),
+                kind: ast::ErrorKind::DecimalInvalid,
+            }
+        );
+        assert_eq!(
+            parser_backreferences(r"\k").parse_escape().unwrap_err(),
+            TestError {
+                span: span(2..2),
+                kind: ast::ErrorKind::EscapeUnexpectedEof,
+            }
+        );
+        assert_eq!(
+            parser_backreferences(r"\kfoo").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::EscapeUnrecognized,
+            }
+        );
+        assert_eq!(
+            parser_backreferences(r"\k<>").parse_escape().unwrap_err(),
+            TestError {
+                span: span(3..3),
+                kind: ast::ErrorKind::GroupNameEmpty,
+            }
+        );
+        assert_eq!(
+            parser_backreferences(r"\k<1>").parse_escape().unwrap_err(),
+            TestError {
+                span: span(3..4),
+                kind: ast::ErrorKind::GroupNameInvalid,
+            }
+        );
+        assert_eq!(
+            parser_backreferences(r"\k<foo").parse_escape().unwrap_err(),
+            TestError {
+                span: span(6..6),
+                kind: ast::ErrorKind::GroupNameUnexpectedEof,
+            }
+        );
+        assert_eq!(
+            parser_backreferences(r"[\1]").parse().unwrap_err(),
+            TestError {
+                span: span(1..3),
+                kind: ast::ErrorKind::ClassEscapeInvalid,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pcre_quote() {
+        assert_eq!(
+            parser_pcre(r"\Qa.b\E").parse(),
+            Ok(concat(
+                0..7,
+                vec![lit('a', 2), meta_lit('.', span(3..4)), lit('b', 4)]
+            ))
+        );
+        // A missing \E quotes the remainder of the pattern.
+        assert_eq!(
+            parser_pcre(r"\Q(a").parse(),
+            Ok(concat(0..4, vec![meta_lit('(', span(2..3)), lit('a', 3)]))
+        );
+        // A \E without a preceding \Q is ignored.
+        assert_eq!(
+            parser_pcre(r"a\Eb").parse(),
+            Ok(concat(0..4, vec![lit('a', 0), lit('b', 3)]))
+        );
+        assert_eq!(parser_pcre(r"\Q\E").parse(), Ok(Ast::empty(span(0..4))));
+        assert!(parser_pcre(r"[\Q]-\E]").parse().is_ok());
+
+        assert_eq!(
+            parser(r"\Qa\E").parse().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::EscapeUnrecognized,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pcre_comment() {
+        assert_eq!(
+            parser_pcre(r"a(?#x)+b").parse_with_comments(),
+            Ok(ast::WithComments {
+                ast: concat(
+                    0..8,
+                    vec![
+                        Ast::repetition(ast::Repetition {
+                            span: span(0..7),
+                            op: ast::RepetitionOp {
+                                span: span(6..7),
+                                kind: ast::RepetitionKind::OneOrMore,
+                            },
+                            greedy: true,
+                            possessive: false,
+                            ast: Box::new(lit('a', 0)),
+                        }),
+                        lit('b', 7),
+                    ]
+                ),
+                comments: vec![ast::Comment {
+                    span: span(1..6),
+                    comment: s("x"),
+                }],
+            })
+        );
+        assert_eq!(
+            parser_pcre(r"(?#abc").parse().unwrap_err(),
+            TestError {
+                span: span(0..1),
+                kind: ast::ErrorKind::GroupUnclosed,
+            }
+        );
+        assert_eq!(
+            parser(r"(?#abc)").parse().unwrap_err(),
+            TestError {
+                span: span(2..3),
+                kind: ast::ErrorKind::FlagUnrecognized,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pcre_escape() {
+        let perl = |range, kind, negated| {
+            Ok(Primitive::Perl(ast::ClassPerl {
+                span: span(range),
+                kind,
+                negated,
+            }))
+        };
+        assert_eq!(
+            parser_pcre(r"\h").parse_escape(),
+            perl(0..2, ast::ClassPerlKind::HorizontalSpace, false)
+        );
+        assert_eq!(
+            parser_pcre(r"\H").parse_escape(),
+            perl(0..2, ast::ClassPerlKind::HorizontalSpace, true)
+        );
+        assert_eq!(
+            parser_pcre(r"\v").parse_escape(),
+            perl(0..2, ast::ClassPerlKind::VerticalSpace, false)
+        );
+        assert_eq!(
+            parser_pcre(r"\V").parse_escape(),
+            perl(0..2, ast::ClassPerlKind::VerticalSpace, true)
+        );
+        assert_eq!(
+            parser_pcre(r"\R").parse(),
+            Ok(Ast::newline_sequence(span(0..2)))
+        );
+        assert_eq!(
+            parser_pcre(r"\Z").parse(),
+            Ok(Ast::assertion(ast::Assertion {
+                span: span(0..2),
+                kind: ast::AssertionKind::EndTextOptionalNewline,
+            }))
+        );
+        assert_eq!(
+            parser_pcre(r"\x").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..2),
+                kind: ast::LiteralKind::HexFixed(ast::HexLiteralKind::X),
+                c: '\x00',
+            }))
+        );
+        assert_eq!(
+            parser_pcre(r"\x9g").parse(),
+            Ok(concat(
+                0..4,
+                vec![
+                    Ast::literal(ast::Literal {
+                        span: span(0..3),
+                        kind: ast::LiteralKind::HexFixed(
+                            ast::HexLiteralKind::X
+                        ),
+                        c: '\x09',
+                    }),
+                    lit('g', 3),
+                ]
+            ))
+        );
+        assert_eq!(
+            parser_pcre(r"\x{41}").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..6),
+                kind: ast::LiteralKind::HexBrace(ast::HexLiteralKind::X),
+                c: 'A',
+            }))
+        );
+        assert_eq!(
+            parser_pcre(r"\0").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..2),
+                kind: ast::LiteralKind::Octal,
+                c: '\x00',
+            }))
+        );
+        assert_eq!(
+            parser_pcre(r"\012").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..4),
+                kind: ast::LiteralKind::Octal,
+                c: '\n',
+            }))
+        );
+        assert_eq!(
+            parser_pcre(r"\p{^Greek}").parse_escape(),
+            Ok(Primitive::Unicode(ast::ClassUnicode {
+                span: span(0..10),
+                negated: true,
+                kind: ast::ClassUnicodeKind::Named(s("Greek")),
+            }))
+        );
+        assert_eq!(
+            parser_pcre(r"\P{^Greek}").parse_escape(),
+            Ok(Primitive::Unicode(ast::ClassUnicode {
+                span: span(0..10),
+                negated: false,
+                kind: ast::ClassUnicodeKind::Named(s("Greek")),
+            }))
+        );
+
+        // None of the above are accepted by the default dialect.
+        assert_eq!(
+            parser(r"\h").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::EscapeUnrecognized,
+            }
+        );
+        assert_eq!(
+            parser(r"\v").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..2),
+                kind: ast::LiteralKind::Special(
+                    ast::SpecialLiteralKind::VerticalTab
+                ),
+                c: '\x0B',
+            }))
+        );
+        assert_eq!(
+            parser(r"\x9g").parse_escape().unwrap_err(),
+            TestError {
+                span: span(3..4),
+                kind: ast::ErrorKind::EscapeHexInvalidDigit,
+            }
+        );
+
+        assert_eq!(
+            parser_pcre(r"\G").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::UnsupportedMatchStartAnchor,
+            }
+        );
+        assert_eq!(
+            parser_pcre(r"\K").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::UnsupportedMatchStartReset,
+            }
+        );
+        assert_eq!(
+            parser_pcre(r"[\R]").parse().unwrap_err(),
+            TestError {
+                span: span(1..3),
+                kind: ast::ErrorKind::ClassEscapeInvalid,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_pcre_backreference() {
+        let backref = |range, kind| {
+            Ok(Primitive::Backreference(ast::Backreference {
+                span: span(range),
+                kind,
+            }))
+        };
+        assert_eq!(
+            parser_pcre_backreferences(r"\g1").parse_escape(),
+            backref(0..3, ast::BackreferenceKind::Index(1))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"\g{12}").parse_escape(),
+            backref(0..6, ast::BackreferenceKind::Index(12))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"\g{foo}").parse_escape(),
+            backref(0..7, ast::BackreferenceKind::Named(s("foo")))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"\k'foo'").parse_escape(),
+            backref(0..7, ast::BackreferenceKind::Named(s("foo")))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"\k{foo}").parse_escape(),
+            backref(0..7, ast::BackreferenceKind::Named(s("foo")))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"(?P=foo)").parse(),
+            Ok(Ast::backreference(ast::Backreference {
+                span: span(0..8),
+                kind: ast::BackreferenceKind::Named(s("foo")),
+            }))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"(a)(b)\g{-1}").parse(),
+            Ok(concat(
+                0..12,
+                vec![
+                    group(0..3, 1, lit('a', 1)),
+                    group(3..6, 2, lit('b', 4)),
+                    Ast::backreference(ast::Backreference {
+                        span: span(6..12),
+                        kind: ast::BackreferenceKind::Index(2),
+                    }),
+                ]
+            ))
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"\g{-1}").parse().unwrap_err(),
+            TestError {
+                span: span(4..5),
+                kind: ast::ErrorKind::DecimalInvalid,
+            }
+        );
+        assert_eq!(
+            parser_pcre_backreferences(r"\g<foo>").parse().unwrap_err(),
+            TestError {
+                span: span(0..7),
+                kind: ast::ErrorKind::UnsupportedRecursion,
+            }
+        );
+
+        // When backreferences are disabled, the error covers the entire
+        // backreference.
+        for (pat, end) in [
+            (r"\1", 2),
+            (r"\12", 3),
+            (r"\k<foo>", 7),
+            (r"\g{1}", 5),
+            (r"(?P=foo)", 8),
+        ] {
+            assert_eq!(
+                parser_pcre(pat).parse().unwrap_err(),
+                TestError {
+                    span: span(0..end),
+                    kind: ast::ErrorKind::UnsupportedBackreference,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn parse_pcre_unsupported_group() {
+        let err = |range, kind| TestError { span: span(range), kind };
+        assert_eq!(
+            parser_pcre(r"(?R)").parse().unwrap_err(),
+            err(0..4, ast::ErrorKind::UnsupportedRecursion)
+        );
+        assert_eq!(
+            parser_pcre(r"a(?1)").parse().unwrap_err(),
+            err(1..5, ast::ErrorKind::UnsupportedRecursion)
+        );
+        assert_eq!(
+            parser_pcre(r"(?-1)").parse().unwrap_err(),
+            err(0..5, ast::ErrorKind::UnsupportedRecursion)
+        );
+        assert_eq!(
+            parser_pcre(r"(?&name)").parse().unwrap_err(),
+            err(0..8, ast::ErrorKind::UnsupportedRecursion)
+        );
+        assert_eq!(
+            parser_pcre(r"(?P>name)").parse().unwrap_err(),
+            err(0..9, ast::ErrorKind::UnsupportedRecursion)
+        );
+        assert_eq!(
+            parser_pcre(r"(?(1)a|b)").parse().unwrap_err(),
+            err(0..3, ast::ErrorKind::UnsupportedConditional)
+        );
+        assert_eq!(
+            parser_pcre(r"(?|a|b)").parse().unwrap_err(),
+            err(0..3, ast::ErrorKind::UnsupportedBranchReset)
+        );
+        assert_eq!(
+            parser_pcre(r"(*SKIP)").parse().unwrap_err(),
+            err(0..7, ast::ErrorKind::UnsupportedBacktrackingVerb)
+        );
+
+        // Flags that look a bit like the above are still fine.
+        let pat = r"(?-i)";
+        assert_eq!(
+            parser_pcre(pat).parse(),
+            Ok(flag_set(pat, 0..5, ast::Flag::CaseInsensitive, true))
+        );
+        let pat = r"(?R)";
+        assert_eq!(
+            parser(pat).parse(),
+            Ok(flag_set(pat, 0..4, ast::Flag::CRLF, false))
+        );
+    }
+
+    #[test]
+    fn parse_hex_two() {
+        for i in 0..256 {
+            let pat = format!(r"\x{:02x}", i);
+            assert_eq!(
+                parser(&pat).parse_escape(),
+                Ok(Primitive::Literal(ast::Literal {
+                    span: span(0..pat.len()),
+                    kind: ast::LiteralKind::HexFixed(ast::HexLiteralKind::X),
+                    c: char::from_u32(i).unwrap(),
+                }))
+            );
+        }
+
+        assert_eq!(
+            parser(r"\xF").parse_escape().unwrap_err(),
+            TestError {
                 span: span(3..3),
                 kind: ast::ErrorKind::EscapeUnexpectedEof,
             }
@@ -5076,6 +6814,86 @@ bar
         );
     }
 
+    #[test]
+    fn parse_unicode_name() {
+        assert_eq!(
+            parser(r"\N{GREEK SMALL LETTER ALPHA}").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..28),
+                kind: ast::LiteralKind::Named(s("GREEK SMALL LETTER ALPHA")),
+                c: '\u{FFFD}',
+            }))
+        );
+        assert_eq!(
+            parser(r"\N{snowman}").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..11),
+                kind: ast::LiteralKind::Named(s("snowman")),
+                c: '\u{FFFD}',
+            }))
+        );
+        assert_eq!(
+            parser_ignore_whitespace(r"\N{LATIN SMALL LETTER A} ")
+                .parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..25),
+                kind: ast::LiteralKind::Named(s("LATIN SMALL LETTER A")),
+                c: '\u{FFFD}',
+            }))
+        );
+        assert_eq!(
+            parser(r"[\N{DIGIT ZERO}-\N{DIGIT NINE}]").parse(),
+            Ok(Ast::class_bracketed(ast::ClassBracketed {
+                span: span(0..31),
+                negated: false,
+                kind: ast::ClassSet::Item(ast::ClassSetItem::Range(
+                    ast::ClassSetRange {
+                        span: span(1..30),
+                        start: ast::Literal {
+                            span: span(1..15),
+                            kind: ast::LiteralKind::Named(s("DIGIT ZERO")),
+                            c: '\u{FFFD}',
+                        },
+                        end: ast::Literal {
+                            span: span(16..30),
+                            kind: ast::LiteralKind::Named(s("DIGIT NINE")),
+                            c: '\u{FFFD}',
+                        },
+                    }
+                )),
+            }))
+        );
+
+        assert_eq!(
+            parser(r"\N").parse_escape().unwrap_err(),
+            TestError {
+                span: span(0..2),
+                kind: ast::ErrorKind::EscapeUnrecognized,
+            }
+        );
+        assert_eq!(
+            parser(r"\N{SNOWMAN").parse_escape().unwrap_err(),
+            TestError {
+                span: span(2..10),
+                kind: ast::ErrorKind::EscapeUnexpectedEof,
+            }
+        );
+        assert_eq!(
+            parser(r"\N{}").parse_escape(),
+            Ok(Primitive::Literal(ast::Literal {
+                span: span(0..4),
+                kind: ast::LiteralKind::Named(s("")),
+                c: '\u{FFFD}',
+            }))
+        );
+        // Names are only resolved during translation, so a range whose
+        // endpoints are names is never rejected by the parser.
+        assert_eq!(
+            parser(r"[\N{DIGIT NINE}-\N{DIGIT ZERO}]").parse().map(|_| ()),
+            Ok(())
+        );
+    }
+
     #[test]
     fn parse_decimal() {
         assert_eq!(parser("123").parse_decimal(), Ok(123));
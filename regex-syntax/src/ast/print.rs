@@ -44,6 +44,11 @@ impl PrinterBuilder {
 /// This printer will not necessarily preserve the original formatting of the
 /// regular expression pattern string. For example, all whitespace and comments
 /// are ignored.
+///
+/// Syntax that only exists in the [`Dialect::Pcre`](ast::parse::Dialect::Pcre)
+/// dialect, such as `\h`, `\v`, `\R` or `\Z`, is printed as is. The result
+/// therefore needs to be parsed with that same dialect to get back an
+/// equivalent AST.
 #[derive(Debug)]
 pub struct Printer {
     _priv: (),
@@ -81,6 +86,7 @@ impl<W: fmt::Write> Visitor for Writer<W> {
         match *ast {
             Ast::Group(ref x) => self.fmt_group_pre(x),
             Ast::ClassBracketed(ref x) => self.fmt_class_bracketed_pre(x),
+            Ast::Complement(_) => self.wtr.write_str("~"),
             _ => Ok(()),
         }
     }
@@ -99,6 +105,11 @@ impl<W: fmt::Write> Visitor for Writer<W> {
             Ast::Group(ref x) => self.fmt_group_post(x),
             Ast::Alternation(_) => Ok(()),
             Ast::Concat(_) => Ok(()),
+            Ast::Intersection(_) => Ok(()),
+            Ast::Complement(_) => Ok(()),
+            Ast::Backreference(ref x) => self.fmt_backreference(x),
+            Ast::GraphemeCluster(_) => self.wtr.write_str(r"\X"),
+            Ast::NewlineSequence(_) => self.wtr.write_str(r"\R"),
         }
     }
 
@@ -106,6 +117,10 @@ impl<W: fmt::Write> Visitor for Writer<W> {
         self.wtr.write_str("|")
     }
 
+    fn visit_intersection_in(&mut self) -> fmt::Result {
+        self.wtr.write_str("&")
+    }
+
     fn visit_class_set_item_pre(
         &mut self,
         ast: &ast::ClassSetItem,
@@ -167,6 +182,16 @@ impl<W: fmt::Write> Writer<W> {
                 self.wtr.write_str(":")?;
                 Ok(())
             }
+            LookAround(kind) => {
+                use crate::ast::LookAroundKind::*;
+                self.wtr.write_str(match kind {
+                    PositiveLookAhead => "(?=",
+                    NegativeLookAhead => "(?!",
+                    PositiveLookBehind => "(?<=",
+                    NegativeLookBehind => "(?<!",
+                })
+            }
+            Atomic => self.wtr.write_str("(?>"),
         }
     }
 
@@ -177,20 +202,17 @@ impl<W: fmt::Write> Writer<W> {
     fn fmt_repetition(&mut self, ast: &ast::Repetition) -> fmt::Result {
         use crate::ast::RepetitionKind::*;
         match ast.op.kind {
-            ZeroOrOne if ast.greedy => self.wtr.write_str("?"),
-            ZeroOrOne => self.wtr.write_str("??"),
-            ZeroOrMore if ast.greedy => self.wtr.write_str("*"),
-            ZeroOrMore => self.wtr.write_str("*?"),
-            OneOrMore if ast.greedy => self.wtr.write_str("+"),
-            OneOrMore => self.wtr.write_str("+?"),
-            Range(ref x) => {
-                self.fmt_repetition_range(x)?;
-                if !ast.greedy {
-                    self.wtr.write_str("?")?;
-                }
-                Ok(())
-            }
+            ZeroOrOne => self.wtr.write_str("?"),
+            ZeroOrMore => self.wtr.write_str("*"),
+            OneOrMore => self.wtr.write_str("+"),
+            Range(ref x) => self.fmt_repetition_range(x),
+        }?;
+        if !ast.greedy {
+            self.wtr.write_str("?")?;
+        } else if ast.possessive {
+            self.wtr.write_str("+")?;
         }
+        Ok(())
     }
 
     fn fmt_repetition_range(
@@ -246,6 +268,7 @@ impl<W: fmt::Write> Writer<W> {
             Special(ast::SpecialLiteralKind::VerticalTab) => {
                 self.wtr.write_str(r"\v")
             }
+            Named(ref name) => write!(self.wtr, r"\N{{{}}}", name),
             Special(ast::SpecialLiteralKind::Space) => {
                 self.wtr.write_str(r"\ ")
             }
@@ -259,6 +282,7 @@ impl<W: fmt::Write> Writer<W> {
             EndLine => self.wtr.write_str("$"),
             StartText => self.wtr.write_str(r"\A"),
             EndText => self.wtr.write_str(r"\z"),
+            EndTextOptionalNewline => self.wtr.write_str(r"\Z"),
             WordBoundary => self.wtr.write_str(r"\b"),
             NotWordBoundary => self.wtr.write_str(r"\B"),
             WordBoundaryStart => self.wtr.write_str(r"\b{start}"),
@@ -267,6 +291,19 @@ impl<W: fmt::Write> Writer<W> {
             WordBoundaryEndAngle => self.wtr.write_str(r"\>"),
             WordBoundaryStartHalf => self.wtr.write_str(r"\b{start-half}"),
             WordBoundaryEndHalf => self.wtr.write_str(r"\b{end-half}"),
+            WordSegmentBoundary => self.wtr.write_str(r"\b{wb}"),
+            SentenceSegmentBoundary => self.wtr.write_str(r"\b{sb}"),
+        }
+    }
+
+    fn fmt_backreference(&mut self, ast: &ast::Backreference) -> fmt::Result {
+        match ast.kind {
+            ast::BackreferenceKind::Index(index) => {
+                write!(self.wtr, r"\{}", index)
+            }
+            ast::BackreferenceKind::Named(ref name) => {
+                write!(self.wtr, r"\k<{}>", name)
+            }
         }
     }
 
@@ -291,6 +328,7 @@ impl<W: fmt::Write> Writer<W> {
                     Flag::Unicode => self.wtr.write_str("u"),
                     Flag::CRLF => self.wtr.write_str("R"),
                     Flag::IgnoreWhitespace => self.wtr.write_str("x"),
+                    Flag::Grapheme => self.wtr.write_str("g"),
                 },
             }?;
         }
@@ -336,6 +374,10 @@ impl<W: fmt::Write> Writer<W> {
             Space => self.wtr.write_str(r"\s"),
             Word if ast.negated => self.wtr.write_str(r"\W"),
             Word => self.wtr.write_str(r"\w"),
+            HorizontalSpace if ast.negated => self.wtr.write_str(r"\H"),
+            HorizontalSpace => self.wtr.write_str(r"\h"),
+            VerticalSpace if ast.negated => self.wtr.write_str(r"\V"),
+            VerticalSpace => self.wtr.write_str(r"\v"),
         }
     }
 
@@ -402,7 +444,7 @@ impl<W: fmt::Write> Writer<W> {
 mod tests {
     use alloc::string::String;
 
-    use crate::ast::parse::ParserBuilder;
+    use crate::ast::parse::{Dialect, ParserBuilder};
 
     use super::*;
 
@@ -445,6 +487,10 @@ mod tests {
         roundtrip(r"\r");
         roundtrip(r"\v");
         roundtrip(r"(?x)\ ");
+
+        roundtrip(r"\N{GREEK SMALL LETTER ALPHA}");
+        roundtrip(r"\N{snowman}");
+        roundtrip(r"(?x)\N{LATIN SMALL LETTER A}");
     }
 
     #[test]
@@ -452,6 +498,30 @@ mod tests {
         roundtrip(".");
     }
 
+    #[test]
+    fn print_grapheme_cluster() {
+        roundtrip(r"\X");
+        roundtrip(r"\X+");
+    }
+
+    #[test]
+    fn print_pcre() {
+        roundtrip_with(|b| b.dialect(Dialect::Pcre), r"\R");
+        roundtrip_with(|b| b.dialect(Dialect::Pcre), r"a\Z");
+        roundtrip_with(|b| b.dialect(Dialect::Pcre), r"\h\H\v\V");
+        roundtrip_with(|b| b.dialect(Dialect::Pcre), r"[\h\V]");
+
+        // Quoted literals print as their escaped equivalents.
+        let ast = ParserBuilder::new()
+            .dialect(Dialect::Pcre)
+            .build()
+            .parse(r"\Qa.b\E")
+            .unwrap();
+        let mut dst = String::new();
+        Printer::new().print(&ast, &mut dst).unwrap();
+        assert_eq!(r"a\.b", dst);
+    }
+
     #[test]
     fn print_concat() {
         roundtrip("ab");
@@ -468,6 +538,23 @@ mod tests {
         roundtrip("a(b|c|d)|e|f");
     }
 
+    #[test]
+    fn print_boolean() {
+        roundtrip_with(|b| b.boolean_operators(true), "a&b");
+        roundtrip_with(|b| b.boolean_operators(true), "ab&cd|e&f");
+        roundtrip_with(|b| b.boolean_operators(true), "~a");
+        roundtrip_with(|b| b.boolean_operators(true), "~~a*");
+        roundtrip_with(|b| b.boolean_operators(true), "x~(a&~b)y");
+        roundtrip_with(|b| b.boolean_operators(true), "(?i)~[a-z]");
+    }
+
+    #[test]
+    fn print_backreference() {
+        roundtrip_with(|b| b.backreferences(true), r"(a)\1");
+        roundtrip_with(|b| b.backreferences(true), r"(a)(b)\2\1");
+        roundtrip_with(|b| b.backreferences(true), r"(?<x>a)\k<x>");
+    }
+
     #[test]
     fn print_assertion() {
         roundtrip(r"^");
@@ -476,6 +563,8 @@ mod tests {
         roundtrip(r"\z");
         roundtrip(r"\b");
         roundtrip(r"\B");
+        roundtrip(r"\b{wb}");
+        roundtrip(r"\b{sb}");
     }
 
     #[test]
@@ -494,6 +583,17 @@ mod tests {
         roundtrip("a{5,10}?");
     }
 
+    #[test]
+    fn print_repetition_possessive() {
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a?+");
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a*+");
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a++");
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a{5}+");
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a{5,}+");
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a{5,10}+");
+        roundtrip_with(|b| b.possessive_quantifiers(true), "a*?+");
+    }
+
     #[test]
     fn print_flags() {
         roundtrip("(?i)");
@@ -501,6 +601,7 @@ mod tests {
         roundtrip("(?s-i)");
         roundtrip("(?-si)");
         roundtrip("(?siUmux)");
+        roundtrip("(?gR-i)");
     }
 
     #[test]
@@ -509,6 +610,12 @@ mod tests {
         roundtrip("(?P<foo>a)");
         roundtrip("(?<foo>a)");
         roundtrip("(a)");
+        roundtrip("(?=a)");
+        roundtrip("(?!a)");
+        roundtrip("(?<=a)");
+        roundtrip("(?<!a)");
+        roundtrip("(?>a)");
+        roundtrip("(?>a|b*)");
     }
 
     #[test]
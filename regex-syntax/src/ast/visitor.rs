@@ -53,6 +53,12 @@ pub trait Visitor {
         Ok(())
     }
 
+    /// This method is called between child nodes of an
+    /// [`Intersection`](ast::Intersection).
+    fn visit_intersection_in(&mut self) -> Result<(), Self::Err> {
+        Ok(())
+    }
+
     /// This method is called on every [`ClassSetItem`](ast::ClassSetItem)
     /// before descending into child nodes.
     fn visit_class_set_item_pre(
@@ -156,6 +162,17 @@ enum Frame<'a> {
         /// The remaining child nodes to visit (which may be empty).
         tail: &'a [Ast],
     },
+    /// The stack frame used while visiting every child node of an
+    /// intersection of expressions.
+    Intersection {
+        /// The child node we are currently visiting.
+        head: &'a Ast,
+        /// The remaining child nodes to visit (which may be empty).
+        tail: &'a [Ast],
+    },
+    /// A stack frame allocated just before descending into a complement's
+    /// child node.
+    Complement(&'a ast::Complement),
 }
 
 /// Represents a single stack frame while performing structural induction over
@@ -240,6 +257,9 @@ impl<'a> HeapVisitor<'a> {
                         Frame::Concat { .. } => {
                             visitor.visit_concat_in()?;
                         }
+                        Frame::Intersection { .. } => {
+                            visitor.visit_intersection_in()?;
+                        }
                         _ => {}
                     }
                     ast = x.child();
@@ -279,6 +299,12 @@ impl<'a> HeapVisitor<'a> {
                 head: &x.asts[0],
                 tail: &x.asts[1..],
             }),
+            Ast::Intersection(ref x) if x.asts.is_empty() => None,
+            Ast::Intersection(ref x) => Some(Frame::Intersection {
+                head: &x.asts[0],
+                tail: &x.asts[1..],
+            }),
+            Ast::Complement(ref x) => Some(Frame::Complement(x)),
             _ => None,
         })
     }
@@ -306,6 +332,17 @@ impl<'a> HeapVisitor<'a> {
                     })
                 }
             }
+            Frame::Intersection { tail, .. } => {
+                if tail.is_empty() {
+                    None
+                } else {
+                    Some(Frame::Intersection {
+                        head: &tail[0],
+                        tail: &tail[1..],
+                    })
+                }
+            }
+            Frame::Complement(_) => None,
         }
     }
 
@@ -446,6 +483,8 @@ impl<'a> Frame<'a> {
             Frame::Group(group) => &group.ast,
             Frame::Concat { head, .. } => head,
             Frame::Alternation { head, .. } => head,
+            Frame::Intersection { head, .. } => head,
+            Frame::Complement(complement) => &complement.ast,
         }
     }
 }
@@ -173,6 +173,11 @@ impl Extractor {
 
         match *hir.kind() {
             Empty | Look(_) => Seq::singleton(self::Literal::exact(vec![])),
+            // A look-around with a sub-expression matches the empty string,
+            // but only sometimes. So the empty string is an inexact literal
+            // for it, which prevents literals after it from being treated
+            // as exact.
+            LookAround(_) => Seq::singleton(self::Literal::inexact(vec![])),
             Literal(hir::Literal(ref bytes)) => {
                 let mut seq =
                     Seq::singleton(self::Literal::exact(bytes.to_vec()));
@@ -195,6 +200,30 @@ impl Extractor {
                 // which doesn't change based on forwards vs reverse.
                 self.extract_alternation(hirs.iter())
             }
+            // Every match of an intersection is also a match of each of its
+            // operands, so the literals of any one operand will do. They are
+            // never exact though, since the other operands might reject the
+            // match.
+            Intersection(ref hirs) => {
+                let mut seq = self.extract(&hirs[0]);
+                seq.make_inexact();
+                seq
+            }
+            // We could extract literals from a complement when its operand
+            // is sufficiently simple, but we don't bother.
+            Complement(_) => Seq::infinite(),
+            // A backreference can match anything its group can match, which
+            // we could extract literals from, but only when the group
+            // matched. It's simpler to just give up.
+            Backreference(_) => Seq::infinite(),
+            // Every match of an atomic group is a match of its
+            // sub-expression, but not vice versa. So the literals of the
+            // sub-expression will do, but they can never be exact.
+            Atomic(ref sub) => {
+                let mut seq = self.extract(sub);
+                seq.make_inexact();
+                seq
+            }
         }
     }
 
@@ -2453,6 +2482,14 @@ mod tests {
         assert_eq!(expected, e(r"^aZ*b"));
     }
 
+    #[test]
+    fn look_around() {
+        assert_eq!(inexact([I("a")], [I("c")]), e(r"a(?=b)c"));
+        assert_eq!(inexact([I("a")], [I("c")]), e(r"a(?<!b)c"));
+        assert_eq!(inexact([I("")], [I("ab")]), e(r"(?<=x)ab"));
+        assert_eq!(inexact([I("ab")], [I("")]), e(r"ab(?!x)"));
+    }
+
     #[test]
     fn repetition() {
         assert_eq!(exact(["a", ""]), e(r"a?"));
@@ -34,13 +34,17 @@ use crate::{
 };
 
 pub use crate::{
-    hir::visitor::{visit, Visitor},
+    hir::{
+        sample::{ClassWeight, Sampler, SamplerBuilder},
+        visitor::{visit, Visitor},
+    },
     unicode::CaseFoldError,
 };
 
 mod interval;
 pub mod literal;
 pub mod print;
+mod sample;
 pub mod translate;
 mod visitor;
 
@@ -105,6 +109,56 @@ pub enum ErrorKind {
     /// available, and the regular expression required Unicode aware case
     /// insensitivity.
     UnicodeCaseUnavailable,
+    /// This occurs when the name in a `\N{...}` escape does not refer to any
+    /// codepoint.
+    UnicodeNameNotFound,
+    /// This occurs when the name in a `\N{...}` escape could not be looked
+    /// up because the Unicode character name data is not available. This can
+    /// occur when the `unicode-name` crate feature is not enabled.
+    UnicodeNameUnavailable,
+    /// This occurs when a character class range has a start greater than its
+    /// end, and at least one of its endpoints is written as a `\N{...}`
+    /// escape. (Since names are only resolved during translation, the parser
+    /// cannot detect this. All other invalid ranges are reported by the
+    /// parser.)
+    ClassRangeInvalid,
+    /// This occurs when a look-around assertion (e.g., `(?=a)`) appears
+    /// within another look-around assertion. Nested look-around is not
+    /// supported.
+    LookAroundNested,
+    /// This occurs when a capturing group appears within a look-around
+    /// assertion. Since a look-around assertion never consumes any of the
+    /// haystack, capturing groups inside of one are not supported.
+    LookAroundCapture,
+    /// This occurs when the sub-expression of a look-behind assertion
+    /// (e.g., `(?<=a+)`) does not have a bounded maximum length. Only
+    /// look-behind assertions of bounded length are supported.
+    LookBehindUnbounded,
+    /// This occurs when a capturing group appears within an operand of an
+    /// intersection (`a&b`) or a complement (`~a`). Since these operators
+    /// are only defined on whole strings, there is no meaningful span to
+    /// report for a capturing group inside of one.
+    BooleanCapture,
+    /// This occurs when a backreference refers to a capturing group that
+    /// doesn't exist, or to a group that doesn't open until after the
+    /// backreference.
+    BackreferenceUndefined,
+    /// This occurs when a backreference appears within a look-around
+    /// assertion or within an operand of an intersection or a complement.
+    BackreferenceUnsupported,
+    /// This occurs when a backreference appears while case insensitive
+    /// matching is enabled, e.g., `(?i)(a)\1`. Backreferences always match
+    /// the text of the group they refer to exactly.
+    BackreferenceCaseInsensitive,
+    /// This occurs when an atomic group or a possessive repetition appears
+    /// within a look-around assertion or within an operand of an
+    /// intersection or a complement.
+    AtomicUnsupported,
+    /// This occurs when the Unicode tables needed to match extended grapheme
+    /// clusters are not available. This can occur when `\X` or grapheme mode
+    /// (`g`) is used without the `unicode-segment` and `unicode-bool` crate
+    /// features enabled.
+    UnicodeGraphemeUnavailable,
 }
 
 #[cfg(feature = "std")]
@@ -134,6 +188,46 @@ impl core::fmt::Display for ErrorKind {
                 "Unicode-aware case insensitivity matching is not available \
                  (make sure the unicode-case feature is enabled)"
             }
+            UnicodeNameNotFound => "Unicode character name not found",
+            UnicodeNameUnavailable => {
+                "Unicode character names are not available \
+                 (make sure the unicode-name feature is enabled)"
+            }
+            ClassRangeInvalid => {
+                "invalid character class range, the start must be <= the end"
+            }
+            LookAroundNested => "nested look-around is not supported",
+            LookAroundCapture => {
+                "capturing groups inside of look-around are not supported"
+            }
+            LookBehindUnbounded => {
+                "look-behind must have a bounded maximum length"
+            }
+            BooleanCapture => {
+                "capturing groups inside of intersection or complement \
+                 are not supported"
+            }
+            BackreferenceUndefined => {
+                "backreference refers to a group that is not defined \
+                 before it"
+            }
+            BackreferenceUnsupported => {
+                "backreferences inside of look-around, intersection or \
+                 complement are not supported"
+            }
+            BackreferenceCaseInsensitive => {
+                "backreferences are not supported when case insensitive \
+                 matching is enabled"
+            }
+            AtomicUnsupported => {
+                "atomic groups and possessive repetitions inside of \
+                 look-around, intersection or complement are not supported"
+            }
+            UnicodeGraphemeUnavailable => {
+                "Unicode-aware grapheme cluster matching is not available \
+                 (make sure the unicode-segment and unicode-bool features \
+                 are enabled)"
+            }
         };
         f.write_str(msg)
     }
@@ -373,6 +467,18 @@ impl Hir {
         Hir { kind: HirKind::Look(look), props }
     }
 
+    /// Creates a look-around assertion HIR expression with a sub-expression,
+    /// e.g., `(?=a)` or `(?<!b)`.
+    ///
+    /// Like [`Hir::look`], a look-around assertion always matches the empty
+    /// string. Its sub-expression is only used to decide whether the empty
+    /// string can match at a particular position.
+    #[inline]
+    pub fn look_around(look_around: LookAround) -> Hir {
+        let props = Properties::look_around(&look_around);
+        Hir { kind: HirKind::LookAround(look_around), props }
+    }
+
     /// Creates a repetition HIR expression.
     #[inline]
     pub fn repetition(mut rep: Repetition) -> Hir {
@@ -631,6 +737,143 @@ impl Hir {
         Hir { kind: HirKind::Alternation(new), props }
     }
 
+    /// Returns the intersection of the given expressions.
+    ///
+    /// An intersection matches a string only when every one of the given
+    /// expressions matches that entire string. Nested intersections are
+    /// flattened.
+    ///
+    /// The intersection of zero expressions matches every string, and is
+    /// represented as the complement of [`Hir::fail`]. The intersection of
+    /// a single expression is that expression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_syntax::hir::{Hir, HirKind};
+    ///
+    /// let hir = Hir::intersection(vec![
+    ///     Hir::literal("a".as_bytes()),
+    ///     Hir::intersection(vec![
+    ///         Hir::literal("b".as_bytes()),
+    ///         Hir::literal("c".as_bytes()),
+    ///     ]),
+    /// ]);
+    /// assert!(matches!(*hir.kind(), HirKind::Intersection(ref subs)
+    ///     if subs.len() == 3));
+    /// ```
+    pub fn intersection(subs: Vec<Hir>) -> Hir {
+        let mut new = Vec::with_capacity(subs.len());
+        for sub in subs {
+            let (kind, props) = sub.into_parts();
+            match kind {
+                HirKind::Intersection(subs2) => {
+                    new.extend(subs2);
+                }
+                kind => {
+                    new.push(Hir { kind, props });
+                }
+            }
+        }
+        if new.is_empty() {
+            return Hir::complement(Hir::fail());
+        } else if new.len() == 1 {
+            return new.pop().unwrap();
+        }
+        let props = Properties::intersection(&new);
+        Hir { kind: HirKind::Intersection(new), props }
+    }
+
+    /// Returns the complement of the given expression.
+    ///
+    /// The complement matches every sequence of bytes that the given
+    /// expression does not match, including sequences that are not valid
+    /// UTF-8. To get the complement with respect to valid UTF-8, intersect
+    /// the result with `(?s:.)*`.
+    ///
+    /// The complement of a complement is simplified to the original
+    /// expression.
+    #[inline]
+    pub fn complement(sub: Hir) -> Hir {
+        let (kind, props) = sub.into_parts();
+        if let HirKind::Complement(sub) = kind {
+            return *sub;
+        }
+        let sub = Hir { kind, props };
+        let props = Properties::complement(&sub);
+        Hir { kind: HirKind::Complement(Box::new(sub)), props }
+    }
+
+    /// Creates a backreference to the capturing group with the given index.
+    ///
+    /// A backreference matches exactly the text most recently matched by
+    /// the capturing group it refers to. If that group did not participate
+    /// in the match, then the backreference does not match.
+    ///
+    /// Backreferences cannot be implemented by finite automata. Most regex
+    /// engines will therefore refuse to compile an expression containing
+    /// one.
+    #[inline]
+    pub fn backreference(index: u32) -> Hir {
+        let props = Properties::backreference();
+        Hir { kind: HirKind::Backreference(index), props }
+    }
+
+    /// Returns an atomic group containing the given expression.
+    ///
+    /// An atomic group matches whatever its sub-expression matches first
+    /// (according to leftmost-first preference order), and then never
+    /// backtracks into it. That is, once an atomic group matches, no other
+    /// way of matching its sub-expression is ever considered. For example,
+    /// `(?>a*)a` never matches since `a*` consumes every `a` and gives none
+    /// of them back. A possessive repetition like `a*+` is translated into
+    /// an atomic group containing a greedy repetition.
+    ///
+    /// Expressions that can only match in one way, like literals and
+    /// classes, are returned as-is. An atomic group directly inside of
+    /// another one is flattened.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_syntax::hir::{Hir, HirKind, Repetition};
+    ///
+    /// let rep = Hir::repetition(Repetition {
+    ///     min: 0,
+    ///     max: None,
+    ///     greedy: true,
+    ///     sub: Box::new(Hir::literal("a".as_bytes())),
+    /// });
+    /// let hir = Hir::atomic(rep.clone());
+    /// assert_eq!(hir.kind(), &HirKind::Atomic(Box::new(rep)));
+    ///
+    /// let lit = Hir::literal("a".as_bytes());
+    /// assert_eq!(Hir::atomic(lit.clone()), lit);
+    /// ```
+    #[inline]
+    pub fn atomic(sub: Hir) -> Hir {
+        match *sub.kind() {
+            HirKind::Empty
+            | HirKind::Literal(_)
+            | HirKind::Class(_)
+            | HirKind::Look(_)
+            | HirKind::Backreference(_)
+            | HirKind::Atomic(_) => return sub,
+            _ => {}
+        }
+        let props = Properties::atomic(&sub);
+        Hir { kind: HirKind::Atomic(Box::new(sub)), props }
+    }
+
+    /// Returns an expression that matches every string matched by `a` that
+    /// is not matched by `b`.
+    ///
+    /// This is equivalent to `Hir::intersection(vec![a, Hir::complement(b)])`.
+    #[inline]
+    pub fn difference(a: Hir, b: Hir) -> Hir {
+        Hir::intersection(vec![a, Hir::complement(b)])
+    }
+
     /// Returns an HIR expression for `.`.
     ///
     /// * [`Dot::AnyChar`] maps to `(?su-R:.)`.
@@ -728,6 +971,9 @@ pub enum HirKind {
     Class(Class),
     /// A look-around assertion. A look-around match always has zero length.
     Look(Look),
+    /// A look-around assertion with a sub-expression, e.g., `(?=a)` or
+    /// `(?<!b)`. Like [`HirKind::Look`], it always has zero length.
+    LookAround(LookAround),
     /// A repetition operation applied to a sub-expression.
     Repetition(Repetition),
     /// A capturing group, which contains a sub-expression.
@@ -749,6 +995,33 @@ pub enum HirKind {
     /// Alternations are guaranteed by `Hir`'s smart constructors to always
     /// have at least two sub-expressions.
     Alternation(Vec<Hir>),
+    /// An intersection of expressions.
+    ///
+    /// An intersection matches a string only if every one of its
+    /// sub-expressions matches that same string.
+    ///
+    /// Intersections are guaranteed by `Hir`'s smart constructors to always
+    /// have at least two sub-expressions.
+    Intersection(Vec<Hir>),
+    /// The complement of an expression.
+    ///
+    /// A complement matches every sequence of bytes that its sub-expression
+    /// does not match. Note that this includes sequences of bytes that are
+    /// not valid UTF-8. The translator restricts the complement to valid
+    /// UTF-8 when Unicode mode is enabled by intersecting it with `(?s:.)*`.
+    Complement(Box<Hir>),
+    /// A backreference to the capturing group with the given index.
+    ///
+    /// A backreference matches exactly the text most recently matched by
+    /// the capturing group it refers to.
+    Backreference(u32),
+    /// An atomic group, e.g., `(?>a*)` or `a*+`.
+    ///
+    /// An atomic group matches whatever its sub-expression matches first
+    /// and never backtracks into it. Atomic groups cannot be implemented by
+    /// finite automata, but they can be implemented by the NFA simulations
+    /// and backtracking engines in `regex-automata`.
+    Atomic(Box<Hir>),
 }
 
 impl HirKind {
@@ -760,11 +1033,16 @@ impl HirKind {
             HirKind::Empty
             | HirKind::Literal(_)
             | HirKind::Class(_)
-            | HirKind::Look(_) => &[],
+            | HirKind::Look(_)
+            | HirKind::Backreference(_) => &[],
             HirKind::Repetition(Repetition { ref sub, .. }) => from_ref(sub),
             HirKind::Capture(Capture { ref sub, .. }) => from_ref(sub),
+            HirKind::LookAround(LookAround { ref sub, .. }) => from_ref(sub),
             HirKind::Concat(ref subs) => subs,
             HirKind::Alternation(ref subs) => subs,
+            HirKind::Intersection(ref subs) => subs,
+            HirKind::Complement(ref sub) => from_ref(sub),
+            HirKind::Atomic(ref sub) => from_ref(sub),
         }
     }
 }
@@ -1683,6 +1961,20 @@ pub enum Look {
     /// a position at either the end of the haystack or where the following
     /// character is not a word character.
     WordEndHalfUnicode = 1 << 17,
+    /// Match a Unicode word segmentation boundary. That is, this matches a
+    /// position that separates two words according to the default word
+    /// boundary rules in [UAX #29]. Unlike `WordUnicode`, this matches
+    /// between any two words (including punctuation and whitespace), and
+    /// never within words like `can't` or numbers like `3.14`.
+    ///
+    /// [UAX #29]: https://unicode.org/reports/tr29/#Word_Boundaries
+    WordSegment = 1 << 18,
+    /// Match a Unicode sentence segmentation boundary. That is, this matches
+    /// a position that separates two sentences according to the default
+    /// sentence boundary rules in [UAX #29].
+    ///
+    /// [UAX #29]: https://unicode.org/reports/tr29/#Sentence_Boundaries
+    SentenceSegment = 1 << 19,
 }
 
 impl Look {
@@ -1712,6 +2004,8 @@ impl Look {
             Look::WordEndHalfAscii => Look::WordStartHalfAscii,
             Look::WordStartHalfUnicode => Look::WordEndHalfUnicode,
             Look::WordEndHalfUnicode => Look::WordStartHalfUnicode,
+            Look::WordSegment => Look::WordSegment,
+            Look::SentenceSegment => Look::SentenceSegment,
         }
     }
 
@@ -1732,24 +2026,26 @@ impl Look {
     #[inline]
     pub const fn from_repr(repr: u32) -> Option<Look> {
         match repr {
-            0b00_0000_0000_0000_0001 => Some(Look::Start),
-            0b00_0000_0000_0000_0010 => Some(Look::End),
-            0b00_0000_0000_0000_0100 => Some(Look::StartLF),
-            0b00_0000_0000_0000_1000 => Some(Look::EndLF),
-            0b00_0000_0000_0001_0000 => Some(Look::StartCRLF),
-            0b00_0000_0000_0010_0000 => Some(Look::EndCRLF),
-            0b00_0000_0000_0100_0000 => Some(Look::WordAscii),
-            0b00_0000_0000_1000_0000 => Some(Look::WordAsciiNegate),
-            0b00_0000_0001_0000_0000 => Some(Look::WordUnicode),
-            0b00_0000_0010_0000_0000 => Some(Look::WordUnicodeNegate),
-            0b00_0000_0100_0000_0000 => Some(Look::WordStartAscii),
-            0b00_0000_1000_0000_0000 => Some(Look::WordEndAscii),
-            0b00_0001_0000_0000_0000 => Some(Look::WordStartUnicode),
-            0b00_0010_0000_0000_0000 => Some(Look::WordEndUnicode),
-            0b00_0100_0000_0000_0000 => Some(Look::WordStartHalfAscii),
-            0b00_1000_0000_0000_0000 => Some(Look::WordEndHalfAscii),
-            0b01_0000_0000_0000_0000 => Some(Look::WordStartHalfUnicode),
-            0b10_0000_0000_0000_0000 => Some(Look::WordEndHalfUnicode),
+            0b0000_0000_0000_0000_0001 => Some(Look::Start),
+            0b0000_0000_0000_0000_0010 => Some(Look::End),
+            0b0000_0000_0000_0000_0100 => Some(Look::StartLF),
+            0b0000_0000_0000_0000_1000 => Some(Look::EndLF),
+            0b0000_0000_0000_0001_0000 => Some(Look::StartCRLF),
+            0b0000_0000_0000_0010_0000 => Some(Look::EndCRLF),
+            0b0000_0000_0000_0100_0000 => Some(Look::WordAscii),
+            0b0000_0000_0000_1000_0000 => Some(Look::WordAsciiNegate),
+            0b0000_0000_0001_0000_0000 => Some(Look::WordUnicode),
+            0b0000_0000_0010_0000_0000 => Some(Look::WordUnicodeNegate),
+            0b0000_0000_0100_0000_0000 => Some(Look::WordStartAscii),
+            0b0000_0000_1000_0000_0000 => Some(Look::WordEndAscii),
+            0b0000_0001_0000_0000_0000 => Some(Look::WordStartUnicode),
+            0b0000_0010_0000_0000_0000 => Some(Look::WordEndUnicode),
+            0b0000_0100_0000_0000_0000 => Some(Look::WordStartHalfAscii),
+            0b0000_1000_0000_0000_0000 => Some(Look::WordEndHalfAscii),
+            0b0001_0000_0000_0000_0000 => Some(Look::WordStartHalfUnicode),
+            0b0010_0000_0000_0000_0000 => Some(Look::WordEndHalfUnicode),
+            0b0100_0000_0000_0000_0000 => Some(Look::WordSegment),
+            0b1000_0000_0000_0000_0000 => Some(Look::SentenceSegment),
             _ => None,
         }
     }
@@ -1782,10 +2078,68 @@ impl Look {
             Look::WordEndHalfAscii => '▷',
             Look::WordStartHalfUnicode => '◀',
             Look::WordEndHalfUnicode => '▶',
+            Look::WordSegment => 'w',
+            Look::SentenceSegment => 's',
         }
     }
 }
 
+/// The high-level intermediate representation for a look-around assertion
+/// with a sub-expression.
+///
+/// A look-around assertion matches the empty string at a position if and
+/// only if its sub-expression matches (or, when negated, does not match)
+/// immediately after that position (for look-ahead) or immediately before
+/// that position (for look-behind). The sub-expression is matched against
+/// the entire haystack and is not limited by the bounds of a search.
+///
+/// When produced by the translator, the sub-expression of a look-around
+/// never contains another look-around or a capturing group, and the
+/// sub-expression of a look-behind always has a bounded maximum length.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LookAround {
+    /// The kind of this look-around assertion.
+    pub kind: LookAroundKind,
+    /// The expression inside the look-around assertion, which may be empty.
+    pub sub: Box<Hir>,
+}
+
+/// The kind of a look-around assertion with a sub-expression.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LookAroundKind {
+    /// `(?=a)`
+    PositiveLookAhead,
+    /// `(?!a)`
+    NegativeLookAhead,
+    /// `(?<=a)`
+    PositiveLookBehind,
+    /// `(?<!a)`
+    NegativeLookBehind,
+}
+
+impl LookAroundKind {
+    /// Returns true if and only if this is a look-behind assertion.
+    #[inline]
+    pub fn is_look_behind(self) -> bool {
+        matches!(
+            self,
+            LookAroundKind::PositiveLookBehind
+                | LookAroundKind::NegativeLookBehind
+        )
+    }
+
+    /// Returns true if and only if this is a negative assertion, i.e., one
+    /// that matches when its sub-expression does not.
+    #[inline]
+    pub fn is_negated(self) -> bool {
+        matches!(
+            self,
+            LookAroundKind::NegativeLookAhead
+                | LookAroundKind::NegativeLookBehind
+        )
+    }
+}
+
 /// The high-level intermediate representation for a capturing group.
 ///
 /// A capturing group always has an index and a child expression. It may
@@ -1918,13 +2272,20 @@ impl Drop for Hir {
             HirKind::Empty
             | HirKind::Literal(_)
             | HirKind::Class(_)
-            | HirKind::Look(_) => return,
+            | HirKind::Look(_)
+            | HirKind::Backreference(_) => return,
             HirKind::Capture(ref x) if x.sub.kind.subs().is_empty() => return,
+            HirKind::LookAround(ref x) if x.sub.kind.subs().is_empty() => {
+                return
+            }
             HirKind::Repetition(ref x) if x.sub.kind.subs().is_empty() => {
                 return
             }
             HirKind::Concat(ref x) if x.is_empty() => return,
             HirKind::Alternation(ref x) if x.is_empty() => return,
+            HirKind::Intersection(ref x) if x.is_empty() => return,
+            HirKind::Complement(ref x) if x.kind.subs().is_empty() => return,
+            HirKind::Atomic(ref x) if x.kind.subs().is_empty() => return,
             _ => {}
         }
 
@@ -1934,10 +2295,14 @@ impl Drop for Hir {
                 HirKind::Empty
                 | HirKind::Literal(_)
                 | HirKind::Class(_)
-                | HirKind::Look(_) => {}
+                | HirKind::Look(_)
+                | HirKind::Backreference(_) => {}
                 HirKind::Capture(ref mut x) => {
                     stack.push(mem::replace(&mut x.sub, Hir::empty()));
                 }
+                HirKind::LookAround(ref mut x) => {
+                    stack.push(mem::replace(&mut x.sub, Hir::empty()));
+                }
                 HirKind::Repetition(ref mut x) => {
                     stack.push(mem::replace(&mut x.sub, Hir::empty()));
                 }
@@ -1947,6 +2312,15 @@ impl Drop for Hir {
                 HirKind::Alternation(ref mut x) => {
                     stack.extend(x.drain(..));
                 }
+                HirKind::Intersection(ref mut x) => {
+                    stack.extend(x.drain(..));
+                }
+                HirKind::Complement(ref mut x) => {
+                    stack.push(mem::replace(x, Hir::empty()));
+                }
+                HirKind::Atomic(ref mut x) => {
+                    stack.push(mem::replace(x, Hir::empty()));
+                }
             }
         }
     }
@@ -2499,6 +2873,58 @@ impl Properties {
         Properties(Box::new(inner))
     }
 
+    /// Create a new set of HIR properties for a look-around assertion with a
+    /// sub-expression.
+    fn look_around(look_around: &LookAround) -> Properties {
+        let p = look_around.sub.properties();
+        let inner = PropertiesI {
+            minimum_len: Some(0),
+            maximum_len: Some(0),
+            // The look set only tracks the simple assertions in 'Look'. We
+            // don't include the look set of the sub-expression either, since
+            // it is never required to match at the position of the
+            // look-around itself.
+            look_set: LookSet::empty(),
+            look_set_prefix: LookSet::empty(),
+            look_set_suffix: LookSet::empty(),
+            look_set_prefix_any: LookSet::empty(),
+            look_set_suffix_any: LookSet::empty(),
+            // See the comment in 'Properties::look' for why this is true.
+            utf8: true,
+            explicit_captures_len: p.explicit_captures_len(),
+            static_explicit_captures_len: p.static_explicit_captures_len(),
+            literal: false,
+            alternation_literal: false,
+        };
+        Properties(Box::new(inner))
+    }
+
+    /// Create a new set of HIR properties for a backreference.
+    fn backreference() -> Properties {
+        let inner = PropertiesI {
+            // The group being referred to might match the empty string, and
+            // there is no bound on what it might match otherwise. We could
+            // do better by looking at the group, but it's not clear that
+            // it's worth it.
+            minimum_len: Some(0),
+            maximum_len: None,
+            look_set: LookSet::empty(),
+            look_set_prefix: LookSet::empty(),
+            look_set_suffix: LookSet::empty(),
+            look_set_prefix_any: LookSet::empty(),
+            look_set_suffix_any: LookSet::empty(),
+            // A backreference only ever matches text that was already
+            // matched by a capturing group. So if the group can't match
+            // invalid UTF-8, then neither can the backreference.
+            utf8: true,
+            explicit_captures_len: 0,
+            static_explicit_captures_len: Some(0),
+            literal: false,
+            alternation_literal: false,
+        };
+        Properties(Box::new(inner))
+    }
+
     /// Create a new set of HIR properties for a repetition.
     fn repetition(rep: &Repetition) -> Properties {
         let p = rep.sub.properties();
@@ -2567,6 +2993,92 @@ impl Properties {
         }))
     }
 
+    /// Create a new set of HIR properties for an intersection.
+    fn intersection(subs: &[Hir]) -> Properties {
+        // The base case is an empty intersection, which matches everything.
+        // Note though that empty intersections aren't possible, because the
+        // Hir::intersection smart constructor rewrites those as the
+        // complement of Hir::fail.
+        let mut props = PropertiesI {
+            minimum_len: Some(0),
+            maximum_len: None,
+            look_set: LookSet::empty(),
+            // We could union the required prefix and suffix assertions of
+            // every operand, but we keep things simple and conservative.
+            look_set_prefix: LookSet::empty(),
+            look_set_suffix: LookSet::empty(),
+            look_set_prefix_any: LookSet::empty(),
+            look_set_suffix_any: LookSet::empty(),
+            utf8: false,
+            // Capturing groups are never reported for the operands of an
+            // intersection, since they only ever match whole strings.
+            explicit_captures_len: 0,
+            static_explicit_captures_len: Some(0),
+            literal: false,
+            alternation_literal: false,
+        };
+        for x in subs.iter() {
+            let p = x.properties();
+            props.look_set.set_union(p.look_set());
+            props.look_set_prefix_any.set_union(p.look_set_prefix_any());
+            props.look_set_suffix_any.set_union(p.look_set_suffix_any());
+            // Every string matched by the intersection is matched by every
+            // operand. So if any operand only matches valid UTF-8, then so
+            // does the intersection.
+            props.utf8 = props.utf8 || p.is_utf8();
+            props.minimum_len = match (props.minimum_len, p.minimum_len()) {
+                (Some(x), Some(y)) => Some(cmp::max(x, y)),
+                _ => None,
+            };
+            props.maximum_len = match (props.maximum_len, p.maximum_len()) {
+                (Some(x), Some(y)) => Some(cmp::min(x, y)),
+                (x, y) => x.or(y),
+            };
+        }
+        Properties(Box::new(props))
+    }
+
+    /// Create a new set of HIR properties for a complement.
+    fn complement(sub: &Hir) -> Properties {
+        let p = sub.properties();
+        let inner = PropertiesI {
+            // A complement might match the empty string, and is generally
+            // unbounded. We could be more precise in some cases, but it isn't
+            // obviously worth it.
+            minimum_len: Some(0),
+            maximum_len: None,
+            look_set: p.look_set(),
+            look_set_prefix: LookSet::empty(),
+            look_set_suffix: LookSet::empty(),
+            look_set_prefix_any: p.look_set_prefix_any(),
+            look_set_suffix_any: p.look_set_suffix_any(),
+            // A complement always matches invalid UTF-8, unless it is
+            // intersected with something that doesn't.
+            utf8: false,
+            explicit_captures_len: 0,
+            static_explicit_captures_len: Some(0),
+            literal: false,
+            alternation_literal: false,
+        };
+        Properties(Box::new(inner))
+    }
+
+    /// Create a new set of HIR properties for an atomic group.
+    fn atomic(sub: &Hir) -> Properties {
+        let p = sub.properties();
+        let inner = PropertiesI {
+            // Every match of an atomic group is a match of its
+            // sub-expression, so the sub-expression's properties remain
+            // valid bounds. An atomic group might never match even when its
+            // sub-expression could, e.g., in '(?>a*)a', but that's fine
+            // since the lengths are only bounds.
+            literal: false,
+            alternation_literal: false,
+            ..(*p.0).clone()
+        };
+        Properties(Box::new(inner))
+    }
+
     /// Create a new set of HIR properties for a concatenation.
     fn concat(concat: &[Hir]) -> Properties {
         // The base case is an empty concatenation, which matches the empty
@@ -3850,7 +4362,7 @@ mod tests {
         assert_eq!(0, set.iter().count());
 
         let set = LookSet::full();
-        assert_eq!(18, set.iter().count());
+        assert_eq!(20, set.iter().count());
 
         let set =
             LookSet::empty().insert(Look::StartLF).insert(Look::WordUnicode);
@@ -3868,6 +4380,6 @@ mod tests {
         let res = format!("{:?}", LookSet::empty());
         assert_eq!("∅", res);
         let res = format!("{:?}", LookSet::full());
-        assert_eq!("Az^$rRbB𝛃𝚩<>〈〉◁▷◀▶", res);
+        assert_eq!("Az^$rRbB𝛃𝚩<>〈〉◁▷◀▶ws", res);
     }
 }
@@ -226,7 +226,27 @@ impl<W: fmt::Write> Visitor for Writer<W> {
                 hir::Look::WordEndHalfUnicode => {
                     self.wtr.write_str(r"\b{end-half}")?;
                 }
+                hir::Look::WordSegment => {
+                    self.wtr.write_str(r"\b{wb}")?;
+                }
+                hir::Look::SentenceSegment => {
+                    self.wtr.write_str(r"\b{sb}")?;
+                }
             },
+            // The group ensures that a literal digit that follows the
+            // backreference isn't interpreted as part of its index.
+            HirKind::Backreference(index) => {
+                write!(self.wtr, r"(?:\{})", index)?;
+            }
+            HirKind::LookAround(ref x) => {
+                use crate::hir::LookAroundKind::*;
+                self.wtr.write_str(match x.kind {
+                    PositiveLookAhead => "(?=",
+                    NegativeLookAhead => "(?!",
+                    PositiveLookBehind => "(?<=",
+                    NegativeLookBehind => "(?<!",
+                })?;
+            }
             HirKind::Capture(hir::Capture { ref name, .. }) => {
                 self.wtr.write_str("(")?;
                 if let Some(ref name) = *name {
@@ -246,9 +266,20 @@ impl<W: fmt::Write> Visitor for Writer<W> {
             // of an HIR printer to show a nice easy-to-read regex. Indeed,
             // its construction forbids it from doing so. Therefore, inserting
             // extra groups where they aren't necessary is perfectly okay.
-            HirKind::Concat(_) | HirKind::Alternation(_) => {
+            HirKind::Concat(_)
+            | HirKind::Alternation(_)
+            | HirKind::Intersection(_) => {
                 self.wtr.write_str(r"(?:")?;
             }
+            // Like repetition operators, the complement operator binds more
+            // tightly than concatenation. So we always put its operand in a
+            // group, which also makes things like '~(?:a)*' unambiguous.
+            HirKind::Complement(_) => {
+                self.wtr.write_str(r"~(?:")?;
+            }
+            HirKind::Atomic(_) => {
+                self.wtr.write_str(r"(?>")?;
+            }
         }
         Ok(())
     }
@@ -259,7 +290,8 @@ impl<W: fmt::Write> Visitor for Writer<W> {
             HirKind::Empty
             | HirKind::Literal(_)
             | HirKind::Class(_)
-            | HirKind::Look(_) => {}
+            | HirKind::Look(_)
+            | HirKind::Backreference(_) => {}
             HirKind::Repetition(ref x) => {
                 match (x.min, x.max) {
                     (0, Some(1)) => {
@@ -292,8 +324,12 @@ impl<W: fmt::Write> Visitor for Writer<W> {
                 }
             }
             HirKind::Capture(_)
+            | HirKind::LookAround(_)
             | HirKind::Concat(_)
-            | HirKind::Alternation(_) => {
+            | HirKind::Alternation(_)
+            | HirKind::Intersection(_)
+            | HirKind::Complement(_)
+            | HirKind::Atomic(_) => {
                 self.wtr.write_str(r")")?;
             }
         }
@@ -303,6 +339,10 @@ impl<W: fmt::Write> Visitor for Writer<W> {
     fn visit_alternation_in(&mut self) -> fmt::Result {
         self.wtr.write_str("|")
     }
+
+    fn visit_intersection_in(&mut self) -> fmt::Result {
+        self.wtr.write_str("&")
+    }
 }
 
 impl<W: fmt::Write> Writer<W> {
@@ -376,6 +416,28 @@ mod tests {
         roundtrip("☃", "☃");
     }
 
+    #[test]
+    fn print_backreference() {
+        roundtrip_with(|b| b.backreferences(true), r"(a)\1", r"(?:(a)(?:\1))");
+        roundtrip_with(
+            |b| b.backreferences(true),
+            r"(?<x>a)\k<x>2",
+            r"(?:(?P<x>a)(?:\1)2)",
+        );
+    }
+
+    #[test]
+    fn print_atomic() {
+        roundtrip(r"(?>a*)", r"(?>a*)");
+        roundtrip(r"(?>a|bc)", r"(?>(?:a|(?:bc)))");
+        roundtrip(r"(?>a)", r"a");
+        roundtrip_with(
+            |b| b.possessive_quantifiers(true),
+            r"a*+b",
+            r"(?:(?>a*)b)",
+        );
+    }
+
     #[test]
     fn print_class() {
         roundtrip(r"[a]", r"a");
@@ -0,0 +1,583 @@
+/*!
+Defines a sampler that produces random strings matched by an `Hir`.
+*/
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::hir::{Class, Hir, HirKind, Look};
+
+/// The number of times a sampler tries to pick a character from a class
+/// that is compatible with pending look-around assertions before giving up
+/// on the current sample.
+const CLASS_PICKS: usize = 32;
+
+/// How a sampler weights the characters in a class.
+///
+/// The choice here matters most for large Unicode classes. For example,
+/// `\w` contains well over 100,000 codepoints, nearly all of which are
+/// outside of ASCII. When weighting every codepoint equally, samples of `\w`
+/// will almost always be CJK ideographs.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClassWeight {
+    /// Every codepoint (or byte) in a class is equally likely.
+    Codepoint,
+    /// Every contiguous range in a class is equally likely, and every
+    /// codepoint (or byte) within the chosen range is equally likely. This
+    /// tends to produce a wider variety of scripts.
+    ///
+    /// This is the default.
+    Range,
+}
+
+/// A builder for constructing a [`Sampler`].
+///
+/// This permits configuring how repetitions are expanded and how characters
+/// are chosen from classes.
+#[derive(Clone, Debug)]
+pub struct SamplerBuilder {
+    max_repeat: u32,
+    class_weight: ClassWeight,
+    ascii_percent: u8,
+    line_terminator: u8,
+    max_attempts: usize,
+    seed: u64,
+}
+
+impl Default for SamplerBuilder {
+    fn default() -> SamplerBuilder {
+        SamplerBuilder::new()
+    }
+}
+
+impl SamplerBuilder {
+    /// Create a new sampler builder with a default configuration.
+    pub fn new() -> SamplerBuilder {
+        SamplerBuilder {
+            max_repeat: 8,
+            class_weight: ClassWeight::Range,
+            ascii_percent: 50,
+            line_terminator: b'\n',
+            max_attempts: 100,
+            seed: 0,
+        }
+    }
+
+    /// Build a sampler using the current configuration.
+    pub fn build(&self) -> Sampler {
+        Sampler { config: self.clone(), rng: Rng::new(self.seed) }
+    }
+
+    /// Set the maximum number of times a repetition is repeated beyond its
+    /// minimum.
+    ///
+    /// For example, with the default of `8`, `a+` produces between 1 and 9
+    /// `a`s and `a{2,20}` produces between 2 and 10 `a`s. The minimum of a
+    /// repetition is always respected.
+    pub fn max_repeat(&mut self, limit: u32) -> &mut SamplerBuilder {
+        self.max_repeat = limit;
+        self
+    }
+
+    /// Set how characters are chosen from classes.
+    ///
+    /// By default, [`ClassWeight::Range`] is used.
+    pub fn class_weight(
+        &mut self,
+        weight: ClassWeight,
+    ) -> &mut SamplerBuilder {
+        self.class_weight = weight;
+        self
+    }
+
+    /// Set the percentage of characters chosen from the ASCII subset of a
+    /// class, for classes that contain at least one ASCII character.
+    ///
+    /// A value of `0` means characters are chosen from an entire class
+    /// according to the [`ClassWeight`] setting, while a value of `100`
+    /// means that ASCII characters are always chosen when possible. Values
+    /// greater than `100` are treated as `100`. The default is `50`.
+    pub fn ascii_percent(&mut self, percent: u8) -> &mut SamplerBuilder {
+        self.ascii_percent = core::cmp::min(percent, 100);
+        self
+    }
+
+    /// Set the line terminator used by the line anchors [`Look::StartLF`]
+    /// and [`Look::EndLF`].
+    ///
+    /// This should match the line terminator configured in the regex engine
+    /// used to check samples. By default, this is `\n`.
+    pub fn line_terminator(&mut self, byte: u8) -> &mut SamplerBuilder {
+        self.line_terminator = byte;
+        self
+    }
+
+    /// Set the number of times a sampler tries to produce a sample before
+    /// giving up.
+    ///
+    /// A sampler only needs more than one attempt when a random choice
+    /// conflicts with a look-around assertion. For example, `a\bb` never
+    /// matches anything, so every attempt fails. The default is `100`.
+    pub fn max_attempts(&mut self, attempts: usize) -> &mut SamplerBuilder {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Set the seed for the sampler's pseudo-random number generator.
+    ///
+    /// Two samplers with the same configuration and seed produce the same
+    /// samples for the same sequence of expressions. The default is `0`.
+    pub fn seed(&mut self, seed: u64) -> &mut SamplerBuilder {
+        self.seed = seed;
+        self
+    }
+}
+
+/// A sampler produces random strings matched by an [`Hir`].
+///
+/// Samples are built by walking the `Hir`: a random branch is chosen for
+/// each alternation, a random number of iterations for each repetition and
+/// a random character for each class. Look-around assertions (such as
+/// `\b`, `^` and `(?m:$)`) constrain the characters chosen around them.
+/// When a choice conflicts with an assertion, the sample is discarded and
+/// another one is attempted.
+///
+/// A sample matches the `Hir` in its entirety. That is, a sample of `Hir`
+/// compiled into a regex engine with the same configuration, such as the
+/// same line terminator, always matches the whole sample. (Subject to the
+/// Unicode data available: `\w` might, for example, be a different set of
+/// codepoints in a regex engine using a different version of Unicode.)
+///
+/// Look-around assertions with a sub-expression (like `(?=re)`),
+/// intersections and complements are not supported. Sampling an `Hir`
+/// containing any of them always fails.
+///
+/// # Example
+///
+/// ```
+/// use regex_syntax::{hir::Sampler, parse};
+///
+/// let hir = parse(r"\b[a-z]+@[a-z]+\.com\b")?;
+/// let mut sampler = Sampler::new();
+/// for _ in 0..10 {
+///     let sample = sampler.sample_string(&hir).unwrap();
+///     assert!(sample.ends_with(".com"));
+///     assert!(sample.contains('@'));
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sampler {
+    config: SamplerBuilder,
+    rng: Rng,
+}
+
+impl Default for Sampler {
+    fn default() -> Sampler {
+        Sampler::new()
+    }
+}
+
+impl Sampler {
+    /// Create a new sampler with a default configuration.
+    ///
+    /// To configure a sampler, use a [`SamplerBuilder`].
+    pub fn new() -> Sampler {
+        SamplerBuilder::new().build()
+    }
+
+    /// Returns a random string matched by the given `Hir`.
+    ///
+    /// This returns `None` if no sample could be produced within the
+    /// configured number of attempts, or if the `Hir` contains features
+    /// that the sampler doesn't support.
+    ///
+    /// The sample returned is not necessarily valid UTF-8 when the `Hir`
+    /// can match invalid UTF-8.
+    pub fn sample(&mut self, hir: &Hir) -> Option<Vec<u8>> {
+        for _ in 0..self.config.max_attempts {
+            let mut state = State { out: vec![], pending: vec![] };
+            match self.walk(hir, &mut state) {
+                Ok(()) => {}
+                Err(Failure::Retry) => continue,
+                Err(Failure::Unsupported) => return None,
+            }
+            match state.check(self.config.line_terminator, None) {
+                Ok(true) => return Some(state.out),
+                Ok(false) => continue,
+                Err(Failure::Retry) => continue,
+                Err(Failure::Unsupported) => return None,
+            }
+        }
+        None
+    }
+
+    /// Returns a random string matched by the given `Hir` if it is valid
+    /// UTF-8.
+    ///
+    /// This is like [`Sampler::sample`], except it returns `None` if the
+    /// sample isn't valid UTF-8. Samples are always valid UTF-8 for an `Hir`
+    /// that can only match valid UTF-8, as is the case for any `Hir` built by
+    /// a translator with UTF-8 mode enabled (the default).
+    pub fn sample_string(&mut self, hir: &Hir) -> Option<String> {
+        self.sample(hir).and_then(|bytes| String::from_utf8(bytes).ok())
+    }
+
+    /// Append a random string matched by `hir` to the given state.
+    fn walk(&mut self, hir: &Hir, state: &mut State) -> Result<(), Failure> {
+        match *hir.kind() {
+            HirKind::Empty => Ok(()),
+            HirKind::Literal(ref lit) => {
+                state.emit(self.config.line_terminator, &lit.0)
+            }
+            HirKind::Class(Class::Unicode(ref cls)) => {
+                let ranges: Vec<(u32, u32)> = cls
+                    .iter()
+                    .map(|r| (u32::from(r.start()), u32::from(r.end())))
+                    .collect();
+                let ranges = self.choose_subset(ranges);
+                let mut buf = [0; 4];
+                for _ in 0..CLASS_PICKS {
+                    let cp = self.pick(&ranges)?;
+                    let ch = match char::from_u32(cp) {
+                        None => continue,
+                        Some(ch) => ch,
+                    };
+                    let bytes = ch.encode_utf8(&mut buf).as_bytes();
+                    if state.check(self.config.line_terminator, Some(bytes))? {
+                        return state.emit(self.config.line_terminator, bytes);
+                    }
+                }
+                Err(Failure::Retry)
+            }
+            HirKind::Class(Class::Bytes(ref cls)) => {
+                let ranges: Vec<(u32, u32)> = cls
+                    .iter()
+                    .map(|r| (u32::from(r.start()), u32::from(r.end())))
+                    .collect();
+                let ranges = self.choose_subset(ranges);
+                for _ in 0..CLASS_PICKS {
+                    let byte = [u8::try_from(self.pick(&ranges)?).unwrap()];
+                    if state.check(self.config.line_terminator, Some(&byte))? {
+                        return state.emit(self.config.line_terminator, &byte);
+                    }
+                }
+                Err(Failure::Retry)
+            }
+            HirKind::Look(look) => {
+                state.pending.push(look);
+                Ok(())
+            }
+            HirKind::LookAround(_)
+            | HirKind::Intersection(_)
+            | HirKind::Complement(_)
+            | HirKind::Backreference(_)
+            | HirKind::Atomic(_) => Err(Failure::Unsupported),
+            HirKind::Repetition(ref rep) => {
+                let extra = match rep.max {
+                    None => self.config.max_repeat,
+                    Some(max) => {
+                        core::cmp::min(max - rep.min, self.config.max_repeat)
+                    }
+                };
+                let n = rep.min + self.rng.below(u64::from(extra) + 1) as u32;
+                for _ in 0..n {
+                    self.walk(&rep.sub, state)?;
+                }
+                Ok(())
+            }
+            HirKind::Capture(ref cap) => self.walk(&cap.sub, state),
+            HirKind::Concat(ref subs) => {
+                for sub in subs.iter() {
+                    self.walk(sub, state)?;
+                }
+                Ok(())
+            }
+            HirKind::Alternation(ref subs) => {
+                let i = self.rng.below(subs.len() as u64) as usize;
+                self.walk(&subs[i], state)
+            }
+        }
+    }
+
+    /// Returns either the given ranges or their ASCII subset, according to
+    /// the configured ASCII percentage.
+    fn choose_subset(&mut self, ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+        let has_ascii =
+            ranges.first().map_or(false, |&(start, _)| start <= 0x7F);
+        if !has_ascii
+            || self.rng.below(100) >= u64::from(self.config.ascii_percent)
+        {
+            return ranges;
+        }
+        ranges
+            .into_iter()
+            .filter(|&(start, _)| start <= 0x7F)
+            .map(|(start, end)| (start, core::cmp::min(end, 0x7F)))
+            .collect()
+    }
+
+    /// Pick a random value from the given ranges, according to the
+    /// configured class weighting. This fails if there are no ranges.
+    fn pick(&mut self, ranges: &[(u32, u32)]) -> Result<u32, Failure> {
+        if ranges.is_empty() {
+            return Err(Failure::Retry);
+        }
+        match self.config.class_weight {
+            ClassWeight::Range => {
+                let i = self.rng.below(ranges.len() as u64) as usize;
+                let (start, end) = ranges[i];
+                let offset = self.rng.below(u64::from(end - start) + 1);
+                Ok(start + offset as u32)
+            }
+            ClassWeight::Codepoint => {
+                let total: u64 = ranges
+                    .iter()
+                    .map(|&(start, end)| u64::from(end - start) + 1)
+                    .sum();
+                let mut offset = self.rng.below(total);
+                for &(start, end) in ranges.iter() {
+                    let len = u64::from(end - start) + 1;
+                    if offset < len {
+                        return Ok(start + offset as u32);
+                    }
+                    offset -= len;
+                }
+                unreachable!("offset is always less than the total")
+            }
+        }
+    }
+}
+
+/// The reason a sample couldn't be completed.
+#[derive(Clone, Copy, Debug)]
+enum Failure {
+    /// A random choice conflicted with a look-around assertion, so another
+    /// attempt should be made.
+    Retry,
+    /// The `Hir` contains something that can't be sampled.
+    Unsupported,
+}
+
+/// The state of a sample being built.
+#[derive(Debug)]
+struct State {
+    /// The sample so far.
+    out: Vec<u8>,
+    /// The look-around assertions that apply at the end of the sample so
+    /// far. They are checked once the next character is known.
+    pending: Vec<Look>,
+}
+
+impl State {
+    /// Append the given bytes to the sample, failing if they don't satisfy
+    /// every pending assertion.
+    fn emit(&mut self, lineterm: u8, bytes: &[u8]) -> Result<(), Failure> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        if !self.check(lineterm, Some(bytes))? {
+            return Err(Failure::Retry);
+        }
+        self.pending.clear();
+        self.out.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Returns true if every pending assertion holds when `next` follows
+    /// the sample so far. When `next` is `None`, the sample is assumed to
+    /// end.
+    fn check(
+        &self,
+        lineterm: u8,
+        next: Option<&[u8]>,
+    ) -> Result<bool, Failure> {
+        for &look in self.pending.iter() {
+            if !is_look_match(look, lineterm, &self.out, next)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Returns true if the given assertion holds between `before` and `next`.
+/// When `next` is `None`, the assertion is checked at the end of the
+/// haystack.
+///
+/// This fails if the assertion requires Unicode data that isn't available.
+fn is_look_match(
+    look: Look,
+    lineterm: u8,
+    before: &[u8],
+    next: Option<&[u8]>,
+) -> Result<bool, Failure> {
+    let prev_byte = before.last().copied();
+    let next_byte = next.and_then(|bytes| bytes.first().copied());
+    let word_ascii = |b: Option<u8>| b.map_or(false, crate::is_word_byte);
+    let word_unicode = |ch: Option<char>| match ch {
+        None => Ok(false),
+        Some(ch) => {
+            crate::try_is_word_character(ch).map_err(|_| Failure::Unsupported)
+        }
+    };
+    let (wb, wa) = match look {
+        Look::WordAscii
+        | Look::WordAsciiNegate
+        | Look::WordStartAscii
+        | Look::WordEndAscii
+        | Look::WordStartHalfAscii
+        | Look::WordEndHalfAscii => {
+            (word_ascii(prev_byte), word_ascii(next_byte))
+        }
+        Look::WordUnicode
+        | Look::WordUnicodeNegate
+        | Look::WordStartUnicode
+        | Look::WordEndUnicode
+        | Look::WordStartHalfUnicode
+        | Look::WordEndHalfUnicode => (
+            word_unicode(decode_last(before))?,
+            word_unicode(next.and_then(decode_first))?,
+        ),
+        // Segmentation boundaries depend on an unbounded amount of context
+        // on either side, which isn't known while a sample is being built.
+        Look::WordSegment | Look::SentenceSegment => {
+            return Err(Failure::Unsupported)
+        }
+        _ => (false, false),
+    };
+    Ok(match look {
+        Look::Start => before.is_empty(),
+        Look::End => next.is_none(),
+        Look::StartLF => prev_byte.map_or(true, |b| b == lineterm),
+        Look::EndLF => next_byte.map_or(true, |b| b == lineterm),
+        Look::StartCRLF => match prev_byte {
+            None | Some(b'\n') => true,
+            Some(b'\r') => next_byte != Some(b'\n'),
+            Some(_) => false,
+        },
+        Look::EndCRLF => match next_byte {
+            None | Some(b'\r') => true,
+            Some(b'\n') => prev_byte != Some(b'\r'),
+            Some(_) => false,
+        },
+        Look::WordAscii | Look::WordUnicode => wb != wa,
+        Look::WordAsciiNegate | Look::WordUnicodeNegate => wb == wa,
+        Look::WordStartAscii | Look::WordStartUnicode => !wb && wa,
+        Look::WordEndAscii | Look::WordEndUnicode => wb && !wa,
+        Look::WordStartHalfAscii | Look::WordStartHalfUnicode => !wb,
+        Look::WordEndHalfAscii | Look::WordEndHalfUnicode => !wa,
+        Look::WordSegment | Look::SentenceSegment => unreachable!(),
+    })
+}
+
+/// Decodes the first codepoint in the given bytes, if they begin with a
+/// valid UTF-8 encoding of one.
+fn decode_first(bytes: &[u8]) -> Option<char> {
+    (1..=core::cmp::min(4, bytes.len()))
+        .find_map(|len| core::str::from_utf8(&bytes[..len]).ok())
+        .and_then(|s| s.chars().next())
+}
+
+/// Decodes the last codepoint in the given bytes, if they end with a valid
+/// UTF-8 encoding of one.
+fn decode_last(bytes: &[u8]) -> Option<char> {
+    (1..=core::cmp::min(4, bytes.len()))
+        .find_map(|len| core::str::from_utf8(&bytes[bytes.len() - len..]).ok())
+        .and_then(|s| s.chars().next())
+}
+
+/// A simple xorshift64* pseudo-random number generator.
+#[derive(Clone, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        // The state must never be zero.
+        Rng(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Returns a number in the range `0..n`. `n` must be non-zero.
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, ParserBuilder};
+
+    use super::*;
+
+    fn samples(hir: &Hir, count: usize) -> Vec<Vec<u8>> {
+        let mut sampler = Sampler::new();
+        (0..count).map(|_| sampler.sample(hir).unwrap()).collect()
+    }
+
+    #[test]
+    fn repetition_cap() {
+        let hir = parse(r"a{2,}").unwrap();
+        for s in samples(&hir, 100) {
+            assert!((2..=10).contains(&s.len()), "{:?}", s);
+        }
+        let mut sampler = SamplerBuilder::new().max_repeat(0).build();
+        assert_eq!(Some(b"aa".to_vec()), sampler.sample(&hir));
+    }
+
+    #[test]
+    fn ascii_percent() {
+        let hir = parse(r"\w").unwrap();
+        let mut sampler = SamplerBuilder::new().ascii_percent(100).build();
+        for _ in 0..100 {
+            let s = sampler.sample(&hir).unwrap();
+            assert!(s.len() == 1 && crate::is_word_byte(s[0]), "{:?}", s);
+        }
+        let mut sampler = SamplerBuilder::new().ascii_percent(0).build();
+        assert!((0..100).any(|_| sampler.sample(&hir).unwrap().len() > 1));
+    }
+
+    #[test]
+    fn look_assertions() {
+        // The '\b' forces the class to choose a non-word character.
+        let hir = parse(r"a\b[a-z ]").unwrap();
+        for s in samples(&hir, 100) {
+            assert_eq!(b"a ", &*s);
+        }
+        let hir = parse(r"(?m)a$[\n ]b").unwrap();
+        for s in samples(&hir, 100) {
+            assert_eq!(b"a\nb", &*s);
+        }
+        let hir = parse(r"[a-z]\B[!a-z]").unwrap();
+        for s in samples(&hir, 100) {
+            assert!(s.iter().all(|b| b.is_ascii_lowercase()), "{:?}", s);
+        }
+        let parser =
+            || ParserBuilder::new().crlf(true).multi_line(true).build();
+        let hir = parser().parse(r"\r^x").unwrap();
+        assert_eq!(Some(b"\rx".to_vec()), Sampler::new().sample(&hir));
+        let hir = parser().parse(r"\r^\n").unwrap();
+        assert_eq!(None, Sampler::new().sample(&hir));
+    }
+
+    #[test]
+    fn impossible() {
+        let mut sampler = Sampler::new();
+        assert_eq!(None, sampler.sample(&parse(r"a\bb").unwrap()));
+        assert_eq!(None, sampler.sample(&parse(r"[a&&b]").unwrap()));
+        assert_eq!(None, sampler.sample(&parse(r"a$b").unwrap()));
+    }
+
+    #[test]
+    fn seeded() {
+        let hir = parse(r"\w{5}").unwrap();
+        let mut s1 = SamplerBuilder::new().seed(42).build();
+        let mut s2 = SamplerBuilder::new().seed(42).build();
+        for _ in 0..10 {
+            assert_eq!(s1.sample(&hir), s2.sample(&hir));
+        }
+    }
+}
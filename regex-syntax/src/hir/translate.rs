@@ -4,7 +4,12 @@ Defines a translator that converts an `Ast` to an `Hir`.
 
 use core::cell::{Cell, RefCell};
 
-use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use crate::{
     ast::{self, Ast, Span, Visitor},
@@ -20,6 +25,8 @@ type Result<T> = core::result::Result<T, Error>;
 pub struct TranslatorBuilder {
     utf8: bool,
     line_terminator: u8,
+    full_case_folding: bool,
+    turkic_case_folding: bool,
     flags: Flags,
 }
 
@@ -35,6 +42,8 @@ impl TranslatorBuilder {
         TranslatorBuilder {
             utf8: true,
             line_terminator: b'\n',
+            full_case_folding: false,
+            turkic_case_folding: false,
             flags: Flags::default(),
         }
     }
@@ -43,9 +52,12 @@ impl TranslatorBuilder {
     pub fn build(&self) -> Translator {
         Translator {
             stack: RefCell::new(vec![]),
+            captures: RefCell::new(vec![]),
             flags: Cell::new(self.flags),
             utf8: self.utf8,
             line_terminator: self.line_terminator,
+            full_case_folding: self.full_case_folding,
+            turkic_case_folding: self.turkic_case_folding,
         }
     }
 
@@ -94,6 +106,49 @@ impl TranslatorBuilder {
         self
     }
 
+    /// Enable or disable full case folding in case insensitive mode.
+    ///
+    /// By default, case insensitive matching uses simple case folding, which
+    /// only relates single codepoints to one another. When this is enabled,
+    /// a literal or a non-negated class that contains a codepoint whose full
+    /// case folding is a sequence of codepoints will also match anything
+    /// that case folds to that sequence. For example, `(?i)ß` matches `ss`
+    /// and `SS`, and `(?i)ﬁ` matches `fi`.
+    ///
+    /// Conversely, a sequence of case insensitive characters in the pattern
+    /// that spells out such a case folding also matches the codepoint, so
+    /// `(?i)ss` matches `ß`. These sequences are found greedily from left to
+    /// right, which means that `(?i)sss` matches `ßs` but not `sß`.
+    ///
+    /// Negated classes only ever match a single codepoint, and every
+    /// codepoint with the same full case folding as another is already
+    /// related to it by simple case folding. So negated classes are
+    /// unaffected by this setting.
+    ///
+    /// This only has an effect when both Unicode mode and case insensitive
+    /// mode are enabled.
+    pub fn full_case_folding(&mut self, yes: bool) -> &mut TranslatorBuilder {
+        self.full_case_folding = yes;
+        self
+    }
+
+    /// Enable or disable Turkic tailoring of case folding.
+    ///
+    /// When enabled, case insensitive matching follows the conventions of
+    /// Turkish and Azerbaijani for dotted and dotless `i`: `I` is related to
+    /// `ı` (instead of `i`) and `İ` is related to `i` (instead of `I`). This
+    /// corresponds to the `T` entries in `CaseFolding.txt`.
+    ///
+    /// This only has an effect when both Unicode mode and case insensitive
+    /// mode are enabled. It may be combined with full case folding.
+    pub fn turkic_case_folding(
+        &mut self,
+        yes: bool,
+    ) -> &mut TranslatorBuilder {
+        self.turkic_case_folding = yes;
+        self
+    }
+
     /// Enable or disable the case insensitive flag (`i`) by default.
     pub fn case_insensitive(&mut self, yes: bool) -> &mut TranslatorBuilder {
         self.flags.case_insensitive = if yes { Some(true) } else { None };
@@ -122,6 +177,12 @@ impl TranslatorBuilder {
         self
     }
 
+    /// Enable or disable the grapheme mode flag (`g`) by default.
+    pub fn grapheme(&mut self, yes: bool) -> &mut TranslatorBuilder {
+        self.flags.grapheme = if yes { Some(true) } else { None };
+        self
+    }
+
     /// Enable or disable the "swap greed" flag (`U`) by default.
     pub fn swap_greed(&mut self, yes: bool) -> &mut TranslatorBuilder {
         self.flags.swap_greed = if yes { Some(true) } else { None };
@@ -147,12 +208,20 @@ impl TranslatorBuilder {
 pub struct Translator {
     /// Our call stack, but on the heap.
     stack: RefCell<Vec<HirFrame>>,
+    /// The names of the capturing groups opened so far, in order. The
+    /// group with index `i` is at position `i - 1`. This is used to resolve
+    /// backreferences.
+    captures: RefCell<Vec<Option<String>>>,
     /// The current flag settings.
     flags: Cell<Flags>,
     /// Whether we're allowed to produce HIR that can match arbitrary bytes.
     utf8: bool,
     /// The line terminator to use for `.`.
     line_terminator: u8,
+    /// Whether to use full case folding in case insensitive mode.
+    full_case_folding: bool,
+    /// Whether to apply Turkic tailoring to case folding.
+    turkic_case_folding: bool,
 }
 
 impl Translator {
@@ -171,6 +240,7 @@ impl Translator {
     /// provided. The translator does not use the pattern string during any
     /// correct translation, but is used for error reporting.
     pub fn translate(&mut self, pattern: &str, ast: &Ast) -> Result<Hir> {
+        self.captures.borrow_mut().clear();
         ast::visit(ast, TranslatorI::new(self, pattern))
     }
 }
@@ -229,6 +299,10 @@ enum HirFrame {
         /// The "active" flags correspond to whatever flags are set in the
         /// Translator.
         old_flags: Flags,
+        /// Whether this group is a look-around assertion. This is used to
+        /// reject capturing groups and other look-around assertions inside
+        /// of it.
+        look_around: bool,
     },
     /// This is pushed whenever a concatenation is observed. After visiting
     /// every sub-expression in the concatenation, the translator's stack is
@@ -246,6 +320,18 @@ enum HirFrame {
     /// It is popped after each expression in a branch until an 'Alternation'
     /// frame is observed when doing a post visit on an alternation.
     AlternationBranch,
+    /// This is pushed whenever an intersection is observed. After visiting
+    /// every sub-expression in the intersection, the translator's stack is
+    /// popped until it sees an Intersection frame.
+    Intersection,
+    /// This is pushed immediately before each sub-expression in an
+    /// intersection. Like 'AlternationBranch', it prevents literal
+    /// flattening from reaching across the operands of an intersection.
+    IntersectionBranch,
+    /// This is pushed whenever a complement is observed. After visiting its
+    /// sub-expression, the translator's stack is expected to have this
+    /// sentinel at the top.
+    Complement,
 }
 
 impl HirFrame {
@@ -303,7 +389,7 @@ impl HirFrame {
     /// group was entered).
     fn unwrap_group(self) -> Flags {
         match self {
-            HirFrame::Group { old_flags } => old_flags,
+            HirFrame::Group { old_flags, .. } => old_flags,
             _ => {
                 panic!("tried to unwrap group from HirFrame, got: {:?}", self)
             }
@@ -323,6 +409,35 @@ impl HirFrame {
             }
         }
     }
+
+    /// Assert that the current stack frame is an intersection operand
+    /// sentinel. If it isn't, then panic.
+    fn unwrap_intersection_branch(self) {
+        match self {
+            HirFrame::IntersectionBranch => {}
+            _ => {
+                panic!(
+                    "tried to unwrap intersection branch from HirFrame, \
+                     got: {:?}",
+                    self
+                )
+            }
+        }
+    }
+
+    /// Assert that the current stack frame is a complement sentinel. If it
+    /// isn't, then panic.
+    fn unwrap_complement(self) {
+        match self {
+            HirFrame::Complement => {}
+            _ => {
+                panic!(
+                    "tried to unwrap complement from HirFrame, got: {:?}",
+                    self
+                )
+            }
+        }
+    }
 }
 
 impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
@@ -346,13 +461,52 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                     self.push(HirFrame::ClassBytes(cls));
                 }
             }
-            Ast::Repetition(_) => self.push(HirFrame::Repetition),
+            Ast::Repetition(ref x) => {
+                if x.possessive && (self.in_look_around() || self.in_boolean())
+                {
+                    return Err(
+                        self.error(x.span, ErrorKind::AtomicUnsupported)
+                    );
+                }
+                self.push(HirFrame::Repetition);
+            }
             Ast::Group(ref x) => {
+                if x.kind == ast::GroupKind::Atomic
+                    && (self.in_look_around() || self.in_boolean())
+                {
+                    return Err(
+                        self.error(x.span, ErrorKind::AtomicUnsupported)
+                    );
+                }
+                let look_around = x.look_around().is_some();
+                if self.in_look_around() {
+                    if look_around {
+                        return Err(
+                            self.error(x.span, ErrorKind::LookAroundNested)
+                        );
+                    } else if x.is_capturing() {
+                        return Err(
+                            self.error(x.span, ErrorKind::LookAroundCapture)
+                        );
+                    }
+                }
+                if x.is_capturing() && self.in_boolean() {
+                    return Err(self.error(x.span, ErrorKind::BooleanCapture));
+                }
+                if x.is_capturing() {
+                    let name = match x.kind {
+                        ast::GroupKind::CaptureName { ref name, .. } => {
+                            Some(name.name.clone())
+                        }
+                        _ => None,
+                    };
+                    self.trans().captures.borrow_mut().push(name);
+                }
                 let old_flags = x
                     .flags()
                     .map(|ast| self.set_flags(ast))
                     .unwrap_or_else(|| self.flags());
-                self.push(HirFrame::Group { old_flags });
+                self.push(HirFrame::Group { old_flags, look_around });
             }
             Ast::Concat(_) => {
                 self.push(HirFrame::Concat);
@@ -363,6 +517,13 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                     self.push(HirFrame::AlternationBranch);
                 }
             }
+            Ast::Intersection(ref x) => {
+                self.push(HirFrame::Intersection);
+                if !x.asts.is_empty() {
+                    self.push(HirFrame::IntersectionBranch);
+                }
+            }
+            Ast::Complement(_) => self.push(HirFrame::Complement),
             _ => {}
         }
         Ok(())
@@ -411,8 +572,13 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                 }
             }
             Ast::ClassUnicode(ref x) => {
-                let cls = hir::Class::Unicode(self.hir_unicode_class(x)?);
-                self.push(HirFrame::Expr(Hir::class(cls)));
+                let cls = self.hir_unicode_class(x)?;
+                let expr = if x.negated {
+                    Hir::class(hir::Class::Unicode(cls))
+                } else {
+                    self.hir_unicode_class_full(x.span, cls)?
+                };
+                self.push(HirFrame::Expr(expr));
             }
             Ast::ClassBracketed(ref ast) => {
                 if self.flags().unicode() {
@@ -422,7 +588,15 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                         ast.negated,
                         &mut cls,
                     )?;
-                    let expr = Hir::class(hir::Class::Unicode(cls));
+                    // In grapheme mode, a negated class matches an entire
+                    // grapheme cluster instead of a single codepoint.
+                    let expr = if !ast.negated {
+                        self.hir_unicode_class_full(ast.span, cls)?
+                    } else if self.flags().grapheme() {
+                        self.hir_grapheme_cluster(ast.span, &cls)?
+                    } else {
+                        Hir::class(hir::Class::Unicode(cls))
+                    };
                     self.push(HirFrame::Expr(expr));
                 } else {
                     let mut cls = self.pop().unwrap().unwrap_class_bytes();
@@ -444,7 +618,14 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                 let expr = self.pop().unwrap().unwrap_expr();
                 let old_flags = self.pop().unwrap().unwrap_group();
                 self.trans().flags.set(old_flags);
-                self.push(HirFrame::Expr(self.hir_capture(x, expr)));
+                let expr = match x.kind {
+                    ast::GroupKind::LookAround(kind) => {
+                        self.hir_look_around(x, kind, expr)?
+                    }
+                    ast::GroupKind::Atomic => Hir::atomic(expr),
+                    _ => self.hir_capture(x, expr),
+                };
+                self.push(HirFrame::Expr(expr));
             }
             Ast::Concat(_) => {
                 let mut exprs = vec![];
@@ -454,6 +635,7 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                     }
                 }
                 exprs.reverse();
+                let exprs = self.hir_concat_full_case_folding(ast, exprs)?;
                 self.push(HirFrame::Expr(Hir::concat(exprs)));
             }
             Ast::Alternation(_) => {
@@ -465,6 +647,40 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
                 exprs.reverse();
                 self.push(HirFrame::Expr(Hir::alternation(exprs)));
             }
+            Ast::Intersection(_) => {
+                let mut exprs = vec![];
+                while let Some(expr) = self.pop_intersection_expr() {
+                    self.pop().unwrap().unwrap_intersection_branch();
+                    exprs.push(expr);
+                }
+                exprs.reverse();
+                self.push(HirFrame::Expr(Hir::intersection(exprs)));
+            }
+            Ast::Complement(ref x) => {
+                let expr = self.pop().unwrap().unwrap_expr();
+                self.pop().unwrap().unwrap_complement();
+                self.push(HirFrame::Expr(self.hir_complement(x, expr)?));
+            }
+            Ast::Backreference(ref x) => {
+                self.push(HirFrame::Expr(self.hir_backreference(x)?));
+            }
+            Ast::GraphemeCluster(ref span) => {
+                if !self.flags().unicode() {
+                    return Err(
+                        self.error(**span, ErrorKind::UnicodeNotAllowed)
+                    );
+                }
+                let any =
+                    hir::ClassUnicode::new([hir::ClassUnicodeRange::new(
+                        '\0',
+                        '\u{10FFFF}',
+                    )]);
+                let expr = self.hir_grapheme_cluster(**span, &any)?;
+                self.push(HirFrame::Expr(expr));
+            }
+            Ast::NewlineSequence(_) => {
+                self.push(HirFrame::Expr(self.hir_newline_sequence()));
+            }
         }
         Ok(())
     }
@@ -474,6 +690,11 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
         Ok(())
     }
 
+    fn visit_intersection_in(&mut self) -> Result<()> {
+        self.push(HirFrame::IntersectionBranch);
+        Ok(())
+    }
+
     fn visit_class_set_item_pre(
         &mut self,
         ast: &ast::ClassSetItem,
@@ -503,8 +724,9 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
             ast::ClassSetItem::Empty(_) => {}
             ast::ClassSetItem::Literal(ref x) => {
                 if self.flags().unicode() {
+                    let c = self.ast_literal_char(x)?;
                     let mut cls = self.pop().unwrap().unwrap_class_unicode();
-                    cls.push(hir::ClassUnicodeRange::new(x.c, x.c));
+                    cls.push(hir::ClassUnicodeRange::new(c, c));
                     self.push(HirFrame::ClassUnicode(cls));
                 } else {
                     let mut cls = self.pop().unwrap().unwrap_class_bytes();
@@ -515,13 +737,25 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
             }
             ast::ClassSetItem::Range(ref x) => {
                 if self.flags().unicode() {
+                    let start = self.ast_literal_char(&x.start)?;
+                    let end = self.ast_literal_char(&x.end)?;
+                    if start > end {
+                        return Err(
+                            self.error(x.span, ErrorKind::ClassRangeInvalid)
+                        );
+                    }
                     let mut cls = self.pop().unwrap().unwrap_class_unicode();
-                    cls.push(hir::ClassUnicodeRange::new(x.start.c, x.end.c));
+                    cls.push(hir::ClassUnicodeRange::new(start, end));
                     self.push(HirFrame::ClassUnicode(cls));
                 } else {
-                    let mut cls = self.pop().unwrap().unwrap_class_bytes();
                     let start = self.class_literal_byte(&x.start)?;
                     let end = self.class_literal_byte(&x.end)?;
+                    if start > end {
+                        return Err(
+                            self.error(x.span, ErrorKind::ClassRangeInvalid)
+                        );
+                    }
+                    let mut cls = self.pop().unwrap().unwrap_class_bytes();
                     cls.push(hir::ClassBytesRange::new(start, end));
                     self.push(HirFrame::ClassBytes(cls));
                 }
@@ -628,18 +862,8 @@ impl<'t, 'p> Visitor for TranslatorI<'t, 'p> {
             let mut lhs = self.pop().unwrap().unwrap_class_unicode();
             let mut cls = self.pop().unwrap().unwrap_class_unicode();
             if self.flags().case_insensitive() {
-                rhs.try_case_fold_simple().map_err(|_| {
-                    self.error(
-                        op.rhs.span().clone(),
-                        ErrorKind::UnicodeCaseUnavailable,
-                    )
-                })?;
-                lhs.try_case_fold_simple().map_err(|_| {
-                    self.error(
-                        op.lhs.span().clone(),
-                        ErrorKind::UnicodeCaseUnavailable,
-                    )
-                })?;
+                self.unicode_case_fold(op.rhs.span(), &mut rhs)?;
+                self.unicode_case_fold(op.lhs.span(), &mut lhs)?;
             }
             match op.kind {
                 Intersection => lhs.intersect(&rhs),
@@ -759,6 +983,19 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             HirFrame::AlternationBranch => {
                 unreachable!("expected expr or concat, got alt branch marker")
             }
+            HirFrame::Intersection => {
+                unreachable!(
+                    "expected expr or concat, got intersection marker"
+                )
+            }
+            HirFrame::IntersectionBranch => {
+                unreachable!(
+                    "expected expr or concat, got intersection branch marker"
+                )
+            }
+            HirFrame::Complement => {
+                unreachable!("expected expr or concat, got complement")
+            }
         }
     }
 
@@ -790,9 +1027,55 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             HirFrame::AlternationBranch => {
                 unreachable!("expected expr or alt, got alt branch marker")
             }
+            HirFrame::Intersection => {
+                unreachable!("expected expr or alt, got intersection marker")
+            }
+            HirFrame::IntersectionBranch => {
+                unreachable!(
+                    "expected expr or alt, got intersection branch marker"
+                )
+            }
+            HirFrame::Complement => {
+                unreachable!("expected expr or alt, got complement")
+            }
+        }
+    }
+
+    /// Pop an HIR expression from the top of the stack for an intersection.
+    ///
+    /// This returns None if the stack is empty or when an intersection frame
+    /// is seen. Otherwise, it panics if it could not find an HIR expression.
+    fn pop_intersection_expr(&self) -> Option<Hir> {
+        let frame = self.pop()?;
+        match frame {
+            HirFrame::Intersection => None,
+            HirFrame::Expr(expr) => Some(expr),
+            HirFrame::Literal(lit) => Some(Hir::literal(lit)),
+            _ => {
+                unreachable!("expected expr or intersection, got {:?}", frame)
+            }
         }
     }
 
+    /// Returns true if and only if the translator is currently inside of a
+    /// look-around assertion.
+    fn in_look_around(&self) -> bool {
+        self.trans().stack.borrow().iter().any(|frame| {
+            matches!(*frame, HirFrame::Group { look_around: true, .. })
+        })
+    }
+
+    /// Returns true if and only if the translator is currently inside an
+    /// operand of an intersection or a complement.
+    fn in_boolean(&self) -> bool {
+        self.trans().stack.borrow().iter().any(|frame| {
+            matches!(
+                *frame,
+                HirFrame::IntersectionBranch | HirFrame::Complement
+            )
+        })
+    }
+
     /// Create a new error with the given span and error type.
     fn error(&self, span: Span, kind: ErrorKind) -> Error {
         Error { kind, pattern: self.pattern.to_string(), span }
@@ -827,10 +1110,10 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
         lit: &ast::Literal,
     ) -> Result<Either<char, u8>> {
         if self.flags().unicode() {
-            return Ok(Either::Left(lit.c));
+            return Ok(Either::Left(self.ast_literal_char(lit)?));
         }
         let byte = match lit.byte() {
-            None => return Ok(Either::Left(lit.c)),
+            None => return Ok(Either::Left(self.ast_literal_char(lit)?)),
             Some(byte) => byte,
         };
         if byte <= 0x7F {
@@ -842,6 +1125,28 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
         Ok(Either::Right(byte))
     }
 
+    /// Return the codepoint of an Ast literal.
+    ///
+    /// This is just the literal's `c` field, except for Unicode character
+    /// names (`\N{...}`), which the parser leaves unresolved. Those are
+    /// looked up here, which fails if the name doesn't exist or if the name
+    /// data is not available.
+    fn ast_literal_char(&self, lit: &ast::Literal) -> Result<char> {
+        let name = match lit.kind {
+            ast::LiteralKind::Named(ref name) => name,
+            _ => return Ok(lit.c),
+        };
+        match unicode::character_by_name(name) {
+            Ok(Some(c)) => Ok(c),
+            Ok(None) => {
+                Err(self.error(lit.span, ErrorKind::UnicodeNameNotFound))
+            }
+            Err(_) => {
+                Err(self.error(lit.span, ErrorKind::UnicodeNameUnavailable))
+            }
+        }
+    }
+
     fn case_fold_char(&self, span: Span, c: char) -> Result<Option<Hir>> {
         if !self.flags().case_insensitive() {
             return Ok(None);
@@ -853,17 +1158,24 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
                 .map_err(|_| {
                     self.error(span, ErrorKind::UnicodeCaseUnavailable)
                 })?;
-            if !map {
+            let trans = self.trans();
+            if !map && !trans.full_case_folding && !trans.turkic_case_folding {
                 return Ok(None);
             }
             let mut cls =
                 hir::ClassUnicode::new(vec![hir::ClassUnicodeRange::new(
                     c, c,
                 )]);
-            cls.try_case_fold_simple().map_err(|_| {
-                self.error(span, ErrorKind::UnicodeCaseUnavailable)
-            })?;
-            Ok(Some(Hir::class(hir::Class::Unicode(cls))))
+            self.unicode_case_fold(&span, &mut cls)?;
+            let expr = self.hir_unicode_class_full(span, cls)?;
+            if let HirKind::Class(hir::Class::Unicode(ref cls)) = *expr.kind()
+            {
+                let single = hir::ClassUnicodeRange::new(c, c);
+                if cls.ranges() == [single] {
+                    return Ok(None);
+                }
+            }
+            Ok(Some(expr))
         } else {
             if !c.is_ascii() {
                 return Ok(None);
@@ -917,9 +1229,98 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
                 }
             }
         };
+        if flags.unicode() && flags.grapheme() {
+            // In grapheme mode, '.' matches an entire grapheme cluster, so
+            // long as it doesn't start with a character that '.' wouldn't
+            // otherwise match.
+            let mut first =
+                hir::ClassUnicode::new([hir::ClassUnicodeRange::new(
+                    '\0',
+                    '\u{10FFFF}',
+                )]);
+            let except = match dot {
+                hir::Dot::AnyCharExcept(ch) => {
+                    hir::ClassUnicode::new([hir::ClassUnicodeRange::new(
+                        ch, ch,
+                    )])
+                }
+                hir::Dot::AnyCharExceptCRLF => hir::ClassUnicode::new([
+                    hir::ClassUnicodeRange::new('\n', '\n'),
+                    hir::ClassUnicodeRange::new('\r', '\r'),
+                ]),
+                _ => hir::ClassUnicode::empty(),
+            };
+            first.difference(&except);
+            return self.hir_grapheme_cluster(span, &first);
+        }
         Ok(Hir::dot(dot))
     }
 
+    /// Returns an expression that matches a single extended grapheme cluster
+    /// whose first codepoint is in the given class.
+    ///
+    /// A `\r\n` sequence is only matched as a single cluster when the class
+    /// contains both `\r` and `\n`. Otherwise, `\r` is matched on its own
+    /// (if the class contains it).
+    fn hir_grapheme_cluster(
+        &self,
+        span: Span,
+        first: &hir::ClassUnicode,
+    ) -> Result<Hir> {
+        let classes = unicode::grapheme_classes().map_err(|_| {
+            self.error(span, ErrorKind::UnicodeGraphemeUnavailable)
+        })?;
+        Ok(grapheme_cluster(&classes, first))
+    }
+
+    /// Returns an expression for the PCRE dialect's `\R`, i.e.,
+    /// `\r\n|\v`. Unlike in PCRE, the alternation isn't atomic.
+    fn hir_newline_sequence(&self) -> Hir {
+        let kind = ast::ClassPerlKind::VerticalSpace;
+        let class = if self.flags().unicode() {
+            hir::Class::Unicode(hir_pcre_space_class_unicode(&kind))
+        } else {
+            hir::Class::Bytes(hir_pcre_space_class_bytes(&kind))
+        };
+        Hir::alternation(vec![Hir::literal(&b"\r\n"[..]), Hir::class(class)])
+    }
+
+    fn hir_backreference(&self, backref: &ast::Backreference) -> Result<Hir> {
+        if self.in_look_around() || self.in_boolean() {
+            return Err(
+                self.error(backref.span, ErrorKind::BackreferenceUnsupported)
+            );
+        }
+        if self.flags().case_insensitive() {
+            return Err(self.error(
+                backref.span,
+                ErrorKind::BackreferenceCaseInsensitive,
+            ));
+        }
+        let captures = self.trans().captures.borrow();
+        let index = match backref.kind {
+            ast::BackreferenceKind::Index(index) => {
+                let len = u32::try_from(captures.len()).unwrap_or(u32::MAX);
+                if index == 0 || index > len {
+                    None
+                } else {
+                    Some(index)
+                }
+            }
+            ast::BackreferenceKind::Named(ref name) => captures
+                .iter()
+                .position(|n| n.as_deref() == Some(name.as_str()))
+                .map(|i| u32::try_from(i + 1).unwrap()),
+        };
+        match index {
+            Some(index) => Ok(Hir::backreference(index)),
+            None => {
+                Err(self
+                    .error(backref.span, ErrorKind::BackreferenceUndefined))
+            }
+        }
+    }
+
     fn hir_assertion(&self, asst: &ast::Assertion) -> Result<Hir> {
         let unicode = self.flags().unicode();
         let multi_line = self.flags().multi_line();
@@ -945,6 +1346,27 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             }),
             ast::AssertionKind::StartText => Hir::look(hir::Look::Start),
             ast::AssertionKind::EndText => Hir::look(hir::Look::End),
+            ast::AssertionKind::EndTextOptionalNewline => {
+                // `\Z` is implemented as `(?=\n?\z)`, so it can't appear
+                // inside of another look-around assertion.
+                if self.in_look_around() {
+                    return Err(
+                        self.error(asst.span, ErrorKind::LookAroundNested)
+                    );
+                }
+                let newline = Hir::repetition(hir::Repetition {
+                    min: 0,
+                    max: Some(1),
+                    greedy: true,
+                    sub: Box::new(Hir::literal(&b"\n"[..])),
+                });
+                let sub =
+                    Hir::concat(vec![newline, Hir::look(hir::Look::End)]);
+                Hir::look_around(hir::LookAround {
+                    kind: hir::LookAroundKind::PositiveLookAhead,
+                    sub: Box::new(sub),
+                })
+            }
             ast::AssertionKind::WordBoundary => Hir::look(if unicode {
                 hir::Look::WordUnicode
             } else {
@@ -983,6 +1405,18 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             } else {
                 hir::Look::WordEndHalfAscii
             }),
+            ast::AssertionKind::WordSegmentBoundary if unicode => {
+                Hir::look(hir::Look::WordSegment)
+            }
+            ast::AssertionKind::SentenceSegmentBoundary if unicode => {
+                Hir::look(hir::Look::SentenceSegment)
+            }
+            // There are no ASCII-only versions of these since they are
+            // defined entirely in terms of Unicode properties.
+            ast::AssertionKind::WordSegmentBoundary
+            | ast::AssertionKind::SentenceSegmentBoundary => {
+                return Err(self.error(asst.span, ErrorKind::UnicodeNotAllowed))
+            }
         })
     }
 
@@ -995,10 +1429,68 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             // The HIR doesn't need to use non-capturing groups, since the way
             // in which the data type is defined handles this automatically.
             ast::GroupKind::NonCapturing(_) => return expr,
+            ast::GroupKind::LookAround(_) | ast::GroupKind::Atomic => {
+                unreachable!(
+                    "look-around and atomic groups are handled separately"
+                )
+            }
         };
         Hir::capture(hir::Capture { index, name, sub: Box::new(expr) })
     }
 
+    fn hir_look_around(
+        &self,
+        group: &ast::Group,
+        kind: ast::LookAroundKind,
+        expr: Hir,
+    ) -> Result<Hir> {
+        let kind = match kind {
+            ast::LookAroundKind::PositiveLookAhead => {
+                hir::LookAroundKind::PositiveLookAhead
+            }
+            ast::LookAroundKind::NegativeLookAhead => {
+                hir::LookAroundKind::NegativeLookAhead
+            }
+            ast::LookAroundKind::PositiveLookBehind => {
+                hir::LookAroundKind::PositiveLookBehind
+            }
+            ast::LookAroundKind::NegativeLookBehind => {
+                hir::LookAroundKind::NegativeLookBehind
+            }
+        };
+        // Bounding the length of a look-behind permits regex engines to
+        // limit how far back before the start of a search they need to look
+        // when evaluating it.
+        if kind.is_look_behind() && expr.properties().maximum_len().is_none() {
+            return Err(self.error(group.span, ErrorKind::LookBehindUnbounded));
+        }
+        Ok(Hir::look_around(hir::LookAround { kind, sub: Box::new(expr) }))
+    }
+
+    fn hir_complement(
+        &self,
+        complement: &ast::Complement,
+        expr: Hir,
+    ) -> Result<Hir> {
+        // The complement of an expression includes every string it doesn't
+        // match, which includes strings that are not valid UTF-8. When
+        // Unicode mode is enabled, the universe of strings is instead
+        // restricted to valid UTF-8 by intersecting with '(?s:.)*'.
+        if self.flags().unicode() {
+            let any = Hir::repetition(hir::Repetition {
+                min: 0,
+                max: None,
+                greedy: true,
+                sub: Box::new(Hir::dot(hir::Dot::AnyChar)),
+            });
+            return Ok(Hir::intersection(vec![any, Hir::complement(expr)]));
+        }
+        if self.trans().utf8 {
+            return Err(self.error(complement.span, ErrorKind::InvalidUtf8));
+        }
+        Ok(Hir::complement(expr))
+    }
+
     fn hir_repetition(&self, rep: &ast::Repetition, expr: Hir) -> Hir {
         let (min, max) = match rep.op.kind {
             ast::RepetitionKind::ZeroOrOne => (0, Some(1)),
@@ -1015,6 +1507,16 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
                 n,
             )) => (m, Some(n)),
         };
+        // A possessive repetition is always greedy, even when greediness is
+        // swapped, and never gives back what it matched.
+        if rep.possessive {
+            return Hir::atomic(Hir::repetition(hir::Repetition {
+                min,
+                max,
+                greedy: true,
+                sub: Box::new(expr),
+            }));
+        }
         let greedy =
             if self.flags().swap_greed() { !rep.greedy } else { rep.greedy };
         Hir::repetition(hir::Repetition {
@@ -1093,6 +1595,9 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             Digit => unicode::perl_digit(),
             Space => unicode::perl_space(),
             Word => unicode::perl_word(),
+            HorizontalSpace | VerticalSpace => {
+                Ok(hir_pcre_space_class_unicode(&ast_class.kind))
+            }
         };
         let mut class =
             self.convert_unicode_class_error(&ast_class.span, result)?;
@@ -1115,6 +1620,9 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
             Digit => hir_ascii_class_bytes(&ast::ClassAsciiKind::Digit),
             Space => hir_ascii_class_bytes(&ast::ClassAsciiKind::Space),
             Word => hir_ascii_class_bytes(&ast::ClassAsciiKind::Word),
+            HorizontalSpace | VerticalSpace => {
+                hir_pcre_space_class_bytes(&ast_class.kind)
+            }
         };
         // We needn't apply case folding here because the Perl ASCII classes
         // are already closed (under ASCII case folding).
@@ -1166,9 +1674,7 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
         // the result would be the character class that matched any
         // Unicode scalar value.
         if self.flags().case_insensitive() {
-            class.try_case_fold_simple().map_err(|_| {
-                self.error(span.clone(), ErrorKind::UnicodeCaseUnavailable)
-            })?;
+            self.unicode_case_fold(span, class)?;
         }
         if negated {
             class.negate();
@@ -1176,6 +1682,277 @@ impl<'t, 'p> TranslatorI<'t, 'p> {
         Ok(())
     }
 
+    /// Applies simple case folding to the given class, with Turkic tailoring
+    /// when it's enabled.
+    fn unicode_case_fold(
+        &self,
+        span: &Span,
+        class: &mut hir::ClassUnicode,
+    ) -> Result<()> {
+        let err =
+            || self.error(span.clone(), ErrorKind::UnicodeCaseUnavailable);
+        if !self.trans().turkic_case_folding {
+            return class.try_case_fold_simple().map_err(|_| err());
+        }
+        // Dotted and dotless `i` are the only codepoints affected by Turkic
+        // tailoring. None of them are related to any other codepoint under
+        // simple case folding, so we can take them out of the class, fold
+        // what's left and then add back their tailored equivalence classes.
+        let mut dotted = hir::ClassUnicode::empty();
+        let mut turkic = hir::ClassUnicode::empty();
+        for &[a, b] in TURKIC_CASE_FOLDING {
+            dotted.push(hir::ClassUnicodeRange::new(a, a));
+            dotted.push(hir::ClassUnicodeRange::new(b, b));
+            if class_contains(class, a) || class_contains(class, b) {
+                turkic.push(hir::ClassUnicodeRange::new(a, a));
+                turkic.push(hir::ClassUnicodeRange::new(b, b));
+            }
+        }
+        class.difference(&dotted);
+        class.try_case_fold_simple().map_err(|_| err())?;
+        class.union(&turkic);
+        Ok(())
+    }
+
+    /// Returns an expression that matches the given case folded class.
+    ///
+    /// When full case folding is enabled in case insensitive Unicode mode,
+    /// the expression also matches every sequence of codepoints that is
+    /// equivalent, under full case folding, to a codepoint in the class.
+    /// Longer alternatives come first, so that leftmost-first semantics
+    /// prefer them.
+    fn hir_unicode_class_full(
+        &self,
+        span: Span,
+        class: hir::ClassUnicode,
+    ) -> Result<Hir> {
+        let flags = self.flags();
+        if !self.trans().full_case_folding
+            || !flags.case_insensitive()
+            || !flags.unicode()
+        {
+            return Ok(Hir::class(hir::Class::Unicode(class)));
+        }
+        let table = unicode::full_case_folding_table().map_err(|_| {
+            self.error(span, ErrorKind::UnicodeCaseUnavailable)
+        })?;
+        let mut folds: Vec<&[char]> = vec![];
+        for &(c, fold) in table {
+            if self.is_full_case_fold(c)
+                && class_contains(&class, c)
+                && !folds.contains(&fold)
+            {
+                folds.push(fold);
+            }
+        }
+        folds.sort_by_key(|fold| core::cmp::Reverse(fold.len()));
+        let mut alts = vec![];
+        for fold in folds {
+            // Codepoints whose full case folding is all of `fold` are
+            // already matched by the class itself.
+            let max = fold.len() - 1;
+            alts.push(self.hir_case_fold_sequence(&span, table, fold, max)?);
+        }
+        alts.push(Hir::class(hir::Class::Unicode(class)));
+        Ok(Hir::alternation(alts))
+    }
+
+    /// Returns an expression that matches every sequence of codepoints whose
+    /// full case folding is `fold`, except for those that start with a single
+    /// codepoint folding to a prefix of `fold` longer than `max`.
+    ///
+    /// Since `fold` is itself a full case folding from the table, it has at
+    /// most three codepoints, so the size of the expression is bounded.
+    fn hir_case_fold_sequence(
+        &self,
+        span: &Span,
+        table: &[(char, &'static [char])],
+        fold: &[char],
+        max: usize,
+    ) -> Result<Hir> {
+        if fold.is_empty() {
+            return Ok(Hir::empty());
+        }
+        let mut alts = vec![];
+        // A prefix of `fold` might itself be the full case folding of a
+        // single codepoint. For example, `ﬀi` and `fﬁ` both fold to `ffi`.
+        for len in (2..=max).rev() {
+            let mut cls = hir::ClassUnicode::empty();
+            for &(c, f) in table {
+                if f == &fold[..len] && self.is_full_case_fold(c) {
+                    cls.push(hir::ClassUnicodeRange::new(c, c));
+                }
+            }
+            if cls.ranges().is_empty() {
+                continue;
+            }
+            let rest = &fold[len..];
+            let rest =
+                self.hir_case_fold_sequence(span, table, rest, rest.len())?;
+            alts.push(Hir::concat(vec![
+                Hir::class(hir::Class::Unicode(cls)),
+                rest,
+            ]));
+        }
+        let mut first =
+            hir::ClassUnicode::new(vec![hir::ClassUnicodeRange::new(
+                fold[0], fold[0],
+            )]);
+        self.unicode_case_fold(span, &mut first)?;
+        let rest = &fold[1..];
+        let rest =
+            self.hir_case_fold_sequence(span, table, rest, rest.len())?;
+        alts.push(Hir::concat(vec![
+            Hir::class(hir::Class::Unicode(first)),
+            rest,
+        ]));
+        Ok(Hir::alternation(alts))
+    }
+
+    /// Returns the given concatenation, where every run of expressions that
+    /// spells out the full case folding of a codepoint, like `ss` for `ß`,
+    /// is replaced by an alternation that also matches that codepoint.
+    ///
+    /// This is the converse of what `hir_unicode_class_full` does, and it's
+    /// only done when full case folding is enabled. Each expression in a run
+    /// must be a character translated case insensitively, unless that
+    /// character has no other case anyway. Runs are found greedily from left
+    /// to right and don't overlap, so for example, `(?i)sss` matches `ßs`
+    /// but not `sß`.
+    fn hir_concat_full_case_folding(
+        &self,
+        ast: &Ast,
+        exprs: Vec<Hir>,
+    ) -> Result<Vec<Hir>> {
+        if !self.trans().full_case_folding || exprs.len() < 2 {
+            return Ok(exprs);
+        }
+        let span = ast.span();
+        let table = unicode::full_case_folding_table().map_err(|_| {
+            self.error(*span, ErrorKind::UnicodeCaseUnavailable)
+        })?;
+        // Adjacent characters without any other case end up in the same
+        // literal, but each of them could be part of a different run.
+        let mut items = vec![];
+        for expr in exprs {
+            let chars = match *expr.kind() {
+                HirKind::Literal(hir::Literal(ref bytes)) => {
+                    core::str::from_utf8(bytes).ok()
+                }
+                _ => None,
+            };
+            match chars {
+                None => items.push(expr),
+                Some(chars) => items.extend(chars.chars().map(|ch| {
+                    Hir::literal(ch.encode_utf8(&mut [0; 4]).as_bytes())
+                })),
+            }
+        }
+        let mut exprs = vec![];
+        let mut i = 0;
+        while i < items.len() {
+            let mut len = 1;
+            for n in (2..=3).rev() {
+                let cls = self.full_case_folds(span, table, &items[i..], n)?;
+                if !cls.ranges().is_empty() {
+                    len = n;
+                    break;
+                }
+            }
+            if len == 1 {
+                exprs.push(items[i].clone());
+            } else {
+                let run = &items[i..i + len];
+                exprs.push(self.hir_full_case_fold_run(span, table, run)?);
+            }
+            i += len;
+        }
+        Ok(exprs)
+    }
+
+    /// Returns an expression that matches the given run of expressions, or
+    /// any sequence that is equivalent to it under full case folding.
+    fn hir_full_case_fold_run(
+        &self,
+        span: &Span,
+        table: &[(char, &'static [char])],
+        run: &[Hir],
+    ) -> Result<Hir> {
+        if run.is_empty() {
+            return Ok(Hir::empty());
+        }
+        let rest = self.hir_full_case_fold_run(span, table, &run[1..])?;
+        let mut alts = vec![Hir::concat(vec![run[0].clone(), rest])];
+        for len in 2..=run.len() {
+            let mut cls = self.full_case_folds(span, table, run, len)?;
+            if cls.ranges().is_empty() {
+                continue;
+            }
+            self.unicode_case_fold(span, &mut cls)?;
+            let rest =
+                self.hir_full_case_fold_run(span, table, &run[len..])?;
+            alts.push(Hir::concat(vec![
+                Hir::class(hir::Class::Unicode(cls)),
+                rest,
+            ]));
+        }
+        Ok(Hir::alternation(alts))
+    }
+
+    /// Returns the codepoints whose full case folding has exactly `len`
+    /// codepoints and is spelled out by the first `len` expressions given.
+    fn full_case_folds(
+        &self,
+        span: &Span,
+        table: &[(char, &'static [char])],
+        exprs: &[Hir],
+        len: usize,
+    ) -> Result<hir::ClassUnicode> {
+        let mut cls = hir::ClassUnicode::empty();
+        if exprs.len() < len {
+            return Ok(cls);
+        }
+        'entries: for &(c, fold) in table {
+            if fold.len() != len || !self.is_full_case_fold(c) {
+                continue;
+            }
+            for (expr, &ch) in exprs.iter().zip(fold) {
+                // Check whether the expression could possibly be `ch` before
+                // doing the more expensive comparison with the expression
+                // for `ch`.
+                let possible = match *expr.kind() {
+                    HirKind::Literal(hir::Literal(ref bytes)) => {
+                        **bytes == *ch.encode_utf8(&mut [0; 4]).as_bytes()
+                    }
+                    HirKind::Class(hir::Class::Unicode(ref x)) => {
+                        class_contains(x, ch)
+                    }
+                    _ => false,
+                };
+                if !possible {
+                    continue 'entries;
+                }
+                let mut folded =
+                    hir::ClassUnicode::new([hir::ClassUnicodeRange::new(
+                        ch, ch,
+                    )]);
+                self.unicode_case_fold(span, &mut folded)?;
+                if *expr != Hir::class(hir::Class::Unicode(folded)) {
+                    continue 'entries;
+                }
+            }
+            cls.push(hir::ClassUnicodeRange::new(c, c));
+        }
+        Ok(cls)
+    }
+
+    /// Returns true if the entry for `c` in the full case folding table
+    /// applies. With Turkic tailoring, `İ` folds to `i` instead of to `i`
+    /// followed by U+0307 COMBINING DOT ABOVE.
+    fn is_full_case_fold(&self, c: char) -> bool {
+        !(self.trans().turkic_case_folding && c == '\u{130}')
+    }
+
     fn bytes_fold_and_negate(
         &self,
         span: &Span,
@@ -1230,6 +2007,7 @@ struct Flags {
     swap_greed: Option<bool>,
     unicode: Option<bool>,
     crlf: Option<bool>,
+    grapheme: Option<bool>,
     // Note that `ignore_whitespace` is omitted here because it is handled
     // entirely in the parser.
 }
@@ -1261,6 +2039,9 @@ impl Flags {
                 ast::FlagsItemKind::Flag(ast::Flag::CRLF) => {
                     flags.crlf = Some(enable);
                 }
+                ast::FlagsItemKind::Flag(ast::Flag::Grapheme) => {
+                    flags.grapheme = Some(enable);
+                }
                 ast::FlagsItemKind::Flag(ast::Flag::IgnoreWhitespace) => {}
             }
         }
@@ -1286,6 +2067,9 @@ impl Flags {
         if self.crlf.is_none() {
             self.crlf = previous.crlf;
         }
+        if self.grapheme.is_none() {
+            self.grapheme = previous.grapheme;
+        }
     }
 
     fn case_insensitive(&self) -> bool {
@@ -1311,6 +2095,172 @@ impl Flags {
     fn crlf(&self) -> bool {
         self.crlf.unwrap_or(false)
     }
+
+    fn grapheme(&self) -> bool {
+        self.grapheme.unwrap_or(false)
+    }
+}
+
+/// The pairs of codepoints that are case insensitively equivalent under
+/// Turkic tailoring: `i` with `İ` and `I` with `ı`.
+const TURKIC_CASE_FOLDING: &[[char; 2]] =
+    &[['i', '\u{130}'], ['I', '\u{131}']];
+
+/// Returns true if and only if the given class contains the given codepoint.
+fn class_contains(cls: &hir::ClassUnicode, ch: char) -> bool {
+    cls.ranges().iter().any(|r| r.start() <= ch && ch <= r.end())
+}
+
+/// Returns an expression that matches a single extended grapheme cluster
+/// whose first codepoint is in `first`.
+///
+/// This follows the grapheme cluster boundary rules in [UAX #29], with the
+/// exception of the rule for Indic conjunct sequences (GB9c), which requires
+/// data that isn't available here. Alternatives are ordered such that
+/// leftmost-first semantics always prefer the longest cluster.
+///
+/// Note that the expression isn't atomic. When it's followed by something
+/// else, it may match a shorter prefix of a cluster if that's the only way
+/// for the rest of the regex to match.
+///
+/// [UAX #29]: https://unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+fn grapheme_cluster(
+    g: &unicode::GraphemeClasses,
+    first: &hir::ClassUnicode,
+) -> Hir {
+    let class =
+        |cls: &hir::ClassUnicode| Hir::class(hir::Class::Unicode(cls.clone()));
+    let contains = |ch: char| {
+        first.ranges().iter().any(|r| r.start() <= ch && ch <= r.end())
+    };
+    let mut controls = g.control.clone();
+    controls.union(&g.cr);
+    controls.union(&g.lf);
+    let mut marks = g.extend.clone();
+    marks.union(&g.zwj);
+    marks.union(&g.spacing_mark);
+    let any = hir::ClassUnicode::new([hir::ClassUnicodeRange::new(
+        '\0',
+        '\u{10FFFF}',
+    )]);
+
+    let mut branches = vec![];
+    // GB3: CR × LF
+    if contains('\r') && contains('\n') {
+        branches.push(Hir::concat(vec![class(&g.cr), class(&g.lf)]));
+    }
+    // GB4, GB5: Controls and line terminators are always on their own.
+    branches.push(class(&intersect(&controls, first)));
+    // Every core starts with a codepoint that isn't a control, so when
+    // `first` contains all of those (and thus all prepended characters too),
+    // the core only needs to be compiled once. This is the case for `\X` and
+    // `(?s).`, and it roughly halves the size of the expression.
+    let mut noncontrols = controls.clone();
+    noncontrols.negate();
+    let core = if intersect(&noncontrols, first) == noncontrols {
+        // GB9b: Prepend ×
+        Hir::concat(vec![
+            star(class(&g.prepend)),
+            grapheme_cluster_core(g, &controls, &any),
+        ])
+    } else {
+        Hir::alternation(vec![
+            // GB9b: Prepend ×
+            Hir::concat(vec![
+                class(&intersect(&g.prepend, first)),
+                star(class(&g.prepend)),
+                grapheme_cluster_core(g, &controls, &any),
+            ]),
+            grapheme_cluster_core(g, &controls, first),
+        ])
+    };
+    // GB9, GB9a: × (Extend | ZWJ | SpacingMark)
+    branches.push(Hir::concat(vec![core, star(class(&marks))]));
+    Hir::alternation(branches)
+}
+
+/// Returns an expression that matches the "core" of an extended grapheme
+/// cluster, i.e., everything but its prepended characters and trailing
+/// marks, whose first codepoint is in `first`.
+fn grapheme_cluster_core(
+    g: &unicode::GraphemeClasses,
+    controls: &hir::ClassUnicode,
+    first: &hir::ClassUnicode,
+) -> Hir {
+    let class =
+        |cls: &hir::ClassUnicode| Hir::class(hir::Class::Unicode(cls.clone()));
+    let starting = |cls: &hir::ClassUnicode| class(&intersect(cls, first));
+    // GB6, GB7, GB8: Hangul syllable sequences.
+    let syllable = Hir::concat(vec![
+        Hir::alternation(vec![
+            Hir::concat(vec![class(&g.v), star(class(&g.v))]),
+            Hir::concat(vec![class(&g.lv), star(class(&g.v))]),
+            class(&g.lvt),
+        ]),
+        star(class(&g.t)),
+    ]);
+    let mut other = controls.clone();
+    other.negate();
+    Hir::alternation(vec![
+        Hir::concat(vec![
+            starting(&g.l),
+            star(class(&g.l)),
+            Hir::repetition(hir::Repetition {
+                min: 0,
+                max: Some(1),
+                greedy: true,
+                sub: Box::new(syllable),
+            }),
+        ]),
+        Hir::concat(vec![
+            starting(&g.v),
+            star(class(&g.v)),
+            star(class(&g.t)),
+        ]),
+        Hir::concat(vec![
+            starting(&g.lv),
+            star(class(&g.v)),
+            star(class(&g.t)),
+        ]),
+        Hir::concat(vec![starting(&g.lvt), star(class(&g.t))]),
+        Hir::concat(vec![starting(&g.t), star(class(&g.t))]),
+        // GB12, GB13: Pairs of regional indicators.
+        Hir::concat(vec![
+            starting(&g.regional_indicator),
+            class(&g.regional_indicator),
+        ]),
+        // GB11: Emoji ZWJ sequences.
+        Hir::concat(vec![
+            starting(&g.extended_pictographic),
+            star(Hir::concat(vec![
+                star(class(&g.extend)),
+                class(&g.zwj),
+                class(&g.extended_pictographic),
+            ])),
+        ]),
+        // GB999: Any other character that isn't a control.
+        starting(&other),
+    ])
+}
+
+/// Returns the intersection of the two classes given.
+fn intersect(
+    cls1: &hir::ClassUnicode,
+    cls2: &hir::ClassUnicode,
+) -> hir::ClassUnicode {
+    let mut cls = cls1.clone();
+    cls.intersect(cls2);
+    cls
+}
+
+/// Returns a greedy `*` repetition of the given expression.
+fn star(sub: Hir) -> Hir {
+    Hir::repetition(hir::Repetition {
+        min: 0,
+        max: None,
+        greedy: true,
+        sub: Box::new(sub),
+    })
 }
 
 fn hir_ascii_class_bytes(kind: &ast::ClassAsciiKind) -> hir::ClassBytes {
@@ -1355,8 +2305,51 @@ fn ascii_class_as_chars(
     ascii_class(kind).map(|(s, e)| (char::from(s), char::from(e)))
 }
 
-#[cfg(test)]
-mod tests {
+/// Returns the codepoints matched by the PCRE dialect's `\h` or `\v`.
+///
+/// These are defined by PCRE and are not derived from any Unicode property.
+fn pcre_space_class(kind: &ast::ClassPerlKind) -> &'static [(char, char)] {
+    match *kind {
+        ast::ClassPerlKind::HorizontalSpace => &[
+            ('\t', '\t'),
+            (' ', ' '),
+            ('\u{A0}', '\u{A0}'),
+            ('\u{1680}', '\u{1680}'),
+            ('\u{180E}', '\u{180E}'),
+            ('\u{2000}', '\u{200A}'),
+            ('\u{202F}', '\u{202F}'),
+            ('\u{205F}', '\u{205F}'),
+            ('\u{3000}', '\u{3000}'),
+        ],
+        ast::ClassPerlKind::VerticalSpace => {
+            &[('\n', '\r'), ('\u{85}', '\u{85}'), ('\u{2028}', '\u{2029}')]
+        }
+        _ => unreachable!("not a PCRE space class: {:?}", kind),
+    }
+}
+
+fn hir_pcre_space_class_unicode(
+    kind: &ast::ClassPerlKind,
+) -> hir::ClassUnicode {
+    hir::ClassUnicode::new(
+        pcre_space_class(kind)
+            .iter()
+            .map(|&(s, e)| hir::ClassUnicodeRange::new(s, e)),
+    )
+}
+
+/// Like `hir_pcre_space_class_unicode`, but only includes ASCII codepoints.
+fn hir_pcre_space_class_bytes(kind: &ast::ClassPerlKind) -> hir::ClassBytes {
+    hir::ClassBytes::new(
+        pcre_space_class(kind)
+            .iter()
+            .filter(|&&(_, e)| e.is_ascii())
+            .map(|&(s, e)| hir::ClassBytesRange::new(s as u8, e as u8)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
     use crate::{
         ast::{parse::ParserBuilder, Position},
         hir::{Look, Properties},
@@ -1413,6 +2406,15 @@ mod tests {
             .unwrap()
     }
 
+    fn t_fold(pattern: &str, full: bool, turkic: bool) -> Hir {
+        TranslatorBuilder::new()
+            .full_case_folding(full)
+            .turkic_case_folding(turkic)
+            .build()
+            .translate(pattern, &parse(pattern))
+            .unwrap()
+    }
+
     fn props(pattern: &str) -> Properties {
         t(pattern).properties().clone()
     }
@@ -1601,6 +2603,10 @@ mod tests {
         Hir::look(look)
     }
 
+    fn hir_look_around(kind: hir::LookAroundKind, expr: Hir) -> Hir {
+        Hir::look_around(hir::LookAround { kind, sub: Box::new(expr) })
+    }
+
     #[test]
     fn empty() {
         assert_eq!(t(""), Hir::empty());
@@ -1733,6 +2739,130 @@ mod tests {
         assert_eq!(t("(?i-u)β"), hir_lit("β"),);
     }
 
+    #[test]
+    #[cfg(feature = "unicode-case")]
+    fn case_insensitive_full() {
+        let s = || hir_uclass(&[('S', 'S'), ('s', 's'), ('ſ', 'ſ')]);
+        let sharp_s = hir_alt(vec![
+            hir_cat(vec![s(), s()]),
+            hir_uclass(&[('ß', 'ß'), ('ẞ', 'ẞ')]),
+        ]);
+        assert_eq!(t_fold("(?i)ß", true, false), sharp_s);
+        assert_eq!(t_fold("(?i)ẞ", true, false), sharp_s);
+        assert_eq!(t_fold("(?i)[ß]", true, false), sharp_s);
+        assert_eq!(
+            t_fold("(?i)ß", false, false),
+            hir_uclass(&[('ß', 'ß'), ('ẞ', 'ẞ')])
+        );
+        assert_eq!(t_fold("ß", true, false), hir_lit("ß"));
+        assert_eq!(t_fold("(?i)@", true, false), hir_lit("@"));
+        assert_eq!(t_fold("(?i-u)a", true, true), t("(?i-u)a"));
+
+        // Codepoints without simple case folding can still have a full case
+        // folding.
+        assert_eq!(
+            t_fold("(?i)ŉ", true, false),
+            hir_alt(vec![
+                hir_cat(vec![
+                    hir_lit("ʼ"),
+                    hir_uclass(&[('N', 'N'), ('n', 'n')]),
+                ]),
+                hir_lit("ŉ"),
+            ])
+        );
+
+        // Prefixes of a full case folding can themselves be the full case
+        // folding of another codepoint.
+        let f = || hir_uclass(&[('F', 'F'), ('f', 'f')]);
+        let i = || hir_uclass(&[('I', 'I'), ('i', 'i')]);
+        assert_eq!(
+            t_fold("(?i)ﬃ", true, false),
+            hir_alt(vec![
+                hir_alt(vec![
+                    hir_cat(vec![hir_lit("ﬀ"), i()]),
+                    hir_cat(vec![
+                        f(),
+                        hir_alt(vec![hir_lit("ﬁ"), hir_cat(vec![f(), i()])]),
+                    ]),
+                ]),
+                hir_lit("ﬃ"),
+            ])
+        );
+
+        // Sequences in the pattern that spell out a full case folding also
+        // match the codepoint it belongs to, but only if each of their
+        // characters is matched case insensitively.
+        let sharp_s_seq = hir_alt(vec![
+            hir_cat(vec![s(), s()]),
+            hir_uclass(&[('ß', 'ß'), ('ẞ', 'ẞ')]),
+        ]);
+        assert_eq!(t_fold("(?i)ss", true, false), sharp_s_seq);
+        assert_eq!(t_fold("(?i)sS", true, false), sharp_s_seq);
+        assert_eq!(
+            t_fold("(?i)sss", true, false),
+            hir_cat(vec![sharp_s_seq.clone(), s()])
+        );
+        assert_eq!(t_fold("(?i)ss", false, false), hir_cat(vec![s(), s()]));
+        assert_eq!(t_fold("(?i)s(?-i)s", true, false), t("(?i)s(?-i)s"));
+        assert_eq!(
+            t_fold("(?i)ʼn", true, false),
+            hir_alt(vec![
+                hir_cat(vec![
+                    hir_lit("ʼ"),
+                    hir_uclass(&[('N', 'N'), ('n', 'n')]),
+                ]),
+                hir_lit("ŉ"),
+            ])
+        );
+
+        // Negated classes only use simple case folding.
+        assert_eq!(t_fold("(?i)[^ß]", true, false), t("(?i)[^ß]"));
+        assert_eq!(t_fold(r"(?i)\P{Ll}", true, false), t(r"(?i)\P{Ll}"));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-case")]
+    fn case_insensitive_turkic() {
+        assert_eq!(
+            t_fold("(?i)i", false, true),
+            hir_uclass(&[('i', 'i'), ('İ', 'İ')])
+        );
+        assert_eq!(
+            t_fold("(?i)I", false, true),
+            hir_uclass(&[('I', 'I'), ('ı', 'ı')])
+        );
+        assert_eq!(
+            t_fold("(?i)ı", false, true),
+            hir_uclass(&[('I', 'I'), ('ı', 'ı')])
+        );
+        assert_eq!(
+            t_fold("(?i)[h-j]", false, true),
+            hir_uclass(&[('H', 'H'), ('J', 'J'), ('h', 'j'), ('İ', 'İ'),])
+        );
+        assert_eq!(
+            t_fold("(?i)[h-j&&[^i]]", false, true),
+            hir_uclass(&[('H', 'H'), ('J', 'J'), ('h', 'h'), ('j', 'j')])
+        );
+        // Without Turkic tailoring, `İ` has no simple case folding but its
+        // full case folding is `i` followed by a combining dot above. With
+        // it, `İ` just folds to `i`.
+        assert_eq!(t_fold("(?i)İ", false, false), hir_lit("İ"));
+        assert_eq!(
+            t_fold("(?i)İ", true, false),
+            hir_alt(vec![
+                hir_cat(vec![
+                    hir_uclass(&[('I', 'I'), ('i', 'i')]),
+                    hir_lit("\u{307}"),
+                ]),
+                hir_lit("İ"),
+            ])
+        );
+        assert_eq!(
+            t_fold("(?i)İ", true, true),
+            hir_uclass(&[('i', 'i'), ('İ', 'İ')])
+        );
+    }
+
     #[test]
     fn dot() {
         assert_eq!(
@@ -1807,6 +2937,39 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "unicode-segment", feature = "unicode-bool"))]
+    fn grapheme_cluster() {
+        let cluster = t(r"\X");
+        assert_eq!(Some(1), cluster.properties().minimum_len());
+        assert_eq!(None, cluster.properties().maximum_len());
+        assert!(cluster.properties().is_utf8());
+
+        // When `.` may match any character, grapheme mode makes it
+        // equivalent to `\X`.
+        assert_eq!(t("(?gs)."), cluster);
+        assert_ne!(t("(?g)."), cluster);
+        assert_ne!(t("(?g)[^a]"), t("[^a]"));
+        // Only `.` and negated bracketed classes are affected.
+        assert_eq!(t("(?g)[a]"), t("[a]"));
+        assert_eq!(t(r"(?g)\W"), t(r"\W"));
+        assert_eq!(t(r"(?g)\P{Greek}"), t(r"\P{Greek}"));
+        // Grapheme mode has no effect without Unicode mode.
+        assert_eq!(t_bytes("(?g-u)."), t_bytes("(?-u)."));
+        assert_eq!(t_bytes("(?g-u)[^a]"), t_bytes("(?-u)[^a]"));
+
+        assert_eq!(
+            t_err(r"(?-u)\X"),
+            TestError {
+                kind: hir::ErrorKind::UnicodeNotAllowed,
+                span: Span::new(
+                    Position::new(5, 1, 6),
+                    Position::new(7, 1, 8)
+                ),
+            }
+        );
+    }
+
     #[test]
     fn assertions() {
         assert_eq!(t("^"), hir_look(hir::Look::Start));
@@ -1822,6 +2985,411 @@ mod tests {
         assert_eq!(t(r"\B"), hir_look(hir::Look::WordUnicodeNegate));
         assert_eq!(t(r"(?-u)\b"), hir_look(hir::Look::WordAscii));
         assert_eq!(t(r"(?-u)\B"), hir_look(hir::Look::WordAsciiNegate));
+
+        assert_eq!(t(r"\b{wb}"), hir_look(hir::Look::WordSegment));
+        assert_eq!(t(r"\b{sb}"), hir_look(hir::Look::SentenceSegment));
+        assert_eq!(
+            t_err(r"(?-u)\b{wb}"),
+            TestError {
+                kind: hir::ErrorKind::UnicodeNotAllowed,
+                span: Span::new(
+                    Position::new(5, 1, 6),
+                    Position::new(11, 1, 12)
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn look_around() {
+        use crate::hir::LookAroundKind::*;
+
+        assert_eq!(
+            t("(?=a)"),
+            hir_look_around(PositiveLookAhead, hir_lit("a"))
+        );
+        assert_eq!(
+            t("(?!a)"),
+            hir_look_around(NegativeLookAhead, hir_lit("a"))
+        );
+        assert_eq!(
+            t("(?<=a)"),
+            hir_look_around(PositiveLookBehind, hir_lit("a"))
+        );
+        assert_eq!(
+            t("(?<!a)"),
+            hir_look_around(NegativeLookBehind, hir_lit("a"))
+        );
+        assert_eq!(
+            t("(?i:(?=a))"),
+            hir_look_around(
+                PositiveLookAhead,
+                hir_uclass(&[('A', 'A'), ('a', 'a'),])
+            )
+        );
+        assert_eq!(
+            t("(?<=a{1,3})b(?=c+)"),
+            hir_cat(vec![
+                hir_look_around(
+                    PositiveLookBehind,
+                    hir_range(true, 1, Some(3), hir_lit("a"))
+                ),
+                hir_lit("b"),
+                hir_look_around(
+                    PositiveLookAhead,
+                    hir_plus(true, hir_lit("c"))
+                ),
+            ])
+        );
+        // Non-capturing groups and flags inside of look-around are fine.
+        assert_eq!(
+            t("(?=(?:a)(?i)b)"),
+            hir_look_around(
+                PositiveLookAhead,
+                hir_cat(vec![
+                    hir_lit("a"),
+                    hir_uclass(&[('B', 'B'), ('b', 'b')]),
+                ])
+            )
+        );
+        // Look-around never matches any of the haystack.
+        let props = t("(?=abc)").properties().clone();
+        assert_eq!(Some(0), props.minimum_len());
+        assert_eq!(Some(0), props.maximum_len());
+        assert_eq!(0, props.explicit_captures_len());
+
+        assert_eq!(
+            t_err("(?=(?!a))"),
+            TestError {
+                kind: hir::ErrorKind::LookAroundNested,
+                span: Span::new(
+                    Position::new(3, 1, 4),
+                    Position::new(8, 1, 9)
+                ),
+            }
+        );
+        assert_eq!(
+            t_err("(?<=a(b))"),
+            TestError {
+                kind: hir::ErrorKind::LookAroundCapture,
+                span: Span::new(
+                    Position::new(5, 1, 6),
+                    Position::new(8, 1, 9)
+                ),
+            }
+        );
+        assert_eq!(
+            t_err("(?!(?P<x>a))"),
+            TestError {
+                kind: hir::ErrorKind::LookAroundCapture,
+                span: Span::new(
+                    Position::new(3, 1, 4),
+                    Position::new(11, 1, 12)
+                ),
+            }
+        );
+        assert_eq!(
+            t_err("(?<=a+)b"),
+            TestError {
+                kind: hir::ErrorKind::LookBehindUnbounded,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(7, 1, 8)
+                ),
+            }
+        );
+        assert_eq!(
+            t_err("(?<!a|b*)"),
+            TestError {
+                kind: hir::ErrorKind::LookBehindUnbounded,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(9, 1, 10)
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn boolean_operators() {
+        fn tb(pattern: &str, utf8: bool) -> Result<Hir> {
+            let ast = ParserBuilder::new()
+                .boolean_operators(true)
+                .build()
+                .parse(pattern)
+                .unwrap();
+            TranslatorBuilder::new()
+                .utf8(utf8)
+                .build()
+                .translate(pattern, &ast)
+        }
+        let any = hir_star(true, hir_uclass(&[('\0', '\u{10FFFF}')]));
+
+        assert_eq!(
+            tb("a&b", true).unwrap(),
+            Hir::intersection(vec![hir_lit("a"), hir_lit("b")])
+        );
+        // Literals are never merged across operands.
+        assert_eq!(
+            tb("ab&cd|e", true).unwrap(),
+            hir_alt(vec![
+                Hir::intersection(vec![hir_lit("ab"), hir_lit("cd")]),
+                hir_lit("e"),
+            ])
+        );
+        // In Unicode mode, a complement is restricted to valid UTF-8.
+        assert_eq!(
+            tb("a~b", true).unwrap(),
+            hir_cat(vec![
+                hir_lit("a"),
+                Hir::intersection(vec![
+                    any.clone(),
+                    Hir::complement(hir_lit("b")),
+                ]),
+            ])
+        );
+        assert_eq!(
+            tb("(?-u)~b", false).unwrap(),
+            Hir::complement(hir_lit("b"))
+        );
+        assert_eq!(tb("(?-u)~~b", false).unwrap(), hir_lit("b"));
+        assert_eq!(
+            tb("a&(?:~b)", true).unwrap(),
+            Hir::intersection(vec![
+                hir_lit("a"),
+                any.clone(),
+                Hir::complement(hir_lit("b")),
+            ])
+        );
+
+        let props =
+            tb("[a-z]{2,5}&\\w{3,}", true).unwrap().properties().clone();
+        assert_eq!(Some(3), props.minimum_len());
+        assert_eq!(Some(5), props.maximum_len());
+        assert!(props.is_utf8());
+        assert_eq!(0, props.explicit_captures_len());
+
+        let err = tb("(?-u)~b", true).unwrap_err();
+        assert_eq!(
+            TestError {
+                kind: hir::ErrorKind::InvalidUtf8,
+                span: Span::new(
+                    Position::new(5, 1, 6),
+                    Position::new(7, 1, 8)
+                ),
+            },
+            err,
+        );
+        let err = tb("a&(b)", true).unwrap_err();
+        assert_eq!(
+            TestError {
+                kind: hir::ErrorKind::BooleanCapture,
+                span: Span::new(
+                    Position::new(2, 1, 3),
+                    Position::new(5, 1, 6)
+                ),
+            },
+            err,
+        );
+        let err = tb("~(?:a(?P<x>b))", true).unwrap_err();
+        assert_eq!(
+            TestError {
+                kind: hir::ErrorKind::BooleanCapture,
+                span: Span::new(
+                    Position::new(5, 1, 6),
+                    Position::new(13, 1, 14)
+                ),
+            },
+            err,
+        );
+    }
+
+    #[test]
+    fn backreferences() {
+        fn tb(pattern: &str) -> Result<Hir> {
+            let ast = ParserBuilder::new()
+                .backreferences(true)
+                .boolean_operators(true)
+                .build()
+                .parse(pattern)
+                .unwrap();
+            TranslatorBuilder::new().build().translate(pattern, &ast)
+        }
+        fn err(pattern: &str, kind: hir::ErrorKind, start: usize) {
+            let err = tb(pattern).unwrap_err();
+            assert_eq!(kind, *err.kind(), "{}", pattern);
+            assert_eq!(start, err.span().start.offset, "{}", pattern);
+        }
+
+        assert_eq!(
+            tb(r"(a)\1").unwrap(),
+            hir_cat(vec![hir_capture(1, hir_lit("a")), Hir::backreference(1)])
+        );
+        assert_eq!(
+            tb(r"(?<x>a)(?<y>b)\k<y>").unwrap(),
+            hir_cat(vec![
+                hir_capture_name(1, "x", hir_lit("a")),
+                hir_capture_name(2, "y", hir_lit("b")),
+                Hir::backreference(2),
+            ])
+        );
+        // A group may refer to itself, which never matches on the first
+        // iteration but might on subsequent iterations.
+        assert_eq!(
+            tb(r"(a\1)").unwrap(),
+            hir_capture(1, hir_cat(vec![hir_lit("a"), Hir::backreference(1)]))
+        );
+
+        let props = tb(r"(a)\1").unwrap().properties().clone();
+        assert_eq!(Some(1), props.minimum_len());
+        assert_eq!(None, props.maximum_len());
+        assert!(!props.is_literal());
+
+        err(r"\1(a)", hir::ErrorKind::BackreferenceUndefined, 0);
+        err(r"(a)\2", hir::ErrorKind::BackreferenceUndefined, 3);
+        err(r"(a)\k<x>", hir::ErrorKind::BackreferenceUndefined, 3);
+        err(r"(a)(?=\1)", hir::ErrorKind::BackreferenceUnsupported, 6);
+        err(r"(a)(?:b&\1)", hir::ErrorKind::BackreferenceUnsupported, 8);
+        err(r"(a)~\1", hir::ErrorKind::BackreferenceUnsupported, 4);
+        err(r"(?i)(a)\1", hir::ErrorKind::BackreferenceCaseInsensitive, 7);
+        err(r"(a)(?i:\1)", hir::ErrorKind::BackreferenceCaseInsensitive, 7);
+        // Only the case insensitivity of the backreference itself matters.
+        assert_eq!(
+            tb(r"(?i:(a))\1").unwrap(),
+            hir_cat(vec![
+                hir_capture(1, hir_uclass(&[('A', 'A'), ('a', 'a')])),
+                Hir::backreference(1),
+            ])
+        );
+    }
+
+    #[test]
+    fn pcre_dialect() {
+        fn tp(pattern: &str) -> Result<Hir> {
+            let ast = ParserBuilder::new()
+                .dialect(crate::ast::parse::Dialect::Pcre)
+                .build()
+                .parse(pattern)
+                .unwrap();
+            TranslatorBuilder::new()
+                .utf8(false)
+                .build()
+                .translate(pattern, &ast)
+        }
+
+        let hspace = &[
+            ('\t', '\t'),
+            (' ', ' '),
+            ('\u{A0}', '\u{A0}'),
+            ('\u{1680}', '\u{1680}'),
+            ('\u{180E}', '\u{180E}'),
+            ('\u{2000}', '\u{200A}'),
+            ('\u{202F}', '\u{202F}'),
+            ('\u{205F}', '\u{205F}'),
+            ('\u{3000}', '\u{3000}'),
+        ];
+        let vspace =
+            &[('\n', '\r'), ('\u{85}', '\u{85}'), ('\u{2028}', '\u{2029}')];
+        assert_eq!(tp(r"\h").unwrap(), hir_uclass(hspace));
+        assert_eq!(tp(r"\H").unwrap(), hir_negate(hir_uclass(hspace)));
+        assert_eq!(tp(r"\v").unwrap(), hir_uclass(vspace));
+        assert_eq!(tp(r"[\v]").unwrap(), hir_uclass(vspace));
+        assert_eq!(
+            tp(r"(?-u)\h").unwrap(),
+            hir_bclass(&[(b'\t', b'\t'), (b' ', b' ')])
+        );
+        assert_eq!(tp(r"(?-u)\v").unwrap(), hir_bclass(&[(b'\n', b'\r')]));
+
+        assert_eq!(
+            tp(r"\R").unwrap(),
+            hir_alt(vec![hir_lit("\r\n"), hir_uclass(vspace)])
+        );
+        assert_eq!(
+            tp(r"(?-u)\R").unwrap(),
+            hir_alt(vec![hir_lit("\r\n"), hir_bclass(&[(b'\n', b'\r')])])
+        );
+
+        assert_eq!(
+            tp(r"\Z").unwrap(),
+            hir_look_around(
+                hir::LookAroundKind::PositiveLookAhead,
+                hir_cat(vec![
+                    hir_quest(true, hir_lit("\n")),
+                    hir_look(hir::Look::End),
+                ])
+            )
+        );
+        let err = tp(r"(?=a\Z)").unwrap_err();
+        assert_eq!(hir::ErrorKind::LookAroundNested, *err.kind());
+        assert_eq!(4, err.span().start.offset);
+
+        assert_eq!(tp(r"\Qa.b\E").unwrap(), hir_lit("a.b"));
+        assert_eq!(
+            tp(r"[\Q]-\E]").unwrap(),
+            hir_uclass(&[('-', '-'), (']', ']')])
+        );
+        assert_eq!(tp(r"a(?#comment)b").unwrap(), hir_lit("ab"));
+        assert_eq!(tp(r"\x\x9").unwrap(), hir_lit("\x00\x09"));
+        assert_eq!(
+            tp(r"[[:^alpha:]]").unwrap(),
+            hir_negate(hir_ascii_uclass(&ast::ClassAsciiKind::Alpha))
+        );
+    }
+
+    #[test]
+    fn atomic() {
+        fn ta(pattern: &str) -> Result<Hir> {
+            let ast = ParserBuilder::new()
+                .possessive_quantifiers(true)
+                .boolean_operators(true)
+                .build()
+                .parse(pattern)
+                .unwrap();
+            TranslatorBuilder::new().build().translate(pattern, &ast)
+        }
+        fn err(pattern: &str, kind: hir::ErrorKind, start: usize) {
+            let err = ta(pattern).unwrap_err();
+            assert_eq!(kind, *err.kind(), "{}", pattern);
+            assert_eq!(start, err.span().start.offset, "{}", pattern);
+        }
+
+        assert_eq!(
+            ta(r"(?>a*)").unwrap(),
+            Hir::atomic(hir_star(true, hir_lit("a")))
+        );
+        assert_eq!(
+            ta(r"a*+").unwrap(),
+            Hir::atomic(hir_star(true, hir_lit("a")))
+        );
+        // Possessive repetitions are always greedy.
+        assert_eq!(
+            ta(r"(?U)a++").unwrap(),
+            Hir::atomic(hir_plus(true, hir_lit("a")))
+        );
+        assert_eq!(
+            ta(r"(?>(a|ab))").unwrap(),
+            Hir::atomic(hir_capture(
+                1,
+                hir_alt(vec![hir_lit("a"), hir_lit("ab")])
+            ))
+        );
+        // Things that can only match in one way don't need an atomic group.
+        assert_eq!(ta(r"(?>abc)").unwrap(), hir_lit("abc"));
+        assert_eq!(
+            ta(r"a?+").unwrap(),
+            Hir::atomic(hir_quest(true, hir_lit("a")))
+        );
+
+        let props = ta(r"(?>ab|cd)").unwrap().properties().clone();
+        assert_eq!(Some(2), props.minimum_len());
+        assert_eq!(Some(2), props.maximum_len());
+        assert!(!props.is_literal());
+        assert!(!props.is_alternation_literal());
+
+        err(r"(?=(?>a))", hir::ErrorKind::AtomicUnsupported, 3);
+        err(r"(?<=a++)", hir::ErrorKind::AtomicUnsupported, 4);
+        err(r"b&(?>a)", hir::ErrorKind::AtomicUnsupported, 2);
+        err(r"~(?:a*+)", hir::ErrorKind::AtomicUnsupported, 4);
     }
 
     #[test]
@@ -2697,6 +4265,143 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "unicode-name")]
+    fn literal_unicode_name() {
+        assert_eq!(t(r"\N{GREEK SMALL LETTER ALPHA}"), hir_lit("α"));
+        assert_eq!(t(r"\N{snowman}"), hir_lit("☃"));
+        assert_eq!(t(r"(?x)\N{LATIN SMALL LETTER A} "), hir_lit("a"));
+        assert_eq!(t_bytes(r"(?-u)\N{DIGIT ZERO}"), hir_blit(b"0"));
+        assert_eq!(
+            t(r"[\N{DIGIT ZERO}-\N{DIGIT NINE}]"),
+            hir_uclass(&[('0', '9')])
+        );
+        assert_eq!(
+            t_bytes(r"(?-u)[\N{DIGIT ZERO}-\N{DIGIT NINE}]"),
+            hir_bclass(&[(b'0', b'9')])
+        );
+
+        assert_eq!(
+            t_err(r"\N{SNOW MAN PERSON}"),
+            TestError {
+                kind: hir::ErrorKind::UnicodeNameNotFound,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(19, 1, 20)
+                ),
+            }
+        );
+        assert_eq!(
+            t_err(r"[\N{DIGIT NINE}-\N{DIGIT ZERO}]"),
+            TestError {
+                kind: hir::ErrorKind::ClassRangeInvalid,
+                span: Span::new(
+                    Position::new(1, 1, 2),
+                    Position::new(30, 1, 31)
+                ),
+            }
+        );
+        assert_eq!(
+            t_err(r"[\N{DIGIT NINE}-0]"),
+            TestError {
+                kind: hir::ErrorKind::ClassRangeInvalid,
+                span: Span::new(
+                    Position::new(1, 1, 2),
+                    Position::new(17, 1, 18)
+                ),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode-name"))]
+    fn literal_unicode_name_disabled() {
+        assert_eq!(
+            t_err(r"\N{SNOWMAN}"),
+            TestError {
+                kind: hir::ErrorKind::UnicodeNameUnavailable,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(11, 1, 12)
+                ),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-block")]
+    fn class_unicode_block() {
+        assert_eq!(t(r"\p{Block=Cyrillic}"), hir_uclass(&[('Ѐ', 'ӿ')]));
+        assert_eq!(t(r"\p{blk=ASCII}"), hir_uclass(&[('\0', '\x7F')]));
+        assert_eq!(t(r"\p{blk:basic latin}"), t(r"\p{blk=ASCII}"));
+
+        assert_eq!(
+            t_err(r"\p{blk:Foo}"),
+            TestError {
+                kind: hir::ErrorKind::UnicodePropertyValueNotFound,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(11, 1, 12)
+                ),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode-block"))]
+    fn class_unicode_block_disabled() {
+        assert_eq!(
+            t_err(r"\p{blk=ASCII}"),
+            TestError {
+                kind: hir::ErrorKind::UnicodePropertyNotFound,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(13, 1, 14)
+                ),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "unicode-east-asian-width",
+        feature = "unicode-line-break",
+        feature = "unicode-numeric-type",
+    ))]
+    fn class_unicode_enumerated() {
+        assert_eq!(t(r"\p{ea=W}"), t(r"\p{East_Asian_Width=Wide}"));
+        assert_eq!(
+            t(r"\p{ea=H}"),
+            hir_uclass(&[
+                ('\u{20A9}', '\u{20A9}'),
+                ('\u{FF61}', '\u{FFBE}'),
+                ('\u{FFC2}', '\u{FFC7}'),
+                ('\u{FFCA}', '\u{FFCF}'),
+                ('\u{FFD2}', '\u{FFD7}'),
+                ('\u{FFDA}', '\u{FFDC}'),
+                ('\u{FFE8}', '\u{FFEE}'),
+            ])
+        );
+
+        assert_eq!(t(r"\p{lb=ZWJ}"), hir_lit("\u{200D}"));
+        assert_eq!(t(r"\p{Line_Break=Carriage_Return}"), hir_lit("\r"));
+        assert_eq!(t(r"\p{lb=IS}"), t(r"\p{lb=Infix_Numeric}"));
+
+        assert_eq!(t(r"\p{nt=Decimal}"), t(r"\p{nt=De}"));
+        assert_eq!(t(r"[\p{nt=De}&&\x00-\x7F]"), hir_uclass(&[('0', '9')]));
+
+        assert_eq!(
+            t_err(r"\p{ea=Foo}"),
+            TestError {
+                kind: hir::ErrorKind::UnicodePropertyValueNotFound,
+                span: Span::new(
+                    Position::new(0, 1, 1),
+                    Position::new(10, 1, 11)
+                ),
+            }
+        );
+    }
+
     #[test]
     fn class_bracketed() {
         assert_eq!(t("[a]"), hir_lit("a"));
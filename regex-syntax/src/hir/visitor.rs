@@ -46,6 +46,11 @@ pub trait Visitor {
     fn visit_concat_in(&mut self) -> Result<(), Self::Err> {
         Ok(())
     }
+
+    /// This method is called between child nodes of an intersection.
+    fn visit_intersection_in(&mut self) -> Result<(), Self::Err> {
+        Ok(())
+    }
 }
 
 /// Executes an implementation of `Visitor` in constant stack space.
@@ -83,6 +88,9 @@ enum Frame<'a> {
     /// A stack frame allocated just before descending into a capture's child
     /// node.
     Capture(&'a hir::Capture),
+    /// A stack frame allocated just before descending into a look-around
+    /// assertion's child node.
+    LookAround(&'a hir::LookAround),
     /// The stack frame used while visiting every child node of a concatenation
     /// of expressions.
     Concat {
@@ -99,6 +107,20 @@ enum Frame<'a> {
         /// The remaining child nodes to visit (which may be empty).
         tail: &'a [Hir],
     },
+    /// The stack frame used while visiting every child node of an
+    /// intersection of expressions.
+    Intersection {
+        /// The child node we are currently visiting.
+        head: &'a Hir,
+        /// The remaining child nodes to visit (which may be empty).
+        tail: &'a [Hir],
+    },
+    /// A stack frame allocated just before descending into a complement's
+    /// child node.
+    Complement(&'a Hir),
+    /// A stack frame allocated just before descending into an atomic group's
+    /// child node.
+    Atomic(&'a Hir),
 }
 
 impl<'a> HeapVisitor<'a> {
@@ -143,6 +165,9 @@ impl<'a> HeapVisitor<'a> {
                         Frame::Concat { .. } => {
                             visitor.visit_concat_in()?;
                         }
+                        Frame::Intersection { .. } => {
+                            visitor.visit_intersection_in()?;
+                        }
                         _ => {}
                     }
                     hir = x.child();
@@ -162,6 +187,7 @@ impl<'a> HeapVisitor<'a> {
         match *hir.kind() {
             HirKind::Repetition(ref x) => Some(Frame::Repetition(x)),
             HirKind::Capture(ref x) => Some(Frame::Capture(x)),
+            HirKind::LookAround(ref x) => Some(Frame::LookAround(x)),
             HirKind::Concat(ref x) if x.is_empty() => None,
             HirKind::Concat(ref x) => {
                 Some(Frame::Concat { head: &x[0], tail: &x[1..] })
@@ -170,6 +196,12 @@ impl<'a> HeapVisitor<'a> {
             HirKind::Alternation(ref x) => {
                 Some(Frame::Alternation { head: &x[0], tail: &x[1..] })
             }
+            HirKind::Intersection(ref x) if x.is_empty() => None,
+            HirKind::Intersection(ref x) => {
+                Some(Frame::Intersection { head: &x[0], tail: &x[1..] })
+            }
+            HirKind::Complement(ref x) => Some(Frame::Complement(x)),
+            HirKind::Atomic(ref x) => Some(Frame::Atomic(x)),
             _ => None,
         }
     }
@@ -180,6 +212,7 @@ impl<'a> HeapVisitor<'a> {
         match induct {
             Frame::Repetition(_) => None,
             Frame::Capture(_) => None,
+            Frame::LookAround(_) => None,
             Frame::Concat { tail, .. } => {
                 if tail.is_empty() {
                     None
@@ -197,6 +230,18 @@ impl<'a> HeapVisitor<'a> {
                     })
                 }
             }
+            Frame::Intersection { tail, .. } => {
+                if tail.is_empty() {
+                    None
+                } else {
+                    Some(Frame::Intersection {
+                        head: &tail[0],
+                        tail: &tail[1..],
+                    })
+                }
+            }
+            Frame::Complement(_) => None,
+            Frame::Atomic(_) => None,
         }
     }
 }
@@ -208,8 +253,12 @@ impl<'a> Frame<'a> {
         match *self {
             Frame::Repetition(rep) => &rep.sub,
             Frame::Capture(capture) => &capture.sub,
+            Frame::LookAround(look_around) => &look_around.sub,
             Frame::Concat { head, .. } => head,
             Frame::Alternation { head, .. } => head,
+            Frame::Intersection { head, .. } => head,
+            Frame::Complement(sub) => sub,
+            Frame::Atomic(sub) => sub,
         }
     }
 }
@@ -129,6 +129,10 @@ The following features are available:
   [Unicode `Age` property](https://www.unicode.org/reports/tr44/tr44-24.html#Character_Age).
   This makes it possible to use classes like `\p{Age:6.0}` to refer to all
   codepoints first introduced in Unicode 6.0
+* **unicode-block** -
+  Provide the data for the
+  [Unicode `Block` property](https://www.unicode.org/reports/tr44/tr44-24.html#Block).
+  This makes it possible to use classes like `\p{blk=Greek_And_Coptic}`.
 * **unicode-bool** -
   Provide the data for numerous Unicode boolean properties. The full list
   is not included here, but contains properties like `Alphabetic`, `Emoji`,
@@ -136,11 +140,27 @@ The following features are available:
 * **unicode-case** -
   Provide the data for case insensitive matching using
   [Unicode's "simple loose matches" specification](https://www.unicode.org/reports/tr18/#Simple_Loose_Matches).
+* **unicode-east-asian-width** -
+  Provide the data for the
+  [Unicode `East_Asian_Width` property](https://www.unicode.org/reports/tr11/).
+  This makes it possible to use classes like `\p{ea=Wide}`.
 * **unicode-gencat** -
   Provide the data for
   [Unicode general categories](https://www.unicode.org/reports/tr44/tr44-24.html#General_Category_Values).
   This includes, but is not limited to, `Decimal_Number`, `Letter`,
   `Math_Symbol`, `Number` and `Punctuation`.
+* **unicode-line-break** -
+  Provide the data for the
+  [Unicode `Line_Break` property](https://www.unicode.org/reports/tr14/).
+  This makes it possible to use classes like `\p{lb=Ideographic}`.
+* **unicode-name** -
+  Provide the data for looking up codepoints by their
+  [Unicode name](https://www.unicode.org/reports/tr44/tr44-24.html#Name).
+  This makes it possible to use escapes like `\N{GREEK SMALL LETTER ALPHA}`.
+* **unicode-numeric-type** -
+  Provide the data for the
+  [Unicode `Numeric_Type` property](https://www.unicode.org/reports/tr44/tr44-24.html#Numeric_Type).
+  This makes it possible to use classes like `\p{nt=Decimal}`.
 * **unicode-perl** -
   Provide the data for supporting the Unicode-aware Perl character classes,
   corresponding to `\w`, `\s` and `\d`. This is also necessary for using
@@ -176,9 +196,10 @@ extern crate std;
 extern crate alloc;
 
 pub use crate::{
+    ast::parse::Dialect,
     error::Error,
     parser::{parse, Parser, ParserBuilder},
-    unicode::UnicodeWordError,
+    unicode::{UnicodeSegmentError, UnicodeWordError},
 };
 
 use alloc::string::String;
@@ -369,6 +390,57 @@ pub fn try_is_word_character(
     unicode::is_word_character(c)
 }
 
+/// Returns the value of the `Word_Break` property of the given character.
+///
+/// The value is returned as its canonical name from
+/// [UAX#29](https://unicode.org/reports/tr29/#Word_Boundaries), e.g.,
+/// `ALetter` or `MidNumLet`. Characters that aren't assigned a value
+/// explicitly have the value `Other`.
+///
+/// # Errors
+///
+/// If the `unicode-segment` feature is not enabled, then this function always
+/// returns an error.
+pub fn try_word_break(
+    c: char,
+) -> core::result::Result<&'static str, UnicodeSegmentError> {
+    unicode::word_break(c)
+}
+
+/// Returns the value of the `Sentence_Break` property of the given character.
+///
+/// The value is returned as its canonical name from
+/// [UAX#29](https://unicode.org/reports/tr29/#Sentence_Boundaries), e.g.,
+/// `ATerm` or `Close`. Characters that aren't assigned a value explicitly
+/// have the value `Other`.
+///
+/// # Errors
+///
+/// If the `unicode-segment` feature is not enabled, then this function always
+/// returns an error.
+pub fn try_sentence_break(
+    c: char,
+) -> core::result::Result<&'static str, UnicodeSegmentError> {
+    unicode::sentence_break(c)
+}
+
+/// Returns true if and only if the given character has the
+/// `Extended_Pictographic` property.
+///
+/// This property is used by the segmentation rules in
+/// [UAX#29](https://unicode.org/reports/tr29/) to keep emoji sequences
+/// together.
+///
+/// # Errors
+///
+/// If the `unicode-bool` feature is not enabled, then this function always
+/// returns an error.
+pub fn try_is_extended_pictographic(
+    c: char,
+) -> core::result::Result<bool, UnicodeSegmentError> {
+    unicode::is_extended_pictographic(c)
+}
+
 /// Returns true if and only if the given character is an ASCII word character.
 ///
 /// An ASCII word character is defined by the following character class:
@@ -428,4 +500,25 @@ mod tests {
     fn word_char_disabled_error() {
         assert!(try_is_word_character('a').is_err());
     }
+
+    #[test]
+    #[cfg(all(feature = "unicode-segment", feature = "unicode-bool"))]
+    fn segment_properties() {
+        assert_eq!("ALetter", try_word_break('a').unwrap());
+        assert_eq!("MidNumLet", try_word_break('.').unwrap());
+        assert_eq!("Numeric", try_word_break('٣').unwrap());
+        assert_eq!("Other", try_word_break('☃').unwrap());
+        assert_eq!("ATerm", try_sentence_break('.').unwrap());
+        assert_eq!("Upper", try_sentence_break('Β').unwrap());
+        assert_eq!("Other", try_sentence_break('☃').unwrap());
+        assert!(try_is_extended_pictographic('☃').unwrap());
+        assert!(!try_is_extended_pictographic('a').unwrap());
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode-segment"))]
+    fn segment_properties_disabled_error() {
+        assert!(try_word_break('a').is_err());
+        assert!(try_sentence_break('a').is_err());
+    }
 }
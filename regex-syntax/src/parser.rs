@@ -88,6 +88,144 @@ impl ParserBuilder {
         self
     }
 
+    /// Enable the boolean operators `&` (intersection) and `~` (complement).
+    ///
+    /// When enabled, `a&b` matches a string only if both `a` and `b` match
+    /// it, and `~a` matches every string that `a` does not match. When
+    /// Unicode mode is enabled, the complement is taken with respect to all
+    /// valid UTF-8 strings. Otherwise, it is taken with respect to all byte
+    /// strings, which requires disabling UTF-8 mode.
+    ///
+    /// Capturing groups are not permitted inside the operands of either
+    /// operator.
+    ///
+    /// See [`ast::parse::ParserBuilder::boolean_operators`] for details on
+    /// the precedence of these operators.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_syntax::{hir::HirKind, ParserBuilder};
+    ///
+    /// let hir = ParserBuilder::new()
+    ///     .boolean_operators(true)
+    ///     .build()
+    ///     .parse(r"\w+&~(?:foo)")?;
+    /// assert!(matches!(*hir.kind(), HirKind::Intersection(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn boolean_operators(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.ast.boolean_operators(yes);
+        self
+    }
+
+    /// Enable backreferences, e.g., `\1` or `\k<name>`.
+    ///
+    /// When enabled, a backreference is translated into
+    /// [`HirKind::Backreference`](crate::hir::HirKind::Backreference), which
+    /// refers to a capturing group by its index. It is an error to refer to
+    /// a group that does not exist or whose opening parenthesis appears after
+    /// the backreference. It is also an error to use a backreference while
+    /// case insensitive matching is enabled, e.g., `(?i)(a)\1`, since a
+    /// backreference always matches the text of its group exactly.
+    ///
+    /// Backreferences cannot be implemented by finite automata, so most
+    /// regex engines will refuse to compile an `Hir` containing them.
+    ///
+    /// See [`ast::parse::ParserBuilder::backreferences`] for details on the
+    /// concrete syntax.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_syntax::{hir::HirKind, ParserBuilder};
+    ///
+    /// let hir = ParserBuilder::new()
+    ///     .backreferences(true)
+    ///     .build()
+    ///     .parse(r#"(?<q>['"])\w+\k<q>"#)?;
+    /// let HirKind::Concat(ref subs) = *hir.kind() else { unreachable!() };
+    /// assert_eq!(subs[2].kind(), &HirKind::Backreference(1));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn backreferences(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.ast.backreferences(yes);
+        self
+    }
+
+    /// Enable possessive repetition operators, e.g., `a*+`, `a++`, `a?+`
+    /// and `a{m,n}+`.
+    ///
+    /// A possessive repetition is translated into an atomic group
+    /// containing the corresponding greedy repetition, i.e.,
+    /// [`HirKind::Atomic`](crate::hir::HirKind::Atomic). Atomic groups
+    /// written as `(?>a*)` are always supported, regardless of this setting.
+    ///
+    /// See [`ast::parse::ParserBuilder::possessive_quantifiers`] for details
+    /// on the concrete syntax.
+    ///
+    /// This is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_syntax::{hir::HirKind, ParserBuilder};
+    ///
+    /// let hir = ParserBuilder::new()
+    ///     .possessive_quantifiers(true)
+    ///     .build()
+    ///     .parse(r"a*+")?;
+    /// assert!(matches!(*hir.kind(), HirKind::Atomic(_)));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn possessive_quantifiers(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.ast.possessive_quantifiers(yes);
+        self
+    }
+
+    /// Set the dialect of concrete syntax accepted by the parser.
+    ///
+    /// The [`Dialect::Pcre`](crate::Dialect::Pcre) dialect accepts a number
+    /// of syntactic forms from PCRE and Oniguruma, such as `\Q...\E`, `\h`,
+    /// `\R`, `\Z` and `(?#comment)`, and translates them into the same `Hir`
+    /// as their equivalents in the default syntax. PCRE features that have
+    /// no equivalent, such as recursion or conditionals, are reported with
+    /// an error specific to that feature.
+    ///
+    /// See [`ast::parse::Dialect`] for the complete list.
+    ///
+    /// This is set to [`Dialect::Default`](crate::Dialect::Default) by
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex_syntax::{ast, hir::Hir, Dialect, Error, ParserBuilder};
+    ///
+    /// let mut builder = ParserBuilder::new();
+    /// builder.dialect(Dialect::Pcre);
+    ///
+    /// let hir = builder.build().parse(r"\Qa.b\E(?#a comment)")?;
+    /// assert_eq!(hir, Hir::literal("a.b".as_bytes()));
+    ///
+    /// let err = builder.build().parse(r"(a)(?1)").unwrap_err();
+    /// let Error::Parse(err) = err else { unreachable!() };
+    /// assert_eq!(err.kind(), &ast::ErrorKind::UnsupportedRecursion);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn dialect(&mut self, dialect: crate::Dialect) -> &mut ParserBuilder {
+        self.ast.dialect(dialect);
+        self
+    }
+
     /// When disabled, translation will permit the construction of a regular
     /// expression that may match invalid UTF-8.
     ///
@@ -130,6 +268,37 @@ impl ParserBuilder {
         self
     }
 
+    /// Enable or disable full case folding in case insensitive mode.
+    ///
+    /// By default this is disabled, and case insensitive matching uses
+    /// simple case folding. When enabled, a literal or non-negated class
+    /// that contains a codepoint whose full case folding is a sequence of
+    /// codepoints also matches anything that case folds to that sequence.
+    /// For example, `(?i)ß` matches `SS` and `(?i)ﬁ` matches `fi`.
+    /// Conversely, a sequence of case insensitive characters that spells out
+    /// such a case folding also matches the codepoint, so `(?i)ss` matches
+    /// `ß`.
+    ///
+    /// This only has an effect when Unicode mode and case insensitive mode
+    /// are both enabled.
+    pub fn full_case_folding(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.hir.full_case_folding(yes);
+        self
+    }
+
+    /// Enable or disable Turkic tailoring of case folding.
+    ///
+    /// By default this is disabled. When enabled, case insensitive matching
+    /// relates `I` to `ı` and `İ` to `i`, as is done in Turkish and
+    /// Azerbaijani, instead of relating `I` to `i`.
+    ///
+    /// This only has an effect when Unicode mode and case insensitive mode
+    /// are both enabled.
+    pub fn turkic_case_folding(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.hir.turkic_case_folding(yes);
+        self
+    }
+
     /// Enable or disable the multi-line matching flag by default.
     ///
     /// By default this is disabled. It may alternatively be selectively
@@ -165,6 +334,27 @@ impl ParserBuilder {
         self
     }
 
+    /// Enable or disable the grapheme mode flag by default.
+    ///
+    /// By default this is disabled. It may alternatively be selectively
+    /// enabled in the regular expression itself via the `g` flag.
+    ///
+    /// When grapheme mode is enabled (along with Unicode mode), `.` and
+    /// negated bracketed character classes like `[^a-z]` match an entire
+    /// extended grapheme cluster (just like `\X`) instead of a single
+    /// codepoint. The cluster's first codepoint must be one that would
+    /// otherwise have been matched. For example, `.` still doesn't match a
+    /// cluster starting with `\n` unless `dot_matches_new_line` is enabled.
+    ///
+    /// Each grapheme cluster translates to a fairly large expression, so
+    /// callers that compile the result should expect it to be much bigger
+    /// than a single codepoint. The rule for Indic conjunct sequences
+    /// (GB9c) isn't implemented.
+    pub fn grapheme(&mut self, yes: bool) -> &mut ParserBuilder {
+        self.hir.grapheme(yes);
+        self
+    }
+
     /// Sets the line terminator for use with `(?u-s:.)` and `(?-us:.)`.
     ///
     /// Namely, instead of `.` (by default) matching everything except for `\n`,
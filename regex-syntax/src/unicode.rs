@@ -64,6 +64,52 @@ impl core::fmt::Display for UnicodeWordError {
     }
 }
 
+/// An error that occurs when the Unicode text segmentation tables are
+/// unavailable.
+///
+/// This error can occur when the data tables necessary for looking up the
+/// `Word_Break`, `Sentence_Break` or `Extended_Pictographic` properties of a
+/// codepoint are unavailable. This only occurs when either the
+/// `unicode-segment` or `unicode-bool` features are disabled. (Both features
+/// are enabled by default.)
+#[derive(Debug)]
+pub struct UnicodeSegmentError(());
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnicodeSegmentError {}
+
+impl core::fmt::Display for UnicodeSegmentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Unicode text segmentation data is not available (probably \
+             because the unicode-segment or unicode-bool feature is not \
+             enabled)"
+        )
+    }
+}
+
+/// An error that occurs when the Unicode character name table is unavailable.
+///
+/// This error can occur when looking up a codepoint by its name, e.g., for
+/// `\N{GREEK SMALL LETTER ALPHA}`. This only occurs when the `unicode-name`
+/// feature is disabled. (The feature is enabled by default.)
+#[derive(Debug)]
+pub struct UnicodeNameError(());
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnicodeNameError {}
+
+impl core::fmt::Display for UnicodeNameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Unicode character names are not available \
+             (probably because the unicode-name feature is not enabled)"
+        )
+    }
+}
+
 /// A state oriented traverser of the simple case folding table.
 ///
 /// A case folder can be constructed via `SimpleCaseFolder::new()`, which will
@@ -200,6 +246,27 @@ impl SimpleCaseFolder {
     }
 }
 
+/// Returns the table of "full" case folding mappings.
+///
+/// Each entry maps a codepoint to the sequence of two or more codepoints it
+/// case folds to under full case folding (i.e., the `F` entries in
+/// `CaseFolding.txt`). Codepoints whose full case folding is a single
+/// codepoint aren't included, since their full and simple case foldings are
+/// the same. The table is sorted by codepoint.
+///
+/// This returns an error if the `unicode-case` feature is not enabled.
+pub fn full_case_folding_table(
+) -> Result<&'static [(char, &'static [char])], CaseFoldError> {
+    #[cfg(not(feature = "unicode-case"))]
+    {
+        Err(CaseFoldError(()))
+    }
+    #[cfg(feature = "unicode-case")]
+    {
+        Ok(crate::unicode_tables::case_folding_full::CASE_FOLDING_FULL)
+    }
+}
+
 /// A query for finding a character class defined by Unicode. This supports
 /// either use of a property name directly, or lookup by property value. The
 /// former generally refers to Binary properties (see UTS#44, Table 8), but
@@ -375,6 +442,18 @@ pub fn class(query: ClassQuery<'_>) -> Result<hir::ClassUnicode, Error> {
         ByValue { property_name: "Word_Break", property_value } => {
             wb(property_value)
         }
+        ByValue { property_name: "Block", property_value } => {
+            block(property_value)
+        }
+        ByValue { property_name: "East_Asian_Width", property_value } => {
+            east_asian_width(property_value)
+        }
+        ByValue { property_name: "Line_Break", property_value } => {
+            line_break(property_value)
+        }
+        ByValue { property_name: "Numeric_Type", property_value } => {
+            numeric_type(property_value)
+        }
         _ => {
             // What else should we support?
             Err(Error::PropertyNotFound)
@@ -448,6 +527,55 @@ pub fn perl_digit() -> Result<hir::ClassUnicode, Error> {
     imp()
 }
 
+/// The character classes needed to match a single extended grapheme cluster,
+/// as defined by [UAX #29].
+///
+/// Most of these are the values of the `Grapheme_Cluster_Break` property.
+///
+/// [UAX #29]: https://unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+#[derive(Debug)]
+pub struct GraphemeClasses {
+    pub cr: hir::ClassUnicode,
+    pub lf: hir::ClassUnicode,
+    pub control: hir::ClassUnicode,
+    pub extend: hir::ClassUnicode,
+    pub zwj: hir::ClassUnicode,
+    pub spacing_mark: hir::ClassUnicode,
+    pub prepend: hir::ClassUnicode,
+    pub regional_indicator: hir::ClassUnicode,
+    pub l: hir::ClassUnicode,
+    pub v: hir::ClassUnicode,
+    pub t: hir::ClassUnicode,
+    pub lv: hir::ClassUnicode,
+    pub lvt: hir::ClassUnicode,
+    /// The `Extended_Pictographic` boolean property.
+    pub extended_pictographic: hir::ClassUnicode,
+}
+
+/// Returns the character classes needed to match an extended grapheme
+/// cluster.
+///
+/// This returns an error if the `Grapheme_Cluster_Break` or boolean property
+/// data is not available.
+pub fn grapheme_classes() -> Result<GraphemeClasses, Error> {
+    Ok(GraphemeClasses {
+        cr: gcb("CR")?,
+        lf: gcb("LF")?,
+        control: gcb("Control")?,
+        extend: gcb("Extend")?,
+        zwj: gcb("ZWJ")?,
+        spacing_mark: gcb("SpacingMark")?,
+        prepend: gcb("Prepend")?,
+        regional_indicator: gcb("Regional_Indicator")?,
+        l: gcb("L")?,
+        v: gcb("V")?,
+        t: gcb("T")?,
+        lv: gcb("LV")?,
+        lvt: gcb("LVT")?,
+        extended_pictographic: bool_property("Extended_Pictographic")?,
+    })
+}
+
 /// Build a Unicode HIR class from a sequence of Unicode scalar value ranges.
 pub fn hir_class(ranges: &[(char, char)]) -> hir::ClassUnicode {
     let hir_ranges: Vec<hir::ClassUnicodeRange> = ranges
@@ -491,6 +619,99 @@ pub fn is_word_character(c: char) -> Result<bool, UnicodeWordError> {
     imp(c)
 }
 
+/// Returns the canonical name of the `Word_Break` property value of the
+/// given codepoint. Codepoints without an explicit value have the value
+/// `Other`.
+///
+/// If the `unicode-segment` feature is not enabled, then this returns an
+/// error.
+pub fn word_break(c: char) -> Result<&'static str, UnicodeSegmentError> {
+    #[cfg(not(feature = "unicode-segment"))]
+    fn imp(_: char) -> Result<&'static str, UnicodeSegmentError> {
+        Err(UnicodeSegmentError(()))
+    }
+
+    #[cfg(feature = "unicode-segment")]
+    fn imp(c: char) -> Result<&'static str, UnicodeSegmentError> {
+        use crate::unicode_tables::word_break::BY_NAME;
+        Ok(property_value_of(BY_NAME, c).unwrap_or("Other"))
+    }
+
+    imp(c)
+}
+
+/// Returns the canonical name of the `Sentence_Break` property value of the
+/// given codepoint. Codepoints without an explicit value have the value
+/// `Other`.
+///
+/// If the `unicode-segment` feature is not enabled, then this returns an
+/// error.
+pub fn sentence_break(c: char) -> Result<&'static str, UnicodeSegmentError> {
+    #[cfg(not(feature = "unicode-segment"))]
+    fn imp(_: char) -> Result<&'static str, UnicodeSegmentError> {
+        Err(UnicodeSegmentError(()))
+    }
+
+    #[cfg(feature = "unicode-segment")]
+    fn imp(c: char) -> Result<&'static str, UnicodeSegmentError> {
+        use crate::unicode_tables::sentence_break::BY_NAME;
+        Ok(property_value_of(BY_NAME, c).unwrap_or("Other"))
+    }
+
+    imp(c)
+}
+
+/// Returns true only if the given codepoint has the `Extended_Pictographic`
+/// property.
+///
+/// If the `unicode-bool` feature is not enabled, then this returns an error.
+pub fn is_extended_pictographic(c: char) -> Result<bool, UnicodeSegmentError> {
+    #[cfg(not(feature = "unicode-bool"))]
+    fn imp(_: char) -> Result<bool, UnicodeSegmentError> {
+        Err(UnicodeSegmentError(()))
+    }
+
+    #[cfg(feature = "unicode-bool")]
+    fn imp(c: char) -> Result<bool, UnicodeSegmentError> {
+        use crate::unicode_tables::property_bool::EXTENDED_PICTOGRAPHIC;
+        Ok(ranges_contain(EXTENDED_PICTOGRAPHIC, c))
+    }
+
+    imp(c)
+}
+
+/// Returns the name of the property value whose codepoint ranges contain
+/// the given codepoint, if one exists.
+#[cfg(feature = "unicode-segment")]
+fn property_value_of(
+    by_name: &'static [(&'static str, Range)],
+    c: char,
+) -> Option<&'static str> {
+    by_name
+        .iter()
+        .find(|&&(_, ranges)| ranges_contain(ranges, c))
+        .map(|&(name, _)| name)
+}
+
+/// Returns true if and only if the given sorted ranges contain the given
+/// codepoint.
+#[cfg(any(feature = "unicode-segment", feature = "unicode-bool"))]
+fn ranges_contain(ranges: Range, c: char) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| {
+            use core::cmp::Ordering;
+
+            if start <= c && c <= end {
+                Ordering::Equal
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        })
+        .is_ok()
+}
+
 /// A mapping of property values for a specific property.
 ///
 /// The first element of each tuple is a normalized property value while the
@@ -532,8 +753,12 @@ fn canonical_prop(
 ) -> Result<Option<&'static str>, Error> {
     #[cfg(not(any(
         feature = "unicode-age",
+        feature = "unicode-block",
         feature = "unicode-bool",
+        feature = "unicode-east-asian-width",
         feature = "unicode-gencat",
+        feature = "unicode-line-break",
+        feature = "unicode-numeric-type",
         feature = "unicode-perl",
         feature = "unicode-script",
         feature = "unicode-segment",
@@ -544,8 +769,12 @@ fn canonical_prop(
 
     #[cfg(any(
         feature = "unicode-age",
+        feature = "unicode-block",
         feature = "unicode-bool",
+        feature = "unicode-east-asian-width",
         feature = "unicode-gencat",
+        feature = "unicode-line-break",
+        feature = "unicode-numeric-type",
         feature = "unicode-perl",
         feature = "unicode-script",
         feature = "unicode-segment",
@@ -589,8 +818,12 @@ fn property_values(
 ) -> Result<Option<PropertyValues>, Error> {
     #[cfg(not(any(
         feature = "unicode-age",
+        feature = "unicode-block",
         feature = "unicode-bool",
+        feature = "unicode-east-asian-width",
         feature = "unicode-gencat",
+        feature = "unicode-line-break",
+        feature = "unicode-numeric-type",
         feature = "unicode-perl",
         feature = "unicode-script",
         feature = "unicode-segment",
@@ -601,8 +834,12 @@ fn property_values(
 
     #[cfg(any(
         feature = "unicode-age",
+        feature = "unicode-block",
         feature = "unicode-bool",
+        feature = "unicode-east-asian-width",
         feature = "unicode-gencat",
+        feature = "unicode-line-break",
+        feature = "unicode-numeric-type",
         feature = "unicode-perl",
         feature = "unicode-script",
         feature = "unicode-segment",
@@ -875,6 +1112,235 @@ fn sb(canonical_name: &'static str) -> Result<hir::ClassUnicode, Error> {
     imp(canonical_name)
 }
 
+/// Returns the Unicode HIR class corresponding to the given block.
+///
+/// Name canonicalization is assumed to be performed by the caller.
+///
+/// If the given property could not be found, or if the corresponding data is
+/// not available, then an error is returned.
+fn block(canonical_name: &'static str) -> Result<hir::ClassUnicode, Error> {
+    #[cfg(not(feature = "unicode-block"))]
+    fn imp(_: &'static str) -> Result<hir::ClassUnicode, Error> {
+        Err(Error::PropertyNotFound)
+    }
+
+    #[cfg(feature = "unicode-block")]
+    fn imp(name: &'static str) -> Result<hir::ClassUnicode, Error> {
+        use crate::unicode_tables::block::BY_NAME;
+        property_set(BY_NAME, name)
+            .map(hir_class)
+            .ok_or(Error::PropertyValueNotFound)
+    }
+
+    imp(canonical_name)
+}
+
+/// Returns the Unicode HIR class corresponding to the given East Asian width
+/// property.
+///
+/// Name canonicalization is assumed to be performed by the caller.
+///
+/// If the given property could not be found, or if the corresponding data is
+/// not available, then an error is returned.
+fn east_asian_width(
+    canonical_name: &'static str,
+) -> Result<hir::ClassUnicode, Error> {
+    #[cfg(not(feature = "unicode-east-asian-width"))]
+    fn imp(_: &'static str) -> Result<hir::ClassUnicode, Error> {
+        Err(Error::PropertyNotFound)
+    }
+
+    #[cfg(feature = "unicode-east-asian-width")]
+    fn imp(name: &'static str) -> Result<hir::ClassUnicode, Error> {
+        use crate::unicode_tables::east_asian_width::BY_NAME;
+        property_set(BY_NAME, name)
+            .map(hir_class)
+            .ok_or(Error::PropertyValueNotFound)
+    }
+
+    imp(canonical_name)
+}
+
+/// Returns the Unicode HIR class corresponding to the given line break
+/// property.
+///
+/// Name canonicalization is assumed to be performed by the caller.
+///
+/// If the given property could not be found, or if the corresponding data is
+/// not available, then an error is returned.
+fn line_break(
+    canonical_name: &'static str,
+) -> Result<hir::ClassUnicode, Error> {
+    #[cfg(not(feature = "unicode-line-break"))]
+    fn imp(_: &'static str) -> Result<hir::ClassUnicode, Error> {
+        Err(Error::PropertyNotFound)
+    }
+
+    #[cfg(feature = "unicode-line-break")]
+    fn imp(name: &'static str) -> Result<hir::ClassUnicode, Error> {
+        use crate::unicode_tables::line_break::BY_NAME;
+        property_set(BY_NAME, name)
+            .map(hir_class)
+            .ok_or(Error::PropertyValueNotFound)
+    }
+
+    imp(canonical_name)
+}
+
+/// Returns the Unicode HIR class corresponding to the given numeric type
+/// property.
+///
+/// Name canonicalization is assumed to be performed by the caller.
+///
+/// If the given property could not be found, or if the corresponding data is
+/// not available, then an error is returned.
+fn numeric_type(
+    canonical_name: &'static str,
+) -> Result<hir::ClassUnicode, Error> {
+    #[cfg(not(feature = "unicode-numeric-type"))]
+    fn imp(_: &'static str) -> Result<hir::ClassUnicode, Error> {
+        Err(Error::PropertyNotFound)
+    }
+
+    #[cfg(feature = "unicode-numeric-type")]
+    fn imp(name: &'static str) -> Result<hir::ClassUnicode, Error> {
+        use crate::unicode_tables::numeric_type::BY_NAME;
+        property_set(BY_NAME, name)
+            .map(hir_class)
+            .ok_or(Error::PropertyValueNotFound)
+    }
+
+    imp(canonical_name)
+}
+
+/// Looks up a codepoint by its Unicode name or by one of its name aliases.
+///
+/// Names are matched loosely according to UAX44-LM2. That is, case,
+/// whitespace, underscores and medial hyphens are ignored, so
+/// `greek small letter alpha` and `GREEK_SMALL_LETTER_ALPHA` both refer to
+/// `α`. Names derived from the codepoint, like `CJK UNIFIED IDEOGRAPH-4E00`,
+/// and Hangul syllable names, like `HANGUL SYLLABLE GA`, are supported too.
+///
+/// If no codepoint has the given name, then `None` is returned. If the name
+/// data is not available, then an error is returned. This only occurs when
+/// the `unicode-name` feature is disabled.
+pub fn character_by_name(
+    name: &str,
+) -> Result<Option<char>, UnicodeNameError> {
+    #[cfg(not(feature = "unicode-name"))]
+    fn imp(_: &str) -> Result<Option<char>, UnicodeNameError> {
+        Err(UnicodeNameError(()))
+    }
+
+    #[cfg(feature = "unicode-name")]
+    fn imp(name: &str) -> Result<Option<char>, UnicodeNameError> {
+        use crate::unicode_tables::name::{DERIVED_NAMES, NAMES};
+
+        let key = character_name_normalize(name);
+        // This is the one exception to ignoring medial hyphens, since it
+        // would otherwise collide with U+116C HANGUL JUNGSEONG OE. The name
+        // table leaves it out for that reason.
+        if key == "HANGULJUNGSEONGOE" && name.contains('-') {
+            return Ok(Some('\u{1180}'));
+        }
+        if let Ok(i) = NAMES.binary_search_by_key(&key.as_str(), |x| x.0) {
+            return Ok(Some(NAMES[i].1));
+        }
+        if let Some(rest) = key.strip_prefix("HANGULSYLLABLE") {
+            return Ok(hangul_syllable(rest));
+        }
+        for &(prefix, start, end) in DERIVED_NAMES.iter() {
+            let hex = match key.strip_prefix(prefix) {
+                None => continue,
+                Some(hex) => hex,
+            };
+            // The codepoint is always written with at least 4 uppercase
+            // hex digits and no leading zeros beyond that.
+            let cp = match u32::from_str_radix(hex, 16) {
+                Err(_) => continue,
+                Ok(cp) => cp,
+            };
+            if alloc::format!("{:04X}", cp) != hex {
+                continue;
+            }
+            if (u32::from(start)..=u32::from(end)).contains(&cp) {
+                return Ok(char::from_u32(cp));
+            }
+        }
+        Ok(None)
+    }
+
+    imp(name)
+}
+
+/// Returns the Hangul syllable whose name is `HANGUL SYLLABLE` followed by
+/// the name given, with its Jamo short names concatenated.
+///
+/// See Section 3.12 of the Unicode Standard for how syllable names are
+/// derived.
+#[cfg(feature = "unicode-name")]
+fn hangul_syllable(name: &str) -> Option<char> {
+    const LEADING: &[&str] = &[
+        "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J",
+        "JJ", "C", "K", "T", "P", "H",
+    ];
+    const VOWEL: &[&str] = &[
+        "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE",
+        "OE", "YO", "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I",
+    ];
+    const TRAILING: &[&str] = &[
+        "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB",
+        "LS", "LT", "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C",
+        "K", "T", "P", "H",
+    ];
+
+    for (l, leading) in LEADING.iter().enumerate() {
+        let rest = match name.strip_prefix(leading) {
+            None => continue,
+            Some(rest) => rest,
+        };
+        for (v, vowel) in VOWEL.iter().enumerate() {
+            let rest = match rest.strip_prefix(vowel) {
+                None => continue,
+                Some(rest) => rest,
+            };
+            if let Some(t) = TRAILING.iter().position(|&t| t == rest) {
+                let index = (l * VOWEL.len() + v) * TRAILING.len() + t;
+                return char::from_u32(0xAC00 + u32::try_from(index).ok()?);
+            }
+        }
+    }
+    None
+}
+
+/// Normalize the given character name according to UAX44-LM2, except for
+/// the special case of U+1180 HANGUL JUNGSEONG O-E, which is handled by the
+/// caller.
+///
+/// That is, whitespace, underscores and medial hyphens (those between two
+/// letters or digits) are removed, and letters are uppercased. Since all
+/// character names are ASCII, non-ASCII characters are kept as is, which
+/// means they never match anything.
+#[cfg(feature = "unicode-name")]
+fn character_name_normalize(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut normalized = String::with_capacity(name.len());
+    for (i, ch) in name.char_indices() {
+        if ch.is_whitespace() || ch == '_' {
+            continue;
+        }
+        if ch == '-'
+            && i > 0
+            && bytes[i - 1].is_ascii_alphanumeric()
+            && bytes.get(i + 1).map_or(false, |b| b.is_ascii_alphanumeric())
+        {
+            continue;
+        }
+        normalized.push(ch.to_ascii_uppercase());
+    }
+    normalized
+}
+
 /// Like symbolic_name_normalize_bytes, but operates on a string.
 fn symbolic_name_normalize(x: &str) -> String {
     let mut tmp = x.as_bytes().to_vec();
@@ -1015,6 +1481,55 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "unicode-name")]
+    fn character_names() {
+        let name = |n| character_by_name(n).unwrap();
+
+        assert_eq!(name("LATIN SMALL LETTER A"), Some('a'));
+        assert_eq!(name("latin_small_letter_a"), Some('a'));
+        assert_eq!(name("Latin Small LetterA"), Some('a'));
+        assert_eq!(name("LATIN SMALL LETTER"), None);
+        assert_eq!(name("LATIN SMALL LETTER Á"), None);
+        assert_eq!(name(""), None);
+        // Aliases work too.
+        assert_eq!(name("NULL"), Some('\0'));
+        assert_eq!(name("LINE FEED"), Some('\n'));
+        assert_eq!(name("LF"), Some('\n'));
+        assert_eq!(name("BYTE ORDER MARK"), Some('\u{FEFF}'));
+
+        // Medial hyphens are ignored, but other hyphens aren't.
+        assert_eq!(name("ZERO WIDTH NO-BREAK SPACE"), Some('\u{FEFF}'));
+        assert_eq!(name("ZERO WIDTH NOBREAK SPACE"), Some('\u{FEFF}'));
+        assert_eq!(name("TIBETAN MARK TSA -PHRU"), Some('\u{F39}'));
+        assert_eq!(name("TIBETAN MARK TSA PHRU"), None);
+        assert_eq!(name("HANGUL JUNGSEONG OE"), Some('\u{116C}'));
+        assert_eq!(name("HANGUL JUNGSEONG O-E"), Some('\u{1180}'));
+
+        // Names derived from the codepoint.
+        assert_eq!(name("CJK UNIFIED IDEOGRAPH-4E00"), Some('一'));
+        assert_eq!(name("cjk unified ideograph-4e00"), Some('一'));
+        assert_eq!(name("CJK UNIFIED IDEOGRAPH-04E00"), None);
+        assert_eq!(name("CJK UNIFIED IDEOGRAPH-0041"), None);
+        assert_eq!(name("CJK UNIFIED IDEOGRAPH-20000"), Some('\u{20000}'));
+        assert_eq!(name("CJK COMPATIBILITY IDEOGRAPH-F900"), Some('\u{F900}'));
+        assert_eq!(name("TANGUT IDEOGRAPH-17000"), Some('\u{17000}'));
+
+        // Hangul syllables.
+        assert_eq!(name("HANGUL SYLLABLE GA"), Some('가'));
+        assert_eq!(name("HANGUL SYLLABLE GAG"), Some('각'));
+        assert_eq!(name("HANGUL SYLLABLE A"), Some('아'));
+        assert_eq!(name("HANGUL SYLLABLE HIH"), Some('힣'));
+        assert_eq!(name("HANGUL SYLLABLE"), None);
+        assert_eq!(name("HANGUL SYLLABLE GAX"), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode-name"))]
+    fn character_names_disabled() {
+        assert!(character_by_name("LATIN SMALL LETTER A").is_err());
+    }
+
     #[test]
     fn sym_normalize() {
         let sym_norm = symbolic_name_normalize;
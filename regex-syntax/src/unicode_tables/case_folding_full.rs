@@ -0,0 +1,114 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   regex-cli generate unicode
+//
+// from the full (F) mappings in CaseFolding.txt.
+//
+// Unicode version: 16.0.0.
+
+pub const CASE_FOLDING_FULL: &'static [(char, &'static [char])] = &[
+    ('ß', &['s', 's']),
+    ('İ', &['i', '\u{307}']),
+    ('ŉ', &['ʼ', 'n']),
+    ('ǰ', &['j', '\u{30c}']),
+    ('ΐ', &['ι', '\u{308}', '\u{301}']),
+    ('ΰ', &['υ', '\u{308}', '\u{301}']),
+    ('և', &['ե', 'ւ']),
+    ('ẖ', &['h', '\u{331}']),
+    ('ẗ', &['t', '\u{308}']),
+    ('ẘ', &['w', '\u{30a}']),
+    ('ẙ', &['y', '\u{30a}']),
+    ('ẚ', &['a', 'ʾ']),
+    ('ẞ', &['s', 's']),
+    ('ὐ', &['υ', '\u{313}']),
+    ('ὒ', &['υ', '\u{313}', '\u{300}']),
+    ('ὔ', &['υ', '\u{313}', '\u{301}']),
+    ('ὖ', &['υ', '\u{313}', '\u{342}']),
+    ('ᾀ', &['ἀ', 'ι']),
+    ('ᾁ', &['ἁ', 'ι']),
+    ('ᾂ', &['ἂ', 'ι']),
+    ('ᾃ', &['ἃ', 'ι']),
+    ('ᾄ', &['ἄ', 'ι']),
+    ('ᾅ', &['ἅ', 'ι']),
+    ('ᾆ', &['ἆ', 'ι']),
+    ('ᾇ', &['ἇ', 'ι']),
+    ('ᾈ', &['ἀ', 'ι']),
+    ('ᾉ', &['ἁ', 'ι']),
+    ('ᾊ', &['ἂ', 'ι']),
+    ('ᾋ', &['ἃ', 'ι']),
+    ('ᾌ', &['ἄ', 'ι']),
+    ('ᾍ', &['ἅ', 'ι']),
+    ('ᾎ', &['ἆ', 'ι']),
+    ('ᾏ', &['ἇ', 'ι']),
+    ('ᾐ', &['ἠ', 'ι']),
+    ('ᾑ', &['ἡ', 'ι']),
+    ('ᾒ', &['ἢ', 'ι']),
+    ('ᾓ', &['ἣ', 'ι']),
+    ('ᾔ', &['ἤ', 'ι']),
+    ('ᾕ', &['ἥ', 'ι']),
+    ('ᾖ', &['ἦ', 'ι']),
+    ('ᾗ', &['ἧ', 'ι']),
+    ('ᾘ', &['ἠ', 'ι']),
+    ('ᾙ', &['ἡ', 'ι']),
+    ('ᾚ', &['ἢ', 'ι']),
+    ('ᾛ', &['ἣ', 'ι']),
+    ('ᾜ', &['ἤ', 'ι']),
+    ('ᾝ', &['ἥ', 'ι']),
+    ('ᾞ', &['ἦ', 'ι']),
+    ('ᾟ', &['ἧ', 'ι']),
+    ('ᾠ', &['ὠ', 'ι']),
+    ('ᾡ', &['ὡ', 'ι']),
+    ('ᾢ', &['ὢ', 'ι']),
+    ('ᾣ', &['ὣ', 'ι']),
+    ('ᾤ', &['ὤ', 'ι']),
+    ('ᾥ', &['ὥ', 'ι']),
+    ('ᾦ', &['ὦ', 'ι']),
+    ('ᾧ', &['ὧ', 'ι']),
+    ('ᾨ', &['ὠ', 'ι']),
+    ('ᾩ', &['ὡ', 'ι']),
+    ('ᾪ', &['ὢ', 'ι']),
+    ('ᾫ', &['ὣ', 'ι']),
+    ('ᾬ', &['ὤ', 'ι']),
+    ('ᾭ', &['ὥ', 'ι']),
+    ('ᾮ', &['ὦ', 'ι']),
+    ('ᾯ', &['ὧ', 'ι']),
+    ('ᾲ', &['ὰ', 'ι']),
+    ('ᾳ', &['α', 'ι']),
+    ('ᾴ', &['ά', 'ι']),
+    ('ᾶ', &['α', '\u{342}']),
+    ('ᾷ', &['α', '\u{342}', 'ι']),
+    ('ᾼ', &['α', 'ι']),
+    ('ῂ', &['ὴ', 'ι']),
+    ('ῃ', &['η', 'ι']),
+    ('ῄ', &['ή', 'ι']),
+    ('ῆ', &['η', '\u{342}']),
+    ('ῇ', &['η', '\u{342}', 'ι']),
+    ('ῌ', &['η', 'ι']),
+    ('ῒ', &['ι', '\u{308}', '\u{300}']),
+    ('ΐ', &['ι', '\u{308}', '\u{301}']),
+    ('ῖ', &['ι', '\u{342}']),
+    ('ῗ', &['ι', '\u{308}', '\u{342}']),
+    ('ῢ', &['υ', '\u{308}', '\u{300}']),
+    ('ΰ', &['υ', '\u{308}', '\u{301}']),
+    ('ῤ', &['ρ', '\u{313}']),
+    ('ῦ', &['υ', '\u{342}']),
+    ('ῧ', &['υ', '\u{308}', '\u{342}']),
+    ('ῲ', &['ὼ', 'ι']),
+    ('ῳ', &['ω', 'ι']),
+    ('ῴ', &['ώ', 'ι']),
+    ('ῶ', &['ω', '\u{342}']),
+    ('ῷ', &['ω', '\u{342}', 'ι']),
+    ('ῼ', &['ω', 'ι']),
+    ('ﬀ', &['f', 'f']),
+    ('ﬁ', &['f', 'i']),
+    ('ﬂ', &['f', 'l']),
+    ('ﬃ', &['f', 'f', 'i']),
+    ('ﬄ', &['f', 'f', 'l']),
+    ('ﬅ', &['s', 't']),
+    ('ﬆ', &['s', 't']),
+    ('ﬓ', &['մ', 'ն']),
+    ('ﬔ', &['մ', 'ե']),
+    ('ﬕ', &['մ', 'ի']),
+    ('ﬖ', &['վ', 'ն']),
+    ('ﬗ', &['մ', 'խ']),
+];
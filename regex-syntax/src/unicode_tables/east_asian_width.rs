@@ -0,0 +1,631 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   regex-cli generate unicode
+//
+// from EastAsianWidth.txt.
+//
+// Unicode version: 16.0.0.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(char, char)])] = &[
+    ("Ambiguous", AMBIGUOUS),
+    ("Fullwidth", FULLWIDTH),
+    ("Halfwidth", HALFWIDTH),
+    ("Narrow", NARROW),
+    ("Neutral", NEUTRAL),
+    ("Wide", WIDE),
+];
+
+pub const AMBIGUOUS: &'static [(char, char)] = &[
+    ('¡', '¡'),
+    ('¤', '¤'),
+    ('§', '¨'),
+    ('ª', 'ª'),
+    ('\u{ad}', '®'),
+    ('°', '´'),
+    ('¶', 'º'),
+    ('¼', '¿'),
+    ('Æ', 'Æ'),
+    ('Ð', 'Ð'),
+    ('×', 'Ø'),
+    ('Þ', 'á'),
+    ('æ', 'æ'),
+    ('è', 'ê'),
+    ('ì', 'í'),
+    ('ð', 'ð'),
+    ('ò', 'ó'),
+    ('÷', 'ú'),
+    ('ü', 'ü'),
+    ('þ', 'þ'),
+    ('ā', 'ā'),
+    ('đ', 'đ'),
+    ('ē', 'ē'),
+    ('ě', 'ě'),
+    ('Ħ', 'ħ'),
+    ('ī', 'ī'),
+    ('ı', 'ĳ'),
+    ('ĸ', 'ĸ'),
+    ('Ŀ', 'ł'),
+    ('ń', 'ń'),
+    ('ň', 'ŋ'),
+    ('ō', 'ō'),
+    ('Œ', 'œ'),
+    ('Ŧ', 'ŧ'),
+    ('ū', 'ū'),
+    ('ǎ', 'ǎ'),
+    ('ǐ', 'ǐ'),
+    ('ǒ', 'ǒ'),
+    ('ǔ', 'ǔ'),
+    ('ǖ', 'ǖ'),
+    ('ǘ', 'ǘ'),
+    ('ǚ', 'ǚ'),
+    ('ǜ', 'ǜ'),
+    ('ɑ', 'ɑ'),
+    ('ɡ', 'ɡ'),
+    ('˄', '˄'),
+    ('ˇ', 'ˇ'),
+    ('ˉ', 'ˋ'),
+    ('ˍ', 'ˍ'),
+    ('ː', 'ː'),
+    ('˘', '˛'),
+    ('˝', '˝'),
+    ('˟', '˟'),
+    ('\u{300}', '\u{36f}'),
+    ('Α', 'Ρ'),
+    ('Σ', 'Ω'),
+    ('α', 'ρ'),
+    ('σ', 'ω'),
+    ('Ё', 'Ё'),
+    ('А', 'я'),
+    ('ё', 'ё'),
+    ('‐', '‐'),
+    ('–', '‖'),
+    ('‘', '’'),
+    ('“', '”'),
+    ('†', '•'),
+    ('․', '‧'),
+    ('‰', '‰'),
+    ('′', '″'),
+    ('‵', '‵'),
+    ('※', '※'),
+    ('‾', '‾'),
+    ('⁴', '⁴'),
+    ('ⁿ', 'ⁿ'),
+    ('₁', '₄'),
+    ('€', '€'),
+    ('℃', '℃'),
+    ('℅', '℅'),
+    ('℉', '℉'),
+    ('ℓ', 'ℓ'),
+    ('№', '№'),
+    ('℡', '™'),
+    ('Ω', 'Ω'),
+    ('Å', 'Å'),
+    ('⅓', '⅔'),
+    ('⅛', '⅞'),
+    ('Ⅰ', 'Ⅻ'),
+    ('ⅰ', 'ⅹ'),
+    ('↉', '↉'),
+    ('←', '↙'),
+    ('↸', '↹'),
+    ('⇒', '⇒'),
+    ('⇔', '⇔'),
+    ('⇧', '⇧'),
+    ('∀', '∀'),
+    ('∂', '∃'),
+    ('∇', '∈'),
+    ('∋', '∋'),
+    ('∏', '∏'),
+    ('∑', '∑'),
+    ('∕', '∕'),
+    ('√', '√'),
+    ('∝', '∠'),
+    ('∣', '∣'),
+    ('∥', '∥'),
+    ('∧', '∬'),
+    ('∮', '∮'),
+    ('∴', '∷'),
+    ('∼', '∽'),
+    ('≈', '≈'),
+    ('≌', '≌'),
+    ('≒', '≒'),
+    ('≠', '≡'),
+    ('≤', '≧'),
+    ('≪', '≫'),
+    ('≮', '≯'),
+    ('⊂', '⊃'),
+    ('⊆', '⊇'),
+    ('⊕', '⊕'),
+    ('⊙', '⊙'),
+    ('⊥', '⊥'),
+    ('⊿', '⊿'),
+    ('⌒', '⌒'),
+    ('①', 'ⓩ'),
+    ('⓫', '╋'),
+    ('═', '╳'),
+    ('▀', '▏'),
+    ('▒', '▕'),
+    ('■', '□'),
+    ('▣', '▩'),
+    ('▲', '△'),
+    ('▶', '▷'),
+    ('▼', '▽'),
+    ('◀', '◁'),
+    ('◆', '◈'),
+    ('○', '○'),
+    ('◎', '◑'),
+    ('◢', '◥'),
+    ('◯', '◯'),
+    ('★', '☆'),
+    ('☉', '☉'),
+    ('☎', '☏'),
+    ('☜', '☜'),
+    ('☞', '☞'),
+    ('♀', '♀'),
+    ('♂', '♂'),
+    ('♠', '♡'),
+    ('♣', '♥'),
+    ('♧', '♪'),
+    ('♬', '♭'),
+    ('♯', '♯'),
+    ('⚞', '⚟'),
+    ('⚿', '⚿'),
+    ('⛆', '⛍'),
+    ('⛏', '⛓'),
+    ('⛕', '⛡'),
+    ('⛣', '⛣'),
+    ('⛨', '⛩'),
+    ('⛫', '⛱'),
+    ('⛴', '⛴'),
+    ('⛶', '⛹'),
+    ('⛻', '⛼'),
+    ('⛾', '⛿'),
+    ('✽', '✽'),
+    ('❶', '❿'),
+    ('⭖', '⭙'),
+    ('㉈', '㉏'),
+    ('\u{e000}', '\u{f8ff}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('�', '�'),
+    ('🄀', '🄊'),
+    ('🄐', '🄭'),
+    ('🄰', '🅩'),
+    ('🅰', '🆍'),
+    ('🆏', '🆐'),
+    ('🆛', '🆬'),
+    ('\u{e0100}', '\u{e01ef}'),
+    ('\u{f0000}', '\u{ffffd}'),
+    ('\u{100000}', '\u{10fffd}'),
+];
+
+pub const FULLWIDTH: &'static [(char, char)] =
+    &[('\u{3000}', '\u{3000}'), ('！', '｠'), ('￠', '￦')];
+
+pub const HALFWIDTH: &'static [(char, char)] = &[
+    ('₩', '₩'),
+    ('｡', 'ﾾ'),
+    ('ￂ', 'ￇ'),
+    ('ￊ', 'ￏ'),
+    ('ￒ', 'ￗ'),
+    ('ￚ', 'ￜ'),
+    ('￨', '￮'),
+];
+
+pub const NARROW: &'static [(char, char)] = &[
+    (' ', '~'),
+    ('¢', '£'),
+    ('¥', '¦'),
+    ('¬', '¬'),
+    ('¯', '¯'),
+    ('⟦', '⟭'),
+    ('⦅', '⦆'),
+];
+
+pub const NEUTRAL: &'static [(char, char)] = &[
+    ('\0', '\u{1f}'),
+    ('\u{7f}', '\u{a0}'),
+    ('©', '©'),
+    ('«', '«'),
+    ('µ', 'µ'),
+    ('»', '»'),
+    ('À', 'Å'),
+    ('Ç', 'Ï'),
+    ('Ñ', 'Ö'),
+    ('Ù', 'Ý'),
+    ('â', 'å'),
+    ('ç', 'ç'),
+    ('ë', 'ë'),
+    ('î', 'ï'),
+    ('ñ', 'ñ'),
+    ('ô', 'ö'),
+    ('û', 'û'),
+    ('ý', 'ý'),
+    ('ÿ', 'Ā'),
+    ('Ă', 'Đ'),
+    ('Ē', 'Ē'),
+    ('Ĕ', 'Ě'),
+    ('Ĝ', 'ĥ'),
+    ('Ĩ', 'Ī'),
+    ('Ĭ', 'İ'),
+    ('Ĵ', 'ķ'),
+    ('Ĺ', 'ľ'),
+    ('Ń', 'Ń'),
+    ('Ņ', 'Ň'),
+    ('Ō', 'Ō'),
+    ('Ŏ', 'ő'),
+    ('Ŕ', 'ť'),
+    ('Ũ', 'Ū'),
+    ('Ŭ', 'Ǎ'),
+    ('Ǐ', 'Ǐ'),
+    ('Ǒ', 'Ǒ'),
+    ('Ǔ', 'Ǔ'),
+    ('Ǖ', 'Ǖ'),
+    ('Ǘ', 'Ǘ'),
+    ('Ǚ', 'Ǚ'),
+    ('Ǜ', 'Ǜ'),
+    ('ǝ', 'ɐ'),
+    ('ɒ', 'ɠ'),
+    ('ɢ', '˃'),
+    ('˅', 'ˆ'),
+    ('ˈ', 'ˈ'),
+    ('ˌ', 'ˌ'),
+    ('ˎ', 'ˏ'),
+    ('ˑ', '˗'),
+    ('˜', '˜'),
+    ('˞', '˞'),
+    ('ˠ', '˿'),
+    ('Ͱ', 'ΐ'),
+    ('\u{3a2}', '\u{3a2}'),
+    ('Ϊ', 'ΰ'),
+    ('ς', 'ς'),
+    ('ϊ', 'Ѐ'),
+    ('Ђ', 'Џ'),
+    ('ѐ', 'ѐ'),
+    ('ђ', 'ჿ'),
+    ('ᅠ', '\u{200f}'),
+    ('‑', '‒'),
+    ('‗', '‗'),
+    ('‚', '‛'),
+    ('„', '‟'),
+    ('‣', '‣'),
+    ('\u{2028}', '\u{202f}'),
+    ('‱', '‱'),
+    ('‴', '‴'),
+    ('‶', '›'),
+    ('‼', '‽'),
+    ('‿', '\u{2073}'),
+    ('⁵', '⁾'),
+    ('₀', '₀'),
+    ('₅', '₨'),
+    ('₪', '₫'),
+    ('₭', 'ℂ'),
+    ('℄', '℄'),
+    ('℆', '℈'),
+    ('ℊ', 'ℒ'),
+    ('℔', 'ℕ'),
+    ('℗', '℠'),
+    ('℣', '℥'),
+    ('℧', 'K'),
+    ('ℬ', '⅒'),
+    ('⅕', '⅚'),
+    ('⅟', '⅟'),
+    ('Ⅼ', 'Ⅿ'),
+    ('ⅺ', 'ↈ'),
+    ('↊', '\u{218f}'),
+    ('↚', '↷'),
+    ('↺', '⇑'),
+    ('⇓', '⇓'),
+    ('⇕', '⇦'),
+    ('⇨', '⇿'),
+    ('∁', '∁'),
+    ('∄', '∆'),
+    ('∉', '∊'),
+    ('∌', '∎'),
+    ('∐', '∐'),
+    ('−', '∔'),
+    ('∖', '∙'),
+    ('∛', '∜'),
+    ('∡', '∢'),
+    ('∤', '∤'),
+    ('∦', '∦'),
+    ('∭', '∭'),
+    ('∯', '∳'),
+    ('∸', '∻'),
+    ('∾', '≇'),
+    ('≉', '≋'),
+    ('≍', '≑'),
+    ('≓', '≟'),
+    ('≢', '≣'),
+    ('≨', '≩'),
+    ('≬', '≭'),
+    ('≰', '⊁'),
+    ('⊄', '⊅'),
+    ('⊈', '⊔'),
+    ('⊖', '⊘'),
+    ('⊚', '⊤'),
+    ('⊦', '⊾'),
+    ('⋀', '⌑'),
+    ('⌓', '⌙'),
+    ('⌜', '⌨'),
+    ('⌫', '⏨'),
+    ('⏭', '⏯'),
+    ('⏱', '⏲'),
+    ('⏴', '\u{245f}'),
+    ('⓪', '⓪'),
+    ('╌', '╏'),
+    ('╴', '╿'),
+    ('▐', '░'),
+    ('▖', '▟'),
+    ('▢', '▢'),
+    ('▪', '▱'),
+    ('▴', '▵'),
+    ('▸', '▻'),
+    ('▾', '▿'),
+    ('◂', '◅'),
+    ('◉', '◊'),
+    ('◌', '◍'),
+    ('◒', '◡'),
+    ('◦', '◮'),
+    ('◰', '◼'),
+    ('◿', '☄'),
+    ('☇', '☈'),
+    ('☊', '☍'),
+    ('☐', '☓'),
+    ('☖', '☛'),
+    ('☝', '☝'),
+    ('☟', '☯'),
+    ('☸', '☿'),
+    ('♁', '♁'),
+    ('♃', '♇'),
+    ('♔', '♟'),
+    ('♢', '♢'),
+    ('♦', '♦'),
+    ('♫', '♫'),
+    ('♮', '♮'),
+    ('♰', '♾'),
+    ('⚀', '⚉'),
+    ('⚐', '⚒'),
+    ('⚔', '⚝'),
+    ('⚠', '⚠'),
+    ('⚢', '⚩'),
+    ('⚬', '⚼'),
+    ('⛀', '⛃'),
+    ('⛢', '⛢'),
+    ('⛤', '⛧'),
+    ('✀', '✄'),
+    ('✆', '✉'),
+    ('✌', '✧'),
+    ('✩', '✼'),
+    ('✾', '❋'),
+    ('❍', '❍'),
+    ('❏', '❒'),
+    ('❖', '❖'),
+    ('❘', '❵'),
+    ('➀', '➔'),
+    ('➘', '➯'),
+    ('➱', '➾'),
+    ('⟀', '⟥'),
+    ('⟮', '⦄'),
+    ('⦇', '⬚'),
+    ('⬝', '⭏'),
+    ('⭑', '⭔'),
+    ('⭚', '\u{2e7f}'),
+    ('\u{2e9a}', '\u{2e9a}'),
+    ('\u{2ef4}', '\u{2eff}'),
+    ('\u{2fd6}', '\u{2fef}'),
+    ('〿', '\u{3040}'),
+    ('\u{3097}', '\u{3098}'),
+    ('\u{3100}', '\u{3104}'),
+    ('\u{3130}', '\u{3130}'),
+    ('\u{318f}', '\u{318f}'),
+    ('\u{31e6}', '\u{31ee}'),
+    ('\u{321f}', '\u{321f}'),
+    ('\u{a48d}', '\u{a48f}'),
+    ('\u{a4c7}', '꥟'),
+    ('\u{a97d}', '\u{abff}'),
+    ('\u{d7a4}', '\u{d7ff}'),
+    ('ﬀ', '﷿'),
+    ('\u{fe1a}', '\u{fe2f}'),
+    ('\u{fe53}', '\u{fe53}'),
+    ('\u{fe67}', '\u{fe67}'),
+    ('\u{fe6c}', '\u{ff00}'),
+    ('\u{ffbf}', '\u{ffc1}'),
+    ('\u{ffc8}', '\u{ffc9}'),
+    ('\u{ffd0}', '\u{ffd1}'),
+    ('\u{ffd8}', '\u{ffd9}'),
+    ('\u{ffdd}', '\u{ffdf}'),
+    ('\u{ffe7}', '\u{ffe7}'),
+    ('\u{ffef}', '￼'),
+    ('\u{fffe}', '\u{16fdf}'),
+    ('\u{16fe5}', '\u{16fef}'),
+    ('𖿲', '\u{16fff}'),
+    ('𘟸', '𘟿'),
+    ('\u{18cd6}', '\u{18cfe}'),
+    ('𘴉', '\u{1afef}'),
+    ('\u{1aff4}', '\u{1aff4}'),
+    ('\u{1affc}', '\u{1affc}'),
+    ('\u{1afff}', '\u{1afff}'),
+    ('\u{1b123}', '\u{1b131}'),
+    ('\u{1b133}', '\u{1b14f}'),
+    ('\u{1b153}', '\u{1b154}'),
+    ('\u{1b156}', '\u{1b163}'),
+    ('\u{1b168}', '\u{1b16f}'),
+    ('\u{1b2fc}', '\u{1d2ff}'),
+    ('\u{1d357}', '\u{1d35f}'),
+    ('𝍷', '🀃'),
+    ('🀅', '🃎'),
+    ('\u{1f0d0}', '\u{1f0ff}'),
+    ('🄋', '🄏'),
+    ('🄮', '🄯'),
+    ('🅪', '🅯'),
+    ('🆭', '🇿'),
+    ('\u{1f203}', '\u{1f20f}'),
+    ('\u{1f23c}', '\u{1f23f}'),
+    ('\u{1f249}', '\u{1f24f}'),
+    ('\u{1f252}', '\u{1f25f}'),
+    ('\u{1f266}', '\u{1f2ff}'),
+    ('🌡', '🌬'),
+    ('🌶', '🌶'),
+    ('🍽', '🍽'),
+    ('🎔', '🎟'),
+    ('🏋', '🏎'),
+    ('🏔', '🏟'),
+    ('🏱', '🏳'),
+    ('🏵', '🏷'),
+    ('🐿', '🐿'),
+    ('👁', '👁'),
+    ('📽', '📾'),
+    ('🔾', '🕊'),
+    ('🕏', '🕏'),
+    ('🕨', '🕹'),
+    ('🕻', '🖔'),
+    ('🖗', '🖣'),
+    ('🖥', '🗺'),
+    ('🙐', '🙿'),
+    ('🛆', '🛋'),
+    ('🛍', '🛏'),
+    ('🛓', '🛔'),
+    ('🛘', '\u{1f6db}'),
+    ('🛠', '🛪'),
+    ('\u{1f6ed}', '🛳'),
+    ('\u{1f6fd}', '\u{1f7df}'),
+    ('\u{1f7ec}', '\u{1f7ef}'),
+    ('\u{1f7f1}', '🤋'),
+    ('🤻', '🤻'),
+    ('🥆', '🥆'),
+    ('🨀', '\u{1fa6f}'),
+    ('\u{1fa7d}', '\u{1fa7f}'),
+    ('🪊', '🪎'),
+    ('\u{1fac7}', '🫍'),
+    ('\u{1fadd}', '\u{1fade}'),
+    ('🫪', '🫯'),
+    ('\u{1faf9}', '\u{1ffff}'),
+    ('\u{2fffe}', '\u{2ffff}'),
+    ('\u{3fffe}', '\u{e00ff}'),
+    ('\u{e01f0}', '\u{effff}'),
+    ('\u{ffffe}', '\u{fffff}'),
+    ('\u{10fffe}', '\u{10ffff}'),
+];
+
+pub const WIDE: &'static [(char, char)] = &[
+    ('ᄀ', 'ᅟ'),
+    ('⌚', '⌛'),
+    ('〈', '〉'),
+    ('⏩', '⏬'),
+    ('⏰', '⏰'),
+    ('⏳', '⏳'),
+    ('◽', '◾'),
+    ('☔', '☕'),
+    ('☰', '☷'),
+    ('♈', '♓'),
+    ('♿', '♿'),
+    ('⚊', '⚏'),
+    ('⚓', '⚓'),
+    ('⚡', '⚡'),
+    ('⚪', '⚫'),
+    ('⚽', '⚾'),
+    ('⛄', '⛅'),
+    ('⛎', '⛎'),
+    ('⛔', '⛔'),
+    ('⛪', '⛪'),
+    ('⛲', '⛳'),
+    ('⛵', '⛵'),
+    ('⛺', '⛺'),
+    ('⛽', '⛽'),
+    ('✅', '✅'),
+    ('✊', '✋'),
+    ('✨', '✨'),
+    ('❌', '❌'),
+    ('❎', '❎'),
+    ('❓', '❕'),
+    ('❗', '❗'),
+    ('➕', '➗'),
+    ('➰', '➰'),
+    ('➿', '➿'),
+    ('⬛', '⬜'),
+    ('⭐', '⭐'),
+    ('⭕', '⭕'),
+    ('⺀', '⺙'),
+    ('⺛', '⻳'),
+    ('⼀', '⿕'),
+    ('⿰', '⿿'),
+    ('、', '〾'),
+    ('ぁ', 'ゖ'),
+    ('\u{3099}', 'ヿ'),
+    ('ㄅ', 'ㄯ'),
+    ('ㄱ', 'ㆎ'),
+    ('㆐', '㇥'),
+    ('㇯', '㈞'),
+    ('㈠', '㉇'),
+    ('㉐', 'ꒌ'),
+    ('꒐', '꓆'),
+    ('ꥠ', 'ꥼ'),
+    ('가', '힣'),
+    ('豈', '\u{faff}'),
+    ('︐', '︙'),
+    ('︰', '﹒'),
+    ('﹔', '﹦'),
+    ('﹨', '﹫'),
+    ('𖿠', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'),
+    ('𗀀', '𘟷'),
+    ('𘠀', '𘳕'),
+    ('𘳿', '𘴈'),
+    ('𚿰', '𚿳'),
+    ('𚿵', '𚿻'),
+    ('𚿽', '𚿾'),
+    ('𛀀', '𛄢'),
+    ('𛄲', '𛄲'),
+    ('𛅐', '𛅒'),
+    ('𛅕', '𛅕'),
+    ('𛅤', '𛅧'),
+    ('𛅰', '𛋻'),
+    ('𝌀', '𝍖'),
+    ('𝍠', '𝍶'),
+    ('🀄', '🀄'),
+    ('🃏', '🃏'),
+    ('🆎', '🆎'),
+    ('🆑', '🆚'),
+    ('🈀', '🈂'),
+    ('🈐', '🈻'),
+    ('🉀', '🉈'),
+    ('🉐', '🉑'),
+    ('🉠', '🉥'),
+    ('🌀', '🌠'),
+    ('🌭', '🌵'),
+    ('🌷', '🍼'),
+    ('🍾', '🎓'),
+    ('🎠', '🏊'),
+    ('🏏', '🏓'),
+    ('🏠', '🏰'),
+    ('🏴', '🏴'),
+    ('🏸', '🐾'),
+    ('👀', '👀'),
+    ('👂', '📼'),
+    ('📿', '🔽'),
+    ('🕋', '🕎'),
+    ('🕐', '🕧'),
+    ('🕺', '🕺'),
+    ('🖕', '🖖'),
+    ('🖤', '🖤'),
+    ('🗻', '🙏'),
+    ('🚀', '🛅'),
+    ('🛌', '🛌'),
+    ('🛐', '🛒'),
+    ('🛕', '🛗'),
+    ('🛜', '🛟'),
+    ('🛫', '🛬'),
+    ('🛴', '🛼'),
+    ('🟠', '🟫'),
+    ('🟰', '🟰'),
+    ('🤌', '🤺'),
+    ('🤼', '🥅'),
+    ('🥇', '🧿'),
+    ('🩰', '🩼'),
+    ('🪀', '🪉'),
+    ('🪏', '🫆'),
+    ('🫎', '🫜'),
+    ('🫟', '🫩'),
+    ('🫰', '🫸'),
+    ('𠀀', '\u{2fffd}'),
+    ('𰀀', '\u{3fffd}'),
+];
@@ -1,15 +1,33 @@
 #[cfg(feature = "unicode-age")]
 pub mod age;
 
+#[cfg(feature = "unicode-block")]
+pub mod block;
+
+#[cfg(feature = "unicode-case")]
+pub mod case_folding_full;
+
 #[cfg(feature = "unicode-case")]
 pub mod case_folding_simple;
 
+#[cfg(feature = "unicode-east-asian-width")]
+pub mod east_asian_width;
+
 #[cfg(feature = "unicode-gencat")]
 pub mod general_category;
 
 #[cfg(feature = "unicode-segment")]
 pub mod grapheme_cluster_break;
 
+#[cfg(feature = "unicode-line-break")]
+pub mod line_break;
+
+#[cfg(feature = "unicode-name")]
+pub mod name;
+
+#[cfg(feature = "unicode-numeric-type")]
+pub mod numeric_type;
+
 #[cfg(all(feature = "unicode-perl", not(feature = "unicode-gencat")))]
 #[allow(dead_code)]
 pub mod perl_decimal;
@@ -26,8 +44,12 @@ pub mod property_bool;
 
 #[cfg(any(
     feature = "unicode-age",
+    feature = "unicode-block",
     feature = "unicode-bool",
+    feature = "unicode-east-asian-width",
     feature = "unicode-gencat",
+    feature = "unicode-line-break",
+    feature = "unicode-numeric-type",
     feature = "unicode-perl",
     feature = "unicode-script",
     feature = "unicode-segment",
@@ -36,8 +58,12 @@ pub mod property_names;
 
 #[cfg(any(
     feature = "unicode-age",
+    feature = "unicode-block",
     feature = "unicode-bool",
+    feature = "unicode-east-asian-width",
     feature = "unicode-gencat",
+    feature = "unicode-line-break",
+    feature = "unicode-numeric-type",
     feature = "unicode-perl",
     feature = "unicode-script",
     feature = "unicode-segment",
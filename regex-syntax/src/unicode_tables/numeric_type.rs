@@ -0,0 +1,487 @@
+// DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
+//
+//   regex-cli generate unicode
+//
+// from extracted/DerivedNumericType.txt.
+//
+// Unicode version: 16.0.0.
+
+pub const BY_NAME: &'static [(&'static str, &'static [(char, char)])] = &[
+    ("Decimal", DECIMAL),
+    ("Digit", DIGIT),
+    ("None", NONE),
+    ("Numeric", NUMERIC),
+];
+
+pub const DECIMAL: &'static [(char, char)] = &[
+    ('0', '9'),
+    ('٠', '٩'),
+    ('۰', '۹'),
+    ('߀', '߉'),
+    ('०', '९'),
+    ('০', '৯'),
+    ('੦', '੯'),
+    ('૦', '૯'),
+    ('୦', '୯'),
+    ('௦', '௯'),
+    ('౦', '౯'),
+    ('೦', '೯'),
+    ('൦', '൯'),
+    ('෦', '෯'),
+    ('๐', '๙'),
+    ('໐', '໙'),
+    ('༠', '༩'),
+    ('၀', '၉'),
+    ('႐', '႙'),
+    ('០', '៩'),
+    ('᠐', '᠙'),
+    ('᥆', '᥏'),
+    ('᧐', '᧙'),
+    ('᪀', '᪉'),
+    ('᪐', '᪙'),
+    ('᭐', '᭙'),
+    ('᮰', '᮹'),
+    ('᱀', '᱉'),
+    ('᱐', '᱙'),
+    ('꘠', '꘩'),
+    ('꣐', '꣙'),
+    ('꤀', '꤉'),
+    ('꧐', '꧙'),
+    ('꧰', '꧹'),
+    ('꩐', '꩙'),
+    ('꯰', '꯹'),
+    ('０', '９'),
+    ('𐒠', '𐒩'),
+    ('𐴰', '𐴹'),
+    ('𐵀', '𐵉'),
+    ('𑁦', '𑁯'),
+    ('𑃰', '𑃹'),
+    ('𑄶', '𑄿'),
+    ('𑇐', '𑇙'),
+    ('𑋰', '𑋹'),
+    ('𑑐', '𑑙'),
+    ('𑓐', '𑓙'),
+    ('𑙐', '𑙙'),
+    ('𑛀', '𑛉'),
+    ('𑛐', '𑛣'),
+    ('𑜰', '𑜹'),
+    ('𑣠', '𑣩'),
+    ('𑥐', '𑥙'),
+    ('𑯰', '𑯹'),
+    ('𑱐', '𑱙'),
+    ('𑵐', '𑵙'),
+    ('𑶠', '𑶩'),
+    ('𑽐', '𑽙'),
+    ('𖄰', '𖄹'),
+    ('𖩠', '𖩩'),
+    ('𖫀', '𖫉'),
+    ('𖭐', '𖭙'),
+    ('𖵰', '𖵹'),
+    ('𜳰', '𜳹'),
+    ('𝟎', '𝟿'),
+    ('𞅀', '𞅉'),
+    ('𞋰', '𞋹'),
+    ('𞓰', '𞓹'),
+    ('𞗱', '𞗺'),
+    ('𞥐', '𞥙'),
+    ('🯰', '🯹'),
+];
+
+pub const DIGIT: &'static [(char, char)] = &[
+    ('²', '³'),
+    ('¹', '¹'),
+    ('፩', '፱'),
+    ('᧚', '᧚'),
+    ('⁰', '⁰'),
+    ('⁴', '⁹'),
+    ('₀', '₉'),
+    ('①', '⑨'),
+    ('⑴', '⑼'),
+    ('⒈', '⒐'),
+    ('⓪', '⓪'),
+    ('⓵', '⓽'),
+    ('⓿', '⓿'),
+    ('❶', '❾'),
+    ('➀', '➈'),
+    ('➊', '➒'),
+    ('𐩀', '𐩃'),
+    ('𐹠', '𐹨'),
+    ('𑁒', '𑁚'),
+    ('🄀', '🄊'),
+];
+
+pub const NONE: &'static [(char, char)] = &[
+    ('\0', '/'),
+    (':', '±'),
+    ('´', '¸'),
+    ('º', '»'),
+    ('¿', '\u{65f}'),
+    ('٪', 'ۯ'),
+    ('ۺ', '\u{7bf}'),
+    ('ߊ', '॥'),
+    ('॰', '\u{9e5}'),
+    ('ৰ', '৳'),
+    ('৺', '\u{a65}'),
+    ('\u{a70}', '\u{ae5}'),
+    ('૰', '\u{b65}'),
+    ('୰', 'ୱ'),
+    ('\u{b78}', '\u{be5}'),
+    ('௳', '\u{c65}'),
+    ('\u{c70}', '౷'),
+    ('౿', '\u{ce5}'),
+    ('\u{cf0}', '\u{d57}'),
+    ('ൟ', '\u{d65}'),
+    ('൹', '\u{de5}'),
+    ('\u{df0}', '๏'),
+    ('๚', '\u{ecf}'),
+    ('\u{eda}', '༟'),
+    ('༴', 'ဿ'),
+    ('၊', 'ႏ'),
+    ('ႚ', '፨'),
+    ('\u{137d}', '᛭'),
+    ('ᛱ', '\u{17df}'),
+    ('\u{17ea}', '\u{17ef}'),
+    ('\u{17fa}', '\u{180f}'),
+    ('\u{181a}', '᥅'),
+    ('ᥐ', '\u{19cf}'),
+    ('\u{19db}', '\u{1a7f}'),
+    ('\u{1a8a}', '\u{1a8f}'),
+    ('\u{1a9a}', '᭏'),
+    ('᭚', 'ᮯ'),
+    ('ᮺ', '᰿'),
+    ('\u{1c4a}', 'ᱏ'),
+    ('ᱚ', '\u{206f}'),
+    ('ⁱ', '\u{2073}'),
+    ('⁺', 'ⁿ'),
+    ('₊', '⅏'),
+    ('Ↄ', 'ↄ'),
+    ('↊', '\u{245f}'),
+    ('⒜', 'ⓩ'),
+    ('─', '❵'),
+    ('➔', '⳼'),
+    ('⳾', '〆'),
+    ('〈', '〠'),
+    ('\u{302a}', '〷'),
+    ('〻', '㆑'),
+    ('㆖', '\u{321f}'),
+    ('㈪', '㉇'),
+    ('㉐', '㉐'),
+    ('㉠', '㉿'),
+    ('㊊', '㊰'),
+    ('㋀', '㐄'),
+    ('㐆', '㒂'),
+    ('㒄', '㠩'),
+    ('㠫', '㭌'),
+    ('㭎', '䷿'),
+    ('丁', '丂'),
+    ('丄', '丆'),
+    ('丈', '丈'),
+    ('上', '乜'),
+    ('乞', '事'),
+    ('亍', '亓'),
+    ('井', '井'),
+    ('亗', '亾'),
+    ('仁', '仞'),
+    ('仠', '仧'),
+    ('仩', '伌'),
+    ('伎', '佯'),
+    ('佱', '儃'),
+    ('儅', '充'),
+    ('兇', '全'),
+    ('兪', '兪'),
+    ('公', '公'),
+    ('兮', '區'),
+    ('卂', '卂'),
+    ('卆', '卋'),
+    ('卍', '叀'),
+    ('叅', '囚'),
+    ('囜', '声'),
+    ('売', '壸'),
+    ('壺', '幹'),
+    ('幻', '廽'),
+    ('开', '弋'),
+    ('式', '式'),
+    ('弑', '拽'),
+    ('拿', '捋'),
+    ('捍', '柑'),
+    ('染', '漅'),
+    ('漇', '玕'),
+    ('玗', '白'),
+    ('癿', '肅'),
+    ('肇', '萫'),
+    ('萭', '貭'),
+    ('貯', '貲'),
+    ('貴', '贯'),
+    ('贱', '阠'),
+    ('阢', '际'),
+    ('陇', '陋'),
+    ('降', '陷'),
+    ('陹', '雵'),
+    ('雷', 'ꘟ'),
+    ('ꘪ', 'ꛥ'),
+    ('\u{a6f0}', '\u{a82f}'),
+    ('꠶', '꣏'),
+    ('\u{a8da}', '\u{a8ff}'),
+    ('ꤊ', 'ꧏ'),
+    ('\u{a9da}', 'ꧯ'),
+    ('ꧺ', '\u{aa4f}'),
+    ('\u{aa5a}', '\u{abef}'),
+    ('\u{abfa}', '\u{d7ff}'),
+    ('\u{e000}', '索'),
+    ('塞', '沈'),
+    ('若', '亮'),
+    ('凉', '鈴'),
+    ('靈', '類'),
+    ('戮', '戮'),
+    ('倫', '識'),
+    ('茶', '／'),
+    ('：', '\u{10106}'),
+    ('\u{10134}', '𐄿'),
+    ('𐅹', '𐆉'),
+    ('𐆌', '\u{102e0}'),
+    ('\u{102fc}', '𐌟'),
+    ('\u{10324}', '𐍀'),
+    ('𐍂', '𐍉'),
+    ('\u{1034b}', '𐏐'),
+    ('\u{103d6}', '\u{1049f}'),
+    ('\u{104aa}', '𐡗'),
+    ('𐡠', '𐡸'),
+    ('𐢀', '\u{108a6}'),
+    ('\u{108b0}', '\u{108fa}'),
+    ('𐤀', '𐤕'),
+    ('\u{1091c}', '\u{109bb}'),
+    ('𐦾', '𐦿'),
+    ('\u{109d0}', '\u{109d1}'),
+    ('𐨀', '\u{10a3f}'),
+    ('\u{10a49}', '𐩼'),
+    ('𐩿', '𐪜'),
+    ('\u{10aa0}', '\u{10aea}'),
+    ('𐫰', '\u{10b57}'),
+    ('𐭠', '\u{10b77}'),
+    ('𐮀', '\u{10ba8}'),
+    ('\u{10bb0}', '\u{10cf9}'),
+    ('𐴀', '\u{10d2f}'),
+    ('\u{10d3a}', '\u{10d3f}'),
+    ('𐵊', '\u{10e5f}'),
+    ('\u{10e7f}', '𐼜'),
+    ('𐼧', '\u{10f50}'),
+    ('𐽕', '𐿄'),
+    ('\u{10fcc}', '\u{11051}'),
+    ('\u{11070}', '\u{110ef}'),
+    ('\u{110fa}', '\u{11135}'),
+    ('𑅀', '\u{111cf}'),
+    ('𑇚', '\u{111e0}'),
+    ('\u{111f5}', '\u{112ef}'),
+    ('\u{112fa}', '𑑏'),
+    ('𑑚', '\u{114cf}'),
+    ('\u{114da}', '\u{1164f}'),
+    ('\u{1165a}', '\u{116bf}'),
+    ('\u{116ca}', '\u{116cf}'),
+    ('\u{116e4}', '\u{1172f}'),
+    ('𑜼', '𑣟'),
+    ('\u{118f3}', '\u{1194f}'),
+    ('\u{1195a}', '\u{11bef}'),
+    ('\u{11bfa}', '\u{11c4f}'),
+    ('\u{11c6d}', '\u{11d4f}'),
+    ('\u{11d5a}', '\u{11d9f}'),
+    ('\u{11daa}', '𑽏'),
+    ('\u{11f5a}', '\u{11fbf}'),
+    ('𑿕', '\u{123ff}'),
+    ('\u{1246f}', '\u{1612f}'),
+    ('\u{1613a}', '\u{16a5f}'),
+    ('\u{16a6a}', '\u{16abf}'),
+    ('\u{16aca}', '\u{16b4f}'),
+    ('\u{16b5a}', '\u{16b5a}'),
+    ('\u{16b62}', '𖵯'),
+    ('\u{16d7a}', '𖹿'),
+    ('𖺗', '𜳯'),
+    ('𜳺', '\u{1d2bf}'),
+    ('\u{1d2d4}', '\u{1d2df}'),
+    ('\u{1d2f4}', '\u{1d35f}'),
+    ('\u{1d379}', '\u{1d7cd}'),
+    ('𝠀', '\u{1e13f}'),
+    ('\u{1e14a}', '\u{1e2ef}'),
+    ('\u{1e2fa}', '\u{1e4ef}'),
+    ('\u{1e4fa}', '𞗰'),
+    ('\u{1e5fb}', '\u{1e8c6}'),
+    ('\u{1e8d0}', '\u{1e94f}'),
+    ('\u{1e95a}', '\u{1ec70}'),
+    ('𞲬', '𞲬'),
+    ('𞲰', '𞲰'),
+    ('\u{1ecb5}', '\u{1ed00}'),
+    ('𞴮', '𞴮'),
+    ('\u{1ed3e}', '\u{1f0ff}'),
+    ('🄍', '🯯'),
+    ('🯺', '𠀀'),
+    ('𠀂', '𠁣'),
+    ('𠁥', '𠃡'),
+    ('𠃣', '𠄠'),
+    ('𠄢', '𠤩'),
+    ('𠤫', '𠦂'),
+    ('𠦄', '𠦋'),
+    ('𠦍', '𠦛'),
+    ('𠦝', '𠫩'),
+    ('𠫫', '𠫼'),
+    ('𠫾', '𠬘'),
+    ('𠬚', '𢎏'),
+    ('𢎑', '𢦗'),
+    ('𢦙', '𣬚'),
+    ('𣬜', '𦉬'),
+    ('𦉮', '𪎒'),
+    ('𢌱', '\u{10ffff}'),
+];
+
+pub const NUMERIC: &'static [(char, char)] = &[
+    ('¼', '¾'),
+    ('৴', '৹'),
+    ('୲', '୷'),
+    ('௰', '௲'),
+    ('౸', '౾'),
+    ('൘', '൞'),
+    ('൰', '൸'),
+    ('༪', '༳'),
+    ('፲', '፼'),
+    ('ᛮ', 'ᛰ'),
+    ('៰', '៹'),
+    ('⅐', 'ↂ'),
+    ('ↅ', '↉'),
+    ('⑩', '⑳'),
+    ('⑽', '⒇'),
+    ('⒑', '⒛'),
+    ('⓫', '⓴'),
+    ('⓾', '⓾'),
+    ('❿', '❿'),
+    ('➉', '➉'),
+    ('➓', '➓'),
+    ('⳽', '⳽'),
+    ('〇', '〇'),
+    ('〡', '〩'),
+    ('〸', '〺'),
+    ('㆒', '㆕'),
+    ('㈠', '㈩'),
+    ('㉈', '㉏'),
+    ('㉑', '㉟'),
+    ('㊀', '㊉'),
+    ('㊱', '㊿'),
+    ('㐅', '㐅'),
+    ('㒃', '㒃'),
+    ('㠪', '㠪'),
+    ('㭍', '㭍'),
+    ('一', '一'),
+    ('七', '七'),
+    ('万', '万'),
+    ('三', '三'),
+    ('九', '九'),
+    ('二', '二'),
+    ('五', '五'),
+    ('亖', '亖'),
+    ('亿', '什'),
+    ('仟', '仟'),
+    ('仨', '仨'),
+    ('伍', '伍'),
+    ('佰', '佰'),
+    ('億', '億'),
+    ('兆', '兆'),
+    ('兩', '兩'),
+    ('八', '八'),
+    ('六', '六'),
+    ('十', '十'),
+    ('千', '卅'),
+    ('卌', '卌'),
+    ('叁', '叄'),
+    ('四', '四'),
+    ('壱', '壱'),
+    ('壹', '壹'),
+    ('幺', '幺'),
+    ('廾', '廿'),
+    ('弌', '弎'),
+    ('弐', '弐'),
+    ('拾', '拾'),
+    ('捌', '捌'),
+    ('柒', '柒'),
+    ('漆', '漆'),
+    ('玖', '玖'),
+    ('百', '百'),
+    ('肆', '肆'),
+    ('萬', '萬'),
+    ('貮', '貮'),
+    ('貳', '貳'),
+    ('贰', '贰'),
+    ('阡', '阡'),
+    ('陆', '陆'),
+    ('陌', '陌'),
+    ('陸', '陸'),
+    ('零', '零'),
+    ('ꛦ', 'ꛯ'),
+    ('꠰', '꠵'),
+    ('參', '參'),
+    ('拾', '拾'),
+    ('兩', '兩'),
+    ('零', '零'),
+    ('六', '六'),
+    ('陸', '陸'),
+    ('什', '什'),
+    ('𐄇', '𐄳'),
+    ('𐅀', '𐅸'),
+    ('𐆊', '𐆋'),
+    ('𐋡', '𐋻'),
+    ('𐌠', '𐌣'),
+    ('𐍁', '𐍁'),
+    ('𐍊', '𐍊'),
+    ('𐏑', '𐏕'),
+    ('𐡘', '𐡟'),
+    ('𐡹', '𐡿'),
+    ('𐢧', '𐢯'),
+    ('𐣻', '𐣿'),
+    ('𐤖', '𐤛'),
+    ('𐦼', '𐦽'),
+    ('𐧀', '𐧏'),
+    ('𐧒', '𐧿'),
+    ('𐩄', '𐩈'),
+    ('𐩽', '𐩾'),
+    ('𐪝', '𐪟'),
+    ('𐫫', '𐫯'),
+    ('𐭘', '𐭟'),
+    ('𐭸', '𐭿'),
+    ('𐮩', '𐮯'),
+    ('𐳺', '𐳿'),
+    ('𐹩', '𐹾'),
+    ('𐼝', '𐼦'),
+    ('𐽑', '𐽔'),
+    ('𐿅', '𐿋'),
+    ('𑁛', '𑁥'),
+    ('𑇡', '𑇴'),
+    ('𑜺', '𑜻'),
+    ('𑣪', '𑣲'),
+    ('𑱚', '𑱬'),
+    ('𑿀', '𑿔'),
+    ('𒐀', '𒑮'),
+    ('𖭛', '𖭡'),
+    ('𖺀', '𖺖'),
+    ('𝋀', '𝋓'),
+    ('𝋠', '𝋳'),
+    ('𝍠', '𝍸'),
+    ('𞣇', '𞣏'),
+    ('𞱱', '𞲫'),
+    ('𞲭', '𞲯'),
+    ('𞲱', '𞲴'),
+    ('𞴁', '𞴭'),
+    ('𞴯', '𞴽'),
+    ('🄋', '🄌'),
+    ('𠀁', '𠀁'),
+    ('𠁤', '𠁤'),
+    ('𠃢', '𠃢'),
+    ('𠄡', '𠄡'),
+    ('𠤪', '𠤪'),
+    ('𠦃', '𠦃'),
+    ('𠦌', '𠦌'),
+    ('𠦜', '𠦜'),
+    ('𠫪', '𠫪'),
+    ('𠫽', '𠫽'),
+    ('𠬙', '𠬙'),
+    ('𢎐', '𢎐'),
+    ('𢦘', '𢦘'),
+    ('𣬛', '𣬛'),
+    ('𦉭', '𦉭'),
+    ('廾', '廾'),
+];
@@ -1,6 +1,6 @@
 // DO NOT EDIT THIS FILE. IT WAS AUTOMATICALLY GENERATED BY:
 //
-//   ucd-generate property-values ucd-16.0.0 --include gc,script,scx,age,gcb,wb,sb
+//   ucd-generate property-values ucd-16.0.0 --include gc,script,scx,age,gcb,wb,sb,blk,ea,lb,nt
 //
 // Unicode version: 16.0.0.
 //
@@ -71,6 +71,634 @@ pub const PROPERTY_VALUES: &'static [(
             ("v90", "V9_0"),
         ],
     ),
+    (
+        "Block",
+        &[
+            ("adlam", "Adlam"),
+            ("aegeannumbers", "Aegean_Numbers"),
+            ("ahom", "Ahom"),
+            ("alchemical", "Alchemical_Symbols"),
+            ("alchemicalsymbols", "Alchemical_Symbols"),
+            ("alphabeticpf", "Alphabetic_Presentation_Forms"),
+            ("alphabeticpresentationforms", "Alphabetic_Presentation_Forms"),
+            ("anatolianhieroglyphs", "Anatolian_Hieroglyphs"),
+            ("ancientgreekmusic", "Ancient_Greek_Musical_Notation"),
+            ("ancientgreekmusicalnotation", "Ancient_Greek_Musical_Notation"),
+            ("ancientgreeknumbers", "Ancient_Greek_Numbers"),
+            ("ancientsymbols", "Ancient_Symbols"),
+            ("arabic", "Arabic"),
+            ("arabicexta", "Arabic_Extended_A"),
+            ("arabicextb", "Arabic_Extended_B"),
+            ("arabicextc", "Arabic_Extended_C"),
+            ("arabicextendeda", "Arabic_Extended_A"),
+            ("arabicextendedb", "Arabic_Extended_B"),
+            ("arabicextendedc", "Arabic_Extended_C"),
+            ("arabicmath", "Arabic_Mathematical_Alphabetic_Symbols"),
+            (
+                "arabicmathematicalalphabeticsymbols",
+                "Arabic_Mathematical_Alphabetic_Symbols",
+            ),
+            ("arabicpfa", "Arabic_Presentation_Forms_A"),
+            ("arabicpfb", "Arabic_Presentation_Forms_B"),
+            ("arabicpresentationformsa", "Arabic_Presentation_Forms_A"),
+            ("arabicpresentationformsb", "Arabic_Presentation_Forms_B"),
+            ("arabicsup", "Arabic_Supplement"),
+            ("arabicsupplement", "Arabic_Supplement"),
+            ("armenian", "Armenian"),
+            ("arrows", "Arrows"),
+            ("ascii", "Basic_Latin"),
+            ("avestan", "Avestan"),
+            ("balinese", "Balinese"),
+            ("bamum", "Bamum"),
+            ("bamumsup", "Bamum_Supplement"),
+            ("bamumsupplement", "Bamum_Supplement"),
+            ("basiclatin", "Basic_Latin"),
+            ("bassavah", "Bassa_Vah"),
+            ("batak", "Batak"),
+            ("bengali", "Bengali"),
+            ("bhaiksuki", "Bhaiksuki"),
+            ("blockelements", "Block_Elements"),
+            ("bopomofo", "Bopomofo"),
+            ("bopomofoext", "Bopomofo_Extended"),
+            ("bopomofoextended", "Bopomofo_Extended"),
+            ("boxdrawing", "Box_Drawing"),
+            ("brahmi", "Brahmi"),
+            ("braille", "Braille_Patterns"),
+            ("braillepatterns", "Braille_Patterns"),
+            ("buginese", "Buginese"),
+            ("buhid", "Buhid"),
+            ("byzantinemusic", "Byzantine_Musical_Symbols"),
+            ("byzantinemusicalsymbols", "Byzantine_Musical_Symbols"),
+            ("canadiansyllabics", "Unified_Canadian_Aboriginal_Syllabics"),
+            ("carian", "Carian"),
+            ("caucasianalbanian", "Caucasian_Albanian"),
+            ("chakma", "Chakma"),
+            ("cham", "Cham"),
+            ("cherokee", "Cherokee"),
+            ("cherokeesup", "Cherokee_Supplement"),
+            ("cherokeesupplement", "Cherokee_Supplement"),
+            ("chesssymbols", "Chess_Symbols"),
+            ("chorasmian", "Chorasmian"),
+            ("cjk", "CJK_Unified_Ideographs"),
+            ("cjkcompat", "CJK_Compatibility"),
+            ("cjkcompatforms", "CJK_Compatibility_Forms"),
+            ("cjkcompatibility", "CJK_Compatibility"),
+            ("cjkcompatibilityforms", "CJK_Compatibility_Forms"),
+            ("cjkcompatibilityideographs", "CJK_Compatibility_Ideographs"),
+            (
+                "cjkcompatibilityideographssupplement",
+                "CJK_Compatibility_Ideographs_Supplement",
+            ),
+            ("cjkcompatideographs", "CJK_Compatibility_Ideographs"),
+            (
+                "cjkcompatideographssup",
+                "CJK_Compatibility_Ideographs_Supplement",
+            ),
+            ("cjkexta", "CJK_Unified_Ideographs_Extension_A"),
+            ("cjkextb", "CJK_Unified_Ideographs_Extension_B"),
+            ("cjkextc", "CJK_Unified_Ideographs_Extension_C"),
+            ("cjkextd", "CJK_Unified_Ideographs_Extension_D"),
+            ("cjkexte", "CJK_Unified_Ideographs_Extension_E"),
+            ("cjkextf", "CJK_Unified_Ideographs_Extension_F"),
+            ("cjkextg", "CJK_Unified_Ideographs_Extension_G"),
+            ("cjkexth", "CJK_Unified_Ideographs_Extension_H"),
+            ("cjkexti", "CJK_Unified_Ideographs_Extension_I"),
+            ("cjkradicalssup", "CJK_Radicals_Supplement"),
+            ("cjkradicalssupplement", "CJK_Radicals_Supplement"),
+            ("cjkstrokes", "CJK_Strokes"),
+            ("cjksymbols", "CJK_Symbols_And_Punctuation"),
+            ("cjksymbolsandpunctuation", "CJK_Symbols_And_Punctuation"),
+            ("cjkunifiedideographs", "CJK_Unified_Ideographs"),
+            (
+                "cjkunifiedideographsextensiona",
+                "CJK_Unified_Ideographs_Extension_A",
+            ),
+            (
+                "cjkunifiedideographsextensionb",
+                "CJK_Unified_Ideographs_Extension_B",
+            ),
+            (
+                "cjkunifiedideographsextensionc",
+                "CJK_Unified_Ideographs_Extension_C",
+            ),
+            (
+                "cjkunifiedideographsextensiond",
+                "CJK_Unified_Ideographs_Extension_D",
+            ),
+            (
+                "cjkunifiedideographsextensione",
+                "CJK_Unified_Ideographs_Extension_E",
+            ),
+            (
+                "cjkunifiedideographsextensionf",
+                "CJK_Unified_Ideographs_Extension_F",
+            ),
+            (
+                "cjkunifiedideographsextensiong",
+                "CJK_Unified_Ideographs_Extension_G",
+            ),
+            (
+                "cjkunifiedideographsextensionh",
+                "CJK_Unified_Ideographs_Extension_H",
+            ),
+            (
+                "cjkunifiedideographsextensioni",
+                "CJK_Unified_Ideographs_Extension_I",
+            ),
+            ("combiningdiacriticalmarks", "Combining_Diacritical_Marks"),
+            (
+                "combiningdiacriticalmarksextended",
+                "Combining_Diacritical_Marks_Extended",
+            ),
+            (
+                "combiningdiacriticalmarksforsymbols",
+                "Combining_Diacritical_Marks_For_Symbols",
+            ),
+            (
+                "combiningdiacriticalmarkssupplement",
+                "Combining_Diacritical_Marks_Supplement",
+            ),
+            ("combininghalfmarks", "Combining_Half_Marks"),
+            (
+                "combiningmarksforsymbols",
+                "Combining_Diacritical_Marks_For_Symbols",
+            ),
+            ("commonindicnumberforms", "Common_Indic_Number_Forms"),
+            ("compatjamo", "Hangul_Compatibility_Jamo"),
+            ("controlpictures", "Control_Pictures"),
+            ("coptic", "Coptic"),
+            ("copticepactnumbers", "Coptic_Epact_Numbers"),
+            ("countingrod", "Counting_Rod_Numerals"),
+            ("countingrodnumerals", "Counting_Rod_Numerals"),
+            ("cuneiform", "Cuneiform"),
+            ("cuneiformnumbers", "Cuneiform_Numbers_And_Punctuation"),
+            (
+                "cuneiformnumbersandpunctuation",
+                "Cuneiform_Numbers_And_Punctuation",
+            ),
+            ("currencysymbols", "Currency_Symbols"),
+            ("cypriotsyllabary", "Cypriot_Syllabary"),
+            ("cyprominoan", "Cypro_Minoan"),
+            ("cyrillic", "Cyrillic"),
+            ("cyrillicexta", "Cyrillic_Extended_A"),
+            ("cyrillicextb", "Cyrillic_Extended_B"),
+            ("cyrillicextc", "Cyrillic_Extended_C"),
+            ("cyrillicextd", "Cyrillic_Extended_D"),
+            ("cyrillicextendeda", "Cyrillic_Extended_A"),
+            ("cyrillicextendedb", "Cyrillic_Extended_B"),
+            ("cyrillicextendedc", "Cyrillic_Extended_C"),
+            ("cyrillicextendedd", "Cyrillic_Extended_D"),
+            ("cyrillicsup", "Cyrillic_Supplement"),
+            ("cyrillicsupplement", "Cyrillic_Supplement"),
+            ("cyrillicsupplementary", "Cyrillic_Supplement"),
+            ("deseret", "Deseret"),
+            ("devanagari", "Devanagari"),
+            ("devanagariext", "Devanagari_Extended"),
+            ("devanagariexta", "Devanagari_Extended_A"),
+            ("devanagariextended", "Devanagari_Extended"),
+            ("devanagariextendeda", "Devanagari_Extended_A"),
+            ("diacriticals", "Combining_Diacritical_Marks"),
+            ("diacriticalsext", "Combining_Diacritical_Marks_Extended"),
+            (
+                "diacriticalsforsymbols",
+                "Combining_Diacritical_Marks_For_Symbols",
+            ),
+            ("diacriticalssup", "Combining_Diacritical_Marks_Supplement"),
+            ("dingbats", "Dingbats"),
+            ("divesakuru", "Dives_Akuru"),
+            ("dogra", "Dogra"),
+            ("domino", "Domino_Tiles"),
+            ("dominotiles", "Domino_Tiles"),
+            ("duployan", "Duployan"),
+            ("earlydynasticcuneiform", "Early_Dynastic_Cuneiform"),
+            (
+                "egyptianhieroglyphformatcontrols",
+                "Egyptian_Hieroglyph_Format_Controls",
+            ),
+            ("egyptianhieroglyphs", "Egyptian_Hieroglyphs"),
+            ("egyptianhieroglyphsexta", "Egyptian_Hieroglyphs_Extended_A"),
+            (
+                "egyptianhieroglyphsextendeda",
+                "Egyptian_Hieroglyphs_Extended_A",
+            ),
+            ("elbasan", "Elbasan"),
+            ("elymaic", "Elymaic"),
+            ("emoticons", "Emoticons"),
+            ("enclosedalphanum", "Enclosed_Alphanumerics"),
+            ("enclosedalphanumerics", "Enclosed_Alphanumerics"),
+            (
+                "enclosedalphanumericsupplement",
+                "Enclosed_Alphanumeric_Supplement",
+            ),
+            ("enclosedalphanumsup", "Enclosed_Alphanumeric_Supplement"),
+            ("enclosedcjk", "Enclosed_CJK_Letters_And_Months"),
+            ("enclosedcjklettersandmonths", "Enclosed_CJK_Letters_And_Months"),
+            ("enclosedideographicsup", "Enclosed_Ideographic_Supplement"),
+            (
+                "enclosedideographicsupplement",
+                "Enclosed_Ideographic_Supplement",
+            ),
+            ("ethiopic", "Ethiopic"),
+            ("ethiopicext", "Ethiopic_Extended"),
+            ("ethiopicexta", "Ethiopic_Extended_A"),
+            ("ethiopicextb", "Ethiopic_Extended_B"),
+            ("ethiopicextended", "Ethiopic_Extended"),
+            ("ethiopicextendeda", "Ethiopic_Extended_A"),
+            ("ethiopicextendedb", "Ethiopic_Extended_B"),
+            ("ethiopicsup", "Ethiopic_Supplement"),
+            ("ethiopicsupplement", "Ethiopic_Supplement"),
+            ("garay", "Garay"),
+            ("generalpunctuation", "General_Punctuation"),
+            ("geometricshapes", "Geometric_Shapes"),
+            ("geometricshapesext", "Geometric_Shapes_Extended"),
+            ("geometricshapesextended", "Geometric_Shapes_Extended"),
+            ("georgian", "Georgian"),
+            ("georgianext", "Georgian_Extended"),
+            ("georgianextended", "Georgian_Extended"),
+            ("georgiansup", "Georgian_Supplement"),
+            ("georgiansupplement", "Georgian_Supplement"),
+            ("glagolitic", "Glagolitic"),
+            ("glagoliticsup", "Glagolitic_Supplement"),
+            ("glagoliticsupplement", "Glagolitic_Supplement"),
+            ("gothic", "Gothic"),
+            ("grantha", "Grantha"),
+            ("greek", "Greek_And_Coptic"),
+            ("greekandcoptic", "Greek_And_Coptic"),
+            ("greekext", "Greek_Extended"),
+            ("greekextended", "Greek_Extended"),
+            ("gujarati", "Gujarati"),
+            ("gunjalagondi", "Gunjala_Gondi"),
+            ("gurmukhi", "Gurmukhi"),
+            ("gurungkhema", "Gurung_Khema"),
+            ("halfandfullforms", "Halfwidth_And_Fullwidth_Forms"),
+            ("halfmarks", "Combining_Half_Marks"),
+            ("halfwidthandfullwidthforms", "Halfwidth_And_Fullwidth_Forms"),
+            ("hangul", "Hangul_Syllables"),
+            ("hangulcompatibilityjamo", "Hangul_Compatibility_Jamo"),
+            ("hanguljamo", "Hangul_Jamo"),
+            ("hanguljamoextendeda", "Hangul_Jamo_Extended_A"),
+            ("hanguljamoextendedb", "Hangul_Jamo_Extended_B"),
+            ("hangulsyllables", "Hangul_Syllables"),
+            ("hanifirohingya", "Hanifi_Rohingya"),
+            ("hanunoo", "Hanunoo"),
+            ("hatran", "Hatran"),
+            ("hebrew", "Hebrew"),
+            ("highprivateusesurrogates", "High_Private_Use_Surrogates"),
+            ("highpusurrogates", "High_Private_Use_Surrogates"),
+            ("highsurrogates", "High_Surrogates"),
+            ("hiragana", "Hiragana"),
+            ("idc", "Ideographic_Description_Characters"),
+            (
+                "ideographicdescriptioncharacters",
+                "Ideographic_Description_Characters",
+            ),
+            ("ideographicsymbols", "Ideographic_Symbols_And_Punctuation"),
+            (
+                "ideographicsymbolsandpunctuation",
+                "Ideographic_Symbols_And_Punctuation",
+            ),
+            ("imperialaramaic", "Imperial_Aramaic"),
+            ("indicnumberforms", "Common_Indic_Number_Forms"),
+            ("indicsiyaqnumbers", "Indic_Siyaq_Numbers"),
+            ("inscriptionalpahlavi", "Inscriptional_Pahlavi"),
+            ("inscriptionalparthian", "Inscriptional_Parthian"),
+            ("ipaext", "IPA_Extensions"),
+            ("ipaextensions", "IPA_Extensions"),
+            ("jamo", "Hangul_Jamo"),
+            ("jamoexta", "Hangul_Jamo_Extended_A"),
+            ("jamoextb", "Hangul_Jamo_Extended_B"),
+            ("javanese", "Javanese"),
+            ("kaithi", "Kaithi"),
+            ("kaktoviknumerals", "Kaktovik_Numerals"),
+            ("kanaexta", "Kana_Extended_A"),
+            ("kanaextb", "Kana_Extended_B"),
+            ("kanaextendeda", "Kana_Extended_A"),
+            ("kanaextendedb", "Kana_Extended_B"),
+            ("kanasup", "Kana_Supplement"),
+            ("kanasupplement", "Kana_Supplement"),
+            ("kanbun", "Kanbun"),
+            ("kangxi", "Kangxi_Radicals"),
+            ("kangxiradicals", "Kangxi_Radicals"),
+            ("kannada", "Kannada"),
+            ("katakana", "Katakana"),
+            ("katakanaext", "Katakana_Phonetic_Extensions"),
+            ("katakanaphoneticextensions", "Katakana_Phonetic_Extensions"),
+            ("kawi", "Kawi"),
+            ("kayahli", "Kayah_Li"),
+            ("kharoshthi", "Kharoshthi"),
+            ("khitansmallscript", "Khitan_Small_Script"),
+            ("khmer", "Khmer"),
+            ("khmersymbols", "Khmer_Symbols"),
+            ("khojki", "Khojki"),
+            ("khudawadi", "Khudawadi"),
+            ("kiratrai", "Kirat_Rai"),
+            ("lao", "Lao"),
+            ("latin1", "Latin_1_Supplement"),
+            ("latin1sup", "Latin_1_Supplement"),
+            ("latin1supplement", "Latin_1_Supplement"),
+            ("latinexta", "Latin_Extended_A"),
+            ("latinextadditional", "Latin_Extended_Additional"),
+            ("latinextb", "Latin_Extended_B"),
+            ("latinextc", "Latin_Extended_C"),
+            ("latinextd", "Latin_Extended_D"),
+            ("latinexte", "Latin_Extended_E"),
+            ("latinextendeda", "Latin_Extended_A"),
+            ("latinextendedadditional", "Latin_Extended_Additional"),
+            ("latinextendedb", "Latin_Extended_B"),
+            ("latinextendedc", "Latin_Extended_C"),
+            ("latinextendedd", "Latin_Extended_D"),
+            ("latinextendede", "Latin_Extended_E"),
+            ("latinextendedf", "Latin_Extended_F"),
+            ("latinextendedg", "Latin_Extended_G"),
+            ("latinextf", "Latin_Extended_F"),
+            ("latinextg", "Latin_Extended_G"),
+            ("lepcha", "Lepcha"),
+            ("letterlikesymbols", "Letterlike_Symbols"),
+            ("limbu", "Limbu"),
+            ("lineara", "Linear_A"),
+            ("linearbideograms", "Linear_B_Ideograms"),
+            ("linearbsyllabary", "Linear_B_Syllabary"),
+            ("lisu", "Lisu"),
+            ("lisusup", "Lisu_Supplement"),
+            ("lisusupplement", "Lisu_Supplement"),
+            ("lowsurrogates", "Low_Surrogates"),
+            ("lycian", "Lycian"),
+            ("lydian", "Lydian"),
+            ("mahajani", "Mahajani"),
+            ("mahjong", "Mahjong_Tiles"),
+            ("mahjongtiles", "Mahjong_Tiles"),
+            ("makasar", "Makasar"),
+            ("malayalam", "Malayalam"),
+            ("mandaic", "Mandaic"),
+            ("manichaean", "Manichaean"),
+            ("marchen", "Marchen"),
+            ("masaramgondi", "Masaram_Gondi"),
+            ("mathalphanum", "Mathematical_Alphanumeric_Symbols"),
+            (
+                "mathematicalalphanumericsymbols",
+                "Mathematical_Alphanumeric_Symbols",
+            ),
+            ("mathematicaloperators", "Mathematical_Operators"),
+            ("mathoperators", "Mathematical_Operators"),
+            ("mayannumerals", "Mayan_Numerals"),
+            ("medefaidrin", "Medefaidrin"),
+            ("meeteimayek", "Meetei_Mayek"),
+            ("meeteimayekext", "Meetei_Mayek_Extensions"),
+            ("meeteimayekextensions", "Meetei_Mayek_Extensions"),
+            ("mendekikakui", "Mende_Kikakui"),
+            ("meroiticcursive", "Meroitic_Cursive"),
+            ("meroitichieroglyphs", "Meroitic_Hieroglyphs"),
+            ("miao", "Miao"),
+            ("miscarrows", "Miscellaneous_Symbols_And_Arrows"),
+            (
+                "miscellaneousmathematicalsymbolsa",
+                "Miscellaneous_Mathematical_Symbols_A",
+            ),
+            (
+                "miscellaneousmathematicalsymbolsb",
+                "Miscellaneous_Mathematical_Symbols_B",
+            ),
+            ("miscellaneoussymbols", "Miscellaneous_Symbols"),
+            (
+                "miscellaneoussymbolsandarrows",
+                "Miscellaneous_Symbols_And_Arrows",
+            ),
+            (
+                "miscellaneoussymbolsandpictographs",
+                "Miscellaneous_Symbols_And_Pictographs",
+            ),
+            ("miscellaneoustechnical", "Miscellaneous_Technical"),
+            ("miscmathsymbolsa", "Miscellaneous_Mathematical_Symbols_A"),
+            ("miscmathsymbolsb", "Miscellaneous_Mathematical_Symbols_B"),
+            ("miscpictographs", "Miscellaneous_Symbols_And_Pictographs"),
+            ("miscsymbols", "Miscellaneous_Symbols"),
+            ("misctechnical", "Miscellaneous_Technical"),
+            ("modi", "Modi"),
+            ("modifierletters", "Spacing_Modifier_Letters"),
+            ("modifiertoneletters", "Modifier_Tone_Letters"),
+            ("mongolian", "Mongolian"),
+            ("mongoliansup", "Mongolian_Supplement"),
+            ("mongoliansupplement", "Mongolian_Supplement"),
+            ("mro", "Mro"),
+            ("multani", "Multani"),
+            ("music", "Musical_Symbols"),
+            ("musicalsymbols", "Musical_Symbols"),
+            ("myanmar", "Myanmar"),
+            ("myanmarexta", "Myanmar_Extended_A"),
+            ("myanmarextb", "Myanmar_Extended_B"),
+            ("myanmarextc", "Myanmar_Extended_C"),
+            ("myanmarextendeda", "Myanmar_Extended_A"),
+            ("myanmarextendedb", "Myanmar_Extended_B"),
+            ("myanmarextendedc", "Myanmar_Extended_C"),
+            ("nabataean", "Nabataean"),
+            ("nagmundari", "Nag_Mundari"),
+            ("nandinagari", "Nandinagari"),
+            ("nb", "No_Block"),
+            ("newa", "Newa"),
+            ("newtailue", "New_Tai_Lue"),
+            ("nko", "NKo"),
+            ("noblock", "No_Block"),
+            ("numberforms", "Number_Forms"),
+            ("nushu", "Nushu"),
+            ("nyiakengpuachuehmong", "Nyiakeng_Puachue_Hmong"),
+            ("ocr", "Optical_Character_Recognition"),
+            ("ogham", "Ogham"),
+            ("olchiki", "Ol_Chiki"),
+            ("oldhungarian", "Old_Hungarian"),
+            ("olditalic", "Old_Italic"),
+            ("oldnortharabian", "Old_North_Arabian"),
+            ("oldpermic", "Old_Permic"),
+            ("oldpersian", "Old_Persian"),
+            ("oldsogdian", "Old_Sogdian"),
+            ("oldsoutharabian", "Old_South_Arabian"),
+            ("oldturkic", "Old_Turkic"),
+            ("olduyghur", "Old_Uyghur"),
+            ("olonal", "Ol_Onal"),
+            ("opticalcharacterrecognition", "Optical_Character_Recognition"),
+            ("oriya", "Oriya"),
+            ("ornamentaldingbats", "Ornamental_Dingbats"),
+            ("osage", "Osage"),
+            ("osmanya", "Osmanya"),
+            ("ottomansiyaqnumbers", "Ottoman_Siyaq_Numbers"),
+            ("pahawhhmong", "Pahawh_Hmong"),
+            ("palmyrene", "Palmyrene"),
+            ("paucinhau", "Pau_Cin_Hau"),
+            ("phagspa", "Phags_Pa"),
+            ("phaistos", "Phaistos_Disc"),
+            ("phaistosdisc", "Phaistos_Disc"),
+            ("phoenician", "Phoenician"),
+            ("phoneticext", "Phonetic_Extensions"),
+            ("phoneticextensions", "Phonetic_Extensions"),
+            ("phoneticextensionssupplement", "Phonetic_Extensions_Supplement"),
+            ("phoneticextsup", "Phonetic_Extensions_Supplement"),
+            ("playingcards", "Playing_Cards"),
+            ("privateuse", "Private_Use_Area"),
+            ("privateusearea", "Private_Use_Area"),
+            ("psalterpahlavi", "Psalter_Pahlavi"),
+            ("pua", "Private_Use_Area"),
+            ("punctuation", "General_Punctuation"),
+            ("rejang", "Rejang"),
+            ("rumi", "Rumi_Numeral_Symbols"),
+            ("ruminumeralsymbols", "Rumi_Numeral_Symbols"),
+            ("runic", "Runic"),
+            ("samaritan", "Samaritan"),
+            ("saurashtra", "Saurashtra"),
+            ("sharada", "Sharada"),
+            ("shavian", "Shavian"),
+            ("shorthandformatcontrols", "Shorthand_Format_Controls"),
+            ("siddham", "Siddham"),
+            ("sinhala", "Sinhala"),
+            ("sinhalaarchaicnumbers", "Sinhala_Archaic_Numbers"),
+            ("smallforms", "Small_Form_Variants"),
+            ("smallformvariants", "Small_Form_Variants"),
+            ("smallkanaext", "Small_Kana_Extension"),
+            ("smallkanaextension", "Small_Kana_Extension"),
+            ("sogdian", "Sogdian"),
+            ("sorasompeng", "Sora_Sompeng"),
+            ("soyombo", "Soyombo"),
+            ("spacingmodifierletters", "Spacing_Modifier_Letters"),
+            ("specials", "Specials"),
+            ("sundanese", "Sundanese"),
+            ("sundanesesup", "Sundanese_Supplement"),
+            ("sundanesesupplement", "Sundanese_Supplement"),
+            ("sunuwar", "Sunuwar"),
+            ("suparrowsa", "Supplemental_Arrows_A"),
+            ("suparrowsb", "Supplemental_Arrows_B"),
+            ("suparrowsc", "Supplemental_Arrows_C"),
+            ("superandsub", "Superscripts_And_Subscripts"),
+            ("superscriptsandsubscripts", "Superscripts_And_Subscripts"),
+            ("supmathoperators", "Supplemental_Mathematical_Operators"),
+            ("supplementalarrowsa", "Supplemental_Arrows_A"),
+            ("supplementalarrowsb", "Supplemental_Arrows_B"),
+            ("supplementalarrowsc", "Supplemental_Arrows_C"),
+            (
+                "supplementalmathematicaloperators",
+                "Supplemental_Mathematical_Operators",
+            ),
+            ("supplementalpunctuation", "Supplemental_Punctuation"),
+            (
+                "supplementalsymbolsandpictographs",
+                "Supplemental_Symbols_And_Pictographs",
+            ),
+            (
+                "supplementaryprivateuseareaa",
+                "Supplementary_Private_Use_Area_A",
+            ),
+            (
+                "supplementaryprivateuseareab",
+                "Supplementary_Private_Use_Area_B",
+            ),
+            ("suppuaa", "Supplementary_Private_Use_Area_A"),
+            ("suppuab", "Supplementary_Private_Use_Area_B"),
+            ("suppunctuation", "Supplemental_Punctuation"),
+            (
+                "supsymbolsandpictographs",
+                "Supplemental_Symbols_And_Pictographs",
+            ),
+            ("suttonsignwriting", "Sutton_SignWriting"),
+            ("sylotinagri", "Syloti_Nagri"),
+            (
+                "symbolsandpictographsexta",
+                "Symbols_And_Pictographs_Extended_A",
+            ),
+            (
+                "symbolsandpictographsextendeda",
+                "Symbols_And_Pictographs_Extended_A",
+            ),
+            ("symbolsforlegacycomputing", "Symbols_For_Legacy_Computing"),
+            (
+                "symbolsforlegacycomputingsup",
+                "Symbols_For_Legacy_Computing_Supplement",
+            ),
+            (
+                "symbolsforlegacycomputingsupplement",
+                "Symbols_For_Legacy_Computing_Supplement",
+            ),
+            ("syriac", "Syriac"),
+            ("syriacsup", "Syriac_Supplement"),
+            ("syriacsupplement", "Syriac_Supplement"),
+            ("tagalog", "Tagalog"),
+            ("tagbanwa", "Tagbanwa"),
+            ("tags", "Tags"),
+            ("taile", "Tai_Le"),
+            ("taitham", "Tai_Tham"),
+            ("taiviet", "Tai_Viet"),
+            ("taixuanjing", "Tai_Xuan_Jing_Symbols"),
+            ("taixuanjingsymbols", "Tai_Xuan_Jing_Symbols"),
+            ("takri", "Takri"),
+            ("tamil", "Tamil"),
+            ("tamilsup", "Tamil_Supplement"),
+            ("tamilsupplement", "Tamil_Supplement"),
+            ("tangsa", "Tangsa"),
+            ("tangut", "Tangut"),
+            ("tangutcomponents", "Tangut_Components"),
+            ("tangutsup", "Tangut_Supplement"),
+            ("tangutsupplement", "Tangut_Supplement"),
+            ("telugu", "Telugu"),
+            ("thaana", "Thaana"),
+            ("thai", "Thai"),
+            ("tibetan", "Tibetan"),
+            ("tifinagh", "Tifinagh"),
+            ("tirhuta", "Tirhuta"),
+            ("todhri", "Todhri"),
+            ("toto", "Toto"),
+            ("transportandmap", "Transport_And_Map_Symbols"),
+            ("transportandmapsymbols", "Transport_And_Map_Symbols"),
+            ("tulutigalari", "Tulu_Tigalari"),
+            ("ucas", "Unified_Canadian_Aboriginal_Syllabics"),
+            ("ucasext", "Unified_Canadian_Aboriginal_Syllabics_Extended"),
+            ("ucasexta", "Unified_Canadian_Aboriginal_Syllabics_Extended_A"),
+            ("ugaritic", "Ugaritic"),
+            (
+                "unifiedcanadianaboriginalsyllabics",
+                "Unified_Canadian_Aboriginal_Syllabics",
+            ),
+            (
+                "unifiedcanadianaboriginalsyllabicsextended",
+                "Unified_Canadian_Aboriginal_Syllabics_Extended",
+            ),
+            (
+                "unifiedcanadianaboriginalsyllabicsextendeda",
+                "Unified_Canadian_Aboriginal_Syllabics_Extended_A",
+            ),
+            ("vai", "Vai"),
+            ("variationselectors", "Variation_Selectors"),
+            ("variationselectorssupplement", "Variation_Selectors_Supplement"),
+            ("vedicext", "Vedic_Extensions"),
+            ("vedicextensions", "Vedic_Extensions"),
+            ("verticalforms", "Vertical_Forms"),
+            ("vithkuqi", "Vithkuqi"),
+            ("vs", "Variation_Selectors"),
+            ("vssup", "Variation_Selectors_Supplement"),
+            ("wancho", "Wancho"),
+            ("warangciti", "Warang_Citi"),
+            ("yezidi", "Yezidi"),
+            ("yijing", "Yijing_Hexagram_Symbols"),
+            ("yijinghexagramsymbols", "Yijing_Hexagram_Symbols"),
+            ("yiradicals", "Yi_Radicals"),
+            ("yisyllables", "Yi_Syllables"),
+            ("zanabazarsquare", "Zanabazar_Square"),
+            ("znamennymusic", "Znamenny_Musical_Notation"),
+            ("znamennymusicalnotation", "Znamenny_Musical_Notation"),
+        ],
+    ),
+    (
+        "East_Asian_Width",
+        &[
+            ("a", "Ambiguous"),
+            ("ambiguous", "Ambiguous"),
+            ("f", "Fullwidth"),
+            ("fullwidth", "Fullwidth"),
+            ("h", "Halfwidth"),
+            ("halfwidth", "Halfwidth"),
+            ("n", "Neutral"),
+            ("na", "Narrow"),
+            ("narrow", "Narrow"),
+            ("neutral", "Neutral"),
+            ("w", "Wide"),
+            ("wide", "Wide"),
+        ],
+    ),
     (
         "General_Category",
         &[
@@ -189,6 +817,114 @@ pub const PROPERTY_VALUES: &'static [(
             ("zwj", "ZWJ"),
         ],
     ),
+    (
+        "Line_Break",
+        &[
+            ("", "Infix_Numeric"),
+            ("ai", "Ambiguous"),
+            ("ak", "Aksara"),
+            ("aksara", "Aksara"),
+            ("aksaraprebase", "Aksara_Prebase"),
+            ("aksarastart", "Aksara_Start"),
+            ("al", "Alphabetic"),
+            ("alphabetic", "Alphabetic"),
+            ("ambiguous", "Ambiguous"),
+            ("ap", "Aksara_Prebase"),
+            ("as", "Aksara_Start"),
+            ("b2", "Break_Both"),
+            ("ba", "Break_After"),
+            ("bb", "Break_Before"),
+            ("bk", "Mandatory_Break"),
+            ("breakafter", "Break_After"),
+            ("breakbefore", "Break_Before"),
+            ("breakboth", "Break_Both"),
+            ("breaksymbols", "Break_Symbols"),
+            ("carriagereturn", "Carriage_Return"),
+            ("cb", "Contingent_Break"),
+            ("cj", "Conditional_Japanese_Starter"),
+            ("cl", "Close_Punctuation"),
+            ("closeparenthesis", "Close_Parenthesis"),
+            ("closepunctuation", "Close_Punctuation"),
+            ("cm", "Combining_Mark"),
+            ("combiningmark", "Combining_Mark"),
+            ("complexcontext", "Complex_Context"),
+            ("conditionaljapanesestarter", "Conditional_Japanese_Starter"),
+            ("contingentbreak", "Contingent_Break"),
+            ("cp", "Close_Parenthesis"),
+            ("cr", "Carriage_Return"),
+            ("eb", "E_Base"),
+            ("ebase", "E_Base"),
+            ("em", "E_Modifier"),
+            ("emodifier", "E_Modifier"),
+            ("ex", "Exclamation"),
+            ("exclamation", "Exclamation"),
+            ("gl", "Glue"),
+            ("glue", "Glue"),
+            ("h2", "H2"),
+            ("h3", "H3"),
+            ("hebrewletter", "Hebrew_Letter"),
+            ("hl", "Hebrew_Letter"),
+            ("hy", "Hyphen"),
+            ("hyphen", "Hyphen"),
+            ("id", "Ideographic"),
+            ("ideographic", "Ideographic"),
+            ("in", "Inseparable"),
+            ("infixnumeric", "Infix_Numeric"),
+            ("inseparable", "Inseparable"),
+            ("inseperable", "Inseparable"),
+            ("jl", "JL"),
+            ("jt", "JT"),
+            ("jv", "JV"),
+            ("lf", "Line_Feed"),
+            ("linefeed", "Line_Feed"),
+            ("mandatorybreak", "Mandatory_Break"),
+            ("nextline", "Next_Line"),
+            ("nl", "Next_Line"),
+            ("nonstarter", "Nonstarter"),
+            ("ns", "Nonstarter"),
+            ("nu", "Numeric"),
+            ("numeric", "Numeric"),
+            ("op", "Open_Punctuation"),
+            ("openpunctuation", "Open_Punctuation"),
+            ("po", "Postfix_Numeric"),
+            ("postfixnumeric", "Postfix_Numeric"),
+            ("pr", "Prefix_Numeric"),
+            ("prefixnumeric", "Prefix_Numeric"),
+            ("qu", "Quotation"),
+            ("quotation", "Quotation"),
+            ("regionalindicator", "Regional_Indicator"),
+            ("ri", "Regional_Indicator"),
+            ("sa", "Complex_Context"),
+            ("sg", "Surrogate"),
+            ("sp", "Space"),
+            ("space", "Space"),
+            ("surrogate", "Surrogate"),
+            ("sy", "Break_Symbols"),
+            ("unknown", "Unknown"),
+            ("vf", "Virama_Final"),
+            ("vi", "Virama"),
+            ("virama", "Virama"),
+            ("viramafinal", "Virama_Final"),
+            ("wj", "Word_Joiner"),
+            ("wordjoiner", "Word_Joiner"),
+            ("xx", "Unknown"),
+            ("zw", "ZWSpace"),
+            ("zwj", "ZWJ"),
+            ("zwspace", "ZWSpace"),
+        ],
+    ),
+    (
+        "Numeric_Type",
+        &[
+            ("de", "Decimal"),
+            ("decimal", "Decimal"),
+            ("di", "Digit"),
+            ("digit", "Digit"),
+            ("none", "None"),
+            ("nu", "Numeric"),
+            ("numeric", "Numeric"),
+        ],
+    ),
     (
         "Script",
         &[
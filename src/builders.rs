@@ -43,6 +43,9 @@ use crate::error::Error;
 #[derive(Clone, Debug)]
 struct Builder {
     pats: Vec<String>,
+    /// The name of each pattern in `pats`, if it has one. This is only used
+    /// when building regex sets.
+    names: Vec<Option<String>>,
     metac: meta::Config,
     syntaxc: syntax::Config,
 }
@@ -52,7 +55,12 @@ impl Default for Builder {
         let metac = meta::Config::new()
             .nfa_size_limit(Some(10 * (1 << 20)))
             .hybrid_cache_capacity(2 * (1 << 20));
-        Builder { pats: vec![], metac, syntaxc: syntax::Config::default() }
+        Builder {
+            pats: vec![],
+            names: vec![],
+            metac,
+            syntaxc: syntax::Config::default(),
+        }
     }
 }
 
@@ -64,9 +72,42 @@ impl Builder {
     {
         let mut b = Builder::default();
         b.pats.extend(patterns.into_iter().map(|p| p.as_ref().to_string()));
+        b.names.resize(b.pats.len(), None);
+        b
+    }
+
+    fn new_named<I, N, S>(patterns: I) -> Builder
+    where
+        N: AsRef<str>,
+        S: AsRef<str>,
+        I: IntoIterator<Item = (N, S)>,
+    {
+        let mut b = Builder::default();
+        for (name, pattern) in patterns {
+            b.names.push(Some(name.as_ref().to_string()));
+            b.pats.push(pattern.as_ref().to_string());
+        }
         b
     }
 
+    /// Returns the names of the patterns in this builder, or an error if two
+    /// patterns have the same name.
+    fn pattern_names(&self) -> Result<Arc<[Option<String>]>, Error> {
+        for (i, name) in self.names.iter().enumerate() {
+            let name = match *name {
+                None => continue,
+                Some(ref name) => name,
+            };
+            if self.names[..i].iter().any(|n| n.as_ref() == Some(name)) {
+                return Err(Error::Syntax(alloc::format!(
+                    "duplicate pattern name '{}'",
+                    name
+                )));
+            }
+        }
+        Ok(Arc::from(self.names.as_slice()))
+    }
+
     fn build_one_string(&self) -> Result<crate::Regex, Error> {
         assert_eq!(1, self.pats.len());
         let metac = self
@@ -80,7 +121,7 @@ impl Builder {
             .configure(metac)
             .syntax(syntaxc)
             .build(&pattern)
-            .map(|meta| crate::Regex { meta, pattern })
+            .map(|meta| crate::Regex { meta, pattern, syntaxc })
             .map_err(Error::from_meta_build_error)
     }
 
@@ -97,7 +138,7 @@ impl Builder {
             .configure(metac)
             .syntax(syntaxc)
             .build(&pattern)
-            .map(|meta| crate::bytes::Regex { meta, pattern })
+            .map(|meta| crate::bytes::Regex { meta, pattern, syntaxc })
             .map_err(Error::from_meta_build_error)
     }
 
@@ -110,12 +151,22 @@ impl Builder {
             .which_captures(WhichCaptures::None);
         let syntaxc = self.syntaxc.clone().utf8(true);
         let patterns = Arc::from(self.pats.as_slice());
+        let names = self.pattern_names()?;
         meta::Builder::new()
             .configure(metac)
             .syntax(syntaxc)
             .build_many(&patterns)
-            .map(|meta| crate::RegexSet { meta, patterns })
             .map_err(Error::from_meta_build_error)
+            .and_then(|meta| {
+                let leftmost = self.build_many_leftmost(&patterns, true)?;
+                Ok(crate::RegexSet {
+                    meta,
+                    leftmost,
+                    patterns,
+                    names,
+                    syntaxc,
+                })
+            })
     }
 
     fn build_many_bytes(&self) -> Result<crate::bytes::RegexSet, Error> {
@@ -127,11 +178,48 @@ impl Builder {
             .which_captures(WhichCaptures::None);
         let syntaxc = self.syntaxc.clone().utf8(false);
         let patterns = Arc::from(self.pats.as_slice());
+        let names = self.pattern_names()?;
         meta::Builder::new()
             .configure(metac)
             .syntax(syntaxc)
             .build_many(&patterns)
-            .map(|meta| crate::bytes::RegexSet { meta, patterns })
+            .map_err(Error::from_meta_build_error)
+            .and_then(|meta| {
+                let leftmost = self.build_many_leftmost(&patterns, false)?;
+                Ok(crate::bytes::RegexSet {
+                    meta,
+                    leftmost,
+                    patterns,
+                    names,
+                    syntaxc,
+                })
+            })
+    }
+
+    /// Returns a regex for the given set of patterns that uses leftmost-first
+    /// match semantics and reports capture groups, just like a `Regex`.
+    ///
+    /// A regex set is built with `MatchKind::All` semantics so that it can
+    /// report every pattern that matches, but this makes it unsuitable for
+    /// finding match spans. So we build this second regex for that. Since
+    /// it has capture groups and the set itself doesn't, it can exceed the
+    /// configured size limit even though the set didn't, in which case
+    /// building the set fails.
+    fn build_many_leftmost(
+        &self,
+        patterns: &Arc<[String]>,
+        utf8: bool,
+    ) -> Result<meta::Regex, Error> {
+        let metac = self
+            .metac
+            .clone()
+            .match_kind(MatchKind::LeftmostFirst)
+            .utf8_empty(utf8);
+        let syntaxc = self.syntaxc.clone().utf8(utf8);
+        meta::Builder::new()
+            .configure(metac)
+            .syntax(syntaxc)
+            .build_many(patterns)
             .map_err(Error::from_meta_build_error)
     }
 
@@ -155,6 +243,21 @@ impl Builder {
         self
     }
 
+    fn grapheme(&mut self, yes: bool) -> &mut Builder {
+        self.syntaxc = self.syntaxc.grapheme(yes);
+        self
+    }
+
+    fn full_case_folding(&mut self, yes: bool) -> &mut Builder {
+        self.syntaxc = self.syntaxc.full_case_folding(yes);
+        self
+    }
+
+    fn turkic_case_folding(&mut self, yes: bool) -> &mut Builder {
+        self.syntaxc = self.syntaxc.turkic_case_folding(yes);
+        self
+    }
+
     fn line_terminator(&mut self, byte: u8) -> &mut Builder {
         self.metac = self.metac.clone().line_terminator(byte);
         self.syntaxc = self.syntaxc.line_terminator(byte);
@@ -452,6 +555,123 @@ pub(crate) mod string {
             self
         }
 
+        /// This configures grapheme mode for the entire pattern.
+        ///
+        /// When grapheme mode is enabled (and Unicode mode is enabled), `.`
+        /// and negated bracketed character classes like `[^a-z]` match an
+        /// entire extended grapheme cluster instead of a single codepoint,
+        /// just like `\X` does. The first codepoint of the cluster must be
+        /// one that `.` or the class would otherwise have matched. So for
+        /// example, `.` still won't match a cluster starting with `\n` unless
+        /// dot-matches-new-line mode is also enabled.
+        ///
+        /// Beware that matching a grapheme cluster requires a much bigger
+        /// automaton than matching a single codepoint. Each occurrence of `.`
+        /// or `\X` in grapheme mode adds several hundred NFA states (and
+        /// several thousand to the reverse NFA), so bounded repetitions like
+        /// `.{100}` can quickly exceed the configured size limit.
+        ///
+        /// Also, the rule for Indic conjunct sequences (GB9c) isn't
+        /// implemented, so those sequences are split into multiple clusters.
+        ///
+        /// This setting can also be configured using the inline flag `g` in
+        /// the pattern.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexBuilder;
+        ///
+        /// let re = RegexBuilder::new(r"^.$").grapheme(true).build().unwrap();
+        /// // "e" followed by a combining accent is a single grapheme cluster.
+        /// assert!(re.is_match("e\u{301}"));
+        /// // Grapheme mode can be disabled for part of the pattern.
+        /// let re = RegexBuilder::new(r"^(?-g:.)$")
+        ///     .grapheme(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(!re.is_match("e\u{301}"));
+        /// ```
+        pub fn grapheme(&mut self, yes: bool) -> &mut RegexBuilder {
+            self.builder.grapheme(yes);
+            self
+        }
+
+        /// This configures full case folding for case insensitive matching.
+        ///
+        /// By default, case insensitive matching uses simple case folding,
+        /// which only relates single codepoints to one another. When this is
+        /// enabled, a literal or non-negated character class that contains a
+        /// codepoint whose full case folding is a sequence of codepoints also
+        /// matches anything that case folds to that sequence. For example,
+        /// `(?i)ß` matches `SS` and `(?i)ﬁ` matches `fi`. Conversely, a
+        /// sequence of case insensitive characters that spells out such a
+        /// case folding also matches the codepoint, so `(?i)ss` matches `ß`.
+        /// These sequences are found greedily from left to right, which means
+        /// that `(?i)sss` matches `ßs` but not `sß`.
+        ///
+        /// Negated classes like `[^ß]` only ever match a single codepoint, so
+        /// this setting doesn't change what they match.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexBuilder;
+        ///
+        /// let re = RegexBuilder::new(r"(?i)straße")
+        ///     .full_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(re.is_match("STRASSE"));
+        /// // Sequences in the pattern also match the codepoint they're the
+        /// // case folding of.
+        /// let re = RegexBuilder::new(r"(?i)strasse")
+        ///     .full_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(re.is_match("straße"));
+        /// ```
+        pub fn full_case_folding(&mut self, yes: bool) -> &mut RegexBuilder {
+            self.builder.full_case_folding(yes);
+            self
+        }
+
+        /// This configures Turkic tailoring of case folding.
+        ///
+        /// When enabled, case insensitive matching follows the conventions of
+        /// Turkish and Azerbaijani for dotted and dotless `i`: `I` is related
+        /// to `ı` (instead of `i`) and `İ` is related to `i` (instead of
+        /// `I`). This may be combined with full case folding.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexBuilder;
+        ///
+        /// let re = RegexBuilder::new(r"(?i)^istanbul$")
+        ///     .turkic_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(re.is_match("İSTANBUL"));
+        /// assert!(!re.is_match("ISTANBUL"));
+        /// ```
+        pub fn turkic_case_folding(&mut self, yes: bool) -> &mut RegexBuilder {
+            self.builder.turkic_case_folding(yes);
+            self
+        }
+
         /// Configures the line terminator to be used by the regex.
         ///
         /// The line terminator is relevant in two ways for a particular regex:
@@ -803,6 +1023,37 @@ pub(crate) mod string {
             RegexSetBuilder { builder: Builder::new(patterns) }
         }
 
+        /// Create a new builder with a default configuration for the given
+        /// pairs of names and patterns.
+        ///
+        /// This is like [`RegexSetBuilder::new`], except each pattern is
+        /// also given a name. The name of a pattern in the set can be looked
+        /// up by its index with [`RegexSet::name`]. It is an error (reported
+        /// by [`RegexSetBuilder::build`]) for two patterns to have the same
+        /// name.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexSetBuilder;
+        ///
+        /// let set = RegexSetBuilder::new_named([
+        ///     ("ident", r"[a-z]+"),
+        ///     ("number", r"[0-9]+"),
+        /// ]).build().unwrap();
+        /// let (index, caps) = set.captures("x = 42").unwrap();
+        /// assert_eq!(Some("ident"), set.name(index));
+        /// assert_eq!(0..1, caps.get(0).unwrap().range());
+        /// ```
+        pub fn new_named<I, N, S>(patterns: I) -> RegexSetBuilder
+        where
+            I: IntoIterator<Item = (N, S)>,
+            N: AsRef<str>,
+            S: AsRef<str>,
+        {
+            RegexSetBuilder { builder: Builder::new_named(patterns) }
+        }
+
         /// Compiles the patterns given to `RegexSetBuilder::new` with the
         /// configuration set on this builder.
         ///
@@ -1029,6 +1280,126 @@ pub(crate) mod string {
             self
         }
 
+        /// This configures grapheme mode for the entire pattern.
+        ///
+        /// When grapheme mode is enabled (and Unicode mode is enabled), `.`
+        /// and negated bracketed character classes like `[^a-z]` match an
+        /// entire extended grapheme cluster instead of a single codepoint,
+        /// just like `\X` does. The first codepoint of the cluster must be
+        /// one that `.` or the class would otherwise have matched. So for
+        /// example, `.` still won't match a cluster starting with `\n` unless
+        /// dot-matches-new-line mode is also enabled.
+        ///
+        /// Beware that matching a grapheme cluster requires a much bigger
+        /// automaton than matching a single codepoint. Each occurrence of `.`
+        /// or `\X` in grapheme mode adds several hundred NFA states (and
+        /// several thousand to the reverse NFA), so bounded repetitions like
+        /// `.{100}` can quickly exceed the configured size limit.
+        ///
+        /// Also, the rule for Indic conjunct sequences (GB9c) isn't
+        /// implemented, so those sequences are split into multiple clusters.
+        ///
+        /// This setting can also be configured using the inline flag `g` in
+        /// the pattern.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexSetBuilder;
+        ///
+        /// let re = RegexSetBuilder::new([r"^(?-g:.)$", r"^.$"])
+        ///     .grapheme(true)
+        ///     .build()
+        ///     .unwrap();
+        /// // The second regex matches "e" followed by a combining accent as
+        /// // a single grapheme cluster, but the first doesn't.
+        /// let matches: Vec<_> = re.matches("e\u{301}").into_iter().collect();
+        /// assert_eq!(matches, vec![1]);
+        /// ```
+        pub fn grapheme(&mut self, yes: bool) -> &mut RegexSetBuilder {
+            self.builder.grapheme(yes);
+            self
+        }
+
+        /// This configures full case folding for case insensitive matching.
+        ///
+        /// By default, case insensitive matching uses simple case folding,
+        /// which only relates single codepoints to one another. When this is
+        /// enabled, a literal or non-negated character class that contains a
+        /// codepoint whose full case folding is a sequence of codepoints also
+        /// matches anything that case folds to that sequence. For example,
+        /// `(?i)ß` matches `SS` and `(?i)ﬁ` matches `fi`. Conversely, a
+        /// sequence of case insensitive characters that spells out such a
+        /// case folding also matches the codepoint, so `(?i)ss` matches `ß`.
+        /// These sequences are found greedily from left to right, which means
+        /// that `(?i)sss` matches `ßs` but not `sß`.
+        ///
+        /// Negated classes like `[^ß]` only ever match a single codepoint, so
+        /// this setting doesn't change what they match.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexSetBuilder;
+        ///
+        /// let re = RegexSetBuilder::new([r"(?i)straße", r"(?i)strasse"])
+        ///     .full_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// // `ß` matches `SS` and `ss` matches `ß`.
+        /// let matches: Vec<_> = re.matches("STRASSE").into_iter().collect();
+        /// assert_eq!(matches, vec![0, 1]);
+        /// let matches: Vec<_> = re.matches("straße").into_iter().collect();
+        /// assert_eq!(matches, vec![0, 1]);
+        /// ```
+        pub fn full_case_folding(
+            &mut self,
+            yes: bool,
+        ) -> &mut RegexSetBuilder {
+            self.builder.full_case_folding(yes);
+            self
+        }
+
+        /// This configures Turkic tailoring of case folding.
+        ///
+        /// When enabled, case insensitive matching follows the conventions of
+        /// Turkish and Azerbaijani for dotted and dotless `i`: `I` is related
+        /// to `ı` (instead of `i`) and `İ` is related to `i` (instead of
+        /// `I`). This may be combined with full case folding.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::RegexSetBuilder;
+        ///
+        /// let pats = [r"(?i)^istanbul$", r"(?i)^ıstanbul$"];
+        /// let re = RegexSetBuilder::new(pats)
+        ///     .turkic_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// let matches: Vec<_> = re.matches("İSTANBUL").into_iter().collect();
+        /// assert_eq!(matches, vec![0]);
+        /// ```
+        pub fn turkic_case_folding(
+            &mut self,
+            yes: bool,
+        ) -> &mut RegexSetBuilder {
+            self.builder.turkic_case_folding(yes);
+            self
+        }
+
         /// Configures the line terminator to be used by the regex.
         ///
         /// The line terminator is relevant in two ways for a particular regex:
@@ -1622,6 +1993,123 @@ pub(crate) mod bytes {
             self
         }
 
+        /// This configures grapheme mode for the entire pattern.
+        ///
+        /// When grapheme mode is enabled (and Unicode mode is enabled), `.`
+        /// and negated bracketed character classes like `[^a-z]` match an
+        /// entire extended grapheme cluster instead of a single codepoint,
+        /// just like `\X` does. The first codepoint of the cluster must be
+        /// one that `.` or the class would otherwise have matched. So for
+        /// example, `.` still won't match a cluster starting with `\n` unless
+        /// dot-matches-new-line mode is also enabled.
+        ///
+        /// Beware that matching a grapheme cluster requires a much bigger
+        /// automaton than matching a single codepoint. Each occurrence of `.`
+        /// or `\X` in grapheme mode adds several hundred NFA states (and
+        /// several thousand to the reverse NFA), so bounded repetitions like
+        /// `.{100}` can quickly exceed the configured size limit.
+        ///
+        /// Also, the rule for Indic conjunct sequences (GB9c) isn't
+        /// implemented, so those sequences are split into multiple clusters.
+        ///
+        /// This setting can also be configured using the inline flag `g` in
+        /// the pattern.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexBuilder;
+        ///
+        /// let re = RegexBuilder::new(r"^.$").grapheme(true).build().unwrap();
+        /// // "e" followed by a combining accent is a single grapheme cluster.
+        /// assert!(re.is_match("e\u{301}".as_bytes()));
+        /// // Grapheme mode can be disabled for part of the pattern.
+        /// let re = RegexBuilder::new(r"^(?-g:.)$")
+        ///     .grapheme(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(!re.is_match("e\u{301}".as_bytes()));
+        /// ```
+        pub fn grapheme(&mut self, yes: bool) -> &mut RegexBuilder {
+            self.builder.grapheme(yes);
+            self
+        }
+
+        /// This configures full case folding for case insensitive matching.
+        ///
+        /// By default, case insensitive matching uses simple case folding,
+        /// which only relates single codepoints to one another. When this is
+        /// enabled, a literal or non-negated character class that contains a
+        /// codepoint whose full case folding is a sequence of codepoints also
+        /// matches anything that case folds to that sequence. For example,
+        /// `(?i)ß` matches `SS` and `(?i)ﬁ` matches `fi`. Conversely, a
+        /// sequence of case insensitive characters that spells out such a
+        /// case folding also matches the codepoint, so `(?i)ss` matches `ß`.
+        /// These sequences are found greedily from left to right, which means
+        /// that `(?i)sss` matches `ßs` but not `sß`.
+        ///
+        /// Negated classes like `[^ß]` only ever match a single codepoint, so
+        /// this setting doesn't change what they match.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexBuilder;
+        ///
+        /// let re = RegexBuilder::new(r"(?i)straße")
+        ///     .full_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(re.is_match(b"STRASSE"));
+        /// // Sequences in the pattern also match the codepoint they're the
+        /// // case folding of.
+        /// let re = RegexBuilder::new(r"(?i)strasse")
+        ///     .full_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(re.is_match("straße".as_bytes()));
+        /// ```
+        pub fn full_case_folding(&mut self, yes: bool) -> &mut RegexBuilder {
+            self.builder.full_case_folding(yes);
+            self
+        }
+
+        /// This configures Turkic tailoring of case folding.
+        ///
+        /// When enabled, case insensitive matching follows the conventions of
+        /// Turkish and Azerbaijani for dotted and dotless `i`: `I` is related
+        /// to `ı` (instead of `i`) and `İ` is related to `i` (instead of
+        /// `I`). This may be combined with full case folding.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexBuilder;
+        ///
+        /// let re = RegexBuilder::new(r"(?i)^istanbul$")
+        ///     .turkic_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// assert!(re.is_match("İSTANBUL".as_bytes()));
+        /// assert!(!re.is_match(b"ISTANBUL"));
+        /// ```
+        pub fn turkic_case_folding(&mut self, yes: bool) -> &mut RegexBuilder {
+            self.builder.turkic_case_folding(yes);
+            self
+        }
+
         /// Configures the line terminator to be used by the regex.
         ///
         /// The line terminator is relevant in two ways for a particular regex:
@@ -1982,6 +2470,37 @@ pub(crate) mod bytes {
             RegexSetBuilder { builder: Builder::new(patterns) }
         }
 
+        /// Create a new builder with a default configuration for the given
+        /// pairs of names and patterns.
+        ///
+        /// This is like [`RegexSetBuilder::new`], except each pattern is
+        /// also given a name. The name of a pattern in the set can be looked
+        /// up by its index with [`RegexSet::name`]. It is an error (reported
+        /// by [`RegexSetBuilder::build`]) for two patterns to have the same
+        /// name.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexSetBuilder;
+        ///
+        /// let set = RegexSetBuilder::new_named([
+        ///     ("ident", r"[a-z]+"),
+        ///     ("number", r"[0-9]+"),
+        /// ]).build().unwrap();
+        /// let (index, caps) = set.captures(b"x = 42").unwrap();
+        /// assert_eq!(Some("ident"), set.name(index));
+        /// assert_eq!(0..1, caps.get(0).unwrap().range());
+        /// ```
+        pub fn new_named<I, N, S>(patterns: I) -> RegexSetBuilder
+        where
+            I: IntoIterator<Item = (N, S)>,
+            N: AsRef<str>,
+            S: AsRef<str>,
+        {
+            RegexSetBuilder { builder: Builder::new_named(patterns) }
+        }
+
         /// Compiles the patterns given to `RegexSetBuilder::new` with the
         /// configuration set on this builder.
         ///
@@ -2220,6 +2739,129 @@ pub(crate) mod bytes {
             self
         }
 
+        /// This configures grapheme mode for the entire pattern.
+        ///
+        /// When grapheme mode is enabled (and Unicode mode is enabled), `.`
+        /// and negated bracketed character classes like `[^a-z]` match an
+        /// entire extended grapheme cluster instead of a single codepoint,
+        /// just like `\X` does. The first codepoint of the cluster must be
+        /// one that `.` or the class would otherwise have matched. So for
+        /// example, `.` still won't match a cluster starting with `\n` unless
+        /// dot-matches-new-line mode is also enabled.
+        ///
+        /// Beware that matching a grapheme cluster requires a much bigger
+        /// automaton than matching a single codepoint. Each occurrence of `.`
+        /// or `\X` in grapheme mode adds several hundred NFA states (and
+        /// several thousand to the reverse NFA), so bounded repetitions like
+        /// `.{100}` can quickly exceed the configured size limit.
+        ///
+        /// Also, the rule for Indic conjunct sequences (GB9c) isn't
+        /// implemented, so those sequences are split into multiple clusters.
+        ///
+        /// This setting can also be configured using the inline flag `g` in
+        /// the pattern.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexSetBuilder;
+        ///
+        /// let re = RegexSetBuilder::new([r"^(?-g:.)$", r"^.$"])
+        ///     .grapheme(true)
+        ///     .build()
+        ///     .unwrap();
+        /// // The second regex matches "e" followed by a combining accent as
+        /// // a single grapheme cluster, but the first doesn't.
+        /// let hay = "e\u{301}".as_bytes();
+        /// let matches: Vec<_> = re.matches(hay).into_iter().collect();
+        /// assert_eq!(matches, vec![1]);
+        /// ```
+        pub fn grapheme(&mut self, yes: bool) -> &mut RegexSetBuilder {
+            self.builder.grapheme(yes);
+            self
+        }
+
+        /// This configures full case folding for case insensitive matching.
+        ///
+        /// By default, case insensitive matching uses simple case folding,
+        /// which only relates single codepoints to one another. When this is
+        /// enabled, a literal or non-negated character class that contains a
+        /// codepoint whose full case folding is a sequence of codepoints also
+        /// matches anything that case folds to that sequence. For example,
+        /// `(?i)ß` matches `SS` and `(?i)ﬁ` matches `fi`. Conversely, a
+        /// sequence of case insensitive characters that spells out such a
+        /// case folding also matches the codepoint, so `(?i)ss` matches `ß`.
+        /// These sequences are found greedily from left to right, which means
+        /// that `(?i)sss` matches `ßs` but not `sß`.
+        ///
+        /// Negated classes like `[^ß]` only ever match a single codepoint, so
+        /// this setting doesn't change what they match.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexSetBuilder;
+        ///
+        /// let re = RegexSetBuilder::new([r"(?i)straße", r"(?i)strasse"])
+        ///     .full_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// // `ß` matches `SS` and `ss` matches `ß`.
+        /// let matches: Vec<_> = re.matches(b"STRASSE").into_iter().collect();
+        /// assert_eq!(matches, vec![0, 1]);
+        /// let hay = "straße".as_bytes();
+        /// let matches: Vec<_> = re.matches(hay).into_iter().collect();
+        /// assert_eq!(matches, vec![0, 1]);
+        /// ```
+        pub fn full_case_folding(
+            &mut self,
+            yes: bool,
+        ) -> &mut RegexSetBuilder {
+            self.builder.full_case_folding(yes);
+            self
+        }
+
+        /// This configures Turkic tailoring of case folding.
+        ///
+        /// When enabled, case insensitive matching follows the conventions of
+        /// Turkish and Azerbaijani for dotted and dotless `i`: `I` is related
+        /// to `ı` (instead of `i`) and `İ` is related to `i` (instead of
+        /// `I`). This may be combined with full case folding.
+        ///
+        /// This only has an effect when both Unicode mode and case
+        /// insensitive mode are enabled.
+        ///
+        /// The default for this is `false`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use regex::bytes::RegexSetBuilder;
+        ///
+        /// let pats = [r"(?i)^istanbul$", r"(?i)^ıstanbul$"];
+        /// let re = RegexSetBuilder::new(pats)
+        ///     .turkic_case_folding(true)
+        ///     .build()
+        ///     .unwrap();
+        /// let hay = "İSTANBUL".as_bytes();
+        /// let matches: Vec<_> = re.matches(hay).into_iter().collect();
+        /// assert_eq!(matches, vec![0]);
+        /// ```
+        pub fn turkic_case_folding(
+            &mut self,
+            yes: bool,
+        ) -> &mut RegexSetBuilder {
+            self.builder.turkic_case_folding(yes);
+            self
+        }
+
         /// Configures the line terminator to be used by the regex.
         ///
         /// The line terminator is relevant in two ways for a particular regex:
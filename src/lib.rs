@@ -2,10 +2,11 @@
 This crate provides routines for searching strings for matches of a [regular
 expression] (aka "regex"). The regex syntax supported by this crate is similar
 to other regex engines, but it lacks several features that are not known how to
-implement efficiently. This includes, but is not limited to, look-around and
-backreferences. In exchange, all regex searches in this crate have worst case
-`O(m * n)` time complexity, where `m` is proportional to the size of the regex
-and `n` is proportional to the size of the string being searched.
+implement efficiently. This includes, but is not limited to, backreferences
+and look-behind of unbounded length. In exchange, all regex searches in this
+crate have worst case `O(m * n)` time complexity, where `m` is proportional to
+the size of the regex and `n` is proportional to the size of the string being
+searched.
 
 [regular expression]: https://en.wikipedia.org/wiki/Regular_expression
 
@@ -342,7 +343,9 @@ assert_eq!(after, "01/05/1973, 08/25/1975 and 10/18/1980");
 
 The replace methods are actually polymorphic in the replacement, which
 provides more flexibility than is seen here. (See the documentation for
-[`Regex::replace`] for more details.)
+[`Regex::replace`] for more details.) For example, a replacement string that
+is used many times can be parsed once with a [`ReplacementTemplate`], which
+also reports references to capture groups that don't exist as errors.
 
 ### Example: verbose mode
 
@@ -647,6 +650,7 @@ a separate crate, [`regex-syntax`](https://docs.rs/regex-syntax).
 \p{Greek}     Unicode character class (general category or script)
 \PX           Negated Unicode character class identified by a one-letter name
 \P{Greek}     negated Unicode character class (general category or script)
+\X            an extended grapheme cluster (requires Unicode mode)
 </pre>
 
 ### Character classes
@@ -735,8 +739,21 @@ $               the end of a haystack (or end-of-line with multi-line mode)
 \b{end}, \>     a Unicode end-of-word boundary (\w on the left, \W|\z on the right))
 \b{start-half}  half of a Unicode start-of-word boundary (\W|\A on the left)
 \b{end-half}    half of a Unicode end-of-word boundary (\W|\z on the right)
+\b{wb}          a Unicode word segmentation boundary (as defined by UAX #29)
+\b{sb}          a Unicode sentence segmentation boundary (as defined by UAX #29)
 </pre>
 
+The `\b{wb}` and `\b{sb}` assertions implement the word and sentence
+boundary rules from [UAX #29]. Unlike `\b`, they consider the text
+surrounding a position, so contractions like `can't` and numbers like `3.14`
+are not split. They are only available in Unicode mode and are never
+satisfied in an empty haystack. Since no DFA supports them, regexes using
+them are executed by slower engines. Checking a single position may need to
+look at an arbitrary amount of surrounding text, so their use on large
+haystacks can be expensive.
+
+[UAX #29]: https://www.unicode.org/reports/tr29/
+
 The empty regex is valid and matches the empty string. For example, the
 empty regex matches `abc` at positions `0`, `1`, `2` and `3`. When using the
 top-level [`Regex`] on `&str` haystacks, an empty match that splits a codepoint
@@ -768,8 +785,24 @@ nothing, not even the empty string.
 (?:exp)        non-capturing group
 (?flags)       set flags within current group
 (?flags:exp)   set flags for exp (non-capturing)
+(?=exp)        positive look-ahead: exp matches at the current position
+(?!exp)        negative look-ahead: exp does not match at the current position
+(?&lt;=exp)       positive look-behind: exp matches ending at the current position
+(?&lt;!exp)       negative look-behind: exp does not match ending at the current position
 </pre>
 
+Look-around assertions are zero-width, like `^` or `\b`. They are restricted
+so that they can be evaluated without giving up the worst case time bound of
+this crate: the sub-expression of a look-behind must have a bounded length
+(so `(?<=ab|c)` is allowed but `(?<=a+)` is not), look-around assertions
+may not be nested and they may not contain capture groups. Look-around is
+only supported by the slower NFA based regex engines, so using it disables
+most optimizations. Each search also does an extra pass over the span being
+searched for every look-around assertion in the pattern. (When the length of
+a look-ahead's sub-expression is unbounded, its pass continues to the end of
+the haystack.) Iterators like [`Regex::find_iter`] only do these passes once
+for the whole haystack, not once for every match.
+
 Capture group names must be any sequence of alpha-numeric Unicode codepoints,
 in addition to `.`, `_`, `[` and `]`. Names must start with either an `_` or
 an alphabetic codepoint. Alphabetic codepoints correspond to the `Alphabetic`
@@ -788,11 +821,25 @@ i     case-insensitive: letters match both upper and lower case
 m     multi-line mode: ^ and $ match begin/end of line
 s     allow . to match \n
 R     enables CRLF mode: when multi-line mode is enabled, \r\n is used
+g     grapheme mode: . and negated classes like [^a] match a grapheme cluster
 U     swap the meaning of x* and x*?
 u     Unicode support (enabled by default)
 x     verbose mode, ignores whitespace and allow line comments (starting with `#`)
 </pre>
 
+In grapheme mode, `.` and negated bracketed classes like `[^a-z]` match an
+entire extended grapheme cluster, just like `\X`, as long as the cluster's
+first codepoint would have been matched by `.` or the class. For example,
+`(?g)^.$` matches `e\u{301}` (an `e` followed by a combining accent), but
+`^.$` doesn't. Grapheme mode has no effect when Unicode mode is disabled.
+
+Matching a grapheme cluster, whether via `\X` or grapheme mode, needs a much
+bigger automaton than matching a single codepoint: each occurrence adds several
+hundred NFA states. Large bounded repetitions like `\X{100}` may therefore
+exceed the [size limit](crate::RegexBuilder::size_limit). The rule for Indic
+conjunct sequences (GB9c) isn't implemented, so those sequences are split into
+multiple clusters.
+
 Note that in verbose mode, whitespace is ignored everywhere, including within
 character classes. To insert whitespace, use its escaped form or a hex literal.
 For example, `\ ` or `\x20` for an ASCII space.
@@ -877,6 +924,8 @@ documented elsewhere.
 \b{end}, \>     end-of-word boundary assertion
 \b{start-half}  half of a start-of-word boundary assertion
 \b{end-half}    half of a end-of-word boundary assertion
+\b{wb}          word segmentation boundary assertion (Unicode only)
+\b{sb}          sentence segmentation boundary assertion (Unicode only)
 \123            octal character code, up to three digits (when enabled)
 \x7F            hex character code (exactly two digits)
 \x{10FFFF}      any hex character code corresponding to a Unicode code point
@@ -884,10 +933,12 @@ documented elsewhere.
 \u{7F}          any hex character code corresponding to a Unicode code point
 \U0000007F      hex character code (exactly eight digits)
 \U{7F}          any hex character code corresponding to a Unicode code point
+\N{SNOWMAN}     the Unicode code point with the given name
 \p{Letter}      Unicode character class
 \P{Letter}      negated Unicode character class
 \d, \s, \w      Perl character class
 \D, \S, \W      negated Perl character class
+\X              extended grapheme cluster
 </pre>
 
 ### Perl character classes (Unicode friendly)
@@ -1180,6 +1231,12 @@ default are noted.
   typically only useful to someone working on this crate's internals, but might
   be useful if you're doing some rabbit hole performance hacking. Or if you're
   just interested in the kinds of decisions being made by the regex engine.
+* **stream** -
+  When enabled, the `bytes::Regex::stream_find_iter` API is provided for
+  searching a `std::io::Read` stream without reading it entirely into memory.
+  This requires `std`. Since streaming searches are driven by a lazy DFA,
+  enabling this feature also compiles the lazy DFA into the crate, even if
+  `perf-dfa` is disabled.
 
 ### Performance features
 
@@ -1235,6 +1292,10 @@ default are noted.
   [Unicode `Age` property](https://www.unicode.org/reports/tr44/tr44-24.html#Character_Age).
   This makes it possible to use classes like `\p{Age:6.0}` to refer to all
   codepoints first introduced in Unicode 6.0
+* **unicode-block** -
+  Provide the data for the
+  [Unicode `Block` property](https://www.unicode.org/reports/tr44/tr44-24.html#Block).
+  This makes it possible to use classes like `\p{blk=Greek_And_Coptic}`.
 * **unicode-bool** -
   Provide the data for numerous Unicode boolean properties. The full list
   is not included here, but contains properties like `Alphabetic`, `Emoji`,
@@ -1242,11 +1303,27 @@ default are noted.
 * **unicode-case** -
   Provide the data for case insensitive matching using
   [Unicode's "simple loose matches" specification](https://www.unicode.org/reports/tr18/#Simple_Loose_Matches).
+* **unicode-east-asian-width** -
+  Provide the data for the
+  [Unicode `East_Asian_Width` property](https://www.unicode.org/reports/tr11/).
+  This makes it possible to use classes like `\p{ea=Wide}`.
 * **unicode-gencat** -
   Provide the data for
   [Unicode general categories](https://www.unicode.org/reports/tr44/tr44-24.html#General_Category_Values).
   This includes, but is not limited to, `Decimal_Number`, `Letter`,
   `Math_Symbol`, `Number` and `Punctuation`.
+* **unicode-line-break** -
+  Provide the data for the
+  [Unicode `Line_Break` property](https://www.unicode.org/reports/tr14/).
+  This makes it possible to use classes like `\p{lb=Ideographic}`.
+* **unicode-name** -
+  Provide the data for looking up codepoints by their
+  [Unicode name](https://www.unicode.org/reports/tr44/tr44-24.html#Name).
+  This makes it possible to use escapes like `\N{GREEK SMALL LETTER ALPHA}`.
+* **unicode-numeric-type** -
+  Provide the data for the
+  [Unicode `Numeric_Type` property](https://www.unicode.org/reports/tr44/tr44-24.html#Numeric_Type).
+  This makes it possible to use classes like `\p{nt=Decimal}`.
 * **unicode-perl** -
   Provide the data for supporting the Unicode-aware Perl character classes,
   corresponding to `\w`, `\s` and `\d`. This is also necessary for using
@@ -1,8 +1,19 @@
 use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
 
-use regex_automata::{meta, util::captures, Input, PatternID};
+use regex_automata::{
+    meta,
+    util::{captures, syntax},
+    Input, PatternID,
+};
 
-use crate::{bytes::RegexBuilder, error::Error};
+use crate::{
+    bytes::RegexBuilder,
+    error::Error,
+    regex::{overlapping, template},
+};
+
+#[cfg(feature = "stream")]
+pub use crate::regex::stream::{StreamMatch, StreamMatches};
 
 /// A compiled regular expression for searching Unicode haystacks.
 ///
@@ -99,6 +110,10 @@ use crate::{bytes::RegexBuilder, error::Error};
 pub struct Regex {
     pub(crate) meta: meta::Regex,
     pub(crate) pattern: Arc<str>,
+    // This is only needed to build the regex engines for overlapping
+    // searches and to rebuild the regex as a pair of lazy DFAs for streaming
+    // searches, since the meta regex doesn't expose its NFAs.
+    pub(crate) syntaxc: syntax::Config,
 }
 
 impl core::fmt::Display for Regex {
@@ -231,6 +246,45 @@ impl Regex {
         self.find_at(haystack, 0)
     }
 
+    /// This routine searches backwards from the end of the haystack for the
+    /// last match of this regex, and if found, returns a [`Match`].
+    ///
+    /// The match returned is always one that [`Regex::find_at`] returns when
+    /// starting at the beginning of the match. Of those, it's the one found
+    /// from the last position at which any match starts, extended to start
+    /// as early as possible while still ending in the same place. For
+    /// example, `[0-9]+` finds `123` in `abc123`, even though matches also
+    /// start at each of `2` and `3`.
+    ///
+    /// # Time complexity
+    ///
+    /// This usually takes `O(m * n)` time, just like a forward search. But
+    /// when a lazy DFA can't be used (see
+    /// [`Regex::find_overlapping_iter`] for when that happens), it runs a
+    /// forward search from every starting position in the haystack instead,
+    /// which takes `O(m * n^2)` time in the worst case.
+    ///
+    /// # Example
+    ///
+    /// Find the last date in a haystack:
+    ///
+    /// ```
+    /// use regex::bytes::Regex;
+    ///
+    /// let re = Regex::new(r"[0-9]{4}-[0-9]{2}-[0-9]{2}").unwrap();
+    /// let hay = b"2010-03-14 and 2014-10-14";
+    /// let mat = re.rfind(hay).unwrap();
+    /// assert_eq!(15..25, mat.range());
+    /// assert_eq!(&b"2014-10-14"[..], mat.as_bytes());
+    /// ```
+    #[inline]
+    pub fn rfind<'h>(&self, haystack: &'h [u8]) -> Option<Match<'h>> {
+        let input = Input::new(haystack);
+        self.meta
+            .rsearch(&input)
+            .map(|m| Match::new(haystack, m.start(), m.end()))
+    }
+
     /// Returns an iterator that yields successive non-overlapping matches in
     /// the given haystack. The iterator yields values of type [`Match`].
     ///
@@ -263,6 +317,202 @@ impl Regex {
         Matches { haystack, it: self.meta.find_iter(haystack) }
     }
 
+    /// Returns an iterator that yields successive non-overlapping matches in
+    /// the given haystack, starting from the end of the haystack and
+    /// proceeding backwards. The iterator yields values of type [`Match`].
+    ///
+    /// Each match is found with [`Regex::rfind`] on the part of the haystack
+    /// that precedes the previous match. Like [`Regex::find_iter`], an empty
+    /// match is never yielded if it starts where the previous match did.
+    ///
+    /// The matches yielded are often the same as those yielded by
+    /// [`Regex::find_iter`], just in reverse order. But this isn't always the
+    /// case, since a forward search resumes where the previous match ended,
+    /// while a reverse search resumes where it started. For example, `aa`
+    /// matches `0..2` in `aaa` when searching forwards, but `1..3` when
+    /// searching backwards.
+    ///
+    /// # Time complexity
+    ///
+    /// Since `rfind_iter` runs potentially many searches on the haystack,
+    /// the overall worst case time complexity for iteration is `O(m * n^2)`.
+    /// When a lazy DFA can't be used, it is `O(m * n^3)`. See
+    /// [`Regex::rfind`] for details.
+    ///
+    /// # Example
+    ///
+    /// Find the last two words with exactly 13 Unicode word characters:
+    ///
+    /// ```
+    /// use regex::bytes::Regex;
+    ///
+    /// let re = Regex::new(r"\b\w{13}\b").unwrap();
+    /// let hay = b"Retroactively relinquishing remunerations is reprehensible.";
+    /// let matches: Vec<_> =
+    ///     re.rfind_iter(hay).take(2).map(|m| m.as_bytes()).collect();
+    /// assert_eq!(matches, vec![
+    ///     &b"reprehensible"[..],
+    ///     &b"remunerations"[..],
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn rfind_iter<'r, 'h>(
+        &'r self,
+        haystack: &'h [u8],
+    ) -> ReverseMatches<'r, 'h> {
+        ReverseMatches { haystack, it: self.meta.rfind_iter(haystack) }
+    }
+
+    /// Returns an iterator that yields every match in the given haystack,
+    /// including matches that overlap with one another. The iterator yields
+    /// values of type [`Match`].
+    ///
+    /// Unlike [`Regex::find_iter`], this reports every span of the haystack
+    /// that the regex matches, regardless of match priority. For example,
+    /// `a+` reports a match for every non-empty substring of `aaa`, and
+    /// `a|ab` reports both `a` and `ab` in `ab`. Greediness and the order of
+    /// alternations have no effect on which matches are reported.
+    ///
+    /// Matches are yielded in ascending order of their end offsets. Matches
+    /// with the same end offset are yielded in descending order of their
+    /// start offsets (so shorter matches come first).
+    ///
+    /// Each call to this method builds the regex engines used for overlapping
+    /// searches, which may take some time. So it's best to find all of the
+    /// matches in a haystack with a single call.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if building the regex engines used for
+    /// overlapping searches fails. This can only happen when they exceed the
+    /// configured [size limit](RegexBuilder::size_limit).
+    ///
+    /// # Time complexity
+    ///
+    /// Finding where each match ends takes `O(m * n)` time in total, but
+    /// finding where matches start requires a reverse search from each of
+    /// those ends. So the overall worst case time complexity for iteration is
+    /// `O(m * n^2)`, not counting the time it takes to yield each match.
+    ///
+    /// This relies on a lazy DFA, which can't be used for every regex or
+    /// every haystack. For example, it can't be used when the regex contains
+    /// a look-around assertion, or when the regex contains a Unicode word
+    /// boundary and the haystack contains non-ASCII bytes. It also can't be
+    /// used when the `perf-dfa` feature is disabled. In those cases, a much
+    /// slower approach is used that runs a separate search for each starting
+    /// position in the haystack. Its worst case time complexity is
+    /// `O(m * n^3)`.
+    ///
+    /// # Example
+    ///
+    /// Find every span of a haystack matching `a+`:
+    ///
+    /// ```
+    /// use regex::bytes::Regex;
+    ///
+    /// let re = Regex::new(r"a+").unwrap();
+    /// let matches: Vec<_> = re
+    ///     .find_overlapping_iter(b"aaa")
+    ///     .unwrap()
+    ///     .map(|m| m.range())
+    ///     .collect();
+    /// assert_eq!(matches, vec![0..1, 1..2, 0..2, 2..3, 1..3, 0..3]);
+    /// ```
+    ///
+    /// Find every word in a haystack that ends with `ing`, including words
+    /// that are suffixes of other words:
+    ///
+    /// ```
+    /// use regex::bytes::Regex;
+    ///
+    /// let re = Regex::new(r"\w+ing\b").unwrap();
+    /// let hay = b"a swing";
+    /// let matches: Vec<_> = re
+    ///     .find_overlapping_iter(hay)
+    ///     .unwrap()
+    ///     .map(|m| m.as_bytes())
+    ///     .collect();
+    /// assert_eq!(matches, vec![&b"wing"[..], &b"swing"[..]]);
+    /// ```
+    #[inline]
+    pub fn find_overlapping_iter<'h>(
+        &self,
+        haystack: &'h [u8],
+    ) -> Result<OverlappingMatches<'h>, Error> {
+        let engine = overlapping::Engine::new(
+            &self.meta,
+            &[self.as_str()],
+            self.syntaxc,
+        )?;
+        let it = overlapping::Searcher::new(engine, haystack);
+        Ok(OverlappingMatches { haystack, it })
+    }
+
+    /// Returns an iterator that yields successive non-overlapping matches in
+    /// the given stream. The iterator yields values of type
+    /// `std::io::Result<StreamMatch>`.
+    ///
+    /// This is useful for searching haystacks that are too big to fit into
+    /// memory, like multi-gigabyte log files, without resorting to memory
+    /// maps. The stream is read incrementally into a rolling buffer, and
+    /// matches are reported with absolute byte offsets into the stream. The
+    /// matches yielded are exactly the same as the ones that
+    /// [`Regex::find_iter`] would report if it were given the entire contents
+    /// of the stream as a single haystack. In particular, matches that
+    /// straddle the boundary between two reads and look-around assertions
+    /// like `\b` and `$` are handled correctly.
+    ///
+    /// If reading from the stream fails, then the error is yielded and the
+    /// iterator stops. Reads interrupted by
+    /// [`std::io::ErrorKind::Interrupted`] are retried automatically.
+    ///
+    /// This routine is only available when the `stream` crate feature is
+    /// enabled.
+    ///
+    /// # Memory usage
+    ///
+    /// The search is driven by a lazy DFA whose state is carried across reads.
+    /// Only the bytes that could still be part of a match are kept in memory.
+    /// For most regexes and haystacks, this is a very small amount. But for
+    /// regexes that can match arbitrarily long strings, this may include
+    /// everything from the start of the longest potential match. For example,
+    /// `a*b` searching a stream consisting of only `a` bytes will buffer the
+    /// entire stream.
+    ///
+    /// Moreover, the lazy DFA cannot handle Unicode word boundaries (`\b` when
+    /// Unicode mode is enabled) when the haystack contains non-ASCII bytes. In
+    /// that case, the rest of the stream is read into memory and searched all
+    /// at once. To avoid this, use an ASCII word boundary, e.g., `(?-u:\b)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::bytes::Regex;
+    ///
+    /// let re = Regex::new(r"[0-9]{4}-[0-9]{2}-[0-9]{2}").unwrap();
+    /// let rdr = std::io::Cursor::new(b"2010-03-14, 2014-10-05 and 2016-05-27");
+    /// let mut matches = vec![];
+    /// for result in re.stream_find_iter(rdr) {
+    ///     let m = result?;
+    ///     matches.push((m.range(), m.into_bytes()));
+    /// }
+    /// assert_eq!(matches, vec![
+    ///     (0..10, b"2010-03-14".to_vec()),
+    ///     (12..22, b"2014-10-05".to_vec()),
+    ///     (27..37, b"2016-05-27".to_vec()),
+    /// ]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "stream")]
+    #[inline]
+    pub fn stream_find_iter<R: std::io::Read>(
+        &self,
+        rdr: R,
+    ) -> StreamMatches<'_, R> {
+        StreamMatches::new(&self.meta, &self.pattern, self.syntaxc, rdr)
+    }
+
     /// This routine searches for the first match of this regex in the haystack
     /// given, and if found, returns not only the overall match but also the
     /// matches of each capture group in the regex. If no match is found, then
@@ -1548,7 +1798,11 @@ impl<'h> Match<'h> {
 
     /// Creates a new match from the given haystack and byte offsets.
     #[inline]
-    fn new(haystack: &'h [u8], start: usize, end: usize) -> Match<'h> {
+    pub(crate) fn new(
+        haystack: &'h [u8],
+        start: usize,
+        end: usize,
+    ) -> Match<'h> {
         Match { haystack, start, end }
     }
 }
@@ -1905,6 +2159,17 @@ impl<'h> Captures<'h> {
     pub fn len(&self) -> usize {
         self.caps.group_len()
     }
+
+    /// Creates a new set of capture group matches from the given haystack
+    /// and the capture groups found in it.
+    #[inline]
+    pub(crate) fn new(
+        haystack: &'h [u8],
+        caps: captures::Captures,
+        static_captures_len: Option<usize>,
+    ) -> Captures<'h> {
+        Captures { haystack, caps, static_captures_len }
+    }
 }
 
 impl<'h> core::fmt::Debug for Captures<'h> {
@@ -2189,6 +2454,74 @@ impl<'r, 'h> Iterator for Matches<'r, 'h> {
 
 impl<'r, 'h> core::iter::FusedIterator for Matches<'r, 'h> {}
 
+/// An iterator over all non-overlapping matches in a haystack, in reverse
+/// order.
+///
+/// This iterator yields [`Match`] values. The iterator stops when no more
+/// matches can be found.
+///
+/// `'r` is the lifetime of the compiled regular expression and `'h` is the
+/// lifetime of the haystack.
+///
+/// This iterator is created by [`Regex::rfind_iter`].
+///
+/// # Time complexity
+///
+/// See [`Regex::rfind_iter`] for details. In the worst case, iteration takes
+/// `O(m * n^2)` time, or `O(m * n^3)` time when a lazy DFA can't be used.
+#[derive(Debug)]
+pub struct ReverseMatches<'r, 'h> {
+    haystack: &'h [u8],
+    it: meta::RFindMatches<'r, 'h>,
+}
+
+impl<'r, 'h> Iterator for ReverseMatches<'r, 'h> {
+    type Item = Match<'h>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Match<'h>> {
+        self.it
+            .next()
+            .map(|sp| Match::new(self.haystack, sp.start(), sp.end()))
+    }
+}
+
+impl<'r, 'h> core::iter::FusedIterator for ReverseMatches<'r, 'h> {}
+
+/// An iterator over all matches in a haystack, including matches that
+/// overlap with one another.
+///
+/// This iterator yields [`Match`] values. The iterator stops when no more
+/// matches can be found.
+///
+/// `'h` is the lifetime of the haystack.
+///
+/// This iterator is created by [`Regex::find_overlapping_iter`].
+///
+/// # Time complexity
+///
+/// See [`Regex::find_overlapping_iter`] for details. In the worst case,
+/// iteration takes `O(m * n^2)` time, or `O(m * n^3)` time when a lazy DFA
+/// can't be used.
+#[derive(Debug)]
+pub struct OverlappingMatches<'h> {
+    haystack: &'h [u8],
+    it: overlapping::Searcher<'h>,
+}
+
+impl<'h> Iterator for OverlappingMatches<'h> {
+    type Item = Match<'h>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Match<'h>> {
+        self.it
+            .next()
+            .map(|(_, start, end)| Match::new(self.haystack, start, end))
+    }
+}
+
+impl<'h> core::iter::FusedIterator for OverlappingMatches<'h> {}
+
 /// An iterator over all non-overlapping capture matches in a haystack.
 ///
 /// This iterator yields [`Captures`] values. The iterator stops when no more
@@ -2600,6 +2933,203 @@ impl<'s> Replacer for NoExpand<'s> {
     }
 }
 
+/// A replacement byte string that is parsed once and can then be used for
+/// any number of replacements.
+///
+/// Using a `&[u8]` as a [`Replacer`] parses its `$name` references again for
+/// every match. A `ReplacementTemplate` parses its template once, up front,
+/// and resolves every capture group it refers to against a specific regex.
+/// This makes it a good fit for replacement strings that are used many times,
+/// and it also catches mistakes in the template early: a reference to a
+/// capture group that doesn't exist in the regex is reported as an error.
+///
+/// The syntax of capture group references is the same as the one described
+/// for [`Captures::expand`]. A template may optionally also convert the case
+/// of its output, with Perl-like directives such as `\U$1`. This is disabled
+/// by default and can be enabled with
+/// [`ReplacementTemplateBuilder::case_conversion`].
+///
+/// A template should be used with the regex it was built for. If it's used
+/// with a different regex, then references to capture groups that don't
+/// exist in that regex are replaced with the empty string.
+///
+/// # Example
+///
+/// ```
+/// use regex::bytes::{Regex, ReplacementTemplate};
+///
+/// let re = Regex::new(r"(?<y>\d{4})-(?<m>\d{2})-(?<d>\d{2})").unwrap();
+/// let template = ReplacementTemplate::new(&re, b"$m/$d/$y").unwrap();
+/// let after = re.replace_all(b"1973-01-05, 1975-08-25", &template);
+/// assert_eq!(&after[..], &b"01/05/1973, 08/25/1975"[..]);
+///
+/// // Referring to a group that doesn't exist is an error.
+/// assert!(ReplacementTemplate::new(&re, b"$month").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReplacementTemplate {
+    template: Vec<u8>,
+    pieces: Vec<template::Piece>,
+    /// The entire replacement, when the template refers to no capture
+    /// groups.
+    literal: Option<Vec<u8>>,
+}
+
+impl ReplacementTemplate {
+    /// Parses the given template for use with the given regex, using the
+    /// default configuration.
+    ///
+    /// If the template refers to a capture group that doesn't exist in the
+    /// regex, then an error is returned. To configure how a template is
+    /// parsed, use a [`ReplacementTemplateBuilder`].
+    pub fn new(
+        re: &Regex,
+        template: &[u8],
+    ) -> Result<ReplacementTemplate, Error> {
+        ReplacementTemplateBuilder::new().build(re, template)
+    }
+
+    /// Returns the template byte string that this was parsed from.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.template
+    }
+
+    /// Appends the replacement for the given match to `dst`.
+    fn expand(&self, caps: Option<&Captures<'_>>, dst: &mut Vec<u8>) {
+        let mut case = template::CaseState::default();
+        for piece in self.pieces.iter() {
+            match *piece {
+                template::Piece::Literal(ref range) => {
+                    case.push_bytes(&self.template[range.clone()], dst);
+                }
+                template::Piece::Group(index) => {
+                    if let Some(m) = caps.and_then(|caps| caps.get(index)) {
+                        case.push_bytes(m.as_bytes(), dst);
+                    }
+                }
+                template::Piece::Case(directive) => case.apply(directive),
+            }
+        }
+    }
+}
+
+impl Replacer for &ReplacementTemplate {
+    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut Vec<u8>) {
+        self.expand(Some(caps), dst)
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, [u8]>> {
+        self.literal.as_deref().map(Cow::Borrowed)
+    }
+}
+
+impl Replacer for ReplacementTemplate {
+    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut Vec<u8>) {
+        self.expand(Some(caps), dst)
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, [u8]>> {
+        self.literal.as_deref().map(Cow::Borrowed)
+    }
+}
+
+/// A configurable builder for a [`ReplacementTemplate`].
+///
+/// # Example
+///
+/// This example shows how to enable case conversion directives.
+///
+/// ```
+/// use regex::bytes::{Regex, ReplacementTemplateBuilder};
+///
+/// let re = Regex::new(r"(\w+) (\w+)").unwrap();
+/// let template = ReplacementTemplateBuilder::new()
+///     .case_conversion(true)
+///     .build(&re, br"\U$2\E, \u\L$1")
+///     .unwrap();
+/// let after = re.replace(b"bruce SPRINGSTEEN", &template);
+/// assert_eq!(&after[..], &b"SPRINGSTEEN, Bruce"[..]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReplacementTemplateBuilder {
+    case_conversion: bool,
+}
+
+impl ReplacementTemplateBuilder {
+    /// Create a new builder with the default configuration.
+    pub fn new() -> ReplacementTemplateBuilder {
+        ReplacementTemplateBuilder::default()
+    }
+
+    /// Parse the given template for use with the given regex.
+    ///
+    /// If the template refers to a capture group that doesn't exist in the
+    /// regex, or if it contains an invalid case conversion directive, then
+    /// an error is returned.
+    pub fn build(
+        &self,
+        re: &Regex,
+        template: &[u8],
+    ) -> Result<ReplacementTemplate, Error> {
+        let pieces =
+            template::parse(&re.meta, template, self.case_conversion)?;
+        let mut template = ReplacementTemplate {
+            template: template.to_vec(),
+            pieces,
+            literal: None,
+        };
+        let has_groups = template
+            .pieces
+            .iter()
+            .any(|piece| matches!(*piece, template::Piece::Group(_)));
+        if !has_groups {
+            let mut literal = Vec::new();
+            template.expand(None, &mut literal);
+            template.literal = Some(literal);
+        }
+        Ok(template)
+    }
+
+    /// Enable or disable case conversion directives in the template.
+    ///
+    /// When enabled, `\U` and `\L` convert everything after them to
+    /// uppercase or lowercase, respectively, until the next `\E`. `\u` and
+    /// `\l` convert just the next character to titlecase or lowercase, and
+    /// take precedence over `\U` and `\L`. (Titlecase is the same as
+    /// uppercase for most characters, but `\u` converts `ß` to `Ss`, for
+    /// example.) Conversions apply to the values of capture groups and to
+    /// literal text alike. A literal backslash is written as `\\`, and any
+    /// other use of a backslash is an error.
+    ///
+    /// Only characters that are valid UTF-8 have their case converted. Any
+    /// other bytes are copied as they are.
+    ///
+    /// This is disabled by default, in which case a backslash has no special
+    /// meaning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::bytes::{Regex, ReplacementTemplateBuilder};
+    ///
+    /// let re = Regex::new(r"(?-u)[a-z\xFF]+").unwrap();
+    /// let template = ReplacementTemplateBuilder::new()
+    ///     .case_conversion(true)
+    ///     .build(&re, br"\U$0")
+    ///     .unwrap();
+    /// let after = re.replace_all(b"ab\xFFcd", &template);
+    /// assert_eq!(&after[..], &b"AB\xFFCD"[..]);
+    /// ```
+    pub fn case_conversion(
+        &mut self,
+        yes: bool,
+    ) -> &mut ReplacementTemplateBuilder {
+        self.case_conversion = yes;
+        self
+    }
+}
+
 /// Quickly checks the given replacement string for whether interpolation
 /// should be done on it. It returns `None` if a `$` was found anywhere in the
 /// given string, which suggests interpolation needs to be done. But if there's
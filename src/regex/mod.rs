@@ -1,2 +1,6 @@
 pub(crate) mod bytes;
+pub(crate) mod overlapping;
+#[cfg(feature = "stream")]
+pub(crate) mod stream;
 pub(crate) mod string;
+pub(crate) mod template;
@@ -0,0 +1,356 @@
+/*!
+This module provides overlapping searches for `Regex` and `RegexSet`, along
+with their `bytes` counterparts.
+
+An overlapping search reports every match in a haystack, for every pattern.
+That is, every pair of offsets `(start, end)` for which some pattern matches
+the haystack from `start` to `end` is reported exactly once, even when that
+match overlaps with another one. For example, `a+` reports six matches in
+`aaa`.
+
+We find these in the same way as regex-automata's own tests do. A forward
+lazy DFA using `MatchKind::All` semantics finds every position at which a
+match ends (and for which pattern). Then, for each such end, a reverse lazy
+DFA runs an anchored overlapping search from that end, which finds every
+position at which a match of that pattern starts.
+
+The lazy DFA can't always be used. It can't be built for some regexes (for
+example, those with look-around) and it gives up when it sees a non-ASCII
+byte while a Unicode word boundary is in play. It's also unavailable when the
+`perf-dfa` feature is disabled. In those cases, we fall back to a much slower
+approach that uses a meta regex with `MatchKind::All` semantics. Namely, an
+anchored search with those semantics reports the longest match starting at
+its anchor and ending no later than the end of its span. So we can find every
+match starting at a given position by repeatedly shrinking the span to end
+just before the previous match found. We do this for every starting position
+and every pattern.
+*/
+
+use alloc::{boxed::Box, vec::Vec};
+
+use regex_automata::{
+    meta, nfa::thompson::WhichCaptures, util::syntax, Anchored, Input,
+    MatchKind, PatternID,
+};
+
+use crate::error::Error;
+
+/// A single match found by an overlapping search, as a pattern ID, start
+/// offset and end offset.
+type RawMatch = (PatternID, usize, usize);
+
+/// The regex engines used for overlapping searches.
+///
+/// Since most users never run an overlapping search, these are built by
+/// each call that starts one instead of along with the regex.
+#[derive(Debug)]
+pub(crate) struct Engine {
+    /// A meta regex using `MatchKind::All` semantics. This is used whenever
+    /// the lazy DFA can't be.
+    meta: meta::Regex,
+    /// A lazy DFA using `MatchKind::All` semantics, or `None` if one could
+    /// not be built.
+    #[cfg(feature = "perf-dfa")]
+    dfa: Option<regex_automata::hybrid::regex::Regex>,
+}
+
+impl Engine {
+    /// Create the engines for overlapping searches of the given patterns.
+    ///
+    /// The regex given must have been built from the given patterns using
+    /// the given syntax configuration. The engines are built with the same
+    /// configuration, except that they use `MatchKind::All` semantics.
+    ///
+    /// Building the engines can only fail when they exceed the configured
+    /// size limit.
+    pub(crate) fn new<P: AsRef<str>>(
+        re: &meta::Regex,
+        patterns: &[P],
+        syntaxc: syntax::Config,
+    ) -> Result<Engine, Error> {
+        let metac = re
+            .get_config()
+            .clone()
+            .match_kind(MatchKind::All)
+            // Without any capture groups, the meta regex can't fall back to
+            // an NFA engine to report match spans, so we keep the implicit
+            // groups around.
+            .which_captures(WhichCaptures::Implicit);
+        let meta = meta::Builder::new()
+            .configure(metac)
+            .syntax(syntaxc)
+            .build_many(patterns)
+            .map_err(Error::from_meta_build_error)?;
+        #[cfg(feature = "perf-dfa")]
+        {
+            let dfa = build_dfa(&meta, patterns, syntaxc);
+            Ok(Engine { meta, dfa })
+        }
+        #[cfg(not(feature = "perf-dfa"))]
+        {
+            Ok(Engine { meta })
+        }
+    }
+}
+
+/// An iterator over every match of every pattern in a haystack, including
+/// matches that overlap.
+///
+/// Matches are yielded in ascending order of their end offsets. Matches of
+/// the same pattern with the same end offset are yielded in descending order
+/// of their start offsets. The order of matches of different patterns with
+/// the same end offset is unspecified: the lazy DFA reports them in whatever
+/// order its match states list them, while the fallback approach reports
+/// them in ascending order of their pattern IDs.
+#[derive(Debug)]
+pub(crate) struct Searcher<'h> {
+    engine: Engine,
+    haystack: &'h [u8],
+    /// Matches that have been found but not yet yielded, in reverse order.
+    pending: Vec<RawMatch>,
+    /// The end offset of the last match yielded. Since matches are found
+    /// (and thus yielded) in complete groups sharing the same end offset,
+    /// every match ending at or before this offset has been yielded.
+    last_end: Option<usize>,
+    imp: Imp,
+}
+
+#[derive(Debug)]
+enum Imp {
+    /// Matches are found with the lazy DFA.
+    #[cfg(feature = "perf-dfa")]
+    Dfa {
+        cache: Box<regex_automata::hybrid::regex::Cache>,
+        state: regex_automata::hybrid::dfa::OverlappingState,
+        /// The first match of the next group found by the forward search,
+        /// if one exists.
+        next: Option<regex_automata::HalfMatch>,
+    },
+    /// Matches are found with the fallback approach. All of them are found
+    /// at once and put in `pending`.
+    Fallback,
+    /// There are no more matches to find. (There may still be some in
+    /// `pending`.)
+    Done,
+}
+
+impl<'h> Searcher<'h> {
+    /// Create a new overlapping search over the given haystack.
+    pub(crate) fn new(engine: Engine, haystack: &'h [u8]) -> Searcher<'h> {
+        #[cfg(feature = "perf-dfa")]
+        let imp = match engine.dfa {
+            None => Imp::Fallback,
+            Some(ref dfa) => Imp::Dfa {
+                cache: Box::new(dfa.create_cache()),
+                state: regex_automata::hybrid::dfa::OverlappingState::start(),
+                next: None,
+            },
+        };
+        #[cfg(not(feature = "perf-dfa"))]
+        let imp = Imp::Fallback;
+        Searcher { engine, haystack, pending: Vec::new(), last_end: None, imp }
+    }
+
+    /// Find the next group of matches and put them in `pending`. If there
+    /// are no more matches, then `pending` is left empty.
+    fn refill(&mut self) {
+        match self.imp {
+            #[cfg(feature = "perf-dfa")]
+            Imp::Dfa { .. } => {
+                if self.refill_dfa().is_err() {
+                    // The lazy DFA gave up, so we switch to the fallback
+                    // approach. Since we always find complete groups of
+                    // matches with the same end offset, we can just skip
+                    // over every match that ends at or before the last one
+                    // we yielded.
+                    self.pending.clear();
+                    self.imp = Imp::Fallback;
+                    self.refill();
+                }
+            }
+            Imp::Fallback => {
+                self.pending =
+                    fallback(&self.engine.meta, self.haystack, self.last_end);
+                self.imp = Imp::Done;
+            }
+            Imp::Done => {}
+        }
+    }
+
+    /// Find the next group of matches sharing the same end offset using the
+    /// lazy DFA.
+    #[cfg(feature = "perf-dfa")]
+    fn refill_dfa(&mut self) -> Result<(), regex_automata::MatchError> {
+        let dfa = match self.engine.dfa {
+            None => unreachable!("lazy DFA must exist when used"),
+            Some(ref dfa) => dfa,
+        };
+        let (cache, state, next) = match self.imp {
+            Imp::Dfa { ref mut cache, ref mut state, ref mut next } => {
+                (cache, state, next)
+            }
+            _ => unreachable!("lazy DFA search state must exist when used"),
+        };
+        let (fwd, rev) = (dfa.forward(), dfa.reverse());
+        let (fcache, rcache) = cache.as_parts_mut();
+        let input = Input::new(self.haystack);
+        let first = match next.take() {
+            Some(hm) => hm,
+            None => {
+                fwd.try_search_overlapping_fwd(fcache, &input, state)?;
+                match state.get_match() {
+                    None => {
+                        self.imp = Imp::Done;
+                        return Ok(());
+                    }
+                    Some(hm) => hm,
+                }
+            }
+        };
+        let end = first.offset();
+        // The lazy DFA reports each pattern at most once for each end
+        // offset, so there's no need to deduplicate these.
+        let mut pids = alloc::vec![first.pattern()];
+        // Once the forward search stops reporting matches, it must not be
+        // resumed.
+        let mut exhausted = false;
+        loop {
+            fwd.try_search_overlapping_fwd(fcache, &input, state)?;
+            match state.get_match() {
+                None => {
+                    exhausted = true;
+                    break;
+                }
+                Some(hm) if hm.offset() == end => pids.push(hm.pattern()),
+                Some(hm) => {
+                    *next = Some(hm);
+                    break;
+                }
+            }
+        }
+        let mut group = Vec::new();
+        for &pid in pids.iter() {
+            let revsearch = Input::new(self.haystack)
+                .range(..end)
+                .anchored(Anchored::Pattern(pid));
+            let mut revstate =
+                regex_automata::hybrid::dfa::OverlappingState::start();
+            loop {
+                rev.try_search_overlapping_rev(
+                    rcache,
+                    &revsearch,
+                    &mut revstate,
+                )?;
+                match revstate.get_match() {
+                    None => break,
+                    Some(hm) => group.push((pid, hm.offset(), end)),
+                }
+            }
+        }
+        // Only commit to this group once we know the lazy DFA didn't give
+        // up while finding it.
+        group.reverse();
+        self.pending = group;
+        if exhausted {
+            self.imp = Imp::Done;
+        }
+        Ok(())
+    }
+}
+
+impl<'h> Iterator for Searcher<'h> {
+    type Item = RawMatch;
+
+    fn next(&mut self) -> Option<RawMatch> {
+        loop {
+            if let Some(m) = self.pending.pop() {
+                self.last_end = Some(m.2);
+                return Some(m);
+            }
+            if matches!(self.imp, Imp::Done) {
+                return None;
+            }
+            self.refill();
+        }
+    }
+}
+
+/// Finds every match ending after `last_end` (or every match, if `last_end`
+/// is `None`) using the given meta regex, which must use `MatchKind::All`
+/// semantics.
+///
+/// The matches are returned in the reverse of the order in which they should
+/// be yielded.
+fn fallback(
+    meta: &meta::Regex,
+    haystack: &[u8],
+    last_end: Option<usize>,
+) -> Vec<RawMatch> {
+    let mut matches = Vec::new();
+    for start in 0..=haystack.len() {
+        for pid in (0..meta.pattern_len()).map(PatternID::must) {
+            let mut end = haystack.len();
+            while last_end.map_or(true, |last_end| end > last_end) {
+                let input = Input::new(haystack)
+                    .span(start..end)
+                    .anchored(Anchored::Pattern(pid));
+                let m = match meta.search(&input) {
+                    None => break,
+                    Some(m) => m,
+                };
+                if last_end.map_or(false, |last_end| m.end() <= last_end) {
+                    break;
+                }
+                matches.push((pid, start, m.end()));
+                if m.is_empty() {
+                    break;
+                }
+                end = m.end() - 1;
+            }
+        }
+    }
+    matches.sort_by_key(|&(pid, start, end)| {
+        (end, pid, core::cmp::Reverse(start))
+    });
+    matches.reverse();
+    matches
+}
+
+/// Build a lazy DFA for overlapping searches that is configured like the
+/// given meta regex.
+///
+/// If one can't be built, then `None` is returned.
+#[cfg(feature = "perf-dfa")]
+fn build_dfa<P: AsRef<str>>(
+    meta: &meta::Regex,
+    patterns: &[P],
+    syntaxc: syntax::Config,
+) -> Option<regex_automata::hybrid::regex::Regex> {
+    use regex_automata::{
+        hybrid::{self, dfa::DFA},
+        nfa::thompson,
+        util::look::LookMatcher,
+    };
+
+    let metac = meta.get_config();
+    let mut lookm = LookMatcher::new();
+    lookm.set_line_terminator(metac.get_line_terminator());
+    let thompsonc = thompson::Config::new()
+        .utf8(metac.get_utf8_empty())
+        .nfa_size_limit(metac.get_nfa_size_limit())
+        .shrink(false)
+        .which_captures(WhichCaptures::None)
+        .look_matcher(lookm);
+    let dfac = DFA::config()
+        .match_kind(MatchKind::All)
+        .starts_for_each_pattern(true)
+        .byte_classes(metac.get_byte_classes())
+        .unicode_word_boundary(true)
+        .cache_capacity(metac.get_hybrid_cache_capacity());
+    hybrid::regex::Builder::new()
+        .syntax(syntaxc)
+        .thompson(thompsonc)
+        .dfa(dfac)
+        .build_many(patterns)
+        .ok()
+}
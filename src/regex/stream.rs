@@ -0,0 +1,640 @@
+/*!
+This module provides a streaming search over a `std::io::Read` for
+`bytes::Regex`.
+
+The central problem with searching a stream is that a match might straddle
+the boundary between two successive reads. A regex like `a+` could match an
+arbitrarily long run of `a` bytes, and we can't know where the match ends
+until we see the first byte that isn't an `a`. The usual trick of
+"overlapping" consecutive windows by some fixed amount doesn't work for
+regexes whose matches have no upper bound on their length.
+
+So instead of running a fresh search on each chunk, we drive a lazy DFA by
+hand. The forward DFA's state is carried across reads, which means the
+position at which a read happens has no bearing on the result. Once the
+forward DFA reports the end of a match, a reverse DFA is run from that end to
+find the start of the match, exactly as the meta regex engine does it.
+
+The remaining problem is deciding how much of the stream we need to keep
+around. Since the reverse search needs every byte of the match, we can't
+throw away anything that might be part of a match we haven't found yet. We
+keep track of this by running anchored searches (using the same lazy DFA, but
+with a separate cache) in lock-step with the unanchored one: one for every
+position that could still be the start of a match. When an anchored search
+dies, no match can begin at the position it started from. And when two
+anchored searches reach the same DFA state, they behave identically from
+then on, so only the one that started earliest needs to be kept. This bounds
+the number of anchored searches by the number of DFA states and keeps the
+total work linear in the length of the stream. In the common case (a
+haystack where most positions can't start a match), this keeps the buffer
+small. In the worst case (for example, `a*b` searching a stream of `a`
+bytes), the buffer grows to accommodate the longest possible match.
+
+Finally, the lazy DFA can't handle every regex. Namely, it quits when it sees
+a non-ASCII byte while a Unicode word boundary is in play. When that happens,
+we fall back to reading the rest of the stream into memory and searching it
+with the meta regex engine. It's not great, but it's correct.
+*/
+
+use alloc::vec::Vec;
+
+use regex_automata::{
+    hybrid::{self, dfa::DFA, LazyStateID},
+    meta,
+    nfa::thompson::{self, WhichCaptures},
+    util::{look::LookMatcher, syntax},
+    Anchored, Input, MatchError, MatchKind,
+};
+
+/// The number of bytes we ask for from the underlying reader on each read.
+const CHUNK_SIZE: usize = 64 * (1 << 10);
+
+/// A single match found by a streaming search.
+///
+/// Since a stream is not a contiguous haystack, the bytes of the match are
+/// copied out of the stream and owned by this value. The offsets reported are
+/// absolute byte offsets, where `0` corresponds to the first byte read from
+/// the stream.
+///
+/// This is created by the [`StreamMatches`] iterator, which in turn is
+/// created by [`Regex::stream_find_iter`](crate::bytes::Regex::stream_find_iter).
+#[derive(Clone, Eq, PartialEq)]
+pub struct StreamMatch {
+    start: u64,
+    end: u64,
+    bytes: Vec<u8>,
+}
+
+impl StreamMatch {
+    /// Returns the absolute byte offset of the start of the match in the
+    /// stream.
+    ///
+    /// It is guaranteed that `StreamMatch::start() <= StreamMatch::end()`.
+    #[inline]
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the absolute byte offset of the end of the match in the
+    /// stream.
+    ///
+    /// It is guaranteed that `StreamMatch::start() <= StreamMatch::end()`.
+    #[inline]
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns true if and only if this match has a length of zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the length, in bytes, of this match.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the range over the starting and ending absolute byte offsets
+    /// of the match in the stream.
+    #[inline]
+    pub fn range(&self) -> core::ops::Range<u64> {
+        self.start..self.end
+    }
+
+    /// Returns the bytes of the stream that were matched.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes this match and returns the bytes of the stream that were
+    /// matched.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl core::fmt::Debug for StreamMatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        use regex_automata::util::escape::DebugHaystack;
+
+        f.debug_struct("StreamMatch")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("bytes", &DebugHaystack(&self.bytes))
+            .finish()
+    }
+}
+
+/// An iterator over all non-overlapping matches in a stream.
+///
+/// This iterator yields [`StreamMatch`] values, or an I/O error if reading
+/// from the underlying stream failed. Once an error is returned, the iterator
+/// stops. The iterator also stops when no more matches can be found.
+///
+/// The matches yielded are precisely the matches that
+/// [`Regex::find_iter`](crate::bytes::Regex::find_iter) would yield if it
+/// were given the entire contents of the stream as a single haystack.
+///
+/// `'r` is the lifetime of the compiled regular expression and `R` is the
+/// type of the reader.
+///
+/// This iterator is created by
+/// [`Regex::stream_find_iter`](crate::bytes::Regex::stream_find_iter).
+pub struct StreamMatches<'r, R> {
+    meta: &'r meta::Regex,
+    /// The lazy DFAs used to drive the search. When this is `None`, we've
+    /// either failed to build them or the lazy DFA gave up during a search.
+    /// In either case, we fall back to the meta regex engine over the
+    /// remainder of the stream read entirely into memory.
+    lazy: Option<(hybrid::regex::Regex, hybrid::regex::Cache, Starts)>,
+    buf: StreamBuffer<R>,
+    /// The position in `buf` at which the next search begins.
+    pos: usize,
+    /// The end of the previous match, relative to `buf`. This is used to
+    /// prevent an empty match from overlapping with the previous match.
+    last_match_end: Option<usize>,
+    done: bool,
+}
+
+impl<'r, R: std::io::Read> StreamMatches<'r, R> {
+    pub(crate) fn new(
+        meta: &'r meta::Regex,
+        pattern: &str,
+        syntaxc: syntax::Config,
+        rdr: R,
+    ) -> StreamMatches<'r, R> {
+        let lazy = build_lazy(meta, pattern, syntaxc).map(|re| {
+            let cache = re.create_cache();
+            let starts = Starts::new(re.forward());
+            (re, cache, starts)
+        });
+        StreamMatches {
+            meta,
+            lazy,
+            buf: StreamBuffer::new(rdr),
+            pos: 0,
+            last_match_end: None,
+            done: false,
+        }
+    }
+
+    /// Returns the next match as a range relative to `buf`, or `None` if
+    /// there are no more matches.
+    fn find(&mut self) -> std::io::Result<Option<(usize, usize)>> {
+        let (start, end) = match self.find_one()? {
+            None => return Ok(None),
+            Some(m) => m,
+        };
+        if start < end || Some(end) != self.last_match_end {
+            self.pos = end;
+            self.last_match_end = Some(end);
+            return Ok(Some((start, end)));
+        }
+        // This is the same dance that the iterator helpers in regex-automata
+        // do: an empty match can't be reported at the same position as the
+        // end of the previous match. So we start again one byte further.
+        self.pos = end.checked_add(1).unwrap();
+        let (start, end) = match self.find_one()? {
+            None => return Ok(None),
+            Some(m) => m,
+        };
+        self.pos = end;
+        self.last_match_end = Some(end);
+        Ok(Some((start, end)))
+    }
+
+    /// Runs a single leftmost-first search beginning at `pos`.
+    fn find_one(&mut self) -> std::io::Result<Option<(usize, usize)>> {
+        while self.pos > self.buf.len() {
+            if !self.fill(self.pos)?.0 {
+                return Ok(None);
+            }
+        }
+        if let Some((re, mut cache, mut starts)) = self.lazy.take() {
+            match self.find_lazy(&re, &mut cache, &mut starts)? {
+                Ok(m) => {
+                    self.lazy = Some((re, cache, starts));
+                    return Ok(m);
+                }
+                // The lazy DFA gave up, so we read the rest of the stream
+                // into memory and use the meta regex engine from here on out.
+                Err(_err) => {}
+            }
+        }
+        self.find_slurp()
+    }
+
+    /// Runs a search using the lazy DFAs, carrying the forward DFA's state
+    /// across reads from the stream.
+    ///
+    /// The outer error is for I/O failures. The inner error occurs when the
+    /// lazy DFA gives up, in which case, the caller should fall back to a
+    /// different engine. In that case, `pos` is guaranteed to still be a
+    /// valid place to resume the search.
+    fn find_lazy(
+        &mut self,
+        re: &hybrid::regex::Regex,
+        cache: &mut hybrid::regex::Cache,
+        starts: &mut Starts,
+    ) -> std::io::Result<Result<Option<(usize, usize)>, MatchError>> {
+        macro_rules! try_lazy {
+            ($result:expr) => {
+                match $result {
+                    Ok(v) => v,
+                    Err(err) => return Ok(Err(err)),
+                }
+            };
+        }
+
+        let (fwd, rev) = (re.forward(), re.reverse());
+        let (fcache, rcache) = cache.as_parts_mut();
+        // The earliest position at which a match could still start. This
+        // never goes backwards, and it never moves once a match has been
+        // found.
+        let mut keep = self.pos;
+        let mut at = self.pos;
+        let mut mat: Option<usize> = None;
+        let mut sid = try_lazy!(start(fwd, fcache, &self.buf, at, false));
+        starts.clear();
+        fcache.search_start(at);
+        starts.cache.search_start(at);
+        loop {
+            if at == self.buf.len() {
+                fcache.search_finish(at);
+                starts.cache.search_finish(at);
+                // Nothing before `keep` can be part of a match, so it's
+                // always correct to resume a search from there.
+                self.pos = keep;
+                let (more, dropped) = self.fill(keep)?;
+                at -= dropped;
+                keep -= dropped;
+                starts.shift(dropped);
+                mat = mat.map(|end| end - dropped);
+                if !more {
+                    sid = try_lazy!(fwd
+                        .next_eoi_state(fcache, sid)
+                        .map_err(|_| MatchError::gave_up(at)));
+                    if sid.is_match() {
+                        mat = Some(at);
+                    }
+                    break;
+                }
+                fcache.search_start(at);
+                starts.cache.search_start(at);
+                continue;
+            }
+            let byte = self.buf.get(at);
+            sid = try_lazy!(next(fwd, fcache, sid, byte, at));
+            if sid.is_match() {
+                // Matches are delayed by one byte, so `at` is precisely the
+                // exclusive end of the match.
+                mat = Some(at);
+            } else if sid.is_dead() {
+                fcache.search_finish(at);
+                starts.cache.search_finish(at);
+                break;
+            }
+            // Once a match has been found, its start is fixed at `keep` or
+            // later, so the anchored searches are no longer needed.
+            if mat.is_none() {
+                try_lazy!(starts.advance(fwd, &self.buf, keep, at));
+                keep = starts.earliest().unwrap_or(at + 1);
+            }
+            at += 1;
+        }
+        let end = match mat {
+            None => return Ok(Ok(None)),
+            Some(end) => end,
+        };
+        if keep == end {
+            return Ok(Ok(Some((end, end))));
+        }
+        // Just like the meta regex engine, we find the start of the match
+        // by running an anchored reverse search from the end of the match.
+        // Since no match can start before `keep`, that's as far back as we
+        // need to go.
+        let revsearch = Input::new(self.buf.as_slice())
+            .span(keep..end)
+            .anchored(Anchored::Yes);
+        let start = try_lazy!(rev.try_search_rev(rcache, &revsearch))
+            .expect("reverse search must match if forward search does");
+        Ok(Ok(Some((start.offset(), end))))
+    }
+
+    /// Reads the remainder of the stream into memory and runs the meta regex
+    /// engine on it.
+    fn find_slurp(&mut self) -> std::io::Result<Option<(usize, usize)>> {
+        while self.fill(self.pos)?.0 {}
+        if self.pos > self.buf.len() {
+            return Ok(None);
+        }
+        let input =
+            Input::new(self.buf.as_slice()).span(self.pos..self.buf.len());
+        Ok(self.meta.search(&input).map(|m| (m.start(), m.end())))
+    }
+
+    /// Reads more bytes into the buffer, dropping everything before `keep`
+    /// except for the one byte immediately preceding it. (That byte is
+    /// needed to compute the correct start state for look-behind assertions
+    /// like `\b` and `(?m:^)`.)
+    ///
+    /// Offsets stored on this iterator are updated to account for any bytes
+    /// that were dropped. This returns whether any bytes were read (which
+    /// only fails to happen at the end of the stream) along with the number
+    /// of bytes dropped from the front of the buffer.
+    fn fill(&mut self, keep: usize) -> std::io::Result<(bool, usize)> {
+        let (more, dropped) = self.buf.fill(keep.saturating_sub(1))?;
+        self.pos -= dropped;
+        self.last_match_end =
+            self.last_match_end.and_then(|end| end.checked_sub(dropped));
+        Ok((more, dropped))
+    }
+}
+
+impl<'r, R: std::io::Read> Iterator for StreamMatches<'r, R> {
+    type Item = std::io::Result<StreamMatch>;
+
+    fn next(&mut self) -> Option<std::io::Result<StreamMatch>> {
+        if self.done {
+            return None;
+        }
+        match self.find() {
+            Ok(Some((start, end))) => {
+                let offset = self.buf.offset();
+                Some(Ok(StreamMatch {
+                    start: offset + start as u64,
+                    end: offset + end as u64,
+                    bytes: self.buf.as_slice()[start..end].to_vec(),
+                }))
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'r, R: std::io::Read> core::iter::FusedIterator for StreamMatches<'r, R> {}
+
+impl<'r, R> core::fmt::Debug for StreamMatches<'r, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("StreamMatches")
+            .field("meta", &self.meta)
+            .field("lazy", &self.lazy)
+            .field("offset", &self.buf.offset)
+            .field("len", &self.buf.buf.len())
+            .field("pos", &self.pos)
+            .field("last_match_end", &self.last_match_end)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+/// The anchored searches used to track the earliest position at which a match
+/// could still begin.
+///
+/// Each search is paired with the position it started from, and searches are
+/// kept in ascending order of that position. Since two searches in the same
+/// DFA state behave identically from then on, only the earliest one is ever
+/// kept.
+///
+/// These searches use their own cache. A lazy DFA only keeps the state being
+/// transitioned from valid when its cache is cleared, so the other states
+/// held here would be invalidated if the searches shared a cache with the
+/// unanchored search. When a clear happens while advancing these searches,
+/// they are recomputed from the bytes still in the buffer.
+#[derive(Debug)]
+struct Starts {
+    cache: hybrid::dfa::Cache,
+    searches: Vec<(usize, LazyStateID)>,
+}
+
+impl Starts {
+    fn new(dfa: &DFA) -> Starts {
+        Starts { cache: dfa.create_cache(), searches: Vec::new() }
+    }
+
+    /// Returns the earliest position at which a match could still begin, if
+    /// one could begin at or before the most recently consumed byte.
+    fn earliest(&self) -> Option<usize> {
+        self.searches.first().map(|&(start, _)| start)
+    }
+
+    fn clear(&mut self) {
+        self.searches.clear();
+    }
+
+    /// Accounts for `dropped` bytes being removed from the front of the
+    /// buffer.
+    fn shift(&mut self, dropped: usize) {
+        for &mut (ref mut start, _) in self.searches.iter_mut() {
+            *start -= dropped;
+        }
+    }
+
+    /// Starts a new anchored search at `at` and advances every search by the
+    /// byte at `at`. `keep` must be the earliest position returned by this
+    /// tracker before this call (or `at` if there was none).
+    ///
+    /// If the cache was cleared in the process, then all searches from
+    /// `keep` onwards are recomputed. If that isn't possible without another
+    /// clear, then the lazy DFA is considered to have given up.
+    fn advance<R>(
+        &mut self,
+        dfa: &DFA,
+        buf: &StreamBuffer<R>,
+        keep: usize,
+        at: usize,
+    ) -> Result<(), MatchError> {
+        let clears = self.cache.clear_count();
+        self.step(dfa, buf, at)?;
+        if self.cache.clear_count() == clears {
+            return Ok(());
+        }
+        let clears = self.cache.clear_count();
+        self.searches.clear();
+        for i in keep..=at {
+            self.step(dfa, buf, i)?;
+            if self.cache.clear_count() != clears {
+                return Err(MatchError::gave_up(at));
+            }
+        }
+        Ok(())
+    }
+
+    fn step<R>(
+        &mut self,
+        dfa: &DFA,
+        buf: &StreamBuffer<R>,
+        at: usize,
+    ) -> Result<(), MatchError> {
+        let sid = start(dfa, &mut self.cache, buf, at, true)?;
+        self.searches.push((at, sid));
+        let byte = buf.buf[at];
+        for i in 0..self.searches.len() {
+            let sid = self.searches[i].1;
+            self.searches[i].1 = next(dfa, &mut self.cache, sid, byte, at)?;
+        }
+        // Drop dead searches, and any search in the same state as one that
+        // started earlier.
+        let mut len = 0;
+        for i in 0..self.searches.len() {
+            let (start, sid) = self.searches[i];
+            if sid.is_dead()
+                || self.searches[..len].iter().any(|&(_, s)| s == sid)
+            {
+                continue;
+            }
+            self.searches[len] = (start, sid);
+            len += 1;
+        }
+        self.searches.truncate(len);
+        Ok(())
+    }
+}
+
+/// A rolling buffer over a reader.
+///
+/// The buffer only ever grows by appending bytes read from the stream and
+/// shrinks by dropping bytes from the front. The absolute offset of the
+/// first byte in the buffer is tracked so that positions in the buffer can
+/// be converted to positions in the stream.
+struct StreamBuffer<R> {
+    rdr: R,
+    buf: Vec<u8>,
+    /// The absolute offset of `buf[0]` in the stream.
+    offset: u64,
+    eof: bool,
+}
+
+impl<R: std::io::Read> StreamBuffer<R> {
+    fn new(rdr: R) -> StreamBuffer<R> {
+        StreamBuffer { rdr, buf: Vec::new(), offset: 0, eof: false }
+    }
+
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn get(&self, at: usize) -> u8 {
+        self.buf[at]
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Drops every byte before `drop` and then appends at least one more
+    /// byte from the reader. This returns whether any bytes were read, which
+    /// is only false at the end of the stream, along with the number of bytes
+    /// that were dropped.
+    fn fill(&mut self, drop: usize) -> std::io::Result<(bool, usize)> {
+        if self.eof {
+            return Ok((false, 0));
+        }
+        self.buf.drain(..drop);
+        self.offset += drop as u64;
+        let len = self.buf.len();
+        self.buf.resize(len + CHUNK_SIZE, 0);
+        loop {
+            match self.rdr.read(&mut self.buf[len..]) {
+                Ok(n) => {
+                    self.buf.truncate(len + n);
+                    if n == 0 {
+                        self.eof = true;
+                    }
+                    return Ok((n > 0, drop));
+                }
+                Err(ref err)
+                    if err.kind() == std::io::ErrorKind::Interrupted =>
+                {
+                    continue
+                }
+                Err(err) => {
+                    self.buf.truncate(len);
+                    return Err(err);
+                }
+            }
+        }
+    }
+}
+
+/// Builds the forward and reverse lazy DFAs used for a streaming search.
+///
+/// These are configured to match the semantics of the meta regex engine
+/// given. If they couldn't be built, then `None` is returned and callers
+/// should fall back to the meta regex engine.
+fn build_lazy(
+    meta: &meta::Regex,
+    pattern: &str,
+    syntaxc: syntax::Config,
+) -> Option<hybrid::regex::Regex> {
+    let metac = meta.get_config();
+    let mut lookm = LookMatcher::new();
+    lookm.set_line_terminator(metac.get_line_terminator());
+    let thompsonc = thompson::Config::new()
+        .utf8(metac.get_utf8_empty())
+        .nfa_size_limit(metac.get_nfa_size_limit())
+        .shrink(false)
+        .which_captures(WhichCaptures::None)
+        .look_matcher(lookm);
+    let dfac = DFA::config()
+        .match_kind(MatchKind::LeftmostFirst)
+        .byte_classes(metac.get_byte_classes())
+        .unicode_word_boundary(true)
+        .cache_capacity(metac.get_hybrid_cache_capacity());
+    hybrid::regex::Builder::new()
+        .syntax(syntaxc)
+        .thompson(thompsonc)
+        .dfa(dfac)
+        .build(pattern)
+        .ok()
+}
+
+/// Returns the start state for a search beginning at `at` in the given
+/// buffer. The byte immediately before `at`, if one exists, is used as the
+/// look-behind byte.
+fn start<R>(
+    dfa: &DFA,
+    cache: &mut hybrid::dfa::Cache,
+    buf: &StreamBuffer<R>,
+    at: usize,
+    anchored: bool,
+) -> Result<LazyStateID, MatchError> {
+    let anchored = if anchored { Anchored::Yes } else { Anchored::No };
+    let input =
+        Input::new(&buf.buf).span(at..buf.buf.len()).anchored(anchored);
+    dfa.start_state_forward(cache, &input)
+}
+
+/// Computes the transition from `sid` on `byte`, which occurs at position
+/// `at`. An error is returned if the lazy DFA gave up or quit.
+fn next(
+    dfa: &DFA,
+    cache: &mut hybrid::dfa::Cache,
+    sid: LazyStateID,
+    byte: u8,
+    at: usize,
+) -> Result<LazyStateID, MatchError> {
+    cache.search_update(at);
+    let sid = dfa
+        .next_state(cache, sid, byte)
+        .map_err(|_| MatchError::gave_up(at))?;
+    if sid.is_quit() {
+        return Err(MatchError::quit(byte, at));
+    }
+    Ok(sid)
+}
@@ -1,8 +1,16 @@
-use alloc::{borrow::Cow, string::String, sync::Arc};
+use alloc::{borrow::Cow, string::String, sync::Arc, vec::Vec};
 
-use regex_automata::{meta, util::captures, Input, PatternID};
+use regex_automata::{
+    meta,
+    util::{captures, syntax},
+    Input, PatternID,
+};
 
-use crate::{error::Error, RegexBuilder};
+use crate::{
+    error::Error,
+    regex::{overlapping, template},
+    RegexBuilder,
+};
 
 /// A compiled regular expression for searching Unicode haystacks.
 ///
@@ -101,6 +109,9 @@ use crate::{error::Error, RegexBuilder};
 pub struct Regex {
     pub(crate) meta: meta::Regex,
     pub(crate) pattern: Arc<str>,
+    // This is only needed to build the regex engines for overlapping
+    // searches, since the meta regex doesn't expose its NFAs.
+    pub(crate) syntaxc: syntax::Config,
 }
 
 impl core::fmt::Display for Regex {
@@ -233,6 +244,45 @@ impl Regex {
         self.find_at(haystack, 0)
     }
 
+    /// This routine searches backwards from the end of the haystack for the
+    /// last match of this regex, and if found, returns a [`Match`].
+    ///
+    /// The match returned is always one that [`Regex::find_at`] returns when
+    /// starting at the beginning of the match. Of those, it's the one found
+    /// from the last position at which any match starts, extended to start
+    /// as early as possible while still ending in the same place. For
+    /// example, `[0-9]+` finds `123` in `abc123`, even though matches also
+    /// start at each of `2` and `3`.
+    ///
+    /// # Time complexity
+    ///
+    /// This usually takes `O(m * n)` time, just like a forward search. But
+    /// when a lazy DFA can't be used (see
+    /// [`Regex::find_overlapping_iter`] for when that happens), it runs a
+    /// forward search from every starting position in the haystack instead,
+    /// which takes `O(m * n^2)` time in the worst case.
+    ///
+    /// # Example
+    ///
+    /// Find the last date in a haystack:
+    ///
+    /// ```
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new(r"[0-9]{4}-[0-9]{2}-[0-9]{2}").unwrap();
+    /// let hay = "2010-03-14 and 2014-10-14";
+    /// let mat = re.rfind(hay).unwrap();
+    /// assert_eq!(15..25, mat.range());
+    /// assert_eq!("2014-10-14", mat.as_str());
+    /// ```
+    #[inline]
+    pub fn rfind<'h>(&self, haystack: &'h str) -> Option<Match<'h>> {
+        let input = Input::new(haystack);
+        self.meta
+            .rsearch(&input)
+            .map(|m| Match::new(haystack, m.start(), m.end()))
+    }
+
     /// Returns an iterator that yields successive non-overlapping matches in
     /// the given haystack. The iterator yields values of type [`Match`].
     ///
@@ -265,6 +315,131 @@ impl Regex {
         Matches { haystack, it: self.meta.find_iter(haystack) }
     }
 
+    /// Returns an iterator that yields successive non-overlapping matches in
+    /// the given haystack, starting from the end of the haystack and
+    /// proceeding backwards. The iterator yields values of type [`Match`].
+    ///
+    /// Each match is found with [`Regex::rfind`] on the part of the haystack
+    /// that precedes the previous match. Like [`Regex::find_iter`], an empty
+    /// match is never yielded if it starts where the previous match did.
+    ///
+    /// The matches yielded are often the same as those yielded by
+    /// [`Regex::find_iter`], just in reverse order. But this isn't always the
+    /// case, since a forward search resumes where the previous match ended,
+    /// while a reverse search resumes where it started. For example, `aa`
+    /// matches `0..2` in `aaa` when searching forwards, but `1..3` when
+    /// searching backwards.
+    ///
+    /// # Time complexity
+    ///
+    /// Since `rfind_iter` runs potentially many searches on the haystack,
+    /// the overall worst case time complexity for iteration is `O(m * n^2)`.
+    /// When a lazy DFA can't be used, it is `O(m * n^3)`. See
+    /// [`Regex::rfind`] for details.
+    ///
+    /// # Example
+    ///
+    /// Find the last two words with exactly 13 Unicode word characters:
+    ///
+    /// ```
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new(r"\b\w{13}\b").unwrap();
+    /// let hay = "Retroactively relinquishing remunerations is reprehensible.";
+    /// let matches: Vec<_> =
+    ///     re.rfind_iter(hay).take(2).map(|m| m.as_str()).collect();
+    /// assert_eq!(matches, vec!["reprehensible", "remunerations"]);
+    /// ```
+    #[inline]
+    pub fn rfind_iter<'r, 'h>(
+        &'r self,
+        haystack: &'h str,
+    ) -> ReverseMatches<'r, 'h> {
+        ReverseMatches { haystack, it: self.meta.rfind_iter(haystack) }
+    }
+
+    /// Returns an iterator that yields every match in the given haystack,
+    /// including matches that overlap with one another. The iterator yields
+    /// values of type [`Match`].
+    ///
+    /// Unlike [`Regex::find_iter`], this reports every span of the haystack
+    /// that the regex matches, regardless of match priority. For example,
+    /// `a+` reports a match for every non-empty substring of `aaa`, and
+    /// `a|ab` reports both `a` and `ab` in `ab`. Greediness and the order of
+    /// alternations have no effect on which matches are reported.
+    ///
+    /// Matches are yielded in ascending order of their end offsets. Matches
+    /// with the same end offset are yielded in descending order of their
+    /// start offsets (so shorter matches come first).
+    ///
+    /// Each call to this method builds the regex engines used for overlapping
+    /// searches, which may take some time. So it's best to find all of the
+    /// matches in a haystack with a single call.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if building the regex engines used for
+    /// overlapping searches fails. This can only happen when they exceed the
+    /// configured [size limit](RegexBuilder::size_limit).
+    ///
+    /// # Time complexity
+    ///
+    /// Finding where each match ends takes `O(m * n)` time in total, but
+    /// finding where matches start requires a reverse search from each of
+    /// those ends. So the overall worst case time complexity for iteration is
+    /// `O(m * n^2)`, not counting the time it takes to yield each match.
+    ///
+    /// This relies on a lazy DFA, which can't be used for every regex or
+    /// every haystack. For example, it can't be used when the regex contains
+    /// a look-around assertion, or when the regex contains a Unicode word
+    /// boundary and the haystack contains non-ASCII bytes. It also can't be
+    /// used when the `perf-dfa` feature is disabled. In those cases, a much
+    /// slower approach is used that runs a separate search for each starting
+    /// position in the haystack. Its worst case time complexity is
+    /// `O(m * n^3)`.
+    ///
+    /// # Example
+    ///
+    /// Find every span of a haystack matching `a+`:
+    ///
+    /// ```
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new(r"a+").unwrap();
+    /// let matches: Vec<_> = re
+    ///     .find_overlapping_iter("aaa")
+    ///     .unwrap()
+    ///     .map(|m| m.range())
+    ///     .collect();
+    /// assert_eq!(matches, vec![0..1, 1..2, 0..2, 2..3, 1..3, 0..3]);
+    /// ```
+    ///
+    /// Find every word in a haystack that ends with `ing`, including words
+    /// that are suffixes of other words:
+    ///
+    /// ```
+    /// use regex::Regex;
+    ///
+    /// let re = Regex::new(r"\w+ing\b").unwrap();
+    /// let hay = "a swing";
+    /// let matches: Vec<_> =
+    ///     re.find_overlapping_iter(hay).unwrap().map(|m| m.as_str()).collect();
+    /// assert_eq!(matches, vec!["wing", "swing"]);
+    /// ```
+    #[inline]
+    pub fn find_overlapping_iter<'h>(
+        &self,
+        haystack: &'h str,
+    ) -> Result<OverlappingMatches<'h>, Error> {
+        let engine = overlapping::Engine::new(
+            &self.meta,
+            &[self.as_str()],
+            self.syntaxc,
+        )?;
+        let it = overlapping::Searcher::new(engine, haystack.as_bytes());
+        Ok(OverlappingMatches { haystack, it })
+    }
+
     /// This routine searches for the first match of this regex in the haystack
     /// given, and if found, returns not only the overall match but also the
     /// matches of each capture group in the regex. If no match is found, then
@@ -1561,7 +1736,11 @@ impl<'h> Match<'h> {
 
     /// Creates a new match from the given haystack and byte offsets.
     #[inline]
-    fn new(haystack: &'h str, start: usize, end: usize) -> Match<'h> {
+    pub(crate) fn new(
+        haystack: &'h str,
+        start: usize,
+        end: usize,
+    ) -> Match<'h> {
         Match { haystack, start, end }
     }
 }
@@ -1915,6 +2094,17 @@ impl<'h> Captures<'h> {
     pub fn len(&self) -> usize {
         self.caps.group_len()
     }
+
+    /// Creates a new set of capture group matches from the given haystack
+    /// and the capture groups found in it.
+    #[inline]
+    pub(crate) fn new(
+        haystack: &'h str,
+        caps: captures::Captures,
+        static_captures_len: Option<usize>,
+    ) -> Captures<'h> {
+        Captures { haystack, caps, static_captures_len }
+    }
 }
 
 impl<'h> core::fmt::Debug for Captures<'h> {
@@ -2197,6 +2387,74 @@ impl<'r, 'h> Iterator for Matches<'r, 'h> {
 
 impl<'r, 'h> core::iter::FusedIterator for Matches<'r, 'h> {}
 
+/// An iterator over all non-overlapping matches in a haystack, in reverse
+/// order.
+///
+/// This iterator yields [`Match`] values. The iterator stops when no more
+/// matches can be found.
+///
+/// `'r` is the lifetime of the compiled regular expression and `'h` is the
+/// lifetime of the haystack.
+///
+/// This iterator is created by [`Regex::rfind_iter`].
+///
+/// # Time complexity
+///
+/// See [`Regex::rfind_iter`] for details. In the worst case, iteration takes
+/// `O(m * n^2)` time, or `O(m * n^3)` time when a lazy DFA can't be used.
+#[derive(Debug)]
+pub struct ReverseMatches<'r, 'h> {
+    haystack: &'h str,
+    it: meta::RFindMatches<'r, 'h>,
+}
+
+impl<'r, 'h> Iterator for ReverseMatches<'r, 'h> {
+    type Item = Match<'h>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Match<'h>> {
+        self.it
+            .next()
+            .map(|sp| Match::new(self.haystack, sp.start(), sp.end()))
+    }
+}
+
+impl<'r, 'h> core::iter::FusedIterator for ReverseMatches<'r, 'h> {}
+
+/// An iterator over all matches in a haystack, including matches that
+/// overlap with one another.
+///
+/// This iterator yields [`Match`] values. The iterator stops when no more
+/// matches can be found.
+///
+/// `'h` is the lifetime of the haystack.
+///
+/// This iterator is created by [`Regex::find_overlapping_iter`].
+///
+/// # Time complexity
+///
+/// See [`Regex::find_overlapping_iter`] for details. In the worst case,
+/// iteration takes `O(m * n^2)` time, or `O(m * n^3)` time when a lazy DFA
+/// can't be used.
+#[derive(Debug)]
+pub struct OverlappingMatches<'h> {
+    haystack: &'h str,
+    it: overlapping::Searcher<'h>,
+}
+
+impl<'h> Iterator for OverlappingMatches<'h> {
+    type Item = Match<'h>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Match<'h>> {
+        self.it
+            .next()
+            .map(|(_, start, end)| Match::new(self.haystack, start, end))
+    }
+}
+
+impl<'h> core::iter::FusedIterator for OverlappingMatches<'h> {}
+
 /// An iterator over all non-overlapping capture matches in a haystack.
 ///
 /// This iterator yields [`Captures`] values. The iterator stops when no more
@@ -2587,6 +2845,210 @@ impl<'s> Replacer for NoExpand<'s> {
     }
 }
 
+/// A replacement string that is parsed once and can then be used for any
+/// number of replacements.
+///
+/// Using a `&str` as a [`Replacer`] parses its `$name` references again for
+/// every match. A `ReplacementTemplate` parses its template once, up front,
+/// and resolves every capture group it refers to against a specific regex.
+/// This makes it a good fit for replacement strings that are used many times,
+/// and it also catches mistakes in the template early: a reference to a
+/// capture group that doesn't exist in the regex is reported as an error.
+///
+/// The syntax of capture group references is the same as the one described
+/// for [`Captures::expand`]. A template may optionally also convert the case
+/// of its output, with Perl-like directives such as `\U$1`. This is disabled
+/// by default and can be enabled with
+/// [`ReplacementTemplateBuilder::case_conversion`].
+///
+/// A template should be used with the regex it was built for. If it's used
+/// with a different regex, then references to capture groups that don't
+/// exist in that regex are replaced with the empty string.
+///
+/// # Example
+///
+/// ```
+/// use regex::{Regex, ReplacementTemplate};
+///
+/// let re = Regex::new(r"(?<y>\d{4})-(?<m>\d{2})-(?<d>\d{2})").unwrap();
+/// let template = ReplacementTemplate::new(&re, "$m/$d/$y").unwrap();
+/// let after = re.replace_all("1973-01-05, 1975-08-25", &template);
+/// assert_eq!(after, "01/05/1973, 08/25/1975");
+///
+/// // Referring to a group that doesn't exist is an error.
+/// assert!(ReplacementTemplate::new(&re, "$month").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReplacementTemplate {
+    template: String,
+    pieces: Vec<template::Piece>,
+    /// The entire replacement, when the template refers to no capture
+    /// groups.
+    literal: Option<String>,
+}
+
+impl ReplacementTemplate {
+    /// Parses the given template for use with the given regex, using the
+    /// default configuration.
+    ///
+    /// If the template refers to a capture group that doesn't exist in the
+    /// regex, then an error is returned. To configure how a template is
+    /// parsed, use a [`ReplacementTemplateBuilder`].
+    pub fn new(
+        re: &Regex,
+        template: &str,
+    ) -> Result<ReplacementTemplate, Error> {
+        ReplacementTemplateBuilder::new().build(re, template)
+    }
+
+    /// Returns the template string that this was parsed from.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Appends the replacement for the given match to `dst`.
+    fn expand(&self, caps: Option<&Captures<'_>>, dst: &mut String) {
+        let mut case = template::CaseState::default();
+        for piece in self.pieces.iter() {
+            match *piece {
+                template::Piece::Literal(ref range) => {
+                    case.push_str(&self.template[range.clone()], dst);
+                }
+                template::Piece::Group(index) => {
+                    if let Some(m) = caps.and_then(|caps| caps.get(index)) {
+                        case.push_str(m.as_str(), dst);
+                    }
+                }
+                template::Piece::Case(directive) => case.apply(directive),
+            }
+        }
+    }
+}
+
+impl Replacer for &ReplacementTemplate {
+    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
+        self.expand(Some(caps), dst)
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        self.literal.as_deref().map(Cow::Borrowed)
+    }
+}
+
+impl Replacer for ReplacementTemplate {
+    fn replace_append(&mut self, caps: &Captures<'_>, dst: &mut String) {
+        self.expand(Some(caps), dst)
+    }
+
+    fn no_expansion(&mut self) -> Option<Cow<'_, str>> {
+        self.literal.as_deref().map(Cow::Borrowed)
+    }
+}
+
+/// A configurable builder for a [`ReplacementTemplate`].
+///
+/// # Example
+///
+/// This example shows how to enable case conversion directives.
+///
+/// ```
+/// use regex::{Regex, ReplacementTemplateBuilder};
+///
+/// let re = Regex::new(r"(\w+) (\w+)").unwrap();
+/// let template = ReplacementTemplateBuilder::new()
+///     .case_conversion(true)
+///     .build(&re, r"\U$2\E, \u\L$1")
+///     .unwrap();
+/// let after = re.replace("bruce SPRINGSTEEN", &template);
+/// assert_eq!(after, "SPRINGSTEEN, Bruce");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReplacementTemplateBuilder {
+    case_conversion: bool,
+}
+
+impl ReplacementTemplateBuilder {
+    /// Create a new builder with the default configuration.
+    pub fn new() -> ReplacementTemplateBuilder {
+        ReplacementTemplateBuilder::default()
+    }
+
+    /// Parse the given template for use with the given regex.
+    ///
+    /// If the template refers to a capture group that doesn't exist in the
+    /// regex, or if it contains an invalid case conversion directive, then
+    /// an error is returned.
+    pub fn build(
+        &self,
+        re: &Regex,
+        template: &str,
+    ) -> Result<ReplacementTemplate, Error> {
+        let pieces = template::parse(
+            &re.meta,
+            template.as_bytes(),
+            self.case_conversion,
+        )?;
+        let mut template = ReplacementTemplate {
+            template: String::from(template),
+            pieces,
+            literal: None,
+        };
+        let has_groups = template
+            .pieces
+            .iter()
+            .any(|piece| matches!(*piece, template::Piece::Group(_)));
+        if !has_groups {
+            let mut literal = String::new();
+            template.expand(None, &mut literal);
+            template.literal = Some(literal);
+        }
+        Ok(template)
+    }
+
+    /// Enable or disable case conversion directives in the template.
+    ///
+    /// When enabled, `\U` and `\L` convert everything after them to
+    /// uppercase or lowercase, respectively, until the next `\E`. `\u` and
+    /// `\l` convert just the next character to titlecase or lowercase, and
+    /// take precedence over `\U` and `\L`. (Titlecase is the same as
+    /// uppercase for most characters, but `\u` converts `ß` to `Ss`, for
+    /// example.) Conversions apply to the values of capture groups and to
+    /// literal text alike. A literal backslash is written as `\\`, and any
+    /// other use of a backslash is an error.
+    ///
+    /// This is disabled by default, in which case a backslash has no special
+    /// meaning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::{Regex, ReplacementTemplateBuilder};
+    ///
+    /// let re = Regex::new(r"\w+").unwrap();
+    /// let template = ReplacementTemplateBuilder::new()
+    ///     .case_conversion(true)
+    ///     .build(&re, r"\u$0")
+    ///     .unwrap();
+    /// let after = re.replace_all("straße und öl", &template);
+    /// assert_eq!(after, "Straße Und Öl");
+    ///
+    /// let template = ReplacementTemplateBuilder::new()
+    ///     .case_conversion(true)
+    ///     .build(&re, r"\U$0")
+    ///     .unwrap();
+    /// let after = re.replace_all("straße", &template);
+    /// assert_eq!(after, "STRASSE");
+    /// ```
+    pub fn case_conversion(
+        &mut self,
+        yes: bool,
+    ) -> &mut ReplacementTemplateBuilder {
+        self.case_conversion = yes;
+        self
+    }
+}
+
 /// Quickly checks the given replacement string for whether interpolation
 /// should be done on it. It returns `None` if a `$` was found anywhere in the
 /// given string, which suggests interpolation needs to be done. But if there's
@@ -0,0 +1,406 @@
+/*!
+This module provides the parsing and case conversion routines shared by
+`ReplacementTemplate` and its `bytes` counterpart.
+
+A template is parsed once into a sequence of pieces: literal text, references
+to capture groups and (when enabled) case conversion directives. The syntax
+of capture group references is precisely the one supported by
+`Captures::expand`, which is implemented by `regex_automata`'s `interpolate`
+module. The only difference is that a reference to a group that doesn't exist
+in the regex is an error instead of being silently replaced with nothing.
+
+When case conversion is enabled, the following directives are also
+recognized:
+
+* `\U` converts everything after it to uppercase, until `\L` or `\E`.
+* `\L` converts everything after it to lowercase, until `\U` or `\E`.
+* `\u` converts the next character to titlecase.
+* `\l` converts the next character to lowercase.
+* `\E` ends a `\U` or `\L` conversion.
+* `\\` is a literal backslash.
+
+Any other use of `\` is an error. A `\u` or `\l` directive takes precedence
+over a `\U` or `\L` directive for the one character it applies to, so
+`\u\L$1` capitalizes the value of the first group. Conversions apply to both
+literal text and the values of capture groups. Case conversion is done with
+the full Unicode case mappings, so a single character may convert to several.
+Since `\u` is meant for capitalizing words, it uses the titlecase mapping
+rather than the uppercase one. For most characters they're the same, but for
+example, `\u` converts `ß` to `Ss` while `\U` converts it to `SS`.
+*/
+
+use alloc::{format, string::String, vec::Vec};
+
+use regex_automata::{meta, PatternID};
+
+use crate::error::Error;
+
+/// A single piece of a parsed template.
+#[derive(Clone, Debug)]
+pub(crate) enum Piece {
+    /// Literal text, as a range of byte offsets into the template.
+    Literal(core::ops::Range<usize>),
+    /// A reference to the capture group at the given index.
+    Group(usize),
+    /// A case conversion directive.
+    Case(Directive),
+}
+
+/// A case conversion directive, as written in a template.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Directive {
+    /// `\U`
+    Upper,
+    /// `\L`
+    Lower,
+    /// `\u`
+    UpperNext,
+    /// `\l`
+    LowerNext,
+    /// `\E`
+    End,
+}
+
+/// Parses the given template into a sequence of pieces, checking that every
+/// capture group it refers to exists in the given regex.
+///
+/// Case conversion directives are only recognized when `case_conversion` is
+/// enabled. Otherwise, a `\` is just a literal backslash.
+///
+/// Every literal piece returned begins and ends at an ASCII byte or at the
+/// edges of the template. So if the template is valid UTF-8, then so is
+/// every literal piece.
+pub(crate) fn parse(
+    re: &meta::Regex,
+    template: &[u8],
+    case_conversion: bool,
+) -> Result<Vec<Piece>, Error> {
+    let group_len = re.group_info().group_len(PatternID::ZERO);
+    let mut pieces = Vec::new();
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < template.len() {
+        let b = template[i];
+        if !(b == b'$' || (case_conversion && b == b'\\')) {
+            i += 1;
+            continue;
+        }
+        push_literal(&mut pieces, literal_start..i);
+        if b == b'\\' {
+            let directive = match template.get(i + 1) {
+                Some(b'U') => Directive::Upper,
+                Some(b'L') => Directive::Lower,
+                Some(b'u') => Directive::UpperNext,
+                Some(b'l') => Directive::LowerNext,
+                Some(b'E') => Directive::End,
+                Some(b'\\') => {
+                    // The escaped backslash is the start of the next literal.
+                    literal_start = i + 1;
+                    i += 2;
+                    continue;
+                }
+                Some(_) => {
+                    return Err(Error::Syntax(format!(
+                        "unrecognized escape sequence at offset {} in \
+                         replacement template",
+                        i,
+                    )));
+                }
+                None => {
+                    return Err(Error::Syntax(String::from(
+                        "incomplete escape sequence at end of replacement \
+                         template",
+                    )));
+                }
+            };
+            pieces.push(Piece::Case(directive));
+            i += 2;
+            literal_start = i;
+            continue;
+        }
+        // Handle escaping of '$'. As with '\\', the second '$' starts the
+        // next literal.
+        if template.get(i + 1) == Some(&b'$') {
+            literal_start = i + 1;
+            i += 2;
+            continue;
+        }
+        let (name, end) = match find_cap_ref(template, i) {
+            None => {
+                // Not a reference, so the '$' is just a literal.
+                literal_start = i;
+                i += 1;
+                continue;
+            }
+            Some(cap_ref) => cap_ref,
+        };
+        let index = match name.parse::<usize>() {
+            Ok(index) if index < group_len => Some(index),
+            Ok(_) => None,
+            Err(_) => re.group_info().to_index(PatternID::ZERO, name),
+        };
+        match index {
+            None => {
+                return Err(Error::Syntax(format!(
+                    "replacement template refers to unknown capture \
+                     group '{}'",
+                    name,
+                )));
+            }
+            Some(index) => pieces.push(Piece::Group(index)),
+        }
+        i = end;
+        literal_start = i;
+    }
+    push_literal(&mut pieces, literal_start..template.len());
+    Ok(pieces)
+}
+
+/// Pushes a literal piece for the given range, if it's non-empty. Adjacent
+/// literals are merged.
+fn push_literal(pieces: &mut Vec<Piece>, range: core::ops::Range<usize>) {
+    if range.is_empty() {
+        return;
+    }
+    if let Some(Piece::Literal(ref mut last)) = pieces.last_mut() {
+        if last.end == range.start {
+            last.end = range.end;
+            return;
+        }
+    }
+    pieces.push(Piece::Literal(range));
+}
+
+/// Parses a possible reference to a capture group starting with the `$` at
+/// `template[i]`. On success, this returns the name of the group referenced
+/// along with the offset immediately following the reference.
+///
+/// This recognizes precisely the same references as `Captures::expand`. In
+/// particular, the unbraced form is the longest sequence of `[0-9A-Za-z_]`
+/// and the braced form is any valid UTF-8 that doesn't contain a `}`.
+fn find_cap_ref(template: &[u8], i: usize) -> Option<(&str, usize)> {
+    debug_assert_eq!(Some(&b'$'), template.get(i));
+    let start = i + 1;
+    if template.get(start) == Some(&b'{') {
+        let start = start + 1;
+        let len = template[start..].iter().position(|&b| b == b'}')?;
+        let end = start + len;
+        let name = core::str::from_utf8(&template[start..end]).ok()?;
+        return Some((name, end + 1));
+    }
+    let len = template[start..]
+        .iter()
+        .position(|&b| !is_valid_cap_letter(b))
+        .unwrap_or(template.len() - start);
+    if len == 0 {
+        return None;
+    }
+    let end = start + len;
+    // We just verified that this range is ASCII, so it must be valid UTF-8.
+    let name = core::str::from_utf8(&template[start..end]).unwrap();
+    Some((name, end))
+}
+
+/// Returns true if and only if the given byte is allowed in a capture name
+/// written in non-brace form.
+fn is_valid_cap_letter(b: u8) -> bool {
+    matches!(b, b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'_')
+}
+
+/// The case conversion in effect while a template is being expanded.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CaseState {
+    /// Set by `\U` or `\L`, until the next `\E`.
+    all: Option<Case>,
+    /// Set by `\u` or `\l`, until the next character is written.
+    next: Option<Case>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Case {
+    Upper,
+    Lower,
+    Title,
+}
+
+impl CaseState {
+    /// Updates this state with the given directive.
+    pub(crate) fn apply(&mut self, directive: Directive) {
+        match directive {
+            Directive::Upper => self.all = Some(Case::Upper),
+            Directive::Lower => self.all = Some(Case::Lower),
+            Directive::UpperNext => self.next = Some(Case::Title),
+            Directive::LowerNext => self.next = Some(Case::Lower),
+            Directive::End => {
+                self.all = None;
+                self.next = None;
+            }
+        }
+    }
+
+    /// Appends the given text to `dst`, converting its case as required by
+    /// this state.
+    pub(crate) fn push_str(&mut self, text: &str, dst: &mut String) {
+        if self.all.is_none() && self.next.is_none() {
+            dst.push_str(text);
+            return;
+        }
+        for ch in text.chars() {
+            match self.next.take().or(self.all) {
+                None => dst.push(ch),
+                Some(Case::Upper) => dst.extend(ch.to_uppercase()),
+                Some(Case::Lower) => dst.extend(ch.to_lowercase()),
+                Some(Case::Title) => push_titlecase(ch, dst),
+            }
+        }
+    }
+
+    /// Appends the given bytes to `dst`, converting the case of every valid
+    /// UTF-8 encoded character in it as required by this state. Bytes that
+    /// aren't part of a valid UTF-8 encoding are copied as they are, but each
+    /// such sequence still counts as the next character for `\u` and `\l`.
+    pub(crate) fn push_bytes(&mut self, mut bytes: &[u8], dst: &mut Vec<u8>) {
+        if self.all.is_none() && self.next.is_none() {
+            dst.extend_from_slice(bytes);
+            return;
+        }
+        let mut buf = String::new();
+        while !bytes.is_empty() {
+            let (valid, invalid) = match core::str::from_utf8(bytes) {
+                Ok(valid) => (valid, bytes.len()),
+                Err(err) => {
+                    let valid_up_to = err.valid_up_to();
+                    let invalid_len =
+                        err.error_len().unwrap_or(bytes.len() - valid_up_to);
+                    // OK because the standard library just told us that
+                    // this prefix is valid UTF-8.
+                    let valid =
+                        core::str::from_utf8(&bytes[..valid_up_to]).unwrap();
+                    (valid, valid_up_to + invalid_len)
+                }
+            };
+            buf.clear();
+            self.push_str(valid, &mut buf);
+            dst.extend_from_slice(buf.as_bytes());
+            dst.extend_from_slice(&bytes[valid.len()..invalid]);
+            if invalid > valid.len() {
+                self.next = None;
+            }
+            bytes = &bytes[invalid..];
+        }
+    }
+}
+
+/// Appends the titlecase mapping of the given character to `dst`.
+///
+/// The standard library only provides the uppercase and lowercase mappings,
+/// but the titlecase mapping only differs from the uppercase one for the
+/// characters in `TITLECASE`, along with the Georgian letters checked below.
+fn push_titlecase(ch: char, dst: &mut String) {
+    // Georgian Mkhedruli letters have uppercase (Mtavruli) forms, but they
+    // aren't used to capitalize words. So they are their own titlecase.
+    if matches!(ch, '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}') {
+        dst.push(ch);
+        return;
+    }
+    match TITLECASE.binary_search_by_key(&ch, |&(c, _)| c) {
+        Ok(i) => dst.push_str(TITLECASE[i].1),
+        Err(_) => dst.extend(ch.to_uppercase()),
+    }
+}
+
+/// The characters (other than Georgian letters) whose titlecase mapping
+/// differs from their uppercase mapping, sorted by character.
+///
+/// This was derived from `UnicodeData.txt` and `SpecialCasing.txt`.
+const TITLECASE: &[(char, &str)] = &[
+    ('\u{DF}', "Ss"),
+    ('\u{1C4}', "\u{1C5}"),
+    ('\u{1C5}', "\u{1C5}"),
+    ('\u{1C6}', "\u{1C5}"),
+    ('\u{1C7}', "\u{1C8}"),
+    ('\u{1C8}', "\u{1C8}"),
+    ('\u{1C9}', "\u{1C8}"),
+    ('\u{1CA}', "\u{1CB}"),
+    ('\u{1CB}', "\u{1CB}"),
+    ('\u{1CC}', "\u{1CB}"),
+    ('\u{1F1}', "\u{1F2}"),
+    ('\u{1F2}', "\u{1F2}"),
+    ('\u{1F3}', "\u{1F2}"),
+    ('\u{587}', "\u{535}\u{582}"),
+    ('\u{1F80}', "\u{1F88}"),
+    ('\u{1F81}', "\u{1F89}"),
+    ('\u{1F82}', "\u{1F8A}"),
+    ('\u{1F83}', "\u{1F8B}"),
+    ('\u{1F84}', "\u{1F8C}"),
+    ('\u{1F85}', "\u{1F8D}"),
+    ('\u{1F86}', "\u{1F8E}"),
+    ('\u{1F87}', "\u{1F8F}"),
+    ('\u{1F88}', "\u{1F88}"),
+    ('\u{1F89}', "\u{1F89}"),
+    ('\u{1F8A}', "\u{1F8A}"),
+    ('\u{1F8B}', "\u{1F8B}"),
+    ('\u{1F8C}', "\u{1F8C}"),
+    ('\u{1F8D}', "\u{1F8D}"),
+    ('\u{1F8E}', "\u{1F8E}"),
+    ('\u{1F8F}', "\u{1F8F}"),
+    ('\u{1F90}', "\u{1F98}"),
+    ('\u{1F91}', "\u{1F99}"),
+    ('\u{1F92}', "\u{1F9A}"),
+    ('\u{1F93}', "\u{1F9B}"),
+    ('\u{1F94}', "\u{1F9C}"),
+    ('\u{1F95}', "\u{1F9D}"),
+    ('\u{1F96}', "\u{1F9E}"),
+    ('\u{1F97}', "\u{1F9F}"),
+    ('\u{1F98}', "\u{1F98}"),
+    ('\u{1F99}', "\u{1F99}"),
+    ('\u{1F9A}', "\u{1F9A}"),
+    ('\u{1F9B}', "\u{1F9B}"),
+    ('\u{1F9C}', "\u{1F9C}"),
+    ('\u{1F9D}', "\u{1F9D}"),
+    ('\u{1F9E}', "\u{1F9E}"),
+    ('\u{1F9F}', "\u{1F9F}"),
+    ('\u{1FA0}', "\u{1FA8}"),
+    ('\u{1FA1}', "\u{1FA9}"),
+    ('\u{1FA2}', "\u{1FAA}"),
+    ('\u{1FA3}', "\u{1FAB}"),
+    ('\u{1FA4}', "\u{1FAC}"),
+    ('\u{1FA5}', "\u{1FAD}"),
+    ('\u{1FA6}', "\u{1FAE}"),
+    ('\u{1FA7}', "\u{1FAF}"),
+    ('\u{1FA8}', "\u{1FA8}"),
+    ('\u{1FA9}', "\u{1FA9}"),
+    ('\u{1FAA}', "\u{1FAA}"),
+    ('\u{1FAB}', "\u{1FAB}"),
+    ('\u{1FAC}', "\u{1FAC}"),
+    ('\u{1FAD}', "\u{1FAD}"),
+    ('\u{1FAE}', "\u{1FAE}"),
+    ('\u{1FAF}', "\u{1FAF}"),
+    ('\u{1FB2}', "\u{1FBA}\u{345}"),
+    ('\u{1FB3}', "\u{1FBC}"),
+    ('\u{1FB4}', "\u{386}\u{345}"),
+    ('\u{1FB7}', "\u{391}\u{342}\u{345}"),
+    ('\u{1FBC}', "\u{1FBC}"),
+    ('\u{1FC2}', "\u{1FCA}\u{345}"),
+    ('\u{1FC3}', "\u{1FCC}"),
+    ('\u{1FC4}', "\u{389}\u{345}"),
+    ('\u{1FC7}', "\u{397}\u{342}\u{345}"),
+    ('\u{1FCC}', "\u{1FCC}"),
+    ('\u{1FF2}', "\u{1FFA}\u{345}"),
+    ('\u{1FF3}', "\u{1FFC}"),
+    ('\u{1FF4}', "\u{38F}\u{345}"),
+    ('\u{1FF7}', "\u{3A9}\u{342}\u{345}"),
+    ('\u{1FFC}', "\u{1FFC}"),
+    ('\u{FB00}', "Ff"),
+    ('\u{FB01}', "Fi"),
+    ('\u{FB02}', "Fl"),
+    ('\u{FB03}', "Ffi"),
+    ('\u{FB04}', "Ffl"),
+    ('\u{FB05}', "St"),
+    ('\u{FB06}', "St"),
+    ('\u{FB13}', "\u{544}\u{576}"),
+    ('\u{FB14}', "\u{544}\u{565}"),
+    ('\u{FB15}', "\u{544}\u{56B}"),
+    ('\u{FB16}', "\u{54E}\u{576}"),
+    ('\u{FB17}', "\u{544}\u{56D}"),
+];
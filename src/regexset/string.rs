@@ -1,8 +1,10 @@
 use alloc::string::String;
 
-use regex_automata::{meta, Input, PatternID, PatternSet, PatternSetIter};
+use regex_automata::{
+    meta, util::syntax, Input, PatternID, PatternSet, PatternSetIter,
+};
 
-use crate::{Error, RegexSetBuilder};
+use crate::{regex::overlapping, Captures, Error, Match, RegexSetBuilder};
 
 /// Match multiple, possibly overlapping, regexes in a single search.
 ///
@@ -36,9 +38,21 @@ use crate::{Error, RegexSetBuilder};
 /// instead of (2) since the matching engines can stop after the first match
 /// is found.
 ///
-/// You cannot directly extract [`Match`][crate::Match] or
-/// [`Captures`][crate::Captures] objects from a regex set. If you need these
-/// operations, the recommended approach is to compile each pattern in the set
+/// A regex set can also report the spans of non-overlapping matches via
+/// [`RegexSet::find_iter`] and [`RegexSet::captures_iter`]. These search for
+/// matches of any regex in the set using the same leftmost-first semantics as
+/// a single regex, so when two regexes in the set match at the same position,
+/// only the one that appears first in the set is reported. Combined with
+/// [`RegexSet::new_named`] and [`RegexSet::captures`], this permits
+/// expressing something like a lexer as a single set instead of many
+/// separate regexes. To support this, building a regex set also builds a
+/// second regex internally, which adds to the time it takes to build a set.
+/// Since this second regex has capture groups, it's also possible for it to
+/// exceed the configured [size limit](RegexSetBuilder::size_limit) when the
+/// set alone wouldn't, in which case building the set fails.
+///
+/// If you need the span of every regex that matches, even when matches
+/// overlap, the recommended approach is to compile each pattern in the set
 /// independently and scan the exact same haystack a second time with those
 /// independently compiled patterns:
 ///
@@ -131,7 +145,12 @@ use crate::{Error, RegexSetBuilder};
 #[derive(Clone)]
 pub struct RegexSet {
     pub(crate) meta: meta::Regex,
+    pub(crate) leftmost: meta::Regex,
     pub(crate) patterns: alloc::sync::Arc<[String]>,
+    pub(crate) names: alloc::sync::Arc<[Option<String>]>,
+    // This is only needed to build the regex engines for overlapping
+    // searches, since the meta regex doesn't expose its NFAs.
+    pub(crate) syntaxc: syntax::Config,
 }
 
 impl RegexSet {
@@ -159,6 +178,54 @@ impl RegexSet {
         RegexSetBuilder::new(exprs).build()
     }
 
+    /// Create a new regex set with the given pairs of names and regular
+    /// expressions.
+    ///
+    /// This is like [`RegexSet::new`], except each regex is also given a
+    /// name, which can be looked up by the regex's index with
+    /// [`RegexSet::name`]. If any of the patterns are not valid regular
+    /// expressions, or if two patterns have the same name, then an error is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// This shows how a simple lexer can be expressed as a single set. Each
+    /// token is found by [`RegexSet::captures_at`], and its kind is the name
+    /// of the regex that matched.
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new_named([
+    ///     ("number", r"[0-9]+(?:\.(?<frac>[0-9]+))?"),
+    ///     ("ident", r"[a-z]+"),
+    ///     ("op", r"[-+*/=]"),
+    /// ]).unwrap();
+    /// let hay = "x = 1.5 + y";
+    ///
+    /// let (mut at, mut tokens) = (0, vec![]);
+    /// while let Some((index, caps)) = set.captures_at(hay, at) {
+    ///     let m = caps.get(0).unwrap();
+    ///     tokens.push((set.name(index).unwrap(), m.as_str()));
+    ///     at = m.end();
+    /// }
+    /// assert_eq!(tokens, vec![
+    ///     ("ident", "x"),
+    ///     ("op", "="),
+    ///     ("number", "1.5"),
+    ///     ("op", "+"),
+    ///     ("ident", "y"),
+    /// ]);
+    /// ```
+    pub fn new_named<I, N, S>(pairs: I) -> Result<RegexSet, Error>
+    where
+        N: AsRef<str>,
+        S: AsRef<str>,
+        I: IntoIterator<Item = (N, S)>,
+    {
+        RegexSetBuilder::new_named(pairs).build()
+    }
+
     /// Create a new empty regex set.
     ///
     /// An empty regex never matches anything.
@@ -381,6 +448,257 @@ impl RegexSet {
         self.matches_read_at(matches, haystack, start)
     }
 
+    /// Returns an iterator over successive non-overlapping matches of the
+    /// regexes in this set. Each item yielded is the index of the regex that
+    /// matched paired with the [`Match`] itself.
+    ///
+    /// Unlike [`RegexSet::matches`], this finds matches using the same
+    /// leftmost-first semantics as a single regex that is an alternation of
+    /// every regex in this set, in order. That is, the leftmost match is
+    /// always reported first, and when more than one regex matches starting
+    /// at the same position, the regex that appears first in the set is
+    /// preferred.
+    ///
+    /// # Time complexity
+    ///
+    /// Note that since `find_iter` runs potentially many searches on the
+    /// haystack and since each search has worst case `O(m * n)` time
+    /// complexity, the overall worst case time complexity for iteration is
+    /// `O(m * n^2)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new([r"[0-9]+", r"[a-z]+"]).unwrap();
+    /// let hay = "abc 123 de4";
+    /// let matches: Vec<(usize, &str)> = set
+    ///     .find_iter(hay)
+    ///     .map(|(index, m)| (index, m.as_str()))
+    ///     .collect();
+    /// assert_eq!(matches, vec![(1, "abc"), (0, "123"), (1, "de"), (0, "4")]);
+    /// ```
+    ///
+    /// This example shows that when more than one regex matches at the same
+    /// position, only the first one is reported:
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new([r"foo", r"foobar"]).unwrap();
+    /// let hay = "foobar foo";
+    /// let matches: Vec<(usize, &str)> = set
+    ///     .find_iter(hay)
+    ///     .map(|(index, m)| (index, m.as_str()))
+    ///     .collect();
+    /// assert_eq!(matches, vec![(0, "foo"), (0, "foo")]);
+    /// ```
+    #[inline]
+    pub fn find_iter<'r, 'h>(
+        &'r self,
+        haystack: &'h str,
+    ) -> SetFindMatches<'r, 'h> {
+        SetFindMatches { haystack, it: self.leftmost.find_iter(haystack) }
+    }
+
+    /// Returns the index of the regex in this set with the leftmost match in
+    /// the haystack given, along with the [`Captures`] for that match.
+    ///
+    /// The match is found in precisely the same way as the first match
+    /// yielded by [`RegexSet::captures_iter`]. That is, when more than one
+    /// regex matches starting at the same position, the regex that appears
+    /// first in the set wins. Capture groups are numbered independently for
+    /// each regex in the set, so the `Captures` returned only contains the
+    /// groups of the winning regex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new_named([
+    ///     ("date", r"(?<y>[0-9]{4})-(?<m>[0-9]{2})-(?<d>[0-9]{2})"),
+    ///     ("time", r"(?<h>[0-9]{2}):(?<m>[0-9]{2})"),
+    /// ]).unwrap();
+    ///
+    /// let hay = "at 12:30 on 2010-03-14";
+    /// let (index, caps) = set.captures(hay).unwrap();
+    /// assert_eq!(Some("time"), set.name(index));
+    /// assert_eq!(&caps["h"], "12");
+    /// assert_eq!(&caps["m"], "30");
+    /// // The names of capture groups are specific to each regex.
+    /// assert!(caps.name("y").is_none());
+    ///
+    /// assert!(set.captures("no dates here").is_none());
+    /// ```
+    #[inline]
+    pub fn captures<'h>(
+        &self,
+        haystack: &'h str,
+    ) -> Option<(usize, Captures<'h>)> {
+        self.captures_at(haystack, 0)
+    }
+
+    /// Returns the same as [`RegexSet::captures`], but starts the search at
+    /// the given offset.
+    ///
+    /// The significance of the starting point is that it takes the
+    /// surrounding context into consideration. For example, the `\A` anchor
+    /// can only match when `start == 0`.
+    ///
+    /// # Panics
+    ///
+    /// This panics when `start >= haystack.len() + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new([r"\bfoo", r"bar"]).unwrap();
+    /// let hay = "foofoobar";
+    /// let (index, caps) = set.captures_at(hay, 3).unwrap();
+    /// assert_eq!(1, index);
+    /// assert_eq!(6..9, caps.get(0).unwrap().range());
+    /// ```
+    #[inline]
+    pub fn captures_at<'h>(
+        &self,
+        haystack: &'h str,
+        start: usize,
+    ) -> Option<(usize, Captures<'h>)> {
+        let re = &self.leftmost;
+        let input = Input::new(haystack).span(start..haystack.len());
+        let mut caps = re.create_captures();
+        re.search_captures(&input, &mut caps);
+        // There is a pattern ID if and only if there was a match.
+        let index = caps.pattern()?.as_usize();
+        let static_captures_len = re.static_captures_len();
+        Some((index, Captures::new(haystack, caps, static_captures_len)))
+    }
+
+    /// Returns an iterator over successive non-overlapping matches of the
+    /// regexes in this set, including the spans of each capture group. Each
+    /// item yielded is the index of the regex that matched paired with the
+    /// [`Captures`] for that match.
+    ///
+    /// Matches are found in precisely the same way as for
+    /// [`RegexSet::find_iter`]. Capture groups are numbered independently
+    /// for each regex in the set, and each regex may have its own named
+    /// groups.
+    ///
+    /// # Time complexity
+    ///
+    /// Note that since `captures_iter` runs potentially many searches on the
+    /// haystack and since each search has worst case `O(m * n)` time
+    /// complexity, the overall worst case time complexity for iteration is
+    /// `O(m * n^2)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new([
+    ///     r"(?<key>[a-z]+)=(?<value>[0-9]+)",
+    ///     r"#(?<tag>[a-z]+)",
+    /// ]).unwrap();
+    /// let hay = "a=1 #x b=2";
+    /// let mut it = set.captures_iter(hay);
+    ///
+    /// let (index, caps) = it.next().unwrap();
+    /// assert_eq!(index, 0);
+    /// assert_eq!(&caps["key"], "a");
+    /// assert_eq!(&caps["value"], "1");
+    ///
+    /// let (index, caps) = it.next().unwrap();
+    /// assert_eq!(index, 1);
+    /// assert_eq!(&caps["tag"], "x");
+    ///
+    /// let (index, caps) = it.next().unwrap();
+    /// assert_eq!(index, 0);
+    /// assert_eq!(&caps["key"], "b");
+    /// assert_eq!(&caps["value"], "2");
+    ///
+    /// assert!(it.next().is_none());
+    /// ```
+    #[inline]
+    pub fn captures_iter<'r, 'h>(
+        &'r self,
+        haystack: &'h str,
+    ) -> SetCaptureMatches<'r, 'h> {
+        SetCaptureMatches {
+            haystack,
+            it: self.leftmost.captures_iter(haystack),
+        }
+    }
+
+    /// Returns an iterator over every match of every regex in this set,
+    /// including matches that overlap with one another. Each item yielded is
+    /// the index of the regex that matched paired with the [`Match`] itself.
+    ///
+    /// Unlike [`RegexSet::find_iter`], this reports every span of the
+    /// haystack that each regex matches, regardless of match priority. In
+    /// particular, the same span is reported once for each regex that
+    /// matches it.
+    ///
+    /// Matches are yielded in ascending order of their end offsets. Matches
+    /// of the same regex with the same end offset are yielded in descending
+    /// order of their start offsets (so shorter matches come first). The
+    /// order of matches of different regexes with the same end offset is
+    /// unspecified.
+    ///
+    /// Each call to this routine builds additional regex engines internally,
+    /// which can take some time for big sets. So it's best to find all of
+    /// the matches in a haystack with a single call.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if building the additional regex engines fails.
+    /// This can only happen when they exceed the configured
+    /// [size limit](RegexSetBuilder::size_limit).
+    ///
+    /// # Time complexity
+    ///
+    /// Finding where each match ends takes `O(m * n)` time in total, but
+    /// finding where matches start requires a reverse search from each of
+    /// those ends. So the overall worst case time complexity for iteration is
+    /// `O(m * n^2)`, not counting the time it takes to yield each match.
+    ///
+    /// When a lazy DFA can't be used, a much slower approach with worst case
+    /// time complexity `O(m * n^3)` is used instead. See
+    /// [`Regex::find_overlapping_iter`](crate::Regex::find_overlapping_iter)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new([r"ab", r"b+c"]).unwrap();
+    /// let hay = "abbc";
+    /// let matches: Vec<(usize, &str)> = set
+    ///     .find_overlapping_iter(hay)
+    ///     .unwrap()
+    ///     .map(|(index, m)| (index, m.as_str()))
+    ///     .collect();
+    /// assert_eq!(matches, vec![(0, "ab"), (1, "bc"), (1, "bbc")]);
+    /// ```
+    #[inline]
+    pub fn find_overlapping_iter<'h>(
+        &self,
+        haystack: &'h str,
+    ) -> Result<SetOverlappingMatches<'h>, Error> {
+        let engine = overlapping::Engine::new(
+            &self.meta,
+            &self.patterns,
+            self.syntaxc,
+        )?;
+        let it = overlapping::Searcher::new(engine, haystack.as_bytes());
+        Ok(SetOverlappingMatches { haystack, it })
+    }
+
     /// Returns the total number of regexes in this set.
     ///
     /// # Example
@@ -444,6 +762,32 @@ impl RegexSet {
     pub fn patterns(&self) -> &[String] {
         &self.patterns
     }
+
+    /// Returns the name of the regex at the given index in this set, if it
+    /// has one.
+    ///
+    /// Only regexes in sets built by [`RegexSet::new_named`] (or
+    /// [`RegexSetBuilder::new_named`]) have names. This returns `None` when
+    /// the index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use regex::RegexSet;
+    ///
+    /// let set = RegexSet::new_named([("word", r"\w+"), ("digit", r"\d")])
+    ///     .unwrap();
+    /// assert_eq!(Some("word"), set.name(0));
+    /// assert_eq!(Some("digit"), set.name(1));
+    /// assert_eq!(None, set.name(2));
+    ///
+    /// let set = RegexSet::new([r"\w+"]).unwrap();
+    /// assert_eq!(None, set.name(0));
+    /// ```
+    #[inline]
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.names.get(index)?.as_deref()
+    }
 }
 
 impl Default for RegexSet {
@@ -717,6 +1061,127 @@ impl<'a> DoubleEndedIterator for SetMatchesIter<'a> {
 
 impl<'a> core::iter::FusedIterator for SetMatchesIter<'a> {}
 
+/// An iterator over all non-overlapping matches of a regex set in a haystack.
+///
+/// This iterator yields pairs of the index of the regex that matched and the
+/// corresponding [`Match`]. The iterator stops when no more matches can be
+/// found.
+///
+/// `'r` is the lifetime of the compiled regex set and `'h` is the lifetime
+/// of the haystack.
+///
+/// This iterator is created by [`RegexSet::find_iter`].
+///
+/// # Time complexity
+///
+/// Note that since an iterator runs potentially many searches on the haystack
+/// and since each search has worst case `O(m * n)` time complexity, the
+/// overall worst case time complexity for iteration is `O(m * n^2)`.
+#[derive(Debug)]
+pub struct SetFindMatches<'r, 'h> {
+    haystack: &'h str,
+    it: meta::FindMatches<'r, 'h>,
+}
+
+impl<'r, 'h> Iterator for SetFindMatches<'r, 'h> {
+    type Item = (usize, Match<'h>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, Match<'h>)> {
+        self.it.next().map(|m| {
+            let index = m.pattern().as_usize();
+            (index, Match::new(self.haystack, m.start(), m.end()))
+        })
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.it.count()
+    }
+}
+
+impl<'r, 'h> core::iter::FusedIterator for SetFindMatches<'r, 'h> {}
+
+/// An iterator over all non-overlapping capture matches of a regex set in a
+/// haystack.
+///
+/// This iterator yields pairs of the index of the regex that matched and the
+/// corresponding [`Captures`]. The iterator stops when no more matches can be
+/// found.
+///
+/// `'r` is the lifetime of the compiled regex set and `'h` is the lifetime
+/// of the haystack.
+///
+/// This iterator is created by [`RegexSet::captures_iter`].
+///
+/// # Time complexity
+///
+/// Note that since an iterator runs potentially many searches on the haystack
+/// and since each search has worst case `O(m * n)` time complexity, the
+/// overall worst case time complexity for iteration is `O(m * n^2)`.
+#[derive(Debug)]
+pub struct SetCaptureMatches<'r, 'h> {
+    haystack: &'h str,
+    it: meta::CapturesMatches<'r, 'h>,
+}
+
+impl<'r, 'h> Iterator for SetCaptureMatches<'r, 'h> {
+    type Item = (usize, Captures<'h>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, Captures<'h>)> {
+        let static_captures_len = self.it.regex().static_captures_len();
+        self.it.next().map(|caps| {
+            // OK because the iterator only yields captures for a match, and
+            // every match has a pattern ID.
+            let index = caps.pattern().unwrap().as_usize();
+            (index, Captures::new(self.haystack, caps, static_captures_len))
+        })
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.it.count()
+    }
+}
+
+impl<'r, 'h> core::iter::FusedIterator for SetCaptureMatches<'r, 'h> {}
+
+/// An iterator over all matches of a regex set in a haystack, including
+/// matches that overlap with one another.
+///
+/// This iterator yields pairs of the index of the regex that matched and the
+/// corresponding [`Match`]. The iterator stops when no more matches can be
+/// found.
+///
+/// `'h` is the lifetime of the haystack.
+///
+/// This iterator is created by [`RegexSet::find_overlapping_iter`].
+///
+/// # Time complexity
+///
+/// See [`RegexSet::find_overlapping_iter`] for details. In the worst case,
+/// iteration takes `O(m * n^2)` time, or `O(m * n^3)` time when a lazy DFA
+/// can't be used.
+#[derive(Debug)]
+pub struct SetOverlappingMatches<'h> {
+    haystack: &'h str,
+    it: overlapping::Searcher<'h>,
+}
+
+impl<'h> Iterator for SetOverlappingMatches<'h> {
+    type Item = (usize, Match<'h>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, Match<'h>)> {
+        self.it.next().map(|(pid, start, end)| {
+            (pid.as_usize(), Match::new(self.haystack, start, end))
+        })
+    }
+}
+
+impl<'h> core::iter::FusedIterator for SetOverlappingMatches<'h> {}
+
 impl core::fmt::Debug for RegexSet {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "RegexSet({:?})", self.patterns())
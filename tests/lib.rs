@@ -5,6 +5,7 @@ mod misc;
 mod regression;
 mod regression_fuzz;
 mod replace;
+mod sample;
 #[cfg(feature = "pattern")]
 mod searcher;
 mod suite_bytes;
@@ -29,6 +30,7 @@ fn suite() -> anyhow::Result<regex_test::RegexTests> {
     }
 
     load!("anchored");
+    load!("atomic");
     load!("bytes");
     load!("crazy");
     load!("crlf");
@@ -39,6 +41,7 @@ fn suite() -> anyhow::Result<regex_test::RegexTests> {
     load!("iter");
     load!("leftmost-all");
     load!("line-terminator");
+    load!("look-around");
     load!("misc");
     load!("multiline");
     load!("no-unicode");
@@ -115,6 +115,55 @@ fn sub_capture_matches() {
     assert_eq!("5", subs[4].unwrap().as_str());
 }
 
+// Test that the regex types can be shared between threads and used across
+// an unwind boundary. Engines that are only needed by some searches must not
+// be stored in a way that loses these traits.
+#[test]
+fn oibits() {
+    use std::panic::{RefUnwindSafe, UnwindSafe};
+
+    fn assert_oibits<T: Send + Sync + UnwindSafe + RefUnwindSafe>() {}
+    assert_oibits::<regex::Regex>();
+    assert_oibits::<regex::bytes::Regex>();
+    assert_oibits::<regex::RegexSet>();
+    assert_oibits::<regex::bytes::RegexSet>();
+}
+
+// Test that a regex set whose capture groups push it over the size limit
+// fails to build, instead of panicking when match spans are first asked for.
+// The set alone (which doesn't need capture groups) fits within the limit.
+#[test]
+fn regex_set_size_limit_captures() {
+    let patterns = [r"(x)(y)(z)", r"((a)|(b))+"];
+    assert!(regex::RegexSetBuilder::new(patterns)
+        .size_limit(1000)
+        .build()
+        .is_err());
+    assert!(regex::bytes::RegexSetBuilder::new(patterns)
+        .size_limit(1000)
+        .build()
+        .is_err());
+
+    let set = regex::RegexSetBuilder::new(patterns)
+        .size_limit(2000)
+        .build()
+        .unwrap();
+    let got: Vec<_> =
+        set.find_iter("xyz").map(|(i, m)| (i, m.range())).collect();
+    assert_eq!(vec![(0, 0..3)], got);
+    let (index, caps) = set.captures("xyz").unwrap();
+    assert_eq!(0, index);
+    assert_eq!("y", &caps[2]);
+
+    let set = regex::bytes::RegexSetBuilder::new(patterns)
+        .size_limit(2000)
+        .build()
+        .unwrap();
+    let (index, caps) = set.captures_at(b"xyzab", 3).unwrap();
+    assert_eq!(1, index);
+    assert_eq!(&b"b"[..], &caps[1]);
+}
+
 // Test that the DFA can handle pathological cases. (This should result in the
 // DFA's cache being flushed too frequently, which should cause it to quit and
 // fall back to the NFA algorithm.)
@@ -141,3 +190,331 @@ fn dfa_handles_pathological_case() {
     };
     assert!(re.is_match(&text));
 }
+
+// Test that streaming searches find matches that straddle the boundary
+// between two reads of the internal buffer, and that they report absolute
+// offsets.
+#[cfg(feature = "stream")]
+#[test]
+fn stream_find_iter_large() {
+    let re =
+        regex::bytes::Regex::new(r"(?-u:\b)foo[a-z]*bar(?-u:\b)").unwrap();
+    let mut hay = vec![b'x'; 200_000];
+    hay.extend_from_slice(b" foozzzbar ");
+    hay.extend(std::iter::repeat(b'y').take(65_530));
+    hay.extend_from_slice(b" foo");
+    hay.extend(std::iter::repeat(b'z').take(100_000));
+    hay.extend_from_slice(b"bar");
+    let expected: Vec<_> = re
+        .find_iter(&hay)
+        .map(|m| (m.start() as u64, m.end() as u64))
+        .collect();
+    assert_eq!(2, expected.len());
+    let got: Vec<_> = re
+        .stream_find_iter(std::io::Cursor::new(&hay))
+        .map(|m| m.unwrap())
+        .map(|m| (m.start(), m.end()))
+        .collect();
+    assert_eq!(expected, got);
+}
+
+// Test that streaming searches fall back to the meta regex engine when the
+// lazy DFA quits because of a Unicode word boundary.
+#[cfg(all(feature = "stream", feature = "unicode-perl"))]
+#[test]
+fn stream_find_iter_unicode_word_boundary() {
+    let re = regex::bytes::Regex::new(r"\b\w+\b").unwrap();
+    let hay = "foo δβ bar".as_bytes();
+    let got: Vec<_> =
+        re.stream_find_iter(hay).map(|m| m.unwrap().into_bytes()).collect();
+    assert_eq!(
+        vec![b"foo".to_vec(), "δβ".as_bytes().to_vec(), b"bar".to_vec()],
+        got
+    );
+}
+
+// Test that streaming searches work when the lazy DFA's cache is small
+// enough to be cleared repeatedly during a search.
+#[cfg(feature = "stream")]
+#[test]
+fn stream_find_iter_small_cache() {
+    let hay: Vec<u8> = (0..200_000u32)
+        .map(|i| if i % 7 == 6 { b'1' } else { b'a' + (i % 26) as u8 })
+        .map(|b| if b == b'q' { b'9' } else { b })
+        .collect();
+    let mut builder = regex::bytes::RegexBuilder::new(r"[a-z]+[0-9]{2,5}");
+    for limit in [1 << 10, 4 << 10, 16 << 10, 64 << 10] {
+        let re = builder.dfa_size_limit(limit).build().unwrap();
+        let expected: Vec<_> = re
+            .find_iter(&hay)
+            .map(|m| (m.start() as u64, m.end() as u64))
+            .collect();
+        let got: Vec<_> = re
+            .stream_find_iter(&hay[..])
+            .map(|m| m.unwrap())
+            .map(|m| (m.start(), m.end()))
+            .collect();
+        assert_eq!(expected, got, "dfa_size_limit({})", limit);
+    }
+}
+
+// Test that a long run of bytes that could each start a match doesn't make
+// streaming searches quadratic.
+#[cfg(feature = "stream")]
+#[test]
+fn stream_find_iter_long_near_match() {
+    let re = regex::bytes::Regex::new(r"[a-z]+X").unwrap();
+    let mut hay = vec![b'a'; 200_000];
+    hay.push(b'Y');
+    assert_eq!(0, re.stream_find_iter(&hay[..]).count());
+    hay.extend_from_slice(b"aX");
+    let got: Vec<_> = re
+        .stream_find_iter(&hay[..])
+        .map(|m| m.unwrap())
+        .map(|m| (m.start(), m.end()))
+        .collect();
+    assert_eq!(vec![(200_001, 200_003)], got);
+}
+
+// Test that I/O errors are reported by streaming searches.
+#[cfg(feature = "stream")]
+#[test]
+fn stream_find_iter_error() {
+    struct Failing;
+
+    impl std::io::Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(std::io::ErrorKind::Other, "boom"))
+        }
+    }
+
+    let re = regex::bytes::Regex::new(r"a").unwrap();
+    let mut it = re.stream_find_iter(Failing);
+    assert_eq!("boom", it.next().unwrap().unwrap_err().to_string());
+    assert!(it.next().is_none());
+}
+
+// Test that overlapping searches still find every match when the lazy DFA
+// gives up part way through the haystack because of a Unicode word boundary.
+#[test]
+fn find_overlapping_iter_unicode_word_boundary() {
+    let re = regex!(r"\w+\b");
+    let got: Vec<_> = re
+        .find_overlapping_iter("ab cδ")
+        .unwrap()
+        .map(|m| m.range())
+        .collect();
+    assert_eq!(vec![1..2, 0..2, 4..6, 3..6], got);
+}
+
+// Test that overlapping searches work for regexes the lazy DFA can't handle
+// at all.
+#[test]
+fn find_overlapping_iter_look_around() {
+    let re = regex!(r"(?<=a)b+");
+    let got: Vec<_> =
+        re.find_overlapping_iter("abbb").unwrap().map(|m| m.range()).collect();
+    assert_eq!(vec![1..2, 1..3, 1..4], got);
+
+    let set = regex::RegexSet::new([r"(?<=a)b+", r"b"]).unwrap();
+    let mut got: Vec<_> = set
+        .find_overlapping_iter("abb")
+        .unwrap()
+        .map(|(i, m)| (i, m.range()))
+        .collect();
+    got.sort_by_key(|(i, m)| (m.end, *i));
+    assert_eq!(vec![(0, 1..2), (1, 1..2), (0, 1..3), (1, 2..3)], got);
+}
+
+// Test that iterating over the matches of a regex with look-around or atomic
+// groups does its precomputations once for the whole haystack instead of once
+// for every match. Otherwise, these searches take quadratic time and this test
+// doesn't finish in any reasonable amount of time.
+#[test]
+fn find_iter_look_around_long_haystack() {
+    let hay = "a".repeat(100_000) + "c";
+    assert_eq!(99_999, regex!(r"a(?=a)").find_iter(&hay).count());
+    assert_eq!(99_999, regex!(r"(?<=a)a").find_iter(&hay).count());
+    assert_eq!(100_000, regex!(r"a(?!b)").find_iter(&hay).count());
+    assert_eq!(100_000, regex!(r"a(?=[a-z]*c)").find_iter(&hay).count());
+    assert_eq!(99_999, regex!(r"(?>a|ab)(?=a)").find_iter(&hay).count());
+}
+
+// Test that reverse iteration finds the same matches with and without a lazy
+// DFA, and that each match extends as far back as a forward search allows.
+#[test]
+fn rfind_iter() {
+    let re = regex!(r"[0-9]+");
+    let got: Vec<_> =
+        re.rfind_iter("a1 b22 c333").map(|m| m.as_str()).collect();
+    assert_eq!(vec!["333", "22", "1"], got);
+    assert_eq!(Some(8..11), re.rfind("a1 b22 c333 ").map(|m| m.range()));
+
+    // The lazy DFA can't handle look-around.
+    let re = regex!(r"(?<=a)b+");
+    let got: Vec<_> = re.rfind_iter("abbb ab").map(|m| m.range()).collect();
+    assert_eq!(vec![6..7, 1..4], got);
+
+    let re = regex::bytes::Regex::new(r"(?-u:\xFF)+|z").unwrap();
+    let got: Vec<_> =
+        re.rfind_iter(b"\xFF\xFFz\xFF").map(|m| m.range()).collect();
+    assert_eq!(vec![3..4, 2..3, 0..2], got);
+}
+
+#[test]
+fn replacement_template() {
+    use regex::{ReplacementTemplate, ReplacementTemplateBuilder};
+
+    // Without case conversion, a template expands exactly like a string.
+    let re = regex!(r"(?<first>\w+)\s+(?<last>\w+)(x)?");
+    let hay = "Bruce Springsteen, Patti Scialfa";
+    let templates = [
+        "$last $first",
+        "${last}_${1}a$2",
+        "$$1 $ ${first $",
+        r"\U$0",
+        "$3",
+        "no references",
+        "",
+    ];
+    for template in templates {
+        let parsed = ReplacementTemplate::new(&re, template).unwrap();
+        assert_eq!(template, parsed.as_str());
+        assert_eq!(
+            re.replace_all(hay, template),
+            re.replace_all(hay, &parsed),
+            "template {:?}",
+            template,
+        );
+    }
+
+    // References to groups that don't exist are errors.
+    for template in ["$4", "$middle", "${1a}", "$1a"] {
+        assert!(ReplacementTemplate::new(&re, template).is_err());
+    }
+
+    let case = |template: &str| {
+        ReplacementTemplateBuilder::new()
+            .case_conversion(true)
+            .build(&re, template)
+    };
+    let replace = |template: &str| {
+        re.replace_all(hay, &case(template).unwrap()).into_owned()
+    };
+    assert_eq!("SPRINGSTEEN bruce, SCIALFA patti", replace(r"\U$2 \L$1"));
+    assert_eq!("SPRINGSTEEN!, SCIALFA!", replace(r"\U$2\E!"));
+    assert_eq!("springsteen-X, scialfa-X", replace(r"\L$2\E-X"));
+    assert_eq!("Bruce-X, Patti-X", replace(r"\u\L$1\E-\ux"));
+    assert_eq!("bRUCE, pATTI", replace(r"\U\l$first"));
+    assert_eq!(r"\BRUCE\, \PATTI\", replace(r"\\\U$1\\"));
+    // A one-character conversion carries over an empty group.
+    assert_eq!("Abc, Abc", replace(r"\u${3}abc"));
+    // '\u' uses titlecase, which differs from uppercase for some characters.
+    let re2 = regex::Regex::new(r"\w+").unwrap();
+    let template = ReplacementTemplateBuilder::new()
+        .case_conversion(true)
+        .build(&re2, r"\u$0|\U$0")
+        .unwrap();
+    let title = |hay: &str| re2.replace(hay, &template).into_owned();
+    assert_eq!("Ss|SS", title("ß"));
+    assert_eq!("Fi|FI", title("\u{FB01}"));
+    assert_eq!("\u{1C5}ungla|\u{1C4}UNGLA", title("\u{1C6}ungla"));
+    assert_eq!("\u{10D0}|\u{1C90}", title("\u{10D0}"));
+    assert_eq!("Ab|AB", title("ab"));
+    assert!(case(r"\n").is_err());
+    assert!(case("\\").is_err());
+    assert!(case("$nope").is_err());
+
+    let re = regex::bytes::Regex::new(r"(\w+)=((?-u:\S)+)").unwrap();
+    let template = regex::bytes::ReplacementTemplateBuilder::new()
+        .case_conversion(true)
+        .build(&re, br"\U$1\E:\u$2")
+        .unwrap();
+    let got = re.replace_all(b"ab=\xFFcd \xC3\xA9=\xC3\xA9", &template);
+    assert_eq!(&b"AB:\xFFcd \xC3\x89:\xC3\x89"[..], &got[..]);
+    assert!(regex::bytes::ReplacementTemplate::new(&re, b"$3").is_err());
+}
+
+#[test]
+fn grapheme_cluster() {
+    let clusters = |pattern: &str, hay: &str| -> Vec<String> {
+        regex::Regex::new(pattern)
+            .unwrap()
+            .find_iter(hay)
+            .map(|m| m.as_str().to_string())
+            .collect()
+    };
+    assert_eq!(
+        vec!["e\u{301}", "\r\n", "a", "\n"],
+        clusters(r"\X", "e\u{301}\r\na\n"),
+    );
+    // A family emoji joined with ZWJ, a flag made of two regional
+    // indicators and a Hangul syllable written with conjoining jamo.
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let flag = "\u{1F1FA}\u{1F1F8}";
+    let hangul = "\u{1100}\u{1161}\u{11A8}";
+    let hay = format!("{}{}{}{}", family, flag, flag, hangul);
+    assert_eq!(vec![family, flag, flag, hangul], clusters(r"\X", &hay));
+    // Prepended characters and spacing marks.
+    assert_eq!(
+        vec!["\u{600}a", "\u{915}\u{93F}"],
+        clusters(r"\X", "\u{600}a\u{915}\u{93F}"),
+    );
+
+    assert_eq!(vec!["e\u{301}", "x"], clusters(r"(?g).", "e\u{301}x"));
+    assert_eq!(
+        vec!["e", "\u{301}", "x"],
+        clusters(r"(?g)(?-g:.)", "e\u{301}x"),
+    );
+    // Without dot-matches-new-line, `.` doesn't match a cluster starting
+    // with a new line, and `(?R)` also excludes CRLF.
+    assert_eq!(vec!["a"], clusters(r"(?g).", "\na\n"));
+    assert_eq!(vec!["a"], clusters(r"(?gR).", "\r\na"));
+    assert_eq!(vec!["\r\n", "a"], clusters(r"(?gs).", "\r\na"));
+    // Negated classes restrict only the first codepoint of the cluster.
+    assert_eq!(vec!["b\u{301}", "c"], clusters(r"(?g)[^a]", "ab\u{301}c"));
+    assert!(regex::Regex::new(r"(?-u)\X").is_err());
+
+    let re = regex::RegexBuilder::new(r"^.$").grapheme(true).build().unwrap();
+    assert!(re.is_match(family));
+    assert!(!regex::Regex::new(r"^.$").unwrap().is_match(family));
+
+    // Repeating a cluster a modest number of times stays within the default
+    // size limit.
+    let pattern = r"^\X{50}$";
+    let re = regex::Regex::new(pattern).unwrap();
+    assert!(re.is_match(&"e\u{301}".repeat(50)));
+    assert!(!re.is_match(&"x".repeat(49)));
+}
+
+#[test]
+fn segment_boundaries() {
+    let split = |pattern: &str, hay: &str| -> Vec<String> {
+        let re = regex::Regex::new(pattern).unwrap();
+        let mut last = 0;
+        let mut pieces = vec![];
+        for m in re.find_iter(hay) {
+            if m.start() > last {
+                pieces.push(hay[last..m.start()].to_string());
+                last = m.start();
+            }
+        }
+        pieces
+    };
+    assert_eq!(
+        vec!["Can't", " ", "pay", " ", "3.14", " ", "e\u{301}", "!"],
+        split(r"\b{wb}", "Can't pay 3.14 e\u{301}!"),
+    );
+    assert_eq!(
+        vec!["Mr. smith is here. ", "Why? ", "Because."],
+        split(r"\b{sb}", "Mr. smith is here. Why? Because."),
+    );
+
+    let re = regex::Regex::new(r"\b{wb}\S+?\b{wb}").unwrap();
+    let words: Vec<&str> =
+        re.find_iter("it's 2.5 o'clock").map(|m| m.as_str()).collect();
+    assert_eq!(vec!["it's", "2.5", "o'clock"], words);
+    assert!(!regex::Regex::new(r"\b{wb}").unwrap().is_match(""));
+    assert!(regex::Regex::new(r"(?-u)\b{wb}").is_err());
+    assert!(regex::Regex::new(r"(?-u)\b{sb}").is_err());
+}
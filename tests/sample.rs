@@ -0,0 +1,60 @@
+use regex_syntax::hir::{Sampler, SamplerBuilder};
+
+// Checks that samples produced from each pattern's HIR are matched, in their
+// entirety, by the corresponding regex.
+#[test]
+fn samples_match_regex() {
+    let patterns = [
+        r"\b\w+\b",
+        r"\w+\B\w*",
+        r"[a-z ]+\b[^\n]{0,3}",
+        r"(?m)^[a-z]*$[\n.]{1,3}^[a-z\n]+$",
+        r"(?m:^)\p{Greek}{2,}\b[ .]?",
+        r"(?-u:\b)[a-zé]+(?-u:\B)é",
+        r"(?i)straße|[0-9]{3}-[0-9]{4}",
+        r"\b{start}\w+\b{end}\W+\b{start-half}",
+        r"(?mR)[\r\n]*^\w+$[\r\n]*",
+        r"(?:\d+\.){3}\d+",
+        r"[[:alpha:]&&[^aeiou]]+",
+    ];
+    let mut sampler = Sampler::new();
+    for pattern in patterns {
+        let hir = regex_syntax::parse(pattern).unwrap();
+        let re = regex::Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+        for _ in 0..200 {
+            let sample = sampler
+                .sample_string(&hir)
+                .unwrap_or_else(|| panic!("no sample for {:?}", pattern));
+            assert!(
+                re.is_match(&sample),
+                "sample {:?} not matched by {:?}",
+                sample,
+                pattern,
+            );
+        }
+    }
+}
+
+#[test]
+fn samples_match_bytes_regex() {
+    let patterns = [r"(?-u:\b[\x00-\xFF]+\b)", r"(?-u)[^a]+\B.*"];
+    let mut sampler = SamplerBuilder::new().ascii_percent(0).build();
+    for pattern in patterns {
+        let hir = regex_syntax::ParserBuilder::new()
+            .utf8(false)
+            .build()
+            .parse(pattern)
+            .unwrap();
+        let re =
+            regex::bytes::Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+        for _ in 0..200 {
+            let sample = sampler.sample(&hir).unwrap();
+            assert!(
+                re.is_match(&sample),
+                "sample {:?} not matched by {:?}",
+                sample,
+                pattern,
+            );
+        }
+    }
+}
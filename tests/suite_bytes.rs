@@ -11,7 +11,9 @@ use {
 fn default() -> Result<()> {
     let mut runner = TestRunner::new()?;
     runner
-        .expand(&["is_match", "find", "captures"], |test| test.compiles())
+        .expand(&["is_match", "find", "captures", "stream"], |test| {
+            test.compiles()
+        })
         .blacklist_iter(super::BLACKLIST)
         .test_iter(crate::suite()?.iter(), compiler)
         .assert();
@@ -36,10 +38,55 @@ fn run_test(re: &Regex, test: &RegexTest) -> TestResult {
                 .map(|caps| testify_captures(&caps));
             TestResult::captures(it)
         }
+        #[cfg(feature = "stream")]
+        "stream" => {
+            // We feed the haystack to the stream one byte at a time, which
+            // ensures that every match straddles a read boundary.
+            let rdr = OneByteReader(test.haystack());
+            let mut matches = vec![];
+            let limit = test.match_limit().unwrap_or(std::usize::MAX);
+            for result in re.stream_find_iter(rdr).take(limit) {
+                let m = match result {
+                    Ok(m) => m,
+                    Err(err) => return TestResult::fail(&err.to_string()),
+                };
+                if m.as_bytes()
+                    != &test.haystack()[m.start() as usize..][..m.len()]
+                {
+                    return TestResult::fail("match bytes are incorrect");
+                }
+                matches.push(Match {
+                    id: 0,
+                    span: Span {
+                        start: m.start() as usize,
+                        end: m.end() as usize,
+                    },
+                });
+            }
+            TestResult::matches(matches)
+        }
+        #[cfg(not(feature = "stream"))]
+        "stream" => TestResult::skip(),
         name => TestResult::fail(&format!("unrecognized test name: {}", name)),
     }
 }
 
+/// A reader that only ever returns one byte at a time.
+#[cfg(feature = "stream")]
+struct OneByteReader<'a>(&'a [u8]);
+
+#[cfg(feature = "stream")]
+impl<'a> std::io::Read for OneByteReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.0.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+        buf[0] = self.0[0];
+        self.0 = &self.0[1..];
+        Ok(1)
+    }
+}
+
 /// Converts the given regex test to a closure that searches with a
 /// `bytes::Regex`. If the test configuration is unsupported, then a
 /// `CompiledRegex` that skips the test is returned.
@@ -1,7 +1,9 @@
 use {
     anyhow::Result,
     regex::{RegexSet, RegexSetBuilder},
-    regex_test::{CompiledRegex, RegexTest, TestResult, TestRunner},
+    regex_test::{
+        CompiledRegex, Match, RegexTest, Span, TestResult, TestRunner,
+    },
 };
 
 /// Tests the default configuration of the hybrid NFA/DFA.
@@ -9,13 +11,61 @@ use {
 fn default() -> Result<()> {
     let mut runner = TestRunner::new()?;
     runner
-        .expand(&["is_match", "which"], |test| test.compiles())
+        .expand(&["is_match", "which", "find"], |test| test.compiles())
         .blacklist_iter(super::BLACKLIST)
         .test_iter(crate::suite()?.iter(), compiler)
         .assert();
     Ok(())
 }
 
+/// Tests finding match spans with a regex set, which uses leftmost-first
+/// semantics.
+#[test]
+fn leftmost() -> Result<()> {
+    let mut runner = TestRunner::new()?;
+    runner
+        .expand(&["find", "captures"], |test| test.compiles())
+        .blacklist_iter(super::BLACKLIST)
+        .test_iter(crate::suite()?.iter(), leftmost_compiler)
+        .assert();
+    Ok(())
+}
+
+/// Tests building a set from named patterns and finding the captures of the
+/// winning pattern.
+#[test]
+fn named() -> Result<()> {
+    let set = RegexSet::new_named([
+        ("kv", r"(?<key>[a-z]+)=(?<value>[0-9]+)"),
+        ("word", r"(?<key>[a-z]+)"),
+    ])?;
+    assert_eq!(Some("kv"), set.name(0));
+    assert_eq!(Some("word"), set.name(1));
+    assert_eq!(None, set.name(2));
+
+    let hay = "-- foo bar=5";
+    let (index, caps) = set.captures(hay).unwrap();
+    assert_eq!(1, index);
+    assert_eq!("foo", &caps["key"]);
+    assert!(caps.name("value").is_none());
+    let (index, caps) = set.captures_at(hay, 6).unwrap();
+    assert_eq!(0, index);
+    assert_eq!("bar", &caps["key"]);
+    assert_eq!("5", &caps["value"]);
+    assert!(set.captures_at(hay, hay.len()).is_none());
+
+    // 'captures' always agrees with the first item of 'captures_iter'.
+    let (index1, caps1) = set.captures(hay).unwrap();
+    let (index2, caps2) = set.captures_iter(hay).next().unwrap();
+    assert_eq!(index1, index2);
+    assert_eq!(caps1.get(0).unwrap().range(), caps2.get(0).unwrap().range());
+
+    // Names must be unique, but patterns need not be.
+    assert!(RegexSet::new_named([("a", "x"), ("b", "y"), ("a", "z")]).is_err());
+    assert!(RegexSet::new_named([("a", "x"), ("b", "x")]).is_ok());
+    Ok(())
+}
+
 fn run_test(re: &RegexSet, test: &RegexTest) -> TestResult {
     let hay = match std::str::from_utf8(test.haystack()) {
         Ok(hay) => hay,
@@ -29,6 +79,17 @@ fn run_test(re: &RegexSet, test: &RegexTest) -> TestResult {
     match test.additional_name() {
         "is_match" => TestResult::matched(re.is_match(hay)),
         "which" => TestResult::which(re.matches(hay).iter()),
+        "find" => match re.find_overlapping_iter(hay) {
+            Ok(it) => TestResult::matches(
+                it.take(test.match_limit().unwrap_or(std::usize::MAX)).map(
+                    |(id, m)| Match {
+                        id,
+                        span: Span { start: m.start(), end: m.end() },
+                    },
+                ),
+            ),
+            Err(err) => TestResult::fail(&err.to_string()),
+        },
         name => TestResult::fail(&format!("unrecognized test name: {}", name)),
     }
 }
@@ -77,3 +138,92 @@ fn compiler(
         .build()?;
     Ok(CompiledRegex::compiled(move |test| run_test(&re, test)))
 }
+
+fn run_leftmost_test(re: &RegexSet, test: &RegexTest) -> TestResult {
+    let hay = match std::str::from_utf8(test.haystack()) {
+        Ok(hay) => hay,
+        Err(err) => {
+            return TestResult::fail(&format!(
+                "haystack is not valid UTF-8: {}",
+                err
+            ));
+        }
+    };
+    match test.additional_name() {
+        "find" => TestResult::matches(
+            re.find_iter(hay)
+                .take(test.match_limit().unwrap_or(std::usize::MAX))
+                .map(|(id, m)| Match {
+                    id,
+                    span: Span { start: m.start(), end: m.end() },
+                }),
+        ),
+        "captures" => {
+            let it = re
+                .captures_iter(hay)
+                .take(test.match_limit().unwrap_or(std::usize::MAX))
+                .map(|(id, caps)| testify_captures(id, &caps));
+            TestResult::captures(it)
+        }
+        name => TestResult::fail(&format!("unrecognized test name: {}", name)),
+    }
+}
+
+/// Like `compiler`, but for tests of leftmost-first searches that report
+/// match spans.
+fn leftmost_compiler(
+    test: &RegexTest,
+    _patterns: &[String],
+) -> anyhow::Result<CompiledRegex> {
+    let skip = Ok(CompiledRegex::skip());
+
+    // We only test find_iter and captures_iter, which are leftmost searches.
+    if !matches!(test.search_kind(), regex_test::SearchKind::Leftmost) {
+        return skip;
+    }
+    // Finding match spans with a RegexSet always uses leftmost-first.
+    if !matches!(test.match_kind(), regex_test::MatchKind::LeftmostFirst) {
+        return skip;
+    }
+    // The top-level RegexSet API always runs unanchored searches. But we can
+    // handle tests that are anchored but have only one match.
+    if test.anchored() && test.match_limit() != Some(1) {
+        return skip;
+    }
+    // We don't support tests with explicit search bounds.
+    let bounds = test.bounds();
+    if !(bounds.start == 0 && bounds.end == test.haystack().len()) {
+        return skip;
+    }
+    // The Regex API specifically does not support disabling UTF-8 mode because
+    // it can only search &str which is always valid UTF-8.
+    if !test.utf8() {
+        return skip;
+    }
+    // If the test requires Unicode but the Unicode feature isn't enabled,
+    // skip it. This is a little aggressive, but the test suite doesn't
+    // have any easy way of communicating which Unicode features are needed.
+    if test.unicode() && !cfg!(feature = "unicode") {
+        return skip;
+    }
+    let re = RegexSetBuilder::new(test.regexes())
+        .case_insensitive(test.case_insensitive())
+        .unicode(test.unicode())
+        .line_terminator(test.line_terminator())
+        .build()?;
+    Ok(CompiledRegex::compiled(move |test| run_leftmost_test(&re, test)))
+}
+
+/// Convert `Captures` into the test suite's capture values.
+fn testify_captures(
+    id: usize,
+    caps: &regex::Captures<'_>,
+) -> regex_test::Captures {
+    let spans = caps.iter().map(|group| {
+        group.map(|m| regex_test::Span { start: m.start(), end: m.end() })
+    });
+    // This unwrap is OK because we assume our 'caps' represents a match, and
+    // a match always gives a non-zero number of groups with the first group
+    // being non-None.
+    regex_test::Captures::new(id, spans).unwrap()
+}